@@ -4,7 +4,7 @@ use swc_common::ast_serde;
 use crate::{
     common::{BaseNode, Identifier, SuperTypeParams},
     expr::Expression,
-    lit::StringLiteral,
+    lit::{LiteralExtra, StringLiteral},
 };
 
 #[derive(Debug, Clone, PartialEq)]
@@ -220,6 +220,8 @@ pub struct JSXText {
     #[serde(flatten)]
     pub base: BaseNode,
     pub value: JsWord,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extra: Option<LiteralExtra>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
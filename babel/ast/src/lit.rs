@@ -24,12 +24,32 @@ pub enum Literal {
     Decimal(DecimalLiteral),
 }
 
+/// `extra` of literals, which stores the source text of them.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct LiteralExtra {
+    pub raw: JsWord,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub raw_value: Option<JsWord>,
+}
+
+/// `extra` of [NumericLiteral].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NumericLiteralExtra {
+    pub raw: JsWord,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub raw_value: Option<f64>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(tag = "type")]
 pub struct StringLiteral {
     #[serde(flatten)]
     pub base: BaseNode,
     pub value: JsWord,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extra: Option<LiteralExtra>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -38,6 +58,8 @@ pub struct NumericLiteral {
     #[serde(flatten)]
     pub base: BaseNode,
     pub value: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extra: Option<NumericLiteralExtra>,
 }
 
 /// Deprecated. Use NumericLiteral instead.
@@ -72,6 +94,8 @@ pub struct RegExpLiteral {
     pub pattern: JsWord,
     #[serde(default)]
     pub flags: JsWord,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extra: Option<LiteralExtra>,
 }
 
 /// Deprecated. Use RegExpLiteral instead.
@@ -164,6 +188,8 @@ pub struct BigIntLiteral {
     pub base: BaseNode,
     #[serde(default)]
     pub value: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extra: Option<LiteralExtra>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
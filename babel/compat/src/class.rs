@@ -53,7 +53,7 @@ impl Babelify for ClassMember {
             ClassMember::ClassProp(p) => ClassBodyEl::Prop(p.babelify(ctx)),
             ClassMember::PrivateProp(p) => ClassBodyEl::PrivateProp(p.babelify(ctx)),
             ClassMember::TsIndexSignature(s) => ClassBodyEl::TSIndex(s.babelify(ctx)),
-            ClassMember::Empty(_) => panic!(
+            ClassMember::Empty(_) | ClassMember::AutoAccessor(_) => panic!(
                 "illegal conversion: Cannot convert {:?} to ClassBodyEl",
                 &self
             ),
@@ -9,6 +9,7 @@ use swc_babel_ast::{
     JSXMemberExpression, JSXNamespacedName as BabelJSXNamespacedName, JSXOpeningElAttr,
     JSXOpeningElement as BabelJSXOpeningElement, JSXOpeningFragment as BabelJSXOpeningFragment,
     JSXSpreadAttribute, JSXSpreadChild as BabelJSXSpreadChild, JSXText as BabelJSXText,
+    LiteralExtra,
 };
 use swc_common::{BytePos, Span, Spanned};
 use swc_ecma_ast::{
@@ -235,6 +236,10 @@ impl Babelify for JSXText {
     fn babelify(self, ctx: &Context) -> Self::Output {
         BabelJSXText {
             base: ctx.base(self.span),
+            extra: Some(LiteralExtra {
+                raw: self.raw,
+                raw_value: Some(self.value.clone()),
+            }),
             value: self.value,
         }
     }
@@ -5,6 +5,7 @@ use serde::de::DeserializeOwned;
 use serde::Serialize;
 use std::sync::Arc;
 use swc::SwcComments;
+use swc_atoms::JsWord;
 use swc_babel_ast::{BaseComment, BaseNode, Comment, LineCol, Loc};
 use swc_common::comments::CommentKind;
 use swc_common::comments::Comments;
@@ -29,8 +30,11 @@ mod operators;
 mod pat;
 mod prop;
 mod stmt;
+mod swcify;
 mod typescript;
 
+pub use swcify::Swcify;
+
 #[derive(Clone)]
 pub struct Context {
     pub fm: Arc<SourceFile>,
@@ -74,6 +78,15 @@ impl Context {
         Some(Loc { start, end })
     }
 
+    /// Source text of `span`, which is stored in `extra.raw` of literals.
+    fn raw(&self, span: Span) -> Option<JsWord> {
+        if span.is_dummy() {
+            return None;
+        }
+
+        self.cm.span_to_snippet(span).ok().map(JsWord::from)
+    }
+
     fn convert_comments(&self, comments: Vec<swc_common::comments::Comment>) -> Vec<Comment> {
         comments
             .into_iter()
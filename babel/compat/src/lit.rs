@@ -1,7 +1,7 @@
 use crate::{Babelify, Context};
 use swc_babel_ast::{
-    BigIntLiteral, BooleanLiteral, JSXText as BabelJSXText, Literal, NullLiteral, NumericLiteral,
-    RegExpLiteral, StringLiteral,
+    BigIntLiteral, BooleanLiteral, JSXText as BabelJSXText, Literal, LiteralExtra, NullLiteral,
+    NumericLiteral, NumericLiteralExtra, RegExpLiteral, StringLiteral,
 };
 
 use serde::{Deserialize, Serialize};
@@ -35,6 +35,10 @@ impl Babelify for Str {
     fn babelify(self, ctx: &Context) -> Self::Output {
         StringLiteral {
             base: ctx.base(self.span),
+            extra: ctx.raw(self.span).map(|raw| LiteralExtra {
+                raw,
+                raw_value: Some(self.value.clone()),
+            }),
             value: self.value,
         }
     }
@@ -68,6 +72,10 @@ impl Babelify for Number {
         NumericLiteral {
             base: ctx.base(self.span),
             value: self.value,
+            extra: ctx.raw(self.span).map(|raw| NumericLiteralExtra {
+                raw,
+                raw_value: Some(self.value),
+            }),
         }
     }
 }
@@ -76,9 +84,14 @@ impl Babelify for BigInt {
     type Output = BigIntLiteral;
 
     fn babelify(self, ctx: &Context) -> Self::Output {
+        let value = self.value.to_string();
         BigIntLiteral {
             base: ctx.base(self.span),
-            value: self.value.to_string(),
+            extra: ctx.raw(self.span).map(|raw| LiteralExtra {
+                raw,
+                raw_value: Some(value.clone().into()),
+            }),
+            value,
         }
     }
 }
@@ -91,6 +104,10 @@ impl Babelify for Regex {
             base: ctx.base(self.span),
             pattern: self.exp,
             flags: self.flags,
+            extra: ctx.raw(self.span).map(|raw| LiteralExtra {
+                raw,
+                raw_value: None,
+            }),
         }
     }
 }
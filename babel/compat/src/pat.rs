@@ -234,16 +234,24 @@ impl Babelify for AssignPatProp {
     type Output = ObjectProperty;
 
     fn babelify(self, ctx: &Context) -> Self::Output {
-        let is_shorthand = self.value.is_none();
+        let key = self.key.clone().babelify(ctx);
+        // Babel represents `{ a = 1 }` as a shorthand property with an assignment
+        // pattern.
+        let value = match self.value {
+            Some(value) => ObjectPropVal::Pattern(PatternLike::AssignmentPat(AssignmentPattern {
+                base: ctx.base(self.span),
+                left: AssignmentPatternLeft::Id(self.key.babelify(ctx)),
+                right: Box::alloc().init(value.babelify(ctx).into()),
+                type_annotation: Default::default(),
+                decorators: Default::default(),
+            })),
+            None => ObjectPropVal::Pattern(PatternLike::Id(self.key.babelify(ctx))),
+        };
         ObjectProperty {
             base: ctx.base(self.span),
-            key: ObjectKey::Id(self.key.clone().babelify(ctx)),
-            value: if is_shorthand {
-                ObjectPropVal::Pattern(PatternLike::Id(self.key.babelify(ctx)))
-            } else {
-                ObjectPropVal::Expr(Box::alloc().init(self.value.unwrap().babelify(ctx).into()))
-            },
-            shorthand: is_shorthand,
+            key: ObjectKey::Id(key),
+            value,
+            shorthand: true,
             computed: Default::default(),
             decorators: Default::default(),
         }
@@ -0,0 +1,297 @@
+use super::{
+    function::{swcify_decorators, swcify_params},
+    prop::{key_to_expr, swcify_key},
+    Swcify,
+};
+use crate::Context;
+use swc_babel_ast::{
+    Access, ClassBodyEl, ClassDeclaration, ClassExpression, ClassImpl,
+    ClassMethod as BabelClassMethod, ClassMethodKind, ClassPrivateMethod, ClassPrivateProperty,
+    ClassProperty, Decorator as BabelDecorator, SuperTypeParams, TSDeclareMethod,
+};
+use swc_ecma_ast::{
+    Accessibility, Class, ClassDecl, ClassExpr, ClassMember, ClassMethod, ClassProp, Constructor,
+    Decorator, Function, MethodKind, PrivateMethod, PrivateProp, TsExprWithTypeArgs,
+    TsTypeParamInstantiation,
+};
+
+impl Swcify for ClassExpression {
+    type Output = ClassExpr;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        ClassExpr {
+            ident: self.id.map(|id| id.swcify(ctx).id),
+            class: Class {
+                span: ctx.span(&self.base),
+                decorators: swcify_decorators(self.decorators, ctx),
+                body: self.body.body.swcify(ctx),
+                super_class: self.super_class.swcify(ctx),
+                is_abstract: false,
+                type_params: self.type_parameters.and_then(|t| t.swcify(ctx)),
+                super_type_params: self.super_type_parameters.swcify(ctx),
+                implements: self.implements.unwrap_or_default().swcify(ctx),
+            },
+        }
+    }
+}
+
+impl Swcify for ClassDeclaration {
+    type Output = ClassDecl;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        let is_abstract = self.is_abstract.unwrap_or(false);
+        let declare = self.declare.unwrap_or(false);
+        let ident = self.id.swcify(ctx).id;
+        let mut class = ClassExpression {
+            base: self.base,
+            id: None,
+            super_class: self.super_class,
+            body: self.body,
+            decorators: self.decorators,
+            implements: self.implements,
+            mixins: self.mixins,
+            super_type_parameters: self.super_type_parameters,
+            type_parameters: self.type_parameters,
+        }
+        .swcify(ctx)
+        .class;
+        class.is_abstract = is_abstract;
+
+        ClassDecl {
+            ident,
+            declare,
+            class,
+        }
+    }
+}
+
+impl Swcify for SuperTypeParams {
+    type Output = TsTypeParamInstantiation;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        match self {
+            SuperTypeParams::TS(params) => params.swcify(ctx),
+            SuperTypeParams::Flow(_) => panic!(
+                "illegal conversion: Cannot convert {:?} to TsTypeParamInstantiation",
+                &self
+            ),
+        }
+    }
+}
+
+impl Swcify for ClassImpl {
+    type Output = TsExprWithTypeArgs;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        match self {
+            ClassImpl::TSExpr(e) => e.swcify(ctx),
+            ClassImpl::Implements(_) => panic!(
+                "illegal conversion: Cannot convert {:?} to TsExprWithTypeArgs",
+                &self
+            ),
+        }
+    }
+}
+
+impl Swcify for ClassBodyEl {
+    type Output = ClassMember;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        match self {
+            ClassBodyEl::Method(m) => m.swcify(ctx),
+            ClassBodyEl::PrivateMethod(m) => ClassMember::PrivateMethod(m.swcify(ctx)),
+            ClassBodyEl::Prop(p) => ClassMember::ClassProp(p.swcify(ctx)),
+            ClassBodyEl::PrivateProp(p) => ClassMember::PrivateProp(p.swcify(ctx)),
+            ClassBodyEl::TSMethod(m) => ClassMember::Method(m.swcify(ctx)),
+            ClassBodyEl::TSIndex(s) => ClassMember::TsIndexSignature(s.swcify(ctx)),
+        }
+    }
+}
+
+impl Swcify for BabelClassMethod {
+    type Output = ClassMember;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        let span = ctx.span(&self.base);
+        let key = swcify_key(self.key, self.computed.unwrap_or(false), ctx);
+        let accessibility = self.accessibility.or(self.access).swcify(ctx);
+
+        let kind = match self.kind {
+            Some(ClassMethodKind::Constructor) => {
+                return ClassMember::Constructor(Constructor {
+                    span,
+                    key,
+                    params: self.params.swcify(ctx),
+                    body: Some(self.body.swcify(ctx)),
+                    accessibility,
+                    is_optional: self.optional.unwrap_or(false),
+                })
+            }
+            kind => kind.swcify(ctx).unwrap_or(MethodKind::Method),
+        };
+
+        ClassMember::Method(ClassMethod {
+            span,
+            key,
+            function: Function {
+                params: swcify_params(self.params, ctx),
+                decorators: swcify_decorators(self.decorators, ctx),
+                span,
+                body: Some(self.body.swcify(ctx)),
+                is_generator: self.generator.unwrap_or(false),
+                is_async: self.is_async.unwrap_or(false),
+                type_params: self.type_parameters.and_then(|t| t.swcify(ctx)),
+                return_type: self.return_type.and_then(|t| (*t).swcify(ctx)),
+            },
+            kind,
+            is_static: self.is_static.unwrap_or(false),
+            accessibility,
+            is_abstract: self.is_abstract.unwrap_or(false),
+            is_optional: self.optional.unwrap_or(false),
+            is_override: false,
+        })
+    }
+}
+
+impl Swcify for ClassPrivateMethod {
+    type Output = PrivateMethod;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        let span = ctx.span(&self.base);
+
+        PrivateMethod {
+            span,
+            key: self.key.swcify(ctx),
+            function: Function {
+                params: swcify_params(self.params, ctx),
+                decorators: swcify_decorators(self.decorators, ctx),
+                span,
+                body: Some(self.body.swcify(ctx)),
+                is_generator: self.generator.unwrap_or(false),
+                is_async: self.is_async.unwrap_or(false),
+                type_params: self.type_parameters.and_then(|t| t.swcify(ctx)),
+                return_type: self.return_type.and_then(|t| (*t).swcify(ctx)),
+            },
+            kind: self.kind.swcify(ctx).unwrap_or(MethodKind::Method),
+            is_static: self.is_static.unwrap_or(false),
+            accessibility: self.accessibility.or(self.access).swcify(ctx),
+            is_abstract: self.is_abstract.unwrap_or(false),
+            is_optional: self.optional.unwrap_or(false),
+            is_override: false,
+        }
+    }
+}
+
+impl Swcify for TSDeclareMethod {
+    type Output = ClassMethod;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        let span = ctx.span(&self.base);
+
+        ClassMethod {
+            span,
+            key: swcify_key(self.key, self.computed.unwrap_or(false), ctx),
+            function: Function {
+                params: swcify_params(self.params, ctx),
+                decorators: swcify_decorators(self.decorators, ctx),
+                span,
+                body: None,
+                is_generator: self.generator.unwrap_or(false),
+                is_async: self.is_async.unwrap_or(false),
+                type_params: self.type_parameters.and_then(|t| t.swcify(ctx)),
+                return_type: self.return_type.and_then(|t| t.swcify(ctx)),
+            },
+            kind: self.kind.swcify(ctx).unwrap_or(MethodKind::Method),
+            is_static: self.is_static.unwrap_or(false),
+            accessibility: self.accessibility.or(self.access).swcify(ctx),
+            is_abstract: self.is_abstract.unwrap_or(false),
+            is_optional: self.optional.unwrap_or(false),
+            is_override: false,
+        }
+    }
+}
+
+impl Swcify for ClassProperty {
+    type Output = ClassProp;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        ClassProp {
+            span: ctx.span(&self.base),
+            key: key_to_expr(self.key, ctx),
+            value: self.value.swcify(ctx),
+            type_ann: self.type_annotation.and_then(|ann| (*ann).swcify(ctx)),
+            is_static: self.is_static.unwrap_or(false),
+            decorators: swcify_decorators(self.decorators, ctx),
+            computed: self.computed.unwrap_or(false),
+            accessibility: self.accessibility.swcify(ctx),
+            is_abstract: self.is_abstract.unwrap_or(false),
+            is_optional: self.optional.unwrap_or(false),
+            is_override: false,
+            readonly: self.readonly.unwrap_or(false),
+            declare: self.declare.unwrap_or(false),
+            definite: self.definite.unwrap_or(false),
+        }
+    }
+}
+
+impl Swcify for ClassPrivateProperty {
+    type Output = PrivateProp;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        PrivateProp {
+            span: ctx.span(&self.base),
+            key: self.key.swcify(ctx),
+            value: self.value.swcify(ctx),
+            type_ann: self.type_annotation.and_then(|ann| (*ann).swcify(ctx)),
+            is_static: self.static_any.as_bool().unwrap_or(false),
+            decorators: swcify_decorators(self.decorators, ctx),
+            computed: false,
+            accessibility: None,
+            is_abstract: false,
+            is_optional: false,
+            is_override: false,
+            readonly: false,
+            definite: false,
+        }
+    }
+}
+
+impl Swcify for BabelDecorator {
+    type Output = Decorator;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        Decorator {
+            span: ctx.span(&self.base),
+            expr: self.expression.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for Access {
+    type Output = Accessibility;
+
+    fn swcify(self, _ctx: &Context) -> Self::Output {
+        match self {
+            Access::Public => Accessibility::Public,
+            Access::Private => Accessibility::Private,
+            Access::Protected => Accessibility::Protected,
+        }
+    }
+}
+
+/// [ClassMethodKind::Constructor] is handled by [BabelClassMethod].
+impl Swcify for ClassMethodKind {
+    type Output = MethodKind;
+
+    fn swcify(self, _ctx: &Context) -> Self::Output {
+        match self {
+            ClassMethodKind::Get => MethodKind::Getter,
+            ClassMethodKind::Set => MethodKind::Setter,
+            ClassMethodKind::Method => MethodKind::Method,
+            ClassMethodKind::Constructor => panic!(
+                "illegal conversion: Cannot convert {:?} to MethodKind",
+                &self
+            ),
+        }
+    }
+}
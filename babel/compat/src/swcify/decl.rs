@@ -0,0 +1,83 @@
+use super::{function::decl_to_expr, Swcify};
+use crate::Context;
+use swc_babel_ast::{
+    Declaration, FunctionDeclaration, VariableDeclaration, VariableDeclarationKind,
+    VariableDeclarator,
+};
+use swc_ecma_ast::{Decl, FnDecl, VarDecl, VarDeclKind, VarDeclarator};
+
+impl Swcify for Declaration {
+    type Output = Decl;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        match self {
+            Declaration::FuncDecl(d) => Decl::Fn(d.swcify(ctx)),
+            Declaration::VarDecl(d) => Decl::Var(d.swcify(ctx)),
+            Declaration::ClassDecl(d) => Decl::Class(d.swcify(ctx)),
+            Declaration::TSDeclFunc(d) => Decl::Fn(d.swcify(ctx)),
+            Declaration::TSInterfaceDecl(d) => Decl::TsInterface(d.swcify(ctx)),
+            Declaration::TSTypeAliasDecl(d) => Decl::TsTypeAlias(d.swcify(ctx)),
+            Declaration::TSEnumDecl(d) => Decl::TsEnum(d.swcify(ctx)),
+            Declaration::TSModuleDecl(d) => Decl::TsModule(d.swcify(ctx)),
+            _ => panic!("illegal conversion: Cannot convert {:?} to Decl", &self),
+        }
+    }
+}
+
+impl Swcify for FunctionDeclaration {
+    type Output = FnDecl;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        let ident = match self.id.clone() {
+            Some(id) => id.swcify(ctx).id,
+            None => panic!(
+                "illegal conversion: Cannot convert {:?} without id to FnDecl",
+                &self
+            ),
+        };
+
+        FnDecl {
+            ident,
+            declare: false,
+            function: decl_to_expr(self).swcify(ctx).function,
+        }
+    }
+}
+
+impl Swcify for VariableDeclaration {
+    type Output = VarDecl;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        VarDecl {
+            span: ctx.span(&self.base),
+            kind: self.kind.swcify(ctx),
+            declare: self.declare.unwrap_or(false),
+            decls: self.declarations.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for VariableDeclarationKind {
+    type Output = VarDeclKind;
+
+    fn swcify(self, _ctx: &Context) -> Self::Output {
+        match self {
+            VariableDeclarationKind::Var => VarDeclKind::Var,
+            VariableDeclarationKind::Let => VarDeclKind::Let,
+            VariableDeclarationKind::Const => VarDeclKind::Const,
+        }
+    }
+}
+
+impl Swcify for VariableDeclarator {
+    type Output = VarDeclarator;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        VarDeclarator {
+            span: ctx.span(&self.base),
+            name: self.id.swcify(ctx),
+            init: self.init.swcify(ctx),
+            definite: self.definite.unwrap_or(false),
+        }
+    }
+}
@@ -0,0 +1,607 @@
+use super::{
+    function::swcify_params,
+    lit::dot3_token,
+    operators::swcify_assign_op,
+    prop::{swcify_key, swcify_prop_value},
+    Swcify,
+};
+use crate::Context;
+use swc_atoms::js_word;
+use swc_babel_ast::{
+    Arg, ArrayExprEl, ArrowFuncExprBody, ArrowFunctionExpression, AssignmentExpression,
+    AssignmentPatternLeft, BinaryExprLeft, BinaryExpression, CallExpression, Callee,
+    ConditionalExpression, Expression, LVal, LogicalExpression, MemberExprProp, MemberExpression,
+    MetaProperty, NewExpression, ObjectExprProp, ObjectExpression, ObjectPropVal, ObjectProperty,
+    OptionalCallExpression, OptionalMemberExprProp, OptionalMemberExpression, PatternLike,
+    SpreadElement as BabelSpreadElement, TSAsExpression, TSEntityName, TSNonNullExpression, TSType,
+    TSTypeAssertion, TaggedTemplateExprTypeParams, TaggedTemplateExpression, UnaryExpression,
+    UpdateExpression, YieldExpression,
+};
+use swc_common::{BytePos, Span, Spanned};
+use swc_ecma_ast::{
+    ArrayLit, ArrowExpr, AssignExpr, AssignOp, AssignProp, AwaitExpr, BinExpr, BlockStmtOrExpr,
+    CallExpr, CondExpr, Expr, ExprOrSpread, ExprOrSuper, Ident, KeyValueProp, Lit, MemberExpr,
+    MetaPropExpr, NewExpr, ObjectLit, OptChainExpr, ParenExpr, PatOrExpr, Prop, PropOrSpread,
+    SeqExpr, SpreadElement, Super, TaggedTpl, ThisExpr, TsAsExpr, TsConstAssertion, TsNonNullExpr,
+    TsTypeAssertion, UnaryExpr, UpdateExpr, YieldExpr,
+};
+
+impl Swcify for Expression {
+    type Output = Expr;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        match self {
+            Expression::Array(e) => Expr::Array(ArrayLit {
+                span: ctx.span(&e.base),
+                elems: e.elements.swcify(ctx),
+            }),
+            Expression::Assignment(e) => Expr::Assign(e.swcify(ctx)),
+            Expression::Binary(e) => Expr::Bin(e.swcify(ctx)),
+            Expression::Call(e) => Expr::Call(e.swcify(ctx)),
+            Expression::Conditional(e) => Expr::Cond(e.swcify(ctx)),
+            Expression::Func(e) => Expr::Fn(e.swcify(ctx)),
+            Expression::Id(e) => Expr::Ident(e.swcify(ctx).id),
+            Expression::StringLiteral(e) => Expr::Lit(Lit::Str(e.swcify(ctx))),
+            Expression::NumericLiteral(e) => Expr::Lit(Lit::Num(e.swcify(ctx))),
+            Expression::NullLiteral(e) => Expr::Lit(Lit::Null(e.swcify(ctx))),
+            Expression::BooleanLiteral(e) => Expr::Lit(Lit::Bool(e.swcify(ctx))),
+            Expression::RegExpLiteral(e) => Expr::Lit(Lit::Regex(e.swcify(ctx))),
+            Expression::BigIntLiteral(e) => Expr::Lit(Lit::BigInt(e.swcify(ctx))),
+            Expression::Logical(e) => Expr::Bin(e.swcify(ctx)),
+            Expression::Member(e) => Expr::Member(e.swcify(ctx)),
+            Expression::New(e) => Expr::New(e.swcify(ctx)),
+            Expression::Object(e) => Expr::Object(e.swcify(ctx)),
+            Expression::Sequence(e) => Expr::Seq(SeqExpr {
+                span: ctx.span(&e.base),
+                exprs: e.expressions.swcify(ctx),
+            }),
+            Expression::Parenthesized(e) => Expr::Paren(ParenExpr {
+                span: ctx.span(&e.base),
+                expr: e.expression.swcify(ctx),
+            }),
+            Expression::This(e) => Expr::This(ThisExpr {
+                span: ctx.span(&e.base),
+            }),
+            Expression::Unary(e) => Expr::Unary(e.swcify(ctx)),
+            Expression::Update(e) => Expr::Update(e.swcify(ctx)),
+            Expression::ArrowFunc(e) => Expr::Arrow(e.swcify(ctx)),
+            Expression::Class(e) => Expr::Class(e.swcify(ctx)),
+            Expression::MetaProp(e) => Expr::MetaProp(e.swcify(ctx)),
+            Expression::TaggedTemplate(e) => Expr::TaggedTpl(e.swcify(ctx)),
+            Expression::TemplateLiteral(e) => Expr::Tpl(e.swcify(ctx)),
+            Expression::Yield(e) => Expr::Yield(e.swcify(ctx)),
+            Expression::Await(e) => Expr::Await(AwaitExpr {
+                span: ctx.span(&e.base),
+                arg: e.argument.swcify(ctx),
+            }),
+            // swc represents `import` of `import()` as an identifier.
+            Expression::Import(e) => Expr::Ident(Ident::new(js_word!("import"), ctx.span(&e.base))),
+            Expression::OptionalMember(e) => e.swcify(ctx),
+            Expression::OptionalCall(e) => e.swcify(ctx),
+            Expression::JSXElement(e) => Expr::JSXElement(Box::new(e.swcify(ctx))),
+            Expression::JSXFragment(e) => Expr::JSXFragment(e.swcify(ctx)),
+            Expression::TSAs(e) => e.swcify(ctx),
+            Expression::TSTypeAssertion(e) => Expr::TsTypeAssertion(e.swcify(ctx)),
+            Expression::TSNonNull(e) => Expr::TsNonNull(e.swcify(ctx)),
+            Expression::Super(_)
+            | Expression::TypeCast(_)
+            | Expression::Bind(_)
+            | Expression::PipelinePrimaryTopicRef(_)
+            | Expression::Do(_)
+            | Expression::Record(_)
+            | Expression::Tuple(_)
+            | Expression::DecimalLiteral(_)
+            | Expression::Module(_) => {
+                panic!("illegal conversion: Cannot convert {:?} to Expr", &self)
+            }
+        }
+    }
+}
+
+/// `super` is only valid as a callee or an object of a member expression.
+fn swcify_expr_or_super(expr: Expression, ctx: &Context) -> ExprOrSuper {
+    match expr {
+        Expression::Super(s) => ExprOrSuper::Super(Super {
+            span: ctx.span(&s.base),
+        }),
+        _ => ExprOrSuper::Expr(Box::new(expr.swcify(ctx))),
+    }
+}
+
+impl Swcify for Callee {
+    type Output = ExprOrSuper;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        match self {
+            Callee::Expr(e) => swcify_expr_or_super(*e, ctx),
+            Callee::V8Id(_) => panic!(
+                "illegal conversion: Cannot convert {:?} to ExprOrSuper",
+                &self
+            ),
+        }
+    }
+}
+
+impl Swcify for Arg {
+    type Output = ExprOrSpread;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        match self {
+            Arg::Expr(e) => ExprOrSpread {
+                spread: None,
+                expr: e.swcify(ctx),
+            },
+            Arg::Spread(s) => spread_to_arg(s.swcify(ctx)),
+            Arg::JSXName(_) | Arg::Placeholder(_) => panic!(
+                "illegal conversion: Cannot convert {:?} to ExprOrSpread",
+                &self
+            ),
+        }
+    }
+}
+
+impl Swcify for ArrayExprEl {
+    type Output = ExprOrSpread;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        match self {
+            ArrayExprEl::Expr(e) => ExprOrSpread {
+                spread: None,
+                expr: e.swcify(ctx),
+            },
+            ArrayExprEl::Spread(s) => spread_to_arg(s.swcify(ctx)),
+        }
+    }
+}
+
+impl Swcify for BabelSpreadElement {
+    type Output = SpreadElement;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        let span = ctx.span(&self.base);
+
+        SpreadElement {
+            dot3_token: dot3_token(span.lo),
+            expr: self.argument.swcify(ctx),
+        }
+    }
+}
+
+fn spread_to_arg(s: SpreadElement) -> ExprOrSpread {
+    ExprOrSpread {
+        spread: Some(s.dot3_token),
+        expr: s.expr,
+    }
+}
+
+impl Swcify for AssignmentExpression {
+    type Output = AssignExpr;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        let op = swcify_assign_op(&self.operator);
+        // The parser of swc stores the left hand side of `=` as a pattern, and
+        // that of other operators as an expression.
+        let left = if op == AssignOp::Assign {
+            PatOrExpr::Pat(Box::new((*self.left).swcify(ctx)))
+        } else {
+            match *self.left {
+                LVal::Id(i) => PatOrExpr::Expr(Box::new(Expr::Ident(i.swcify(ctx).id))),
+                LVal::MemberExpr(m) => PatOrExpr::Expr(Box::new(Expr::Member(m.swcify(ctx)))),
+                left => PatOrExpr::Pat(Box::new(left.swcify(ctx))),
+            }
+        };
+
+        AssignExpr {
+            span: ctx.span(&self.base),
+            op,
+            left,
+            right: self.right.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for BinaryExprLeft {
+    type Output = Box<Expr>;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        match self {
+            BinaryExprLeft::Private(p) => Box::new(Expr::PrivateName(p.swcify(ctx))),
+            BinaryExprLeft::Expr(e) => e.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for BinaryExpression {
+    type Output = BinExpr;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        BinExpr {
+            span: ctx.span(&self.base),
+            op: self.operator.swcify(ctx),
+            left: (*self.left).swcify(ctx),
+            right: self.right.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for LogicalExpression {
+    type Output = BinExpr;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        BinExpr {
+            span: ctx.span(&self.base),
+            op: self.operator.swcify(ctx),
+            left: self.left.swcify(ctx),
+            right: self.right.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for CallExpression {
+    type Output = CallExpr;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        CallExpr {
+            span: ctx.span(&self.base),
+            callee: (*self.callee).swcify(ctx),
+            args: self.arguments.swcify(ctx),
+            type_args: self.type_parameters.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for NewExpression {
+    type Output = NewExpr;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        let span = ctx.span(&self.base);
+        let callee = match self.callee.swcify(ctx) {
+            ExprOrSuper::Expr(e) => e,
+            ExprOrSuper::Super(s) => panic!(
+                "illegal conversion: Cannot convert {:?} to the callee of NewExpr",
+                &s
+            ),
+        };
+        // Babel does not distinguish `new Foo` from `new Foo()`, so we look at
+        // the source code.
+        let args = if self.arguments.is_empty() && !has_parens_after(ctx, span, callee.span()) {
+            None
+        } else {
+            Some(self.arguments.swcify(ctx))
+        };
+
+        NewExpr {
+            span,
+            callee,
+            args,
+            type_args: self.type_parameters.swcify(ctx),
+        }
+    }
+}
+
+fn has_parens_after(ctx: &Context, span: Span, callee: Span) -> bool {
+    if span.is_dummy() || callee.is_dummy() || callee.hi >= span.hi {
+        return true;
+    }
+
+    ctx.cm
+        .span_to_snippet(span.with_lo(callee.hi))
+        .map(|s| s.contains('('))
+        .unwrap_or(true)
+}
+
+impl Swcify for ConditionalExpression {
+    type Output = CondExpr;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        CondExpr {
+            span: ctx.span(&self.base),
+            test: self.test.swcify(ctx),
+            cons: self.consequent.swcify(ctx),
+            alt: self.alternate.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for MemberExprProp {
+    type Output = Box<Expr>;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        match self {
+            MemberExprProp::Id(i) => Box::new(Expr::Ident(i.swcify(ctx).id)),
+            MemberExprProp::PrivateName(p) => Box::new(Expr::PrivateName(p.swcify(ctx))),
+            MemberExprProp::Expr(e) => e.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for MemberExpression {
+    type Output = MemberExpr;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        MemberExpr {
+            span: ctx.span(&self.base),
+            obj: swcify_expr_or_super(*self.object, ctx),
+            prop: (*self.property).swcify(ctx),
+            computed: self.computed,
+        }
+    }
+}
+
+impl Swcify for OptionalMemberExprProp {
+    type Output = Box<Expr>;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        match self {
+            OptionalMemberExprProp::Id(i) => Box::new(Expr::Ident(i.swcify(ctx).id)),
+            OptionalMemberExprProp::Expr(e) => e.swcify(ctx),
+        }
+    }
+}
+
+/// Wraps `expr` with [OptChainExpr] if the babel node is marked as optional.
+fn opt_chain(span: Span, obj: Span, optional: bool, expr: Expr) -> Expr {
+    if !optional {
+        return expr;
+    }
+
+    Expr::OptChain(OptChainExpr {
+        span,
+        question_dot_token: Span::new(obj.hi, obj.hi + BytePos(2), Default::default()),
+        expr: Box::new(expr),
+    })
+}
+
+impl Swcify for OptionalMemberExpression {
+    type Output = Expr;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        let span = ctx.span(&self.base);
+        let obj = self.object.swcify(ctx);
+        let obj_span = obj.span();
+
+        let expr = Expr::Member(MemberExpr {
+            span,
+            obj: ExprOrSuper::Expr(obj),
+            prop: self.property.swcify(ctx),
+            computed: self.computed,
+        });
+
+        opt_chain(span, obj_span, self.optional, expr)
+    }
+}
+
+impl Swcify for OptionalCallExpression {
+    type Output = Expr;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        let span = ctx.span(&self.base);
+        let callee = self.callee.swcify(ctx);
+        let callee_span = callee.span();
+
+        let expr = Expr::Call(CallExpr {
+            span,
+            callee: ExprOrSuper::Expr(callee),
+            args: self.arguments.swcify(ctx),
+            type_args: self.type_parameters.swcify(ctx),
+        });
+
+        opt_chain(span, callee_span, self.optional, expr)
+    }
+}
+
+impl Swcify for ObjectExpression {
+    type Output = ObjectLit;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        ObjectLit {
+            span: ctx.span(&self.base),
+            props: self.properties.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for ObjectExprProp {
+    type Output = PropOrSpread;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        match self {
+            ObjectExprProp::Method(m) => PropOrSpread::Prop(Box::new(m.swcify(ctx))),
+            ObjectExprProp::Prop(p) => PropOrSpread::Prop(Box::new(p.swcify(ctx))),
+            ObjectExprProp::Spread(s) => PropOrSpread::Spread(s.swcify(ctx)),
+        }
+    }
+}
+
+impl Swcify for ObjectProperty {
+    type Output = Prop;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        if self.shorthand {
+            match self.value {
+                ObjectPropVal::Expr(e) => match *e {
+                    Expression::Id(i) => return Prop::Shorthand(i.swcify(ctx).id),
+                    e => {
+                        return Prop::KeyValue(KeyValueProp {
+                            key: swcify_key(self.key, self.computed, ctx),
+                            value: Box::new(e.swcify(ctx)),
+                        })
+                    }
+                },
+                ObjectPropVal::Pattern(PatternLike::Id(i)) => {
+                    return Prop::Shorthand(i.swcify(ctx).id)
+                }
+                // `{ a = 1 }`, which is only valid as a pattern.
+                ObjectPropVal::Pattern(PatternLike::AssignmentPat(a)) => match a.left {
+                    AssignmentPatternLeft::Id(key) => {
+                        return Prop::Assign(AssignProp {
+                            key: key.swcify(ctx).id,
+                            value: a.right.swcify(ctx),
+                        })
+                    }
+                    left => panic!(
+                        "illegal conversion: Cannot convert {:?} to Prop::Assign",
+                        &left
+                    ),
+                },
+                value => {
+                    return Prop::KeyValue(KeyValueProp {
+                        key: swcify_key(self.key, self.computed, ctx),
+                        value: swcify_prop_value(value, ctx),
+                    })
+                }
+            }
+        }
+
+        Prop::KeyValue(KeyValueProp {
+            key: swcify_key(self.key, self.computed, ctx),
+            value: swcify_prop_value(self.value, ctx),
+        })
+    }
+}
+
+impl Swcify for UnaryExpression {
+    type Output = UnaryExpr;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        UnaryExpr {
+            span: ctx.span(&self.base),
+            op: self.operator.swcify(ctx),
+            arg: self.argument.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for UpdateExpression {
+    type Output = UpdateExpr;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        UpdateExpr {
+            span: ctx.span(&self.base),
+            op: self.operator.swcify(ctx),
+            prefix: self.prefix,
+            arg: self.argument.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for ArrowFunctionExpression {
+    type Output = ArrowExpr;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        ArrowExpr {
+            span: ctx.span(&self.base),
+            params: swcify_params(self.params, ctx)
+                .into_iter()
+                .map(|param| param.pat)
+                .collect(),
+            body: (*self.body).swcify(ctx),
+            is_async: self.is_async,
+            is_generator: self.generator,
+            type_params: self.type_parameters.and_then(|t| t.swcify(ctx)),
+            return_type: self.return_type.and_then(|t| (*t).swcify(ctx)),
+        }
+    }
+}
+
+impl Swcify for ArrowFuncExprBody {
+    type Output = BlockStmtOrExpr;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        match self {
+            ArrowFuncExprBody::Block(b) => BlockStmtOrExpr::BlockStmt(b.swcify(ctx)),
+            ArrowFuncExprBody::Expr(e) => BlockStmtOrExpr::Expr(e.swcify(ctx)),
+        }
+    }
+}
+
+impl Swcify for MetaProperty {
+    type Output = MetaPropExpr;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        MetaPropExpr {
+            meta: self.meta.swcify(ctx).id,
+            prop: self.property.swcify(ctx).id,
+        }
+    }
+}
+
+impl Swcify for TaggedTemplateExpression {
+    type Output = TaggedTpl;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TaggedTpl {
+            span: ctx.span(&self.base),
+            tag: self.tag.swcify(ctx),
+            type_params: self.type_parameters.map(|params| match params {
+                TaggedTemplateExprTypeParams::TS(params) => params.swcify(ctx),
+                TaggedTemplateExprTypeParams::Flow(_) => panic!(
+                    "illegal conversion: Cannot convert {:?} to TsTypeParamInstantiation",
+                    &params
+                ),
+            }),
+            tpl: self.quasi.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for YieldExpression {
+    type Output = YieldExpr;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        YieldExpr {
+            span: ctx.span(&self.base),
+            arg: self.argument.swcify(ctx),
+            delegate: self.delegate,
+        }
+    }
+}
+
+impl Swcify for TSAsExpression {
+    type Output = Expr;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        let span = ctx.span(&self.base);
+
+        // Babel represents `a as const` as a type reference named `const`.
+        if let TSType::TypeRef(ref r) = self.type_annotation {
+            if let TSEntityName::Id(ref id) = r.type_name {
+                if &*id.name == "const" && r.type_parameters.is_none() {
+                    return Expr::TsConstAssertion(TsConstAssertion {
+                        span,
+                        expr: self.expression.swcify(ctx),
+                    });
+                }
+            }
+        }
+
+        Expr::TsAs(TsAsExpr {
+            span,
+            expr: self.expression.swcify(ctx),
+            type_ann: Box::new(self.type_annotation.swcify(ctx)),
+        })
+    }
+}
+
+impl Swcify for TSTypeAssertion {
+    type Output = TsTypeAssertion;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsTypeAssertion {
+            span: ctx.span(&self.base),
+            expr: self.expression.swcify(ctx),
+            type_ann: Box::new(self.type_annotation.swcify(ctx)),
+        }
+    }
+}
+
+impl Swcify for TSNonNullExpression {
+    type Output = TsNonNullExpr;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsNonNullExpr {
+            span: ctx.span(&self.base),
+            expr: self.expression.swcify(ctx),
+        }
+    }
+}
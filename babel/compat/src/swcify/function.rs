@@ -0,0 +1,123 @@
+use super::Swcify;
+use crate::Context;
+use swc_babel_ast::{
+    Decorator as BabelDecorator, FunctionDeclaration, FunctionExpression, Param as BabelParam,
+    Pattern, TypeAnnotOrNoop, TypeParamDeclOrNoop,
+};
+use swc_common::Spanned;
+use swc_ecma_ast::{
+    Decorator, FnExpr, Function, Param, ParamOrTsParamProp, Pat, TsTypeAnn, TsTypeParamDecl,
+};
+
+impl Swcify for BabelParam {
+    type Output = ParamOrTsParamProp;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        // Decorators of a parameter are stored in the pattern by babel.
+        let (decorators, pat) = match self {
+            BabelParam::Id(mut i) => (i.decorators.take(), Pat::Ident(i.swcify(ctx))),
+            BabelParam::Pat(Pattern::Assignment(mut a)) => {
+                (a.decorators.take(), Pat::Assign(a.swcify(ctx)))
+            }
+            BabelParam::Pat(Pattern::Array(mut a)) => {
+                (a.decorators.take(), Pat::Array(a.swcify(ctx)))
+            }
+            BabelParam::Pat(Pattern::Object(mut o)) => {
+                (o.decorators.take(), Pat::Object(o.swcify(ctx)))
+            }
+            BabelParam::Rest(mut r) => (r.decorators.take(), Pat::Rest(r.swcify(ctx))),
+            BabelParam::TSProp(p) => return ParamOrTsParamProp::TsParamProp(p.swcify(ctx)),
+        };
+
+        ParamOrTsParamProp::Param(Param {
+            span: pat.span(),
+            decorators: swcify_decorators(decorators, ctx),
+            pat,
+        })
+    }
+}
+
+/// Converts parameters of a function, which cannot be parameter properties.
+pub(super) fn swcify_params(params: Vec<BabelParam>, ctx: &Context) -> Vec<Param> {
+    params
+        .into_iter()
+        .map(|param| match param.swcify(ctx) {
+            ParamOrTsParamProp::Param(param) => param,
+            ParamOrTsParamProp::TsParamProp(p) => {
+                panic!("illegal conversion: Cannot convert {:?} to Param", &p)
+            }
+        })
+        .collect()
+}
+
+pub(super) fn swcify_decorators(
+    decorators: Option<Vec<BabelDecorator>>,
+    ctx: &Context,
+) -> Vec<Decorator> {
+    decorators.unwrap_or_default().swcify(ctx)
+}
+
+impl Swcify for FunctionExpression {
+    type Output = FnExpr;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        FnExpr {
+            ident: self.id.map(|id| id.swcify(ctx).id),
+            function: Function {
+                params: swcify_params(self.params, ctx),
+                decorators: Default::default(),
+                span: ctx.span(&self.base),
+                body: Some(self.body.swcify(ctx)),
+                is_generator: self.generator.unwrap_or(false),
+                is_async: self.is_async.unwrap_or(false),
+                type_params: self.type_parameters.and_then(|t| t.swcify(ctx)),
+                return_type: self.return_type.and_then(|t| (*t).swcify(ctx)),
+            },
+        }
+    }
+}
+
+/// `babelify` converts both of function declarations and function
+/// expressions from [Function], so we do the reverse.
+pub(super) fn decl_to_expr(f: FunctionDeclaration) -> FunctionExpression {
+    FunctionExpression {
+        base: f.base,
+        id: f.id,
+        params: f.params,
+        body: f.body,
+        generator: f.generator,
+        is_async: f.is_async,
+        return_type: f.return_type,
+        type_parameters: f.type_parameters,
+    }
+}
+
+impl Swcify for TypeAnnotOrNoop {
+    type Output = Option<TsTypeAnn>;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        match self {
+            TypeAnnotOrNoop::TS(ann) => Some((*ann).swcify(ctx)),
+            TypeAnnotOrNoop::Noop(_) => None,
+            TypeAnnotOrNoop::Flow(_) => panic!(
+                "illegal conversion: Cannot convert {:?} to TsTypeAnn",
+                &self
+            ),
+        }
+    }
+}
+
+impl Swcify for TypeParamDeclOrNoop {
+    type Output = Option<TsTypeParamDecl>;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        match self {
+            TypeParamDeclOrNoop::TS(decl) => Some(decl.swcify(ctx)),
+            TypeParamDeclOrNoop::Noop(_) => None,
+            TypeParamDeclOrNoop::Flow(_) => panic!(
+                "illegal conversion: Cannot convert {:?} to TsTypeParamDecl",
+                &self
+            ),
+        }
+    }
+}
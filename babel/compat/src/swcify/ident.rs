@@ -0,0 +1,30 @@
+use super::Swcify;
+use crate::Context;
+use swc_babel_ast::{Identifier, PrivateName as BabelPrivateName};
+use swc_ecma_ast::{BindingIdent, Ident, PrivateName};
+
+impl Swcify for Identifier {
+    type Output = BindingIdent;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        BindingIdent {
+            id: Ident {
+                span: ctx.span(&self.base),
+                sym: self.name,
+                optional: self.optional.unwrap_or(false),
+            },
+            type_ann: self.type_annotation.and_then(|ann| (*ann).swcify(ctx)),
+        }
+    }
+}
+
+impl Swcify for BabelPrivateName {
+    type Output = PrivateName;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        PrivateName {
+            span: ctx.span(&self.base),
+            id: self.id.swcify(ctx).id,
+        }
+    }
+}
@@ -0,0 +1,278 @@
+use super::{lit::dot3_token, Swcify};
+use crate::Context;
+use swc_babel_ast::{
+    JSXAttrName as BabelJSXAttrName, JSXAttrVal, JSXAttribute,
+    JSXClosingElement as BabelJSXClosingElement, JSXClosingFragment as BabelJSXClosingFragment,
+    JSXElement as BabelJSXElement, JSXElementChild as BabelJSXElementChild,
+    JSXElementName as BabelJSXElementName, JSXEmptyExpression, JSXExprContainerExpr,
+    JSXExpressionContainer, JSXFragment as BabelJSXFragment, JSXIdentifier, JSXMemberExprObject,
+    JSXMemberExpression, JSXNamespacedName as BabelJSXNamespacedName, JSXOpeningElAttr,
+    JSXOpeningElement as BabelJSXOpeningElement, JSXOpeningFragment as BabelJSXOpeningFragment,
+    JSXSpreadChild as BabelJSXSpreadChild, JSXText as BabelJSXText,
+};
+use swc_common::BytePos;
+use swc_ecma_ast::{
+    Ident, JSXAttr, JSXAttrName, JSXAttrOrSpread, JSXAttrValue, JSXClosingElement,
+    JSXClosingFragment, JSXElement, JSXElementChild, JSXElementName, JSXEmptyExpr, JSXExpr,
+    JSXExprContainer, JSXFragment, JSXMemberExpr, JSXNamespacedName, JSXObject, JSXOpeningElement,
+    JSXOpeningFragment, JSXSpreadChild, JSXText, Lit, SpreadElement,
+};
+
+impl Swcify for JSXIdentifier {
+    type Output = Ident;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        Ident::new(self.name, ctx.span(&self.base))
+    }
+}
+
+impl Swcify for JSXMemberExprObject {
+    type Output = JSXObject;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        match self {
+            JSXMemberExprObject::Expr(e) => JSXObject::JSXMemberExpr(Box::new(e.swcify(ctx))),
+            JSXMemberExprObject::Id(i) => JSXObject::Ident(i.swcify(ctx)),
+        }
+    }
+}
+
+impl Swcify for JSXMemberExpression {
+    type Output = JSXMemberExpr;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        // swc does not store the span of a member expression.
+        ctx.span(&self.base);
+
+        JSXMemberExpr {
+            obj: (*self.object).swcify(ctx),
+            prop: self.property.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for BabelJSXNamespacedName {
+    type Output = JSXNamespacedName;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        ctx.span(&self.base);
+
+        JSXNamespacedName {
+            ns: self.namespace.swcify(ctx),
+            name: self.name.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for JSXEmptyExpression {
+    type Output = JSXEmptyExpr;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        JSXEmptyExpr {
+            span: ctx.span(&self.base),
+        }
+    }
+}
+
+impl Swcify for JSXExpressionContainer {
+    type Output = JSXExprContainer;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        JSXExprContainer {
+            span: ctx.span(&self.base),
+            expr: self.expression.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for JSXExprContainerExpr {
+    type Output = JSXExpr;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        match self {
+            JSXExprContainerExpr::Empty(e) => JSXExpr::JSXEmptyExpr(e.swcify(ctx)),
+            JSXExprContainerExpr::Expr(e) => JSXExpr::Expr(e.swcify(ctx)),
+        }
+    }
+}
+
+impl Swcify for BabelJSXSpreadChild {
+    type Output = JSXSpreadChild;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        JSXSpreadChild {
+            span: ctx.span(&self.base),
+            expr: self.expression.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for BabelJSXElementName {
+    type Output = JSXElementName;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        match self {
+            BabelJSXElementName::Id(i) => JSXElementName::Ident(i.swcify(ctx)),
+            BabelJSXElementName::Expr(e) => JSXElementName::JSXMemberExpr(e.swcify(ctx)),
+            BabelJSXElementName::Name(n) => JSXElementName::JSXNamespacedName(n.swcify(ctx)),
+        }
+    }
+}
+
+impl Swcify for BabelJSXOpeningElement {
+    type Output = JSXOpeningElement;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        JSXOpeningElement {
+            name: self.name.swcify(ctx),
+            span: ctx.span(&self.base),
+            attrs: self.attributes.swcify(ctx),
+            self_closing: self.self_closing,
+            type_args: self.type_parameters.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for JSXOpeningElAttr {
+    type Output = JSXAttrOrSpread;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        match self {
+            JSXOpeningElAttr::Attr(a) => JSXAttrOrSpread::JSXAttr(a.swcify(ctx)),
+            JSXOpeningElAttr::Spread(s) => {
+                // The span of a spread attribute includes the curly braces in babel,
+                // so `...` starts right after `{`.
+                let span = ctx.span(&s.base);
+
+                JSXAttrOrSpread::SpreadElement(SpreadElement {
+                    dot3_token: dot3_token(span.lo + BytePos(1)),
+                    expr: s.argument.swcify(ctx),
+                })
+            }
+        }
+    }
+}
+
+impl Swcify for BabelJSXClosingElement {
+    type Output = JSXClosingElement;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        JSXClosingElement {
+            span: ctx.span(&self.base),
+            name: self.name.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for JSXAttribute {
+    type Output = JSXAttr;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        JSXAttr {
+            span: ctx.span(&self.base),
+            name: self.name.swcify(ctx),
+            value: self.value.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for BabelJSXAttrName {
+    type Output = JSXAttrName;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        match self {
+            BabelJSXAttrName::Id(i) => JSXAttrName::Ident(i.swcify(ctx)),
+            BabelJSXAttrName::Name(n) => JSXAttrName::JSXNamespacedName(n.swcify(ctx)),
+        }
+    }
+}
+
+impl Swcify for JSXAttrVal {
+    type Output = JSXAttrValue;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        match self {
+            JSXAttrVal::String(s) => JSXAttrValue::Lit(Lit::Str(s.swcify(ctx))),
+            JSXAttrVal::Expr(e) => JSXAttrValue::JSXExprContainer(e.swcify(ctx)),
+            JSXAttrVal::Element(e) => JSXAttrValue::JSXElement(Box::new(e.swcify(ctx))),
+            JSXAttrVal::Fragment(f) => JSXAttrValue::JSXFragment(f.swcify(ctx)),
+        }
+    }
+}
+
+impl Swcify for BabelJSXText {
+    type Output = JSXText;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        JSXText {
+            span: ctx.span(&self.base),
+            raw: match self.extra {
+                Some(extra) => extra.raw,
+                None => self.value.clone(),
+            },
+            value: self.value,
+        }
+    }
+}
+
+impl Swcify for BabelJSXElement {
+    type Output = JSXElement;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        JSXElement {
+            span: ctx.span(&self.base),
+            opening: self.opening_element.swcify(ctx),
+            children: self.children.swcify(ctx),
+            closing: self.closing_element.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for BabelJSXElementChild {
+    type Output = JSXElementChild;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        match self {
+            BabelJSXElementChild::Text(t) => JSXElementChild::JSXText(t.swcify(ctx)),
+            BabelJSXElementChild::Expr(e) => JSXElementChild::JSXExprContainer(e.swcify(ctx)),
+            BabelJSXElementChild::Spread(s) => JSXElementChild::JSXSpreadChild(s.swcify(ctx)),
+            BabelJSXElementChild::Element(e) => {
+                JSXElementChild::JSXElement(Box::new(e.swcify(ctx)))
+            }
+            BabelJSXElementChild::Fragment(f) => JSXElementChild::JSXFragment(f.swcify(ctx)),
+        }
+    }
+}
+
+impl Swcify for BabelJSXFragment {
+    type Output = JSXFragment;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        JSXFragment {
+            span: ctx.span(&self.base),
+            opening: self.opening_fragment.swcify(ctx),
+            children: self.children.swcify(ctx),
+            closing: self.closing_fragment.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for BabelJSXOpeningFragment {
+    type Output = JSXOpeningFragment;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        JSXOpeningFragment {
+            span: ctx.span(&self.base),
+        }
+    }
+}
+
+impl Swcify for BabelJSXClosingFragment {
+    type Output = JSXClosingFragment;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        JSXClosingFragment {
+            span: ctx.span(&self.base),
+        }
+    }
+}
@@ -0,0 +1,148 @@
+use super::Swcify;
+use crate::Context;
+use num_bigint::BigInt as BigIntValue;
+use swc_babel_ast::{
+    BigIntLiteral, BooleanLiteral, NullLiteral, NumericLiteral, RegExpLiteral, StringLiteral,
+    TemplateElement, TemplateLiteral, TemplateLiteralExpr,
+};
+use swc_common::BytePos;
+use swc_ecma_ast::{BigInt, Bool, Null, Number, Regex, Str, StrKind, Tpl, TplElement};
+
+impl Swcify for StringLiteral {
+    type Output = Str;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        Str {
+            span: ctx.span(&self.base),
+            value: self.value,
+            has_escape: self
+                .extra
+                .map(|extra| extra.raw.contains('\\'))
+                .unwrap_or(false),
+            kind: StrKind::Normal {
+                contains_quote: true,
+            },
+        }
+    }
+}
+
+impl Swcify for NumericLiteral {
+    type Output = Number;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        Number {
+            span: ctx.span(&self.base),
+            value: self.value,
+        }
+    }
+}
+
+impl Swcify for NullLiteral {
+    type Output = Null;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        Null {
+            span: ctx.span(&self.base),
+        }
+    }
+}
+
+impl Swcify for BooleanLiteral {
+    type Output = Bool;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        Bool {
+            span: ctx.span(&self.base),
+            value: self.value,
+        }
+    }
+}
+
+impl Swcify for RegExpLiteral {
+    type Output = Regex;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        Regex {
+            span: ctx.span(&self.base),
+            exp: self.pattern,
+            flags: self.flags,
+        }
+    }
+}
+
+impl Swcify for BigIntLiteral {
+    type Output = BigInt;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        // Babel stores the digits as written (e.g. `0x1f` or `1_000`), while
+        // `babelify` stores the decimal value.
+        let digits = self.value.replace('_', "");
+        let (radix, digits) = match digits.get(..2) {
+            Some("0x") | Some("0X") => (16, &digits[2..]),
+            Some("0o") | Some("0O") => (8, &digits[2..]),
+            Some("0b") | Some("0B") => (2, &digits[2..]),
+            _ => (10, &*digits),
+        };
+        let value = BigIntValue::parse_bytes(digits.as_bytes(), radix).unwrap_or_else(|| {
+            panic!(
+                "illegal conversion: Cannot convert {:?} to BigInt",
+                &self.value
+            )
+        });
+
+        BigInt {
+            span: ctx.span(&self.base),
+            value,
+        }
+    }
+}
+
+impl Swcify for TemplateLiteral {
+    type Output = Tpl;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        Tpl {
+            span: ctx.span(&self.base),
+            exprs: self
+                .expressions
+                .into_iter()
+                .map(|expr| match expr {
+                    TemplateLiteralExpr::Expr(e) => e.swcify(ctx),
+                    TemplateLiteralExpr::TSType(_) => {
+                        panic!("illegal conversion: Cannot convert {:?} to Expr", &expr)
+                    }
+                })
+                .collect(),
+            quasis: self.quasis.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for TemplateElement {
+    type Output = TplElement;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        let span = ctx.span(&self.base);
+        let has_escape = self.value.raw.contains('\\');
+        let str = |value| Str {
+            span,
+            value,
+            has_escape,
+            kind: StrKind::Normal {
+                contains_quote: false,
+            },
+        };
+
+        TplElement {
+            span,
+            tail: self.tail,
+            cooked: self.value.cooked.map(str),
+            raw: str(self.value.raw),
+        }
+    }
+}
+
+/// The `...` token of a spread element or a rest pattern starting at `lo`.
+pub(super) fn dot3_token(lo: BytePos) -> swc_common::Span {
+    swc_common::Span::new(lo, lo + BytePos(3), Default::default())
+}
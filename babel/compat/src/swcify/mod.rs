@@ -0,0 +1,170 @@
+//! Conversion from the babel ast to the swc ast, which is the reverse of
+//! [Babelify](crate::Babelify).
+
+use crate::Context;
+use ahash::AHashSet;
+use swc_babel_ast::{BaseNode, Comment, LineCol};
+use swc_common::{
+    comments::{Comment as SwcComment, CommentKind, Comments},
+    BytePos, Span, SyntaxContext, DUMMY_SP,
+};
+
+mod class;
+mod decl;
+mod expr;
+mod function;
+mod ident;
+mod jsx;
+mod lit;
+mod module;
+mod module_decl;
+mod operators;
+mod pat;
+mod prop;
+mod stmt;
+mod typescript;
+
+pub trait Swcify {
+    type Output;
+
+    fn swcify(self, ctx: &Context) -> Self::Output;
+}
+
+impl<T> Swcify for Vec<T>
+where
+    T: Swcify,
+{
+    type Output = Vec<T::Output>;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        self.into_iter().map(|v| v.swcify(ctx)).collect()
+    }
+}
+
+impl<T> Swcify for Option<T>
+where
+    T: Swcify,
+{
+    type Output = Option<T::Output>;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        self.map(|v| v.swcify(ctx))
+    }
+}
+
+impl<T> Swcify for Box<T>
+where
+    T: Swcify,
+{
+    type Output = Box<T::Output>;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        Box::new((*self).swcify(ctx))
+    }
+}
+
+impl Context {
+    /// Converts a byte offset (or a line / column pair if the offset is not
+    /// available) to a [BytePos] of [Context::fm].
+    fn pos(&self, offset: Option<usize>, line_col: Option<&LineCol>) -> Option<BytePos> {
+        match offset {
+            Some(offset) => Some(self.fm.start_pos + BytePos(offset as _)),
+            None => {
+                let line_col = line_col?;
+                let line_start = *self.fm.lines.get(line_col.line.checked_sub(1)?)?;
+
+                Some(line_start + BytePos(line_col.column as _))
+            }
+        }
+    }
+
+    /// Recovers a [Span] from `base`.
+    ///
+    /// Comments of the node are added to the comment map while doing so.
+    /// Inner comments are stored as leading comments of the last token of the
+    /// node because swc does not have inner comments.
+    fn span(&self, base: &BaseNode) -> Span {
+        let lo = self.pos(base.start, base.loc.as_ref().map(|loc| &loc.start));
+        let hi = self.pos(base.end, base.loc.as_ref().map(|loc| &loc.end));
+
+        let span = match (lo, hi) {
+            (Some(lo), Some(hi)) => Span::new(lo, hi, SyntaxContext::empty()),
+            _ => return DUMMY_SP,
+        };
+
+        for c in &base.leading_comments {
+            self.add_leading_comment(span.lo, c);
+        }
+        if span.hi > span.lo {
+            for c in &base.inner_comments {
+                self.add_leading_comment(span.hi - BytePos(1), c);
+            }
+        }
+        for c in &base.trailing_comments {
+            self.add_trailing_comment(span.hi, c);
+        }
+
+        span
+    }
+
+    fn swcify_comment(&self, comment: &Comment) -> SwcComment {
+        let (kind, c) = match comment {
+            Comment::Block(c) => (CommentKind::Block, c),
+            Comment::Line(c) => (CommentKind::Line, c),
+        };
+
+        SwcComment {
+            kind,
+            span: Span::new(
+                self.fm.start_pos + BytePos(c.start as _),
+                self.fm.start_pos + BytePos(c.end as _),
+                SyntaxContext::empty(),
+            ),
+            text: c.value.clone(),
+        }
+    }
+
+    // A node is often converted more than once (e.g. the key and the value of a
+    // shorthand property), so we should not add a comment twice.
+
+    fn add_leading_comment(&self, pos: BytePos, comment: &Comment) {
+        let comment = self.swcify_comment(comment);
+        let exists = self
+            .comments
+            .leading
+            .get(&pos)
+            .map_or(false, |v| v.contains(&comment));
+        if !exists {
+            self.comments.add_leading(pos, comment);
+        }
+    }
+
+    fn add_trailing_comment(&self, pos: BytePos, comment: &Comment) {
+        let comment = self.swcify_comment(comment);
+        let exists = self
+            .comments
+            .trailing
+            .get(&pos)
+            .map_or(false, |v| v.contains(&comment));
+        if !exists {
+            self.comments.add_trailing(pos, comment);
+        }
+    }
+
+    /// Babel attaches a comment between two nodes to both of them, as a
+    /// trailing comment of the former and a leading comment of the latter,
+    /// while swc stores it only once.
+    fn dedup_comments(&self) {
+        let leading = self
+            .comments
+            .leading
+            .iter()
+            .flat_map(|e| e.value().iter().map(|c| c.span).collect::<Vec<_>>())
+            .collect::<AHashSet<_>>();
+
+        self.comments.trailing.retain(|_, comments| {
+            comments.retain(|c| !leading.contains(&c.span));
+            !comments.is_empty()
+        });
+    }
+}
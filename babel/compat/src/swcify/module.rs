@@ -0,0 +1,57 @@
+use super::{module_decl::swcify_module_item, Swcify};
+use crate::Context;
+use swc_babel_ast::{File, Program as BabelProgram, SrcType};
+use swc_ecma_ast::{Module, ModuleItem, Program, Script};
+
+impl Swcify for File {
+    type Output = Program;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        ctx.span(&self.base);
+        let program = self.program.swcify(ctx);
+        ctx.dedup_comments();
+
+        program
+    }
+}
+
+impl Swcify for BabelProgram {
+    type Output = Program;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        // `babelify` imitates the trailing newline babel adds to the end of files.
+        let span = ctx.span(&self.base);
+        let span = span.with_hi(span.hi.min(ctx.fm.end_pos));
+        let shebang = self.interpreter.map(|i| {
+            ctx.span(&i.base);
+            i.value
+        });
+        let directives = self.directives;
+
+        match self.source_type {
+            SrcType::Module => Program::Module(Module {
+                span,
+                body: directives
+                    .swcify(ctx)
+                    .into_iter()
+                    .map(ModuleItem::Stmt)
+                    .chain(
+                        self.body
+                            .into_iter()
+                            .map(|stmt| swcify_module_item(stmt, ctx)),
+                    )
+                    .collect(),
+                shebang,
+            }),
+            SrcType::Script => Program::Script(Script {
+                span,
+                body: directives
+                    .swcify(ctx)
+                    .into_iter()
+                    .chain(self.body.swcify(ctx))
+                    .collect(),
+                shebang,
+            }),
+        }
+    }
+}
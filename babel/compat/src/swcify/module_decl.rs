@@ -0,0 +1,258 @@
+use super::{function::decl_to_expr, Swcify};
+use crate::Context;
+use swc_babel_ast::{
+    ExportAllDeclaration, ExportDefaultDeclType, ExportDefaultDeclaration,
+    ExportDefaultSpecifier as BabelExportDefaultSpecifier, ExportKind, ExportNamedDeclaration,
+    ExportNamespaceSpecifier as BabelExportNamespaceSpecifier,
+    ExportSpecifier as BabelExportSpecifier, ExportSpecifierType, IdOrString, ImportAttribute,
+    ImportDeclaration, ImportDefaultSpecifier as BabelImportDefaultSpecifier, ImportKind,
+    ImportNamespaceSpecifier, ImportSpecifier as BabelImportSpecifier, ImportSpecifierType,
+    Statement,
+};
+use swc_common::DUMMY_SP;
+use swc_ecma_ast::{
+    ClassExpr, DefaultDecl, ExportAll, ExportDecl, ExportDefaultDecl, ExportDefaultExpr,
+    ExportDefaultSpecifier, ExportNamedSpecifier, ExportNamespaceSpecifier, ExportSpecifier, Expr,
+    FnExpr, Ident, ImportDecl, ImportDefaultSpecifier, ImportNamedSpecifier, ImportSpecifier,
+    ImportStarAsSpecifier, KeyValueProp, Lit, ModuleDecl, ModuleItem, NamedExport, ObjectLit, Prop,
+    PropName, PropOrSpread,
+};
+
+/// Converts a statement of a module, which may be a module declaration.
+pub(super) fn swcify_module_item(stmt: Statement, ctx: &Context) -> ModuleItem {
+    let decl = match stmt {
+        Statement::ImportDecl(d) => ModuleDecl::Import(d.swcify(ctx)),
+        Statement::ExportAllDecl(d) => ModuleDecl::ExportAll(d.swcify(ctx)),
+        Statement::ExportDefaultDecl(d) => d.swcify(ctx),
+        Statement::ExportNamedDecl(d) => d.swcify(ctx),
+        Statement::TSImportEqualsDecl(d) => ModuleDecl::TsImportEquals(d.swcify(ctx)),
+        Statement::TSExportAssignment(d) => ModuleDecl::TsExportAssignment(d.swcify(ctx)),
+        Statement::TSNamespaceExportDecl(d) => ModuleDecl::TsNamespaceExport(d.swcify(ctx)),
+        _ => return ModuleItem::Stmt(stmt.swcify(ctx)),
+    };
+
+    ModuleItem::ModuleDecl(decl)
+}
+
+/// `babelify` stores each entry of `assert { type: "json" }` as an
+/// [ImportAttribute], so we reconstruct the object literal from them.
+fn swcify_import_asserts(
+    asserts: Option<Vec<ImportAttribute>>,
+    ctx: &Context,
+) -> Option<ObjectLit> {
+    asserts.map(|attrs| {
+        let mut span = DUMMY_SP;
+        let props = attrs
+            .into_iter()
+            .map(|attr| {
+                span = ctx.span(&attr.base);
+
+                PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                    key: match attr.key {
+                        IdOrString::Id(i) => PropName::Ident(i.swcify(ctx).id),
+                        IdOrString::String(s) => PropName::Str(s.swcify(ctx)),
+                    },
+                    value: Box::new(Expr::Lit(Lit::Str(attr.value.swcify(ctx)))),
+                })))
+            })
+            .collect();
+
+        ObjectLit { span, props }
+    })
+}
+
+impl Swcify for ImportDeclaration {
+    type Output = ImportDecl;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        ImportDecl {
+            span: ctx.span(&self.base),
+            specifiers: self.specifiers.swcify(ctx),
+            src: self.source.swcify(ctx),
+            type_only: self.import_kind == Some(ImportKind::Type),
+            asserts: swcify_import_asserts(self.assertions, ctx),
+        }
+    }
+}
+
+impl Swcify for ExportAllDeclaration {
+    type Output = ExportAll;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        ExportAll {
+            span: ctx.span(&self.base),
+            src: self.source.swcify(ctx),
+            asserts: swcify_import_asserts(self.assertions, ctx),
+        }
+    }
+}
+
+impl Swcify for ExportNamedDeclaration {
+    type Output = ModuleDecl;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        let span = ctx.span(&self.base);
+
+        match self.declaration {
+            Some(decl) => ModuleDecl::ExportDecl(ExportDecl {
+                span,
+                decl: (*decl).swcify(ctx),
+            }),
+            None => ModuleDecl::ExportNamed(NamedExport {
+                span,
+                specifiers: self.specifiers.swcify(ctx),
+                src: self.source.swcify(ctx),
+                type_only: self.export_kind == Some(ExportKind::Type),
+                asserts: swcify_import_asserts(self.assertions, ctx),
+            }),
+        }
+    }
+}
+
+impl Swcify for ExportDefaultDeclaration {
+    type Output = ModuleDecl;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        let span = ctx.span(&self.base);
+
+        let decl = match self.declaration {
+            ExportDefaultDeclType::Expr(e) => {
+                return ModuleDecl::ExportDefaultExpr(ExportDefaultExpr {
+                    span,
+                    expr: e.swcify(ctx),
+                })
+            }
+            ExportDefaultDeclType::Func(f) => DefaultDecl::Fn(decl_to_expr(f).swcify(ctx)),
+            ExportDefaultDeclType::TSFunc(f) => {
+                let f = f.swcify(ctx);
+                DefaultDecl::Fn(FnExpr {
+                    ident: Some(f.ident),
+                    function: f.function,
+                })
+            }
+            ExportDefaultDeclType::Class(c) => {
+                let c = c.swcify(ctx);
+                DefaultDecl::Class(ClassExpr {
+                    ident: Some(c.ident),
+                    class: c.class,
+                })
+            }
+        };
+
+        ModuleDecl::ExportDefaultDecl(ExportDefaultDecl { span, decl })
+    }
+}
+
+impl Swcify for ImportSpecifierType {
+    type Output = ImportSpecifier;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        match self {
+            ImportSpecifierType::Import(s) => ImportSpecifier::Named(s.swcify(ctx)),
+            ImportSpecifierType::Default(s) => ImportSpecifier::Default(s.swcify(ctx)),
+            ImportSpecifierType::Namespace(s) => ImportSpecifier::Namespace(s.swcify(ctx)),
+        }
+    }
+}
+
+impl Swcify for BabelImportDefaultSpecifier {
+    type Output = ImportDefaultSpecifier;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        ImportDefaultSpecifier {
+            span: ctx.span(&self.base),
+            local: self.local.swcify(ctx).id,
+        }
+    }
+}
+
+impl Swcify for ImportNamespaceSpecifier {
+    type Output = ImportStarAsSpecifier;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        ImportStarAsSpecifier {
+            span: ctx.span(&self.base),
+            local: self.local.swcify(ctx).id,
+        }
+    }
+}
+
+/// Babel always fills in the imported / exported name, so we drop it if it's
+/// the same node as the local one.
+fn swcify_renamed(local: &Ident, name: IdOrString, ctx: &Context) -> Option<Ident> {
+    let name = match name {
+        IdOrString::Id(i) => i.swcify(ctx).id,
+        IdOrString::String(_) => {
+            panic!("illegal conversion: Cannot convert {:?} to Ident", &name)
+        }
+    };
+
+    if name.sym == local.sym && name.span.lo == local.span.lo {
+        None
+    } else {
+        Some(name)
+    }
+}
+
+impl Swcify for BabelImportSpecifier {
+    type Output = ImportNamedSpecifier;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        let local = self.local.swcify(ctx).id;
+
+        ImportNamedSpecifier {
+            span: ctx.span(&self.base),
+            imported: swcify_renamed(&local, self.imported, ctx),
+            local,
+        }
+    }
+}
+
+impl Swcify for ExportSpecifierType {
+    type Output = ExportSpecifier;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        match self {
+            ExportSpecifierType::Export(s) => ExportSpecifier::Named(s.swcify(ctx)),
+            ExportSpecifierType::Default(s) => ExportSpecifier::Default(s.swcify(ctx)),
+            ExportSpecifierType::Namespace(s) => ExportSpecifier::Namespace(s.swcify(ctx)),
+        }
+    }
+}
+
+impl Swcify for BabelExportNamespaceSpecifier {
+    type Output = ExportNamespaceSpecifier;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        ExportNamespaceSpecifier {
+            span: ctx.span(&self.base),
+            name: self.exported.swcify(ctx).id,
+        }
+    }
+}
+
+impl Swcify for BabelExportDefaultSpecifier {
+    type Output = ExportDefaultSpecifier;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        ctx.span(&self.base);
+
+        ExportDefaultSpecifier {
+            exported: self.exported.swcify(ctx).id,
+        }
+    }
+}
+
+impl Swcify for BabelExportSpecifier {
+    type Output = ExportNamedSpecifier;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        let orig = self.local.swcify(ctx).id;
+
+        ExportNamedSpecifier {
+            span: ctx.span(&self.base),
+            exported: swcify_renamed(&orig, self.exported, ctx),
+            orig,
+        }
+    }
+}
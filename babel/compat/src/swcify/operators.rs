@@ -0,0 +1,100 @@
+use super::Swcify;
+use crate::Context;
+use swc_babel_ast::{BinaryExprOp, LogicalExprOp, UnaryExprOp, UpdateExprOp};
+use swc_ecma_ast::{AssignOp, BinaryOp, UnaryOp, UpdateOp};
+
+impl Swcify for BinaryExprOp {
+    type Output = BinaryOp;
+
+    fn swcify(self, _ctx: &Context) -> Self::Output {
+        match self {
+            BinaryExprOp::Addition => BinaryOp::Add,
+            BinaryExprOp::Subtraction => BinaryOp::Sub,
+            BinaryExprOp::Division => BinaryOp::Div,
+            BinaryExprOp::Remainder => BinaryOp::Mod,
+            BinaryExprOp::Multiplication => BinaryOp::Mul,
+            BinaryExprOp::Exponentiation => BinaryOp::Exp,
+            BinaryExprOp::And => BinaryOp::BitAnd,
+            BinaryExprOp::Or => BinaryOp::BitOr,
+            BinaryExprOp::RightShift => BinaryOp::RShift,
+            BinaryExprOp::UnsignedRightShift => BinaryOp::ZeroFillRShift,
+            BinaryExprOp::LeftShift => BinaryOp::LShift,
+            BinaryExprOp::Xor => BinaryOp::BitXor,
+            BinaryExprOp::Equal => BinaryOp::EqEq,
+            BinaryExprOp::StrictEqual => BinaryOp::EqEqEq,
+            BinaryExprOp::NotEqual => BinaryOp::NotEq,
+            BinaryExprOp::StrictNotEqual => BinaryOp::NotEqEq,
+            BinaryExprOp::In => BinaryOp::In,
+            BinaryExprOp::Instanceof => BinaryOp::InstanceOf,
+            BinaryExprOp::GreaterThan => BinaryOp::Gt,
+            BinaryExprOp::LessThan => BinaryOp::Lt,
+            BinaryExprOp::GreaterThanOrEqual => BinaryOp::GtEq,
+            BinaryExprOp::LessThanOrEqual => BinaryOp::LtEq,
+        }
+    }
+}
+
+impl Swcify for LogicalExprOp {
+    type Output = BinaryOp;
+
+    fn swcify(self, _ctx: &Context) -> Self::Output {
+        match self {
+            LogicalExprOp::Or => BinaryOp::LogicalOr,
+            LogicalExprOp::And => BinaryOp::LogicalAnd,
+            LogicalExprOp::Nullish => BinaryOp::NullishCoalescing,
+        }
+    }
+}
+
+/// Babel stores the operator of `AssignmentExpression` as a string.
+pub(super) fn swcify_assign_op(op: &str) -> AssignOp {
+    match op {
+        "=" => AssignOp::Assign,
+        "+=" => AssignOp::AddAssign,
+        "-=" => AssignOp::SubAssign,
+        "*=" => AssignOp::MulAssign,
+        "/=" => AssignOp::DivAssign,
+        "%=" => AssignOp::ModAssign,
+        "<<=" => AssignOp::LShiftAssign,
+        ">>=" => AssignOp::RShiftAssign,
+        ">>>=" => AssignOp::ZeroFillRShiftAssign,
+        "|=" => AssignOp::BitOrAssign,
+        "^=" => AssignOp::BitXorAssign,
+        "&=" => AssignOp::BitAndAssign,
+        "**=" => AssignOp::ExpAssign,
+        "&&=" => AssignOp::AndAssign,
+        "||=" => AssignOp::OrAssign,
+        "??=" => AssignOp::NullishAssign,
+        _ => panic!("illegal conversion: Cannot convert {:?} to AssignOp", op),
+    }
+}
+
+impl Swcify for UpdateExprOp {
+    type Output = UpdateOp;
+
+    fn swcify(self, _ctx: &Context) -> Self::Output {
+        match self {
+            UpdateExprOp::Increment => UpdateOp::PlusPlus,
+            UpdateExprOp::Decrement => UpdateOp::MinusMinus,
+        }
+    }
+}
+
+impl Swcify for UnaryExprOp {
+    type Output = UnaryOp;
+
+    fn swcify(self, _ctx: &Context) -> Self::Output {
+        match self {
+            UnaryExprOp::Negation => UnaryOp::Minus,
+            UnaryExprOp::Plus => UnaryOp::Plus,
+            UnaryExprOp::LogicalNot => UnaryOp::Bang,
+            UnaryExprOp::BitwiseNot => UnaryOp::Tilde,
+            UnaryExprOp::Typeof => UnaryOp::TypeOf,
+            UnaryExprOp::Void => UnaryOp::Void,
+            UnaryExprOp::Delete => UnaryOp::Delete,
+            UnaryExprOp::Throw => {
+                panic!("illegal conversion: Cannot convert {:?} to UnaryOp", &self)
+            }
+        }
+    }
+}
@@ -0,0 +1,188 @@
+use super::{lit::dot3_token, prop::swcify_key, Swcify};
+use crate::Context;
+use swc_babel_ast::{
+    ArrayPattern, AssignmentPattern, AssignmentPatternLeft, CatchClauseParam, Expression, LVal,
+    ObjectPattern, ObjectPatternProp, ObjectPropVal, ObjectProperty, Pattern, PatternLike,
+    RestElement,
+};
+use swc_ecma_ast::{
+    ArrayPat, AssignPat, AssignPatProp, Expr, Ident, KeyValuePatProp, ObjectPat, ObjectPatProp,
+    Pat, RestPat,
+};
+
+impl Swcify for PatternLike {
+    type Output = Pat;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        match self {
+            PatternLike::Id(i) => Pat::Ident(i.swcify(ctx)),
+            PatternLike::RestEl(r) => Pat::Rest(r.swcify(ctx)),
+            PatternLike::AssignmentPat(a) => Pat::Assign(a.swcify(ctx)),
+            PatternLike::ArrayPat(a) => Pat::Array(a.swcify(ctx)),
+            PatternLike::ObjectPat(o) => Pat::Object(o.swcify(ctx)),
+        }
+    }
+}
+
+impl Swcify for LVal {
+    type Output = Pat;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        match self {
+            LVal::Id(i) => Pat::Ident(i.swcify(ctx)),
+            LVal::MemberExpr(m) => Pat::Expr(Box::new(Expr::Member(m.swcify(ctx)))),
+            LVal::RestEl(r) => Pat::Rest(r.swcify(ctx)),
+            LVal::AssignmentPat(a) => Pat::Assign(a.swcify(ctx)),
+            LVal::ArrayPat(a) => Pat::Array(a.swcify(ctx)),
+            LVal::ObjectPat(o) => Pat::Object(o.swcify(ctx)),
+            LVal::TSParamProp(_) => {
+                panic!("illegal conversion: Cannot convert {:?} to Pat", &self)
+            }
+        }
+    }
+}
+
+impl Swcify for Pattern {
+    type Output = Pat;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        match self {
+            Pattern::Assignment(a) => Pat::Assign(a.swcify(ctx)),
+            Pattern::Array(a) => Pat::Array(a.swcify(ctx)),
+            Pattern::Object(o) => Pat::Object(o.swcify(ctx)),
+        }
+    }
+}
+
+impl Swcify for AssignmentPatternLeft {
+    type Output = Pat;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        match self {
+            AssignmentPatternLeft::Id(i) => Pat::Ident(i.swcify(ctx)),
+            AssignmentPatternLeft::Object(o) => Pat::Object(o.swcify(ctx)),
+            AssignmentPatternLeft::Array(a) => Pat::Array(a.swcify(ctx)),
+            AssignmentPatternLeft::Member(m) => Pat::Expr(Box::new(Expr::Member(m.swcify(ctx)))),
+        }
+    }
+}
+
+impl Swcify for CatchClauseParam {
+    type Output = Pat;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        match self {
+            CatchClauseParam::Id(i) => Pat::Ident(i.swcify(ctx)),
+            CatchClauseParam::Array(a) => Pat::Array(a.swcify(ctx)),
+            CatchClauseParam::Object(o) => Pat::Object(o.swcify(ctx)),
+        }
+    }
+}
+
+impl Swcify for ArrayPattern {
+    type Output = ArrayPat;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        ArrayPat {
+            span: ctx.span(&self.base),
+            elems: self.elements.swcify(ctx),
+            optional: false,
+            type_ann: self.type_annotation.and_then(|ann| (*ann).swcify(ctx)),
+        }
+    }
+}
+
+impl Swcify for ObjectPattern {
+    type Output = ObjectPat;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        ObjectPat {
+            span: ctx.span(&self.base),
+            props: self.properties.swcify(ctx),
+            optional: false,
+            type_ann: self.type_annotation.and_then(|ann| (*ann).swcify(ctx)),
+        }
+    }
+}
+
+impl Swcify for ObjectPatternProp {
+    type Output = ObjectPatProp;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        match self {
+            ObjectPatternProp::Rest(r) => ObjectPatProp::Rest(r.swcify(ctx)),
+            ObjectPatternProp::Prop(p) => swcify_pat_prop(p, ctx),
+        }
+    }
+}
+
+/// Babel represents `{ a }` and `{ a = 1 }` in a pattern as shorthand
+/// properties, which are [AssignPatProp] in swc.
+fn swcify_pat_prop(p: ObjectProperty, ctx: &Context) -> ObjectPatProp {
+    if p.shorthand {
+        let span = ctx.span(&p.base);
+        let assign = |key: Ident, value: Option<Box<Expr>>| {
+            ObjectPatProp::Assign(AssignPatProp { span, key, value })
+        };
+
+        match p.value {
+            ObjectPropVal::Pattern(PatternLike::Id(i)) => return assign(i.swcify(ctx).id, None),
+            ObjectPropVal::Expr(e) => match *e {
+                Expression::Id(i) => return assign(i.swcify(ctx).id, None),
+                e => {
+                    return ObjectPatProp::KeyValue(KeyValuePatProp {
+                        key: swcify_key(p.key, p.computed, ctx),
+                        value: Box::new(Pat::Expr(Box::new(e.swcify(ctx)))),
+                    })
+                }
+            },
+            ObjectPropVal::Pattern(PatternLike::AssignmentPat(AssignmentPattern {
+                left: AssignmentPatternLeft::Id(key),
+                right,
+                ..
+            })) => return assign(key.swcify(ctx).id, Some(right.swcify(ctx))),
+            ObjectPropVal::Pattern(value) => {
+                return ObjectPatProp::KeyValue(KeyValuePatProp {
+                    key: swcify_key(p.key, p.computed, ctx),
+                    value: Box::new(value.swcify(ctx)),
+                })
+            }
+        }
+    }
+
+    ObjectPatProp::KeyValue(KeyValuePatProp {
+        key: swcify_key(p.key, p.computed, ctx),
+        value: Box::new(match p.value {
+            ObjectPropVal::Pattern(value) => value.swcify(ctx),
+            ObjectPropVal::Expr(e) => Pat::Expr(e.swcify(ctx)),
+        }),
+    })
+}
+
+impl Swcify for RestElement {
+    type Output = RestPat;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        let span = ctx.span(&self.base);
+
+        RestPat {
+            span,
+            dot3_token: dot3_token(span.lo),
+            arg: self.argument.swcify(ctx),
+            type_ann: self.type_annotation.and_then(|ann| (*ann).swcify(ctx)),
+        }
+    }
+}
+
+impl Swcify for AssignmentPattern {
+    type Output = AssignPat;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        AssignPat {
+            span: ctx.span(&self.base),
+            left: Box::new(self.left.swcify(ctx)),
+            right: self.right.swcify(ctx),
+            type_ann: self.type_annotation.and_then(|ann| (*ann).swcify(ctx)),
+        }
+    }
+}
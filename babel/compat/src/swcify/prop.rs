@@ -0,0 +1,111 @@
+use super::{function::swcify_params, Swcify};
+use crate::Context;
+use swc_babel_ast::{ObjectKey, ObjectMethod, ObjectMethodKind, ObjectPropVal, PatternLike};
+use swc_common::Spanned;
+use swc_ecma_ast::{
+    ComputedPropName, Expr, Function, GetterProp, Lit, MethodProp, Prop, PropName, SetterProp,
+};
+
+impl Swcify for ObjectKey {
+    type Output = PropName;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        match self {
+            ObjectKey::Id(i) => PropName::Ident(i.swcify(ctx).id),
+            ObjectKey::String(s) => PropName::Str(s.swcify(ctx)),
+            ObjectKey::Numeric(n) => PropName::Num(n.swcify(ctx)),
+            ObjectKey::Expr(e) => {
+                let expr = e.swcify(ctx);
+                PropName::Computed(ComputedPropName {
+                    span: expr.span(),
+                    expr,
+                })
+            }
+        }
+    }
+}
+
+/// Babel marks computed keys with a flag instead of a separate node, so
+/// `[a]` and `a` both have an [ObjectKey::Id] as the key.
+pub(super) fn swcify_key(key: ObjectKey, computed: bool, ctx: &Context) -> PropName {
+    match key {
+        ObjectKey::Expr(..) => key.swcify(ctx),
+        _ if computed => {
+            let expr = key_to_expr(key, ctx);
+            PropName::Computed(ComputedPropName {
+                span: expr.span(),
+                expr,
+            })
+        }
+        _ => key.swcify(ctx),
+    }
+}
+
+/// Converts a key of a class property, which is an expression in swc.
+pub(super) fn key_to_expr(key: ObjectKey, ctx: &Context) -> Box<Expr> {
+    Box::new(match key {
+        ObjectKey::Id(i) => Expr::Ident(i.swcify(ctx).id),
+        ObjectKey::String(s) => Expr::Lit(Lit::Str(s.swcify(ctx))),
+        ObjectKey::Numeric(n) => Expr::Lit(Lit::Num(n.swcify(ctx))),
+        ObjectKey::Expr(e) => return e.swcify(ctx),
+    })
+}
+
+/// Converts the value of an [swc_babel_ast::ObjectProperty] in an object
+/// expression.
+pub(super) fn swcify_prop_value(value: ObjectPropVal, ctx: &Context) -> Box<Expr> {
+    match value {
+        ObjectPropVal::Expr(e) => e.swcify(ctx),
+        ObjectPropVal::Pattern(PatternLike::Id(i)) => Box::new(Expr::Ident(i.swcify(ctx).id)),
+        ObjectPropVal::Pattern(_) => {
+            panic!("illegal conversion: Cannot convert {:?} to Expr", &value)
+        }
+    }
+}
+
+impl Swcify for ObjectMethod {
+    type Output = Prop;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        let span = ctx.span(&self.base);
+        let key = swcify_key(self.key, self.computed, ctx);
+
+        match self.kind {
+            ObjectMethodKind::Method => Prop::Method(MethodProp {
+                key,
+                function: Function {
+                    params: swcify_params(self.params, ctx),
+                    decorators: self.decorator.unwrap_or_default().swcify(ctx),
+                    span,
+                    body: Some(self.body.swcify(ctx)),
+                    is_generator: self.generator.unwrap_or(false),
+                    is_async: self.is_async.unwrap_or(false),
+                    type_params: self.type_parameters.and_then(|t| t.swcify(ctx)),
+                    return_type: self.return_type.and_then(|t| (*t).swcify(ctx)),
+                },
+            }),
+            ObjectMethodKind::Get => Prop::Getter(GetterProp {
+                span,
+                key,
+                type_ann: self.return_type.and_then(|t| (*t).swcify(ctx)),
+                body: Some(self.body.swcify(ctx)),
+            }),
+            ObjectMethodKind::Set => {
+                let param = match swcify_params(self.params, ctx).into_iter().next() {
+                    Some(param) => param.pat,
+                    None => panic!(
+                        "illegal conversion: Cannot convert a setter without a parameter to \
+                         SetterProp"
+                    ),
+                };
+
+                Prop::Setter(SetterProp {
+                    span,
+                    key,
+                    param,
+                    body: Some(self.body.swcify(ctx)),
+                })
+            }
+        }
+    }
+}
@@ -0,0 +1,206 @@
+use super::Swcify;
+use crate::Context;
+use swc_babel_ast::{
+    BlockStatement, CatchClause as BabelCatchClause, Directive, DirectiveLiteral, ForStmtInit,
+    ForStmtLeft, Statement, SwitchCase as BabelSwitchCase,
+};
+use swc_ecma_ast::{
+    BlockStmt, BreakStmt, CatchClause, ContinueStmt, DebuggerStmt, Decl, DoWhileStmt, EmptyStmt,
+    Expr, ExprStmt, ForInStmt, ForOfStmt, ForStmt, IfStmt, LabeledStmt, Lit, ReturnStmt, Stmt, Str,
+    StrKind, SwitchCase, SwitchStmt, ThrowStmt, TryStmt, VarDeclOrExpr, VarDeclOrPat, WhileStmt,
+    WithStmt,
+};
+
+impl Swcify for BlockStatement {
+    type Output = BlockStmt;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        BlockStmt {
+            span: ctx.span(&self.base),
+            stmts: self
+                .directives
+                .swcify(ctx)
+                .into_iter()
+                .chain(self.body.swcify(ctx))
+                .collect(),
+        }
+    }
+}
+
+/// Module declarations are handled by [super::module], and this panics for
+/// them.
+impl Swcify for Statement {
+    type Output = Stmt;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        match self {
+            Statement::Block(s) => Stmt::Block(s.swcify(ctx)),
+            Statement::Break(s) => Stmt::Break(BreakStmt {
+                span: ctx.span(&s.base),
+                label: s.label.map(|l| l.swcify(ctx).id),
+            }),
+            Statement::Continue(s) => Stmt::Continue(ContinueStmt {
+                span: ctx.span(&s.base),
+                label: s.label.map(|l| l.swcify(ctx).id),
+            }),
+            Statement::Debugger(s) => Stmt::Debugger(DebuggerStmt {
+                span: ctx.span(&s.base),
+            }),
+            Statement::DoWhile(s) => Stmt::DoWhile(DoWhileStmt {
+                span: ctx.span(&s.base),
+                test: s.test.swcify(ctx),
+                body: s.body.swcify(ctx),
+            }),
+            Statement::Empty(s) => Stmt::Empty(EmptyStmt {
+                span: ctx.span(&s.base),
+            }),
+            Statement::Expr(s) => Stmt::Expr(ExprStmt {
+                span: ctx.span(&s.base),
+                expr: s.expression.swcify(ctx),
+            }),
+            Statement::ForIn(s) => Stmt::ForIn(ForInStmt {
+                span: ctx.span(&s.base),
+                left: s.left.swcify(ctx),
+                right: s.right.swcify(ctx),
+                body: s.body.swcify(ctx),
+            }),
+            Statement::For(s) => Stmt::For(ForStmt {
+                span: ctx.span(&s.base),
+                init: s.init.swcify(ctx),
+                test: s.test.swcify(ctx),
+                update: s.update.swcify(ctx),
+                body: s.body.swcify(ctx),
+            }),
+            Statement::ForOf(s) => Stmt::ForOf(ForOfStmt {
+                span: ctx.span(&s.base),
+                await_token: None,
+                left: s.left.swcify(ctx),
+                right: s.right.swcify(ctx),
+                body: s.body.swcify(ctx),
+            }),
+            Statement::If(s) => Stmt::If(IfStmt {
+                span: ctx.span(&s.base),
+                test: s.test.swcify(ctx),
+                cons: s.consequent.swcify(ctx),
+                alt: s.alternate.swcify(ctx),
+            }),
+            Statement::Labeled(s) => Stmt::Labeled(LabeledStmt {
+                span: ctx.span(&s.base),
+                label: s.label.swcify(ctx).id,
+                body: s.body.swcify(ctx),
+            }),
+            Statement::Return(s) => Stmt::Return(ReturnStmt {
+                span: ctx.span(&s.base),
+                arg: s.argument.swcify(ctx),
+            }),
+            Statement::Switch(s) => Stmt::Switch(SwitchStmt {
+                span: ctx.span(&s.base),
+                discriminant: s.discriminant.swcify(ctx),
+                cases: s.cases.swcify(ctx),
+            }),
+            Statement::Throw(s) => Stmt::Throw(ThrowStmt {
+                span: ctx.span(&s.base),
+                arg: s.argument.swcify(ctx),
+            }),
+            Statement::Try(s) => Stmt::Try(TryStmt {
+                span: ctx.span(&s.base),
+                block: s.block.swcify(ctx),
+                handler: s.handler.swcify(ctx),
+                finalizer: s.finalizer.swcify(ctx),
+            }),
+            Statement::While(s) => Stmt::While(WhileStmt {
+                span: ctx.span(&s.base),
+                test: s.test.swcify(ctx),
+                body: s.body.swcify(ctx),
+            }),
+            Statement::With(s) => Stmt::With(WithStmt {
+                span: ctx.span(&s.base),
+                obj: s.object.swcify(ctx),
+                body: s.body.swcify(ctx),
+            }),
+            Statement::FuncDecl(d) => Stmt::Decl(Decl::Fn(d.swcify(ctx))),
+            Statement::VarDecl(d) => Stmt::Decl(Decl::Var(d.swcify(ctx))),
+            Statement::ClassDecl(d) => Stmt::Decl(Decl::Class(d.swcify(ctx))),
+            Statement::TSDeclFunc(d) => Stmt::Decl(Decl::Fn(d.swcify(ctx))),
+            Statement::TSInterfaceDecl(d) => Stmt::Decl(Decl::TsInterface(d.swcify(ctx))),
+            Statement::TSTypeAliasDecl(d) => Stmt::Decl(Decl::TsTypeAlias(d.swcify(ctx))),
+            Statement::TSEnumDecl(d) => Stmt::Decl(Decl::TsEnum(d.swcify(ctx))),
+            Statement::TSModuleDecl(d) => Stmt::Decl(Decl::TsModule(d.swcify(ctx))),
+            _ => panic!("illegal conversion: Cannot convert {:?} to Stmt", &self),
+        }
+    }
+}
+
+impl Swcify for ForStmtInit {
+    type Output = VarDeclOrExpr;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        match self {
+            ForStmtInit::VarDecl(v) => VarDeclOrExpr::VarDecl(v.swcify(ctx)),
+            ForStmtInit::Expr(e) => VarDeclOrExpr::Expr(e.swcify(ctx)),
+        }
+    }
+}
+
+impl Swcify for ForStmtLeft {
+    type Output = VarDeclOrPat;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        match self {
+            ForStmtLeft::VarDecl(v) => VarDeclOrPat::VarDecl(v.swcify(ctx)),
+            ForStmtLeft::LVal(l) => VarDeclOrPat::Pat(l.swcify(ctx)),
+        }
+    }
+}
+
+impl Swcify for BabelSwitchCase {
+    type Output = SwitchCase;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        SwitchCase {
+            span: ctx.span(&self.base),
+            test: self.test.swcify(ctx),
+            cons: self.consequent.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for BabelCatchClause {
+    type Output = CatchClause;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        CatchClause {
+            span: ctx.span(&self.base),
+            param: self.param.swcify(ctx),
+            body: self.body.swcify(ctx),
+        }
+    }
+}
+
+/// swc does not have directives, and `"use strict"` is an expression
+/// statement.
+impl Swcify for Directive {
+    type Output = Stmt;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        Stmt::Expr(ExprStmt {
+            span: ctx.span(&self.base),
+            expr: Box::new(Expr::Lit(Lit::Str(self.value.swcify(ctx)))),
+        })
+    }
+}
+
+impl Swcify for DirectiveLiteral {
+    type Output = Str;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        Str {
+            span: ctx.span(&self.base),
+            has_escape: self.value.contains('\\'),
+            value: self.value,
+            kind: StrKind::Normal {
+                contains_quote: true,
+            },
+        }
+    }
+}
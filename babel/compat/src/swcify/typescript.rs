@@ -0,0 +1,752 @@
+use super::{
+    function::{swcify_decorators, swcify_params},
+    module_decl::swcify_module_item,
+    Swcify,
+};
+use crate::Context;
+use swc_babel_ast::{
+    BaseNode, IdOrRest, IdOrString, TSCallSignatureDeclaration, TSConstructSignatureDeclaration,
+    TSConstructorType, TSDeclareFunction, TSEntityName, TSEnumDeclaration, TSEnumMember,
+    TSExportAssignment, TSExpressionWithTypeArguments, TSFuncDeclTypeAnnot, TSFuncDeclTypeParams,
+    TSFunctionType, TSImportEqualsDeclModuleRef, TSImportEqualsDeclaration, TSImportType,
+    TSIndexSignature, TSInterfaceBody, TSInterfaceDeclaration, TSLiteralType, TSLiteralTypeLiteral,
+    TSMappedType, TSMethodSignature, TSModuleBlock, TSModuleDeclBody, TSModuleDeclaration,
+    TSNamespaceExportDeclaration, TSParamPropParam, TSParameterProperty, TSPropertySignature,
+    TSQualifiedName, TSTupleTypeElType, TSType, TSTypeAliasDeclaration, TSTypeAnnotation,
+    TSTypeElement, TSTypeParameter, TSTypeParameterDeclaration, TSTypeParameterInstantiation,
+    TSTypePredicate, TSTypePredicateParamName, TSTypeQueryExprName, TSTypeReference,
+};
+use swc_common::Spanned;
+use swc_ecma_ast::{
+    FnDecl, Function, Ident, Pat, TruePlusMinus, TsArrayType, TsCallSignatureDecl,
+    TsConditionalType, TsConstructSignatureDecl, TsConstructorType, TsEntityName, TsEnumDecl,
+    TsEnumMember, TsEnumMemberId, TsExportAssignment, TsExprWithTypeArgs, TsExternalModuleRef,
+    TsFnOrConstructorType, TsFnParam, TsFnType, TsImportEqualsDecl, TsImportType, TsIndexSignature,
+    TsIndexedAccessType, TsInferType, TsInterfaceBody, TsInterfaceDecl, TsIntersectionType,
+    TsKeywordType, TsKeywordTypeKind, TsLit, TsLitType, TsMappedType, TsMethodSignature,
+    TsModuleBlock, TsModuleDecl, TsModuleName, TsModuleRef, TsNamespaceBody, TsNamespaceDecl,
+    TsNamespaceExportDecl, TsOptionalType, TsParamProp, TsParamPropParam, TsParenthesizedType,
+    TsPropertySignature, TsQualifiedName, TsRestType, TsThisType, TsThisTypeOrIdent,
+    TsTupleElement, TsTupleType, TsType, TsTypeAliasDecl, TsTypeAnn, TsTypeElement, TsTypeLit,
+    TsTypeOperator, TsTypeOperatorOp, TsTypeParam, TsTypeParamDecl, TsTypeParamInstantiation,
+    TsTypePredicate, TsTypeQuery, TsTypeQueryExpr, TsTypeRef, TsUnionOrIntersectionType,
+    TsUnionType,
+};
+
+impl Swcify for TSTypeAnnotation {
+    type Output = TsTypeAnn;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsTypeAnn {
+            span: ctx.span(&self.base),
+            type_ann: Box::new(self.type_annotation.swcify(ctx)),
+        }
+    }
+}
+
+/// Babel allows omitting the type annotation of function types, but swc does
+/// not.
+fn required_type_ann(ann: Option<Box<TSTypeAnnotation>>, ctx: &Context) -> TsTypeAnn {
+    match ann {
+        Some(ann) => (*ann).swcify(ctx),
+        None => panic!("illegal conversion: Cannot convert None to TsTypeAnn"),
+    }
+}
+
+impl Swcify for TSTypeParameterDeclaration {
+    type Output = TsTypeParamDecl;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsTypeParamDecl {
+            span: ctx.span(&self.base),
+            params: self.params.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for TSTypeParameter {
+    type Output = TsTypeParam;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        let span = ctx.span(&self.base);
+
+        TsTypeParam {
+            span,
+            name: Ident::new(self.name, span),
+            constraint: self.constraint.swcify(ctx),
+            default: self.default.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for TSTypeParameterInstantiation {
+    type Output = TsTypeParamInstantiation;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsTypeParamInstantiation {
+            span: ctx.span(&self.base),
+            params: self
+                .params
+                .into_iter()
+                .map(|t| Box::new(t.swcify(ctx)))
+                .collect(),
+        }
+    }
+}
+
+impl Swcify for TSFuncDeclTypeParams {
+    type Output = Option<TsTypeParamDecl>;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        match self {
+            TSFuncDeclTypeParams::Type(t) => Some(t.swcify(ctx)),
+            TSFuncDeclTypeParams::Noop(_) => None,
+        }
+    }
+}
+
+impl Swcify for TSFuncDeclTypeAnnot {
+    type Output = Option<TsTypeAnn>;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        match self {
+            TSFuncDeclTypeAnnot::Type(ann) => Some((*ann).swcify(ctx)),
+            TSFuncDeclTypeAnnot::Noop(_) => None,
+        }
+    }
+}
+
+impl Swcify for TSParameterProperty {
+    type Output = TsParamProp;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        let (decorators, param) = match self.parameter {
+            TSParamPropParam::Id(mut i) => {
+                (i.decorators.take(), TsParamPropParam::Ident(i.swcify(ctx)))
+            }
+            TSParamPropParam::Assignment(mut a) => {
+                (a.decorators.take(), TsParamPropParam::Assign(a.swcify(ctx)))
+            }
+        };
+
+        TsParamProp {
+            span: ctx.span(&self.base),
+            decorators: swcify_decorators(decorators, ctx),
+            accessibility: self.accessibility.swcify(ctx),
+            is_override: false,
+            readonly: self.readonly.unwrap_or(false),
+            param,
+        }
+    }
+}
+
+impl Swcify for TSDeclareFunction {
+    type Output = FnDecl;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        let ident = match self.id.clone() {
+            Some(id) => id.swcify(ctx).id,
+            None => panic!(
+                "illegal conversion: Cannot convert {:?} without id to FnDecl",
+                &self
+            ),
+        };
+
+        FnDecl {
+            ident,
+            declare: self.declare.unwrap_or(false),
+            function: Function {
+                params: swcify_params(self.params, ctx),
+                decorators: Default::default(),
+                span: ctx.span(&self.base),
+                body: None,
+                is_generator: self.generator.unwrap_or(false),
+                is_async: self.is_async.unwrap_or(false),
+                type_params: self.type_parameters.and_then(|t| t.swcify(ctx)),
+                return_type: self.return_type.and_then(|t| t.swcify(ctx)),
+            },
+        }
+    }
+}
+
+impl Swcify for TSQualifiedName {
+    type Output = TsQualifiedName;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsQualifiedName {
+            left: (*self.left).swcify(ctx),
+            right: self.right.swcify(ctx).id,
+        }
+    }
+}
+
+impl Swcify for TSEntityName {
+    type Output = TsEntityName;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        match self {
+            TSEntityName::Id(i) => TsEntityName::Ident(i.swcify(ctx).id),
+            TSEntityName::Qualified(n) => TsEntityName::TsQualifiedName(Box::new(n.swcify(ctx))),
+        }
+    }
+}
+
+impl Swcify for IdOrRest {
+    type Output = TsFnParam;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        match self {
+            IdOrRest::Id(i) => TsFnParam::Ident(i.swcify(ctx)),
+            IdOrRest::Rest(r) => TsFnParam::Rest(r.swcify(ctx)),
+        }
+    }
+}
+
+impl Swcify for TSTypeElement {
+    type Output = TsTypeElement;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        match self {
+            TSTypeElement::CallSignatureDecl(t) => {
+                TsTypeElement::TsCallSignatureDecl(t.swcify(ctx))
+            }
+            TSTypeElement::ConstructSignatureDecl(t) => {
+                TsTypeElement::TsConstructSignatureDecl(t.swcify(ctx))
+            }
+            TSTypeElement::PropSignature(t) => TsTypeElement::TsPropertySignature(t.swcify(ctx)),
+            TSTypeElement::MethodSignature(t) => TsTypeElement::TsMethodSignature(t.swcify(ctx)),
+            TSTypeElement::IndexSignature(t) => TsTypeElement::TsIndexSignature(t.swcify(ctx)),
+        }
+    }
+}
+
+impl Swcify for TSCallSignatureDeclaration {
+    type Output = TsCallSignatureDecl;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsCallSignatureDecl {
+            span: ctx.span(&self.base),
+            params: self.parameters.swcify(ctx),
+            type_ann: self.type_annotation.map(|ann| (*ann).swcify(ctx)),
+            type_params: self.type_parameters.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for TSConstructSignatureDeclaration {
+    type Output = TsConstructSignatureDecl;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsConstructSignatureDecl {
+            span: ctx.span(&self.base),
+            params: self.parameters.swcify(ctx),
+            type_ann: self.type_annotation.map(|ann| (*ann).swcify(ctx)),
+            type_params: self.type_parameters.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for TSPropertySignature {
+    type Output = TsPropertySignature;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsPropertySignature {
+            span: ctx.span(&self.base),
+            readonly: self.readonly.unwrap_or(false),
+            key: self.key.swcify(ctx),
+            computed: self.computed.unwrap_or(false),
+            optional: self.optional.unwrap_or(false),
+            init: self.initializer.swcify(ctx),
+            params: Default::default(),
+            type_ann: self.type_annotation.map(|ann| (*ann).swcify(ctx)),
+            type_params: None,
+        }
+    }
+}
+
+impl Swcify for TSMethodSignature {
+    type Output = TsMethodSignature;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsMethodSignature {
+            span: ctx.span(&self.base),
+            readonly: false,
+            key: self.key.swcify(ctx),
+            computed: self.computed.unwrap_or(false),
+            optional: self.optional.unwrap_or(false),
+            params: self.parameters.swcify(ctx),
+            type_ann: self.type_annotation.map(|ann| (*ann).swcify(ctx)),
+            type_params: self.type_parameters.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for TSIndexSignature {
+    type Output = TsIndexSignature;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsIndexSignature {
+            params: self
+                .paramters
+                .into_iter()
+                .map(|i| TsFnParam::Ident(i.swcify(ctx)))
+                .collect(),
+            type_ann: self.type_annotation.map(|ann| (*ann).swcify(ctx)),
+            readonly: self.readonly.unwrap_or(false),
+            is_static: false,
+            span: ctx.span(&self.base),
+        }
+    }
+}
+
+fn keyword(base: &BaseNode, kind: TsKeywordTypeKind, ctx: &Context) -> TsType {
+    TsType::TsKeywordType(TsKeywordType {
+        span: ctx.span(base),
+        kind,
+    })
+}
+
+impl Swcify for TSType {
+    type Output = TsType;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        match self {
+            TSType::AnyKeyword(k) => keyword(&k.base, TsKeywordTypeKind::TsAnyKeyword, ctx),
+            TSType::BooleanKeyword(k) => keyword(&k.base, TsKeywordTypeKind::TsBooleanKeyword, ctx),
+            TSType::BigIntKeyword(k) => keyword(&k.base, TsKeywordTypeKind::TsBigIntKeyword, ctx),
+            TSType::IntrinsicKeyword(k) => {
+                keyword(&k.base, TsKeywordTypeKind::TsIntrinsicKeyword, ctx)
+            }
+            TSType::NeverKeyword(k) => keyword(&k.base, TsKeywordTypeKind::TsNeverKeyword, ctx),
+            TSType::NullKeyword(k) => keyword(&k.base, TsKeywordTypeKind::TsNullKeyword, ctx),
+            TSType::NumberKeyword(k) => keyword(&k.base, TsKeywordTypeKind::TsNumberKeyword, ctx),
+            TSType::ObjectKeyword(k) => keyword(&k.base, TsKeywordTypeKind::TsObjectKeyword, ctx),
+            TSType::StringKeyword(k) => keyword(&k.base, TsKeywordTypeKind::TsStringKeyword, ctx),
+            TSType::SymbolKeyword(k) => keyword(&k.base, TsKeywordTypeKind::TsSymbolKeyword, ctx),
+            TSType::UndefinedKeyword(k) => {
+                keyword(&k.base, TsKeywordTypeKind::TsUndefinedKeyword, ctx)
+            }
+            TSType::UnknownKeyword(k) => keyword(&k.base, TsKeywordTypeKind::TsUnknownKeyword, ctx),
+            TSType::VoidKeyword(k) => keyword(&k.base, TsKeywordTypeKind::TsVoidKeyword, ctx),
+            TSType::This(t) => TsType::TsThisType(TsThisType {
+                span: ctx.span(&t.base),
+            }),
+            TSType::Function(f) => {
+                TsType::TsFnOrConstructorType(TsFnOrConstructorType::TsFnType(f.swcify(ctx)))
+            }
+            TSType::Constructor(c) => TsType::TsFnOrConstructorType(
+                TsFnOrConstructorType::TsConstructorType(c.swcify(ctx)),
+            ),
+            TSType::TypeRef(r) => TsType::TsTypeRef(r.swcify(ctx)),
+            TSType::TypePredicate(p) => TsType::TsTypePredicate(p.swcify(ctx)),
+            TSType::TypeQuery(q) => TsType::TsTypeQuery(TsTypeQuery {
+                span: ctx.span(&q.base),
+                expr_name: q.expr_name.swcify(ctx),
+            }),
+            TSType::TypeLiteral(l) => TsType::TsTypeLit(TsTypeLit {
+                span: ctx.span(&l.base),
+                members: l.members.swcify(ctx),
+            }),
+            TSType::Array(a) => TsType::TsArrayType(TsArrayType {
+                span: ctx.span(&a.base),
+                elem_type: a.element_type.swcify(ctx),
+            }),
+            TSType::Tuple(t) => TsType::TsTupleType(TsTupleType {
+                span: ctx.span(&t.base),
+                elem_types: t.element_types.swcify(ctx),
+            }),
+            TSType::Optional(o) => TsType::TsOptionalType(TsOptionalType {
+                span: ctx.span(&o.base),
+                type_ann: o.type_annotation.swcify(ctx),
+            }),
+            TSType::Rest(r) => TsType::TsRestType(TsRestType {
+                span: ctx.span(&r.base),
+                type_ann: r.type_annotation.swcify(ctx),
+            }),
+            TSType::Union(u) => TsType::TsUnionOrIntersectionType(
+                TsUnionOrIntersectionType::TsUnionType(TsUnionType {
+                    span: ctx.span(&u.base),
+                    types: u
+                        .types
+                        .into_iter()
+                        .map(|t| Box::new(t.swcify(ctx)))
+                        .collect(),
+                }),
+            ),
+            TSType::Intersection(i) => TsType::TsUnionOrIntersectionType(
+                TsUnionOrIntersectionType::TsIntersectionType(TsIntersectionType {
+                    span: ctx.span(&i.base),
+                    types: i
+                        .types
+                        .into_iter()
+                        .map(|t| Box::new(t.swcify(ctx)))
+                        .collect(),
+                }),
+            ),
+            TSType::Conditional(c) => TsType::TsConditionalType(TsConditionalType {
+                span: ctx.span(&c.base),
+                check_type: c.check_type.swcify(ctx),
+                extends_type: c.extends_type.swcify(ctx),
+                true_type: c.true_type.swcify(ctx),
+                false_type: c.false_type.swcify(ctx),
+            }),
+            TSType::Infer(i) => TsType::TsInferType(TsInferType {
+                span: ctx.span(&i.base),
+                type_param: (*i.type_parameter).swcify(ctx),
+            }),
+            TSType::Parenthesized(p) => TsType::TsParenthesizedType(TsParenthesizedType {
+                span: ctx.span(&p.base),
+                type_ann: p.type_annotation.swcify(ctx),
+            }),
+            TSType::TypeOp(o) => TsType::TsTypeOperator(TsTypeOperator {
+                span: ctx.span(&o.base),
+                op: match &*o.operator {
+                    "keyof" => TsTypeOperatorOp::KeyOf,
+                    "unique" => TsTypeOperatorOp::Unique,
+                    "readonly" => TsTypeOperatorOp::ReadOnly,
+                    _ => panic!(
+                        "illegal conversion: Cannot convert {:?} to TsTypeOperatorOp",
+                        &o.operator
+                    ),
+                },
+                type_ann: o.type_annotation.swcify(ctx),
+            }),
+            TSType::IndexedAccess(a) => TsType::TsIndexedAccessType(TsIndexedAccessType {
+                span: ctx.span(&a.base),
+                readonly: false,
+                obj_type: a.object_type.swcify(ctx),
+                index_type: a.index_type.swcify(ctx),
+            }),
+            TSType::Mapped(m) => TsType::TsMappedType(m.swcify(ctx)),
+            TSType::Literal(l) => TsType::TsLitType(l.swcify(ctx)),
+            TSType::Import(i) => TsType::TsImportType(i.swcify(ctx)),
+            TSType::ExprWithArgs(_) => {
+                panic!("illegal conversion: Cannot convert {:?} to TsType", &self)
+            }
+        }
+    }
+}
+
+impl Swcify for TSFunctionType {
+    type Output = TsFnType;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsFnType {
+            span: ctx.span(&self.base),
+            params: self.parameters.swcify(ctx),
+            type_params: self.type_parameters.swcify(ctx),
+            type_ann: required_type_ann(self.type_annotation, ctx),
+        }
+    }
+}
+
+impl Swcify for TSConstructorType {
+    type Output = TsConstructorType;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsConstructorType {
+            span: ctx.span(&self.base),
+            params: self.parameters.swcify(ctx),
+            type_params: self.type_parameters.swcify(ctx),
+            type_ann: required_type_ann(self.type_annotation, ctx),
+            is_abstract: self.is_abstract.unwrap_or(false),
+        }
+    }
+}
+
+impl Swcify for TSTypeReference {
+    type Output = TsTypeRef;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsTypeRef {
+            span: ctx.span(&self.base),
+            type_name: self.type_name.swcify(ctx),
+            type_params: self.type_parameters.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for TSTypePredicate {
+    type Output = TsTypePredicate;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsTypePredicate {
+            span: ctx.span(&self.base),
+            asserts: self.asserts.unwrap_or(false),
+            param_name: match self.parameter_name {
+                TSTypePredicateParamName::Id(i) => TsThisTypeOrIdent::Ident(i.swcify(ctx).id),
+                TSTypePredicateParamName::This(t) => TsThisTypeOrIdent::TsThisType(TsThisType {
+                    span: ctx.span(&t.base),
+                }),
+            },
+            type_ann: self.type_annotation.map(|ann| (*ann).swcify(ctx)),
+        }
+    }
+}
+
+impl Swcify for TSTypeQueryExprName {
+    type Output = TsTypeQueryExpr;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        match self {
+            TSTypeQueryExprName::EntityName(n) => TsTypeQueryExpr::TsEntityName(n.swcify(ctx)),
+            TSTypeQueryExprName::ImportType(i) => TsTypeQueryExpr::Import(i.swcify(ctx)),
+        }
+    }
+}
+
+impl Swcify for TSImportType {
+    type Output = TsImportType;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsImportType {
+            span: ctx.span(&self.base),
+            arg: self.argument.swcify(ctx),
+            qualifier: self.qualifier.swcify(ctx),
+            type_args: self.type_parameters.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for TSTupleTypeElType {
+    type Output = TsTupleElement;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        match self {
+            TSTupleTypeElType::TSType(t) => {
+                let ty = t.swcify(ctx);
+                TsTupleElement {
+                    span: ty.span(),
+                    label: None,
+                    ty,
+                }
+            }
+            TSTupleTypeElType::Member(m) => TsTupleElement {
+                span: ctx.span(&m.base),
+                label: Some(Pat::Ident(m.label.swcify(ctx))),
+                ty: m.element_type.swcify(ctx),
+            },
+        }
+    }
+}
+
+/// `babelify` converts both of `+` and `-` to `false`, so `false` is
+/// converted to `-`, which is the common one.
+fn true_plus_minus(v: bool) -> TruePlusMinus {
+    if v {
+        TruePlusMinus::True
+    } else {
+        TruePlusMinus::Minus
+    }
+}
+
+impl Swcify for TSMappedType {
+    type Output = TsMappedType;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsMappedType {
+            span: ctx.span(&self.base),
+            readonly: self.readonly.map(true_plus_minus),
+            type_param: (*self.type_parameter).swcify(ctx),
+            name_type: self.name_type.swcify(ctx),
+            optional: self.optional.map(true_plus_minus),
+            type_ann: self.type_annotation.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for TSLiteralType {
+    type Output = TsLitType;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsLitType {
+            span: ctx.span(&self.base),
+            lit: match self.literal {
+                TSLiteralTypeLiteral::Numeric(n) => TsLit::Number(n.swcify(ctx)),
+                TSLiteralTypeLiteral::String(s) => TsLit::Str(s.swcify(ctx)),
+                TSLiteralTypeLiteral::Boolean(b) => TsLit::Bool(b.swcify(ctx)),
+                TSLiteralTypeLiteral::BigInt(i) => TsLit::BigInt(i.swcify(ctx)),
+            },
+        }
+    }
+}
+
+impl Swcify for TSInterfaceDeclaration {
+    type Output = TsInterfaceDecl;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsInterfaceDecl {
+            span: ctx.span(&self.base),
+            id: self.id.swcify(ctx).id,
+            declare: self.declare.unwrap_or(false),
+            type_params: self.type_parameters.swcify(ctx),
+            extends: self.extends.into_iter().map(|e| e.swcify(ctx)).collect(),
+            body: self.body.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for TSInterfaceBody {
+    type Output = TsInterfaceBody;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsInterfaceBody {
+            span: ctx.span(&self.base),
+            body: self.body.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for TSExpressionWithTypeArguments {
+    type Output = TsExprWithTypeArgs;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsExprWithTypeArgs {
+            span: ctx.span(&self.base),
+            expr: self.expression.swcify(ctx),
+            type_args: self.type_parameters.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for TSTypeAliasDeclaration {
+    type Output = TsTypeAliasDecl;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsTypeAliasDecl {
+            span: ctx.span(&self.base),
+            declare: self.declare.unwrap_or(false),
+            id: self.id.swcify(ctx).id,
+            type_params: self.type_parameters.swcify(ctx),
+            type_ann: Box::new(self.type_annotation.swcify(ctx)),
+        }
+    }
+}
+
+impl Swcify for TSEnumDeclaration {
+    type Output = TsEnumDecl;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsEnumDecl {
+            span: ctx.span(&self.base),
+            declare: self.declare.unwrap_or(false),
+            is_const: self.is_const.unwrap_or(false),
+            id: self.id.swcify(ctx).id,
+            members: self.members.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for TSEnumMember {
+    type Output = TsEnumMember;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsEnumMember {
+            span: ctx.span(&self.base),
+            id: match self.id {
+                IdOrString::Id(i) => TsEnumMemberId::Ident(i.swcify(ctx).id),
+                IdOrString::String(s) => TsEnumMemberId::Str(s.swcify(ctx)),
+            },
+            init: self.initializer.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for TSModuleDeclaration {
+    type Output = TsModuleDecl;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsModuleDecl {
+            span: ctx.span(&self.base),
+            declare: self.declare.unwrap_or(false),
+            global: self.global.unwrap_or(false),
+            id: match self.id {
+                IdOrString::Id(i) => TsModuleName::Ident(i.swcify(ctx).id),
+                IdOrString::String(s) => TsModuleName::Str(s.swcify(ctx)),
+            },
+            body: Some((*self.body).swcify(ctx)),
+        }
+    }
+}
+
+impl Swcify for TSModuleDeclBody {
+    type Output = TsNamespaceBody;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        match self {
+            TSModuleDeclBody::Block(b) => TsNamespaceBody::TsModuleBlock(b.swcify(ctx)),
+            TSModuleDeclBody::Decl(d) => TsNamespaceBody::TsNamespaceDecl(TsNamespaceDecl {
+                span: ctx.span(&d.base),
+                declare: d.declare.unwrap_or(false),
+                global: d.global.unwrap_or(false),
+                id: match d.id {
+                    IdOrString::Id(i) => i.swcify(ctx).id,
+                    IdOrString::String(_) => {
+                        panic!("illegal conversion: Cannot convert {:?} to Ident", &d.id)
+                    }
+                },
+                body: d.body.swcify(ctx),
+            }),
+        }
+    }
+}
+
+impl Swcify for TSModuleBlock {
+    type Output = TsModuleBlock;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsModuleBlock {
+            span: ctx.span(&self.base),
+            body: self
+                .body
+                .into_iter()
+                .map(|stmt| swcify_module_item(stmt, ctx))
+                .collect(),
+        }
+    }
+}
+
+impl Swcify for TSImportEqualsDeclaration {
+    type Output = TsImportEqualsDecl;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsImportEqualsDecl {
+            span: ctx.span(&self.base),
+            declare: false,
+            is_export: self.is_export,
+            is_type_only: false,
+            id: self.id.swcify(ctx).id,
+            module_ref: match self.module_reference {
+                TSImportEqualsDeclModuleRef::Name(n) => TsModuleRef::TsEntityName(n.swcify(ctx)),
+                TSImportEqualsDeclModuleRef::External(e) => {
+                    TsModuleRef::TsExternalModuleRef(TsExternalModuleRef {
+                        span: ctx.span(&e.base),
+                        expr: e.expression.swcify(ctx),
+                    })
+                }
+            },
+        }
+    }
+}
+
+impl Swcify for TSExportAssignment {
+    type Output = TsExportAssignment;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsExportAssignment {
+            span: ctx.span(&self.base),
+            expr: self.expression.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for TSNamespaceExportDeclaration {
+    type Output = TsNamespaceExportDecl;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsNamespaceExportDecl {
+            span: ctx.span(&self.base),
+            id: self.id.swcify(ctx).id,
+        }
+    }
+}
@@ -0,0 +1,151 @@
+#![feature(test)]
+extern crate test;
+
+use anyhow::{Context as AnyhowContext, Error};
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+use swc::{Compiler, SwcComments};
+use swc_babel_compat::{Babelify, Context, Swcify};
+use swc_common::{
+    comments::CommentKind,
+    errors::{ColorConfig, Handler},
+    FileName, FilePathMapping, SourceMap,
+};
+use swc_ecma_parser::{EsConfig, Syntax};
+use swc_ecma_visit::assert_eq_ignore_span;
+use test::{test_main, DynTestFn, ShouldPanic, TestDesc, TestDescAndFn, TestName, TestType};
+use walkdir::WalkDir;
+
+/// Asserts `swcify(babelify(x)) == x` for the fixtures of `convert.rs`.
+#[test]
+fn fixtures() -> Result<(), Error> {
+    let mut tests = vec![];
+
+    let fixtures_path = PathBuf::from("tests").join("fixtures");
+    for entry in WalkDir::new(&fixtures_path).into_iter() {
+        let entry = entry.with_context(|| "Failed to walk dir")?;
+        if !entry.file_type().is_dir() {
+            continue;
+        }
+
+        let js_path: PathBuf = entry.path().join("input.js");
+        let ts_path: PathBuf = entry.path().join("input.ts");
+        let mjs_path: PathBuf = entry.path().join("input.mjs");
+        let jsx_path: PathBuf = entry.path().join("input.jsx");
+        let output_path: PathBuf = entry.path().join("output.json");
+
+        let is_javascript = js_path.is_file();
+        let is_typescript = ts_path.is_file();
+        let is_module = mjs_path.is_file();
+        let is_jsx = jsx_path.is_file();
+
+        if (!is_javascript && !is_typescript && !is_module && !is_jsx) || !output_path.is_file() {
+            continue;
+        }
+
+        let input_path = if is_typescript {
+            &ts_path
+        } else if is_module {
+            &mjs_path
+        } else if is_jsx {
+            &jsx_path
+        } else {
+            &js_path
+        };
+        let input = fs::read_to_string(input_path)
+            .with_context(|| format!("Failed to open file: {}", &input_path.to_string_lossy()))?;
+
+        tests.push(TestDescAndFn {
+            desc: TestDesc {
+                test_type: TestType::IntegrationTest,
+                name: TestName::DynTestName(format!(
+                    "babel_compat::swcify::{}",
+                    get_test_name(entry.path(), &fixtures_path)?
+                )),
+                ignore: false,
+                should_panic: ShouldPanic::No,
+                allow_fail: false,
+            },
+            testfn: DynTestFn(Box::new(move || {
+                let syntax = if is_typescript {
+                    Syntax::Typescript(Default::default())
+                } else if is_jsx {
+                    Syntax::Es(EsConfig {
+                        jsx: true,
+                        ..Default::default()
+                    })
+                } else {
+                    Syntax::default()
+                };
+                run_test(input, syntax, is_module);
+            })),
+        })
+    }
+
+    test_main(
+        &env::args().collect::<Vec<_>>(),
+        tests,
+        Some(test::Options::new()),
+    );
+
+    Ok(())
+}
+
+fn run_test(src: String, syntax: Syntax, is_module: bool) {
+    let cm = Arc::new(SourceMap::new(FilePathMapping::empty()));
+    let handler = Arc::new(Handler::with_tty_emitter(
+        ColorConfig::Always,
+        true,
+        false,
+        Some(cm.clone()),
+    ));
+    let compiler = Compiler::new(cm.clone(), handler);
+    let fm = compiler.cm.new_source_file(FileName::Anon, src);
+
+    let expected = compiler
+        .parse_js(fm.clone(), Default::default(), syntax, is_module, true)
+        .unwrap();
+    // `babelify` takes comments from the map.
+    let expected_comments = all_comments(compiler.comments());
+
+    let babelify_ctx = Context {
+        fm: fm.clone(),
+        cm: cm.clone(),
+        comments: compiler.comments().clone(),
+    };
+    let babel_ast = expected.clone().babelify(&babelify_ctx);
+
+    let swcify_ctx = Context {
+        fm,
+        cm,
+        comments: SwcComments::default(),
+    };
+    let actual = babel_ast.swcify(&swcify_ctx);
+
+    assert_eq_ignore_span!(expected, actual);
+    assert_eq!(expected_comments, all_comments(&swcify_ctx.comments));
+}
+
+/// Returns comments in the order of the source, without their spans.
+///
+/// Positions of comments are not compared, as babel attaches a comment to
+/// nodes differently.
+fn all_comments(comments: &SwcComments) -> Vec<(CommentKind, String)> {
+    let mut all = comments
+        .leading
+        .iter()
+        .chain(comments.trailing.iter())
+        .flat_map(|e| e.value().clone())
+        .collect::<Vec<_>>();
+    all.sort_by_key(|c| c.span.lo);
+
+    all.into_iter().map(|c| (c.kind, c.text)).collect()
+}
+
+fn get_test_name(path: &Path, fixture_path: &Path) -> Result<String, Error> {
+    let s: String = path.strip_prefix(fixture_path)?.to_string_lossy().into();
+    Ok(s)
+}
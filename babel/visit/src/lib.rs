@@ -1331,6 +1331,7 @@ define!({
     pub struct JSXText {
         pub base: BaseNode,
         pub value: JsWord,
+        pub extra: Option<LiteralExtra>,
     }
     pub struct JSXFragment {
         pub base: BaseNode,
@@ -1366,13 +1367,23 @@ define!({
         BigInt(BigIntLiteral),
         Decimal(DecimalLiteral),
     }
+    pub struct LiteralExtra {
+        pub raw: JsWord,
+        pub raw_value: Option<JsWord>,
+    }
+    pub struct NumericLiteralExtra {
+        pub raw: JsWord,
+        pub raw_value: Option<f64>,
+    }
     pub struct StringLiteral {
         pub base: BaseNode,
         pub value: JsWord,
+        pub extra: Option<LiteralExtra>,
     }
     pub struct NumericLiteral {
         pub base: BaseNode,
         pub value: f64,
+        pub extra: Option<NumericLiteralExtra>,
     }
     pub struct NumberLiteral {
         pub base: BaseNode,
@@ -1389,6 +1400,7 @@ define!({
         pub base: BaseNode,
         pub pattern: JsWord,
         pub flags: JsWord,
+        pub extra: Option<LiteralExtra>,
     }
     pub struct RegexLiteral {
         pub base: BaseNode,
@@ -1416,6 +1428,7 @@ define!({
     pub struct BigIntLiteral {
         pub base: BaseNode,
         pub value: String,
+        pub extra: Option<LiteralExtra>,
     }
     pub struct DecimalLiteral {
         pub base: BaseNode,
@@ -179,8 +179,8 @@ where
     }
 }
 
-struct TopLevelAwaitFinder {
-    found: bool,
+pub(super) struct TopLevelAwaitFinder {
+    pub found: bool,
 }
 
 impl Visit for TopLevelAwaitFinder {
@@ -30,6 +30,8 @@ pub(super) struct Ctx {
     /// Full dependency graph.
     pub graph: ModuleGraph,
     pub cycles: Vec<Vec<ModuleId>>,
+    /// Modules evaluated as async modules. See `determine_async_modules`.
+    pub async_modules: FxHashSet<ModuleId>,
     pub merged: CHashSet<ModuleId>,
     pub transitive_remap: CloneMap<SyntaxContext, SyntaxContext>,
     pub export_stars_in_wrapped: Lock<FxHashMap<ModuleId, Vec<SyntaxContext>>>,
@@ -72,6 +74,14 @@ where
             let mut module = self.apply_hooks(id, is_entry)?;
             module = self.prepare_for_merging(&ctx, &info, module)?;
 
+            if ctx.async_modules.contains(&id) {
+                if is_entry {
+                    self.await_deps_of_entry(ctx, id, &mut module);
+                } else {
+                    module = self.wrap_async_module(ctx, &info, module);
+                }
+            }
+
             if !is_entry {
                 module = self.wrap_cjs_module(ctx, &info, module)?;
             }
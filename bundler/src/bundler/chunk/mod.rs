@@ -15,6 +15,7 @@ mod cjs;
 mod computed_key;
 mod merge;
 mod plan;
+mod tla;
 
 #[derive(Debug)]
 struct InternalEntry {
@@ -66,9 +67,12 @@ where
             }
         }
 
+        let async_modules = self.determine_async_modules(&plan.all);
+
         let ctx = Ctx {
             graph,
             cycles,
+            async_modules,
             merged: Default::default(),
             transitive_remap: Default::default(),
             export_stars_in_wrapped: Default::default(),
@@ -0,0 +1,417 @@
+use super::{computed_key::TopLevelAwaitFinder, merge::Ctx};
+use crate::{
+    bundler::load::TransformedModule, modules::Modules, util::ExprExt, Bundler, Load, ModuleId,
+    Resolve,
+};
+use fxhash::FxHashSet;
+use indexmap::IndexSet;
+use swc_common::{SyntaxContext, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_utils::{find_ids, member_expr, ExprFactory};
+use swc_ecma_visit::VisitWith;
+
+impl<L, R> Bundler<'_, L, R>
+where
+    L: Load,
+    R: Resolve,
+{
+    /// Determines modules which should be evaluated as async modules.
+    ///
+    /// A module is async if it contains a top-level await or if one of its
+    /// dependencies is async. Common js modules and modules wrapped with a
+    /// function are evaluated in place, so they are not included.
+    pub(super) fn determine_async_modules(&self, all: &[ModuleId]) -> FxHashSet<ModuleId> {
+        let mut async_modules = FxHashSet::default();
+
+        if !self.config.top_level_await {
+            return async_modules;
+        }
+
+        let candidates = all
+            .iter()
+            .copied()
+            .filter(|&id| {
+                let info = self.scope.get_module(id).unwrap();
+
+                info.is_es6 && !self.scope.should_be_wrapped_with_a_fn(id)
+            })
+            .collect::<Vec<_>>();
+
+        for &id in &candidates {
+            let info = self.scope.get_module(id).unwrap();
+
+            let mut v = TopLevelAwaitFinder { found: false };
+            info.module
+                .visit_with(&Invalid { span: DUMMY_SP } as _, &mut v);
+            if v.found {
+                async_modules.insert(id);
+            }
+        }
+
+        // Dependants of an async module should wait for it.
+        loop {
+            let mut changed = false;
+
+            for &id in &candidates {
+                if async_modules.contains(&id) {
+                    continue;
+                }
+
+                let info = self.scope.get_module(id).unwrap();
+                let has_async_dep = info
+                    .imports
+                    .specifiers
+                    .iter()
+                    .chain(info.exports.reexports.iter())
+                    .filter(|(src, _)| src.is_loaded_synchronously)
+                    .any(|(src, _)| async_modules.contains(&src.module_id));
+
+                if has_async_dep {
+                    async_modules.insert(id);
+                    changed = true;
+                }
+            }
+
+            if !changed {
+                break;
+            }
+        }
+
+        async_modules
+    }
+
+    /// Identifier of the promise which resolves when the async module is
+    /// evaluated.
+    fn tla_ident(&self, id: ModuleId) -> Ident {
+        let info = self.scope.get_module(id).unwrap();
+
+        Ident::new("__tla".into(), DUMMY_SP.with_ctxt(info.export_ctxt()))
+    }
+
+    /// Creates
+    ///
+    /// ```js
+    /// await Promise.all([__tla, __tla1]);
+    /// ```
+    ///
+    /// for direct async dependencies of the module. Dependencies in the same
+    /// cycle are not awaited, as it would be a deadlock.
+    fn await_async_deps(&self, ctx: &Ctx, id: ModuleId) -> Option<Stmt> {
+        let info = self.scope.get_module(id).unwrap();
+
+        let deps = info
+            .imports
+            .specifiers
+            .iter()
+            .chain(info.exports.reexports.iter())
+            .filter(|(src, _)| src.is_loaded_synchronously)
+            .map(|(src, _)| src.module_id)
+            .filter(|dep| ctx.async_modules.contains(dep))
+            .filter(|dep| {
+                !ctx.cycles
+                    .iter()
+                    .any(|cycle| cycle.contains(&id) && cycle.contains(dep))
+            })
+            .collect::<IndexSet<_>>();
+
+        let deps = deps
+            .into_iter()
+            .map(|dep| Some(self.tla_ident(dep).as_arg()))
+            .collect::<Vec<_>>();
+
+        if deps.is_empty() {
+            return None;
+        }
+
+        Some(Stmt::Expr(ExprStmt {
+            span: DUMMY_SP,
+            expr: Box::new(Expr::Await(AwaitExpr {
+                span: DUMMY_SP,
+                arg: Box::new(Expr::Call(CallExpr {
+                    span: DUMMY_SP,
+                    callee: member_expr!(DUMMY_SP, Promise.all).as_callee(),
+                    args: vec![ArrayLit {
+                        span: DUMMY_SP,
+                        elems: deps,
+                    }
+                    .as_arg()],
+                    type_args: Default::default(),
+                })),
+            })),
+        }))
+    }
+
+    /// Makes an entry wait for its async dependencies.
+    pub(super) fn await_deps_of_entry(&self, ctx: &Ctx, id: ModuleId, module: &mut Modules) {
+        let stmt = match self.await_async_deps(ctx, id) {
+            Some(v) => v,
+            None => return,
+        };
+
+        let mut stmt = Some(stmt);
+        module.map_any_items(|_, mut items| {
+            if let Some(stmt) = stmt.take() {
+                items.insert(0, ModuleItem::Stmt(stmt));
+            }
+            items
+        });
+    }
+
+    /// Converts an async module to
+    ///
+    /// ```js
+    /// let foo;
+    /// const __tla = (async function () {
+    ///     await Promise.all([__tla1, __tla2]);
+    ///     foo = await bar();
+    /// })();
+    /// ```
+    ///
+    /// Declarations are hoisted out of the function so other modules can
+    /// access them. As the function is invoked while evaluating the module,
+    /// async dependencies of a module are evaluated concurrently, and the
+    /// module waits for all of them.
+    pub(super) fn wrap_async_module(
+        &self,
+        ctx: &Ctx,
+        info: &TransformedModule,
+        mut module: Modules,
+    ) -> Modules {
+        let injected_ctxt = self.injected_ctxt;
+
+        let mut body = vec![];
+        body.extend(self.await_async_deps(ctx, info.id));
+
+        module.map_any_items(|_, items| {
+            let mut new = Vec::with_capacity(items.len());
+
+            for item in items {
+                let stmt = match item {
+                    ModuleItem::Stmt(stmt) => stmt,
+                    ModuleItem::ModuleDecl(decl) => {
+                        new.push(ModuleItem::ModuleDecl(decl));
+                        continue;
+                    }
+                };
+
+                match stmt {
+                    // Function declarations are hoisted anyway.
+                    Stmt::Decl(Decl::Fn(..)) => new.push(ModuleItem::Stmt(stmt)),
+
+                    // Aliases are removed by the inliner.
+                    Stmt::Decl(Decl::Var(var)) if is_alias(&var, injected_ctxt) => {
+                        new.push(ModuleItem::Stmt(Stmt::Decl(Decl::Var(var))))
+                    }
+
+                    Stmt::Decl(Decl::Var(var)) => {
+                        let ids: Vec<Ident> = find_ids(&var);
+                        new.push(ModuleItem::Stmt(Stmt::Decl(Decl::Var(VarDecl {
+                            span: var.span,
+                            kind: match var.kind {
+                                VarDeclKind::Var => VarDeclKind::Var,
+                                _ => VarDeclKind::Let,
+                            },
+                            declare: false,
+                            decls: ids
+                                .into_iter()
+                                .map(|id| VarDeclarator {
+                                    span: DUMMY_SP,
+                                    name: Pat::Ident(id.into()),
+                                    init: None,
+                                    definite: false,
+                                })
+                                .collect(),
+                        }))));
+
+                        for decl in var.decls {
+                            if let Some(init) = decl.init {
+                                body.push(
+                                    AssignExpr {
+                                        span: decl.span,
+                                        left: PatOrExpr::Pat(Box::new(decl.name)),
+                                        op: op!("="),
+                                        right: init,
+                                    }
+                                    .into_stmt(),
+                                );
+                            }
+                        }
+                    }
+
+                    Stmt::Decl(Decl::Class(ClassDecl { ident, class, .. })) => {
+                        new.push(ModuleItem::Stmt(Stmt::Decl(Decl::Var(VarDecl {
+                            span: DUMMY_SP,
+                            kind: VarDeclKind::Let,
+                            declare: false,
+                            decls: vec![VarDeclarator {
+                                span: DUMMY_SP,
+                                name: Pat::Ident(ident.clone().into()),
+                                init: None,
+                                definite: false,
+                            }],
+                        }))));
+
+                        body.push(
+                            AssignExpr {
+                                span: DUMMY_SP,
+                                left: PatOrExpr::Pat(Box::new(Pat::Ident(ident.clone().into()))),
+                                op: op!("="),
+                                right: Box::new(Expr::Class(ClassExpr {
+                                    ident: Some(ident),
+                                    class,
+                                })),
+                            }
+                            .into_stmt(),
+                        );
+                    }
+
+                    _ => body.push(stmt),
+                }
+            }
+
+            new
+        });
+
+        let evaluation = CallExpr {
+            span: DUMMY_SP,
+            callee: FnExpr {
+                ident: None,
+                function: Function {
+                    span: DUMMY_SP,
+                    is_async: true,
+                    is_generator: false,
+                    decorators: Default::default(),
+                    params: Default::default(),
+                    body: Some(BlockStmt {
+                        span: DUMMY_SP,
+                        stmts: body,
+                    }),
+                    type_params: Default::default(),
+                    return_type: Default::default(),
+                },
+            }
+            .as_callee(),
+            args: Default::default(),
+            type_args: Default::default(),
+        };
+
+        let var = evaluation.assign_to(self.tla_ident(info.id));
+        module.append(
+            info.id,
+            ModuleItem::Stmt(Stmt::Decl(Decl::Var(VarDecl {
+                span: DUMMY_SP,
+                kind: VarDeclKind::Const,
+                declare: false,
+                decls: vec![var],
+            }))),
+        );
+
+        module
+    }
+}
+
+/// Returns true for `const local = exported` injected by the bundler.
+fn is_alias(var: &VarDecl, injected_ctxt: SyntaxContext) -> bool {
+    var.span.ctxt == injected_ctxt
+        && var.kind == VarDeclKind::Const
+        && var.decls.iter().all(|decl| match decl.init.as_deref() {
+            Some(Expr::Ident(..)) => true,
+            _ => false,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bundler::tests::suite;
+    use ahash::AHashMap;
+    use swc_common::FileName;
+    use swc_ecma_ast::*;
+
+    #[test]
+    fn async_modules() {
+        suite()
+            .top_level_await()
+            .file(
+                "main.js",
+                "
+                import { a } from './a';
+                import { b } from './b';
+                console.log(a, b);
+                ",
+            )
+            .file("a.js", "export const a = await Promise.resolve(1);")
+            .file("b.js", "import { c } from './c'; export const b = c;")
+            .file("c.js", "export const c = 2;")
+            .run(|t| {
+                let main = t.module("main.js").id;
+                let a = t.module("a.js").id;
+                let b = t.module("b.js").id;
+                let c = t.module("c.js").id;
+
+                let async_modules = t.bundler.determine_async_modules(&[main, a, b, c]);
+
+                assert!(async_modules.contains(&main));
+                assert!(async_modules.contains(&a));
+                assert!(!async_modules.contains(&b));
+                assert!(!async_modules.contains(&c));
+
+                Ok(())
+            });
+    }
+
+    #[test]
+    fn entry_awaits_async_deps() {
+        suite()
+            .top_level_await()
+            .file(
+                "main.js",
+                "
+                import { a } from './a';
+                import { b } from './b';
+                console.log(a, b);
+                ",
+            )
+            .file("a.js", "export const a = await Promise.resolve(1);")
+            .file("b.js", "export const b = await Promise.resolve(2);")
+            .run(|t| {
+                let module = t
+                    .bundler
+                    .load_transformed(&FileName::Real("main.js".into()))?
+                    .unwrap();
+                let mut entries = AHashMap::default();
+                entries.insert("main.js".to_string(), module);
+
+                let chunked = t.bundler.chunk(entries)?;
+                assert_eq!(chunked.len(), 1);
+
+                let body = &chunked[0].module.body;
+                let promises = body
+                    .iter()
+                    .filter(|item| match item {
+                        ModuleItem::Stmt(Stmt::Decl(Decl::Var(var))) => {
+                            var.decls.iter().any(|decl| match &decl.name {
+                                Pat::Ident(i) => &*i.id.sym == "__tla",
+                                _ => false,
+                            })
+                        }
+                        _ => false,
+                    })
+                    .count();
+                assert_eq!(promises, 2, "both dependencies should be async modules");
+
+                let awaits = body
+                    .iter()
+                    .filter(|item| match item {
+                        ModuleItem::Stmt(Stmt::Expr(ExprStmt { expr, .. })) => match &**expr {
+                            Expr::Await(..) => true,
+                            _ => false,
+                        },
+                        _ => false,
+                    })
+                    .count();
+                assert_eq!(awaits, 1, "entry should wait for dependencies at once");
+
+                Ok(())
+            });
+    }
+}
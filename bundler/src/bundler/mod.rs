@@ -35,6 +35,12 @@ pub struct Config {
 
     /// Type of emitted module
     pub module: ModuleType,
+
+    /// If it's true, modules containing top-level await and their dependants
+    /// are evaluated as async modules. Async dependencies of a module are
+    /// evaluated concurrently, and the module waits for all of them before
+    /// evaluating its own body.
+    pub top_level_await: bool,
 }
 
 #[derive(Debug, PartialEq, Eq, Hash)]
@@ -6,7 +6,7 @@ use indexmap::IndexMap;
 use std::path::PathBuf;
 use swc_common::{sync::Lrc, FileName, SourceMap, Span, GLOBALS};
 use swc_ecma_ast::*;
-use swc_ecma_parser::{lexer::Lexer, JscTarget, Parser, StringInput};
+use swc_ecma_parser::{lexer::Lexer, EsConfig, JscTarget, Parser, StringInput, Syntax};
 use swc_ecma_utils::drop_span;
 use swc_ecma_visit::VisitMutWith;
 
@@ -29,7 +29,10 @@ impl Load for Loader {
         let fm = self.cm.new_source_file(f.clone(), v.to_string());
 
         let lexer = Lexer::new(
-            Default::default(),
+            Syntax::Es(EsConfig {
+                top_level_await: true,
+                ..Default::default()
+            }),
             JscTarget::Es2020,
             StringInput::from(&*fm),
             None,
@@ -107,9 +110,15 @@ pub(crate) fn suite() -> TestBuilder {
 #[derive(Default)]
 pub(crate) struct TestBuilder {
     files: IndexMap<String, String>,
+    top_level_await: bool,
 }
 
 impl TestBuilder {
+    pub fn top_level_await(mut self) -> Self {
+        self.top_level_await = true;
+        self
+    }
+
     pub fn file(mut self, name: &str, src: &str) -> Self {
         self.files.insert(name.to_string(), src.to_string());
         self
@@ -134,6 +143,7 @@ impl TestBuilder {
                         disable_inliner: true,
                         external_modules: vec![],
                         module: Default::default(),
+                        top_level_await: self.top_level_await,
                     },
                     Box::new(Hook),
                 );
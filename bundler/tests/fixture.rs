@@ -185,6 +185,7 @@ fn do_test(entry: &DirEntry, entries: HashMap<String, FileName>, inline: bool) {
                 .map(From::from)
                 .collect(),
                 module: Default::default(),
+                top_level_await: false,
             },
             Box::new(Hook),
         );
@@ -3,6 +3,7 @@ use crate::{
     syntax_pos::{BytePos, Span, DUMMY_SP},
 };
 use fxhash::FxHashMap;
+use serde::{Deserialize, Serialize};
 use std::{
     cell::{Ref, RefCell},
     rc::Rc,
@@ -257,7 +258,7 @@ impl SingleThreadedComments {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Comment {
     pub kind: CommentKind,
     pub span: Span,
@@ -270,7 +271,7 @@ impl Spanned for Comment {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum CommentKind {
     Line,
     Block,
@@ -64,6 +64,9 @@ pub enum ClassMember {
     TsIndexSignature(TsIndexSignature),
     #[tag("EmptyStatement")]
     Empty(EmptyStmt),
+    /// Stage 3
+    #[tag("AutoAccessor")]
+    AutoAccessor(AutoAccessor),
 }
 
 #[ast_node("ClassProperty")]
@@ -159,6 +162,44 @@ pub struct PrivateProp {
     pub definite: bool,
 }
 
+/// `accessor x = 1;` of the decorators proposal.
+#[ast_node("AutoAccessor")]
+#[derive(Eq, Hash, EqIgnoreSpan)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct AutoAccessor {
+    #[serde(default)]
+    pub span: Span,
+
+    pub key: Key,
+
+    #[serde(default)]
+    pub value: Option<Box<Expr>>,
+
+    #[serde(default, rename = "typeAnnotation")]
+    pub type_ann: Option<TsTypeAnn>,
+
+    #[serde(default)]
+    pub is_static: bool,
+
+    #[serde(default)]
+    pub decorators: Vec<Decorator>,
+
+    /// Typescript extension.
+    #[serde(default)]
+    pub accessibility: Option<Accessibility>,
+}
+
+/// Key of a class member, which may be private.
+#[ast_node]
+#[derive(Eq, Hash, Is, EqIgnoreSpan)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum Key {
+    #[tag("PrivateName")]
+    Private(PrivateName),
+    #[tag("*")]
+    Public(PropName),
+}
+
 macro_rules! method {
     ($name:ident, $ty:literal, $KEY:ty) => {
         #[ast_node($ty)]
@@ -30,6 +30,11 @@ impl<'a> Emitter<'a> {
             space!();
         }
 
+        if node.class.is_abstract {
+            keyword!("abstract");
+            space!();
+        }
+
         for dec in &node.class.decorators {
             emit!(dec);
         }
@@ -838,6 +838,18 @@ impl<'a> Emitter<'a> {
             keyword!("extends");
             space!();
             emit!(node.super_class);
+            emit!(node.super_type_params);
+            space!();
+        }
+
+        if !node.implements.is_empty() {
+            keyword!("implements");
+            space!();
+            self.emit_list(
+                node.span,
+                Some(&node.implements),
+                ListFormat::HeritageClauseTypes,
+            )?;
             space!();
         }
 
@@ -856,6 +868,7 @@ impl<'a> Emitter<'a> {
             ClassMember::PrivateProp(ref n) => emit!(n),
             ClassMember::TsIndexSignature(ref n) => emit!(n),
             ClassMember::Empty(ref n) => emit!(n),
+            ClassMember::AutoAccessor(ref n) => emit!(n),
         }
     }
 
@@ -917,6 +930,11 @@ impl<'a> Emitter<'a> {
             keyword!("static");
             space!();
         }
+
+        if n.is_abstract {
+            keyword!("abstract");
+            space!();
+        }
         match n.kind {
             MethodKind::Method => {
                 if n.function.is_async {
@@ -943,6 +961,10 @@ impl<'a> Emitter<'a> {
             }
         }
 
+        if n.is_optional {
+            punct!("?");
+        }
+
         if let Some(type_params) = &n.function.type_params {
             emit!(type_params);
         }
@@ -977,6 +999,11 @@ impl<'a> Emitter<'a> {
 
         self.emit_accesibility(n.accessibility)?;
 
+        if n.is_static {
+            keyword!("static");
+            space!();
+        }
+
         if n.readonly {
             keyword!("readonly");
             space!();
@@ -1014,16 +1041,21 @@ impl<'a> Emitter<'a> {
             self.emit_accesibility(n.accessibility)?;
         }
 
-        if n.readonly {
-            keyword!("readonly");
-            space!()
-        }
-
         if n.is_static {
             keyword!("static");
             space!();
         }
 
+        if n.is_abstract {
+            keyword!("abstract");
+            space!();
+        }
+
+        if n.readonly {
+            keyword!("readonly");
+            space!()
+        }
+
         if n.computed {
             punct!("[");
             emit!(n.key);
@@ -1032,6 +1064,10 @@ impl<'a> Emitter<'a> {
             emit!(n.key);
         }
 
+        if n.is_optional {
+            punct!("?");
+        }
+
         if let Some(ty) = &n.type_ann {
             punct!(":");
             space!();
@@ -1055,6 +1091,55 @@ impl<'a> Emitter<'a> {
         formatting_semi!();
     }
 
+    #[emitter]
+    fn emit_auto_accessor(&mut self, n: &AutoAccessor) -> Result {
+        self.emit_leading_comments_of_span(n.span(), false)?;
+
+        self.emit_list(n.span, Some(&n.decorators), ListFormat::Decorators)?;
+
+        self.emit_accesibility(n.accessibility)?;
+
+        if n.is_static {
+            keyword!("static");
+            space!();
+        }
+
+        keyword!("accessor");
+        space!();
+
+        emit!(n.key);
+
+        if let Some(ty) = &n.type_ann {
+            punct!(":");
+            space!();
+            emit!(ty);
+        }
+
+        if let Some(v) = &n.value {
+            formatting_space!();
+            punct!("=");
+            formatting_space!();
+
+            if v.is_seq() {
+                punct!("(");
+                emit!(v);
+                punct!(")");
+            } else {
+                emit!(v);
+            }
+        }
+
+        formatting_semi!();
+    }
+
+    #[emitter]
+    fn emit_key(&mut self, n: &Key) -> Result {
+        match *n {
+            Key::Private(ref n) => emit!(n),
+            Key::Public(ref n) => emit!(n),
+        }
+    }
+
     fn emit_accesibility(&mut self, n: Option<Accessibility>) -> Result {
         if let Some(a) = n {
             match a {
@@ -322,6 +322,40 @@ fn named_and_namespace_export_from_min() {
     );
 }
 
+#[test]
+fn static_private_prop() {
+    let src = "class A {
+    static #x = 1;
+    #y;
+}";
+    test_from_to_custom_config(
+        src,
+        src,
+        Default::default(),
+        Syntax::Es(EsConfig {
+            class_private_props: true,
+            ..EsConfig::default()
+        }),
+    );
+}
+
+#[test]
+fn auto_accessor() {
+    let src = "class A {
+    static accessor #x = 1;
+    accessor [y];
+}";
+    test_from_to_custom_config(
+        src,
+        src,
+        Default::default(),
+        Syntax::Es(EsConfig {
+            decorators: true,
+            ..EsConfig::default()
+        }),
+    );
+}
+
 #[test]
 fn issue_450() {
     test_from_to(
@@ -543,10 +543,13 @@ impl<'a> Emitter<'a> {
             space!();
         }
 
-        keyword!("module");
-        space!();
-        emit!(n.id);
-        formatting_space!();
+        if n.global {
+            keyword!("global");
+        } else {
+            keyword!("module");
+            space!();
+            emit!(n.id);
+        }
 
         if let Some(body) = &n.body {
             emit!(body);
@@ -572,21 +575,27 @@ impl<'a> Emitter<'a> {
     fn emit_ts_ns_body(&mut self, n: &TsNamespaceBody) -> Result {
         self.emit_leading_comments_of_span(n.span(), false)?;
 
-        punct!("{");
-        self.wr.increase_indent()?;
         match n {
-            TsNamespaceBody::TsModuleBlock(n) => emit!(n),
-            TsNamespaceBody::TsNamespaceDecl(n) => emit!(n),
+            TsNamespaceBody::TsModuleBlock(n) => {
+                formatting_space!();
+                punct!("{");
+                self.wr.increase_indent()?;
+                emit!(n);
+                self.wr.decrease_indent()?;
+                punct!("}");
+            }
+            // `a.b` in `namespace a.b {}`
+            TsNamespaceBody::TsNamespaceDecl(n) => {
+                punct!(".");
+                emit!(n);
+            }
         }
-        self.wr.decrease_indent()?;
-        punct!("}");
     }
 
     #[emitter]
     fn emit_ts_ns_decl(&mut self, n: &TsNamespaceDecl) -> Result {
-        self.emit_leading_comments_of_span(n.span(), false)?;
-
-        unimplemented!("emit_ts_ns_decl")
+        emit!(n.id);
+        emit!(n.body);
     }
 
     #[emitter]
@@ -0,0 +1,119 @@
+use swc_common::{
+    comments::{Comment, SingleThreadedComments},
+    BytePos,
+};
+
+/// Comments of a file, which are printed in the order of their positions.
+///
+/// Comments are attached to positions of tokens by the parser, and the printer
+/// takes comments up to the start of each node. So a comment is never lost,
+/// even if it's not at the start or the end of a node.
+pub(crate) struct CommentStore {
+    entries: Vec<Entry>,
+    /// Entries before this are already taken.
+    cursor: usize,
+}
+
+struct Entry {
+    /// The position where the comment is attached.
+    pos: BytePos,
+    /// True if the comment is on the same line as the previous token.
+    trailing: bool,
+    comment: Comment,
+    taken: bool,
+}
+
+impl CommentStore {
+    pub fn new(comments: &SingleThreadedComments) -> Self {
+        let (leading, trailing) = comments.borrow_all();
+
+        let mut entries = vec![];
+        for (map, is_trailing) in [(&*leading, false), (&*trailing, true)].iter() {
+            for (&pos, comments) in map.iter() {
+                entries.extend(comments.iter().map(|c| Entry {
+                    pos,
+                    trailing: *is_trailing,
+                    comment: c.clone(),
+                    taken: false,
+                }));
+            }
+        }
+        entries.sort_by_key(|e| (e.pos, e.comment.span.lo));
+
+        CommentStore { entries, cursor: 0 }
+    }
+
+    /// Returns the start of the first comment which is attached at or before
+    /// `pos`.
+    pub fn peek(&self, pos: BytePos) -> Option<BytePos> {
+        self.entries[self.cursor..]
+            .iter()
+            .take_while(|e| e.pos <= pos)
+            .find(|e| !e.taken)
+            .map(|e| e.comment.span.lo)
+    }
+
+    /// Returns the start of a comment which is attached at `pos` and ends at
+    /// `hi`.
+    pub fn start_of(&self, pos: BytePos, hi: BytePos) -> Option<BytePos> {
+        self.entries
+            .iter()
+            .find(|e| e.pos == pos && e.comment.span.hi == hi)
+            .map(|e| e.comment.span.lo)
+    }
+
+    /// Takes comments which are attached at or before `pos`.
+    ///
+    /// If `trailing_only` is true, only comments on the same line as the
+    /// previous token are taken.
+    pub fn take(&mut self, pos: BytePos, trailing_only: bool) -> Vec<Comment> {
+        let mut taken = vec![];
+        for e in &mut self.entries[self.cursor..] {
+            if e.pos > pos {
+                break;
+            }
+            if e.taken || (trailing_only && !e.trailing) {
+                continue;
+            }
+            e.taken = true;
+            taken.push(e.comment.clone());
+        }
+
+        while self.cursor < self.entries.len() && self.entries[self.cursor].taken {
+            self.cursor += 1;
+        }
+
+        taken
+    }
+
+    /// Takes comments attached in `lo..=hi`, with their positions and whether
+    /// they are trailing comments.
+    pub fn take_range(&mut self, lo: BytePos, hi: BytePos) -> Vec<(BytePos, bool, Comment)> {
+        let mut taken = vec![];
+        for e in &mut self.entries[self.cursor..] {
+            if hi < e.pos {
+                break;
+            }
+            if e.taken || e.pos < lo {
+                continue;
+            }
+            e.taken = true;
+            taken.push((e.pos, e.trailing, e.comment.clone()));
+        }
+
+        taken
+    }
+
+    /// Restores a comment taken by [CommentStore::take_range], which is not
+    /// printed.
+    pub fn restore(&mut self, comment: &Comment) {
+        if let Some(idx) = self
+            .entries
+            .iter()
+            .position(|e| e.comment.span == comment.span)
+        {
+            self.entries[idx].taken = false;
+            self.cursor = self.cursor.min(idx);
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use serde::{Deserialize, Serialize};
+
+/// Options of the formatter, which are a subset of options of Prettier.
+///
+/// ```json
+/// {
+///     "printWidth": 100,
+///     "singleQuote": true,
+///     "trailingComma": "all"
+/// }
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct FmtConfig {
+    /// The line length where the formatter tries to wrap lines.
+    #[serde(default = "default_print_width")]
+    pub print_width: usize,
+
+    /// The number of spaces per indentation level.
+    #[serde(default = "default_tab_width")]
+    pub tab_width: usize,
+
+    /// Prints semicolons at the ends of statements. If false, semicolons are
+    /// only printed at the beginning of lines which may be parsed as a part
+    /// of the previous statement.
+    #[serde(default = "true_by_default")]
+    pub semi: bool,
+
+    /// Prefers single quotes over double quotes for strings.
+    #[serde(default)]
+    pub single_quote: bool,
+
+    #[serde(default)]
+    pub trailing_comma: TrailingComma,
+}
+
+impl Default for FmtConfig {
+    fn default() -> Self {
+        FmtConfig {
+            print_width: default_print_width(),
+            tab_width: default_tab_width(),
+            semi: true,
+            single_quote: false,
+            trailing_comma: Default::default(),
+        }
+    }
+}
+
+/// Where trailing commas are printed in comma-separated lists which are
+/// broken into multiple lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TrailingComma {
+    None,
+    /// Where valid in ES5, like objects and arrays.
+    Es5,
+    /// Also in parameters and arguments of functions.
+    All,
+}
+
+impl Default for TrailingComma {
+    fn default() -> Self {
+        TrailingComma::Es5
+    }
+}
+
+fn default_print_width() -> usize {
+    80
+}
+
+fn default_tab_width() -> usize {
+    2
+}
+
+fn true_by_default() -> bool {
+    true
+}
@@ -0,0 +1,347 @@
+//! Document IR and its printer, based on "A prettier printer" by Philip Wadler
+//! and the algorithm of Prettier.
+//!
+//! A [Doc] describes possible layouts of code. [Group]s are printed on a
+//! single line if they fit in the print width, and otherwise [Line]s directly
+//! in the group are printed as line breaks.
+
+use std::borrow::Cow;
+
+#[derive(Debug, Clone)]
+pub enum Doc {
+    /// Text which does not contain line breaks, unless it's printed verbatim
+    /// like multi-line template literals.
+    Text(Cow<'static, str>),
+    Concat(Vec<Doc>),
+    Line(Line),
+    Group(Box<Group>),
+    /// Increases the indentation of lines in the doc.
+    Indent(Box<Doc>),
+    /// The first doc is printed if the enclosing group is broken, and the
+    /// second one otherwise.
+    IfBreak(Box<Doc>, Box<Doc>),
+    /// Printed just before the next line break, like trailing line comments.
+    LineSuffix(Box<Doc>),
+    /// Breaks all enclosing groups.
+    BreakParent,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Line {
+    /// Nothing if the enclosing group fits on a line.
+    Soft,
+    /// A space if the enclosing group fits on a line.
+    Normal,
+    /// Always a line break, which breaks all enclosing groups.
+    Hard,
+}
+
+#[derive(Debug, Clone)]
+pub struct Group {
+    /// The first state is the contents of the group. Other states are tried
+    /// in order if the group does not fit, and the last one is printed in
+    /// the broken mode if no state fits.
+    states: Vec<Doc>,
+    breaks: bool,
+}
+
+impl Group {
+    fn contents(&self) -> &Doc {
+        &self.states[0]
+    }
+}
+
+impl Default for Doc {
+    fn default() -> Self {
+        Doc::Concat(vec![])
+    }
+}
+
+impl From<&'static str> for Doc {
+    fn from(s: &'static str) -> Self {
+        Doc::Text(s.into())
+    }
+}
+
+impl From<String> for Doc {
+    fn from(s: String) -> Self {
+        Doc::Text(s.into())
+    }
+}
+
+impl From<Vec<Doc>> for Doc {
+    fn from(docs: Vec<Doc>) -> Self {
+        Doc::Concat(docs)
+    }
+}
+
+pub fn text(s: impl Into<Cow<'static, str>>) -> Doc {
+    Doc::Text(s.into())
+}
+
+pub fn concat(docs: Vec<Doc>) -> Doc {
+    Doc::Concat(docs)
+}
+
+pub fn line() -> Doc {
+    Doc::Line(Line::Normal)
+}
+
+pub fn softline() -> Doc {
+    Doc::Line(Line::Soft)
+}
+
+pub fn hardline() -> Doc {
+    Doc::Line(Line::Hard)
+}
+
+pub fn indent(doc: impl Into<Doc>) -> Doc {
+    Doc::Indent(Box::new(doc.into()))
+}
+
+pub fn if_break(broken: impl Into<Doc>, flat: impl Into<Doc>) -> Doc {
+    Doc::IfBreak(Box::new(broken.into()), Box::new(flat.into()))
+}
+
+pub fn line_suffix(doc: impl Into<Doc>) -> Doc {
+    Doc::LineSuffix(Box::new(doc.into()))
+}
+
+pub fn group(doc: impl Into<Doc>) -> Doc {
+    group_with_break(doc, false)
+}
+
+/// A group which is broken if `should_break` is true, even if it fits.
+pub fn group_with_break(doc: impl Into<Doc>, should_break: bool) -> Doc {
+    let doc = doc.into();
+    let breaks = should_break || propagates_break(&doc);
+    Doc::Group(Box::new(Group {
+        states: vec![doc],
+        breaks,
+    }))
+}
+
+/// A group which tries `states` in order. Unlike [group], line breaks in the
+/// states do not break the enclosing groups.
+pub fn conditional_group(states: Vec<Doc>) -> Doc {
+    debug_assert!(!states.is_empty());
+
+    Doc::Group(Box::new(Group {
+        states,
+        breaks: false,
+    }))
+}
+
+pub fn join(sep: Doc, docs: impl IntoIterator<Item = Doc>) -> Doc {
+    let mut v = vec![];
+    for (i, doc) in docs.into_iter().enumerate() {
+        if i != 0 {
+            v.push(sep.clone());
+        }
+        v.push(doc);
+    }
+    Doc::Concat(v)
+}
+
+/// Returns true if `doc` contains a forced line break.
+pub fn will_break(doc: &Doc) -> bool {
+    match doc {
+        Doc::Text(..) | Doc::LineSuffix(..) => false,
+        Doc::Concat(docs) => docs.iter().any(will_break),
+        Doc::Line(line) => *line == Line::Hard,
+        Doc::Group(g) => g.breaks || will_break(g.contents()),
+        Doc::Indent(doc) => will_break(doc),
+        Doc::IfBreak(broken, flat) => will_break(broken) || will_break(flat),
+        Doc::BreakParent => true,
+    }
+}
+
+/// Returns true if a group containing `doc` should be broken.
+fn propagates_break(doc: &Doc) -> bool {
+    match doc {
+        Doc::Text(..) | Doc::LineSuffix(..) => false,
+        Doc::Concat(docs) => docs.iter().any(propagates_break),
+        Doc::Line(line) => *line == Line::Hard,
+        Doc::Group(g) => g.breaks,
+        Doc::Indent(doc) => propagates_break(doc),
+        Doc::IfBreak(broken, flat) => propagates_break(broken) || propagates_break(flat),
+        Doc::BreakParent => true,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Break,
+    Flat,
+}
+
+#[derive(Clone, Copy)]
+struct Cmd<'a> {
+    indent: usize,
+    mode: Mode,
+    doc: &'a Doc,
+}
+
+/// Prints `doc`, trying to fit lines in `width` columns. Each level of
+/// indentation is `indent_width` spaces.
+pub fn print(doc: &Doc, width: usize, indent_width: usize) -> String {
+    let mut out = String::new();
+    // Column of the end of `out`.
+    let mut pos = 0;
+    let mut cmds = vec![Cmd {
+        indent: 0,
+        mode: Mode::Break,
+        doc,
+    }];
+    let mut line_suffixes: Vec<Cmd> = vec![];
+    // Groups in the flat mode are measured again after a line break, which is
+    // printed by hard lines in the flat mode.
+    let mut remeasure = false;
+
+    loop {
+        let Cmd { indent, mode, doc } = match cmds.pop() {
+            Some(cmd) => cmd,
+            None if line_suffixes.is_empty() => break,
+            None => {
+                cmds.extend(line_suffixes.drain(..).rev());
+                continue;
+            }
+        };
+
+        match doc {
+            Doc::Text(s) => {
+                out.push_str(s);
+                pos = match s.rfind('\n') {
+                    Some(idx) => {
+                        remeasure = true;
+                        s[idx + 1..].chars().count()
+                    }
+                    None => pos + s.chars().count(),
+                };
+            }
+            Doc::Concat(docs) => {
+                cmds.extend(docs.iter().rev().map(|doc| Cmd { indent, mode, doc }));
+            }
+            Doc::Indent(doc) => cmds.push(Cmd {
+                indent: indent + 1,
+                mode,
+                doc,
+            }),
+            Doc::Group(g) => {
+                let flat = Cmd {
+                    indent,
+                    mode: Mode::Flat,
+                    doc: g.contents(),
+                };
+                let rem = width as isize - pos as isize;
+                if mode == Mode::Flat && !g.breaks && !remeasure {
+                    cmds.push(flat);
+                    continue;
+                }
+                remeasure = false;
+
+                let cmd = if !g.breaks && fits(flat, &cmds, rem) {
+                    flat
+                } else {
+                    let expanded = g.states.last().unwrap();
+                    let state = if g.breaks {
+                        None
+                    } else {
+                        g.states[1..]
+                            .iter()
+                            .map(|doc| Cmd {
+                                indent,
+                                mode: Mode::Flat,
+                                doc,
+                            })
+                            .find(|&cmd| fits(cmd, &cmds, rem))
+                    };
+                    state.unwrap_or(Cmd {
+                        indent,
+                        mode: Mode::Break,
+                        doc: expanded,
+                    })
+                };
+                cmds.push(cmd);
+            }
+            Doc::IfBreak(broken, flat) => cmds.push(Cmd {
+                indent,
+                mode,
+                doc: if mode == Mode::Break { broken } else { flat },
+            }),
+            Doc::LineSuffix(doc) => line_suffixes.push(Cmd { indent, mode, doc }),
+            Doc::BreakParent => {}
+            Doc::Line(line) => {
+                if mode == Mode::Flat && *line != Line::Hard {
+                    if *line == Line::Normal {
+                        out.push(' ');
+                        pos += 1;
+                    }
+                    continue;
+                }
+
+                if !line_suffixes.is_empty() {
+                    cmds.push(Cmd { indent, mode, doc });
+                    cmds.extend(line_suffixes.drain(..).rev());
+                    continue;
+                }
+
+                let len = out.trim_end_matches(&[' ', '\t'][..]).len();
+                out.truncate(len);
+                out.push('\n');
+                remeasure = true;
+                pos = indent * indent_width;
+                out.push_str(&" ".repeat(pos));
+            }
+        }
+    }
+
+    out
+}
+
+/// Returns true if `next` fits in `width` columns, up to the next line break.
+fn fits(next: Cmd, rest: &[Cmd], mut width: isize) -> bool {
+    let mut rest_idx = rest.len();
+    let mut cmds = vec![(next.mode, next.doc)];
+
+    while width >= 0 {
+        let (mode, doc) = match cmds.pop() {
+            Some(cmd) => cmd,
+            None if rest_idx == 0 => return true,
+            None => {
+                rest_idx -= 1;
+                (rest[rest_idx].mode, rest[rest_idx].doc)
+            }
+        };
+
+        match doc {
+            Doc::Text(s) => match s.find('\n') {
+                Some(idx) => return width - s[..idx].chars().count() as isize >= 0,
+                None => width -= s.chars().count() as isize,
+            },
+            Doc::Concat(docs) => cmds.extend(docs.iter().rev().map(|doc| (mode, doc))),
+            Doc::Indent(doc) => cmds.push((mode, doc)),
+            Doc::Group(g) => {
+                if g.breaks {
+                    cmds.push((Mode::Break, g.states.last().unwrap()));
+                } else {
+                    cmds.push((mode, g.contents()));
+                }
+            }
+            Doc::IfBreak(broken, flat) => {
+                cmds.push((mode, if mode == Mode::Break { broken } else { flat }))
+            }
+            Doc::Line(line) => {
+                if mode == Mode::Break || *line == Line::Hard {
+                    return true;
+                }
+                if *line == Line::Normal {
+                    width -= 1;
+                }
+            }
+            Doc::LineSuffix(..) | Doc::BreakParent => {}
+        }
+    }
+
+    false
+}
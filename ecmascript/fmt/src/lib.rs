@@ -0,0 +1,65 @@
+//! Code formatter for ecmascript, which prints code in the style of Prettier.
+//!
+//! ```ignore
+//! let fm = cm.new_source_file(FileName::Anon, "let a = [1,2,3]".into());
+//! let code = format(cm.clone(), &fm, Default::default(), &FmtConfig::default())?;
+//! assert_eq!(code, "let a = [1, 2, 3];\n");
+//! ```
+//!
+//! Nodes are converted to a [Doc](doc::Doc), which describes possible layouts
+//! of code, and the doc is printed to fit in [FmtConfig::print_width].
+//! Comments and empty lines between statements and properties are preserved.
+//! JSX is printed by `swc_ecma_codegen`.
+pub use self::config::{FmtConfig, TrailingComma};
+use self::printer::Printer;
+use swc_common::{comments::SingleThreadedComments, sync::Lrc, SourceFile, SourceMap};
+use swc_ecma_ast::Program;
+use swc_ecma_parser::{error::Error, lexer::Lexer, Parser, StringInput, Syntax};
+
+mod comments;
+mod config;
+pub mod doc;
+mod printer;
+#[cfg(test)]
+mod tests;
+
+/// Parses and formats `fm`.
+///
+/// Returns the first error if the file has syntax errors, as the formatter
+/// may drop invalid code.
+pub fn format(
+    cm: Lrc<SourceMap>,
+    fm: &SourceFile,
+    syntax: Syntax,
+    config: &FmtConfig,
+) -> Result<String, Error> {
+    let comments = SingleThreadedComments::default();
+    let lexer = Lexer::new(
+        syntax,
+        Default::default(),
+        StringInput::from(fm),
+        Some(&comments),
+    );
+    let mut parser = Parser::new_from(lexer);
+    let program = parser.parse_program()?;
+    if let Some(err) = parser.take_errors().into_iter().next() {
+        return Err(err);
+    }
+
+    Ok(format_program(cm, fm, &comments, &program, config))
+}
+
+/// Formats `program` parsed from `fm`.
+///
+/// The source code of `fm` is used to preserve empty lines and raw values of
+/// literals, so `program` should not be modified after parsing.
+pub fn format_program(
+    cm: Lrc<SourceMap>,
+    fm: &SourceFile,
+    comments: &SingleThreadedComments,
+    program: &Program,
+    config: &FmtConfig,
+) -> String {
+    let doc = Printer::new(cm, fm, comments, config).program(program);
+    doc::print(&doc, config.print_width, config.tab_width)
+}
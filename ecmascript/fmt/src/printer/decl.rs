@@ -0,0 +1,598 @@
+use super::Printer;
+use crate::{doc::*, TrailingComma};
+use swc_common::{BytePos, Spanned};
+use swc_ecma_ast::*;
+
+impl Printer<'_> {
+    /// Functions including the `function` keyword.
+    pub(super) fn function(&mut self, ident: Option<&Ident>, f: &Function) -> Doc {
+        let mut parts = vec![];
+        if f.is_async {
+            parts.push("async ".into());
+        }
+        parts.push(
+            if f.is_generator {
+                "function*"
+            } else {
+                "function"
+            }
+            .into(),
+        );
+        parts.push(match ident {
+            Some(ident) => text(format!(" {}", ident.sym)),
+            None => " ".into(),
+        });
+        parts.push(self.signature(f));
+        parts.push(self.fn_body(f.body.as_ref()));
+        concat(parts)
+    }
+
+    /// Type parameters, parameters and the return type of `f`.
+    pub(super) fn signature(&mut self, f: &Function) -> Doc {
+        let type_params = self.type_params(f.type_params.as_ref());
+        let close = match (&f.return_type, &f.body) {
+            (Some(t), _) => t.span.lo,
+            (None, Some(body)) => body.span.lo,
+            (None, None) => f.span.hi,
+        };
+        let params = self.params(&f.params, close, |p, param| {
+            let decorators = p.decorators(&param.decorators);
+            concat(vec![decorators, p.pat(&param.pat)])
+        });
+        let return_type = self.type_ann(f.return_type.as_ref());
+
+        group(vec![type_params, group(params), return_type])
+    }
+
+    pub(super) fn fn_body(&mut self, body: Option<&BlockStmt>) -> Doc {
+        match body {
+            Some(body) => concat(vec![" ".into(), self.block(body)]),
+            None => self.semi(),
+        }
+    }
+
+    /// Parameters in parentheses. `close` is a position after the closing
+    /// parenthesis.
+    pub(super) fn params<T: Spanned + AsPat>(
+        &mut self,
+        params: &[T],
+        close: BytePos,
+        op: impl FnMut(&mut Self, &T) -> Doc,
+    ) -> Doc {
+        let close = close - BytePos(1);
+        let hug = match params {
+            [param] => param.as_pat().map_or(false, should_hug_param),
+            _ => false,
+        };
+        let has_rest = params
+            .last()
+            .and_then(|p| p.as_pat())
+            .map_or(false, |p| matches!(p, Pat::Rest(..)));
+
+        let docs = self.comma_separated(params, false, op);
+        if hug {
+            let dangling = self.dangling_comments(close, true);
+            let mut docs = docs;
+            return concat(vec!["(".into(), docs.remove(0), dangling, ")".into()]);
+        }
+
+        let trailing_comma = if has_rest {
+            Doc::default()
+        } else {
+            self.trailing_comma(TrailingComma::All)
+        };
+        self.bracketed("(", docs, trailing_comma, close, ")", false, false)
+    }
+
+    /// Decorators followed by a line break, or a space if they were on the
+    /// same line as the decorated node.
+    pub(super) fn decorators(&mut self, decorators: &[Decorator]) -> Doc {
+        let last = match decorators.last() {
+            Some(last) => last,
+            None => return Doc::default(),
+        };
+        let sep = if self.newlines_after(last.span.hi) > 0 {
+            hardline()
+        } else {
+            " ".into()
+        };
+
+        let mut parts = vec![];
+        for d in decorators {
+            parts.push(self.leading_comments(d.span.lo));
+            parts.push("@".into());
+            parts.push(self.expr(&d.expr));
+            parts.push(sep.clone());
+        }
+        concat(parts)
+    }
+
+    pub(super) fn class(&mut self, ident: Option<&Ident>, class: &Class, decorators: bool) -> Doc {
+        let mut parts = vec![];
+        if decorators {
+            parts.push(self.decorators(&class.decorators));
+        }
+        if class.is_abstract {
+            parts.push("abstract ".into());
+        }
+        parts.push("class".into());
+        if let Some(ident) = ident {
+            parts.push(text(format!(" {}", ident.sym)));
+        }
+        parts.push(self.type_params(class.type_params.as_ref()));
+
+        let mut heritage = vec![];
+        if let Some(super_class) = &class.super_class {
+            heritage.push(line());
+            heritage.push("extends ".into());
+            heritage.push(self.expr(super_class));
+            heritage.push(self.type_args(class.super_type_params.as_ref()));
+        }
+        if !class.implements.is_empty() {
+            let implements: Vec<_> = class
+                .implements
+                .iter()
+                .map(|i| self.expr_with_type_args(i))
+                .collect();
+            heritage.push(line());
+            heritage.push("implements ".into());
+            heritage.push(join(", ".into(), implements));
+        }
+        if !heritage.is_empty() {
+            parts.push(group(indent(heritage)));
+        }
+
+        let body = self.lines(
+            &class.body,
+            true,
+            |m| matches!(m, ClassMember::Empty(..)),
+            |p, member, next| p.class_member(member, next),
+        );
+        parts.push(" ".into());
+        parts.push(self.braces(class.span, body));
+        concat(parts)
+    }
+
+    fn class_member(&mut self, member: &ClassMember, next: Option<&ClassMember>) -> Doc {
+        match member {
+            ClassMember::Constructor(c) => {
+                let close = match &c.body {
+                    Some(body) => body.span.lo,
+                    None => c.span.hi,
+                };
+                let params = self.params(&c.params, close, |p, param| match param {
+                    ParamOrTsParamProp::Param(param) => {
+                        let decorators = p.decorators(&param.decorators);
+                        concat(vec![decorators, p.pat(&param.pat)])
+                    }
+                    ParamOrTsParamProp::TsParamProp(param) => p.param_prop(param),
+                });
+                concat(vec![
+                    accessibility(c.accessibility),
+                    self.prop_name(&c.key),
+                    if c.is_optional { "?" } else { "" }.into(),
+                    group(params),
+                    self.fn_body(c.body.as_ref()),
+                ])
+            }
+            ClassMember::Method(m) => {
+                let key = self.prop_name(&m.key);
+                self.method(
+                    &m.function,
+                    key,
+                    MethodModifiers {
+                        kind: m.kind,
+                        accessibility: m.accessibility,
+                        is_static: m.is_static,
+                        is_abstract: m.is_abstract,
+                        is_override: m.is_override,
+                        is_optional: m.is_optional,
+                    },
+                )
+            }
+            ClassMember::PrivateMethod(m) => {
+                let key = text(format!("#{}", m.key.id.sym));
+                self.method(
+                    &m.function,
+                    key,
+                    MethodModifiers {
+                        kind: m.kind,
+                        accessibility: m.accessibility,
+                        is_static: m.is_static,
+                        is_abstract: m.is_abstract,
+                        is_override: m.is_override,
+                        is_optional: m.is_optional,
+                    },
+                )
+            }
+            ClassMember::ClassProp(p) => {
+                let key = if p.computed {
+                    concat(vec!["[".into(), self.expr(&p.key), "]".into()])
+                } else {
+                    self.expr(&p.key)
+                };
+                let key_name = match &*p.key {
+                    Expr::Ident(i) if !p.computed => Some(&*i.sym),
+                    _ => None,
+                };
+                let decorators = self.decorators(&p.decorators);
+                let mut parts = vec![
+                    decorators,
+                    if p.declare { "declare " } else { "" }.into(),
+                    accessibility(p.accessibility),
+                ];
+                parts.extend(modifiers(p.is_static, p.is_abstract, p.is_override));
+                // The parser marks `readonly!: T` as readonly.
+                if p.readonly && self.src(p.span.lo, p.key.span().lo).contains("readonly") {
+                    parts.push("readonly ".into());
+                }
+                parts.push(key);
+                let semi =
+                    self.prop_semi(key_name, p.value.is_none() && p.type_ann.is_none(), next);
+                parts.push(self.prop_tail(
+                    p.is_optional,
+                    p.definite,
+                    p.type_ann.as_ref(),
+                    p.value.as_deref(),
+                    semi,
+                ));
+                concat(parts)
+            }
+            ClassMember::PrivateProp(p) => {
+                let decorators = self.decorators(&p.decorators);
+                let mut parts = vec![decorators, accessibility(p.accessibility)];
+                parts.extend(modifiers(p.is_static, p.is_abstract, p.is_override));
+                if p.readonly {
+                    parts.push("readonly ".into());
+                }
+                parts.push(text(format!("#{}", p.key.id.sym)));
+                let semi = self.prop_semi(None, false, next);
+                parts.push(self.prop_tail(
+                    p.is_optional,
+                    p.definite,
+                    p.type_ann.as_ref(),
+                    p.value.as_deref(),
+                    semi,
+                ));
+                concat(parts)
+            }
+            ClassMember::TsIndexSignature(s) => {
+                let semi = self.prop_semi(None, false, next);
+                concat(vec![self.index_signature(s), semi])
+            }
+            ClassMember::AutoAccessor(a) => {
+                let decorators = self.decorators(&a.decorators);
+                let key = match &a.key {
+                    Key::Private(p) => text(format!("#{}", p.id.sym)),
+                    Key::Public(key) => self.prop_name(key),
+                };
+                let mut parts = vec![decorators, accessibility(a.accessibility)];
+                if a.is_static {
+                    parts.push("static ".into());
+                }
+                parts.push("accessor ".into());
+                parts.push(key);
+                let semi = self.prop_semi(None, false, next);
+                parts.push(self.prop_tail(
+                    false,
+                    false,
+                    a.type_ann.as_ref(),
+                    a.value.as_deref(),
+                    semi,
+                ));
+                concat(parts)
+            }
+            ClassMember::Empty(..) => Doc::default(),
+        }
+    }
+
+    fn method(&mut self, f: &Function, key: Doc, m: MethodModifiers) -> Doc {
+        let decorators = self.decorators(&f.decorators);
+        let mut parts = vec![decorators, accessibility(m.accessibility)];
+        parts.extend(modifiers(m.is_static, m.is_abstract, m.is_override));
+        if f.is_async {
+            parts.push("async ".into());
+        }
+        if f.is_generator {
+            parts.push("*".into());
+        }
+        match m.kind {
+            MethodKind::Method => {}
+            MethodKind::Getter => parts.push("get ".into()),
+            MethodKind::Setter => parts.push("set ".into()),
+        }
+        parts.push(key);
+        if m.is_optional {
+            parts.push("?".into());
+        }
+        parts.push(self.signature(f));
+        parts.push(self.fn_body(f.body.as_ref()));
+        concat(parts)
+    }
+
+    /// Optional or definite marks, a type annotation and a value of class
+    /// properties.
+    fn prop_tail(
+        &mut self,
+        is_optional: bool,
+        definite: bool,
+        type_ann: Option<&TsTypeAnn>,
+        value: Option<&Expr>,
+        semi: Doc,
+    ) -> Doc {
+        let left = concat(vec![
+            if is_optional { "?" } else { "" }.into(),
+            if definite { "!" } else { "" }.into(),
+            self.type_ann(type_ann),
+        ]);
+        let doc = match value {
+            Some(value) => self.assignment(left, " =", value, false),
+            None => left,
+        };
+        concat(vec![doc, semi])
+    }
+
+    /// Semicolon after a class property. Without semicolons, it's printed only
+    /// if the next member can be parsed as a part of the property.
+    fn prop_semi(&self, key: Option<&str>, is_bare: bool, next: Option<&ClassMember>) -> Doc {
+        if self.config.semi {
+            return ";".into();
+        }
+        if is_bare && matches!(key, Some("static") | Some("get") | Some("set")) {
+            return ";".into();
+        }
+
+        let needs_semi = match next {
+            Some(ClassMember::ClassProp(p)) => {
+                p.computed && !p.is_static && p.accessibility.is_none() && !p.readonly
+            }
+            Some(ClassMember::Method(m)) => {
+                m.accessibility.is_none()
+                    && !m.is_static
+                    && !m.function.is_async
+                    && m.kind == MethodKind::Method
+                    && (m.function.is_generator || matches!(m.key, PropName::Computed(..)))
+            }
+            Some(ClassMember::PrivateMethod(m)) => {
+                m.accessibility.is_none()
+                    && !m.is_static
+                    && !m.function.is_async
+                    && m.kind == MethodKind::Method
+                    && m.function.is_generator
+            }
+            Some(ClassMember::TsIndexSignature(s)) => !s.is_static && !s.readonly,
+            _ => false,
+        };
+        if needs_semi {
+            ";".into()
+        } else {
+            Doc::default()
+        }
+    }
+
+    fn param_prop(&mut self, p: &TsParamProp) -> Doc {
+        let decorators = self.decorators(&p.decorators);
+        let param = match &p.param {
+            TsParamPropParam::Ident(i) => self.binding_ident(i),
+            TsParamPropParam::Assign(a) => self.assign_pat(a),
+        };
+        concat(vec![
+            decorators,
+            accessibility(p.accessibility),
+            if p.is_override { "override " } else { "" }.into(),
+            if p.readonly { "readonly " } else { "" }.into(),
+            param,
+        ])
+    }
+
+    pub(super) fn prop_name(&mut self, name: &PropName) -> Doc {
+        match name {
+            PropName::Ident(i) => text(i.sym.to_string()),
+            PropName::Str(s) => self.str_lit(s),
+            PropName::Num(n) => self.num_lit(n),
+            PropName::Computed(c) => concat(vec!["[".into(), self.expr(&c.expr), "]".into()]),
+            PropName::BigInt(b) => self.bigint_lit(b),
+        }
+    }
+
+    pub(super) fn pat(&mut self, pat: &Pat) -> Doc {
+        match pat {
+            Pat::Ident(i) => self.binding_ident(i),
+            Pat::Array(a) => {
+                let docs = self.holey(&a.elems, |p, pat| p.pat(pat));
+                let trailing_comma = match a.elems.last() {
+                    Some(None) => ",".into(),
+                    Some(Some(Pat::Rest(..))) => Doc::default(),
+                    _ => self.trailing_comma(TrailingComma::Es5),
+                };
+                let close = match &a.type_ann {
+                    Some(t) => t.span.lo,
+                    None => a.span.hi,
+                };
+                let elems = self.bracketed(
+                    "[",
+                    docs,
+                    trailing_comma,
+                    close - BytePos(1),
+                    "]",
+                    false,
+                    false,
+                );
+                concat(vec![
+                    elems,
+                    if a.optional { "?" } else { "" }.into(),
+                    self.type_ann(a.type_ann.as_ref()),
+                ])
+            }
+            Pat::Rest(r) => concat(vec![
+                "...".into(),
+                self.pat(&r.arg),
+                self.type_ann(r.type_ann.as_ref()),
+            ]),
+            Pat::Object(o) => {
+                let docs = self.comma_separated(&o.props, false, |p, prop| match prop {
+                    ObjectPatProp::KeyValue(kv) => {
+                        concat(vec![p.prop_name(&kv.key), ": ".into(), p.pat(&kv.value)])
+                    }
+                    ObjectPatProp::Assign(a) => match &a.value {
+                        Some(value) => {
+                            concat(vec![text(format!("{} = ", a.key.sym)), p.expr(value)])
+                        }
+                        None => text(a.key.sym.to_string()),
+                    },
+                    ObjectPatProp::Rest(r) => p.pat(&Pat::Rest(r.clone())),
+                });
+                let trailing_comma = match o.props.last() {
+                    Some(ObjectPatProp::Rest(..)) => Doc::default(),
+                    _ => self.trailing_comma(TrailingComma::Es5),
+                };
+                let close = match &o.type_ann {
+                    Some(t) => t.span.lo,
+                    None => o.span.hi,
+                };
+                let props = self.bracketed(
+                    "{",
+                    docs,
+                    trailing_comma,
+                    close - BytePos(1),
+                    "}",
+                    true,
+                    false,
+                );
+                concat(vec![
+                    props,
+                    if o.optional { "?" } else { "" }.into(),
+                    self.type_ann(o.type_ann.as_ref()),
+                ])
+            }
+            Pat::Assign(a) => self.assign_pat(a),
+            Pat::Invalid(i) => self.fallback(i),
+            Pat::Expr(e) => self.expr(e),
+        }
+    }
+
+    pub(super) fn binding_ident(&mut self, i: &BindingIdent) -> Doc {
+        concat(vec![
+            text(i.id.sym.to_string()),
+            if i.id.optional { "?" } else { "" }.into(),
+            self.type_ann(i.type_ann.as_ref()),
+        ])
+    }
+
+    fn assign_pat(&mut self, a: &AssignPat) -> Doc {
+        concat(vec![
+            self.pat(&a.left),
+            self.type_ann(a.type_ann.as_ref()),
+            " = ".into(),
+            self.expr(&a.right),
+        ])
+    }
+
+    /// Like [Printer::comma_separated], but for lists with holes.
+    pub(super) fn holey<T: Spanned>(
+        &mut self,
+        items: &[Option<T>],
+        mut op: impl FnMut(&mut Self, &T) -> Doc,
+    ) -> Vec<Doc> {
+        let mut docs = vec![];
+        let mut prev_hi = None;
+        for item in items {
+            let item = match item {
+                Some(item) => item,
+                None => {
+                    docs.push(Doc::default());
+                    continue;
+                }
+            };
+
+            let span = item.span();
+            let mut sep = Doc::default();
+            if let Some(prev) = docs.last_mut() {
+                let comments = self.same_line_comments(span.lo);
+                *prev = concat(vec![std::mem::take(prev), comments]);
+                let start = self.comments.peek(span.lo).unwrap_or(span.lo);
+                if prev_hi.map_or(false, |hi| self.has_blank_line(hi, start)) {
+                    sep = softline();
+                }
+            }
+            let leading = self.leading_comments(span.lo);
+            let doc = op(self, item);
+            let trailing = self.trailing_comments(span.hi);
+            docs.push(concat(vec![sep, leading, doc, trailing]));
+            prev_hi = Some(span.hi);
+        }
+        docs
+    }
+}
+
+struct MethodModifiers {
+    kind: MethodKind,
+    accessibility: Option<Accessibility>,
+    is_static: bool,
+    is_abstract: bool,
+    is_override: bool,
+    is_optional: bool,
+}
+
+/// Nodes which may be patterns, for parameters.
+pub(super) trait AsPat {
+    fn as_pat(&self) -> Option<&Pat>;
+}
+
+impl AsPat for Param {
+    fn as_pat(&self) -> Option<&Pat> {
+        Some(&self.pat)
+    }
+}
+
+impl AsPat for Pat {
+    fn as_pat(&self) -> Option<&Pat> {
+        Some(self)
+    }
+}
+
+impl AsPat for ParamOrTsParamProp {
+    fn as_pat(&self) -> Option<&Pat> {
+        match self {
+            ParamOrTsParamProp::Param(p) => Some(&p.pat),
+            ParamOrTsParamProp::TsParamProp(..) => None,
+        }
+    }
+}
+
+/// Returns true if the only parameter `pat` should be printed without a line
+/// break after `(`.
+fn should_hug_param(pat: &Pat) -> bool {
+    match pat {
+        Pat::Object(..) => true,
+        Pat::Assign(a) => matches!(&*a.left, Pat::Object(..)),
+        Pat::Ident(i) => i
+            .type_ann
+            .as_ref()
+            .map_or(false, |t| matches!(&*t.type_ann, TsType::TsTypeLit(..))),
+        _ => false,
+    }
+}
+
+pub(super) fn accessibility(a: Option<Accessibility>) -> Doc {
+    match a {
+        Some(Accessibility::Public) => "public ".into(),
+        Some(Accessibility::Protected) => "protected ".into(),
+        Some(Accessibility::Private) => "private ".into(),
+        None => Doc::default(),
+    }
+}
+
+fn modifiers(is_static: bool, is_abstract: bool, is_override: bool) -> Vec<Doc> {
+    let mut parts = vec![];
+    if is_static {
+        parts.push("static ".into());
+    }
+    if is_abstract {
+        parts.push("abstract ".into());
+    }
+    if is_override {
+        parts.push("override ".into());
+    }
+    parts
+}
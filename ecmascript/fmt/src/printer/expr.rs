@@ -0,0 +1,1200 @@
+use super::Printer;
+use crate::{doc::*, TrailingComma};
+use swc_common::{BytePos, Spanned};
+use swc_ecma_ast::*;
+
+impl Printer<'_> {
+    pub(super) fn expr(&mut self, e: &Expr) -> Doc {
+        let leading = self.leading_comments(e.span().lo);
+        let doc = self.expr_inner(e);
+        concat(vec![leading, doc])
+    }
+
+    fn expr_inner(&mut self, e: &Expr) -> Doc {
+        match e {
+            Expr::This(..) => "this".into(),
+            Expr::Array(a) => self.array_lit(a),
+            Expr::Object(o) => self.object_lit(o),
+            Expr::Fn(f) => self.function(f.ident.as_ref(), &f.function),
+            Expr::Unary(u) => {
+                let op = u.op.as_str();
+                let space =
+                    op.ends_with(|c: char| c.is_ascii_alphabetic()) || starts_with_op(&u.arg, op);
+                concat(vec![
+                    op.into(),
+                    if space { " " } else { "" }.into(),
+                    self.expr(&u.arg),
+                ])
+            }
+            Expr::Update(u) => {
+                let arg = self.expr(&u.arg);
+                if u.prefix {
+                    concat(vec![u.op.as_str().into(), arg])
+                } else {
+                    concat(vec![arg, u.op.as_str().into()])
+                }
+            }
+            Expr::Bin(b) => {
+                let mut parts = self.bin_parts(b);
+                let first = parts.remove(0);
+                group(vec![first, indent(parts)])
+            }
+            Expr::Assign(a) => {
+                let left = match &a.left {
+                    PatOrExpr::Expr(e) => self.expr(e),
+                    PatOrExpr::Pat(p) => self.pat(p),
+                };
+                let op = text(format!(" {}", a.op.as_str()));
+                self.assignment(left, op, &a.right, false)
+            }
+            Expr::Member(m) => self.member(m, false),
+            Expr::Cond(c) => self.cond(c),
+            Expr::Call(c) => match &c.callee {
+                ExprOrSuper::Expr(callee) if is_memberish(callee) => self.member_chain(e),
+                callee => {
+                    let callee = match callee {
+                        ExprOrSuper::Super(..) => "super".into(),
+                        ExprOrSuper::Expr(callee) => self.expr(callee),
+                    };
+                    concat(vec![
+                        callee,
+                        self.type_args(c.type_args.as_ref()),
+                        self.call_args(&c.args, c.span.hi),
+                    ])
+                }
+            },
+            Expr::New(n) => {
+                let args = match &n.args {
+                    Some(args) => self.call_args(args, n.span.hi),
+                    None => "()".into(),
+                };
+                concat(vec![
+                    "new ".into(),
+                    self.expr(&n.callee),
+                    self.type_args(n.type_args.as_ref()),
+                    args,
+                ])
+            }
+            Expr::Seq(s) => {
+                let mut parts = vec![];
+                for (i, e) in s.exprs.iter().enumerate() {
+                    if i == 0 {
+                        parts.push(self.expr(e));
+                    } else {
+                        parts.push(",".into());
+                        parts.push(indent(vec![line(), self.expr(e)]));
+                    }
+                }
+                group(parts)
+            }
+            Expr::Ident(i) => text(i.sym.to_string()),
+            Expr::Lit(l) => self.lit(l),
+            Expr::Tpl(t) => self.tpl(t),
+            Expr::TaggedTpl(t) => concat(vec![
+                self.expr(&t.tag),
+                self.type_args(t.type_params.as_ref()),
+                self.tpl(&t.tpl),
+            ]),
+            Expr::Arrow(a) => self.arrow(a),
+            Expr::Class(c) => self.class(c.ident.as_ref(), &c.class, true),
+            Expr::Yield(y) => {
+                let mut parts = vec![if y.delegate { "yield*" } else { "yield" }.into()];
+                if let Some(arg) = &y.arg {
+                    parts.push(" ".into());
+                    parts.push(self.expr(arg));
+                }
+                concat(parts)
+            }
+            Expr::MetaProp(m) => text(format!("{}.{}", m.meta.sym, m.prop.sym)),
+            Expr::Await(a) => concat(vec!["await ".into(), self.expr(&a.arg)]),
+            Expr::Paren(p) => self.paren(p),
+            Expr::JSXMember(..)
+            | Expr::JSXNamespacedName(..)
+            | Expr::JSXEmpty(..)
+            | Expr::JSXElement(..)
+            | Expr::JSXFragment(..)
+            | Expr::Invalid(..) => self.fallback(e),
+            Expr::TsTypeAssertion(t) => concat(vec![
+                "<".into(),
+                self.ts_type(&t.type_ann),
+                ">".into(),
+                self.expr(&t.expr),
+            ]),
+            Expr::TsConstAssertion(t) => concat(vec![self.expr(&t.expr), " as const".into()]),
+            Expr::TsNonNull(t) => concat(vec![self.expr(&t.expr), "!".into()]),
+            Expr::TsAs(t) => concat(vec![
+                self.expr(&t.expr),
+                " as ".into(),
+                self.ts_type(&t.type_ann),
+            ]),
+            Expr::PrivateName(p) => text(format!("#{}", p.id.sym)),
+            Expr::OptChain(o) => match &*o.expr {
+                Expr::Member(m) => self.member(m, true),
+                Expr::Call(c) => match &c.callee {
+                    ExprOrSuper::Expr(callee) if is_memberish(callee) => self.member_chain(e),
+                    _ => {
+                        let callee = self.callee(&c.callee);
+                        concat(vec![
+                            callee,
+                            "?.".into(),
+                            self.type_args(c.type_args.as_ref()),
+                            self.call_args(&c.args, c.span.hi),
+                        ])
+                    }
+                },
+                expr => self.expr(expr),
+            },
+        }
+    }
+
+    fn lit(&mut self, l: &Lit) -> Doc {
+        match l {
+            Lit::Str(s) => self.str_lit(s),
+            Lit::Bool(b) => if b.value { "true" } else { "false" }.into(),
+            Lit::Null(..) => "null".into(),
+            Lit::Num(n) => self.num_lit(n),
+            Lit::BigInt(b) => self.bigint_lit(b),
+            Lit::Regex(r) => {
+                let raw = self.snippet(r.span);
+                let (exp, flags) = match raw.rfind('/') {
+                    Some(idx) if raw.starts_with('/') && idx > 0 => (&raw[1..idx], &raw[idx + 1..]),
+                    _ => (&*r.exp, &*r.flags),
+                };
+                let mut flags: Vec<_> = flags.chars().collect();
+                flags.sort_unstable();
+                text(format!(
+                    "/{}/{}",
+                    exp,
+                    flags.into_iter().collect::<String>()
+                ))
+            }
+            Lit::JSXText(..) => self.fallback(l),
+        }
+    }
+
+    /// Strings are printed with the preferred quote, unless it requires more
+    /// escapes than the other one.
+    pub(super) fn str_lit(&mut self, s: &Str) -> Doc {
+        let raw = self.snippet(s.span);
+        let content = match raw.chars().next() {
+            Some(q @ '"') | Some(q @ '\'') if raw.len() >= 2 && raw.ends_with(q) => {
+                raw[1..raw.len() - 1].to_string()
+            }
+            _ => escape(&s.value),
+        };
+
+        let (preferred, alternate) = if self.config.single_quote {
+            ('\'', '"')
+        } else {
+            ('"', '\'')
+        };
+        let count = |q| content.chars().filter(|&c| c == q).count();
+        let quote = if count(preferred) > count(alternate) {
+            alternate
+        } else {
+            preferred
+        };
+        text(make_string(&content, quote))
+    }
+
+    pub(super) fn num_lit(&mut self, n: &Number) -> Doc {
+        let raw = self.snippet(n.span);
+        if raw.is_empty() {
+            return text(n.value.to_string());
+        }
+        text(normalize_number(raw))
+    }
+
+    pub(super) fn bigint_lit(&mut self, b: &BigInt) -> Doc {
+        let raw = self.snippet(b.span);
+        if raw.is_empty() {
+            return text(format!("{}n", b.value));
+        }
+        text(raw.to_ascii_lowercase())
+    }
+
+    fn tpl(&mut self, t: &Tpl) -> Doc {
+        let mut parts = vec!["`".into()];
+        for (i, quasi) in t.quasis.iter().enumerate() {
+            parts.push(text(self.quasi(quasi)));
+            if let Some(e) = t.exprs.get(i) {
+                // Expressions are kept on a line, as template literals are
+                // printed verbatim.
+                let doc = self.expr(e);
+                let printed = print(&doc, usize::MAX / 2, self.config.tab_width);
+                parts.push(text(format!("${{{}}}", printed)));
+            }
+        }
+        parts.push("`".into());
+        concat(parts)
+    }
+
+    fn expr_or_spread(&mut self, e: &ExprOrSpread) -> Doc {
+        let spread = if e.spread.is_some() { "..." } else { "" };
+        concat(vec![spread.into(), self.expr(&e.expr)])
+    }
+
+    fn array_lit(&mut self, a: &ArrayLit) -> Doc {
+        let docs = self.holey(&a.elems, |p, e| p.expr_or_spread(e));
+        let trailing_comma = match a.elems.last() {
+            Some(None) => ",".into(),
+            _ => self.trailing_comma(TrailingComma::Es5),
+        };
+
+        // Arrays of objects or arrays are broken, like matrices.
+        let should_break = a.elems.len() > 1
+            && a.elems.iter().all(|e| match e {
+                Some(ExprOrSpread { spread: None, expr }) => match (&**expr, &a.elems[0]) {
+                    (Expr::Object(o), Some(first)) => {
+                        o.props.len() > 1 && matches!(&*first.expr, Expr::Object(..))
+                    }
+                    (Expr::Array(a), Some(first)) => {
+                        a.elems.len() > 1 && matches!(&*first.expr, Expr::Array(..))
+                    }
+                    _ => false,
+                },
+                _ => false,
+            });
+
+        self.bracketed(
+            "[",
+            docs,
+            trailing_comma,
+            a.span.hi - BytePos(1),
+            "]",
+            false,
+            should_break,
+        )
+    }
+
+    /// Objects are broken if there was a line break between `{` and the first
+    /// property.
+    pub(super) fn object_lit(&mut self, o: &ObjectLit) -> Doc {
+        let should_break = o
+            .props
+            .first()
+            .map_or(false, |p| self.has_newline(o.span.lo, p.span().lo));
+        let docs = self.comma_separated(&o.props, true, |p, prop| p.prop(prop));
+        let trailing_comma = self.trailing_comma(TrailingComma::Es5);
+        self.bracketed(
+            "{",
+            docs,
+            trailing_comma,
+            o.span.hi - BytePos(1),
+            "}",
+            true,
+            should_break,
+        )
+    }
+
+    fn prop(&mut self, prop: &PropOrSpread) -> Doc {
+        let prop = match prop {
+            PropOrSpread::Spread(s) => return concat(vec!["...".into(), self.expr(&s.expr)]),
+            PropOrSpread::Prop(prop) => prop,
+        };
+        match &**prop {
+            Prop::Shorthand(i) => text(i.sym.to_string()),
+            Prop::KeyValue(kv) => {
+                let short_key = match &kv.key {
+                    PropName::Ident(i) => i.sym.len() < self.config.tab_width,
+                    PropName::Str(s) => s.value.len() + 2 < self.config.tab_width,
+                    _ => false,
+                };
+                let key = self.prop_name(&kv.key);
+                self.assignment(key, ":", &kv.value, short_key)
+            }
+            Prop::Assign(a) => concat(vec![text(format!("{} = ", a.key.sym)), self.expr(&a.value)]),
+            Prop::Getter(g) => concat(vec![
+                "get ".into(),
+                self.prop_name(&g.key),
+                "()".into(),
+                self.type_ann(g.type_ann.as_ref()),
+                self.fn_body(g.body.as_ref()),
+            ]),
+            Prop::Setter(s) => concat(vec![
+                "set ".into(),
+                self.prop_name(&s.key),
+                "(".into(),
+                self.pat(&s.param),
+                ")".into(),
+                self.fn_body(s.body.as_ref()),
+            ]),
+            Prop::Method(m) => {
+                let f = &m.function;
+                concat(vec![
+                    if f.is_async { "async " } else { "" }.into(),
+                    if f.is_generator { "*" } else { "" }.into(),
+                    self.prop_name(&m.key),
+                    self.signature(f),
+                    self.fn_body(f.body.as_ref()),
+                ])
+            }
+        }
+    }
+
+    /// Binary expressions with the same precedence as `b`, which are joined
+    /// with lines.
+    pub(super) fn bin_parts(&mut self, b: &BinExpr) -> Vec<Doc> {
+        let mut parts = match &*b.left {
+            Expr::Bin(left) if should_flatten(b.op, left.op) => {
+                let leading = self.leading_comments(left.span.lo);
+                let mut parts = self.bin_parts(left);
+                parts[0] = concat(vec![leading, std::mem::take(&mut parts[0])]);
+                parts
+            }
+            left => vec![self.bin_operand(b.op, left)],
+        };
+
+        let right = self.bin_operand(b.op, &b.right);
+        let sep = if should_inline_logical(b) {
+            " ".into()
+        } else {
+            line()
+        };
+        parts.push(concat(vec![
+            text(format!(" {}", b.op.as_str())),
+            sep,
+            right,
+        ]));
+        parts
+    }
+
+    /// Operands of binary expressions, which are wrapped with parentheses if
+    /// mixed operators may be confusing, like `(a && b) || c`.
+    fn bin_operand(&mut self, op: BinaryOp, e: &Expr) -> Doc {
+        let b = match e {
+            Expr::Bin(b) if needs_parens(op, b.op) => b,
+            Expr::Paren(ParenExpr { expr, .. }) => match &**expr {
+                Expr::Bin(b) => b,
+                _ => return self.expr(e),
+            },
+            _ => return self.expr(e),
+        };
+        let leading = self.leading_comments(e.span().lo);
+        let mut parts = self.bin_parts(b);
+        let first = parts.remove(0);
+        concat(vec![
+            leading,
+            "(".into(),
+            group(vec![first, indent(parts)]),
+            ")".into(),
+        ])
+    }
+
+    /// Assignment-like nodes, including variable declarators and properties.
+    pub(super) fn assignment(
+        &mut self,
+        left: Doc,
+        op: impl Into<Doc>,
+        right: &Expr,
+        short_key: bool,
+    ) -> Doc {
+        let op = op.into();
+        if break_after_op(right, short_key) {
+            let leading = self.leading_comments(right.span().lo);
+            let right = match right {
+                Expr::Bin(b) => group(concat(self.bin_parts(b))),
+                _ => self.expr(right),
+            };
+            return group(vec![
+                group(left),
+                op,
+                group(indent(vec![line(), leading, right])),
+            ]);
+        }
+
+        let right = self.expr(right);
+        group(vec![left, op, " ".into(), right])
+    }
+
+    fn cond(&mut self, c: &CondExpr) -> Doc {
+        let test = self.expr(&c.test);
+        let mut rest = vec![];
+        let mut c = c;
+        loop {
+            rest.push(line());
+            rest.push("? ".into());
+            rest.push(self.expr(&c.cons));
+            rest.push(line());
+            rest.push(": ".into());
+            match &*c.alt {
+                Expr::Cond(alt) => {
+                    rest.push(self.expr(&alt.test));
+                    c = alt;
+                }
+                alt => {
+                    rest.push(self.expr(alt));
+                    break;
+                }
+            }
+        }
+        group(vec![test, indent(rest)])
+    }
+
+    fn paren(&mut self, p: &ParenExpr) -> Doc {
+        let inner = match &*p.expr {
+            Expr::Bin(b) => {
+                let leading = self.leading_comments(b.span.lo);
+                concat(vec![leading, concat(self.bin_parts(b))])
+            }
+            e @ Expr::Seq(..)
+            | e @ Expr::Cond(..)
+            | e @ Expr::Assign(..)
+            | e @ Expr::Yield(..)
+            | e @ Expr::TsAs(..) => self.expr(e),
+            e => return concat(vec!["(".into(), self.expr(e), ")".into()]),
+        };
+        let dangling = self.dangling_comments(p.span.hi - BytePos(1), true);
+        group(vec![
+            "(".into(),
+            indent(vec![softline(), inner, dangling]),
+            softline(),
+            ")".into(),
+        ])
+    }
+
+    fn arrow(&mut self, a: &ArrowExpr) -> Doc {
+        let mut parts = vec![];
+        if a.is_async {
+            parts.push("async ".into());
+        }
+        parts.push(self.type_params(a.type_params.as_ref()));
+        let close = match (&a.return_type, &a.body) {
+            (Some(t), _) => t.span.lo,
+            (None, BlockStmtOrExpr::BlockStmt(b)) => b.span.lo,
+            (None, BlockStmtOrExpr::Expr(e)) => e.span().lo,
+        };
+        let params = self.params(&a.params, close, |p, pat| p.pat(pat));
+        parts.push(group(params));
+        parts.push(self.type_ann(a.return_type.as_ref()));
+        parts.push(" =>".into());
+
+        match &a.body {
+            BlockStmtOrExpr::BlockStmt(b) => {
+                parts.push(" ".into());
+                parts.push(self.block(b));
+            }
+            BlockStmtOrExpr::Expr(body) => {
+                let hug = matches!(
+                    unparen(body),
+                    Expr::Object(..)
+                        | Expr::Array(..)
+                        | Expr::Arrow(..)
+                        | Expr::Tpl(..)
+                        | Expr::TaggedTpl(..)
+                        | Expr::JSXElement(..)
+                        | Expr::JSXFragment(..)
+                );
+                if hug {
+                    parts.push(" ".into());
+                    parts.push(self.expr(body));
+                } else {
+                    parts.push(group(indent(vec![line(), self.expr(body)])));
+                }
+            }
+        }
+        group(parts)
+    }
+
+    fn callee(&mut self, callee: &ExprOrSuper) -> Doc {
+        match callee {
+            ExprOrSuper::Super(..) => "super".into(),
+            ExprOrSuper::Expr(e) => self.expr(e),
+        }
+    }
+
+    fn member(&mut self, m: &MemberExpr, optional: bool) -> Doc {
+        let obj = match &m.obj {
+            ExprOrSuper::Super(..) => "super".into(),
+            ExprOrSuper::Expr(obj) => match &**obj {
+                // `1..toString()` should not be printed as `1.toString()`.
+                Expr::Lit(Lit::Num(n)) if !self.snippet(n.span).is_empty() => {
+                    let leading = self.leading_comments(n.span.lo);
+                    concat(vec![leading, text(self.snippet(n.span).to_string())])
+                }
+                obj => self.expr(obj),
+            },
+        };
+        concat(vec![obj, self.member_lookup(m, optional)])
+    }
+
+    fn member_lookup(&mut self, m: &MemberExpr, optional: bool) -> Doc {
+        if m.computed {
+            concat(vec![
+                if optional { "?.[" } else { "[" }.into(),
+                self.expr(&m.prop),
+                "]".into(),
+            ])
+        } else {
+            concat(vec![
+                if optional { "?." } else { "." }.into(),
+                self.expr(&m.prop),
+            ])
+        }
+    }
+
+    /// Arguments of calls. `hi` is the end of the call.
+    fn call_args(&mut self, args: &[ExprOrSpread], hi: BytePos) -> Doc {
+        let close = hi - BytePos(1);
+
+        if is_react_hook_args(args) {
+            let first = self.expr_or_spread(&args[0]);
+            let second = self.expr_or_spread(&args[1]);
+            let dangling = self.dangling_comments(close, true);
+            return concat(vec![
+                "(".into(),
+                first,
+                ", ".into(),
+                second,
+                dangling,
+                ")".into(),
+            ]);
+        }
+
+        let docs = self.comma_separated(args, false, |p, arg| p.expr_or_spread(arg));
+        let dangling = self.dangling_comments(close, !docs.is_empty());
+        if docs.is_empty() {
+            if super::is_nil(&dangling) {
+                return "()".into();
+            }
+            return group(vec!["(".into(), indent(dangling), softline(), ")".into()]);
+        }
+
+        let trailing_comma = match args.last() {
+            Some(ExprOrSpread {
+                spread: Some(..), ..
+            }) => Doc::default(),
+            _ => self.trailing_comma(TrailingComma::All),
+        };
+        let all_broken = |docs: &[Doc], should_break: bool| {
+            group_with_break(
+                vec![
+                    "(".into(),
+                    indent(vec![
+                        softline(),
+                        join(concat(vec![",".into(), line()]), docs.to_vec()),
+                        trailing_comma.clone(),
+                        dangling.clone(),
+                    ]),
+                    softline(),
+                    ")".into(),
+                ],
+                should_break,
+            )
+        };
+        if !super::is_nil(&dangling) {
+            return all_broken(&docs, true);
+        }
+
+        let n = docs.len();
+        if should_group_last(args) {
+            if docs[..n - 1].iter().any(will_break) {
+                return all_broken(&docs, true);
+            }
+            let flat = concat(vec![
+                "(".into(),
+                join(", ".into(), docs.clone()),
+                ")".into(),
+            ]);
+            let mut hugged = docs[..n - 1].to_vec();
+            hugged.push(group_with_break(docs[n - 1].clone(), true));
+            let hugged = concat(vec!["(".into(), join(", ".into(), hugged), ")".into()]);
+            return concat(vec![
+                if will_break(&docs[n - 1]) {
+                    Doc::BreakParent
+                } else {
+                    Doc::default()
+                },
+                conditional_group(vec![flat, hugged, all_broken(&docs, true)]),
+            ]);
+        }
+
+        if should_group_first(args) {
+            if will_break(&docs[1]) {
+                return all_broken(&docs, true);
+            }
+            let flat = concat(vec![
+                "(".into(),
+                join(", ".into(), docs.clone()),
+                ")".into(),
+            ]);
+            let hugged = concat(vec![
+                "(".into(),
+                group_with_break(docs[0].clone(), true),
+                ", ".into(),
+                docs[1].clone(),
+                ")".into(),
+            ]);
+            return concat(vec![
+                if will_break(&docs[0]) {
+                    Doc::BreakParent
+                } else {
+                    Doc::default()
+                },
+                conditional_group(vec![flat, hugged, all_broken(&docs, true)]),
+            ]);
+        }
+
+        all_broken(&docs, false)
+    }
+
+    /// Chains of member accesses and calls, like `a.b().c()`, which are
+    /// broken before `.` if they have many calls.
+    fn member_chain(&mut self, e: &Expr) -> Doc {
+        let (head, links) = flatten_chain(e);
+
+        let head_doc = match head {
+            ExprOrSuperRef::Super => "super".into(),
+            ExprOrSuperRef::Expr(Expr::Lit(Lit::Num(n))) if !self.snippet(n.span).is_empty() => {
+                let leading = self.leading_comments(n.span.lo);
+                concat(vec![leading, text(self.snippet(n.span).to_string())])
+            }
+            ExprOrSuperRef::Expr(head) => self.expr(head),
+        };
+        let docs: Vec<_> = links
+            .iter()
+            .map(|link| match *link {
+                Link::Member(m, optional) => self.member_lookup(m, optional),
+                Link::Call(c, optional) => concat(vec![
+                    if optional { "?." } else { "" }.into(),
+                    self.type_args(c.type_args.as_ref()),
+                    self.call_args(&c.args, c.span.hi),
+                ]),
+                Link::NonNull => "!".into(),
+            })
+            .collect();
+
+        // The first group is the head followed by calls and computed accesses,
+        // like `a()[0]`, and member accesses except the last one, like `a.b`
+        // of `a.b.c()`.
+        let mut i = 0;
+        let mut first = vec![head_doc];
+        while i < links.len() && links[i].is_factory_like() {
+            first.push(docs[i].clone());
+            i += 1;
+        }
+        if !matches!(head, ExprOrSuperRef::Expr(Expr::Call(..))) {
+            while i + 1 < links.len() && links[i].is_member() && links[i + 1].is_member() {
+                first.push(docs[i].clone());
+                i += 1;
+            }
+        }
+
+        // Other groups start with a member access and end with calls.
+        let mut groups: Vec<Vec<Doc>> = vec![];
+        let mut current = vec![];
+        let mut seen_call = false;
+        for (link, doc) in links[i..].iter().zip(&docs[i..]) {
+            if seen_call && link.is_member() {
+                groups.push(std::mem::take(&mut current));
+                seen_call = false;
+            }
+            if let Link::Call(..) = link {
+                seen_call = true;
+            }
+            current.push(doc.clone());
+        }
+        if !current.is_empty() {
+            groups.push(current);
+        }
+
+        // Short heads like `this` or `z` are kept on the same line as the
+        // first call.
+        let should_merge = !groups.is_empty()
+            && match (head, first.len()) {
+                (ExprOrSuperRef::Expr(Expr::This(..)), 1) => true,
+                (ExprOrSuperRef::Expr(Expr::Ident(i)), 1) => {
+                    is_factory(&i.sym) || i.sym.len() <= self.config.tab_width
+                }
+                (_, 1) => false,
+                _ => match links[first.len() - 2] {
+                    Link::Member(m, _) if !m.computed => {
+                        matches!(&*m.prop, Expr::Ident(i) if is_factory(&i.sym))
+                    }
+                    _ => false,
+                },
+            };
+
+        let one_line = concat(
+            first
+                .iter()
+                .cloned()
+                .chain(groups.iter().flatten().cloned())
+                .collect(),
+        );
+        let cutoff = if should_merge { 3 } else { 2 };
+        if groups.len() < cutoff && !groups.iter().flatten().any(will_break) {
+            return group(one_line);
+        }
+
+        let mut rest = groups.iter().map(|g| concat(g.clone()));
+        let mut expanded = vec![concat(first.clone())];
+        if should_merge {
+            expanded.extend(rest.next());
+        }
+        let rest: Vec<_> = rest.collect();
+        expanded.push(indent(vec![hardline(), join(hardline(), rest)]));
+        let expanded = concat(expanded);
+
+        let calls: Vec<_> = links
+            .iter()
+            .filter_map(|link| match link {
+                Link::Call(c, _) => Some(c),
+                _ => None,
+            })
+            .collect();
+        let has_complex_args = calls.len() > 2
+            && calls
+                .iter()
+                .any(|c| !c.args.iter().all(|a| is_simple_call_arg(&a.expr, 0)));
+        let groups_break =
+            groups.len() > 1 && groups[..groups.len() - 1].iter().flatten().any(will_break);
+        if has_complex_args || groups_break || first.iter().any(will_break) {
+            return group(expanded);
+        }
+
+        concat(vec![
+            if will_break(&one_line) {
+                Doc::BreakParent
+            } else {
+                Doc::default()
+            },
+            conditional_group(vec![one_line, expanded]),
+        ])
+    }
+}
+
+#[derive(Clone, Copy)]
+enum ExprOrSuperRef<'a> {
+    Super,
+    Expr(&'a Expr),
+}
+
+#[derive(Clone, Copy)]
+enum Link<'a> {
+    Member(&'a MemberExpr, bool),
+    Call(&'a CallExpr, bool),
+    NonNull,
+}
+
+impl Link<'_> {
+    fn is_member(&self) -> bool {
+        matches!(self, Link::Member(..))
+    }
+
+    /// Calls, non-null assertions and accesses with literals.
+    fn is_factory_like(&self) -> bool {
+        match self {
+            Link::Member(m, _) => m.computed && matches!(&*m.prop, Expr::Lit(..)),
+            Link::Call(..) | Link::NonNull => true,
+        }
+    }
+}
+
+fn flatten_chain<'a>(e: &'a Expr) -> (ExprOrSuperRef<'a>, Vec<Link<'a>>) {
+    let mut links = vec![];
+    let mut cur = e;
+    let head = loop {
+        let (link, next) = match cur {
+            Expr::Call(c) => (Link::Call(c, false), &c.callee),
+            Expr::Member(m) => (Link::Member(m, false), &m.obj),
+            Expr::OptChain(o) => match &*o.expr {
+                Expr::Call(c) => (Link::Call(c, true), &c.callee),
+                Expr::Member(m) => (Link::Member(m, true), &m.obj),
+                _ => break ExprOrSuperRef::Expr(cur),
+            },
+            Expr::TsNonNull(n) => {
+                links.push(Link::NonNull);
+                cur = &n.expr;
+                continue;
+            }
+            _ => break ExprOrSuperRef::Expr(cur),
+        };
+        links.push(link);
+        match next {
+            ExprOrSuper::Super(..) => break ExprOrSuperRef::Super,
+            ExprOrSuper::Expr(next) => cur = next,
+        }
+    };
+    links.reverse();
+    (head, links)
+}
+
+fn is_memberish(e: &Expr) -> bool {
+    match e {
+        Expr::Member(..) => true,
+        Expr::OptChain(o) => matches!(&*o.expr, Expr::Member(..)),
+        Expr::TsNonNull(n) => is_memberish(&n.expr),
+        _ => false,
+    }
+}
+
+/// Names like `React` or `$`, which are usually short.
+fn is_factory(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_uppercase())
+        || (!name.is_empty() && name.chars().all(|c| c == '$' || c == '_'))
+}
+
+fn is_simple_call_arg(e: &Expr, depth: usize) -> bool {
+    let is_child_simple = |e: &Expr| depth < 2 && is_simple_call_arg(e, depth + 1);
+    match e {
+        Expr::Lit(Lit::Regex(r)) => r.exp.len() <= 5,
+        Expr::Lit(..) | Expr::This(..) | Expr::Ident(..) | Expr::PrivateName(..) => true,
+        Expr::MetaProp(..) => true,
+        Expr::Tpl(t) => {
+            t.quasis.iter().all(|q| !q.raw.value.contains('\n'))
+                && t.exprs.iter().all(|e| is_child_simple(e))
+        }
+        Expr::Object(o) => o.props.iter().all(|p| match p {
+            PropOrSpread::Prop(p) => match &**p {
+                Prop::Shorthand(..) => true,
+                Prop::KeyValue(kv) => {
+                    !matches!(kv.key, PropName::Computed(..)) && is_child_simple(&kv.value)
+                }
+                _ => false,
+            },
+            PropOrSpread::Spread(s) => is_child_simple(&s.expr),
+        }),
+        Expr::Array(a) => a
+            .elems
+            .iter()
+            .all(|e| e.as_ref().map_or(true, |e| is_child_simple(&e.expr))),
+        Expr::Call(c) => {
+            matches!(&c.callee, ExprOrSuper::Expr(callee) if is_simple_call_arg(callee, depth))
+                && c.args.iter().all(|a| is_child_simple(&a.expr))
+        }
+        Expr::New(n) => {
+            is_simple_call_arg(&n.callee, depth)
+                && n.args.iter().flatten().all(|a| is_child_simple(&a.expr))
+        }
+        Expr::Unary(u) => {
+            matches!(u.op, op!("!") | op!(unary, "-")) && is_simple_call_arg(&u.arg, depth)
+        }
+        Expr::TsNonNull(n) => is_simple_call_arg(&n.expr, depth),
+        Expr::Member(m) => {
+            (!m.computed || is_simple_call_arg(&m.prop, depth))
+                && match &m.obj {
+                    ExprOrSuper::Super(..) => true,
+                    ExprOrSuper::Expr(obj) => is_simple_call_arg(obj, depth),
+                }
+        }
+        Expr::OptChain(o) => is_simple_call_arg(&o.expr, depth),
+        _ => false,
+    }
+}
+
+fn unparen(e: &Expr) -> &Expr {
+    match e {
+        Expr::Paren(p) => unparen(&p.expr),
+        _ => e,
+    }
+}
+
+/// Returns true if `e` starts with `op`, like `-a` for `-`.
+fn starts_with_op(e: &Expr, op: &str) -> bool {
+    match e {
+        Expr::Unary(u) => !op.is_empty() && u.op.as_str().starts_with(op),
+        Expr::Update(u) => u.prefix && u.op.as_str().starts_with(op),
+        _ => false,
+    }
+}
+
+fn should_flatten(parent: BinaryOp, child: BinaryOp) -> bool {
+    use BinaryOp::*;
+
+    let is_equality = |op| matches!(op, EqEq | NotEq | EqEqEq | NotEqEq);
+    let is_multiplicative = |op| matches!(op, Mul | Div | Mod);
+    let is_bitshift = |op| matches!(op, LShift | RShift | ZeroFillRShift);
+
+    if parent.precedence() != child.precedence() || parent == Exp {
+        return false;
+    }
+    if parent != child && (parent == NullishCoalescing || child == NullishCoalescing) {
+        return false;
+    }
+    if is_equality(parent) && is_equality(child) {
+        return false;
+    }
+    if (child == Mod && is_multiplicative(parent)) || (parent == Mod && is_multiplicative(child)) {
+        return false;
+    }
+    if child != parent && is_multiplicative(child) && is_multiplicative(parent) {
+        return false;
+    }
+    !(is_bitshift(parent) && is_bitshift(child))
+}
+
+/// Returns true if a binary expression with `child` as the operator should be
+/// wrapped with parentheses in an operand of `parent`.
+pub(super) fn needs_parens(parent: BinaryOp, child: BinaryOp) -> bool {
+    use BinaryOp::*;
+
+    let (pp, np) = (parent.precedence(), child.precedence());
+    if pp == np {
+        return !should_flatten(parent, child);
+    }
+    pp > np
+        || (child == Mod && matches!(parent, Add | Sub))
+        || matches!(parent, BitOr | BitXor | BitAnd)
+        || (parent == LogicalOr && child == LogicalAnd)
+}
+
+/// Logical expressions with object or array literals on the right side, like
+/// `a || {}`, are not broken after the operator.
+fn should_inline_logical(b: &BinExpr) -> bool {
+    matches!(
+        b.op,
+        BinaryOp::LogicalAnd | BinaryOp::LogicalOr | BinaryOp::NullishCoalescing
+    ) && match &*b.right {
+        Expr::Object(o) => !o.props.is_empty(),
+        Expr::Array(a) => !a.elems.is_empty(),
+        _ => false,
+    }
+}
+
+/// Returns true if the right side of an assignment should be moved to the
+/// next line if it does not fit.
+fn break_after_op(right: &Expr, short_key: bool) -> bool {
+    match right {
+        Expr::Bin(b) => !should_inline_logical(b),
+        Expr::Seq(..) => true,
+        Expr::Cond(c) => matches!(&*c.test, Expr::Bin(b) if !should_inline_logical(b)),
+        Expr::Class(c) => !c.class.decorators.is_empty(),
+        _ if short_key => false,
+        Expr::Lit(Lit::Str(..)) => true,
+        Expr::Member(..) => is_member_chain(right),
+        _ => false,
+    }
+}
+
+/// `a.b.c`
+fn is_member_chain(e: &Expr) -> bool {
+    match e {
+        Expr::Member(MemberExpr {
+            obj: ExprOrSuper::Expr(obj),
+            ..
+        }) => is_member_chain(obj),
+        Expr::Ident(..) | Expr::This(..) => true,
+        _ => false,
+    }
+}
+
+/// `useEffect(() => { ... }, [a, b])`
+fn is_react_hook_args(args: &[ExprOrSpread]) -> bool {
+    match args {
+        [ExprOrSpread {
+            spread: None,
+            expr: first,
+        }, ExprOrSpread {
+            spread: None,
+            expr: second,
+        }] => {
+            matches!(&**first, Expr::Arrow(a) if a.params.is_empty() && matches!(a.body, BlockStmtOrExpr::BlockStmt(..)))
+                && matches!(&**second, Expr::Array(a) if a.elems.iter().all(|e| matches!(e, Some(ExprOrSpread { spread: None, expr }) if matches!(&**expr, Expr::Ident(..)))))
+        }
+        _ => false,
+    }
+}
+
+/// Returns true if `e` may be printed on the same line as `(` of calls, with
+/// line breaks inside of it.
+fn could_group(e: &Expr) -> bool {
+    match e {
+        Expr::Object(o) => !o.props.is_empty(),
+        Expr::Array(a) => !a.elems.is_empty(),
+        Expr::TsTypeAssertion(TsTypeAssertion { expr, .. }) | Expr::TsAs(TsAsExpr { expr, .. }) => {
+            could_group(expr)
+        }
+        Expr::Fn(..) => true,
+        Expr::Arrow(a) => match &a.body {
+            BlockStmtOrExpr::BlockStmt(..) => true,
+            BlockStmtOrExpr::Expr(body) => matches!(
+                unparen(body),
+                Expr::Arrow(..)
+                    | Expr::Object(..)
+                    | Expr::Array(..)
+                    | Expr::TsAs(..)
+                    | Expr::Call(..)
+                    | Expr::New(..)
+                    | Expr::Cond(..)
+                    | Expr::JSXElement(..)
+                    | Expr::JSXFragment(..)
+            ),
+        },
+        _ => false,
+    }
+}
+
+fn should_group_last(args: &[ExprOrSpread]) -> bool {
+    let (last, rest) = match args.split_last() {
+        Some((last, rest)) if last.spread.is_none() => (&*last.expr, rest),
+        _ => return false,
+    };
+    let penultimate = rest.last().map(|a| &*a.expr);
+    if !could_group(last) {
+        return false;
+    }
+    if let Some(penultimate) = penultimate {
+        if std::mem::discriminant(penultimate) == std::mem::discriminant(last) {
+            return false;
+        }
+        if args.len() == 2
+            && matches!(penultimate, Expr::Arrow(..) | Expr::Fn(..))
+            && matches!(last, Expr::Arrow(..))
+        {
+            return false;
+        }
+        if let Expr::Array(a) = last {
+            let concise = a.elems.iter().all(|e| {
+                matches!(e, Some(ExprOrSpread { spread: None, expr }) if matches!(&**expr, Expr::Lit(Lit::Num(..))))
+            });
+            if concise {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+fn should_group_first(args: &[ExprOrSpread]) -> bool {
+    let (first, second) = match args {
+        [first, second] if first.spread.is_none() && second.spread.is_none() => {
+            (&*first.expr, &*second.expr)
+        }
+        _ => return false,
+    };
+    let first_ok = match first {
+        Expr::Fn(..) => true,
+        Expr::Arrow(a) => matches!(a.body, BlockStmtOrExpr::BlockStmt(..)),
+        _ => false,
+    };
+    first_ok
+        && !matches!(second, Expr::Fn(..) | Expr::Arrow(..) | Expr::Cond(..))
+        && !could_group(second)
+}
+
+/// Escapes a string value, for strings without source code.
+fn escape(value: &str) -> String {
+    let mut s = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => s.push_str("\\\\"),
+            '\n' => s.push_str("\\n"),
+            '\r' => s.push_str("\\r"),
+            '\t' => s.push_str("\\t"),
+            '\u{2028}' => s.push_str("\\u2028"),
+            '\u{2029}' => s.push_str("\\u2029"),
+            c => s.push(c),
+        }
+    }
+    s
+}
+
+/// Wraps raw `content` of a string literal with `quote`, escaping quotes if
+/// required and removing unnecessary escapes.
+fn make_string(content: &str, quote: char) -> String {
+    let other = if quote == '"' { '\'' } else { '"' };
+
+    let mut s = String::with_capacity(content.len() + 2);
+    s.push(quote);
+    let mut chars = content.chars();
+    while let Some(c) = chars.next() {
+        if c == quote {
+            s.push('\\');
+            s.push(c);
+            continue;
+        }
+        if c != '\\' {
+            s.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some(c) if c == other => s.push(c),
+            Some(c) if c == quote => {
+                s.push('\\');
+                s.push(c);
+            }
+            Some(c) => {
+                let needed = matches!(
+                    c,
+                    '\n' | '\r'
+                        | '"'
+                        | '\''
+                        | '0'..='7'
+                        | '\\'
+                        | 'b'
+                        | 'f'
+                        | 'n'
+                        | 'r'
+                        | 't'..='v'
+                        | 'x'
+                        | '\u{2028}'
+                        | '\u{2029}'
+                );
+                if needed {
+                    s.push('\\');
+                }
+                s.push(c);
+            }
+            None => s.push('\\'),
+        }
+    }
+    s.push(quote);
+    s
+}
+
+/// Normalizes a number literal like Prettier does: lowercase letters, no
+/// trailing dots and no extra zeros.
+fn normalize_number(raw: &str) -> String {
+    let raw = raw.to_ascii_lowercase();
+    let is_decimal = !raw.starts_with("0x")
+        && !raw.starts_with("0o")
+        && !raw.starts_with("0b")
+        && raw
+            .chars()
+            .all(|c| c.is_ascii_digit() || matches!(c, '.' | 'e' | '+' | '-'));
+    if !is_decimal {
+        return raw;
+    }
+
+    let (mantissa, exp) = match raw.find('e') {
+        Some(idx) => (&raw[..idx], Some(&raw[idx + 1..])),
+        None => (&*raw, None),
+    };
+
+    let mut s = String::new();
+    if mantissa.starts_with('.') {
+        s.push('0');
+    }
+    match mantissa.find('.') {
+        Some(idx) => {
+            let fraction = &mantissa[idx + 1..];
+            s.push_str(&mantissa[..idx]);
+            if !fraction.is_empty() {
+                let trimmed = fraction.trim_end_matches('0');
+                s.push('.');
+                s.push_str(if trimmed.is_empty() {
+                    &fraction[..1]
+                } else {
+                    trimmed
+                });
+            }
+        }
+        None => s.push_str(mantissa),
+    }
+
+    if let Some(exp) = exp {
+        let (sign, digits) = match exp.strip_prefix('-') {
+            Some(digits) => ("-", digits),
+            None => ("", exp.strip_prefix('+').unwrap_or(exp)),
+        };
+        let digits = digits.trim_start_matches('0');
+        if !digits.is_empty() {
+            s.push('e');
+            s.push_str(sign);
+            s.push_str(digits);
+        }
+    }
+    s
+}
@@ -0,0 +1,428 @@
+//! Builds [Doc]s from AST nodes.
+
+use crate::{
+    comments::CommentStore,
+    config::{FmtConfig, TrailingComma},
+    doc::*,
+};
+use swc_common::{
+    comments::{Comment, CommentKind, Comments, SingleThreadedComments},
+    sync::Lrc,
+    BytePos, SourceFile, SourceMap, Span, Spanned,
+};
+use swc_ecma_ast::TplElement;
+use swc_ecma_codegen::{text_writer::JsWriter, Emitter, Node};
+
+mod decl;
+mod expr;
+mod stmt;
+mod typescript;
+
+pub(crate) struct Printer<'a> {
+    cm: Lrc<SourceMap>,
+    fm: &'a SourceFile,
+    config: &'a FmtConfig,
+    comments: CommentStore,
+}
+
+impl<'a> Printer<'a> {
+    pub fn new(
+        cm: Lrc<SourceMap>,
+        fm: &'a SourceFile,
+        comments: &SingleThreadedComments,
+        config: &'a FmtConfig,
+    ) -> Self {
+        Printer {
+            cm,
+            fm,
+            config,
+            comments: CommentStore::new(comments),
+        }
+    }
+
+    /// Source code of `lo..hi`, or an empty string if the range is not in the
+    /// file.
+    fn src(&self, lo: BytePos, hi: BytePos) -> &'a str {
+        let start = self.fm.start_pos;
+        if lo < start || hi > self.fm.end_pos || hi < lo {
+            return "";
+        }
+        let src: &'a str = &self.fm.src;
+        src.get((lo - start).0 as usize..(hi - start).0 as usize)
+            .unwrap_or("")
+    }
+
+    fn snippet(&self, span: Span) -> &'a str {
+        self.src(span.lo, span.hi)
+    }
+
+    /// Source code of a quasi of a template literal.
+    ///
+    /// `raw` of a quasi differs from the source code if it contains escapes
+    /// (e.g. `\x42` is stored as `\0x42`), so it's only used for quasis which
+    /// are not in the file.
+    fn quasi(&self, quasi: &TplElement) -> String {
+        if quasi.span.is_dummy() {
+            return quasi.raw.value.to_string();
+        }
+        self.snippet(quasi.span).to_string()
+    }
+
+    fn has_newline(&self, lo: BytePos, hi: BytePos) -> bool {
+        self.src(lo, hi).contains('\n')
+    }
+
+    /// Returns true if there's an empty line in `lo..hi`.
+    fn has_blank_line(&self, lo: BytePos, hi: BytePos) -> bool {
+        let mut blank = false;
+        for (i, line) in self.src(lo, hi).split('\n').enumerate() {
+            if i != 0 && blank {
+                return true;
+            }
+            blank = i != 0 && line.trim().is_empty();
+        }
+        false
+    }
+
+    /// Number of line breaks in whitespaces after `pos`.
+    fn newlines_after(&self, pos: BytePos) -> usize {
+        let src = self.src(pos, self.fm.end_pos);
+        src.chars()
+            .take_while(|c| c.is_whitespace())
+            .filter(|&c| c == '\n')
+            .count()
+    }
+
+    /// Returns true if there's a line break in whitespaces before `pos`.
+    fn newline_before(&self, pos: BytePos) -> bool {
+        let src = self.src(self.fm.start_pos, pos);
+        src.chars()
+            .rev()
+            .take_while(|c| c.is_whitespace())
+            .any(|c| c == '\n')
+    }
+
+    /// End of a statement ending at `hi`, excluding a semicolon on a later
+    /// line and comments before it. Such a semicolon is printed before
+    /// statements like `;(a)` if `semi` is false.
+    fn end_of_code(&self, hi: BytePos) -> BytePos {
+        let semi = hi - BytePos(1);
+        if self.src(semi, hi) != ";" {
+            return hi;
+        }
+        let mut end = semi;
+        loop {
+            let code = self.src(self.fm.start_pos, end).trim_end();
+            end = self.fm.start_pos + BytePos(code.len() as u32);
+            match self.comments.start_of(semi, end) {
+                Some(lo) => end = lo,
+                None => break,
+            }
+        }
+        if self.has_newline(end, semi) {
+            end
+        } else {
+            hi
+        }
+    }
+
+    fn comment(&self, c: &Comment) -> Doc {
+        match c.kind {
+            CommentKind::Line => text(format!("//{}", c.text.trim_end())),
+            CommentKind::Block => {
+                let is_doc = {
+                    let s = format!("*{}*", c.text);
+                    s.contains('\n') && s.lines().all(|l| l.trim_start().starts_with('*'))
+                };
+                if !is_doc {
+                    return text(format!("/*{}*/", c.text));
+                }
+
+                // Reindents lines of JSDoc-like comments.
+                let lines: Vec<_> = c.text.split('\n').collect();
+                let last = lines.len() - 1;
+                let lines = lines.iter().enumerate().map(|(i, l)| {
+                    text(match i {
+                        0 => l.trim_end().to_string(),
+                        _ if i < last => format!(" {}", l.trim()),
+                        _ => format!(" {}", l.trim_start()),
+                    })
+                });
+                concat(vec!["/*".into(), join(hardline(), lines), "*/".into()])
+            }
+        }
+    }
+
+    /// Prints comments before `pos` on their own lines, or followed by a space
+    /// if they were on the same line.
+    fn leading_comments(&mut self, pos: BytePos) -> Doc {
+        let comments = self.comments.take(pos, false);
+        let mut parts = vec![];
+        for c in &comments {
+            parts.push(self.comment(c));
+            let newlines = self.newlines_after(c.span.hi);
+            if c.kind == CommentKind::Line || newlines > 0 {
+                parts.push(hardline());
+                if newlines > 1 {
+                    parts.push(hardline());
+                }
+            } else {
+                parts.push(" ".into());
+            }
+        }
+        concat(parts)
+    }
+
+    /// Prints comments before `pos` after the previous code.
+    fn trailing_comments(&mut self, pos: BytePos) -> Doc {
+        let comments = self.comments.take(pos, false);
+        self.print_trailing(&comments)
+    }
+
+    /// Like [Printer::trailing_comments], but only for comments on the same
+    /// line as the previous token.
+    fn same_line_comments(&mut self, pos: BytePos) -> Doc {
+        let comments = self.comments.take(pos, true);
+        self.print_trailing(&comments)
+    }
+
+    fn print_trailing(&self, comments: &[Comment]) -> Doc {
+        let mut parts = vec![];
+        let mut after_line_comment = false;
+        for c in comments {
+            let doc = self.comment(c);
+            // A comment after a line comment should be on its own line, even if
+            // it was after other code, which is not printed there.
+            if after_line_comment || self.newline_before(c.span.lo) {
+                parts.push(line_suffix(vec![hardline(), doc]));
+                parts.push(Doc::BreakParent);
+            } else if c.kind == CommentKind::Line {
+                parts.push(line_suffix(vec![" ".into(), doc]));
+                parts.push(Doc::BreakParent);
+            } else {
+                parts.push(concat(vec![" ".into(), doc]));
+            }
+            after_line_comment = c.kind == CommentKind::Line;
+        }
+        concat(parts)
+    }
+
+    /// Prints comments before a closing bracket at `pos`. Comments on their
+    /// own lines are preceded by a line break, so the doc should be followed
+    /// by a line.
+    fn dangling_comments(&mut self, pos: BytePos, after_items: bool) -> Doc {
+        let comments = self.comments.take(pos, false);
+        let mut parts = vec![];
+        let mut after_line_comment = false;
+        for (i, c) in comments.iter().enumerate() {
+            let doc = self.comment(c);
+            if after_line_comment || self.newline_before(c.span.lo) {
+                parts.push(hardline());
+                parts.push(doc);
+            } else if c.kind == CommentKind::Line {
+                parts.push(line_suffix(vec![" ".into(), doc]));
+                parts.push(Doc::BreakParent);
+            } else {
+                if after_items || i != 0 {
+                    parts.push(" ".into());
+                }
+                parts.push(doc);
+            }
+            after_line_comment = c.kind == CommentKind::Line;
+        }
+        concat(parts)
+    }
+
+    /// Prints `items` with comments around them. Each doc should be joined
+    /// with `,` and a line.
+    ///
+    /// If `keep_blank` is true, an empty line between items is preserved when
+    /// the list is broken.
+    fn comma_separated<T: Spanned>(
+        &mut self,
+        items: &[T],
+        keep_blank: bool,
+        mut op: impl FnMut(&mut Self, &T) -> Doc,
+    ) -> Vec<Doc> {
+        let mut docs: Vec<Doc> = vec![];
+        let mut prev_hi = None;
+        for item in items {
+            let span = item.span();
+            let mut blank = false;
+            if let Some(prev) = docs.last_mut() {
+                let comments = self.same_line_comments(span.lo);
+                if !is_nil(&comments) {
+                    *prev = concat(vec![std::mem::take(prev), comments]);
+                }
+                let start = self.comments.peek(span.lo).unwrap_or(span.lo);
+                blank = keep_blank && prev_hi.map_or(false, |hi| self.has_blank_line(hi, start));
+            }
+            let leading = self.leading_comments(span.lo);
+            let doc = op(self, item);
+            let trailing = self.trailing_comments(span.hi);
+            let sep = if blank { softline() } else { Doc::default() };
+            docs.push(concat(vec![sep, leading, doc, trailing]));
+            prev_hi = Some(span.hi);
+        }
+        docs
+    }
+
+    /// Prints `items` wrapped with brackets. The list is broken after `open`
+    /// if it does not fit.
+    #[allow(clippy::too_many_arguments)]
+    fn bracketed(
+        &mut self,
+        open: &'static str,
+        items: Vec<Doc>,
+        trailing_comma: Doc,
+        close_pos: BytePos,
+        close: &'static str,
+        spaces: bool,
+        should_break: bool,
+    ) -> Doc {
+        let dangling = self.dangling_comments(close_pos, !items.is_empty());
+        if items.is_empty() && is_nil(&dangling) {
+            return text(format!("{}{}", open, close));
+        }
+        // Dangling comments are printed right after `open` if there's no item.
+        if items.is_empty() {
+            return group(vec![
+                open.into(),
+                indent(dangling),
+                softline(),
+                close.into(),
+            ]);
+        }
+        let sep = if spaces { line() } else { softline() };
+
+        group_with_break(
+            vec![
+                open.into(),
+                indent(vec![
+                    sep.clone(),
+                    join(concat(vec![",".into(), line()]), items),
+                    trailing_comma,
+                    dangling,
+                ]),
+                sep,
+                close.into(),
+            ],
+            should_break,
+        )
+    }
+
+    /// A trailing comma printed if the list is broken and `level` is enabled.
+    fn trailing_comma(&self, level: TrailingComma) -> Doc {
+        let enabled = !matches!(
+            (self.config.trailing_comma, level),
+            (TrailingComma::None, _) | (TrailingComma::Es5, TrailingComma::All)
+        );
+        if enabled {
+            if_break(",", "")
+        } else {
+            Doc::default()
+        }
+    }
+
+    /// Prints statement-like `items` on their own lines, preserving an empty
+    /// line between them. The doc starts with a line break if `first_break`
+    /// is true.
+    fn lines<T: Spanned>(
+        &mut self,
+        items: &[T],
+        first_break: bool,
+        skip: impl Fn(&T) -> bool,
+        mut op: impl FnMut(&mut Self, &T, Option<&T>) -> Doc,
+    ) -> Doc {
+        let items: Vec<_> = items.iter().filter(|item| !skip(item)).collect();
+        let mut parts = vec![];
+        let mut prev_hi = None;
+        for (i, item) in items.iter().enumerate() {
+            let span = item.span();
+            parts.push(self.same_line_comments(span.lo));
+            if let Some(prev_hi) = prev_hi {
+                parts.push(hardline());
+                let start = self.comments.peek(span.lo).unwrap_or(span.lo);
+                if self.has_blank_line(prev_hi, start) {
+                    parts.push(hardline());
+                }
+            } else if first_break {
+                parts.push(hardline());
+            }
+            parts.push(self.leading_comments(span.lo));
+            parts.push(op(self, item, items.get(i + 1).copied()));
+            let hi = self.end_of_code(span.hi);
+            parts.push(self.trailing_comments(hi));
+            prev_hi = Some(hi);
+        }
+        concat(parts)
+    }
+
+    /// Wraps `body` printed by [Printer::lines] with braces. `span` is the span
+    /// of the braces.
+    fn braces(&mut self, span: Span, body: Doc) -> Doc {
+        let dangling = self.dangling_comments(span.hi - BytePos(1), !is_nil(&body));
+        if is_nil(&body) && is_nil(&dangling) {
+            return "{}".into();
+        }
+        concat(vec![
+            "{".into(),
+            indent(vec![body, dangling]),
+            hardline(),
+            "}".into(),
+        ])
+    }
+
+    fn semi(&self) -> Doc {
+        if self.config.semi {
+            ";".into()
+        } else {
+            Doc::default()
+        }
+    }
+
+    /// Prints a node which is not supported by the formatter, like JSX, with
+    /// the code generator.
+    fn fallback<N: Node>(&mut self, node: &N) -> Doc {
+        let span = node.span();
+        let comments = SingleThreadedComments::default();
+        for (pos, trailing, c) in self.comments.take_range(span.lo, span.hi) {
+            if trailing {
+                comments.add_trailing(pos, c);
+            } else {
+                comments.add_leading(pos, c);
+            }
+        }
+
+        let mut buf = vec![];
+        {
+            let mut emitter = Emitter {
+                cfg: Default::default(),
+                cm: self.cm.clone(),
+                comments: Some(&comments),
+                wr: Box::new(JsWriter::new(self.cm.clone(), "\n", &mut buf, None)),
+            };
+            if node.emit_with(&mut emitter).is_err() {
+                // Writing to a vector does not fail.
+                unreachable!("failed to print a node")
+            }
+        }
+
+        let (leading, trailing) = comments.borrow_all();
+        for c in leading.values().chain(trailing.values()).flatten() {
+            self.comments.restore(c);
+        }
+
+        text(String::from_utf8_lossy(&buf).into_owned())
+    }
+}
+
+/// Returns true if `doc` prints nothing.
+fn is_nil(doc: &Doc) -> bool {
+    match doc {
+        Doc::Text(s) => s.is_empty(),
+        Doc::Concat(docs) => docs.iter().all(is_nil),
+        Doc::Indent(doc) => is_nil(doc),
+        _ => false,
+    }
+}
@@ -0,0 +1,650 @@
+use super::{expr::needs_parens, is_nil, Printer};
+use crate::doc::*;
+use swc_common::{BytePos, Spanned};
+use swc_ecma_ast::*;
+
+impl Printer<'_> {
+    pub fn program(&mut self, program: &Program) -> Doc {
+        let (shebang, body) = match program {
+            Program::Module(m) => (
+                m.shebang.as_ref(),
+                self.lines(
+                    &m.body,
+                    false,
+                    |item| matches!(item, ModuleItem::Stmt(Stmt::Empty(..))),
+                    |p, item, _| p.module_item(item),
+                ),
+            ),
+            Program::Script(s) => (
+                s.shebang.as_ref(),
+                self.lines(&s.body, false, is_empty_stmt, |p, stmt, _| p.stmt(stmt)),
+            ),
+        };
+
+        let mut parts = vec![];
+        if let Some(shebang) = shebang {
+            parts.push(text(format!("#!{}", shebang)));
+            parts.push(hardline());
+        }
+        let mut empty = is_nil(&body);
+        parts.push(body);
+
+        // Comments after the last statement.
+        let mut prev_hi = None;
+        for c in self.comments.take(BytePos(u32::MAX), false) {
+            if !empty {
+                if self.newline_before(c.span.lo) {
+                    parts.push(hardline());
+                    if prev_hi.map_or(false, |hi| self.has_blank_line(hi, c.span.lo)) {
+                        parts.push(hardline());
+                    }
+                } else {
+                    parts.push(" ".into());
+                }
+            }
+            parts.push(self.comment(&c));
+            prev_hi = Some(c.span.hi);
+            empty = false;
+        }
+
+        if !empty || shebang.is_some() {
+            parts.push(hardline());
+        }
+        concat(parts)
+    }
+
+    fn module_item(&mut self, item: &ModuleItem) -> Doc {
+        match item {
+            ModuleItem::ModuleDecl(decl) => self.module_decl(decl),
+            ModuleItem::Stmt(stmt) => self.stmt(stmt),
+        }
+    }
+
+    pub(super) fn module_items(&mut self, items: &[ModuleItem]) -> Doc {
+        self.lines(
+            items,
+            true,
+            |item| matches!(item, ModuleItem::Stmt(Stmt::Empty(..))),
+            |p, item, _| p.module_item(item),
+        )
+    }
+
+    pub(super) fn stmt(&mut self, stmt: &Stmt) -> Doc {
+        match stmt {
+            Stmt::Block(b) => self.block(b),
+            Stmt::Empty(..) => ";".into(),
+            Stmt::Debugger(..) => concat(vec!["debugger".into(), self.semi()]),
+            Stmt::With(w) => {
+                let body = self.clause(&w.body);
+                group(vec!["with (".into(), self.expr(&w.obj), ")".into(), body])
+            }
+            Stmt::Return(r) => self.return_like("return", r.arg.as_deref()),
+            Stmt::Labeled(l) => {
+                let body = match &*l.body {
+                    Stmt::Empty(..) => ";".into(),
+                    body => concat(vec![" ".into(), self.nested_stmt(body)]),
+                };
+                concat(vec![text(format!("{}:", l.label.sym)), body])
+            }
+            Stmt::Break(b) => self.jump("break", b.label.as_ref()),
+            Stmt::Continue(c) => self.jump("continue", c.label.as_ref()),
+            Stmt::If(i) => self.if_stmt(i),
+            Stmt::Switch(s) => self.switch(s),
+            Stmt::Throw(t) => self.return_like("throw", Some(&t.arg)),
+            Stmt::Try(t) => self.try_stmt(t),
+            Stmt::While(w) => {
+                let test = self.test(&w.test);
+                let body = self.clause(&w.body);
+                group(vec!["while (".into(), test, ")".into(), body])
+            }
+            Stmt::DoWhile(d) => {
+                let body = group(self.clause(&d.body));
+                let sep = match &*d.body {
+                    Stmt::Block(..) => " ".into(),
+                    _ => hardline(),
+                };
+                concat(vec![
+                    "do".into(),
+                    body,
+                    sep,
+                    "while (".into(),
+                    self.test(&d.test),
+                    ")".into(),
+                    self.semi(),
+                ])
+            }
+            Stmt::For(f) => self.for_stmt(f),
+            Stmt::ForIn(f) => self.for_in_of(false, " in ", &f.left, &f.right, &f.body),
+            Stmt::ForOf(f) => {
+                self.for_in_of(f.await_token.is_some(), " of ", &f.left, &f.right, &f.body)
+            }
+            Stmt::Decl(d) => self.decl(d),
+            Stmt::Expr(e) => self.expr_stmt(e, true),
+        }
+    }
+
+    pub(super) fn block(&mut self, b: &BlockStmt) -> Doc {
+        let body = self.lines(&b.stmts, true, is_empty_stmt, |p, s, _| p.stmt(s));
+        self.braces(b.span, body)
+    }
+
+    /// Body of statements like `if` and `while`, which should be in a group.
+    fn clause(&mut self, body: &Stmt) -> Doc {
+        match body {
+            Stmt::Block(b) => concat(vec![" ".into(), self.block(b)]),
+            Stmt::Empty(..) => ";".into(),
+            _ => {
+                let leading = self.leading_comments(body.span().lo);
+                indent(vec![line(), leading, self.nested_stmt(body)])
+            }
+        }
+    }
+
+    /// A statement which is not in a list of statements, so it does not need
+    /// a semicolon before it even if `semi` is false.
+    fn nested_stmt(&mut self, s: &Stmt) -> Doc {
+        match s {
+            Stmt::Expr(e) => self.expr_stmt(e, false),
+            _ => self.stmt(s),
+        }
+    }
+
+    /// Test of statements like `if`, in parentheses which are not printed.
+    fn test(&mut self, test: &Expr) -> Doc {
+        let doc = match test {
+            Expr::Bin(b) => {
+                let leading = self.leading_comments(b.span.lo);
+                concat(vec![leading, concat(self.bin_parts(b))])
+            }
+            _ => self.expr(test),
+        };
+        group(vec![indent(vec![softline(), doc]), softline()])
+    }
+
+    fn return_like(&mut self, keyword: &'static str, arg: Option<&Expr>) -> Doc {
+        let arg = match arg {
+            Some(arg) => arg,
+            None => return concat(vec![keyword.into(), self.semi()]),
+        };
+
+        let doc = match arg {
+            Expr::Bin(..) | Expr::Seq(..) => {
+                let leading = self.leading_comments(arg.span().lo);
+                let doc = match arg {
+                    Expr::Bin(b) => concat(self.bin_parts(b)),
+                    _ => self.expr(arg),
+                };
+                group(vec![
+                    if_break("(", ""),
+                    indent(vec![softline(), leading, doc]),
+                    softline(),
+                    if_break(")", ""),
+                ])
+            }
+            _ => self.expr(arg),
+        };
+        concat(vec![keyword.into(), " ".into(), doc, self.semi()])
+    }
+
+    fn jump(&mut self, keyword: &'static str, label: Option<&Ident>) -> Doc {
+        let label = match label {
+            Some(label) => text(format!(" {}", label.sym)),
+            None => Doc::default(),
+        };
+        concat(vec![keyword.into(), label, self.semi()])
+    }
+
+    fn if_stmt(&mut self, i: &IfStmt) -> Doc {
+        let test = self.test(&i.test);
+        let cons = self.clause(&i.cons);
+        let mut parts = vec![group(vec!["if (".into(), test, ")".into(), cons])];
+
+        if let Some(alt) = &i.alt {
+            parts.push(match &*i.cons {
+                Stmt::Block(..) => " ".into(),
+                _ => hardline(),
+            });
+            parts.push("else".into());
+            let alt = match &**alt {
+                Stmt::If(..) => {
+                    let leading = self.leading_comments(alt.span().lo);
+                    concat(vec![" ".into(), leading, self.stmt(alt)])
+                }
+                _ => self.clause(alt),
+            };
+            parts.push(group(alt));
+        }
+        concat(parts)
+    }
+
+    fn switch(&mut self, s: &SwitchStmt) -> Doc {
+        let discriminant = self.test(&s.discriminant);
+        let cases = self.lines(
+            &s.cases,
+            true,
+            |_| false,
+            |p, case, _| {
+                let head = match &case.test {
+                    Some(test) => concat(vec!["case ".into(), p.expr(test), ":".into()]),
+                    None => "default:".into(),
+                };
+                let stmts: Vec<_> = case.cons.iter().filter(|s| !is_empty_stmt(s)).collect();
+                match &*stmts {
+                    [] => head,
+                    [Stmt::Block(b)] => concat(vec![head, " ".into(), p.block(b)]),
+                    _ => {
+                        let body = p.lines(&case.cons, true, is_empty_stmt, |p, s, _| p.stmt(s));
+                        concat(vec![head, indent(body)])
+                    }
+                }
+            },
+        );
+
+        concat(vec![
+            group(vec!["switch (".into(), discriminant, ") ".into()]),
+            self.braces(s.span, cases),
+        ])
+    }
+
+    fn try_stmt(&mut self, t: &TryStmt) -> Doc {
+        let mut parts = vec!["try ".into(), self.block(&t.block)];
+        if let Some(handler) = &t.handler {
+            parts.push(" catch".into());
+            if let Some(param) = &handler.param {
+                parts.push(" (".into());
+                parts.push(self.pat(param));
+                parts.push(")".into());
+            }
+            parts.push(" ".into());
+            parts.push(self.block(&handler.body));
+        }
+        if let Some(finalizer) = &t.finalizer {
+            parts.push(" finally ".into());
+            parts.push(self.block(finalizer));
+        }
+        concat(parts)
+    }
+
+    fn for_stmt(&mut self, f: &ForStmt) -> Doc {
+        if f.init.is_none() && f.test.is_none() && f.update.is_none() {
+            let body = self.clause(&f.body);
+            return group(vec!["for (;;)".into(), body]);
+        }
+
+        let init = match &f.init {
+            Some(VarDeclOrExpr::VarDecl(v)) => self.var_decl(v),
+            Some(VarDeclOrExpr::Expr(e)) => self.expr(e),
+            None => Doc::default(),
+        };
+        let test = match &f.test {
+            Some(test) => self.expr(test),
+            None => Doc::default(),
+        };
+        let update = match &f.update {
+            Some(update) => self.expr(update),
+            None => Doc::default(),
+        };
+        let body = self.clause(&f.body);
+
+        group(vec![
+            "for (".into(),
+            group(vec![
+                indent(vec![
+                    softline(),
+                    init,
+                    ";".into(),
+                    line(),
+                    test,
+                    ";".into(),
+                    line(),
+                    update,
+                ]),
+                softline(),
+            ]),
+            ")".into(),
+            body,
+        ])
+    }
+
+    fn for_in_of(
+        &mut self,
+        is_await: bool,
+        op: &'static str,
+        left: &VarDeclOrPat,
+        right: &Expr,
+        body: &Stmt,
+    ) -> Doc {
+        let left = match left {
+            VarDeclOrPat::VarDecl(v) => self.var_decl(v),
+            VarDeclOrPat::Pat(p) => self.pat(p),
+        };
+        let right = self.expr(right);
+        let body = self.clause(body);
+
+        group(vec![
+            if is_await { "for await (" } else { "for (" }.into(),
+            left,
+            op.into(),
+            right,
+            ")".into(),
+            body,
+        ])
+    }
+
+    fn expr_stmt(&mut self, e: &ExprStmt, in_list: bool) -> Doc {
+        let doc = match &*e.expr {
+            Expr::Lit(Lit::Str(s)) => self.directive(s),
+            expr => self.expr(expr),
+        };
+        let guard = if in_list && !self.config.semi && starts_with_hazard(&e.expr) {
+            ";".into()
+        } else {
+            Doc::default()
+        };
+        concat(vec![guard, doc, self.semi()])
+    }
+
+    /// Directives like `"use strict"` are kept as is, except for quotes.
+    fn directive(&mut self, s: &Str) -> Doc {
+        let raw = self.snippet(s.span);
+        if raw.len() < 2 {
+            return self.str_lit(s);
+        }
+        let content = &raw[1..raw.len() - 1];
+        if content.contains(&['"', '\''][..]) {
+            return text(raw.to_string());
+        }
+        let quote = if self.config.single_quote { '\'' } else { '"' };
+        text(format!("{}{}{}", quote, content, quote))
+    }
+
+    fn decl(&mut self, decl: &Decl) -> Doc {
+        match decl {
+            Decl::Class(c) => concat(vec![
+                declare(c.declare),
+                self.class(Some(&c.ident), &c.class, true),
+            ]),
+            Decl::Fn(f) => concat(vec![
+                declare(f.declare),
+                self.function(Some(&f.ident), &f.function),
+            ]),
+            Decl::Var(v) => concat(vec![self.var_decl(v), self.semi()]),
+            Decl::TsInterface(i) => self.interface(i),
+            Decl::TsTypeAlias(t) => self.type_alias(t),
+            Decl::TsEnum(e) => self.ts_enum(e),
+            Decl::TsModule(m) => self.ts_module(m),
+        }
+    }
+
+    /// Variable declarations without a semicolon.
+    pub(super) fn var_decl(&mut self, v: &VarDecl) -> Doc {
+        let has_init = v.decls.iter().any(|d| d.init.is_some());
+        let mut decls = self
+            .comma_separated(&v.decls, false, |p, d| p.var_declarator(d))
+            .into_iter();
+
+        let mut parts = vec![declare(v.declare), v.kind.as_str().into()];
+        if let Some(first) = decls.next() {
+            parts.push(" ".into());
+            parts.push(first);
+        }
+        let rest: Vec<_> = decls
+            .map(|d| {
+                concat(vec![
+                    ",".into(),
+                    if has_init { hardline() } else { line() },
+                    d,
+                ])
+            })
+            .collect();
+        parts.push(indent(rest));
+
+        group(parts)
+    }
+
+    fn var_declarator(&mut self, d: &VarDeclarator) -> Doc {
+        let left = match &d.name {
+            Pat::Ident(i) if d.definite => concat(vec![
+                text(format!("{}!", i.id.sym)),
+                self.type_ann(i.type_ann.as_ref()),
+            ]),
+            name => self.pat(name),
+        };
+        match &d.init {
+            Some(init) => self.assignment(left, " =", init, false),
+            None => left,
+        }
+    }
+
+    fn module_decl(&mut self, decl: &ModuleDecl) -> Doc {
+        match decl {
+            ModuleDecl::Import(i) => self.import(i),
+            ModuleDecl::ExportDecl(e) => match &e.decl {
+                Decl::Class(c)
+                    if c.class
+                        .decorators
+                        .first()
+                        .map_or(false, |d| d.span.lo < e.span.lo)
+                        || self.snippet(e.span).starts_with('@') =>
+                {
+                    let decorators = self.decorators(&c.class.decorators);
+                    concat(vec![
+                        decorators,
+                        "export ".into(),
+                        declare(c.declare),
+                        self.class(Some(&c.ident), &c.class, false),
+                    ])
+                }
+                decl => concat(vec!["export ".into(), self.decl(decl)]),
+            },
+            ModuleDecl::ExportNamed(n) => self.named_export(n),
+            ModuleDecl::ExportDefaultDecl(d) => {
+                let decl = match &d.decl {
+                    DefaultDecl::Class(c) => self.class(c.ident.as_ref(), &c.class, true),
+                    DefaultDecl::Fn(f) => self.function(f.ident.as_ref(), &f.function),
+                    DefaultDecl::TsInterfaceDecl(i) => self.interface(i),
+                };
+                concat(vec!["export default ".into(), decl])
+            }
+            ModuleDecl::ExportDefaultExpr(e) => concat(vec![
+                "export default ".into(),
+                self.expr(&e.expr),
+                self.semi(),
+            ]),
+            ModuleDecl::ExportAll(e) => concat(vec![
+                "export * from ".into(),
+                self.str_lit(&e.src),
+                self.asserts(e.asserts.as_ref()),
+                self.semi(),
+            ]),
+            ModuleDecl::TsImportEquals(i) => {
+                let module_ref = match &i.module_ref {
+                    TsModuleRef::TsEntityName(n) => self.entity_name(n),
+                    TsModuleRef::TsExternalModuleRef(r) => {
+                        concat(vec!["require(".into(), self.str_lit(&r.expr), ")".into()])
+                    }
+                };
+                concat(vec![
+                    if i.is_export { "export " } else { "" }.into(),
+                    "import ".into(),
+                    if i.is_type_only { "type " } else { "" }.into(),
+                    text(format!("{} = ", i.id.sym)),
+                    module_ref,
+                    self.semi(),
+                ])
+            }
+            ModuleDecl::TsExportAssignment(e) => {
+                concat(vec!["export = ".into(), self.expr(&e.expr), self.semi()])
+            }
+            ModuleDecl::TsNamespaceExport(e) => concat(vec![
+                text(format!("export as namespace {}", e.id.sym)),
+                self.semi(),
+            ]),
+        }
+    }
+
+    fn import(&mut self, i: &ImportDecl) -> Doc {
+        let mut parts = vec![if i.type_only {
+            "import type "
+        } else {
+            "import "
+        }
+        .into()];
+
+        if i.specifiers.is_empty() {
+            // `import type {} from "a"` is not the same as `import type "a"`.
+            if self.src(i.span.lo, i.src.span.lo).contains('{') {
+                parts.push("{} from ".into());
+            }
+        } else {
+            let mut heads = vec![];
+            let mut named = vec![];
+            for s in &i.specifiers {
+                match s {
+                    ImportSpecifier::Default(d) => heads.push(text(d.local.sym.to_string())),
+                    ImportSpecifier::Namespace(n) => {
+                        heads.push(text(format!("* as {}", n.local.sym)))
+                    }
+                    ImportSpecifier::Named(n) => named.push(n),
+                }
+            }
+            if !named.is_empty() {
+                let docs = self.comma_separated(&named, false, |_, n| match &n.imported {
+                    Some(imported) => text(format!("{} as {}", imported.sym, n.local.sym)),
+                    None => text(n.local.sym.to_string()),
+                });
+                let trailing_comma = self.trailing_comma(crate::TrailingComma::Es5);
+                heads.push(self.bracketed(
+                    "{",
+                    docs,
+                    trailing_comma,
+                    i.src.span.lo - BytePos(1),
+                    "}",
+                    true,
+                    false,
+                ));
+            }
+            parts.push(join(", ".into(), heads));
+            parts.push(" from ".into());
+        }
+
+        parts.push(self.str_lit(&i.src));
+        parts.push(self.asserts(i.asserts.as_ref()));
+        parts.push(self.semi());
+        concat(parts)
+    }
+
+    fn named_export(&mut self, n: &NamedExport) -> Doc {
+        let mut heads = vec![];
+        let mut named = vec![];
+        for s in &n.specifiers {
+            match s {
+                ExportSpecifier::Namespace(ns) => heads.push(text(format!("* as {}", ns.name.sym))),
+                ExportSpecifier::Default(d) => heads.push(text(d.exported.sym.to_string())),
+                ExportSpecifier::Named(s) => named.push(s),
+            }
+        }
+
+        let close = match &n.src {
+            Some(src) => src.span.lo,
+            None => n.span.hi,
+        };
+        if !named.is_empty() || heads.is_empty() {
+            let docs = self.comma_separated(&named, false, |_, s| match &s.exported {
+                Some(exported) => text(format!("{} as {}", s.orig.sym, exported.sym)),
+                None => text(s.orig.sym.to_string()),
+            });
+            let trailing_comma = self.trailing_comma(crate::TrailingComma::Es5);
+            heads.push(self.bracketed(
+                "{",
+                docs,
+                trailing_comma,
+                close - BytePos(1),
+                "}",
+                true,
+                false,
+            ));
+        }
+
+        let mut parts = vec![
+            if n.type_only {
+                "export type "
+            } else {
+                "export "
+            }
+            .into(),
+            join(", ".into(), heads),
+        ];
+        if let Some(src) = &n.src {
+            parts.push(" from ".into());
+            parts.push(self.str_lit(src));
+            parts.push(self.asserts(n.asserts.as_ref()));
+        }
+        parts.push(self.semi());
+        concat(parts)
+    }
+
+    fn asserts(&mut self, asserts: Option<&ObjectLit>) -> Doc {
+        match asserts {
+            Some(asserts) => concat(vec![" assert ".into(), self.object_lit(asserts)]),
+            None => Doc::default(),
+        }
+    }
+}
+
+pub(super) fn is_empty_stmt(s: &Stmt) -> bool {
+    matches!(s, Stmt::Empty(..))
+}
+
+pub(super) fn declare(declare: bool) -> Doc {
+    if declare {
+        "declare ".into()
+    } else {
+        Doc::default()
+    }
+}
+
+/// Returns true if a statement starting with `e` may be parsed as a part of
+/// the previous statement without a semicolon.
+fn starts_with_hazard(e: &Expr) -> bool {
+    match e {
+        Expr::Paren(..)
+        | Expr::Array(..)
+        | Expr::Tpl(..)
+        | Expr::Lit(Lit::Regex(..))
+        | Expr::TsTypeAssertion(..)
+        | Expr::JSXElement(..)
+        | Expr::JSXFragment(..) => true,
+        Expr::Unary(u) => matches!(u.op, op!(unary, "+") | op!(unary, "-")),
+        Expr::Arrow(a) => !a.is_async,
+        Expr::Bin(b) => match &*b.left {
+            Expr::Bin(left) if needs_parens(b.op, left.op) => true,
+            left => starts_with_hazard(left),
+        },
+        Expr::Assign(a) => match &a.left {
+            PatOrExpr::Expr(e) => starts_with_hazard(e),
+            PatOrExpr::Pat(p) => match &**p {
+                Pat::Expr(e) => starts_with_hazard(e),
+                Pat::Array(..) => true,
+                _ => false,
+            },
+        },
+        Expr::Member(MemberExpr {
+            obj: ExprOrSuper::Expr(e),
+            ..
+        })
+        | Expr::Call(CallExpr {
+            callee: ExprOrSuper::Expr(e),
+            ..
+        }) => starts_with_hazard(e),
+        Expr::Cond(c) => starts_with_hazard(&c.test),
+        Expr::Seq(s) => s.exprs.first().map_or(false, |e| starts_with_hazard(e)),
+        Expr::TaggedTpl(t) => starts_with_hazard(&t.tag),
+        Expr::Update(u) => !u.prefix && starts_with_hazard(&u.arg),
+        Expr::TsAs(TsAsExpr { expr, .. })
+        | Expr::TsNonNull(TsNonNullExpr { expr, .. })
+        | Expr::TsConstAssertion(TsConstAssertion { expr, .. })
+        | Expr::OptChain(OptChainExpr { expr, .. }) => starts_with_hazard(expr),
+        _ => false,
+    }
+}
@@ -0,0 +1,604 @@
+use super::{stmt::declare, Printer};
+use crate::{doc::*, TrailingComma};
+use swc_common::{BytePos, Spanned};
+use swc_ecma_ast::*;
+
+impl Printer<'_> {
+    pub(super) fn type_ann(&mut self, t: Option<&TsTypeAnn>) -> Doc {
+        match t {
+            Some(t) => concat(vec![": ".into(), self.ts_type(&t.type_ann)]),
+            None => Doc::default(),
+        }
+    }
+
+    pub(super) fn type_params(&mut self, params: Option<&TsTypeParamDecl>) -> Doc {
+        let params = match params {
+            Some(params) => params,
+            None => return Doc::default(),
+        };
+        let docs = self.comma_separated(&params.params, false, |p, param| p.type_param(param));
+        let trailing_comma = self.trailing_comma(TrailingComma::All);
+        self.bracketed(
+            "<",
+            docs,
+            trailing_comma,
+            params.span.hi - BytePos(1),
+            ">",
+            false,
+            false,
+        )
+    }
+
+    fn type_param(&mut self, param: &TsTypeParam) -> Doc {
+        let mut parts = vec![text(param.name.sym.to_string())];
+        if let Some(constraint) = &param.constraint {
+            parts.push(" extends ".into());
+            parts.push(self.ts_type(constraint));
+        }
+        if let Some(default) = &param.default {
+            parts.push(" = ".into());
+            parts.push(self.ts_type(default));
+        }
+        concat(parts)
+    }
+
+    pub(super) fn type_args(&mut self, args: Option<&TsTypeParamInstantiation>) -> Doc {
+        let args = match args {
+            Some(args) => args,
+            None => return Doc::default(),
+        };
+        let docs = self.comma_separated(&args.params, false, |p, ty| p.ts_type(ty));
+        let trailing_comma = self.trailing_comma(TrailingComma::All);
+        self.bracketed(
+            "<",
+            docs,
+            trailing_comma,
+            args.span.hi - BytePos(1),
+            ">",
+            false,
+            false,
+        )
+    }
+
+    pub(super) fn entity_name(&mut self, n: &TsEntityName) -> Doc {
+        match n {
+            TsEntityName::TsQualifiedName(q) => concat(vec![
+                self.entity_name(&q.left),
+                text(format!(".{}", q.right.sym)),
+            ]),
+            TsEntityName::Ident(i) => text(i.sym.to_string()),
+        }
+    }
+
+    pub(super) fn expr_with_type_args(&mut self, e: &TsExprWithTypeArgs) -> Doc {
+        concat(vec![
+            self.entity_name(&e.expr),
+            self.type_args(e.type_args.as_ref()),
+        ])
+    }
+
+    pub(super) fn ts_type(&mut self, ty: &TsType) -> Doc {
+        let leading = self.leading_comments(ty.span().lo);
+        let doc = match ty {
+            TsType::TsKeywordType(k) => keyword(k.kind).into(),
+            TsType::TsThisType(..) => "this".into(),
+            TsType::TsFnOrConstructorType(TsFnOrConstructorType::TsFnType(f)) => {
+                let type_params = self.type_params(f.type_params.as_ref());
+                let params = self.fn_params(&f.params, f.type_ann.span.lo);
+                let ret = self.ts_type(&f.type_ann.type_ann);
+                group(vec![type_params, params, " => ".into(), ret])
+            }
+            TsType::TsFnOrConstructorType(TsFnOrConstructorType::TsConstructorType(c)) => {
+                let type_params = self.type_params(c.type_params.as_ref());
+                let params = self.fn_params(&c.params, c.type_ann.span.lo);
+                let ret = self.ts_type(&c.type_ann.type_ann);
+                group(vec![
+                    if c.is_abstract {
+                        "abstract new "
+                    } else {
+                        "new "
+                    }
+                    .into(),
+                    type_params,
+                    params,
+                    " => ".into(),
+                    ret,
+                ])
+            }
+            TsType::TsTypeRef(r) => concat(vec![
+                self.entity_name(&r.type_name),
+                self.type_args(r.type_params.as_ref()),
+            ]),
+            TsType::TsTypeQuery(q) => {
+                let name = match &q.expr_name {
+                    TsTypeQueryExpr::TsEntityName(n) => self.entity_name(n),
+                    TsTypeQueryExpr::Import(i) => self.import_type(i),
+                };
+                concat(vec!["typeof ".into(), name])
+            }
+            TsType::TsTypeLit(l) => self.type_members(l.span, &l.members, false),
+            TsType::TsArrayType(a) => concat(vec![self.ts_type(&a.elem_type), "[]".into()]),
+            TsType::TsTupleType(t) => {
+                let docs = self.comma_separated(&t.elem_types, false, |p, elem| {
+                    let ty = p.ts_type(&elem.ty);
+                    match &elem.label {
+                        Some(label) => concat(vec![p.pat(label), ": ".into(), ty]),
+                        None => ty,
+                    }
+                });
+                let trailing_comma = match t.elem_types.last() {
+                    Some(TsTupleElement {
+                        ty: TsType::TsRestType(..),
+                        ..
+                    }) => Doc::default(),
+                    _ => self.trailing_comma(TrailingComma::All),
+                };
+                self.bracketed(
+                    "[",
+                    docs,
+                    trailing_comma,
+                    t.span.hi - BytePos(1),
+                    "]",
+                    false,
+                    false,
+                )
+            }
+            TsType::TsOptionalType(o) => concat(vec![self.ts_type(&o.type_ann), "?".into()]),
+            TsType::TsRestType(r) => concat(vec!["...".into(), self.ts_type(&r.type_ann)]),
+            TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsUnionType(u)) => {
+                self.union_type(u, softline())
+            }
+            TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsIntersectionType(i)) => {
+                let types: Vec<_> = i.types.iter().map(|ty| self.ts_type(ty)).collect();
+                group(indent(join(concat(vec![" &".into(), line()]), types)))
+            }
+            TsType::TsConditionalType(c) => {
+                let mut parts = vec![];
+                let mut c = c;
+                loop {
+                    parts.push(self.ts_type(&c.check_type));
+                    parts.push(" extends ".into());
+                    parts.push(self.ts_type(&c.extends_type));
+                    parts.push(line());
+                    parts.push("? ".into());
+                    parts.push(self.ts_type(&c.true_type));
+                    parts.push(line());
+                    parts.push(": ".into());
+                    match &*c.false_type {
+                        TsType::TsConditionalType(alt) => c = alt,
+                        alt => {
+                            parts.push(self.ts_type(alt));
+                            break;
+                        }
+                    }
+                }
+                let first: Vec<_> = parts.drain(..3).collect();
+                group(vec![concat(first), indent(parts)])
+            }
+            TsType::TsInferType(i) => concat(vec!["infer ".into(), self.type_param(&i.type_param)]),
+            TsType::TsParenthesizedType(p) => {
+                concat(vec!["(".into(), self.ts_type(&p.type_ann), ")".into()])
+            }
+            TsType::TsTypeOperator(o) => concat(vec![
+                text(format!("{} ", o.op.as_str())),
+                self.ts_type(&o.type_ann),
+            ]),
+            TsType::TsIndexedAccessType(i) => concat(vec![
+                if i.readonly { "readonly " } else { "" }.into(),
+                self.ts_type(&i.obj_type),
+                "[".into(),
+                self.ts_type(&i.index_type),
+                "]".into(),
+            ]),
+            TsType::TsMappedType(m) => self.mapped_type(m),
+            TsType::TsLitType(l) => match &l.lit {
+                // The span of `-1` only covers `1`.
+                TsLit::Number(n) if n.value.is_sign_negative() && !n.span.is_dummy() => {
+                    concat(vec!["-".into(), self.num_lit(n)])
+                }
+                TsLit::Number(n) => self.num_lit(n),
+                TsLit::Str(s) => self.str_lit(s),
+                TsLit::Bool(b) => if b.value { "true" } else { "false" }.into(),
+                TsLit::BigInt(b) => self.bigint_lit(b),
+                TsLit::Tpl(t) => {
+                    let mut parts = vec!["`".into()];
+                    for (i, quasi) in t.quasis.iter().enumerate() {
+                        parts.push(text(self.quasi(quasi)));
+                        if let Some(ty) = t.types.get(i) {
+                            parts.push("${".into());
+                            parts.push(self.ts_type(ty));
+                            parts.push("}".into());
+                        }
+                    }
+                    parts.push("`".into());
+                    concat(parts)
+                }
+            },
+            TsType::TsTypePredicate(p) => {
+                let mut parts = vec![];
+                if p.asserts {
+                    parts.push("asserts ".into());
+                }
+                parts.push(match &p.param_name {
+                    TsThisTypeOrIdent::TsThisType(..) => "this".into(),
+                    TsThisTypeOrIdent::Ident(i) => text(i.sym.to_string()),
+                });
+                if let Some(t) = &p.type_ann {
+                    parts.push(" is ".into());
+                    parts.push(self.ts_type(&t.type_ann));
+                }
+                concat(parts)
+            }
+            TsType::TsImportType(i) => self.import_type(i),
+        };
+        concat(vec![leading, doc])
+    }
+
+    fn import_type(&mut self, i: &TsImportType) -> Doc {
+        let mut parts = vec!["import(".into(), self.str_lit(&i.arg), ")".into()];
+        if let Some(qualifier) = &i.qualifier {
+            parts.push(".".into());
+            parts.push(self.entity_name(qualifier));
+        }
+        parts.push(self.type_args(i.type_args.as_ref()));
+        concat(parts)
+    }
+
+    /// Parameters of function types. `close` is a position after the closing
+    /// parenthesis.
+    fn fn_params(&mut self, params: &[TsFnParam], close: BytePos) -> Doc {
+        let pats: Vec<Pat> = params.iter().cloned().map(fn_param_to_pat).collect();
+        self.params(&pats, close, |p, pat| p.pat(pat))
+    }
+
+    fn mapped_type(&mut self, m: &TsMappedType) -> Doc {
+        let mut parts = vec![];
+        parts.push(match m.readonly {
+            Some(TruePlusMinus::True) => "readonly ".into(),
+            Some(TruePlusMinus::Plus) => "+readonly ".into(),
+            Some(TruePlusMinus::Minus) => "-readonly ".into(),
+            None => Doc::default(),
+        });
+        parts.push(text(format!("[{} in ", m.type_param.name.sym)));
+        if let Some(constraint) = &m.type_param.constraint {
+            parts.push(self.ts_type(constraint));
+        }
+        if let Some(name_type) = &m.name_type {
+            parts.push(" as ".into());
+            parts.push(self.ts_type(name_type));
+        }
+        parts.push("]".into());
+        parts.push(match m.optional {
+            Some(TruePlusMinus::True) => "?".into(),
+            Some(TruePlusMinus::Plus) => "+?".into(),
+            Some(TruePlusMinus::Minus) => "-?".into(),
+            None => Doc::default(),
+        });
+        if let Some(ty) = &m.type_ann {
+            parts.push(": ".into());
+            parts.push(self.ts_type(ty));
+        }
+        parts.push(if self.config.semi {
+            if_break(";", "")
+        } else {
+            Doc::default()
+        });
+
+        let dangling = self.dangling_comments(m.span.hi - BytePos(1), true);
+        let should_break = self.has_newline(m.span.lo, m.type_param.span.lo);
+        group_with_break(
+            vec![
+                "{".into(),
+                indent(vec![line(), concat(parts), dangling]),
+                line(),
+                "}".into(),
+            ],
+            should_break,
+        )
+    }
+
+    /// Members of type literals and interfaces. `span` is the span of braces.
+    fn type_members(
+        &mut self,
+        span: swc_common::Span,
+        members: &[TsTypeElement],
+        force_break: bool,
+    ) -> Doc {
+        let sep = if self.config.semi {
+            ";".into()
+        } else {
+            if_break("", ";")
+        };
+        let last_sep = if self.config.semi {
+            if_break(";", "")
+        } else {
+            Doc::default()
+        };
+
+        let docs = self.comma_separated(members, true, |p, member| p.type_element(member));
+        let dangling = self.dangling_comments(span.hi - BytePos(1), !docs.is_empty());
+        if docs.is_empty() && super::is_nil(&dangling) {
+            return "{}".into();
+        }
+        if docs.is_empty() {
+            return group_with_break(
+                vec!["{".into(), indent(dangling), softline(), "}".into()],
+                force_break,
+            );
+        }
+        let should_break = force_break
+            || members
+                .first()
+                .map_or(false, |m| self.has_newline(span.lo, m.span().lo));
+
+        group_with_break(
+            vec![
+                "{".into(),
+                indent(vec![
+                    line(),
+                    join(concat(vec![sep, line()]), docs),
+                    last_sep,
+                    dangling,
+                ]),
+                line(),
+                "}".into(),
+            ],
+            should_break,
+        )
+    }
+
+    fn type_element(&mut self, member: &TsTypeElement) -> Doc {
+        match member {
+            TsTypeElement::TsCallSignatureDecl(c) => {
+                let close = type_ann_pos(c.type_ann.as_ref(), c.span.hi);
+                concat(vec![
+                    self.type_params(c.type_params.as_ref()),
+                    self.fn_params(&c.params, close),
+                    self.type_ann(c.type_ann.as_ref()),
+                ])
+            }
+            TsTypeElement::TsConstructSignatureDecl(c) => {
+                let close = type_ann_pos(c.type_ann.as_ref(), c.span.hi);
+                concat(vec![
+                    "new ".into(),
+                    self.type_params(c.type_params.as_ref()),
+                    self.fn_params(&c.params, close),
+                    self.type_ann(c.type_ann.as_ref()),
+                ])
+            }
+            TsTypeElement::TsPropertySignature(p) => {
+                let mut parts = vec![
+                    if p.readonly { "readonly " } else { "" }.into(),
+                    self.member_key(&p.key, p.computed),
+                    if p.optional { "?" } else { "" }.into(),
+                ];
+                if !p.params.is_empty() || p.type_params.is_some() {
+                    let close = type_ann_pos(p.type_ann.as_ref(), p.span.hi);
+                    parts.push(self.type_params(p.type_params.as_ref()));
+                    parts.push(self.fn_params(&p.params, close));
+                }
+                parts.push(self.type_ann(p.type_ann.as_ref()));
+                if let Some(init) = &p.init {
+                    parts.push(" = ".into());
+                    parts.push(self.expr(init));
+                }
+                concat(parts)
+            }
+            TsTypeElement::TsGetterSignature(g) => concat(vec![
+                if g.readonly { "readonly " } else { "" }.into(),
+                "get ".into(),
+                self.member_key(&g.key, g.computed),
+                if g.optional { "?" } else { "" }.into(),
+                "()".into(),
+                self.type_ann(g.type_ann.as_ref()),
+            ]),
+            TsTypeElement::TsSetterSignature(s) => {
+                let param = self.pat(&fn_param_to_pat(s.param.clone()));
+                concat(vec![
+                    if s.readonly { "readonly " } else { "" }.into(),
+                    "set ".into(),
+                    self.member_key(&s.key, s.computed),
+                    if s.optional { "?" } else { "" }.into(),
+                    "(".into(),
+                    param,
+                    ")".into(),
+                ])
+            }
+            TsTypeElement::TsMethodSignature(m) => {
+                let close = type_ann_pos(m.type_ann.as_ref(), m.span.hi);
+                concat(vec![
+                    if m.readonly { "readonly " } else { "" }.into(),
+                    self.member_key(&m.key, m.computed),
+                    if m.optional { "?" } else { "" }.into(),
+                    self.type_params(m.type_params.as_ref()),
+                    self.fn_params(&m.params, close),
+                    self.type_ann(m.type_ann.as_ref()),
+                ])
+            }
+            TsTypeElement::TsIndexSignature(s) => self.index_signature(s),
+        }
+    }
+
+    fn member_key(&mut self, key: &Expr, computed: bool) -> Doc {
+        if computed {
+            concat(vec!["[".into(), self.expr(key), "]".into()])
+        } else {
+            self.expr(key)
+        }
+    }
+
+    pub(super) fn index_signature(&mut self, s: &TsIndexSignature) -> Doc {
+        let params: Vec<_> = s
+            .params
+            .iter()
+            .cloned()
+            .map(|p| self.pat(&fn_param_to_pat(p)))
+            .collect();
+        concat(vec![
+            if s.is_static { "static " } else { "" }.into(),
+            if s.readonly { "readonly " } else { "" }.into(),
+            "[".into(),
+            join(", ".into(), params),
+            "]".into(),
+            self.type_ann(s.type_ann.as_ref()),
+        ])
+    }
+
+    pub(super) fn interface(&mut self, i: &TsInterfaceDecl) -> Doc {
+        let mut parts = vec![
+            declare(i.declare),
+            text(format!("interface {}", i.id.sym)),
+            self.type_params(i.type_params.as_ref()),
+        ];
+        if !i.extends.is_empty() {
+            let extends: Vec<_> = i
+                .extends
+                .iter()
+                .map(|e| self.expr_with_type_args(e))
+                .collect();
+            parts.push(group(indent(vec![
+                line(),
+                "extends ".into(),
+                join(", ".into(), extends),
+            ])));
+        }
+        parts.push(" ".into());
+        parts.push(self.type_members(i.body.span, &i.body.body, true));
+        concat(parts)
+    }
+
+    /// Unions are broken before each `|`. `sep` is printed before the first
+    /// type.
+    fn union_type(&mut self, u: &TsUnionType, sep: Doc) -> Doc {
+        let types: Vec<_> = u.types.iter().map(|ty| self.ts_type(ty)).collect();
+        group(indent(vec![
+            sep,
+            if_break("| ", ""),
+            join(concat(vec![line(), "| ".into()]), types),
+        ]))
+    }
+
+    pub(super) fn type_alias(&mut self, t: &TsTypeAliasDecl) -> Doc {
+        let left = concat(vec![
+            declare(t.declare),
+            text(format!("type {}", t.id.sym)),
+            self.type_params(t.type_params.as_ref()),
+        ]);
+        let right = match &*t.type_ann {
+            TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsUnionType(u)) => {
+                self.union_type(u, line())
+            }
+            TsType::TsTypeLit(..)
+            | TsType::TsMappedType(..)
+            | TsType::TsTupleType(..)
+            | TsType::TsConditionalType(..)
+            | TsType::TsFnOrConstructorType(..) => {
+                concat(vec![" ".into(), self.ts_type(&t.type_ann)])
+            }
+            ty => group(indent(vec![line(), self.ts_type(ty)])),
+        };
+        group(vec![left, " =".into(), right, self.semi()])
+    }
+
+    pub(super) fn ts_enum(&mut self, e: &TsEnumDecl) -> Doc {
+        let members = self.comma_separated(&e.members, true, |p, m| {
+            let id = match &m.id {
+                TsEnumMemberId::Ident(i) => text(i.sym.to_string()),
+                TsEnumMemberId::Str(s) => p.str_lit(s),
+            };
+            match &m.init {
+                Some(init) => p.assignment(id, " =", init, false),
+                None => id,
+            }
+        });
+        let trailing_comma = self.trailing_comma(TrailingComma::Es5);
+        let body = self.bracketed(
+            "{",
+            members,
+            trailing_comma,
+            e.span.hi - BytePos(1),
+            "}",
+            true,
+            true,
+        );
+        concat(vec![
+            declare(e.declare),
+            if e.is_const { "const enum " } else { "enum " }.into(),
+            text(format!("{} ", e.id.sym)),
+            body,
+        ])
+    }
+
+    pub(super) fn ts_module(&mut self, m: &TsModuleDecl) -> Doc {
+        let mut parts = vec![declare(m.declare)];
+        match &m.id {
+            TsModuleName::Ident(i) if m.global => parts.push(text(i.sym.to_string())),
+            TsModuleName::Ident(i) => {
+                let keyword = if self.is_module_keyword(m.span.lo) {
+                    "module"
+                } else {
+                    "namespace"
+                };
+                parts.push(text(format!("{} {}", keyword, i.sym)));
+            }
+            TsModuleName::Str(s) => {
+                parts.push("module ".into());
+                parts.push(self.str_lit(s));
+            }
+        }
+
+        let mut body = m.body.as_ref();
+        while let Some(TsNamespaceBody::TsNamespaceDecl(decl)) = body {
+            parts.push(text(format!(".{}", decl.id.sym)));
+            body = Some(&decl.body);
+        }
+        match body {
+            Some(TsNamespaceBody::TsModuleBlock(block)) => {
+                let items = self.module_items(&block.body);
+                parts.push(" ".into());
+                parts.push(self.braces(block.span, items));
+            }
+            _ => parts.push(self.semi()),
+        }
+        concat(parts)
+    }
+
+    /// Returns true if a declaration at `pos` uses the `module` keyword
+    /// instead of `namespace`.
+    fn is_module_keyword(&self, pos: BytePos) -> bool {
+        let src = self.src(pos, self.fm.end_pos);
+        let src = src.strip_prefix("declare").unwrap_or(src).trim_start();
+        src.starts_with("module")
+    }
+}
+
+fn keyword(kind: TsKeywordTypeKind) -> &'static str {
+    match kind {
+        TsKeywordTypeKind::TsAnyKeyword => "any",
+        TsKeywordTypeKind::TsUnknownKeyword => "unknown",
+        TsKeywordTypeKind::TsNumberKeyword => "number",
+        TsKeywordTypeKind::TsObjectKeyword => "object",
+        TsKeywordTypeKind::TsBooleanKeyword => "boolean",
+        TsKeywordTypeKind::TsBigIntKeyword => "bigint",
+        TsKeywordTypeKind::TsStringKeyword => "string",
+        TsKeywordTypeKind::TsSymbolKeyword => "symbol",
+        TsKeywordTypeKind::TsVoidKeyword => "void",
+        TsKeywordTypeKind::TsUndefinedKeyword => "undefined",
+        TsKeywordTypeKind::TsNullKeyword => "null",
+        TsKeywordTypeKind::TsNeverKeyword => "never",
+        TsKeywordTypeKind::TsIntrinsicKeyword => "intrinsic",
+    }
+}
+
+fn fn_param_to_pat(p: TsFnParam) -> Pat {
+    match p {
+        TsFnParam::Ident(i) => Pat::Ident(i),
+        TsFnParam::Array(a) => Pat::Array(a),
+        TsFnParam::Rest(r) => Pat::Rest(r),
+        TsFnParam::Object(o) => Pat::Object(o),
+    }
+}
+
+fn type_ann_pos(t: Option<&TsTypeAnn>, hi: BytePos) -> BytePos {
+    t.map_or(hi, |t| t.span.lo)
+}
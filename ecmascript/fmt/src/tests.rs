@@ -0,0 +1,328 @@
+use super::*;
+use crate::doc::*;
+use swc_common::FileName;
+use swc_ecma_parser::{EsConfig, TsConfig};
+
+fn fmt_with(config: &str, syntax: Syntax, src: &str) -> String {
+    let config: FmtConfig = serde_json::from_str(config).unwrap();
+
+    ::testing::run_test(false, |cm, handler| {
+        let fm = cm.new_source_file(FileName::Anon, src.into());
+        format(cm.clone(), &fm, syntax, &config).map_err(|err| err.into_diagnostic(handler).emit())
+    })
+    .unwrap()
+}
+
+fn fmt(src: &str) -> String {
+    fmt_with("{}", Syntax::default(), src)
+}
+
+fn ts(src: &str) -> String {
+    fmt_with(
+        "{}",
+        Syntax::Typescript(TsConfig {
+            decorators: true,
+            ..Default::default()
+        }),
+        src,
+    )
+}
+
+#[test]
+fn doc_group_fits() {
+    let doc = group(vec![
+        "[".into(),
+        indent(vec![
+            softline(),
+            join(
+                concat(vec![",".into(), line()]),
+                vec!["a".into(), "b".into()],
+            ),
+        ]),
+        softline(),
+        "]".into(),
+    ]);
+
+    assert_eq!(print(&doc, 80, 2), "[a, b]");
+    assert_eq!(print(&doc, 4, 2), "[\n  a,\n  b\n]");
+}
+
+#[test]
+fn doc_conditional_group() {
+    let doc = conditional_group(vec![
+        "a long state".into(),
+        "short".into(),
+        concat(vec!["broken".into(), line(), "state".into()]),
+    ]);
+
+    assert_eq!(print(&doc, 80, 2), "a long state");
+    assert_eq!(print(&doc, 6, 2), "short");
+
+    let doc = conditional_group(vec![
+        "a long state".into(),
+        concat(vec!["b".into(), line(), "c".into()]),
+    ]);
+    assert_eq!(print(&doc, 2, 2), "b\nc");
+}
+
+#[test]
+fn doc_line_suffix() {
+    let doc = concat(vec![
+        "a".into(),
+        line_suffix(" // c"),
+        ";".into(),
+        hardline(),
+        "b".into(),
+    ]);
+
+    assert_eq!(print(&doc, 80, 2), "a; // c\nb");
+}
+
+#[test]
+fn statements() {
+    assert_eq!(
+        fmt("function foo(a, b = 2, ...rest) { if (a) return b; else { throw new Error('x') } }"),
+        "function foo(a, b = 2, ...rest) {
+  if (a) return b;
+  else {
+    throw new Error(\"x\");
+  }
+}
+"
+    );
+    assert_eq!(
+        fmt("switch (a) { case 1: b(); break; default: c() }"),
+        "switch (a) {
+  case 1:
+    b();
+    break;
+  default:
+    c();
+}
+"
+    );
+    assert_eq!(
+        fmt("for(;;){}\nwhile(a)b()"),
+        "for (;;) {}\nwhile (a) b();\n"
+    );
+}
+
+#[test]
+fn blank_lines() {
+    assert_eq!(fmt("a()\n\n\n\nb()\nc()\n\n"), "a();\n\nb();\nc();\n");
+    assert_eq!(
+        fmt("class A {\n  a() {}\n\n  b() {}\n  c = 1\n}"),
+        "class A {\n  a() {}\n\n  b() {}\n  c = 1;\n}\n"
+    );
+}
+
+#[test]
+fn break_calls() {
+    assert_eq!(
+        fmt("const veryLongVariableNameForTesting = \
+             anotherVeryLongFunctionName(argumentNumberOne, argumentNumberTwo);"),
+        "const veryLongVariableNameForTesting = anotherVeryLongFunctionName(
+  argumentNumberOne,
+  argumentNumberTwo
+);
+"
+    );
+    assert_eq!(
+        fmt(
+            "someFunction(argumentOne, { key: value, otherKey: otherValue, thirdKey: thirdValue \
+             });"
+        ),
+        "someFunction(argumentOne, {
+  key: value,
+  otherKey: otherValue,
+  thirdKey: thirdValue,
+});
+"
+    );
+    assert_eq!(
+        fmt("useEffect(() => { doSomething() }, [a, b])"),
+        "useEffect(() => {\n  doSomething();\n}, [a, b]);\n"
+    );
+}
+
+#[test]
+fn member_chains() {
+    assert_eq!(fmt("a.b().c().d()"), "a.b().c().d();\n");
+    assert_eq!(
+        fmt("promise.then(result => { console.log(result); return result; }).catch(() => {});"),
+        "promise
+  .then((result) => {
+    console.log(result);
+    return result;
+  })
+  .catch(() => {});
+"
+    );
+    assert_eq!(
+        fmt("const result = someObject.methodOne().methodTwo().methodThree().methodFour(argument);"),
+        "const result = someObject
+  .methodOne()
+  .methodTwo()
+  .methodThree()
+  .methodFour(argument);
+"
+    );
+}
+
+#[test]
+fn binary_and_conditional() {
+    assert_eq!(
+        fmt(
+            "const total = firstValue + secondValue + thirdValue + fourthValue + fifthValue + \
+             sixthValue;"
+        ),
+        "const total =
+  firstValue + secondValue + thirdValue + fourthValue + fifthValue + sixthValue;
+"
+    );
+    assert_eq!(
+        fmt("x = a && b || c; y = a * b % c; z = (a + b) * c"),
+        "x = (a && b) || c;\ny = (a * b) % c;\nz = (a + b) * c;\n"
+    );
+    assert_eq!(
+        fmt(
+            "const cond = someCondition ? someValueThatIsQuiteLong : anotherCondition ? \
+             anotherValueLong : finalValueLong;"
+        ),
+        "const cond = someCondition
+  ? someValueThatIsQuiteLong
+  : anotherCondition
+  ? anotherValueLong
+  : finalValueLong;
+"
+    );
+}
+
+#[test]
+fn objects_and_arrays() {
+    assert_eq!(
+        fmt("const a = {b:1,c:[1,2,3]}"),
+        "const a = { b: 1, c: [1, 2, 3] };\n"
+    );
+    assert_eq!(fmt("const a = {\n b:1}"), "const a = {\n  b: 1,\n};\n");
+    assert_eq!(
+        fmt("let a = [[1, 2], [3, 4]]; let [x, , y = 1, ...z] = b"),
+        "let a = [\n  [1, 2],\n  [3, 4],\n];\nlet [x, , y = 1, ...z] = b;\n"
+    );
+}
+
+#[test]
+fn literals() {
+    assert_eq!(
+        fmt(r#"a = 'it\'s'; b = "say \"hi\""; c = '\d'"#),
+        "a = \"it's\";\nb = 'say \"hi\"';\nc = \"d\";\n"
+    );
+    assert_eq!(
+        fmt("x = [0.50, 1E+5, 0XFF, .5, 5., 10n, 1.0e-05]"),
+        "x = [0.5, 1e5, 0xff, 0.5, 5, 10n, 1.0e-5];\n"
+    );
+    assert_eq!(fmt("z = /ab+c/ig"), "z = /ab+c/gi;\n");
+    assert_eq!(
+        fmt(r"t = `\x42\u{43}${a}\n`"),
+        "t = `\\x42\\u{43}${a}\\n`;\n"
+    );
+    assert_eq!(ts(r"type T = `\x42${A}`"), "type T = `\\x42${A}`;\n");
+    assert_eq!(ts("let x: -1;"), "let x: -1;\n");
+}
+
+#[test]
+fn comments() {
+    assert_eq!(
+        fmt(
+            "// leading\n\n/**\n   * Docs\n   */\nfunction a(/* inline */ b) {\n  // only \
+             comment\n}\nconst a = 1; // trailing\n"
+        ),
+        "// leading
+
+/**
+ * Docs
+ */
+function a(/* inline */ b) {
+  // only comment
+}
+const a = 1; // trailing
+"
+    );
+    assert_eq!(
+        fmt("const obj = {\n  a: 1, // one\n\n  // before b\n  b: 2,\n};"),
+        "const obj = {\n  a: 1, // one\n\n  // before b\n  b: 2,\n};\n"
+    );
+}
+
+#[test]
+fn options() {
+    assert_eq!(
+        fmt_with(
+            r#"{"semi": false, "singleQuote": true}"#,
+            Syntax::default(),
+            "let a = \"b\";\n[1, 2].forEach(f);\n(function () {})()"
+        ),
+        "let a = 'b'\n;[1, 2].forEach(f)\n;(function () {})()\n"
+    );
+    assert_eq!(
+        fmt_with(
+            r#"{"printWidth": 20, "tabWidth": 4, "trailingComma": "all"}"#,
+            Syntax::default(),
+            "foo(argumentOne, argumentTwo)"
+        ),
+        "foo(\n    argumentOne,\n    argumentTwo,\n);\n"
+    );
+}
+
+#[test]
+fn typescript() {
+    assert_eq!(
+        ts("interface Props { name: string; age?: number }"),
+        "interface Props {\n  name: string;\n  age?: number;\n}\n"
+    );
+    assert_eq!(
+        ts(
+            "type U = 'a' | 'b';\ntype LongUnion = 'option-one' | 'option-two' | 'option-three' | \
+             'option-four' | 'option-five';"
+        ),
+        "type U = \"a\" | \"b\";
+type LongUnion =
+  | \"option-one\"
+  | \"option-two\"
+  | \"option-three\"
+  | \"option-four\"
+  | \"option-five\";
+"
+    );
+    assert_eq!(
+        ts(
+            "@dec()\nclass C<T> implements I { @prop() private readonly a: number = 1; \
+             constructor(public b: string) {} }"
+        ),
+        "@dec()
+class C<T> implements I {
+  @prop() private readonly a: number = 1;
+  constructor(public b: string) {}
+}
+"
+    );
+    assert_eq!(
+        ts("class C { 'constructor'() {} }"),
+        "class C {\n  \"constructor\"() {}\n}\n"
+    );
+}
+
+#[test]
+fn jsx() {
+    assert_eq!(
+        fmt_with(
+            "{}",
+            Syntax::Es(EsConfig {
+                jsx: true,
+                ..Default::default()
+            }),
+            "const a = <div className='b'>{c}</div>"
+        ),
+        "const a = <div className='b'>{c}</div>;\n"
+    );
+}
@@ -0,0 +1,180 @@
+use std::{
+    fs::read_to_string,
+    panic::catch_unwind,
+    path::{Path, PathBuf},
+};
+use swc_common::{sync::Lrc, FileName, SourceMap};
+use swc_ecma_ast::*;
+use swc_ecma_fmt::{format, FmtConfig, TrailingComma};
+use swc_ecma_parser::{lexer::Lexer, Parser, StringInput, Syntax, TsConfig};
+use swc_ecma_visit::{as_folder, assert_eq_ignore_span, FoldWith, VisitMut, VisitMutWith};
+use walkdir::WalkDir;
+
+const IGNORED: &[&str] = &[
+    // `Prop::Setter` does not store `this` parameters.
+    "thisType/thisTypeInAccessors/",
+    "thisType/thisTypeInAccessorsNegative/",
+    // The parser accepts a property named `public` only if it's not followed
+    // by a semicolon.
+    "ClassDeclarations/parserClassDeclaration26/",
+    "RegressionTests/parser509546/",
+    "RegressionTests/parser509546_1/",
+    "RegressionTests/parser509546_2/",
+];
+
+/// Removes differences which are expected to be introduced by the formatter.
+struct Normalizer;
+
+impl VisitMut for Normalizer {
+    /// Empty class members are removed.
+    fn visit_mut_class_members(&mut self, members: &mut Vec<ClassMember>) {
+        members.visit_mut_children_with(self);
+        members.retain(|m| !matches!(m, ClassMember::Empty(..)));
+    }
+
+    fn visit_mut_expr(&mut self, e: &mut Expr) {
+        e.visit_mut_children_with(self);
+
+        while let Expr::Paren(ParenExpr { expr, .. }) = e {
+            let expr = (**expr).clone();
+            *e = expr;
+        }
+    }
+
+    fn visit_mut_module_items(&mut self, items: &mut Vec<ModuleItem>) {
+        items.visit_mut_children_with(self);
+        items.retain(|item| !matches!(item, ModuleItem::Stmt(Stmt::Empty(..))));
+    }
+
+    /// `new Foo` is printed as `new Foo()`.
+    fn visit_mut_new_expr(&mut self, e: &mut NewExpr) {
+        e.visit_mut_children_with(self);
+        e.args.get_or_insert_with(Vec::new);
+    }
+
+    fn visit_mut_stmts(&mut self, stmts: &mut Vec<Stmt>) {
+        stmts.visit_mut_children_with(self);
+        stmts.retain(|s| !matches!(s, Stmt::Empty(..)));
+    }
+
+    /// Quotes are changed.
+    fn visit_mut_str(&mut self, s: &mut Str) {
+        s.has_escape = false;
+        s.kind = Default::default();
+    }
+
+    fn visit_mut_ts_type(&mut self, ty: &mut TsType) {
+        ty.visit_mut_children_with(self);
+
+        while let TsType::TsParenthesizedType(TsParenthesizedType { type_ann, .. }) = ty {
+            let type_ann = (**type_ann).clone();
+            *ty = type_ann;
+        }
+    }
+}
+
+fn parse(cm: &SourceMap, syntax: Syntax, src: &str) -> Program {
+    let fm = cm.new_source_file(FileName::Anon, src.into());
+    let lexer = Lexer::new(syntax, Default::default(), StringInput::from(&*fm), None);
+    let program = Parser::new_from(lexer)
+        .parse_program()
+        .expect("failed to parse");
+
+    program.fold_with(&mut as_folder(Normalizer))
+}
+
+/// Formats `src` with some configurations, and returns an error if the output
+/// is invalid or formatting it again changes it.
+///
+/// Panics if the output is not equivalent to `src`.
+fn check(syntax: Syntax, src: &str) -> Result<(), String> {
+    let configs = [
+        FmtConfig::default(),
+        FmtConfig {
+            semi: false,
+            single_quote: true,
+            trailing_comma: TrailingComma::All,
+            ..Default::default()
+        },
+    ];
+    let cm: Lrc<SourceMap> = Default::default();
+
+    for config in &configs {
+        let fm = cm.new_source_file(FileName::Anon, src.into());
+        let formatted = match format(cm.clone(), &fm, syntax, config) {
+            Ok(formatted) => formatted,
+            // Not a valid input.
+            Err(..) => return Ok(()),
+        };
+
+        let fm = cm.new_source_file(FileName::Anon, formatted.clone());
+        let reformatted = format(cm.clone(), &fm, syntax, config)
+            .map_err(|err| format!("invalid output: {:?}\n{}", err, formatted))?;
+        if formatted != reformatted {
+            return Err(format!(
+                "not idempotent with {:?}:\n{}\n---\n{}",
+                config, formatted, reformatted
+            ));
+        }
+
+        assert_eq_ignore_span!(
+            parse(&cm, syntax, src),
+            parse(&cm, syntax, &formatted),
+            "changed with {:?}:\n{}",
+            config,
+            formatted
+        );
+    }
+    Ok(())
+}
+
+fn check_dir(dir: &Path, ext: &str, syntax: Syntax) {
+    let files: Vec<PathBuf> = WalkDir::new(dir)
+        .into_iter()
+        .map(|entry| entry.unwrap().into_path())
+        .filter(|path| {
+            let path = path.to_string_lossy();
+            path.ends_with(ext) && !IGNORED.iter().any(|ignored| path.contains(ignored))
+        })
+        .collect();
+    assert!(!files.is_empty(), "no files in {}", dir.display());
+
+    let mut errors = vec![];
+    for path in &files {
+        let src = read_to_string(path).unwrap();
+        let res = catch_unwind(|| check(syntax, &src))
+            .unwrap_or_else(|_| Err("the output is not equivalent to the input".into()));
+        if let Err(err) = res {
+            errors.push(format!("{}: {}", path.display(), err));
+        }
+    }
+    if !errors.is_empty() {
+        panic!(
+            "{} of {} files failed:\n{}",
+            errors.len(),
+            files.len(),
+            errors.join("\n\n")
+        );
+    }
+}
+
+#[test]
+fn codegen_references() {
+    check_dir(
+        &Path::new(env!("CARGO_MANIFEST_DIR")).join("../codegen/tests/references"),
+        ".js",
+        Syntax::default(),
+    );
+}
+
+#[test]
+fn typescript() {
+    check_dir(
+        &Path::new(env!("CARGO_MANIFEST_DIR")).join("../parser/tests/typescript"),
+        ".ts",
+        Syntax::Typescript(TsConfig {
+            decorators: true,
+            ..Default::default()
+        }),
+    );
+}
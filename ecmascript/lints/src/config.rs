@@ -0,0 +1,127 @@
+use serde::{Deserialize, Serialize};
+use swc_atoms::JsWord;
+
+/// `jsc.lints` of `.swcrc`.
+///
+/// Keys are ids of rules. A rule which is not configured uses its default
+/// level.
+///
+/// ```json
+/// {
+///     "no-unused-vars": "off",
+///     "no-undef": ["error", { "globals": ["console"] }]
+/// }
+/// ```
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct LintConfig {
+    #[serde(default)]
+    pub no_undef: RuleConfig<NoUndefConfig>,
+
+    #[serde(default)]
+    pub no_unused_vars: RuleConfig<NoUnusedVarsConfig>,
+
+    #[serde(default)]
+    pub no_dupe_keys: RuleConfig<()>,
+
+    #[serde(default)]
+    pub no_const_assign: RuleConfig<()>,
+
+    #[serde(default)]
+    pub no_unreachable: RuleConfig<()>,
+
+    #[serde(default)]
+    pub getter_return: RuleConfig<()>,
+
+    #[serde(default)]
+    pub no_duplicate_case: RuleConfig<()>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LintRuleLevel {
+    Off,
+    Warn,
+    Error,
+}
+
+/// `"warn"` or `["warn", { ...options }]`.
+///
+/// `null` in place of the level means the default level of the rule.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(
+    from = "RuleConfigRepr<T>",
+    into = "RuleConfigRepr<T>",
+    bound(
+        serialize = "T: Clone + Serialize",
+        deserialize = "T: Default + Deserialize<'de>"
+    )
+)]
+pub struct RuleConfig<T> {
+    /// `None` means the default level of the rule.
+    pub level: Option<LintRuleLevel>,
+    pub options: T,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+enum RuleConfigRepr<T> {
+    Level(Option<LintRuleLevel>),
+    WithOptions(Option<LintRuleLevel>, T),
+}
+
+impl<T: Default> From<RuleConfigRepr<T>> for RuleConfig<T> {
+    fn from(repr: RuleConfigRepr<T>) -> Self {
+        match repr {
+            RuleConfigRepr::Level(level) => RuleConfig {
+                level,
+                options: Default::default(),
+            },
+            RuleConfigRepr::WithOptions(level, options) => RuleConfig { level, options },
+        }
+    }
+}
+
+impl<T> From<RuleConfig<T>> for RuleConfigRepr<T> {
+    fn from(config: RuleConfig<T>) -> Self {
+        RuleConfigRepr::WithOptions(config.level, config.options)
+    }
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct NoUndefConfig {
+    /// Global variables other than builtins of ECMAScript, like `console` or
+    /// `window`.
+    #[serde(default)]
+    pub globals: Vec<JsWord>,
+
+    /// Reports `typeof a` if `a` is not defined.
+    #[serde(default)]
+    pub check_typeof: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct NoUnusedVarsConfig {
+    /// Reports unused parameters after the last used one.
+    #[serde(default = "default_true")]
+    pub args: bool,
+
+    /// Regex of names which are not reported, like `^_`.
+    #[serde(default)]
+    pub ignore_pattern: Option<String>,
+}
+
+impl Default for NoUnusedVarsConfig {
+    fn default() -> Self {
+        NoUnusedVarsConfig {
+            args: true,
+            ignore_pattern: None,
+        }
+    }
+}
+
+fn default_true() -> bool {
+    true
+}
@@ -0,0 +1,68 @@
+use fxhash::FxHashMap;
+use swc_common::{comments::Comment, SourceMap, Span};
+
+const DISABLE_NEXT_LINE: &str = "swc-disable-next-line";
+
+/// `// swc-disable-next-line` and `// swc-disable-next-line rule-a, rule-b`
+///
+/// Like eslint, text after `--` is a description and ignored.
+#[derive(Debug, Default)]
+pub(crate) struct Directives {
+    /// Disabled rules by 1-based lines. `None` means all rules.
+    disabled_lines: FxHashMap<usize, Option<Vec<String>>>,
+}
+
+impl Directives {
+    pub fn new<'a, I>(cm: &SourceMap, comments: I) -> Self
+    where
+        I: IntoIterator<Item = &'a Comment>,
+    {
+        let mut directives = Directives::default();
+
+        for c in comments {
+            let text = c.text.trim();
+            if !text.starts_with(DISABLE_NEXT_LINE) {
+                continue;
+            }
+            let rules = text[DISABLE_NEXT_LINE.len()..].split("--").next().unwrap();
+            // `swc-disable-next-lines`
+            if !rules.is_empty() && !rules.starts_with(char::is_whitespace) {
+                continue;
+            }
+
+            let line = cm.lookup_char_pos(c.span.hi).line + 1;
+            let rules: Vec<_> = rules
+                .split(',')
+                .map(str::trim)
+                .filter(|rule| !rule.is_empty())
+                .map(String::from)
+                .collect();
+
+            let disabled = directives
+                .disabled_lines
+                .entry(line)
+                .or_insert_with(|| Some(vec![]));
+            match disabled {
+                Some(disabled) if !rules.is_empty() => disabled.extend(rules),
+                // Disabling all rules wins.
+                _ => *disabled = None,
+            }
+        }
+
+        directives
+    }
+
+    /// Returns true if `rule` is disabled at `span`.
+    pub fn is_disabled(&self, cm: &SourceMap, rule: &str, span: Span) -> bool {
+        if self.disabled_lines.is_empty() {
+            return false;
+        }
+
+        let line = cm.lookup_char_pos(span.lo).line;
+        match self.disabled_lines.get(&line) {
+            Some(Some(rules)) => rules.iter().any(|r| r == rule),
+            Some(None) => true,
+            None => false,
+        }
+    }
+}
@@ -0,0 +1,91 @@
+//! Lints for ecmascript.
+//!
+//! ```ignore
+//! let program = program.fold_with(&mut resolver());
+//!
+//! let linter = Linter::new(&LintConfig::default())?;
+//! for d in linter.lint(&cm, comments.iter(), &program) {
+//!     d.emit(&handler);
+//! }
+//! ```
+//!
+//! A problem on a line can be suppressed by a comment on the previous line,
+//! like `// swc-disable-next-line no-undef, no-unused-vars`. If no rule is
+//! specified, all rules are disabled for the line.
+pub use self::{
+    config::{LintConfig, LintRuleLevel, RuleConfig},
+    rule::{LintContext, LintDiagnostic, LintFix, Rule},
+};
+use self::{directive::Directives, rules::EnabledRules};
+use swc_common::{comments::Comment, SourceMap};
+use swc_ecma_ast::Program;
+use swc_ecma_transforms_base::scope::analyze;
+
+pub mod config;
+mod directive;
+mod rule;
+pub mod rules;
+#[cfg(test)]
+mod tests;
+
+pub struct Linter {
+    rules: EnabledRules,
+}
+
+impl Linter {
+    /// Creates a linter with builtin rules.
+    ///
+    /// Returns an error if a regex in `config` is invalid.
+    pub fn new(config: &LintConfig) -> Result<Self, regex::Error> {
+        Ok(Linter {
+            rules: rules::all(config)?,
+        })
+    }
+
+    /// Adds a custom rule. If `level` is `None`, [Rule::default_level] is used.
+    pub fn add_rule(&mut self, rule: Box<dyn Rule>, level: Option<LintRuleLevel>) {
+        let level = level.unwrap_or_else(|| rule.default_level());
+        if level != LintRuleLevel::Off {
+            self.rules.push((rule, level));
+        }
+    }
+
+    /// Returns problems in `program`, sorted by their positions.
+    ///
+    /// `comments` are used to find `swc-disable-next-line` directives.
+    ///
+    /// # When to run
+    ///
+    /// `program` should be processed by
+    /// [resolver](swc_ecma_transforms_base::resolver::resolver) (or
+    /// [ts_resolver](swc_ecma_transforms_base::resolver::ts_resolver) for
+    /// typescript) first, and the types should not be stripped yet.
+    pub fn lint<'a, I>(&self, cm: &SourceMap, comments: I, program: &Program) -> Vec<LintDiagnostic>
+    where
+        I: IntoIterator<Item = &'a Comment>,
+    {
+        let analysis = analyze(program);
+        let directives = Directives::new(cm, comments);
+
+        let mut diagnostics = vec![];
+        for (rule, level) in &self.rules {
+            let ctx = LintContext {
+                analysis: &analysis,
+                rule: rule.id(),
+                level: *level,
+                diagnostics: Default::default(),
+            };
+            rule.lint(&ctx, program);
+
+            diagnostics.extend(
+                ctx.diagnostics
+                    .into_inner()
+                    .into_iter()
+                    .filter(|d| !directives.is_disabled(cm, d.rule, d.span)),
+            );
+        }
+        diagnostics.sort_by_key(|d| (d.span.lo, d.span.hi));
+
+        diagnostics
+    }
+}
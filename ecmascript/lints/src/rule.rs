@@ -0,0 +1,109 @@
+use crate::config::LintRuleLevel;
+use std::cell::RefCell;
+use swc_common::{
+    errors::{Applicability, DiagnosticId, Handler},
+    Span,
+};
+use swc_ecma_ast::Program;
+use swc_ecma_transforms_base::scope::ScopeAnalysis;
+
+/// A lint rule.
+///
+/// Rules are usually implemented by visiting `program` with a
+/// [Visit](swc_ecma_visit::Visit) which reports problems via [LintContext].
+pub trait Rule {
+    /// Stable id of the rule, like `no-undef`.
+    ///
+    /// This is used as the key of the config, as the code of diagnostics and
+    /// by `// swc-disable-next-line` comments.
+    fn id(&self) -> &'static str;
+
+    /// Used if the level is not configured.
+    fn default_level(&self) -> LintRuleLevel {
+        LintRuleLevel::Error
+    }
+
+    fn lint(&self, ctx: &LintContext, program: &Program);
+}
+
+/// A problem reported by a [Rule].
+#[derive(Debug, Clone)]
+pub struct LintDiagnostic {
+    /// [Rule::id]
+    pub rule: &'static str,
+    /// [LintRuleLevel::Warn] or [LintRuleLevel::Error].
+    pub level: LintRuleLevel,
+    pub span: Span,
+    pub message: String,
+    pub fix: Option<LintFix>,
+}
+
+/// Text edits which fix a [LintDiagnostic].
+#[derive(Debug, Clone)]
+pub struct LintFix {
+    /// Describes the fix, like ``remove `a` ``.
+    pub message: String,
+    /// Spans to replace and their replacements. Spans may not overlap.
+    pub edits: Vec<(Span, String)>,
+    /// [Applicability::MachineApplicable] if the fix can be applied without
+    /// changing the behavior of the program.
+    pub applicability: Applicability,
+}
+
+impl LintDiagnostic {
+    pub fn is_error(&self) -> bool {
+        self.level == LintRuleLevel::Error
+    }
+
+    /// Emits `self` with the id of the rule as the code. [LintFix] is attached
+    /// as a suggestion.
+    pub fn emit(&self, handler: &Handler) {
+        let mut db = if self.is_error() {
+            handler.struct_span_err(self.span, &self.message)
+        } else {
+            handler.struct_span_warn(self.span, &self.message)
+        };
+        db.code(DiagnosticId::Lint(self.rule.into()))
+            .note(&format!("reported by the lint rule `{}`", self.rule));
+        if let Some(fix) = &self.fix {
+            db.multipart_suggestion_with_applicability(
+                &fix.message,
+                fix.edits.clone(),
+                fix.applicability,
+            );
+        }
+        db.emit();
+    }
+}
+
+pub struct LintContext<'a> {
+    pub(crate) analysis: &'a ScopeAnalysis,
+    pub(crate) rule: &'static str,
+    pub(crate) level: LintRuleLevel,
+    pub(crate) diagnostics: RefCell<Vec<LintDiagnostic>>,
+}
+
+impl LintContext<'_> {
+    /// Scopes, bindings and references of the program being linted.
+    pub fn analysis(&self) -> &ScopeAnalysis {
+        self.analysis
+    }
+
+    pub fn report(&self, span: Span, message: impl Into<String>) {
+        self.push(span, message.into(), None);
+    }
+
+    pub fn report_with_fix(&self, span: Span, message: impl Into<String>, fix: LintFix) {
+        self.push(span, message.into(), Some(fix));
+    }
+
+    fn push(&self, span: Span, message: String, fix: Option<LintFix>) {
+        self.diagnostics.borrow_mut().push(LintDiagnostic {
+            rule: self.rule,
+            level: self.level,
+            span,
+            message,
+            fix,
+        });
+    }
+}
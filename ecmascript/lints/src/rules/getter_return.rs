@@ -0,0 +1,96 @@
+use super::always_exits;
+use crate::rule::{LintContext, Rule};
+use swc_common::{Span, Spanned, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{noop_visit_type, Node, Visit, VisitWith};
+
+/// `getter-return`: Reports getters which may not return a value.
+#[derive(Debug)]
+pub struct GetterReturn;
+
+impl Rule for GetterReturn {
+    fn id(&self) -> &'static str {
+        "getter-return"
+    }
+
+    fn lint(&self, ctx: &LintContext, program: &Program) {
+        program.visit_with(&Invalid { span: DUMMY_SP }, &mut Visitor { ctx });
+    }
+}
+
+struct Visitor<'a, 'b> {
+    ctx: &'a LintContext<'b>,
+}
+
+impl Visitor<'_, '_> {
+    fn check(&self, key_span: Span, body: &Option<BlockStmt>) {
+        let body = match body {
+            Some(body) => body,
+            // Declarations in typescript.
+            None => return,
+        };
+
+        body.visit_with(
+            &Invalid { span: DUMMY_SP },
+            &mut EmptyReturnFinder { ctx: self.ctx },
+        );
+
+        if !body.stmts.iter().any(|s| always_exits(s, false)) {
+            self.ctx.report(
+                key_span,
+                "expected to return a value at the end of the getter",
+            );
+        }
+    }
+}
+
+impl Visit for Visitor<'_, '_> {
+    noop_visit_type!();
+
+    fn visit_class_method(&mut self, n: &ClassMethod, _: &dyn Node) {
+        if n.kind == MethodKind::Getter {
+            self.check(n.key.span(), &n.function.body);
+        }
+
+        n.visit_children_with(self);
+    }
+
+    fn visit_getter_prop(&mut self, n: &GetterProp, _: &dyn Node) {
+        self.check(n.key.span(), &n.body);
+
+        n.visit_children_with(self);
+    }
+
+    fn visit_private_method(&mut self, n: &PrivateMethod, _: &dyn Node) {
+        if n.kind == MethodKind::Getter {
+            self.check(n.key.span, &n.function.body);
+        }
+
+        n.visit_children_with(self);
+    }
+}
+
+/// Reports `return;` in a getter, excluding nested functions.
+struct EmptyReturnFinder<'a, 'b> {
+    ctx: &'a LintContext<'b>,
+}
+
+impl Visit for EmptyReturnFinder<'_, '_> {
+    noop_visit_type!();
+
+    fn visit_arrow_expr(&mut self, _: &ArrowExpr, _: &dyn Node) {}
+
+    fn visit_class(&mut self, _: &Class, _: &dyn Node) {}
+
+    fn visit_function(&mut self, _: &Function, _: &dyn Node) {}
+
+    fn visit_getter_prop(&mut self, _: &GetterProp, _: &dyn Node) {}
+
+    fn visit_return_stmt(&mut self, n: &ReturnStmt, _: &dyn Node) {
+        if n.arg.is_none() {
+            self.ctx.report(n.span, "getter must return a value");
+        }
+    }
+
+    fn visit_setter_prop(&mut self, _: &SetterProp, _: &dyn Node) {}
+}
@@ -0,0 +1,89 @@
+//! Builtin rules.
+
+pub use self::{
+    getter_return::GetterReturn, no_const_assign::NoConstAssign, no_dupe_keys::NoDupeKeys,
+    no_duplicate_case::NoDuplicateCase, no_undef::NoUndef, no_unreachable::NoUnreachable,
+    no_unused_vars::NoUnusedVars,
+};
+use crate::{
+    config::{LintConfig, LintRuleLevel, RuleConfig},
+    rule::Rule,
+};
+use swc_ecma_ast::*;
+
+mod getter_return;
+mod no_const_assign;
+mod no_dupe_keys;
+mod no_duplicate_case;
+mod no_undef;
+mod no_unreachable;
+mod no_unused_vars;
+
+/// Rules with their levels.
+pub type EnabledRules = Vec<(Box<dyn Rule>, LintRuleLevel)>;
+
+/// Returns enabled builtin rules.
+///
+/// Returns an error if a regex in `config` is invalid.
+pub fn all(config: &LintConfig) -> Result<EnabledRules, regex::Error> {
+    let mut rules = vec![];
+    let no_unused_vars = NoUnusedVars::new(&config.no_unused_vars.options)?;
+
+    add(&mut rules, &config.no_undef, NoUndef::new);
+    add(&mut rules, &config.no_unused_vars, |_| no_unused_vars);
+    add(&mut rules, &config.no_dupe_keys, |_| NoDupeKeys);
+    add(&mut rules, &config.no_const_assign, |_| NoConstAssign);
+    add(&mut rules, &config.no_unreachable, |_| NoUnreachable);
+    add(&mut rules, &config.getter_return, |_| GetterReturn);
+    add(&mut rules, &config.no_duplicate_case, |_| NoDuplicateCase);
+
+    Ok(rules)
+}
+
+fn add<T, R>(rules: &mut EnabledRules, config: &RuleConfig<T>, make: impl FnOnce(&T) -> R)
+where
+    R: 'static + Rule,
+{
+    let rule = make(&config.options);
+    let level = config.level.unwrap_or_else(|| rule.default_level());
+    if level != LintRuleLevel::Off {
+        rules.push((Box::new(rule), level));
+    }
+}
+
+/// Returns true if statements after `stmt` can't be executed, ignoring
+/// exceptions other than `throw`.
+///
+/// If `breaks` is false, `break` and `continue` are not considered as exits.
+/// Loops and labeled statements are assumed to complete normally.
+fn always_exits(stmt: &Stmt, breaks: bool) -> bool {
+    match stmt {
+        Stmt::Return(..) | Stmt::Throw(..) => true,
+        Stmt::Break(..) | Stmt::Continue(..) => breaks,
+        Stmt::Block(b) => b.stmts.iter().any(|s| always_exits(s, breaks)),
+        Stmt::If(IfStmt { cons, alt, .. }) => {
+            always_exits(cons, breaks)
+                && alt.as_ref().map_or(false, |alt| always_exits(alt, breaks))
+        }
+        Stmt::Try(TryStmt {
+            block,
+            handler,
+            finalizer,
+            ..
+        }) => {
+            let exits = |b: &BlockStmt| b.stmts.iter().any(|s| always_exits(s, breaks));
+
+            (exits(block) && handler.as_ref().map_or(true, |h| exits(&h.body)))
+                || finalizer.as_ref().map_or(false, exits)
+        }
+        // `break` exits the switch statement.
+        Stmt::Switch(SwitchStmt { cases, .. }) => {
+            let exits = |case: &SwitchCase| case.cons.iter().any(|s| always_exits(s, false));
+
+            cases.iter().any(|case| case.test.is_none())
+                && cases.iter().all(|case| case.cons.is_empty() || exits(case))
+                && cases.last().map_or(false, exits)
+        }
+        _ => false,
+    }
+}
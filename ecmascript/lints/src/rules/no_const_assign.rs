@@ -0,0 +1,74 @@
+use crate::rule::{LintContext, LintFix, Rule};
+use fxhash::FxHashMap;
+use swc_common::{errors::Applicability, BytePos, Span, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_transforms_base::scope::BindingKind;
+use swc_ecma_utils::{find_ids, Id};
+use swc_ecma_visit::{noop_visit_type, Node, Visit, VisitWith};
+
+/// `no-const-assign`: Reports assignments to `const` variables.
+///
+/// Declaring the variable with `let` is suggested, but it's not applied
+/// automatically as the assignment may be a mistake.
+#[derive(Debug)]
+pub struct NoConstAssign;
+
+impl Rule for NoConstAssign {
+    fn id(&self) -> &'static str {
+        "no-const-assign"
+    }
+
+    fn lint(&self, ctx: &LintContext, program: &Program) {
+        let analysis = ctx.analysis();
+
+        let mut v = ConstKeywords::default();
+        program.visit_with(&Invalid { span: DUMMY_SP }, &mut v);
+
+        for b in analysis.bindings() {
+            if b.kind != BindingKind::Const || !b.reassigned {
+                continue;
+            }
+
+            let message = format!("`{}` is a constant", b.id.0);
+            for r in analysis.references_to(&b.id) {
+                if !r.kind.is_write() {
+                    continue;
+                }
+
+                match v.keywords.get(&b.id) {
+                    Some(&keyword) => ctx.report_with_fix(
+                        r.span,
+                        &*message,
+                        LintFix {
+                            message: format!("declare `{}` with `let`", b.id.0),
+                            edits: vec![(keyword, "let".into())],
+                            applicability: Applicability::MaybeIncorrect,
+                        },
+                    ),
+                    None => ctx.report(r.span, &*message),
+                }
+            }
+        }
+    }
+}
+
+/// Spans of `const` keywords, by the declared variables.
+#[derive(Default)]
+struct ConstKeywords {
+    keywords: FxHashMap<Id, Span>,
+}
+
+impl Visit for ConstKeywords {
+    noop_visit_type!();
+
+    fn visit_var_decl(&mut self, n: &VarDecl, _: &dyn Node) {
+        if n.kind == VarDeclKind::Const {
+            let keyword = n.span.with_hi(n.span.lo + BytePos(5));
+            let ids: Vec<Id> = find_ids(&n.decls);
+            self.keywords
+                .extend(ids.into_iter().map(|id| (id, keyword)));
+        }
+
+        n.visit_children_with(self);
+    }
+}
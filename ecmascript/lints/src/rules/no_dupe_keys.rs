@@ -0,0 +1,97 @@
+use crate::rule::{LintContext, Rule};
+use fxhash::FxHashMap;
+use swc_atoms::JsWord;
+use swc_common::{Spanned, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{Node, Visit, VisitWith};
+
+/// `no-dupe-keys`: Reports duplicate keys in object literals.
+///
+/// A getter and a setter with the same key are allowed.
+#[derive(Debug)]
+pub struct NoDupeKeys;
+
+impl Rule for NoDupeKeys {
+    fn id(&self) -> &'static str {
+        "no-dupe-keys"
+    }
+
+    fn lint(&self, ctx: &LintContext, program: &Program) {
+        program.visit_with(&Invalid { span: DUMMY_SP }, &mut Visitor { ctx });
+    }
+}
+
+#[derive(Default, Clone, Copy)]
+struct Defined {
+    value: bool,
+    getter: bool,
+    setter: bool,
+}
+
+struct Visitor<'a, 'b> {
+    ctx: &'a LintContext<'b>,
+}
+
+impl Visit for Visitor<'_, '_> {
+    fn visit_object_lit(&mut self, n: &ObjectLit, _: &dyn Node) {
+        let mut keys: FxHashMap<JsWord, Defined> = Default::default();
+
+        for prop in &n.props {
+            let prop = match prop {
+                PropOrSpread::Prop(prop) => prop,
+                PropOrSpread::Spread(..) => continue,
+            };
+            let (key, span) = match &**prop {
+                Prop::Shorthand(i) => (Some(i.sym.clone()), i.span),
+                Prop::KeyValue(KeyValueProp { key, .. })
+                | Prop::Getter(GetterProp { key, .. })
+                | Prop::Setter(SetterProp { key, .. })
+                | Prop::Method(MethodProp { key, .. }) => (static_key(key), key.span()),
+                Prop::Assign(..) => continue,
+            };
+            let key = match key {
+                Some(key) => key,
+                None => continue,
+            };
+
+            let defined = keys.entry(key.clone()).or_default();
+            let is_dupe = match &**prop {
+                Prop::Getter(..) => {
+                    let is_dupe = defined.value || defined.getter;
+                    defined.getter = true;
+                    is_dupe
+                }
+                Prop::Setter(..) => {
+                    let is_dupe = defined.value || defined.setter;
+                    defined.setter = true;
+                    is_dupe
+                }
+                _ => {
+                    let is_dupe = defined.value || defined.getter || defined.setter;
+                    defined.value = true;
+                    is_dupe
+                }
+            };
+
+            if is_dupe {
+                self.ctx.report(span, format!("duplicate key `{}`", key));
+            }
+        }
+
+        n.visit_children_with(self);
+    }
+}
+
+fn static_key(key: &PropName) -> Option<JsWord> {
+    match key {
+        PropName::Ident(i) => Some(i.sym.clone()),
+        PropName::Str(s) => Some(s.value.clone()),
+        PropName::Num(n) => Some(n.value.to_string().into()),
+        PropName::BigInt(n) => Some(n.value.to_string().into()),
+        PropName::Computed(c) => match &*c.expr {
+            Expr::Lit(Lit::Str(s)) => Some(s.value.clone()),
+            Expr::Lit(Lit::Num(n)) => Some(n.value.to_string().into()),
+            _ => None,
+        },
+    }
+}
@@ -0,0 +1,39 @@
+use crate::rule::{LintContext, Rule};
+use swc_common::{EqIgnoreSpan, Spanned, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{noop_visit_type, Node, Visit, VisitWith};
+
+/// `no-duplicate-case`: Reports `case` labels which are identical to a
+/// previous one in the same `switch` statement.
+#[derive(Debug)]
+pub struct NoDuplicateCase;
+
+impl Rule for NoDuplicateCase {
+    fn id(&self) -> &'static str {
+        "no-duplicate-case"
+    }
+
+    fn lint(&self, ctx: &LintContext, program: &Program) {
+        program.visit_with(&Invalid { span: DUMMY_SP }, &mut Visitor { ctx });
+    }
+}
+
+struct Visitor<'a, 'b> {
+    ctx: &'a LintContext<'b>,
+}
+
+impl Visit for Visitor<'_, '_> {
+    noop_visit_type!();
+
+    fn visit_switch_stmt(&mut self, n: &SwitchStmt, _: &dyn Node) {
+        let tests: Vec<_> = n.cases.iter().filter_map(|c| c.test.as_ref()).collect();
+
+        for (i, test) in tests.iter().enumerate() {
+            if tests[..i].iter().any(|prev| prev.eq_ignore_span(test)) {
+                self.ctx.report(test.span(), "duplicate case label");
+            }
+        }
+
+        n.visit_children_with(self);
+    }
+}
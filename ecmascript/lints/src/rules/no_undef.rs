@@ -0,0 +1,164 @@
+use crate::{
+    config::NoUndefConfig,
+    rule::{LintContext, Rule},
+};
+use fxhash::FxHashSet;
+use swc_atoms::{js_word, JsWord};
+use swc_common::{Span, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_transforms_base::scope::ScopeId;
+use swc_ecma_utils::{ident::IdentLike, Id};
+use swc_ecma_visit::{Node, Visit, VisitWith};
+
+/// Builtin globals of ECMAScript.
+static BUILTINS: &[&str] = &[
+    "AggregateError",
+    "Array",
+    "ArrayBuffer",
+    "Atomics",
+    "BigInt",
+    "BigInt64Array",
+    "BigUint64Array",
+    "Boolean",
+    "DataView",
+    "Date",
+    "decodeURI",
+    "decodeURIComponent",
+    "encodeURI",
+    "encodeURIComponent",
+    "Error",
+    "escape",
+    "eval",
+    "EvalError",
+    "FinalizationRegistry",
+    "Float32Array",
+    "Float64Array",
+    "Function",
+    "globalThis",
+    "Infinity",
+    "Int16Array",
+    "Int32Array",
+    "Int8Array",
+    "isFinite",
+    "isNaN",
+    "JSON",
+    "Map",
+    "Math",
+    "NaN",
+    "Number",
+    "Object",
+    "parseFloat",
+    "parseInt",
+    "Promise",
+    "Proxy",
+    "RangeError",
+    "ReferenceError",
+    "Reflect",
+    "RegExp",
+    "Set",
+    "SharedArrayBuffer",
+    "String",
+    "Symbol",
+    "SyntaxError",
+    "TypeError",
+    "Uint16Array",
+    "Uint32Array",
+    "Uint8Array",
+    "Uint8ClampedArray",
+    "undefined",
+    "unescape",
+    "URIError",
+    "WeakMap",
+    "WeakRef",
+    "WeakSet",
+];
+
+/// `no-undef`: Reports references to variables which are not declared.
+#[derive(Debug)]
+pub struct NoUndef {
+    globals: FxHashSet<JsWord>,
+    check_typeof: bool,
+}
+
+impl NoUndef {
+    pub fn new(config: &NoUndefConfig) -> Self {
+        NoUndef {
+            globals: BUILTINS
+                .iter()
+                .map(|&s| JsWord::from(s))
+                .chain(config.globals.iter().cloned())
+                .collect(),
+            check_typeof: config.check_typeof,
+        }
+    }
+}
+
+impl Rule for NoUndef {
+    fn id(&self) -> &'static str {
+        "no-undef"
+    }
+
+    fn lint(&self, ctx: &LintContext, program: &Program) {
+        let mut v = Collector::default();
+        program.visit_with(&Invalid { span: DUMMY_SP }, &mut v);
+
+        let analysis = ctx.analysis();
+        for r in analysis.unresolved_references() {
+            let sym = &r.id.0;
+            if self.globals.contains(sym) || v.ts_decls.contains(&r.id) {
+                continue;
+            }
+            if !self.check_typeof && v.typeof_args.contains(&r.span) {
+                continue;
+            }
+            // `arguments` is defined in functions.
+            if *sym == js_word!("arguments") && analysis.fn_scope(r.scope) != ScopeId::ROOT {
+                continue;
+            }
+
+            ctx.report(r.span, format!("`{}` is not defined", sym));
+        }
+    }
+}
+
+#[derive(Default)]
+struct Collector {
+    /// Spans of `a` in `typeof a`.
+    typeof_args: FxHashSet<Span>,
+    /// Enums, namespaces and `import a = require('a')`, which are not
+    /// bindings of
+    /// [ScopeAnalysis](swc_ecma_transforms_base::scope::ScopeAnalysis).
+    ts_decls: FxHashSet<Id>,
+}
+
+impl Visit for Collector {
+    fn visit_unary_expr(&mut self, n: &UnaryExpr, _: &dyn Node) {
+        if n.op == op!("typeof") {
+            if let Expr::Ident(i) = &*n.arg {
+                self.typeof_args.insert(i.span);
+            }
+        }
+
+        n.visit_children_with(self);
+    }
+
+    fn visit_ts_enum_decl(&mut self, n: &TsEnumDecl, _: &dyn Node) {
+        self.ts_decls.insert(n.id.to_id());
+
+        n.visit_children_with(self);
+    }
+
+    fn visit_ts_module_decl(&mut self, n: &TsModuleDecl, _: &dyn Node) {
+        if let TsModuleName::Ident(i) = &n.id {
+            self.ts_decls.insert(i.to_id());
+        }
+
+        n.visit_children_with(self);
+    }
+
+    fn visit_ts_import_equals_decl(&mut self, n: &TsImportEqualsDecl, _: &dyn Node) {
+        self.ts_decls.insert(n.id.to_id());
+
+        n.visit_children_with(self);
+    }
+}
@@ -0,0 +1,82 @@
+use super::always_exits;
+use crate::rule::{LintContext, Rule};
+use swc_common::{Span, Spanned, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{Node, Visit, VisitWith};
+
+/// `no-unreachable`: Reports statements after `return`, `throw`, `break` and
+/// `continue`.
+///
+/// Function declarations and `var` declarations without initializers are not
+/// reported because they are hoisted.
+#[derive(Debug)]
+pub struct NoUnreachable;
+
+impl Rule for NoUnreachable {
+    fn id(&self) -> &'static str {
+        "no-unreachable"
+    }
+
+    fn lint(&self, ctx: &LintContext, program: &Program) {
+        program.visit_with(&Invalid { span: DUMMY_SP }, &mut Visitor { ctx });
+    }
+}
+
+struct Visitor<'a, 'b> {
+    ctx: &'a LintContext<'b>,
+}
+
+impl Visitor<'_, '_> {
+    fn check<'a>(&self, stmts: impl Iterator<Item = &'a Stmt>) {
+        let mut stmts = stmts.skip_while(|s| !always_exits(s, true));
+        if stmts.next().is_none() {
+            return;
+        }
+
+        // Consecutive unreachable statements are reported at once.
+        let span = stmts.filter(|s| !is_hoisted(s)).map(|s| s.span()).fold(
+            None,
+            |acc: Option<Span>, span| match acc {
+                Some(acc) => Some(acc.to(span)),
+                None => Some(span),
+            },
+        );
+        if let Some(span) = span {
+            self.ctx.report(span, "unreachable code");
+        }
+    }
+}
+
+impl Visit for Visitor<'_, '_> {
+    fn visit_module_items(&mut self, n: &[ModuleItem], _: &dyn Node) {
+        self.check(n.iter().filter_map(|item| match item {
+            ModuleItem::Stmt(s) => Some(s),
+            ModuleItem::ModuleDecl(..) => None,
+        }));
+
+        for item in n {
+            item.visit_with(&Invalid { span: DUMMY_SP }, self);
+        }
+    }
+
+    fn visit_stmts(&mut self, n: &[Stmt], _: &dyn Node) {
+        self.check(n.iter());
+
+        for stmt in n {
+            stmt.visit_with(&Invalid { span: DUMMY_SP }, self);
+        }
+    }
+}
+
+fn is_hoisted(stmt: &Stmt) -> bool {
+    match stmt {
+        Stmt::Empty(..)
+        | Stmt::Decl(Decl::Fn(..))
+        | Stmt::Decl(Decl::TsInterface(..))
+        | Stmt::Decl(Decl::TsTypeAlias(..)) => true,
+        Stmt::Decl(Decl::Var(v)) => {
+            v.declare || (v.kind == VarDeclKind::Var && v.decls.iter().all(|d| d.init.is_none()))
+        }
+        _ => false,
+    }
+}
@@ -0,0 +1,246 @@
+use crate::{
+    config::{LintRuleLevel, NoUnusedVarsConfig},
+    rule::{LintContext, LintFix, Rule},
+};
+use fxhash::{FxHashMap, FxHashSet};
+use regex::Regex;
+use swc_common::{errors::Applicability, BytePos, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_transforms_base::scope::{Binding, BindingKind, ScopeAnalysis};
+use swc_ecma_utils::{find_ids, ident::IdentLike, Id};
+use swc_ecma_visit::{Node, Visit, VisitWith};
+
+/// `no-unused-vars`: Reports variables, functions, classes, imports and
+/// parameters which are never read.
+///
+/// Exported bindings, parameters of `catch` clauses and names of function or
+/// class expressions are not reported.
+///
+/// Unused imports are fixed by removing them.
+#[derive(Debug)]
+pub struct NoUnusedVars {
+    args: bool,
+    ignore_pattern: Option<Regex>,
+}
+
+impl NoUnusedVars {
+    pub fn new(config: &NoUnusedVarsConfig) -> Result<Self, regex::Error> {
+        Ok(NoUnusedVars {
+            args: config.args,
+            ignore_pattern: config
+                .ignore_pattern
+                .as_deref()
+                .map(Regex::new)
+                .transpose()?,
+        })
+    }
+}
+
+impl Rule for NoUnusedVars {
+    fn id(&self) -> &'static str {
+        "no-unused-vars"
+    }
+
+    fn default_level(&self) -> LintRuleLevel {
+        LintRuleLevel::Warn
+    }
+
+    fn lint(&self, ctx: &LintContext, program: &Program) {
+        let mut v = Collector::default();
+        program.visit_with(&Invalid { span: DUMMY_SP }, &mut v);
+
+        let analysis = ctx.analysis();
+        let is_used = |b: &Binding| {
+            v.type_refs.contains(&b.id) || analysis.references_to(&b.id).any(|r| r.kind.is_read())
+        };
+
+        // Only parameters after the last used one are reported.
+        let mut last_used_params: FxHashMap<_, BytePos> = Default::default();
+        for b in analysis.bindings() {
+            if b.kind == BindingKind::Param && is_used(b) {
+                let last = last_used_params.entry(b.scope).or_insert(b.span.lo);
+                *last = (*last).max(b.span.lo);
+            }
+        }
+
+        let mut unused = vec![];
+        for b in analysis.bindings() {
+            if b.exported || b.kind == BindingKind::CatchParam || v.ignored.contains(&b.id) {
+                continue;
+            }
+            if is_used(b) {
+                continue;
+            }
+            if self
+                .ignore_pattern
+                .as_ref()
+                .map_or(false, |pattern| pattern.is_match(&b.id.0))
+            {
+                continue;
+            }
+            if b.kind == BindingKind::Param
+                && (!self.args
+                    || last_used_params
+                        .get(&b.scope)
+                        .map_or(false, |&last| last > b.span.lo))
+            {
+                continue;
+            }
+
+            unused.push(b);
+        }
+
+        // Imported bindings, with their declarations and indices of specifiers.
+        let mut imports = FxHashMap::default();
+        if let Program::Module(m) = program {
+            for item in &m.body {
+                if let ModuleItem::ModuleDecl(ModuleDecl::Import(decl)) = item {
+                    for (idx, s) in decl.specifiers.iter().enumerate() {
+                        imports.insert(local(s).to_id(), (decl, idx));
+                    }
+                }
+            }
+        }
+
+        let unused_ids: FxHashSet<_> = unused.iter().map(|b| &b.id).collect();
+        for b in unused {
+            let message = message(analysis, b);
+            match imports.get(&b.id) {
+                Some(&(decl, idx)) => match import_fix(decl, idx, &unused_ids) {
+                    Some(fix) => ctx.report_with_fix(b.span, message, fix),
+                    None => ctx.report(b.span, message),
+                },
+                None => ctx.report(b.span, message),
+            }
+        }
+    }
+}
+
+/// Removes the `idx`th specifier of `decl`, or the whole import if all
+/// specifiers are unused.
+fn import_fix(decl: &ImportDecl, idx: usize, unused: &FxHashSet<&Id>) -> Option<LintFix> {
+    let is_unused = |s: &ImportSpecifier| unused.contains(&local(s).to_id());
+
+    if decl.specifiers.iter().all(is_unused) {
+        // The module is still imported for its side effects, unless it's a
+        // type-only import.
+        let edit = if decl.type_only {
+            (decl.span, String::new())
+        } else {
+            (decl.span.with_hi(decl.src.span.lo), "import ".into())
+        };
+        return Some(LintFix {
+            message: "remove the unused import".into(),
+            edits: vec![edit],
+            applicability: Applicability::MachineApplicable,
+        });
+    }
+
+    // Only named specifiers between braces can be removed with a comma.
+    let named = |idx: usize| match decl.specifiers.get(idx) {
+        Some(ImportSpecifier::Named(s)) => Some(s.span),
+        _ => None,
+    };
+    let span = named(idx)?;
+    let span = match named(idx + 1) {
+        Some(next) => span.with_hi(next.lo),
+        None => span.with_lo(named(idx.checked_sub(1)?)?.hi),
+    };
+
+    Some(LintFix {
+        message: format!("remove `{}`", local(&decl.specifiers[idx]).sym),
+        edits: vec![(span, String::new())],
+        applicability: Applicability::MachineApplicable,
+    })
+}
+
+fn local(s: &ImportSpecifier) -> &Ident {
+    match s {
+        ImportSpecifier::Named(s) => &s.local,
+        ImportSpecifier::Default(s) => &s.local,
+        ImportSpecifier::Namespace(s) => &s.local,
+    }
+}
+
+fn message(analysis: &ScopeAnalysis, b: &Binding) -> String {
+    let assigned = match b.kind {
+        BindingKind::Var | BindingKind::Let | BindingKind::Const => {
+            b.reassigned || analysis.references_to(&b.id).next().is_some()
+        }
+        _ => false,
+    };
+
+    if assigned {
+        format!("`{}` is assigned a value but never used", b.id.0)
+    } else {
+        format!("`{}` is defined but never used", b.id.0)
+    }
+}
+
+#[derive(Default)]
+struct Collector {
+    /// Identifiers referenced from types, like `A` in `let a: A`.
+    type_refs: FxHashSet<Id>,
+    /// Bindings which should not be reported.
+    ignored: FxHashSet<Id>,
+}
+
+impl Visit for Collector {
+    fn visit_class_decl(&mut self, n: &ClassDecl, _: &dyn Node) {
+        if n.declare {
+            self.ignored.insert(n.ident.to_id());
+        }
+
+        n.visit_children_with(self);
+    }
+
+    fn visit_class_expr(&mut self, n: &ClassExpr, _: &dyn Node) {
+        if let Some(ident) = &n.ident {
+            self.ignored.insert(ident.to_id());
+        }
+
+        n.visit_children_with(self);
+    }
+
+    fn visit_fn_decl(&mut self, n: &FnDecl, _: &dyn Node) {
+        if n.declare {
+            self.ignored.insert(n.ident.to_id());
+        }
+
+        n.visit_children_with(self);
+    }
+
+    fn visit_fn_expr(&mut self, n: &FnExpr, _: &dyn Node) {
+        if let Some(ident) = &n.ident {
+            self.ignored.insert(ident.to_id());
+        }
+
+        n.visit_children_with(self);
+    }
+
+    fn visit_ts_entity_name(&mut self, n: &TsEntityName, _: &dyn Node) {
+        match n {
+            TsEntityName::Ident(i) => {
+                self.type_refs.insert(i.to_id());
+            }
+            TsEntityName::TsQualifiedName(q) => q.left.visit_with(n, self),
+        }
+    }
+
+    /// Parameter properties are properties of the class.
+    fn visit_ts_param_prop(&mut self, n: &TsParamProp, _: &dyn Node) {
+        let ids: Vec<Id> = find_ids(&n.param);
+        self.ignored.extend(ids);
+
+        n.visit_children_with(self);
+    }
+
+    fn visit_var_decl(&mut self, n: &VarDecl, _: &dyn Node) {
+        if n.declare {
+            let ids: Vec<Id> = find_ids(&n.decls);
+            self.ignored.extend(ids);
+        }
+
+        n.visit_children_with(self);
+    }
+}
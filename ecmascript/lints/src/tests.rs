@@ -0,0 +1,347 @@
+use super::*;
+use swc_common::{
+    comments::SingleThreadedComments, errors::Applicability, FileName, Mark, SourceMap,
+};
+use swc_ecma_parser::{Parser, StringInput, Syntax, TsConfig};
+use swc_ecma_transforms_base::resolver::ts_resolver;
+use swc_ecma_visit::FoldWith;
+
+/// Returns ids of rules and source code of problems.
+fn lint_with(config: &str, syntax: Syntax, src: &str) -> Vec<(&'static str, String)> {
+    run_lint(config, syntax, src, |cm, d| {
+        (d.rule, cm.span_to_snippet(d.span).unwrap())
+    })
+}
+
+fn run_lint<T>(
+    config: &str,
+    syntax: Syntax,
+    src: &str,
+    op: impl Fn(&SourceMap, LintDiagnostic) -> T,
+) -> Vec<T> {
+    let config: LintConfig = serde_json::from_str(config).unwrap();
+
+    ::testing::run_test(false, |cm, handler| {
+        let fm = cm.new_source_file(FileName::Anon, src.into());
+        let comments = SingleThreadedComments::default();
+        let program = Parser::new(syntax, StringInput::from(&*fm), Some(&comments))
+            .parse_program()
+            .map_err(|err| err.into_diagnostic(handler).emit())?;
+        let program = program.fold_with(&mut ts_resolver(Mark::fresh(Mark::root())));
+
+        let (leading, trailing) = comments.take_all();
+        let leading = leading.borrow();
+        let trailing = trailing.borrow();
+        let comments = leading.values().chain(trailing.values()).flatten();
+
+        Ok(Linter::new(&config)
+            .unwrap()
+            .lint(&cm, comments, &program)
+            .into_iter()
+            .map(|d| op(&cm, d))
+            .collect())
+    })
+    .unwrap()
+}
+
+fn lint(src: &str) -> Vec<(&'static str, String)> {
+    lint_with("{}", Syntax::default(), src)
+}
+
+fn problems(rule: &'static str, snippets: &[&str]) -> Vec<(&'static str, String)> {
+    snippets.iter().map(|s| (rule, s.to_string())).collect()
+}
+
+#[test]
+fn no_undef() {
+    assert_eq!(
+        lint("foo(Math, undefined); typeof bar; export function f() { return arguments }"),
+        problems("no-undef", &["foo"])
+    );
+    assert_eq!(
+        lint_with(
+            r#"{ "no-undef": ["error", { "globals": ["foo"], "checkTypeof": true }] }"#,
+            Syntax::default(),
+            "foo(); typeof bar;"
+        ),
+        problems("no-undef", &["bar"])
+    );
+    assert_eq!(
+        lint_with(
+            "{}",
+            Syntax::Typescript(TsConfig::default()),
+            "enum E { A } namespace N {} export const a: Foo = [E.A, N];"
+        ),
+        vec![]
+    );
+}
+
+#[test]
+fn no_unused_vars() {
+    assert_eq!(
+        lint(
+            "
+import { a, b } from 'a';
+let c = 1;
+c = 2;
+function d(e, f, g) { return f }
+try {} catch (h) {}
+(function i() {});
+export const j = a;
+"
+        ),
+        problems("no-unused-vars", &["b", "c", "d", "g"])
+    );
+    assert_eq!(
+        lint_with(
+            r#"{ "no-unused-vars": ["warn", { "args": false, "ignorePattern": "^_" }] }"#,
+            Syntax::default(),
+            "let _a; function f(b) {} f();"
+        ),
+        vec![]
+    );
+    assert_eq!(
+        lint_with(
+            "{}",
+            Syntax::Typescript(TsConfig::default()),
+            "import { A } from 'a'; export let a: A;"
+        ),
+        vec![]
+    );
+}
+
+/// Replaced source code and replacements.
+type Fix = (Vec<(String, String)>, Applicability);
+
+/// Returns source code of problems and edits of their fixes.
+fn fixes(src: &str) -> Vec<(String, Option<Fix>)> {
+    run_lint("{}", Syntax::default(), src, |cm, d| {
+        let fix = d.fix.map(|fix| {
+            let edits = fix
+                .edits
+                .into_iter()
+                .map(|(span, s)| (cm.span_to_snippet(span).unwrap(), s))
+                .collect();
+            (edits, fix.applicability)
+        });
+        (cm.span_to_snippet(d.span).unwrap(), fix)
+    })
+}
+
+fn edit(snippet: &str, replacement: &str) -> (String, String) {
+    (snippet.into(), replacement.into())
+}
+
+#[test]
+fn no_unused_vars_fix() {
+    assert_eq!(
+        fixes(
+            "
+import a, { b } from 'a';
+import * as c from 'c';
+import { d, e, f, g } from 'd';
+import h, { i } from 'h';
+export { e, h };
+"
+        ),
+        vec![
+            (
+                "a".into(),
+                Some((
+                    vec![edit("import a, { b } from ", "import ")],
+                    Applicability::MachineApplicable
+                ))
+            ),
+            (
+                "b".into(),
+                Some((
+                    vec![edit("import a, { b } from ", "import ")],
+                    Applicability::MachineApplicable
+                ))
+            ),
+            (
+                "c".into(),
+                Some((
+                    vec![edit("import * as c from ", "import ")],
+                    Applicability::MachineApplicable
+                ))
+            ),
+            (
+                "d".into(),
+                Some((vec![edit("d, ", "")], Applicability::MachineApplicable))
+            ),
+            (
+                "f".into(),
+                Some((vec![edit("f, ", "")], Applicability::MachineApplicable))
+            ),
+            (
+                "g".into(),
+                Some((vec![edit(", g", "")], Applicability::MachineApplicable))
+            ),
+            ("i".into(), None),
+        ]
+    );
+}
+
+#[test]
+fn no_dupe_keys() {
+    assert_eq!(
+        lint(
+            "
+export default {
+    a: 1,
+    'a': 2,
+    ['a']: 3,
+    1: 1,
+    1.0: 1,
+    get b() { return 1 },
+    set b(v) { v },
+    get b() { return 1 },
+};
+"
+        ),
+        problems("no-dupe-keys", &["'a'", "['a']", "1.0", "b"])
+    );
+}
+
+#[test]
+fn no_const_assign() {
+    assert_eq!(
+        lint("const a = 1; a = 2; a++; for (const b of a) b; export { a };"),
+        problems("no-const-assign", &["a", "a"])
+    );
+}
+
+#[test]
+fn no_const_assign_fix() {
+    assert_eq!(
+        fixes("export const a = 1; a = 2;"),
+        vec![(
+            "a".into(),
+            Some((vec![edit("const", "let")], Applicability::MaybeIncorrect))
+        )]
+    );
+}
+
+#[test]
+fn no_unreachable() {
+    assert_eq!(
+        lint(
+            "
+export function f(a) {
+    if (a) {
+        return 1;
+    } else {
+        throw a;
+    }
+    f(a);
+    var b;
+    function g() {}
+    f(b, g);
+}
+"
+        ),
+        problems(
+            "no-unreachable",
+            &["f(a);\n    var b;\n    function g() {}\n    f(b, g);"]
+        )
+    );
+    assert_eq!(
+        lint("export function f(a) { switch (a) { case 1: break; default: return } f(); }"),
+        vec![]
+    );
+    assert_eq!(
+        lint("export function f(a) { while (a) { if (a) { continue; } break; f(); } }"),
+        problems("no-unreachable", &["f();"])
+    );
+}
+
+#[test]
+fn getter_return() {
+    assert_eq!(
+        lint(
+            "
+export default {
+    get a() {},
+    get b() { if (this) return 1; },
+    get c() { return; },
+    get d() { if (this) { return 1 } else { throw 1 } },
+    get e() { [].map(() => { return; }); return 1; },
+};
+export class A {
+    get f() { switch (this) { case 1: return 1; default: return 2; } }
+    get g() { try { return 1 } finally {} }
+    get h() { try { this.x() } catch (e) { return 1 } }
+}
+"
+        ),
+        vec![
+            ("getter-return", "a".into()),
+            ("getter-return", "b".into()),
+            ("getter-return", "return;".into()),
+            ("getter-return", "h".into()),
+        ]
+    );
+}
+
+#[test]
+fn no_duplicate_case() {
+    assert_eq!(
+        lint(
+            "export function f(a) { switch (a) { case a.b: case 1: case a.b: case a.c: case 1: } }"
+        ),
+        problems("no-duplicate-case", &["a.b", "1"])
+    );
+}
+
+#[test]
+fn levels() {
+    let config = r#"{ "no-undef": "off", "no-unused-vars": "error", "no-dupe-keys": null }"#;
+    let config: LintConfig = serde_json::from_str(config).unwrap();
+    let rules = rules::all(&config).unwrap();
+    let levels: Vec<_> = rules.iter().map(|(r, level)| (r.id(), *level)).collect();
+
+    assert_eq!(
+        levels[..2],
+        [
+            ("no-unused-vars", LintRuleLevel::Error),
+            ("no-dupe-keys", LintRuleLevel::Error)
+        ]
+    );
+
+    let default_config = rules::all(&Default::default()).unwrap();
+    assert_eq!(
+        default_config
+            .iter()
+            .find(|(r, _)| r.id() == "no-unused-vars")
+            .unwrap()
+            .1,
+        LintRuleLevel::Warn
+    );
+
+    assert!(serde_json::from_str::<LintConfig>(r#"{ "no-such-rule": "off" }"#).is_err());
+    assert!(Linter::new(
+        &serde_json::from_str(r#"{ "no-unused-vars": ["warn", { "ignorePattern": "(" }] }"#)
+            .unwrap()
+    )
+    .is_err());
+}
+
+#[test]
+fn disable_next_line() {
+    assert_eq!(
+        lint(
+            "
+// swc-disable-next-line
+a();
+// swc-disable-next-line no-unused-vars -- b is not a problem
+b();
+/* swc-disable-next-line no-undef, no-unused-vars */
+let c = d;
+// swc-disable-next-lines
+e();
+"
+        ),
+        problems("no-undef", &["b", "e"])
+    );
+}
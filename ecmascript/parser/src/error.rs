@@ -44,6 +44,7 @@ pub enum SyntaxError {
     ArrowNotAllowed,
     ExportNotAllowed,
     GetterSetterCannotBeReadonly,
+    AutoAccessorCannotBeReadonly,
 
     TopLevelAwait,
 
@@ -445,6 +446,9 @@ impl SyntaxError {
             SyntaxError::GetterSetterCannotBeReadonly => {
                 "A getter or a setter cannot be readonly".into()
             }
+            SyntaxError::AutoAccessorCannotBeReadonly => {
+                "An auto-accessor cannot be readonly".into()
+            }
             SyntaxError::RestPatInSetter => "Rest pattern is not allowed in setter".into(),
 
             SyntaxError::GeneratorConstructor => "A constructor cannot be generator".into(),
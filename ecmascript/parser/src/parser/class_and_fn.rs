@@ -753,6 +753,29 @@ impl<'a, I: Tokens> Parser<I> {
             );
         }
 
+        if (self.syntax().decorators() || self.syntax().typescript())
+            && match key {
+                Either::Right(PropName::Ident(ref i)) => &*i.sym == "accessor",
+                _ => false,
+            }
+            && !self.input.had_line_break_before_cur()
+        {
+            // handle accessor foo = 1;
+
+            if readonly.is_some() {
+                self.emit_err(
+                    span!(self, start),
+                    SyntaxError::AutoAccessorCannotBeReadonly,
+                );
+            }
+
+            let key = self.parse_class_prop_name()?;
+            if is_constructor(&key) {
+                syntax_error!(self, key.span(), SyntaxError::PropertyNamedConstructor);
+            }
+            return self.make_auto_accessor(start, decorators, accessibility, key, is_static);
+        }
+
         let is_next_line_generator = self.input.had_line_break_before_cur() && is!(self, '*');
         let key_span = key.span();
 
@@ -928,6 +951,49 @@ impl<'a, I: Tokens> Parser<I> {
         })
     }
 
+    fn make_auto_accessor(
+        &mut self,
+        start: BytePos,
+        decorators: Vec<Decorator>,
+        accessibility: Option<Accessibility>,
+        key: Either<PrivateName, PropName>,
+        is_static: bool,
+    ) -> PResult<ClassMember> {
+        let type_ann = self.try_parse_ts_type_ann()?;
+
+        let ctx = Context {
+            in_class_prop: true,
+            in_method: false,
+            include_in_expr: true,
+            ..self.ctx()
+        };
+        self.with_ctx(ctx).parse_with(|p| {
+            let value = if eat!(p, '=') {
+                Some(p.parse_assignment_expr()?)
+            } else {
+                None
+            };
+
+            if !eat!(p, ';') {
+                p.emit_err(p.input.cur_span(), SyntaxError::TS1005);
+            }
+
+            Ok(AutoAccessor {
+                span: span!(p, start),
+                key: match key {
+                    Either::Left(key) => Key::Private(key),
+                    Either::Right(key) => Key::Public(key),
+                },
+                value,
+                type_ann,
+                is_static,
+                decorators,
+                accessibility,
+            }
+            .into())
+        })
+    }
+
     fn is_class_method(&mut self) -> PResult<bool> {
         Ok(is!(self, '(')
             || (self.input.syntax().typescript() && is!(self, '<'))
@@ -1403,6 +1469,7 @@ struct MakeMethodArgs {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::EsConfig;
     use swc_common::DUMMY_SP as span;
     use swc_ecma_visit::assert_eq_ignore_span;
 
@@ -1436,4 +1503,62 @@ mod tests {
             }))
         );
     }
+
+    fn class_member(s: &'static str) -> ClassMember {
+        test_parser(
+            s,
+            Syntax::Es(EsConfig {
+                decorators: true,
+                ..Default::default()
+            }),
+            |p| p.parse_class_member(),
+        )
+    }
+
+    #[test]
+    fn auto_accessor() {
+        assert_eq_ignore_span!(
+            class_member("static accessor #x = 1;"),
+            ClassMember::AutoAccessor(AutoAccessor {
+                span,
+                key: Key::Private(PrivateName {
+                    span,
+                    id: Ident::new("x".into(), span),
+                }),
+                value: Some(expr("1")),
+                type_ann: None,
+                is_static: true,
+                decorators: vec![],
+                accessibility: None,
+            })
+        );
+    }
+
+    #[test]
+    fn auto_accessor_decorated() {
+        assert_eq_ignore_span!(
+            class_member("@dec accessor [a];"),
+            ClassMember::AutoAccessor(AutoAccessor {
+                span,
+                key: Key::Public(PropName::Computed(ComputedPropName {
+                    span,
+                    expr: expr("a"),
+                })),
+                value: None,
+                type_ann: None,
+                is_static: false,
+                decorators: vec![Decorator {
+                    span,
+                    expr: expr("dec"),
+                }],
+                accessibility: None,
+            })
+        );
+    }
+
+    #[test]
+    fn property_named_accessor() {
+        assert!(class_member("accessor = 1;").is_class_prop());
+        assert!(class_member("accessor() {}").is_method());
+    }
 }
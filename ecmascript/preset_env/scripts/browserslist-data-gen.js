@@ -0,0 +1,55 @@
+// Generates data used by `src/browserslist`.
+//
+// Usage: node scripts/browserslist-data-gen.js
+const fs = require('fs');
+const path = require('path');
+const { agents } = require('caniuse-lite/dist/unpacker/agents');
+const electron = require('electron-to-chromium/versions');
+const nodeReleases = require('node-releases/data/processed/envs.json');
+const nodeSchedule = require('node-releases/data/release-schedule/release-schedule.json');
+
+const outDir = path.join(__dirname, '..', 'src', 'browserslist');
+
+function write(name, lines, open, close) {
+    const content = `${open}\n${lines.join(',\n')}\n${close}\n`;
+    fs.writeFileSync(path.join(outDir, name), content);
+}
+
+// [version, release date, global usage]
+const agentLines = Object.keys(agents).map((name) => {
+    const agent = agents[name];
+    const versions = agent.versions
+        .filter((v) => v)
+        .map((v) => '    ' + JSON.stringify([v, agent.release_date[v] || null, agent.usage_global[v] || 0])
+            .replace(/,/g, ', '));
+
+    return `  ${JSON.stringify(name)}: [\n${versions.join(',\n')}\n  ]`;
+});
+write('agents.json', agentLines, '{', '}');
+
+// [electron version, chrome version]
+const electronLines = Object.keys(electron)
+    .filter((v) => /^\d+\.\d+$/.test(v))
+    .map((v) => `  ${JSON.stringify([v, electron[v]]).replace(/,/g, ', ')}`);
+write('electron.json', electronLines, '[', ']');
+
+const nodeLines = Object.keys(nodeSchedule)
+    .map((key) => {
+        const line = key.slice(1);
+        const releases = nodeReleases
+            .filter((r) => r.name === 'nodejs')
+            .filter((r) => r.version === line || r.version.startsWith(line + '.'));
+        if (releases.length === 0) {
+            return null;
+        }
+
+        return '  ' + JSON.stringify({
+            line,
+            first: releases[0].version,
+            latest: releases[releases.length - 1].version,
+            start: nodeSchedule[key].start,
+            end: nodeSchedule[key].end,
+        }).replace(/([,:])/g, '$1 ');
+    })
+    .filter((l) => l);
+write('node.json', nodeLines, '[', ']');
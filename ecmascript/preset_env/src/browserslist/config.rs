@@ -0,0 +1,228 @@
+//! Lookup of `.browserslistrc`, `browserslist` and `package.json`.
+
+use super::Error;
+use fxhash::FxHashMap;
+use serde::Deserialize;
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Queries {
+    Single(String),
+    Multiple(Vec<String>),
+}
+
+impl From<Queries> for Vec<String> {
+    fn from(q: Queries) -> Self {
+        match q {
+            Queries::Single(s) => vec![s],
+            Queries::Multiple(v) => v,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum PackageJsonConfig {
+    Queries(Queries),
+    Envs(FxHashMap<String, Queries>),
+}
+
+#[derive(Debug, Deserialize)]
+struct PackageJson {
+    #[serde(default)]
+    browserslist: Option<PackageJsonConfig>,
+    #[serde(default)]
+    main: Option<String>,
+}
+
+/// Name of the environment used to select a section of a config.
+fn env_name() -> String {
+    env::var("BROWSERSLIST_ENV")
+        .or_else(|_| env::var("NODE_ENV"))
+        .unwrap_or_else(|_| "production".into())
+}
+
+/// Finds queries for `path`, like browserslist does if no query is given.
+///
+/// Returns `None` if there's no config.
+pub(super) fn load(path: &Path) -> Result<Option<Vec<String>>, Error> {
+    if let Ok(queries) = env::var("BROWSERSLIST") {
+        return Ok(Some(vec![queries]));
+    }
+
+    let env = env_name();
+
+    if let Ok(file) = env::var("BROWSERSLIST_CONFIG") {
+        return parse_file(Path::new(&file), &env).map(Some);
+    }
+
+    for dir in path.ancestors() {
+        let mut found: Vec<PathBuf> = vec![];
+
+        for name in &["browserslist", ".browserslistrc"] {
+            let file = dir.join(name);
+            if file.is_file() {
+                found.push(file);
+            }
+        }
+
+        let package_json = dir.join("package.json");
+        if package_json.is_file() && read_package_json(&package_json)?.browserslist.is_some() {
+            found.push(package_json);
+        }
+
+        match found.len() {
+            0 => continue,
+            1 => return parse_file(&found[0], &env).map(Some),
+            _ => {
+                return Err(Error::Config(format!(
+                    "{} contains both {} and {}",
+                    dir.display(),
+                    found[0].display(),
+                    found[1].display()
+                )))
+            }
+        }
+    }
+
+    Ok(None)
+}
+
+fn read_package_json(path: &Path) -> Result<PackageJson, Error> {
+    let content = fs::read_to_string(path)
+        .map_err(|err| Error::Config(format!("failed to read {}: {}", path.display(), err)))?;
+
+    serde_json::from_str(&content)
+        .map_err(|err| Error::Config(format!("failed to parse {}: {}", path.display(), err)))
+}
+
+fn parse_file(path: &Path, env: &str) -> Result<Vec<String>, Error> {
+    if path
+        .file_name()
+        .map_or(false, |name| name == "package.json")
+    {
+        let config = read_package_json(path)?.browserslist;
+
+        return Ok(match config {
+            None => vec![],
+            Some(PackageJsonConfig::Queries(q)) => q.into(),
+            Some(PackageJsonConfig::Envs(mut envs)) => envs
+                .remove(env)
+                .or_else(|| envs.remove("defaults"))
+                .map(From::from)
+                .unwrap_or_default(),
+        });
+    }
+
+    let content = fs::read_to_string(path)
+        .map_err(|err| Error::Config(format!("failed to read {}: {}", path.display(), err)))?;
+    let mut sections = parse_config(&content)
+        .map_err(|err| Error::Config(format!("failed to parse {}: {}", path.display(), err)))?;
+
+    Ok(sections
+        .remove(env)
+        .or_else(|| sections.remove("defaults"))
+        .unwrap_or_default())
+}
+
+/// Parses a config file.
+///
+/// ```text
+/// # Queries before sections are stored in `defaults`.
+/// > 1%
+///
+/// [production staging]
+/// > 1%, not dead
+/// ```
+fn parse_config(content: &str) -> Result<FxHashMap<String, Vec<String>>, String> {
+    let mut result: FxHashMap<String, Vec<String>> = Default::default();
+    result.insert("defaults".into(), vec![]);
+    let mut sections = vec!["defaults".to_string()];
+
+    for line in content.lines() {
+        let line = match line.find('#') {
+            Some(idx) => &line[..idx],
+            None => line,
+        }
+        .trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        if line.starts_with('[') && line.ends_with(']') {
+            sections = line[1..line.len() - 1]
+                .split_whitespace()
+                .map(String::from)
+                .collect();
+
+            for section in &sections {
+                if result.contains_key(section) {
+                    return Err(format!("duplicate section {}", section));
+                }
+                result.insert(section.clone(), vec![]);
+            }
+            continue;
+        }
+
+        for section in &sections {
+            result.get_mut(section).unwrap().push(line.to_string());
+        }
+    }
+
+    Ok(result)
+}
+
+/// Reads queries of a shareable config (`extends browserslist-config-foo`)
+/// from `node_modules`.
+///
+/// As the config is not evaluated, only configs which export an array
+/// literal (or a json file) are supported.
+pub(super) fn load_shareable(name: &str, path: &Path) -> Result<Vec<String>, Error> {
+    let dir = path
+        .ancestors()
+        .map(|dir| dir.join("node_modules").join(name))
+        .find(|dir| dir.is_dir())
+        .ok_or_else(|| Error::Config(format!("cannot find shareable config `{}`", name)))?;
+
+    let main = {
+        let package_json = dir.join("package.json");
+        let main = if package_json.is_file() {
+            read_package_json(&package_json)?.main
+        } else {
+            None
+        };
+
+        let main = dir.join(main.as_deref().unwrap_or("index.js"));
+        if main.is_file() {
+            main
+        } else {
+            main.with_extension("js")
+        }
+    };
+
+    let content = fs::read_to_string(&main)
+        .map_err(|err| Error::Config(format!("failed to read {}: {}", main.display(), err)))?;
+
+    let value = match content.find("module.exports") {
+        Some(idx) => {
+            let value = content[idx + "module.exports".len()..].trim_start();
+            let value = value.strip_prefix('=').unwrap_or(value).trim();
+            value.strip_suffix(';').unwrap_or(value)
+        }
+        None => &*content,
+    };
+
+    serde_json::from_str::<Queries>(value)
+        .or_else(|_| serde_json::from_str::<Queries>(&value.replace('\'', "\"")))
+        .map(From::from)
+        .map_err(|_| {
+            Error::Config(format!(
+                "`{}` should export an array of queries without any other expression",
+                name
+            ))
+        })
+}
@@ -0,0 +1,52 @@
+//! Generated by `scripts/browserslist-data-gen.js` from `caniuse-lite`,
+//! `electron-to-chromium` and `node-releases`.
+
+use indexmap::IndexMap;
+use once_cell::sync::Lazy;
+use serde::Deserialize;
+
+/// `(version, release date, global usage)`
+///
+/// Release date is a unix timestamp, and it's `None` for unreleased versions.
+#[derive(Debug, Deserialize)]
+pub(super) struct VersionDetail(pub String, pub Option<i64>, pub f64);
+
+impl VersionDetail {
+    pub fn version(&self) -> &str {
+        &self.0
+    }
+
+    pub fn release_date(&self) -> Option<i64> {
+        self.1
+    }
+
+    pub fn usage(&self) -> f64 {
+        self.2
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub(super) struct NodeRelease {
+    /// `12` or `0.10`
+    pub line: String,
+    pub first: String,
+    pub latest: String,
+    /// Start of the maintenance, in `YYYY-MM-DD`.
+    pub start: String,
+    /// End of life, in `YYYY-MM-DD`.
+    pub end: String,
+}
+
+/// Versions of agents, from the oldest one.
+pub(super) static AGENTS: Lazy<IndexMap<String, Vec<VersionDetail>>> = Lazy::new(|| {
+    serde_json::from_str(include_str!("agents.json")).expect("failed to parse agents.json")
+});
+
+/// `(electron version, chrome version)`
+pub(super) static ELECTRON: Lazy<Vec<(String, String)>> = Lazy::new(|| {
+    serde_json::from_str(include_str!("electron.json")).expect("failed to parse electron.json")
+});
+
+pub(super) static NODE: Lazy<Vec<NodeRelease>> = Lazy::new(|| {
+    serde_json::from_str(include_str!("node.json")).expect("failed to parse node.json")
+});
@@ -0,0 +1,812 @@
+//! Evaluates [browserslist] queries using the bundled data.
+//!
+//! [browserslist]: https://github.com/browserslist/browserslist
+
+use self::data::{VersionDetail, AGENTS, ELECTRON, NODE};
+use indexmap::IndexSet;
+use std::{
+    cmp::Ordering,
+    fmt,
+    path::Path,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+mod config;
+mod data;
+
+/// `> 0.5%, last 2 versions, Firefox ESR, not dead`
+const DEFAULTS: &[&str] = &["> 0.5%", "last 2 versions", "Firefox ESR", "not dead"];
+
+/// Browsers without official support or updates for 24 months.
+const DEAD: &[&str] = &[
+    "ie <= 10",
+    "ie_mob <= 11",
+    "bb <= 10",
+    "op_mob <= 12.1",
+    "samsung 4",
+];
+
+const FIREFOX_ESR: &[&str] = &["78"];
+
+/// The first version of android which uses versions of chrome.
+const ANDROID_EVERGREEN_FIRST: f64 = 37.0;
+
+const ONE_YEAR_IN_SECONDS: f64 = 365.259641 * 24.0 * 60.0 * 60.0;
+
+#[derive(Debug)]
+pub(crate) enum Error {
+    UnknownQuery(String),
+    UnknownBrowser(String),
+    UnknownVersion(String, String),
+    /// `not` is used for the first query.
+    NotAtStart(String),
+    Unsupported(String),
+    Config(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::UnknownQuery(q) => write!(f, "unknown browser query `{}`", q),
+            Error::UnknownBrowser(b) => write!(f, "unknown browser `{}`", b),
+            Error::UnknownVersion(b, v) => write!(f, "unknown version {} of {}", v, b),
+            Error::NotAtStart(q) => write!(
+                f,
+                "write any browsers query (for instance, `defaults`) before `{}`",
+                q
+            ),
+            Error::Unsupported(q) => write!(f, "`{}` is not supported", q),
+            Error::Config(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Opts<'a> {
+    /// Directory used to find configs and shareable configs.
+    pub path: &'a Path,
+    /// Use versions of desktop browsers for mobile browsers.
+    pub mobile_to_desktop: bool,
+}
+
+/// Returns browsers like `chrome 90`.
+///
+/// If `queries` is empty, queries are loaded from the config for
+/// [Opts::path], and `defaults` is used if there's no config.
+pub(crate) fn resolve<S>(queries: &[S], opts: Opts) -> Result<Vec<String>, Error>
+where
+    S: AsRef<str>,
+{
+    let queries = queries
+        .iter()
+        .map(|q| q.as_ref().trim())
+        .filter(|q| !q.is_empty())
+        .map(String::from)
+        .collect::<Vec<_>>();
+
+    let queries = if queries.is_empty() {
+        load_config(opts.path)?
+    } else {
+        queries
+    };
+
+    Ok(evaluate(&queries, opts)?.into_iter().collect())
+}
+
+fn load_config(path: &Path) -> Result<Vec<String>, Error> {
+    match config::load(path)? {
+        Some(queries) if !queries.is_empty() => Ok(queries),
+        _ => Ok(DEFAULTS.iter().map(|s| s.to_string()).collect()),
+    }
+}
+
+/// Combinator of a query and the result of previous queries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Combinator {
+    /// `,` or `or`
+    Or,
+    /// `and`
+    And,
+}
+
+/// Splits `> 1%, last 2 versions and not dead`.
+fn split(query: &str) -> Vec<(Combinator, String)> {
+    let mut result = vec![];
+
+    for part in query.split(',') {
+        let mut combinator = Combinator::Or;
+        let mut words: Vec<&str> = vec![];
+
+        for word in part.split_whitespace() {
+            if word.eq_ignore_ascii_case("or") || word.eq_ignore_ascii_case("and") {
+                if !words.is_empty() {
+                    result.push((combinator, words.join(" ")));
+                    words.clear();
+                }
+                combinator = if word.eq_ignore_ascii_case("or") {
+                    Combinator::Or
+                } else {
+                    Combinator::And
+                };
+                continue;
+            }
+
+            words.push(word);
+        }
+
+        if !words.is_empty() {
+            result.push((combinator, words.join(" ")));
+        }
+    }
+
+    result
+}
+
+fn evaluate<S>(queries: &[S], opts: Opts) -> Result<IndexSet<String>, Error>
+where
+    S: AsRef<str>,
+{
+    let mut result = IndexSet::<String>::default();
+    let mut is_first = true;
+
+    for query in queries {
+        for (combinator, selection) in split(query.as_ref()) {
+            let (exclude, selection) = match selection.get(..4) {
+                Some(prefix) if prefix.eq_ignore_ascii_case("not ") => (true, &selection[4..]),
+                _ => (false, &*selection),
+            };
+            if exclude && is_first {
+                return Err(Error::NotAtStart(selection.to_string()));
+            }
+            is_first = false;
+
+            let selected = select(selection, opts)?;
+
+            result = match (combinator, exclude) {
+                (Combinator::Or, false) => {
+                    result.extend(selected);
+                    result
+                }
+                (Combinator::And, false) => result
+                    .into_iter()
+                    .filter(|b| selected.contains(b))
+                    .collect(),
+                (_, true) => result
+                    .into_iter()
+                    .filter(|b| !selected.contains(b))
+                    .collect(),
+            };
+        }
+    }
+
+    Ok(result)
+}
+
+/// Splits words and comparison operators, like `ie>=10` into `ie`, `>=`
+/// and `10`.
+fn tokenize(s: &str) -> Vec<String> {
+    let mut tokens = vec![];
+    let mut cur = String::new();
+
+    for c in s.chars() {
+        match c {
+            '>' | '<' | '=' => {
+                if !cur.is_empty() && !cur.ends_with(|c| c == '>' || c == '<' || c == '=') {
+                    tokens.push(cur.clone());
+                    cur.clear();
+                }
+                cur.push(c);
+            }
+            _ if c.is_whitespace() => {
+                if !cur.is_empty() {
+                    tokens.push(cur.clone());
+                    cur.clear();
+                }
+            }
+            _ => {
+                if cur.ends_with(|c| c == '>' || c == '<' || c == '=') {
+                    tokens.push(cur.clone());
+                    cur.clear();
+                }
+                cur.push(c);
+            }
+        }
+    }
+
+    if !cur.is_empty() {
+        tokens.push(cur);
+    }
+
+    tokens
+}
+
+fn select(selection: &str, opts: Opts) -> Result<IndexSet<String>, Error> {
+    let raw = tokenize(selection);
+    let lower = raw.iter().map(|t| t.to_lowercase()).collect::<Vec<_>>();
+    let tokens = lower.iter().map(|t| &**t).collect::<Vec<_>>();
+
+    let unknown = || Error::UnknownQuery(selection.to_string());
+
+    let result = match &*tokens {
+        ["defaults"] => return evaluate(DEFAULTS, opts),
+        ["dead"] => return evaluate(DEAD, opts),
+        ["browserslist", "config"] => return evaluate(&load_config(opts.path)?, opts),
+        ["extends", _] => {
+            let queries = config::load_shareable(&raw[1], opts.path)?;
+            return evaluate(&queries, opts);
+        }
+
+        [op, usage] if is_op(op) && usage.ends_with('%') => {
+            let op = parse_op(op).ok_or_else(unknown)?;
+            let usage = parse_percent(usage).ok_or_else(unknown)?;
+
+            AGENTS
+                .iter()
+                .flat_map(|(name, versions)| {
+                    versions
+                        .iter()
+                        .filter(move |v| op.test(v.usage().partial_cmp(&usage)))
+                        .map(move |v| format!("{} {}", name, v.version()))
+                })
+                .collect()
+        }
+        [op, _, "in", ..] if is_op(op) => return Err(Error::Unsupported(selection.to_string())),
+
+        ["cover", coverage] => {
+            let coverage = parse_percent(coverage).ok_or_else(unknown)?;
+
+            let mut all = AGENTS
+                .iter()
+                .flat_map(|(name, versions)| versions.iter().map(move |v| (name, v)))
+                .collect::<Vec<_>>();
+            all.sort_by(|a, b| {
+                b.1.usage()
+                    .partial_cmp(&a.1.usage())
+                    .unwrap_or(Ordering::Equal)
+            });
+
+            let mut result = vec![];
+            let mut total = 0.0;
+            for (name, v) in all {
+                if total >= coverage || v.usage() == 0.0 {
+                    break;
+                }
+                total += v.usage();
+                result.push(format!("{} {}", name, v.version()));
+            }
+            result.into_iter().collect()
+        }
+
+        ["last", n, "version"] | ["last", n, "versions"] => {
+            let n = n.parse::<usize>().map_err(|_| unknown())?;
+            all_agents(opts)
+                .into_iter()
+                .flat_map(|agent| agent.last_versions(n))
+                .collect()
+        }
+        ["last", n, "major", "version"] | ["last", n, "major", "versions"] => {
+            let n = n.parse::<usize>().map_err(|_| unknown())?;
+            all_agents(opts)
+                .into_iter()
+                .flat_map(|agent| agent.last_major_versions(n))
+                .collect()
+        }
+        ["last", n, "year"] | ["last", n, "years"] => {
+            let n = n.parse::<f64>().map_err(|_| unknown())?;
+            released_since(now() - (n * ONE_YEAR_IN_SECONDS) as i64, opts)
+        }
+        ["last", n, "electron", "version"] | ["last", n, "electron", "versions"] => {
+            let n = n.parse::<usize>().map_err(|_| unknown())?;
+            ELECTRON
+                .iter()
+                .rev()
+                .take(n)
+                .map(|(_, chrome)| format!("chrome {}", chrome))
+                .collect()
+        }
+        ["last", n, "node", "version"]
+        | ["last", n, "node", "versions"]
+        | ["last", n, "node", "major", "version"]
+        | ["last", n, "node", "major", "versions"] => {
+            let n = n.parse::<usize>().map_err(|_| unknown())?;
+            NODE.iter()
+                .rev()
+                .take(n)
+                .map(|release| format!("node {}", release.latest))
+                .collect()
+        }
+        ["last", n, name, "version"] | ["last", n, name, "versions"] => {
+            let n = n.parse::<usize>().map_err(|_| unknown())?;
+            agent(name, opts)?.last_versions(n).into_iter().collect()
+        }
+        ["last", n, name, "major", "version"] | ["last", n, name, "major", "versions"] => {
+            let n = n.parse::<usize>().map_err(|_| unknown())?;
+            agent(name, opts)?
+                .last_major_versions(n)
+                .into_iter()
+                .collect()
+        }
+
+        ["unreleased", "versions"] => all_agents(opts)
+            .into_iter()
+            .flat_map(|agent| agent.unreleased_versions())
+            .collect(),
+        ["unreleased", name, "versions"] => agent(name, opts)?
+            .unreleased_versions()
+            .into_iter()
+            .collect(),
+
+        ["since", date] => {
+            let date = parse_date(date).ok_or_else(unknown)?;
+            released_since(date, opts)
+        }
+
+        ["firefox", "esr"] | ["ff", "esr"] | ["fx", "esr"] => FIREFOX_ESR
+            .iter()
+            .map(|v| format!("firefox {}", v))
+            .collect(),
+
+        ["maintained", "node", "versions"] => {
+            let now = now();
+            NODE.iter()
+                .filter(|release| {
+                    parse_date(&release.start).map_or(false, |start| start < now)
+                        && parse_date(&release.end).map_or(false, |end| now < end)
+                })
+                .map(|release| format!("node {}", release.latest))
+                .collect()
+        }
+        ["current", "node"] | ["supports", _] => {
+            return Err(Error::Unsupported(selection.to_string()))
+        }
+
+        ["node", op, version] if is_op(op) => {
+            let op = parse_op(op).ok_or_else(unknown)?;
+            let version = parse_semver(version).ok_or_else(unknown)?;
+
+            let mut candidates = NODE
+                .iter()
+                .flat_map(|release| vec![&*release.first, &*release.latest])
+                .filter_map(parse_semver)
+                .collect::<Vec<_>>();
+            candidates.push(version);
+
+            candidates
+                .into_iter()
+                .filter(|v| op.test(Some(v.cmp(&version))))
+                .map(|(major, minor, patch)| format!("node {}.{}.{}", major, minor, patch))
+                .collect()
+        }
+        ["node", version] => {
+            let release = NODE
+                .iter()
+                .find(|release| {
+                    &*release.line == *version || version.split('.').next() == Some(&*release.line)
+                })
+                .ok_or_else(|| Error::UnknownVersion("node".into(), version.to_string()))?;
+
+            let version = if version.contains('.') && !release.line.contains('.') {
+                let (major, minor, patch) = parse_semver(version).ok_or_else(unknown)?;
+                format!("{}.{}.{}", major, minor, patch)
+            } else {
+                release.latest.clone()
+            };
+
+            vec![format!("node {}", version)].into_iter().collect()
+        }
+
+        ["electron", op, version] if is_op(op) => {
+            let op = parse_op(op).ok_or_else(unknown)?;
+            let version = parse_float(version).ok_or_else(unknown)?;
+
+            ELECTRON
+                .iter()
+                .filter(|(electron, _)| {
+                    op.test(parse_float(electron).and_then(|v| v.partial_cmp(&version)))
+                })
+                .map(|(_, chrome)| format!("chrome {}", chrome))
+                .collect()
+        }
+        ["electron", version] => match split_range(version) {
+            Some((from, to)) => ELECTRON
+                .iter()
+                .filter(|(electron, _)| {
+                    parse_float(electron).map_or(false, |v| from <= v && v <= to)
+                })
+                .map(|(_, chrome)| format!("chrome {}", chrome))
+                .collect(),
+            None => {
+                let chrome = ELECTRON
+                    .iter()
+                    .find(|(electron, _)| {
+                        electron.as_str() == *version || *electron == format!("{}.0", version)
+                    })
+                    .map(|(_, chrome)| chrome)
+                    .ok_or_else(|| Error::UnknownVersion("electron".into(), version.to_string()))?;
+                vec![format!("chrome {}", chrome)].into_iter().collect()
+            }
+        },
+
+        [name, op, version] if is_op(op) => {
+            let op = parse_op(op).ok_or_else(unknown)?;
+            let version = parse_float(version).ok_or_else(unknown)?;
+            let agent = agent(name, opts)?;
+
+            agent
+                .versions
+                .iter()
+                .filter(|v| op.test(parse_float(v.version()).and_then(|v| v.partial_cmp(&version))))
+                .map(|v| format!("{} {}", agent.name, v.version()))
+                .collect()
+        }
+        [name, version] => {
+            let agent = agent(name, opts)?;
+
+            if let Some(v) = agent.normalize_version(version) {
+                vec![format!("{} {}", agent.name, v)].into_iter().collect()
+            } else if let Some((from, to)) = split_range(version) {
+                agent
+                    .versions
+                    .iter()
+                    .filter(|v| parse_float(v.version()).map_or(false, |v| from <= v && v <= to))
+                    .map(|v| format!("{} {}", agent.name, v.version()))
+                    .collect()
+            } else {
+                return Err(Error::UnknownVersion(
+                    agent.name.to_string(),
+                    version.to_string(),
+                ));
+            }
+        }
+
+        _ => return Err(unknown()),
+    };
+
+    Ok(result)
+}
+
+fn released_since(since: i64, opts: Opts) -> IndexSet<String> {
+    all_agents(opts)
+        .into_iter()
+        .flat_map(|agent| {
+            let name = agent.name;
+            agent
+                .versions
+                .into_iter()
+                .filter(move |v| v.release_date().map_or(false, |date| date >= since))
+                .map(move |v| format!("{} {}", name, v.version()))
+        })
+        .collect()
+}
+
+#[derive(Debug)]
+struct Agent {
+    /// Name used for the result.
+    name: &'static str,
+    versions: Vec<&'static VersionDetail>,
+}
+
+impl Agent {
+    fn released(&self) -> impl DoubleEndedIterator<Item = &'static VersionDetail> + '_ {
+        self.versions
+            .iter()
+            .copied()
+            .filter(|v| v.release_date().is_some())
+    }
+
+    fn last_versions(&self, n: usize) -> Vec<String> {
+        let mut versions = self.released().rev().take(n).collect::<Vec<_>>();
+        versions.reverse();
+
+        versions
+            .into_iter()
+            .map(|v| format!("{} {}", self.name, v.version()))
+            .collect()
+    }
+
+    fn last_major_versions(&self, n: usize) -> Vec<String> {
+        let mut majors = self
+            .released()
+            .rev()
+            .map(|v| major(v.version()))
+            .collect::<Vec<_>>();
+        majors.dedup();
+        let min = majors.into_iter().take(n).last();
+
+        self.released()
+            .filter(|v| min.map_or(false, |min| major(v.version()) >= min))
+            .map(|v| format!("{} {}", self.name, v.version()))
+            .collect()
+    }
+
+    fn unreleased_versions(&self) -> Vec<String> {
+        self.versions
+            .iter()
+            .filter(|v| v.release_date().is_none())
+            .map(|v| format!("{} {}", self.name, v.version()))
+            .collect()
+    }
+
+    fn normalize_version(&self, version: &str) -> Option<&'static str> {
+        let found = self.versions.iter().find(|v| {
+            v.version() == version
+                || v.version() == format!("{}.0", version)
+                || version.strip_suffix(".0") == Some(v.version())
+                || (v.version().contains('-') && v.version().split('-').any(|v| v == version))
+        });
+
+        match found {
+            Some(v) => Some(v.version()),
+            None if self.versions.len() == 1 => Some(self.versions[0].version()),
+            None => None,
+        }
+    }
+}
+
+fn canonical_name(name: &str) -> &str {
+    match name {
+        "fx" | "ff" => "firefox",
+        "ios" => "ios_saf",
+        "explorer" => "ie",
+        "blackberry" => "bb",
+        "explorermobile" => "ie_mob",
+        "operamini" => "op_mini",
+        "operamobile" => "op_mob",
+        "chromeandroid" => "and_chr",
+        "firefoxandroid" => "and_ff",
+        "ucandroid" => "and_uc",
+        "qqandroid" => "and_qq",
+        _ => name,
+    }
+}
+
+fn desktop_name(name: &str) -> Option<&'static str> {
+    match name {
+        "and_chr" => Some("chrome"),
+        "and_ff" => Some("firefox"),
+        "ie_mob" => Some("ie"),
+        "op_mob" => Some("opera"),
+        "android" => Some("chrome"),
+        _ => None,
+    }
+}
+
+fn agent(name: &str, opts: Opts) -> Result<Agent, Error> {
+    let name = canonical_name(name);
+    let (name, versions) = AGENTS
+        .get_key_value(name)
+        .ok_or_else(|| Error::UnknownBrowser(name.to_string()))?;
+    let name = &**name;
+
+    let desktop = match desktop_name(name) {
+        Some(desktop) if opts.mobile_to_desktop => &AGENTS[desktop],
+        _ => {
+            return Ok(Agent {
+                name,
+                versions: versions.iter().collect(),
+            })
+        }
+    };
+
+    let versions = if name == "android" {
+        versions
+            .iter()
+            .filter(|v| parse_float(v.version()).map_or(false, |v| v < ANDROID_EVERGREEN_FIRST))
+            .chain(desktop.iter().filter(|v| {
+                parse_float(v.version()).map_or(false, |v| v >= ANDROID_EVERGREEN_FIRST)
+            }))
+            .collect()
+    } else {
+        desktop.iter().collect()
+    };
+
+    Ok(Agent { name, versions })
+}
+
+fn all_agents(opts: Opts) -> Vec<Agent> {
+    AGENTS
+        .keys()
+        .map(|name| agent(name, opts).expect("agent from data should exist"))
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Op {
+    Gt,
+    Gte,
+    Lt,
+    Lte,
+}
+
+impl Op {
+    fn test(self, ord: Option<Ordering>) -> bool {
+        match (self, ord) {
+            (_, None) => false,
+            (Op::Gt, Some(ord)) => ord == Ordering::Greater,
+            (Op::Gte, Some(ord)) => ord != Ordering::Less,
+            (Op::Lt, Some(ord)) => ord == Ordering::Less,
+            (Op::Lte, Some(ord)) => ord != Ordering::Greater,
+        }
+    }
+}
+
+fn is_op(s: &str) -> bool {
+    s.starts_with(|c| c == '>' || c == '<')
+}
+
+fn parse_op(s: &str) -> Option<Op> {
+    match s {
+        ">" => Some(Op::Gt),
+        ">=" => Some(Op::Gte),
+        "<" => Some(Op::Lt),
+        "<=" => Some(Op::Lte),
+        _ => None,
+    }
+}
+
+fn parse_percent(s: &str) -> Option<f64> {
+    s.strip_suffix('%')?.parse().ok()
+}
+
+/// Same as `parseFloat` of javascript, but returns `None` instead of `NaN`.
+fn parse_float(s: &str) -> Option<f64> {
+    let mut end = 0;
+    let mut seen_dot = false;
+    for (i, c) in s.char_indices() {
+        match c {
+            '0'..='9' => end = i + 1,
+            '.' if !seen_dot => seen_dot = true,
+            _ => break,
+        }
+    }
+
+    s[..end].parse().ok()
+}
+
+/// `6-8` or `4.4-5`
+fn split_range(s: &str) -> Option<(f64, f64)> {
+    let mut parts = s.splitn(2, '-');
+    let from = parse_float(parts.next()?)?;
+    let to = parse_float(parts.next()?)?;
+
+    Some((from, to))
+}
+
+fn major(version: &str) -> u32 {
+    version
+        .split(|c| c == '.' || c == '-')
+        .next()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(0)
+}
+
+fn parse_semver(s: &str) -> Option<(u32, u32, u32)> {
+    let mut parts = s.split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next().map_or(Some(0), |v| v.parse().ok())?;
+    let patch = parts.next().map_or(Some(0), |v| v.parse().ok())?;
+
+    Some((major, minor, patch))
+}
+
+/// Parses `YYYY`, `YYYY-MM` or `YYYY-MM-DD` as an unix timestamp in UTC.
+fn parse_date(s: &str) -> Option<i64> {
+    let mut parts = s.split('-');
+    let year: i64 = parts.next()?.parse().ok()?;
+    let month: i64 = parts.next().map_or(Some(1), |v| v.parse().ok())?;
+    let day: i64 = parts.next().map_or(Some(1), |v| v.parse().ok())?;
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+
+    // http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = if y >= 0 { y } else { y - 399 } / 400;
+    let yoe = y - era * 400;
+    let mp = (month + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era * 146097 + doe - 719468;
+
+    Some(days * 24 * 60 * 60)
+}
+
+fn now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_date, resolve, split, tokenize, Combinator, Error, Opts};
+    use std::path::Path;
+
+    fn query(q: &str) -> Result<Vec<String>, Error> {
+        resolve(
+            &[q],
+            Opts {
+                path: Path::new(env!("CARGO_MANIFEST_DIR")),
+                mobile_to_desktop: true,
+            },
+        )
+    }
+
+    #[test]
+    fn split_queries() {
+        assert_eq!(
+            split("> 1%, last 2 versions or ie 11 and not dead"),
+            vec![
+                (Combinator::Or, "> 1%".to_string()),
+                (Combinator::Or, "last 2 versions".to_string()),
+                (Combinator::Or, "ie 11".to_string()),
+                (Combinator::And, "not dead".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn tokenize_ops() {
+        assert_eq!(tokenize("ie>=10"), vec!["ie", ">=", "10"]);
+        assert_eq!(tokenize(">1%"), vec![">", "1%"]);
+        assert_eq!(tokenize("chrome 90"), vec!["chrome", "90"]);
+    }
+
+    #[test]
+    fn dates() {
+        assert_eq!(parse_date("1970"), Some(0));
+        assert_eq!(parse_date("2015-06-30"), Some(1435622400));
+        assert_eq!(parse_date("2015-13"), None);
+    }
+
+    #[test]
+    fn direct_versions() {
+        assert_eq!(query("ie > 10").unwrap(), vec!["ie 11"]);
+        assert_eq!(query("ie 6-8").unwrap(), vec!["ie 6", "ie 7", "ie 8"]);
+        assert_eq!(query("ios 14.4").unwrap(), vec!["ios_saf 14.0-14.4"]);
+        assert_eq!(query("chromeandroid 59").unwrap(), vec!["and_chr 59"]);
+        assert_eq!(query("Firefox ESR").unwrap(), vec!["firefox 78"]);
+    }
+
+    #[test]
+    fn node_and_electron() {
+        assert_eq!(query("node 10.13").unwrap(), vec!["node 10.13.0"]);
+        assert_eq!(query("electron 1.4").unwrap(), vec!["chrome 53"]);
+        assert!(query("node >= 12")
+            .unwrap()
+            .contains(&"node 12.0.0".to_string()));
+    }
+
+    #[test]
+    fn not_dead() {
+        let res = query("ie >= 9, not dead").unwrap();
+        assert_eq!(res, vec!["ie 11"]);
+    }
+
+    #[test]
+    fn defaults() {
+        let res = query("defaults").unwrap();
+        assert!(res.contains(&"ie 11".to_string()));
+        assert!(res.contains(&"firefox 78".to_string()));
+        assert!(!res.contains(&"ie 10".to_string()));
+    }
+
+    #[test]
+    fn errors() {
+        assert!(match query("not dead") {
+            Err(Error::NotAtStart(..)) => true,
+            _ => false,
+        });
+        assert!(match query("unknown 10") {
+            Err(Error::UnknownBrowser(..)) => true,
+            _ => false,
+        });
+        assert!(match query("> 5% in US") {
+            Err(Error::Unsupported(..)) => true,
+            _ => false,
+        });
+    }
+}
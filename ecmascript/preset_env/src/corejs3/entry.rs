@@ -1,4 +1,4 @@
-use super::compat::DATA as CORE_JS_COMPAT_DATA;
+use super::{compat::DATA as CORE_JS_COMPAT_DATA, is_available};
 use crate::{version::should_enable, Version, Versions};
 use fxhash::FxBuildHasher;
 use fxhash::FxHashMap;
@@ -24,21 +24,6 @@ static ENTRIES: Lazy<FxHashMap<String, Vec<&'static str>>> = Lazy::new(|| {
         .collect()
 });
 
-static MODULES_BY_VERSION: Lazy<FxHashMap<Version, Vec<&'static str>>> = Lazy::new(|| {
-    serde_json::from_str::<FxHashMap<_, _>>(include_str!("modules-by-versions.json"))
-        .expect("failed to parse modules-by-versions.json")
-        .into_iter()
-        .map(|(k, v): (Version, Vec<String>)| {
-            (
-                k,
-                v.into_iter()
-                    .map(|s: String| &*Box::leak(s.into_boxed_str()))
-                    .collect::<Vec<_>>(),
-            )
-        })
-        .collect()
-});
-
 #[derive(Debug)]
 pub struct Entry {
     is_any_target: bool,
@@ -90,13 +75,8 @@ impl Entry {
 
                 //                println!("{} -> {}", src, f);
 
-                for (_, features) in MODULES_BY_VERSION
-                    .iter()
-                    .filter(|(version, _features)| *corejs_version < **version)
-                {
-                    if features.contains(&*f) {
-                        return None;
-                    }
+                if !is_available(*corejs_version, f) {
+                    return None;
                 }
 
                 Some(f)
@@ -1,4 +1,4 @@
-use super::data::BUILTINS;
+use super::{data::BUILTINS, is_available};
 use crate::{
     corejs3::{
         compat::DATA as CORE_JS_COMPAT_DATA,
@@ -9,7 +9,7 @@ use crate::{
     },
     util::DataMapExt,
     version::should_enable,
-    Versions,
+    Version, Versions,
 };
 use fxhash::FxBuildHasher;
 use indexmap::IndexSet;
@@ -18,15 +18,35 @@ use swc_common::DUMMY_SP;
 use swc_ecma_ast::*;
 use swc_ecma_visit::{noop_visit_type, Node, Visit, VisitWith};
 
+/// Proposals which are polyfilled if `shippedProposals` is enabled.
+const SHIPPED_PROPOSALS: &[&str] = &[
+    "esnext.global-this",
+    "esnext.promise.all-settled",
+    "esnext.string.match-all",
+];
+
+/// Namespaces of modules which may share a method name with another
+/// namespace.
+///
+/// e.g. `includes` is `es.array.includes` or `es.string.includes`
+const INSTANCE_NAMESPACES: &[&str] = &["array", "string", "dom-collections"];
+
 pub(crate) struct UsageVisitor {
     shipped_proposals: bool,
+    proposals: bool,
     is_any_target: bool,
     target: Versions,
+    corejs_version: Version,
     pub required: IndexSet<&'static str, FxBuildHasher>,
 }
 
 impl UsageVisitor {
-    pub fn new(target: Versions, shipped_proposals: bool) -> Self {
+    pub fn new(
+        target: Versions,
+        corejs_version: Version,
+        shipped_proposals: bool,
+        proposals: bool,
+    ) -> Self {
         //        let mut v = Self { required: vec![] };
         //
         //
@@ -48,8 +68,10 @@ impl UsageVisitor {
 
         Self {
             shipped_proposals,
+            proposals,
             is_any_target: target.is_any_target(),
             target,
+            corejs_version,
             required: Default::default(),
         }
     }
@@ -58,13 +80,22 @@ impl UsageVisitor {
     fn add(&mut self, features: &[&'static str]) {
         let UsageVisitor {
             shipped_proposals,
+            proposals,
             is_any_target,
             target,
+            corejs_version,
             ..
         } = self;
 
         self.required.extend(features.iter().filter_map(|f| {
-            if !*shipped_proposals && f.starts_with("esnext.") {
+            if f.starts_with("esnext.")
+                && !*proposals
+                && !(*shipped_proposals && SHIPPED_PROPOSALS.contains(f))
+            {
+                return None;
+            }
+
+            if !is_available(*corejs_version, f) {
                 return None;
             }
 
@@ -97,7 +128,10 @@ impl UsageVisitor {
         let obj = match obj {
             Expr::Ident(i) => &i.sym,
             _ => {
-                self.add_property_deps_inner(None, prop);
+                match instance_type(obj) {
+                    Some(ty) => self.add_instance_deps(ty, prop),
+                    None => self.add_property_deps_inner(None, prop),
+                }
                 return;
             }
         };
@@ -123,6 +157,30 @@ impl UsageVisitor {
         }
     }
 
+    /// Adds dependencies of an instance property, when the type of the object
+    /// is known.
+    ///
+    /// `''.includes` requires `es.string.includes`, but not
+    /// `es.array.includes`.
+    fn add_instance_deps(&mut self, ty: &str, prop: &JsWord) {
+        if let Some(features) = INSTANCE_PROPERTIES.get_data(&prop) {
+            let features = features
+                .iter()
+                .copied()
+                .filter(|f| {
+                    let namespace = f
+                        .splitn(3, '.')
+                        .nth(1)
+                        .expect("core-js module name should have a namespace");
+
+                    namespace == ty || !INSTANCE_NAMESPACES.contains(&namespace)
+                })
+                .collect::<Vec<_>>();
+
+            self.add(&features);
+        }
+    }
+
     fn visit_object_pat_props(&mut self, obj: &Expr, props: &[ObjectPatProp]) {
         let obj = match obj {
             Expr::Ident(i) => Some(&i.sym),
@@ -145,6 +203,63 @@ impl UsageVisitor {
     }
 }
 
+/// Guesses the type of an object using its syntax.
+///
+/// Returns `None` if it's not known, and a name which does not match any of
+/// [INSTANCE_NAMESPACES] if it's not an array nor a string.
+fn instance_type(e: &Expr) -> Option<&'static str> {
+    match e {
+        Expr::Paren(ParenExpr { expr, .. }) => instance_type(expr),
+        Expr::Lit(Lit::Str(..)) | Expr::Tpl(..) => Some("string"),
+        Expr::Array(..) => Some("array"),
+        Expr::Lit(Lit::Num(..)) => Some("number"),
+        Expr::Lit(Lit::Regex(..)) => Some("regexp"),
+        Expr::Object(..) => Some("object"),
+        Expr::Fn(..) | Expr::Arrow(..) | Expr::Class(..) => Some("function"),
+
+        // new Array(), Array.from(), String(foo)
+        Expr::New(NewExpr { callee, .. })
+        | Expr::Call(CallExpr {
+            callee: ExprOrSuper::Expr(callee),
+            ..
+        }) => match &**callee {
+            Expr::Ident(Ident {
+                sym: js_word!("Array"),
+                ..
+            }) => Some("array"),
+            Expr::Ident(Ident {
+                sym: js_word!("String"),
+                ..
+            }) => Some("string"),
+            Expr::Member(MemberExpr {
+                obj: ExprOrSuper::Expr(obj),
+                prop,
+                computed: false,
+                ..
+            }) => match (&**obj, &**prop) {
+                (
+                    Expr::Ident(Ident {
+                        sym: js_word!("Array"),
+                        ..
+                    }),
+                    Expr::Ident(Ident {
+                        sym: js_word!("from"),
+                        ..
+                    })
+                    | Expr::Ident(Ident {
+                        sym: js_word!("of"),
+                        ..
+                    }),
+                ) => Some("array"),
+                _ => None,
+            },
+            _ => None,
+        },
+
+        _ => None,
+    }
+}
+
 impl Visit for UsageVisitor {
     noop_visit_type!();
 
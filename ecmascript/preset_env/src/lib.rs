@@ -1,17 +1,21 @@
 #![recursion_limit = "256"]
 
 pub use self::{transform_data::Feature, version::Version};
+use anyhow::{Context, Error};
 use dashmap::DashMap;
 use fxhash::{FxHashMap, FxHashSet};
+use indexmap::IndexMap;
 use once_cell::sync::Lazy;
-use serde::{Deserialize, Serialize};
+use serde::Deserialize;
 use st_map::StaticMap;
 use std::{
     path::{Path, PathBuf},
-    process::Command,
+    sync::{Arc, Mutex},
 };
 use swc_atoms::{js_word, JsWord};
-use swc_common::{chain, comments::Comments, FromVariant, Mark, DUMMY_SP};
+use swc_common::{
+    chain, comments::Comments, sync::Lrc, FileName, FromVariant, Mark, SourceMap, DUMMY_SP,
+};
 use swc_ecma_ast::*;
 use swc_ecma_transforms::{
     compat::{bugfixes, es2015, es2016, es2017, es2018, es2020, es3},
@@ -22,6 +26,7 @@ use swc_ecma_visit::{Fold, FoldWith, VisitWith};
 
 #[macro_use]
 mod util;
+mod browserslist;
 mod corejs2;
 mod corejs3;
 mod regenerator;
@@ -29,12 +34,44 @@ mod transform_data;
 mod version;
 
 pub fn preset_env<C>(global_mark: Mark, comments: Option<C>, c: Config) -> impl Fold
+where
+    C: Comments,
+{
+    build(None, global_mark, comments, c)
+        .expect("failed to parse targets")
+        .0
+}
+
+/// Same as [preset_env], but also returns a handle which can be used to get
+/// the polyfills injected by the returned pass.
+///
+/// `cm` is used to find the file of each module processed by the pass.
+///
+/// Unlike [preset_env], this returns an error if `targets` cannot be resolved.
+pub fn preset_env_with_report<C>(
+    cm: Lrc<SourceMap>,
+    global_mark: Mark,
+    comments: Option<C>,
+    c: Config,
+) -> Result<(impl Fold, InjectedPolyfills), Error>
+where
+    C: Comments,
+{
+    build(Some(cm), global_mark, comments, c)
+}
+
+fn build<C>(
+    cm: Option<Lrc<SourceMap>>,
+    global_mark: Mark,
+    comments: Option<C>,
+    c: Config,
+) -> Result<(impl Fold, InjectedPolyfills), Error>
 where
     C: Comments,
 {
     let loose = c.loose;
     let targets: Versions =
-        targets_to_versions(c.targets, &c.path).expect("failed to parse targets");
+        targets_to_versions(c.targets, &c.path).context("failed to parse targets")?;
     let is_any_target = targets.is_any_target();
 
     let (include, included_modules) = FeatureOrModule::split(c.include);
@@ -165,7 +202,9 @@ where
         println!("Targets: {:?}", targets);
     }
 
-    chain!(
+    let injected = InjectedPolyfills::default();
+
+    let pass = chain!(
         pass,
         Polyfills {
             mode: c.mode,
@@ -176,11 +215,52 @@ where
                 patch: 0
             }),
             shipped_proposals: c.shipped_proposals,
+            proposals: c.proposals,
+            polyfill_path: c.polyfill_path.unwrap_or_else(|| "core-js".into()),
             targets,
             includes: included_modules,
             excludes: excluded_modules,
+            cm,
+            injected: injected.clone(),
         }
-    )
+    );
+
+    Ok((pass, injected))
+}
+
+/// Polyfills injected by the pass returned from [preset_env_with_report].
+///
+/// Polyfills are recorded for each file processed by the pass.
+#[derive(Debug, Clone, Default)]
+pub struct InjectedPolyfills(Arc<Mutex<IndexMap<FileName, Vec<JsWord>>>>);
+
+impl InjectedPolyfills {
+    /// Returns the module specifiers of the imports injected into `file`.
+    ///
+    /// e.g.) `core-js/modules/es.array.includes`
+    pub fn get(&self, file: &FileName) -> Vec<JsWord> {
+        self.0
+            .lock()
+            .unwrap()
+            .get(file)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Returns the injected imports of all files, in the order they are
+    /// processed.
+    pub fn all(&self) -> Vec<(FileName, Vec<JsWord>)> {
+        self.0
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(file, polyfills)| (file.clone(), polyfills.clone()))
+            .collect()
+    }
+
+    fn insert(&self, file: FileName, polyfills: Vec<JsWord>) {
+        self.0.lock().unwrap().insert(file, polyfills);
+    }
 }
 
 /// A map without allocation.
@@ -221,15 +301,21 @@ pub struct BrowserData<T: Default> {
     pub opera_mobile: T,
 }
 
-#[derive(Debug)]
 struct Polyfills {
     mode: Option<Mode>,
     targets: Versions,
     shipped_proposals: bool,
+    proposals: bool,
     corejs: Version,
+    /// Path of the core-js package.
+    polyfill_path: String,
     regenerator: bool,
     includes: FxHashSet<String>,
     excludes: FxHashSet<String>,
+    /// Used to record injected polyfills by file. `None` if they are not
+    /// reported.
+    cm: Option<Lrc<SourceMap>>,
+    injected: InjectedPolyfills,
 }
 
 impl Fold for Polyfills {
@@ -247,8 +333,12 @@ impl Fold for Polyfills {
                         v.required
                     }
                     Version { major: 3, .. } => {
-                        let mut v =
-                            corejs3::UsageVisitor::new(self.targets, self.shipped_proposals);
+                        let mut v = corejs3::UsageVisitor::new(
+                            self.targets,
+                            self.corejs,
+                            self.shipped_proposals,
+                            self.proposals,
+                        );
                         m.visit_with(&Invalid { span: DUMMY_SP } as _, &mut v);
                         v.required
                     }
@@ -283,20 +373,25 @@ impl Fold for Polyfills {
             .filter(|s| !self.excludes.contains(&**s))
             .map(|s| -> JsWord {
                 if s != "regenerator-runtime/runtime" {
-                    format!("core-js/modules/{}", s).into()
+                    format!("{}/modules/{}", self.polyfill_path, s).into()
                 } else {
                     format!("regenerator-runtime/runtime").into()
                 }
             })
             .chain(self.includes.iter().map(|s| {
                 if s != "regenerator-runtime/runtime" {
-                    format!("core-js/modules/{}", s).into()
+                    format!("{}/modules/{}", self.polyfill_path, s).into()
                 } else {
                     format!("regenerator-runtime/runtime").into()
                 }
             }))
             .collect::<Vec<_>>();
 
+        if let Some(cm) = &self.cm {
+            self.injected
+                .insert(cm.span_to_filename(span), required.clone());
+        }
+
         if cfg!(debug_assertions) {
             let mut v = required.into_iter().collect::<Vec<_>>();
             v.sort();
@@ -459,6 +554,17 @@ pub struct Config {
     #[serde(default)]
     pub shipped_proposals: bool,
 
+    /// Polyfill all proposals supported by core-js, not only the shipped
+    /// ones.
+    #[serde(default)]
+    pub proposals: bool,
+
+    /// Path of the core-js package used for injected imports.
+    ///
+    /// Defaults to `core-js`.
+    #[serde(default)]
+    pub polyfill_path: Option<String>,
+
     #[serde(default)]
     pub force_all_transforms: bool,
 
@@ -527,73 +633,67 @@ pub enum Query {
     Multiple(Vec<String>),
 }
 
-type QueryResult = Result<Versions, ()>;
+type QueryResult = Result<Versions, Error>;
 
 impl Query {
     fn exec(&self, path: &Path) -> QueryResult {
         fn query<T>(s: &[T], path: &Path) -> QueryResult
         where
-            T: AsRef<str> + Serialize,
+            T: AsRef<str>,
         {
-            let output = {
-                let output = Command::new("node")
-                    .current_dir(path)
-                    .arg("-e")
-                    .arg(include_str!("query.js"))
-                    .arg(serde_json::to_string(&s).expect("failed to serialize with serde"))
-                    .output()
-                    .expect("failed to collect output");
-                if !output.status.success() {
-                    println!("query.js: Status {:?}", output.status,);
-                    println!(
-                        "{}\n{}",
-                        String::from_utf8_lossy(&output.stdout),
-                        String::from_utf8_lossy(&output.stderr),
-                    );
-                    return Err(());
-                }
-
-                output.stdout
-            };
-
-            let browsers: Vec<String> =
-                serde_json::from_slice(&output).expect("failed to read browser data output");
-            let versions = BrowserData::parse_versions(browsers.iter().map(|s| &**s))
-                .expect("failed to parse browser version");
+            let queries = s
+                .iter()
+                .map(|s| s.as_ref())
+                .filter(|s| !s.starts_with("esmodules"))
+                .collect::<Vec<_>>();
+
+            let browsers = browserslist::resolve(
+                &queries,
+                browserslist::Opts {
+                    path,
+                    mobile_to_desktop: true,
+                },
+            )
+            .with_context(|| format!("failed to resolve browserslist query {:?}", queries))?;
+
+            let versions = BrowserData::parse_versions(
+                browsers.iter().filter(|v| !v.contains("TP")).map(|s| &**s),
+            )
+            .expect("failed to parse browser version");
 
             Ok(versions)
         }
 
-        static CACHE: Lazy<DashMap<Query, QueryResult>> = Lazy::new(Default::default);
+        // Keyed by path too, as configs and `extends` are resolved relative to it.
+        static CACHE: Lazy<DashMap<(Query, PathBuf), Versions>> = Lazy::new(Default::default);
 
-        if let Some(v) = CACHE.get(self) {
-            return match &*v {
-                Ok(v) => Ok(*v),
-                Err(err) => Err(*err),
-            };
+        let key = (self.clone(), path.to_path_buf());
+        if let Some(v) = CACHE.get(&key) {
+            return Ok(*v);
         }
 
         let result = match *self {
             Query::Single(ref s) => query(&[s], path),
             Query::Multiple(ref s) => query(&s, path),
-        };
+        }?;
 
-        CACHE.insert(self.clone(), result);
+        CACHE.insert(key, result);
 
-        result
+        Ok(result)
     }
 }
 
-fn targets_to_versions(v: Option<Targets>, path: &Path) -> Result<Versions, ()> {
+fn targets_to_versions(v: Option<Targets>, path: &Path) -> Result<Versions, Error> {
     match v {
         None => Ok(Default::default()),
         Some(Targets::Versions(v)) => Ok(v),
         Some(Targets::Query(q)) => q.exec(path),
         Some(Targets::HashMap(mut map)) => {
             let q = map.remove("browsers").map(|q| match q {
-                QueryOrVersion::Query(q) => q.exec(path).expect("failed to run query"),
+                QueryOrVersion::Query(q) => q.exec(path),
                 _ => unreachable!(),
             });
+            let q = q.transpose()?;
 
             let node = map.remove("node").map(|q| match q {
                 QueryOrVersion::Version(v) => v,
@@ -616,6 +716,7 @@ fn targets_to_versions(v: Option<Targets>, path: &Path) -> Result<Versions, ()>
 #[cfg(test)]
 mod tests {
     use super::Query;
+    use std::fs;
 
     #[test]
     fn test_empty() {
@@ -626,4 +727,34 @@ mod tests {
             "empty query should return non-empty result"
         );
     }
+
+    #[test]
+    fn test_unknown_query() {
+        let path = std::env::current_dir().unwrap();
+        let err = Query::Single("unknown 10".into()).exec(&path).unwrap_err();
+        assert!(
+            format!("{:?}", err).contains("unknown browser `unknown`"),
+            "{:?}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_cache_by_path() {
+        let root = std::env::temp_dir().join("swc-preset-env-cache-by-path");
+        let ie = root.join("ie");
+        let chrome = root.join("chrome");
+        fs::create_dir_all(&ie).unwrap();
+        fs::create_dir_all(&chrome).unwrap();
+        fs::write(ie.join(".browserslistrc"), "ie 11").unwrap();
+        fs::write(chrome.join(".browserslistrc"), "chrome 80").unwrap();
+
+        let ie = Query::Single("".into()).exec(&ie).unwrap();
+        let chrome = Query::Single("".into()).exec(&chrome).unwrap();
+
+        assert!(ie.ie.is_some());
+        assert!(ie.chrome.is_none());
+        assert!(chrome.ie.is_none());
+        assert!(chrome.chrome.is_some());
+    }
 }
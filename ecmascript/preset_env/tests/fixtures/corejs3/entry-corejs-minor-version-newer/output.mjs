@@ -0,0 +1,2 @@
+import   "core-js/modules/es.global-this";
+import   "core-js/modules/esnext.global-this";
@@ -0,0 +1 @@
+import   "core-js/modules/esnext.global-this";
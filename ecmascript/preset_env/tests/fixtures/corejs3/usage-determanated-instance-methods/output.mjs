@@ -1,5 +1,3 @@
-import   "core-js/modules/es.array.includes";
-import   "core-js/modules/es.array.index-of";
 import   "core-js/modules/es.array.iterator";
 import   "core-js/modules/es.function.bind";
 import   "core-js/modules/es.object.define-getter";
@@ -0,0 +1,2 @@
+''.padStart(2);
+[].includes(1);
@@ -0,0 +1,4 @@
+import   "core-js/modules/es.array.includes";
+import   "core-js/modules/es.string.pad-start";
+''.padStart(2);
+[].includes(1);
@@ -0,0 +1,2 @@
+[].includes(1);
+foo.includes(1);
@@ -0,0 +1,4 @@
+import   "core-js/modules/es.array.includes";
+import   "core-js/modules/es.string.includes";
+[].includes(1);
+foo.includes(1);
@@ -0,0 +1 @@
+'foo'.padStart(5);
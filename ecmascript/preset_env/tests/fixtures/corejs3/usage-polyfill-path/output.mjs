@@ -0,0 +1,2 @@
+import   "@my/core-js/modules/es.string.pad-start";
+'foo'.padStart(5);
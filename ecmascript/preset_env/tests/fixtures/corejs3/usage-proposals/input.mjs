@@ -0,0 +1,2 @@
+new Observable(() => {});
+Promise.allSettled([]);
@@ -0,0 +1,6 @@
+import   "core-js/modules/esnext.observable";
+import   "core-js/modules/esnext.promise.all-settled";
+import   "core-js/modules/esnext.symbol.observable";
+new Observable(()=>{
+});
+Promise.allSettled([]);
@@ -0,0 +1,4 @@
+import   "core-js/modules/esnext.promise.all-settled";
+new Observable(()=>{
+});
+Promise.allSettled([]);
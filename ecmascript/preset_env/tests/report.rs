@@ -0,0 +1,63 @@
+use swc_atoms::JsWord;
+use swc_common::{comments::SingleThreadedComments, input::StringInput, FileName, Mark};
+use swc_ecma_parser::{Parser, Syntax};
+use swc_ecma_preset_env::{preset_env_with_report, Config, Mode, Query, Version};
+use swc_ecma_visit::FoldWith;
+use testing::Tester;
+
+#[test]
+fn injected_polyfills_per_file() {
+    Tester::new()
+        .print_errors(|cm, handler| {
+            let (mut pass, injected) = preset_env_with_report(
+                cm.clone(),
+                Mark::fresh(Mark::root()),
+                Some(SingleThreadedComments::default()),
+                Config {
+                    mode: Some(Mode::Usage),
+                    core_js: Some(Version {
+                        major: 3,
+                        minor: 0,
+                        patch: 0,
+                    }),
+                    targets: Some(Query::Single("ie 11".into()).into()),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+
+            let mut files = vec![];
+            for (name, src) in &[
+                ("a.js", "'a'.padStart(2);"),
+                ("b.js", "[1].includes(1);"),
+                ("c.js", "foo();"),
+            ] {
+                let fm = cm.new_source_file(FileName::Real(name.into()), src.to_string());
+                let module = Parser::new(Syntax::default(), StringInput::from(&*fm), None)
+                    .parse_module()
+                    .map_err(|e| e.into_diagnostic(&handler).emit())?;
+                files.push(fm.name.clone());
+
+                module.fold_with(&mut pass);
+            }
+
+            assert_eq!(
+                injected.get(&files[0]),
+                vec![JsWord::from("core-js/modules/es.string.pad-start")]
+            );
+            assert_eq!(
+                injected.get(&files[1]),
+                vec![JsWord::from("core-js/modules/es.array.includes")]
+            );
+            assert!(injected.get(&files[2]).is_empty());
+
+            let all = injected.all();
+            assert_eq!(
+                all.into_iter().map(|(file, _)| file).collect::<Vec<_>>(),
+                files
+            );
+
+            Ok(())
+        })
+        .unwrap();
+}
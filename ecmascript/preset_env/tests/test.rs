@@ -61,6 +61,10 @@ struct PresetConfig {
     #[serde(default)]
     pub shipped_proposals: bool,
 
+    /// Not an option of babel.
+    #[serde(default)]
+    pub polyfill_path: Option<String>,
+
     #[serde(default)]
     pub config_path: String,
 
@@ -197,10 +201,20 @@ fn exec(c: PresetConfig, dir: PathBuf) -> Result<(), Error> {
                     exclude: c.exclude,
                     core_js: match c.corejs {
                         CoreJs::Ver(v) => Some(v),
-                        ref s => unimplemented!("Unknown core js version: {:?}", s),
+                        CoreJs::Val(ref v) => Some(
+                            serde_json::from_value(v["version"].clone())
+                                .expect("invalid core js version"),
+                        ),
                     },
                     force_all_transforms: c.force_all_transforms,
                     shipped_proposals: c.shipped_proposals,
+                    proposals: match c.corejs {
+                        CoreJs::Val(ref v) => {
+                            v.get("proposals").and_then(Value::as_bool).unwrap_or(false)
+                        }
+                        _ => false,
+                    },
+                    polyfill_path: c.polyfill_path.clone(),
                     targets: c.targets,
                     path: std::env::current_dir().unwrap(),
                 },
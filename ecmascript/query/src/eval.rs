@@ -0,0 +1,335 @@
+use crate::parser::{AttrOp, AttrValue, Combinator, Complex, Simple};
+use swc_atoms::JsWord;
+use swc_common::{Span, DUMMY_SP};
+use swc_ecma_visit::path::{AstField, AstKind, AstNodeRef};
+
+/// Flattened tree of nodes, in document order.
+///
+/// Enums are not stored in the tree. Instead, a node stores the kinds of enums
+/// wrapping it, so `Expr` matches any node stored as an `Expr`.
+pub(crate) struct Tree<'ast> {
+    pub nodes: Vec<TreeNode<'ast>>,
+}
+
+pub(crate) struct TreeNode<'ast> {
+    pub node: AstNodeRef<'ast>,
+    pub wrappers: Vec<AstKind>,
+    pub parent: Option<usize>,
+    /// Name of the field of the parent.
+    pub field: &'static str,
+    pub index: Option<usize>,
+    pub children: Vec<usize>,
+    pub span: Span,
+}
+
+impl<'ast> Tree<'ast> {
+    pub fn new(root: AstNodeRef<'ast>) -> Self {
+        let mut tree = Tree { nodes: vec![] };
+        tree.add(root, None, "", None, vec![]);
+        tree
+    }
+
+    fn add(
+        &mut self,
+        node: AstNodeRef<'ast>,
+        parent: Option<usize>,
+        field: &'static str,
+        index: Option<usize>,
+        mut wrappers: Vec<AstKind>,
+    ) -> Option<usize> {
+        if node.variant().is_some() {
+            wrappers.push(node.kind());
+
+            let mut id = None;
+            node.for_each_field(&mut |_, _, value| {
+                if let AstField::Node(child) = value {
+                    id = self.add(child, parent, field, index, wrappers.clone());
+                }
+            });
+            return id;
+        }
+
+        let id = self.nodes.len();
+        self.nodes.push(TreeNode {
+            node,
+            wrappers,
+            parent,
+            field,
+            index,
+            children: vec![],
+            span: DUMMY_SP,
+        });
+
+        let mut span = None;
+        let mut children = vec![];
+        node.for_each_field(&mut |name, index, value| match value {
+            AstField::Node(child) => {
+                children.extend(self.add(child, Some(id), name, index, vec![]))
+            }
+            AstField::Value(value) => {
+                if name == "span" {
+                    span = value.downcast_ref::<Span>().copied();
+                }
+            }
+        });
+
+        // Use the span of children if the node does not have one, like
+        // `ExprOrSpread`.
+        let span = span.unwrap_or_else(|| {
+            children
+                .iter()
+                .map(|&child| self.nodes[child].span)
+                .filter(|span| !span.is_dummy())
+                .fold(
+                    DUMMY_SP,
+                    |acc, span| {
+                        if acc.is_dummy() {
+                            span
+                        } else {
+                            acc.to(span)
+                        }
+                    },
+                )
+        });
+
+        let n = &mut self.nodes[id];
+        n.span = span;
+        n.children = children;
+        Some(id)
+    }
+
+    fn siblings(&self, id: usize) -> impl '_ + Iterator<Item = usize> {
+        let field = self.nodes[id].field;
+        self.nodes[id]
+            .parent
+            .into_iter()
+            .flat_map(move |parent| self.nodes[parent].children.iter().copied())
+            .filter(move |&sibling| {
+                self.nodes[sibling].field == field && self.nodes[sibling].index.is_some()
+            })
+    }
+
+    fn is_ancestor(&self, ancestor: usize, mut id: usize) -> bool {
+        while let Some(parent) = self.nodes[id].parent {
+            if parent == ancestor {
+                return true;
+            }
+            id = parent;
+        }
+        false
+    }
+
+    pub fn matches_any(&self, id: usize, list: &[Complex], scope: Option<usize>) -> bool {
+        list.iter()
+            .any(|complex| self.matches_complex(id, complex, complex.rest.len(), scope))
+    }
+
+    /// Returns true if `id` matches the compound selector at `idx` and the
+    /// compound selectors before it.
+    fn matches_complex(
+        &self,
+        id: usize,
+        complex: &Complex,
+        idx: usize,
+        scope: Option<usize>,
+    ) -> bool {
+        let (combinator, compound) = match idx {
+            0 => return self.matches_compound(id, &complex.first, scope),
+            _ => {
+                let (combinator, compound) = &complex.rest[idx - 1];
+                (*combinator, compound)
+            }
+        };
+        if !self.matches_compound(id, compound, scope) {
+            return false;
+        }
+
+        let node = &self.nodes[id];
+        match combinator {
+            Combinator::Child => node.parent.map_or(false, |parent| {
+                self.matches_complex(parent, complex, idx - 1, scope)
+            }),
+            Combinator::Descendant => {
+                let mut cur = node.parent;
+                while let Some(parent) = cur {
+                    if self.matches_complex(parent, complex, idx - 1, scope) {
+                        return true;
+                    }
+                    cur = self.nodes[parent].parent;
+                }
+                false
+            }
+            Combinator::Sibling | Combinator::Adjacent => {
+                let index = match node.index {
+                    Some(index) => index,
+                    None => return false,
+                };
+                self.siblings(id).any(|sibling| {
+                    let sibling_index = self.nodes[sibling].index.unwrap();
+                    let is_candidate = match combinator {
+                        Combinator::Adjacent => sibling_index + 1 == index,
+                        _ => sibling_index < index,
+                    };
+                    is_candidate && self.matches_complex(sibling, complex, idx - 1, scope)
+                })
+            }
+        }
+    }
+
+    fn matches_compound(&self, id: usize, compound: &[Simple], scope: Option<usize>) -> bool {
+        compound
+            .iter()
+            .all(|simple| self.matches_simple(id, simple, scope))
+    }
+
+    fn matches_simple(&self, id: usize, simple: &Simple, scope: Option<usize>) -> bool {
+        let node = &self.nodes[id];
+
+        match simple {
+            Simple::Any => true,
+            Simple::Type(name) => {
+                node.node.kind().name() == name
+                    || node.wrappers.iter().any(|kind| kind.name() == name)
+            }
+            Simple::Attr { path, op } => {
+                let mut values = vec![];
+                resolve(AstField::Node(node.node), path, &mut values);
+
+                match op {
+                    None => !values.is_empty(),
+                    Some((AttrOp::NotEq, expected)) => {
+                        !values.iter().any(|value| value.equals(expected))
+                    }
+                    Some((AttrOp::Eq, expected)) => {
+                        values.iter().any(|value| value.equals(expected))
+                    }
+                    Some((op, AttrValue::Num(expected))) => values.iter().any(|value| {
+                        let value = match value {
+                            Value::Num(value) => *value,
+                            _ => return false,
+                        };
+                        match op {
+                            AttrOp::Lt => value < *expected,
+                            AttrOp::LtEq => value <= *expected,
+                            AttrOp::Gt => value > *expected,
+                            AttrOp::GtEq => value >= *expected,
+                            AttrOp::Eq | AttrOp::NotEq => unreachable!(),
+                        }
+                    }),
+                    Some(..) => false,
+                }
+            }
+            Simple::Not(list) => !self.matches_any(id, list, scope),
+            Simple::Matches(list) => self.matches_any(id, list, scope),
+            Simple::Has(list) => {
+                // Relative selectors may select siblings.
+                let root = node.parent.unwrap_or(id);
+                (root..self.nodes.len())
+                    .take_while(|&candidate| candidate == root || self.is_ancestor(root, candidate))
+                    .any(|candidate| candidate != id && self.matches_any(candidate, list, Some(id)))
+            }
+            Simple::FirstChild => node.index == Some(0),
+            Simple::LastChild => node
+                .index
+                .map_or(false, |index| index + 1 == self.siblings(id).count()),
+            Simple::NthChild(n) => node.index == Some(n - 1),
+            Simple::NthLastChild(n) => match node.index {
+                Some(index) => self.siblings(id).count() == index + n,
+                None => false,
+            },
+            Simple::Scope => scope == Some(id),
+        }
+    }
+}
+
+/// Value of an attribute.
+enum Value {
+    Str(String),
+    Num(f64),
+    Bool(bool),
+    /// Name of a variant of an enum without fields, like `VarDeclKind::Const`.
+    Variant(&'static str),
+    /// A node or a value which cannot be compared, like `Span`.
+    Other,
+}
+
+impl Value {
+    fn from_any(value: &dyn std::any::Any) -> Self {
+        if let Some(v) = value.downcast_ref::<JsWord>() {
+            Value::Str(v.to_string())
+        } else if let Some(v) = value.downcast_ref::<String>() {
+            Value::Str(v.clone())
+        } else if let Some(v) = value.downcast_ref::<bool>() {
+            Value::Bool(*v)
+        } else if let Some(v) = value.downcast_ref::<f64>() {
+            Value::Num(*v)
+        } else if let Some(v) = value.downcast_ref::<u32>() {
+            Value::Num(*v as f64)
+        } else if let Some(v) = value.downcast_ref::<usize>() {
+            Value::Num(*v as f64)
+        } else {
+            Value::Other
+        }
+    }
+
+    fn equals(&self, expected: &AttrValue) -> bool {
+        let s = match self {
+            Value::Str(s) => s.clone(),
+            Value::Num(n) => {
+                if let AttrValue::Num(expected) = expected {
+                    return n == expected;
+                }
+                n.to_string()
+            }
+            Value::Bool(b) => b.to_string(),
+            Value::Variant(name) => {
+                if let AttrValue::Str(expected) = expected {
+                    return name.eq_ignore_ascii_case(expected);
+                }
+                name.to_string()
+            }
+            Value::Other => return false,
+        };
+
+        match expected {
+            AttrValue::Str(expected) => s == *expected,
+            AttrValue::Num(expected) => s.parse::<f64>().ok() == Some(*expected),
+            AttrValue::Regex(regex) => regex.is_match(&s),
+        }
+    }
+}
+
+/// Pushes values of `field` at `path` to `values`.
+fn resolve(field: AstField, path: &[String], values: &mut Vec<Value>) {
+    let node = match field {
+        AstField::Node(node) => node,
+        AstField::Value(value) => {
+            if path.is_empty() {
+                values.push(Value::from_any(value));
+            }
+            return;
+        }
+    };
+
+    // Enums are transparent.
+    if let Some(variant) = node.variant() {
+        let mut has_field = false;
+        node.for_each_field(&mut |_, _, value| {
+            has_field = true;
+            resolve(value, path, values);
+        });
+        if !has_field && path.is_empty() {
+            values.push(Value::Variant(variant));
+        }
+        return;
+    }
+
+    match path.split_first() {
+        Some((name, rest)) => node.for_each_field(&mut |field, _, value| {
+            if field == name {
+                resolve(value, rest, values);
+            }
+        }),
+        None => values.push(Value::Other),
+    }
+}
@@ -0,0 +1,93 @@
+//! esquery-compatible selectors for swc ecmascript nodes.
+//!
+//! ```ignore
+//! let selector: Selector = r#"CallExpr[callee.prop.sym="then"] > ExprOrSpread > ArrowExpr"#
+//!     .parse()?;
+//!
+//! for m in selector.query(&module) {
+//!     if let AstNodeRef::ArrowExpr(arrow) = m.node {
+//!         // ...
+//!     }
+//! }
+//! ```
+//!
+//! # Syntax
+//!
+//! Names of types and fields are those of `swc_ecma_ast`, not of ESTree.
+//!
+//!  - `CallExpr`, `*`: Type of a node. Names of enums wrapping a node also
+//!    match, so `Expr` matches all expressions.
+//!  - `a b`, `a > b`, `a ~ b`, `a + b`: Descendant, child, sibling and adjacent
+//!    sibling combinators. Siblings are nodes in the same vector.
+//!  - `[callee.sym]`: Matches if the field path exists. Enums are transparent
+//!    in field paths. `None` and empty vectors do not exist.
+//!  - `[sym="a"]`, `[sym!=a]`, `[sym=/^use/i]`, `[value>=1]`: Attribute
+//!    matchers. Variants of enums without fields, like `VarDeclKind::Const`,
+//!    are compared with their names ignoring case.
+//!  - `:not(a, b)`, `:matches(a, b)`, `:is(a, b)`, `:has(a, > b)`
+//!  - `:first-child`, `:last-child`, `:nth-child(n)`, `:nth-last-child(n)`
+pub use self::parser::ParseError;
+use self::{eval::Tree, parser::SelectorList};
+use std::str::FromStr;
+use swc_common::Span;
+pub use swc_ecma_visit::path::{AstKind, AstNodeRef};
+
+mod eval;
+mod parser;
+#[cfg(test)]
+mod tests;
+
+/// A parsed selector.
+#[derive(Debug, Clone)]
+pub struct Selector {
+    list: SelectorList,
+}
+
+/// A node matched by a [Selector].
+#[derive(Clone, Copy)]
+pub struct Match<'ast> {
+    pub node: AstNodeRef<'ast>,
+    /// Span of the node. If the node does not have a span, this covers its
+    /// children.
+    pub span: Span,
+}
+
+impl Selector {
+    pub fn parse(s: &str) -> Result<Self, ParseError> {
+        Ok(Selector {
+            list: parser::parse(s)?,
+        })
+    }
+
+    /// Returns nodes in `root` matching the selector, in the document order.
+    pub fn query<'ast, N>(&self, root: N) -> Vec<Match<'ast>>
+    where
+        N: Into<AstNodeRef<'ast>>,
+    {
+        let tree = Tree::new(root.into());
+
+        (0..tree.nodes.len())
+            .filter(|&id| tree.matches_any(id, &self.list, None))
+            .map(|id| Match {
+                node: tree.nodes[id].node,
+                span: tree.nodes[id].span,
+            })
+            .collect()
+    }
+}
+
+impl FromStr for Selector {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Selector::parse(s)
+    }
+}
+
+/// Parses `selector` and returns nodes in `root` matching it.
+pub fn query<'ast, N>(selector: &str, root: N) -> Result<Vec<Match<'ast>>, ParseError>
+where
+    N: Into<AstNodeRef<'ast>>,
+{
+    Ok(Selector::parse(selector)?.query(root))
+}
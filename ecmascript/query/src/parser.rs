@@ -0,0 +1,410 @@
+use regex::Regex;
+use std::fmt::{self, Display, Formatter};
+
+/// Error returned for an invalid selector.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Byte offset in the selector.
+    pub pos: usize,
+    pub message: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid selector at {}: {}", self.pos, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// `a, b`
+pub(crate) type SelectorList = Vec<Complex>;
+
+/// Compound selectors joined by combinators, like `a > b c`.
+#[derive(Debug, Clone)]
+pub(crate) struct Complex {
+    pub first: Compound,
+    pub rest: Vec<(Combinator, Compound)>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Combinator {
+    /// `a b`
+    Descendant,
+    /// `a > b`
+    Child,
+    /// `a ~ b`
+    Sibling,
+    /// `a + b`
+    Adjacent,
+}
+
+/// Simple selectors which should match the same node, like `Ident[sym="a"]`.
+pub(crate) type Compound = Vec<Simple>;
+
+#[derive(Debug, Clone)]
+pub(crate) enum Simple {
+    /// `*`
+    Any,
+    /// `CallExpr`
+    Type(String),
+    /// `[callee.sym]` or `[callee.sym="require"]`
+    Attr {
+        path: Vec<String>,
+        op: Option<(AttrOp, AttrValue)>,
+    },
+    /// `:not(a, b)`
+    Not(SelectorList),
+    /// `:matches(a, b)` or `:is(a, b)`
+    Matches(SelectorList),
+    /// `:has(a, > b)`
+    ///
+    /// Each selector starts with [Simple::Scope].
+    Has(SelectorList),
+    /// `:first-child`
+    FirstChild,
+    /// `:last-child`
+    LastChild,
+    /// `:nth-child(n)`
+    NthChild(usize),
+    /// `:nth-last-child(n)`
+    NthLastChild(usize),
+    /// The node `:has` is applied to.
+    Scope,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum AttrOp {
+    Eq,
+    NotEq,
+    Lt,
+    LtEq,
+    Gt,
+    GtEq,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum AttrValue {
+    /// `"a"`, `'a'` or `a`
+    Str(String),
+    Num(f64),
+    /// `/a/i`
+    Regex(Regex),
+}
+
+pub(crate) fn parse(input: &str) -> Result<SelectorList, ParseError> {
+    let mut p = Parser { input, pos: 0 };
+    let list = p.parse_list(false)?;
+    p.skip_ws();
+    if p.pos != input.len() {
+        return Err(p.error("unexpected character"));
+    }
+    Ok(list)
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn error(&self, message: &str) -> ParseError {
+        ParseError {
+            pos: self.pos,
+            message: message.into(),
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.input[self.pos..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), ParseError> {
+        if self.eat(c) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected `{}`", c)))
+        }
+    }
+
+    /// Returns true if any whitespace is skipped.
+    fn skip_ws(&mut self) -> bool {
+        let start = self.pos;
+        while self.peek().map_or(false, char::is_whitespace) {
+            self.bump();
+        }
+        self.pos != start
+    }
+
+    fn parse_ident(&mut self) -> Result<String, ParseError> {
+        let start = self.pos;
+        while self.peek().map_or(false, |c| {
+            c.is_alphanumeric() || c == '_' || c == '$' || c == '-'
+        }) {
+            self.bump();
+        }
+        if start == self.pos {
+            return Err(self.error("expected an identifier"));
+        }
+        Ok(self.input[start..self.pos].to_string())
+    }
+
+    /// If `relative` is true, selectors may start with a combinator.
+    fn parse_list(&mut self, relative: bool) -> Result<SelectorList, ParseError> {
+        let mut list = vec![];
+        loop {
+            self.skip_ws();
+            list.push(if relative {
+                self.parse_relative()?
+            } else {
+                self.parse_complex()?
+            });
+            self.skip_ws();
+            if !self.eat(',') {
+                return Ok(list);
+            }
+        }
+    }
+
+    fn parse_relative(&mut self) -> Result<Complex, ParseError> {
+        let combinator = self.parse_combinator().unwrap_or(Combinator::Descendant);
+        self.skip_ws();
+        let Complex { first, mut rest } = self.parse_complex()?;
+        rest.insert(0, (combinator, first));
+
+        Ok(Complex {
+            first: vec![Simple::Scope],
+            rest,
+        })
+    }
+
+    fn parse_combinator(&mut self) -> Option<Combinator> {
+        let combinator = match self.peek()? {
+            '>' => Combinator::Child,
+            '~' => Combinator::Sibling,
+            '+' => Combinator::Adjacent,
+            _ => return None,
+        };
+        self.bump();
+        Some(combinator)
+    }
+
+    fn parse_complex(&mut self) -> Result<Complex, ParseError> {
+        let first = self.parse_compound()?;
+        let mut rest = vec![];
+
+        loop {
+            let had_ws = self.skip_ws();
+            let combinator = match self.parse_combinator() {
+                Some(combinator) => {
+                    self.skip_ws();
+                    combinator
+                }
+                None if had_ws && self.at_compound() => Combinator::Descendant,
+                None => break,
+            };
+            rest.push((combinator, self.parse_compound()?));
+        }
+
+        Ok(Complex { first, rest })
+    }
+
+    fn at_compound(&self) -> bool {
+        self.peek().map_or(false, |c| {
+            c.is_alphanumeric() || c == '_' || c == '$' || c == '*' || c == '[' || c == ':'
+        })
+    }
+
+    fn parse_compound(&mut self) -> Result<Compound, ParseError> {
+        let mut compound = vec![];
+
+        while self.at_compound() {
+            compound.push(match self.peek().unwrap() {
+                '*' => {
+                    self.bump();
+                    Simple::Any
+                }
+                '[' => self.parse_attr()?,
+                ':' => self.parse_pseudo()?,
+                _ => Simple::Type(self.parse_ident()?),
+            });
+        }
+
+        if compound.is_empty() {
+            return Err(self.error("expected a selector"));
+        }
+        Ok(compound)
+    }
+
+    fn parse_attr(&mut self) -> Result<Simple, ParseError> {
+        self.expect('[')?;
+        self.skip_ws();
+
+        let mut path = vec![self.parse_ident()?];
+        while self.eat('.') {
+            path.push(self.parse_ident()?);
+        }
+        self.skip_ws();
+
+        let op = if self.eat(']') {
+            return Ok(Simple::Attr { path, op: None });
+        } else if self.eat('=') {
+            AttrOp::Eq
+        } else if self.eat('!') {
+            self.expect('=')?;
+            AttrOp::NotEq
+        } else if self.eat('<') {
+            if self.eat('=') {
+                AttrOp::LtEq
+            } else {
+                AttrOp::Lt
+            }
+        } else if self.eat('>') {
+            if self.eat('=') {
+                AttrOp::GtEq
+            } else {
+                AttrOp::Gt
+            }
+        } else {
+            return Err(self.error("expected an operator or `]`"));
+        };
+        self.skip_ws();
+
+        let value = self.parse_attr_value()?;
+        match (op, &value) {
+            (AttrOp::Eq, _) | (AttrOp::NotEq, _) | (_, AttrValue::Num(..)) => {}
+            _ => return Err(self.error("only numbers can be compared")),
+        }
+        self.skip_ws();
+        self.expect(']')?;
+
+        Ok(Simple::Attr {
+            path,
+            op: Some((op, value)),
+        })
+    }
+
+    fn parse_attr_value(&mut self) -> Result<AttrValue, ParseError> {
+        match self.peek() {
+            Some(quote) if quote == '"' || quote == '\'' => {
+                self.bump();
+                let mut s = String::new();
+                loop {
+                    match self.bump() {
+                        Some(c) if c == quote => return Ok(AttrValue::Str(s)),
+                        Some('\\') => match self.bump() {
+                            Some(c) => s.push(c),
+                            None => break,
+                        },
+                        Some(c) => s.push(c),
+                        None => break,
+                    }
+                }
+                Err(self.error("unterminated string"))
+            }
+            Some('/') => {
+                self.bump();
+                let start = self.pos;
+                loop {
+                    match self.bump() {
+                        Some('/') => break,
+                        Some('\\') => {
+                            self.bump();
+                        }
+                        Some(_) => {}
+                        None => return Err(self.error("unterminated regex")),
+                    }
+                }
+                let pattern = &self.input[start..self.pos - 1];
+
+                let flags_start = self.pos;
+                while self.peek().map_or(false, |c| "imsx".contains(c)) {
+                    self.bump();
+                }
+                let flags = &self.input[flags_start..self.pos];
+
+                let pattern = if flags.is_empty() {
+                    pattern.to_string()
+                } else {
+                    format!("(?{}){}", flags, pattern)
+                };
+                Regex::new(&pattern)
+                    .map(AttrValue::Regex)
+                    .map_err(|err| self.error(&err.to_string()))
+            }
+            Some(c) if c.is_ascii_digit() || c == '-' || c == '.' => {
+                let start = self.pos;
+                self.bump();
+                while self
+                    .peek()
+                    .map_or(false, |c| c.is_ascii_digit() || c == '.' || c == 'e')
+                {
+                    self.bump();
+                }
+                self.input[start..self.pos]
+                    .parse()
+                    .map(AttrValue::Num)
+                    .map_err(|_| self.error("invalid number"))
+            }
+            _ => self.parse_ident().map(AttrValue::Str),
+        }
+    }
+
+    fn parse_pseudo(&mut self) -> Result<Simple, ParseError> {
+        self.expect(':')?;
+        let start = self.pos;
+        let name = self.parse_ident()?;
+
+        let simple = match &*name {
+            "first-child" => return Ok(Simple::FirstChild),
+            "last-child" => return Ok(Simple::LastChild),
+            "not" | "matches" | "is" | "has" => {
+                self.expect('(')?;
+                let list = self.parse_list(name == "has")?;
+                match &*name {
+                    "not" => Simple::Not(list),
+                    "has" => Simple::Has(list),
+                    _ => Simple::Matches(list),
+                }
+            }
+            "nth-child" | "nth-last-child" => {
+                self.expect('(')?;
+                self.skip_ws();
+                let n = match self.parse_attr_value()? {
+                    AttrValue::Num(n) if n >= 1.0 && n.fract() == 0.0 => n as usize,
+                    _ => return Err(self.error("expected a positive integer")),
+                };
+                if name == "nth-child" {
+                    Simple::NthChild(n)
+                } else {
+                    Simple::NthLastChild(n)
+                }
+            }
+            _ => {
+                return Err(ParseError {
+                    pos: start,
+                    message: format!("unknown pseudo class `{}`", name),
+                })
+            }
+        };
+
+        self.skip_ws();
+        self.expect(')')?;
+        Ok(simple)
+    }
+}
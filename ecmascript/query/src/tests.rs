@@ -0,0 +1,115 @@
+use super::*;
+use swc_common::FileName;
+use swc_ecma_parser::{Parser, StringInput, Syntax};
+
+/// Returns source code of nodes matching `selector`.
+fn run(selector: &str, src: &str) -> Vec<String> {
+    ::testing::run_test(false, |cm, handler| {
+        let fm = cm.new_source_file(FileName::Anon, src.into());
+        let module = Parser::new(Syntax::default(), StringInput::from(&*fm), None)
+            .parse_module()
+            .map_err(|err| err.into_diagnostic(handler).emit())?;
+
+        Ok(query(selector, &module)
+            .unwrap()
+            .into_iter()
+            .map(|m| cm.span_to_snippet(m.span).unwrap())
+            .collect())
+    })
+    .unwrap()
+}
+
+#[test]
+fn types() {
+    assert_eq!(run("Ident", "a + b"), vec!["a", "b"]);
+    assert_eq!(run("BinExpr", "a + b"), vec!["a + b"]);
+    assert_eq!(run("Lit", "a + 1 + 'b'"), vec!["1", "'b'"]);
+}
+
+#[test]
+fn combinators() {
+    let src = "foo(a, () => b, () => { c })";
+
+    assert_eq!(
+        run("CallExpr > ExprOrSpread > ArrowExpr", src),
+        vec!["() => b", "() => { c }"]
+    );
+    assert_eq!(run("CallExpr > ArrowExpr", src), Vec::<String>::new());
+    assert_eq!(run("CallExpr ArrowExpr Ident", src), vec!["b", "c"]);
+    assert_eq!(
+        run("ExprOrSpread ~ ExprOrSpread", src),
+        vec!["() => b", "() => { c }"]
+    );
+    assert_eq!(
+        run("ExprOrSpread:first-child + ExprOrSpread", src),
+        vec!["() => b"]
+    );
+    assert_eq!(run("Stmt + Stmt", "a; b; c;"), vec!["b;", "c;"]);
+}
+
+#[test]
+fn attributes() {
+    let src = "promise.then(() => {}).catch(() => {}); const a = 1; let b = 2;";
+
+    assert_eq!(
+        run(
+            r#"CallExpr[callee.prop.sym="then"] > ExprOrSpread > ArrowExpr"#,
+            src
+        ),
+        vec!["() => {}"]
+    );
+    assert_eq!(run("MemberExpr[prop.sym=/^(then|catch)$/]", src).len(), 2);
+    assert_eq!(
+        run("MemberExpr[prop.sym!=then] > Ident", src),
+        vec!["catch"]
+    );
+    assert_eq!(run("VarDecl[kind=const] Ident", src), vec!["a"]);
+    assert_eq!(run("VarDecl[kind='LET'] Ident", src), vec!["b"]);
+    assert_eq!(run("Number[value>1]", src), vec!["2"]);
+    assert_eq!(run("ArrowExpr[is_async=false][body.span]", src).len(), 2);
+    assert_eq!(run("ArrowExpr[body.sym]", src), Vec::<String>::new());
+    assert_eq!(run("ArrowExpr[body.sym]", "() => a"), vec!["() => a"]);
+}
+
+#[test]
+fn pseudo_classes() {
+    let src = "foo(a, b, c); bar(d);";
+
+    assert_eq!(run("ExprOrSpread:first-child", src), vec!["a", "d"]);
+    assert_eq!(run("ExprOrSpread:last-child", src), vec!["c", "d"]);
+    assert_eq!(run("ExprOrSpread:nth-child(2)", src), vec!["b"]);
+    assert_eq!(run("ExprOrSpread:nth-last-child(3)", src), vec!["a"]);
+    assert_eq!(
+        run("Ident:not(ExprOrSpread > Ident)", src),
+        vec!["foo", "bar"]
+    );
+    assert_eq!(
+        run(
+            r#":matches(CallExpr[callee.sym="bar"], Ident[sym="b"])"#,
+            src
+        ),
+        vec!["b", "bar(d)"]
+    );
+    assert_eq!(
+        run(r#"CallExpr:has(Ident[sym="c"])"#, src),
+        vec!["foo(a, b, c)"]
+    );
+    assert_eq!(
+        run(
+            r#"ExprStmt:has(> CallExpr > ExprOrSpread:nth-child(3))"#,
+            src
+        ),
+        vec!["foo(a, b, c);"]
+    );
+    assert_eq!(run(r#"ExprStmt:has(> Ident)"#, src), Vec::<String>::new());
+}
+
+#[test]
+fn parse_errors() {
+    assert!(Selector::parse("CallExpr >").is_err());
+    assert!(Selector::parse("[sym=").is_err());
+    assert!(Selector::parse("[sym<'a']").is_err());
+    assert!(Selector::parse(":unknown").is_err());
+    assert!(Selector::parse("[sym=/(/]").is_err());
+    assert_eq!(Selector::parse("a ]").unwrap_err().pos, 2);
+}
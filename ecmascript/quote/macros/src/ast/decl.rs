@@ -0,0 +1,14 @@
+impl_enum!(
+    Decl,
+    [Class, Fn, Var, TsInterface, TsTypeAlias, TsEnum, TsModule]
+);
+
+impl_struct!(FnDecl, [ident, declare, function]);
+
+impl_struct!(ClassDecl, [ident, declare, class]);
+
+impl_struct!(VarDecl, [span, kind, declare, decls]);
+
+impl_unit_enum!(VarDeclKind, [Var, Let, Const]);
+
+impl_struct!(VarDeclarator, [span, name, init, definite]);
@@ -0,0 +1,154 @@
+use crate::{ast::ToCode, ctx::Ctx, input::VarKind};
+use proc_macro2::TokenStream;
+use swc_ecma_ast::{BindingIdent, Expr, Pat, PatOrExpr};
+
+impl ToCode for Expr {
+    fn to_code(&self, cx: &Ctx) -> TokenStream {
+        if let Expr::Ident(ident) = self {
+            if let Some(var) = cx.var(VarKind::Expr, ident) {
+                return quote::quote!(*#var);
+            }
+        }
+
+        match_enum!(
+            self,
+            cx,
+            Expr,
+            [
+                This,
+                Array,
+                Object,
+                Fn,
+                Unary,
+                Update,
+                Bin,
+                Assign,
+                Member,
+                Cond,
+                Call,
+                New,
+                Seq,
+                Ident,
+                Lit,
+                Tpl,
+                TaggedTpl,
+                Arrow,
+                Class,
+                Yield,
+                MetaProp,
+                Await,
+                Paren,
+                JSXMember,
+                JSXNamespacedName,
+                JSXEmpty,
+                JSXElement,
+                JSXFragment,
+                TsTypeAssertion,
+                TsConstAssertion,
+                TsNonNull,
+                TsAs,
+                PrivateName,
+                OptChain,
+                Invalid
+            ]
+        )
+    }
+}
+
+impl_struct!(ThisExpr, [span]);
+
+impl_struct!(ArrayLit, [span, elems]);
+
+impl_struct!(ObjectLit, [span, props]);
+
+impl_enum!(PropOrSpread, [Spread, Prop]);
+
+impl_struct!(SpreadElement, [dot3_token, expr]);
+
+impl_struct!(UnaryExpr, [span, op, arg]);
+
+impl_struct!(UpdateExpr, [span, op, prefix, arg]);
+
+impl_struct!(BinExpr, [span, op, left, right]);
+
+impl_struct!(FnExpr, [ident, function]);
+
+impl_struct!(ClassExpr, [ident, class]);
+
+impl_struct!(AssignExpr, [span, op, left, right]);
+
+impl_struct!(MemberExpr, [span, obj, prop, computed]);
+
+impl_struct!(CondExpr, [span, test, cons, alt]);
+
+impl_struct!(CallExpr, [span, callee, args, type_args]);
+
+impl_struct!(NewExpr, [span, callee, args, type_args]);
+
+impl_struct!(SeqExpr, [span, exprs]);
+
+impl_struct!(
+    ArrowExpr,
+    [
+        span,
+        params,
+        body,
+        is_async,
+        is_generator,
+        type_params,
+        return_type
+    ]
+);
+
+impl_struct!(YieldExpr, [span, arg, delegate]);
+
+impl_struct!(MetaPropExpr, [meta, prop]);
+
+impl_struct!(AwaitExpr, [span, arg]);
+
+impl_struct!(Tpl, [span, exprs, quasis]);
+
+impl_struct!(TaggedTpl, [span, tag, type_params, tpl]);
+
+impl_struct!(TplElement, [span, tail, cooked, raw]);
+
+impl_struct!(ParenExpr, [span, expr]);
+
+impl_enum!(ExprOrSuper, [Super, Expr]);
+
+impl_struct!(Super, [span]);
+
+impl_struct!(ExprOrSpread, [spread, expr]);
+
+impl_enum!(BlockStmtOrExpr, [BlockStmt, Expr]);
+
+impl ToCode for PatOrExpr {
+    fn to_code(&self, cx: &Ctx) -> TokenStream {
+        // Left hand side of an assignment can be an expression or a pattern,
+        // regardless of the way the parser stores it.
+        match self {
+            PatOrExpr::Pat(pat) => {
+                if let Pat::Ident(BindingIdent { id, .. }) = &**pat {
+                    if let Some(var) = cx.var(VarKind::Expr, id) {
+                        return quote::quote!(
+                            ::swc_ecma_quote::swc_ecma_ast::PatOrExpr::Expr(#var)
+                        );
+                    }
+                }
+            }
+            PatOrExpr::Expr(expr) => {
+                if let Expr::Ident(ident) = &**expr {
+                    if let Some(var) = cx.var(VarKind::Pat, ident) {
+                        return quote::quote!(::swc_ecma_quote::swc_ecma_ast::PatOrExpr::Pat(
+                            ::std::boxed::Box::new(#var)
+                        ));
+                    }
+                }
+            }
+        }
+
+        match_enum!(self, cx, PatOrExpr, [Expr, Pat])
+    }
+}
+
+impl_struct!(OptChainExpr, [span, question_dot_token, expr]);
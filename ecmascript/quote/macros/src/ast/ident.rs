@@ -0,0 +1,39 @@
+use crate::{ast::ToCode, ctx::Ctx, input::VarKind};
+use proc_macro2::TokenStream;
+use swc_ecma_ast::Ident;
+
+impl ToCode for Ident {
+    fn to_code(&self, cx: &Ctx) -> TokenStream {
+        if let Some(var) = cx.var(VarKind::Ident, self) {
+            return var;
+        }
+        if let Some(var) = cx.lookup(self) {
+            cx.error(
+                var,
+                format!(
+                    "`${}` is declared as `{:?}`, but used as an identifier",
+                    var.name, var.kind
+                ),
+            );
+        }
+
+        let Ident {
+            span,
+            sym,
+            optional,
+        } = self;
+        let span = span.to_code(cx);
+        let sym = sym.to_code(cx);
+        let optional = optional.to_code(cx);
+
+        quote::quote!(::swc_ecma_quote::swc_ecma_ast::Ident {
+            span: #span,
+            sym: #sym,
+            optional: #optional,
+        })
+    }
+}
+
+impl_struct!(BindingIdent, [id, type_ann]);
+
+impl_struct!(PrivateName, [span, id]);
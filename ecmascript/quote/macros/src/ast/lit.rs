@@ -0,0 +1,48 @@
+use crate::{ast::ToCode, ctx::Ctx};
+use proc_macro2::TokenStream;
+use swc_ecma_ast::{BigInt, Str};
+
+impl_enum!(Lit, [Str, Bool, Null, Num, BigInt, Regex, JSXText]);
+
+impl ToCode for BigInt {
+    fn to_code(&self, cx: &Ctx) -> TokenStream {
+        let BigInt { span, value } = self;
+        let span = span.to_code(cx);
+        let value = value.to_string();
+
+        quote::quote!(::swc_ecma_quote::swc_ecma_ast::BigInt {
+            span: #span,
+            value: #value.parse().unwrap(),
+        })
+    }
+}
+
+impl ToCode for Str {
+    fn to_code(&self, cx: &Ctx) -> TokenStream {
+        let Str {
+            span,
+            value,
+            has_escape,
+            kind: _,
+        } = self;
+        let span = span.to_code(cx);
+        let value = value.to_code(cx);
+        let has_escape = has_escape.to_code(cx);
+
+        // The span does not point to the original code anymore.
+        quote::quote!(::swc_ecma_quote::swc_ecma_ast::Str {
+            span: #span,
+            value: #value,
+            has_escape: #has_escape,
+            kind: ::swc_ecma_quote::swc_ecma_ast::StrKind::Synthesized,
+        })
+    }
+}
+
+impl_struct!(Bool, [span, value]);
+
+impl_struct!(Null, [span]);
+
+impl_struct!(Regex, [span, exp, flags]);
+
+impl_struct!(Number, [span, value]);
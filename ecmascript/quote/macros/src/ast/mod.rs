@@ -0,0 +1,185 @@
+//! Conversion of ast nodes into rust code constructing them.
+//!
+//! Spans are not preserved, and all nodes are created with `DUMMY_SP`.
+use crate::ctx::Ctx;
+use proc_macro2::{Literal, TokenStream};
+use swc_atoms::JsWord;
+use swc_common::Span;
+
+/// Implements [ToCode] for a struct.
+///
+/// All fields should be listed.
+macro_rules! impl_struct {
+    ($name:ident, [$($field:ident),*]) => {
+        impl crate::ast::ToCode for swc_ecma_ast::$name {
+            fn to_code(&self, cx: &crate::ctx::Ctx) -> proc_macro2::TokenStream {
+                let swc_ecma_ast::$name { $($field),* } = self;
+                $(
+                    let $field = crate::ast::ToCode::to_code($field, cx);
+                )*
+
+                quote::quote!(::swc_ecma_quote::swc_ecma_ast::$name {
+                    $($field: #$field,)*
+                })
+            }
+        }
+    };
+}
+
+/// Creates a match expression converting each variant of an enum.
+///
+/// All variants should be listed.
+macro_rules! match_enum {
+    ($node:expr, $cx:expr, $name:ident, [$($variant:ident),*]) => {
+        match $node {
+            $(
+                swc_ecma_ast::$name::$variant(node) => {
+                    let node = crate::ast::ToCode::to_code(node, $cx);
+                    quote::quote!(::swc_ecma_quote::swc_ecma_ast::$name::$variant(#node))
+                }
+            )*
+        }
+    };
+}
+
+/// Implements [ToCode] for an enum with newtype variants.
+macro_rules! impl_enum {
+    ($name:ident, [$($variant:ident),*]) => {
+        impl crate::ast::ToCode for swc_ecma_ast::$name {
+            fn to_code(&self, cx: &crate::ctx::Ctx) -> proc_macro2::TokenStream {
+                match_enum!(self, cx, $name, [$($variant),*])
+            }
+        }
+    };
+}
+
+/// Implements [ToCode] for an enum with unit variants.
+macro_rules! impl_unit_enum {
+    ($name:ident, [$($variant:ident),*]) => {
+        impl crate::ast::ToCode for swc_ecma_ast::$name {
+            fn to_code(&self, _: &crate::ctx::Ctx) -> proc_macro2::TokenStream {
+                match self {
+                    $(
+                        swc_ecma_ast::$name::$variant => {
+                            quote::quote!(::swc_ecma_quote::swc_ecma_ast::$name::$variant)
+                        }
+                    )*
+                }
+            }
+        }
+    };
+}
+
+/// Implements [ToCode] for nodes which are not created by the parser because
+/// the code is parsed as javascript.
+macro_rules! impl_unsupported {
+    ($($name:ident),*) => {
+        $(
+            impl crate::ast::ToCode for swc_ecma_ast::$name {
+                fn to_code(&self, _: &crate::ctx::Ctx) -> proc_macro2::TokenStream {
+                    unreachable!(concat!("javascript code contains ", stringify!($name)))
+                }
+            }
+        )*
+    };
+}
+
+mod class;
+mod decl;
+mod expr;
+mod function;
+mod ident;
+mod lit;
+mod module;
+mod module_decl;
+mod operators;
+mod pat;
+mod prop;
+mod stmt;
+
+/// Converts a node into an expression which creates it.
+pub(crate) trait ToCode {
+    fn to_code(&self, cx: &Ctx) -> TokenStream;
+}
+
+impl<T: ToCode> ToCode for Box<T> {
+    fn to_code(&self, cx: &Ctx) -> TokenStream {
+        let node = (**self).to_code(cx);
+        quote::quote!(::std::boxed::Box::new(#node))
+    }
+}
+
+impl<T: ToCode> ToCode for Option<T> {
+    fn to_code(&self, cx: &Ctx) -> TokenStream {
+        match self {
+            Some(node) => {
+                let node = node.to_code(cx);
+                quote::quote!(::std::option::Option::Some(#node))
+            }
+            None => quote::quote!(::std::option::Option::None),
+        }
+    }
+}
+
+impl<T: ToCode> ToCode for Vec<T> {
+    fn to_code(&self, cx: &Ctx) -> TokenStream {
+        let nodes = self.iter().map(|node| node.to_code(cx));
+        quote::quote!(::std::vec![#(#nodes),*])
+    }
+}
+
+impl ToCode for Span {
+    fn to_code(&self, _: &Ctx) -> TokenStream {
+        quote::quote!(::swc_ecma_quote::swc_common::DUMMY_SP)
+    }
+}
+
+impl ToCode for bool {
+    fn to_code(&self, _: &Ctx) -> TokenStream {
+        let value = *self;
+        quote::quote!(#value)
+    }
+}
+
+impl ToCode for f64 {
+    fn to_code(&self, _: &Ctx) -> TokenStream {
+        let value = Literal::f64_suffixed(*self);
+        quote::quote!(#value)
+    }
+}
+
+impl ToCode for JsWord {
+    fn to_code(&self, _: &Ctx) -> TokenStream {
+        let value = &**self;
+        quote::quote!(::swc_ecma_quote::swc_atoms::JsWord::from(#value))
+    }
+}
+
+impl_struct!(Invalid, [span]);
+
+impl_unsupported!(
+    Accessibility,
+    JSXElement,
+    JSXEmptyExpr,
+    JSXFragment,
+    JSXMemberExpr,
+    JSXNamespacedName,
+    JSXText,
+    TsAsExpr,
+    TsConstAssertion,
+    TsEnumDecl,
+    TsExportAssignment,
+    TsExprWithTypeArgs,
+    TsImportEqualsDecl,
+    TsIndexSignature,
+    TsInterfaceDecl,
+    TsModuleDecl,
+    TsNamespaceExportDecl,
+    TsNonNullExpr,
+    TsParamProp,
+    TsTypeAliasDecl,
+    TsTypeAnn,
+    TsTypeAssertion,
+    TsTypeParamDecl,
+    TsTypeParamInstantiation
+);
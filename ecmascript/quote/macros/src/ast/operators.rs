@@ -0,0 +1,56 @@
+impl_unit_enum!(
+    BinaryOp,
+    [
+        EqEq,
+        NotEq,
+        EqEqEq,
+        NotEqEq,
+        Lt,
+        LtEq,
+        Gt,
+        GtEq,
+        LShift,
+        RShift,
+        ZeroFillRShift,
+        Add,
+        Sub,
+        Mul,
+        Div,
+        Mod,
+        BitOr,
+        BitXor,
+        BitAnd,
+        LogicalOr,
+        LogicalAnd,
+        In,
+        InstanceOf,
+        Exp,
+        NullishCoalescing
+    ]
+);
+
+impl_unit_enum!(
+    AssignOp,
+    [
+        Assign,
+        AddAssign,
+        SubAssign,
+        MulAssign,
+        DivAssign,
+        ModAssign,
+        LShiftAssign,
+        RShiftAssign,
+        ZeroFillRShiftAssign,
+        BitOrAssign,
+        BitXorAssign,
+        BitAndAssign,
+        ExpAssign,
+        AndAssign,
+        OrAssign,
+        NullishAssign
+    ]
+);
+
+impl_unit_enum!(UpdateOp, [PlusPlus, MinusMinus]);
+
+impl_unit_enum!(UnaryOp, [Minus, Plus, Bang, Tilde, TypeOf, Void, Delete]);
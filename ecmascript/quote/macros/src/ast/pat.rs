@@ -0,0 +1,34 @@
+use crate::{ast::ToCode, ctx::Ctx, input::VarKind};
+use proc_macro2::TokenStream;
+use swc_ecma_ast::{BindingIdent, Pat};
+
+impl ToCode for Pat {
+    fn to_code(&self, cx: &Ctx) -> TokenStream {
+        if let Pat::Ident(BindingIdent { id, type_ann: None }) = self {
+            if let Some(var) = cx.var(VarKind::Pat, id) {
+                return var;
+            }
+        }
+
+        match_enum!(
+            self,
+            cx,
+            Pat,
+            [Ident, Array, Rest, Object, Assign, Invalid, Expr]
+        )
+    }
+}
+
+impl_struct!(ArrayPat, [span, elems, optional, type_ann]);
+
+impl_struct!(ObjectPat, [span, props, optional, type_ann]);
+
+impl_struct!(AssignPat, [span, left, right, type_ann]);
+
+impl_struct!(RestPat, [span, dot3_token, arg, type_ann]);
+
+impl_enum!(ObjectPatProp, [KeyValue, Assign, Rest]);
+
+impl_struct!(KeyValuePatProp, [key, value]);
+
+impl_struct!(AssignPatProp, [span, key, value]);
@@ -0,0 +1,69 @@
+use crate::{ast::ToCode, ctx::Ctx, input::VarKind};
+use proc_macro2::TokenStream;
+use swc_ecma_ast::{Expr, ExprStmt, Stmt};
+
+impl ToCode for Stmt {
+    fn to_code(&self, cx: &Ctx) -> TokenStream {
+        if let Stmt::Expr(ExprStmt { expr, .. }) = self {
+            if let Expr::Ident(ident) = &**expr {
+                if let Some(var) = cx.var(VarKind::Stmt, ident) {
+                    return var;
+                }
+            }
+        }
+
+        match_enum!(
+            self,
+            cx,
+            Stmt,
+            [
+                Block, Empty, Debugger, With, Return, Labeled, Break, Continue, If, Switch, Throw,
+                Try, While, DoWhile, For, ForIn, ForOf, Decl, Expr
+            ]
+        )
+    }
+}
+
+impl_struct!(BlockStmt, [span, stmts]);
+
+impl_struct!(ExprStmt, [span, expr]);
+
+impl_struct!(EmptyStmt, [span]);
+
+impl_struct!(DebuggerStmt, [span]);
+
+impl_struct!(WithStmt, [span, obj, body]);
+
+impl_struct!(ReturnStmt, [span, arg]);
+
+impl_struct!(LabeledStmt, [span, label, body]);
+
+impl_struct!(BreakStmt, [span, label]);
+
+impl_struct!(ContinueStmt, [span, label]);
+
+impl_struct!(IfStmt, [span, test, cons, alt]);
+
+impl_struct!(SwitchStmt, [span, discriminant, cases]);
+
+impl_struct!(ThrowStmt, [span, arg]);
+
+impl_struct!(TryStmt, [span, block, handler, finalizer]);
+
+impl_struct!(WhileStmt, [span, test, body]);
+
+impl_struct!(DoWhileStmt, [span, test, body]);
+
+impl_struct!(ForStmt, [span, init, test, update, body]);
+
+impl_struct!(ForInStmt, [span, left, right, body]);
+
+impl_struct!(ForOfStmt, [span, await_token, left, right, body]);
+
+impl_struct!(SwitchCase, [span, test, cons]);
+
+impl_struct!(CatchClause, [span, param, body]);
+
+impl_enum!(VarDeclOrPat, [VarDecl, Pat]);
+
+impl_enum!(VarDeclOrExpr, [VarDecl, Expr]);
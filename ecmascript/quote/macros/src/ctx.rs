@@ -0,0 +1,67 @@
+use crate::input::{Var, VarKind};
+use proc_macro2::TokenStream;
+use std::cell::{Cell, RefCell};
+use swc_ecma_ast::Ident;
+
+/// Context of [ToCode](crate::ast::ToCode).
+pub(crate) struct Ctx<'a> {
+    vars: &'a [Var],
+    used: Vec<Cell<bool>>,
+    errors: RefCell<Vec<syn::Error>>,
+}
+
+impl<'a> Ctx<'a> {
+    pub fn new(vars: &'a [Var]) -> Self {
+        Ctx {
+            vars,
+            used: vars.iter().map(|_| Cell::new(false)).collect(),
+            errors: Default::default(),
+        }
+    }
+
+    /// Returns the variable for `$name`.
+    pub fn lookup(&self, ident: &Ident) -> Option<&'a Var> {
+        let name = ident.sym.strip_prefix('$')?;
+        self.vars.iter().find(|var| var.name == name)
+    }
+
+    /// Returns an expression which creates the value of `$name` if `ident` is
+    /// a variable of `kind`.
+    pub fn var(&self, kind: VarKind, ident: &Ident) -> Option<TokenStream> {
+        let var = self.lookup(ident)?;
+        if var.kind != kind {
+            return None;
+        }
+
+        self.mark_used(var);
+        let local = &var.local;
+        Some(quote::quote!(#local.clone()))
+    }
+
+    /// Reports misuse of a variable.
+    pub fn error(&self, var: &Var, msg: String) {
+        self.mark_used(var);
+        self.errors
+            .borrow_mut()
+            .push(syn::Error::new(var.name.span(), msg));
+    }
+
+    fn mark_used(&self, var: &Var) {
+        let idx = self.vars.iter().position(|v| v.name == var.name).unwrap();
+        self.used[idx].set(true);
+    }
+
+    /// Returns errors, including ones for unused variables.
+    pub fn finish(self) -> Vec<syn::Error> {
+        let mut errors = self.errors.into_inner();
+        for (var, used) in self.vars.iter().zip(&self.used) {
+            if !used.get() {
+                errors.push(syn::Error::new(
+                    var.name.span(),
+                    format!("`${}` is not used in the code", var.name),
+                ));
+            }
+        }
+        errors
+    }
+}
@@ -0,0 +1,131 @@
+use proc_macro2::{Span, TokenStream};
+use syn::{
+    parse::{Parse, ParseStream},
+    Expr, Ident, LitStr, Token,
+};
+
+/// `"code" as Output, name = value, name: Type = value`
+pub(crate) struct QuoteInput {
+    pub src: LitStr,
+    pub output: Option<Output>,
+    pub vars: Vec<Var>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Output {
+    Expr,
+    Stmt,
+    ModuleItem,
+}
+
+/// A variable which can be used as `$name` in the code.
+pub(crate) struct Var {
+    pub name: Ident,
+    pub kind: VarKind,
+    /// Name of the local variable storing the value.
+    pub local: Ident,
+    pub value: Expr,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum VarKind {
+    Ident,
+    Expr,
+    Pat,
+    Stmt,
+}
+
+impl VarKind {
+    /// Type of the local variable.
+    pub fn to_type(self) -> TokenStream {
+        match self {
+            VarKind::Ident => quote::quote!(::swc_ecma_quote::swc_ecma_ast::Ident),
+            VarKind::Expr => {
+                quote::quote!(::std::boxed::Box<::swc_ecma_quote::swc_ecma_ast::Expr>)
+            }
+            VarKind::Pat => quote::quote!(::swc_ecma_quote::swc_ecma_ast::Pat),
+            VarKind::Stmt => quote::quote!(::swc_ecma_quote::swc_ecma_ast::Stmt),
+        }
+    }
+}
+
+impl Parse for QuoteInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let src: LitStr = input.parse()?;
+
+        let output = if input.peek(Token![as]) {
+            input.parse::<Token![as]>()?;
+            let ty: Ident = input.parse()?;
+            Some(match &*ty.to_string() {
+                "Expr" => Output::Expr,
+                "Stmt" => Output::Stmt,
+                "ModuleItem" => Output::ModuleItem,
+                _ => {
+                    return Err(syn::Error::new(
+                        ty.span(),
+                        "expected one of `Expr`, `Stmt` and `ModuleItem`",
+                    ))
+                }
+            })
+        } else {
+            None
+        };
+
+        let mut vars: Vec<Var> = vec![];
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+
+            let var: Var = input.parse()?;
+            if vars.iter().any(|v| v.name == var.name) {
+                return Err(syn::Error::new(
+                    var.name.span(),
+                    format!("variable `{}` is declared twice", var.name),
+                ));
+            }
+            vars.push(var);
+        }
+
+        Ok(QuoteInput { src, output, vars })
+    }
+}
+
+impl Parse for Var {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name: Ident = input.parse()?;
+
+        let kind = if input.peek(Token![:]) {
+            input.parse::<Token![:]>()?;
+            let ty: Ident = input.parse()?;
+            match &*ty.to_string() {
+                "Ident" => VarKind::Ident,
+                "Expr" => VarKind::Expr,
+                "Pat" => VarKind::Pat,
+                "Stmt" => VarKind::Stmt,
+                _ => {
+                    return Err(syn::Error::new(
+                        ty.span(),
+                        "expected one of `Ident`, `Expr`, `Pat` and `Stmt`",
+                    ))
+                }
+            }
+        } else {
+            VarKind::Ident
+        };
+
+        input.parse::<Token![=]>()?;
+        let value: Expr = input.parse()?;
+
+        // Values can't refer to the locals of other variables.
+        let local = Ident::new(&format!("quote_var_{}", name), Span::mixed_site());
+
+        Ok(Var {
+            name,
+            kind,
+            local,
+            value,
+        })
+    }
+}
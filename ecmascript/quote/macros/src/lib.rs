@@ -0,0 +1,84 @@
+//! Macros for `swc_ecma_quote`. See the documentation of the crate for usage.
+extern crate proc_macro;
+
+use self::{
+    ast::ToCode,
+    ctx::Ctx,
+    input::{Output, QuoteInput},
+};
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use swc_macros_common::print;
+
+mod ast;
+mod ctx;
+mod input;
+mod parse;
+
+/// Parses javascript code and creates an expression which constructs the
+/// node.
+///
+/// `quote!("code" as Output, name = value, name: Type = value)`
+///
+/// where `Output` is one of `Expr`, `Stmt` and `ModuleItem`.
+#[proc_macro]
+pub fn quote(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as QuoteInput);
+    let output = match input.output {
+        Some(output) => output,
+        None => {
+            return syn::Error::new(
+                input.src.span(),
+                "expected `as Expr`, `as Stmt` or `as ModuleItem` after the code",
+            )
+            .to_compile_error()
+            .into()
+        }
+    };
+
+    print("quote", expand(&input, output))
+}
+
+/// Same as `quote!("code" as Expr, ...)`, but returns `Box<Expr>`.
+#[proc_macro]
+pub fn quote_expr(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as QuoteInput);
+    if input.output.is_some() {
+        return syn::Error::new(input.src.span(), "quote_expr! always creates `Box<Expr>`")
+            .to_compile_error()
+            .into();
+    }
+
+    let expr = expand(&input, Output::Expr);
+    print("quote_expr", quote::quote!(::std::boxed::Box::new(#expr)))
+}
+
+fn expand(input: &QuoteInput, output: Output) -> TokenStream2 {
+    let cx = Ctx::new(&input.vars);
+    let code = match output {
+        Output::Expr => parse::expr(&input.src).map(|expr| (*expr).to_code(&cx)),
+        Output::Stmt => parse::stmt(&input.src).map(|stmt| stmt.to_code(&cx)),
+        Output::ModuleItem => parse::module_item(&input.src).map(|item| item.to_code(&cx)),
+    };
+    let code = match code {
+        Ok(code) => code,
+        Err(err) => return err.to_compile_error(),
+    };
+
+    let errors = cx.finish();
+    if !errors.is_empty() {
+        return errors.iter().map(syn::Error::to_compile_error).collect();
+    }
+
+    let vars = input.vars.iter().map(|var| {
+        let local = &var.local;
+        let ty = var.kind.to_type();
+        let value = &var.value;
+        quote::quote!(let #local: #ty = ::std::convert::Into::into(#value);)
+    });
+
+    quote::quote!({
+        #(#vars)*
+        #code
+    })
+}
@@ -0,0 +1,88 @@
+use swc_common::{sync::Lrc, FileName, SourceFile, SourceMap, Spanned};
+use swc_ecma_ast::{Expr, ModuleItem, Stmt};
+use swc_ecma_parser::{
+    error::{Error, SyntaxError},
+    lexer::Lexer,
+    PResult, Parser, StringInput, Syntax,
+};
+use syn::LitStr;
+
+type JsParser<'a> = Parser<Lexer<'a, StringInput<'a>>>;
+
+/// Parses the code in `src` with `op`.
+fn parse<T>(
+    src: &LitStr,
+    op: impl FnOnce(&mut JsParser, &SourceFile) -> PResult<T>,
+) -> syn::Result<T> {
+    let cm: Lrc<SourceMap> = Default::default();
+    let fm = cm.new_source_file(FileName::Anon, src.value());
+    let lexer = Lexer::new(
+        Syntax::default(),
+        Default::default(),
+        StringInput::from(&*fm),
+        None,
+    );
+    let mut parser = Parser::new_from(lexer);
+
+    let result = op(&mut parser, &fm);
+    let errors = parser
+        .take_errors()
+        .into_iter()
+        // `return` is allowed so statements in functions can be created.
+        .filter(|err| !matches!(err.kind(), SyntaxError::ReturnNotAllowed));
+
+    match result {
+        Ok(node) => match errors.into_iter().next() {
+            Some(err) => Err(to_syn_error(src, err)),
+            None => Ok(node),
+        },
+        Err(err) => Err(to_syn_error(src, err)),
+    }
+}
+
+fn to_syn_error(src: &LitStr, err: Error) -> syn::Error {
+    syn::Error::new(
+        src.span(),
+        format!("failed to parse the code: {}", err.kind().msg()),
+    )
+}
+
+pub(crate) fn expr(src: &LitStr) -> syn::Result<Box<Expr>> {
+    let expr = parse(src, |parser, fm| {
+        let expr = parser.parse_expr()?;
+        let end = (expr.span().hi - fm.start_pos).0 as usize;
+        Ok((expr, fm.src[end..].trim().is_empty()))
+    })?;
+
+    match expr {
+        (expr, true) => Ok(expr),
+        (.., false) => Err(syn::Error::new(
+            src.span(),
+            "expected an expression, but found additional code",
+        )),
+    }
+}
+
+pub(crate) fn stmt(src: &LitStr) -> syn::Result<Stmt> {
+    let mut script = parse(src, |parser, _| parser.parse_script())?;
+    if script.body.len() != 1 {
+        return Err(syn::Error::new(
+            src.span(),
+            "expected exactly one statement",
+        ));
+    }
+
+    Ok(script.body.remove(0))
+}
+
+pub(crate) fn module_item(src: &LitStr) -> syn::Result<ModuleItem> {
+    let mut module = parse(src, |parser, _| parser.parse_module())?;
+    if module.body.len() != 1 {
+        return Err(syn::Error::new(
+            src.span(),
+            "expected exactly one statement or module declaration",
+        ));
+    }
+
+    Ok(module.body.remove(0))
+}
@@ -0,0 +1,59 @@
+//! Quasi-quotation for ecmascript ast.
+//!
+//! The macros in this crate parse javascript code at compile time, and expand
+//! to rust code which creates the ast nodes.
+//!
+//! ```
+//! use swc_common::DUMMY_SP;
+//! use swc_ecma_ast::*;
+//! use swc_ecma_quote::{quote, quote_expr};
+//!
+//! let exports = quote_expr!("module.exports");
+//!
+//! let name = Ident::new("foo".into(), DUMMY_SP);
+//! let stmt = quote!("const $name = $init;" as Stmt, name = name, init: Expr = exports);
+//! ```
+//!
+//! # Output
+//!
+//! `quote!` requires the type of the node after the code, which should be one
+//! of `Expr`, `Stmt` and `ModuleItem`. `quote_expr!` creates `Box<Expr>`.
+//!
+//! The code is parsed as javascript (not typescript), and the code for `Stmt`
+//! is parsed as a script while the code for `ModuleItem` is parsed as a module.
+//! All nodes are created with `DUMMY_SP`.
+//!
+//! As the generated code refers to this crate, `swc_ecma_quote` should be a
+//! direct dependency of crates using the macros.
+//!
+//! # Variables
+//!
+//! A variable is declared as `name = value` or `name: Type = value` after the
+//! code, and `$name` in the code is replaced with the value. The type
+//! determines where the variable can be used.
+//!
+//! | Type | Value | Usage |
+//! | --- | --- | --- |
+//! | `Ident` (default) | `Ident` | Any identifier |
+//! | `Expr` | `Expr`, `Box<Expr>` | Expressions, assignment targets |
+//! | `Pat` | `Pat` or types converted to `Pat` | Patterns |
+//! | `Stmt` | `Stmt` or types converted to `Stmt` | Statements |
+//!
+//! The value is evaluated only once, and cloned if the variable is used
+//! multiple times. It's an error to declare a variable which is not used in
+//! the code.
+//!
+//! Parentheses are not added around the values, so `fixer` should be applied
+//! to the nodes before emitting them.
+
+pub use swc_ecma_quote_macros::{quote, quote_expr};
+
+/// Not a public api.
+#[doc(hidden)]
+pub use swc_atoms;
+/// Not a public api.
+#[doc(hidden)]
+pub use swc_common;
+/// Not a public api.
+#[doc(hidden)]
+pub use swc_ecma_ast;
@@ -0,0 +1,154 @@
+use swc_common::{sync::Lrc, SourceMap, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_codegen::{text_writer::JsWriter, Emitter};
+use swc_ecma_quote::{quote, quote_expr};
+
+fn print(body: Vec<ModuleItem>) -> String {
+    let cm: Lrc<SourceMap> = Default::default();
+    let mut buf = vec![];
+    {
+        let mut emitter = Emitter {
+            cfg: Default::default(),
+            cm: cm.clone(),
+            comments: None,
+            wr: Box::new(JsWriter::new(cm, "\n", &mut buf, None)),
+        };
+        emitter
+            .emit_module(&Module {
+                span: DUMMY_SP,
+                body,
+                shebang: None,
+            })
+            .unwrap();
+    }
+    String::from_utf8(buf).unwrap()
+}
+
+fn print_stmt(stmt: Stmt) -> String {
+    print(vec![ModuleItem::Stmt(stmt)])
+}
+
+fn print_expr(expr: Box<Expr>) -> String {
+    print_stmt(Stmt::Expr(ExprStmt {
+        span: DUMMY_SP,
+        expr,
+    }))
+}
+
+fn ident(s: &str) -> Ident {
+    Ident::new(s.into(), DUMMY_SP)
+}
+
+#[test]
+fn expr() {
+    assert_eq!(
+        print_expr(quote_expr!("a.b(1.5, 'c', true, null, 10n, /x/g, `d${e}`)")),
+        "a.b(1.5, \"c\", true, null, 10n, /x/g, `d${e}`);\n"
+    );
+    assert_eq!(
+        print_expr(Box::new(quote!("(a, b) => ({ ...a, [b]: c?.d })" as Expr))),
+        "(a, b)=>({\n        ...a,\n        [b]: c?.d\n    })\n;\n"
+    );
+}
+
+#[test]
+fn stmt() {
+    assert_eq!(
+        print_stmt(quote!("for (const a of b) { if (a) break; }" as Stmt)),
+        "for (const a of b){\n    if (a) break;\n}\n"
+    );
+    assert_eq!(print_stmt(quote!("return a;" as Stmt)), "return a;\n");
+}
+
+#[test]
+fn module_item() {
+    assert_eq!(
+        print(vec![
+            quote!("import { a as b } from 'c';" as ModuleItem),
+            quote!("export const a = 1;" as ModuleItem),
+        ]),
+        "import { a as b } from \"c\";\nexport const a = 1;\n"
+    );
+}
+
+#[test]
+fn ident_var() {
+    let name = ident("foo");
+    assert_eq!(
+        print_stmt(quote!(
+            "function $name($param) { return $name.$param; }" as Stmt,
+            name = name,
+            param = ident("bar"),
+        )),
+        "function foo(bar) {\n    return foo.bar;\n}\n"
+    );
+}
+
+#[test]
+fn expr_var() {
+    let value = quote_expr!("a.b");
+    assert_eq!(
+        print_expr(quote_expr!(
+            "$target = $value * $value",
+            target: Expr = quote_expr!("c.d"),
+            value: Expr = value,
+        )),
+        "c.d = a.b * a.b;\n"
+    );
+}
+
+#[test]
+fn pat_var() {
+    let pat = Pat::Object(ObjectPat {
+        span: DUMMY_SP,
+        props: vec![ObjectPatProp::Assign(AssignPatProp {
+            span: DUMMY_SP,
+            key: ident("a"),
+            value: None,
+        })],
+        optional: false,
+        type_ann: None,
+    });
+    assert_eq!(
+        print_stmt(quote!("let $pat = b;" as Stmt, pat: Pat = pat)),
+        "let { a  } = b;\n"
+    );
+}
+
+#[test]
+fn stmt_var() {
+    let body = quote!("a();" as Stmt);
+    assert_eq!(
+        print_stmt(quote!("while (b) $body;" as Stmt, body: Stmt = body)),
+        "while(b)a();\n"
+    );
+}
+
+#[test]
+fn same_as_manual() {
+    // Equivalent to the code creating `module.exports = ...` by hand, which
+    // was used in spack.
+    let expr = quote_expr!("{ a: 1 }");
+    let manual = ModuleItem::Stmt(Stmt::Expr(ExprStmt {
+        span: DUMMY_SP,
+        expr: Box::new(Expr::Assign(AssignExpr {
+            span: DUMMY_SP,
+            op: op!("="),
+            left: PatOrExpr::Expr(Box::new(Expr::Member(MemberExpr {
+                span: DUMMY_SP,
+                obj: ExprOrSuper::Expr(Box::new(Expr::Ident(ident("module")))),
+                prop: Box::new(Expr::Ident(ident("exports"))),
+                computed: false,
+            }))),
+            right: expr.clone(),
+        })),
+    }));
+
+    assert_eq!(
+        print(vec![quote!(
+            "module.exports = $expr;" as ModuleItem,
+            expr: Expr = expr
+        )]),
+        print(vec![manual])
+    );
+}
@@ -3,8 +3,14 @@ pub use swc_ecma_ast as ast;
 pub use swc_ecma_codegen as codegen;
 #[cfg(feature = "dep_graph")]
 pub use swc_ecma_dep_graph as dep_graph;
+#[cfg(feature = "fmt")]
+pub use swc_ecma_fmt as fmt;
+#[cfg(feature = "lints")]
+pub use swc_ecma_lints as lints;
 #[cfg(feature = "parser")]
 pub use swc_ecma_parser as parser;
+#[cfg(feature = "query")]
+pub use swc_ecma_query as query;
 #[cfg(feature = "transforms")]
 pub use swc_ecma_transforms as transforms;
 #[cfg(feature = "utils")]
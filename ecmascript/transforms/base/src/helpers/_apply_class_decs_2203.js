@@ -0,0 +1,57 @@
+function _applyClassDecs2203(targetClass, classDecs) {
+  var metadataKey = typeof Symbol === "function" ? Symbol.metadata || Symbol.for("Symbol.metadata") : "@@metadata";
+  var metadata;
+  if (Object.prototype.hasOwnProperty.call(targetClass, metadataKey)) {
+    metadata = targetClass[metadataKey];
+  } else {
+    var parentClass = Object.getPrototypeOf(targetClass);
+    metadata = Object.create((parentClass && parentClass[metadataKey]) || null);
+  }
+  var initializers = [];
+  var newClass = targetClass;
+  var name = targetClass.name;
+
+  for (var i = classDecs.length - 1; i >= 0; i--) {
+    var state = { finished: false };
+    var ctx = {
+      kind: "class",
+      name: name,
+      metadata: metadata,
+      addInitializer: function (initializer) {
+        if (this.finished) {
+          throw new Error("attempted to call addInitializer after decoration was finished");
+        }
+        if (typeof initializer !== "function") {
+          throw new TypeError("An initializer must be a function");
+        }
+        initializers.push(initializer);
+      }.bind(state)
+    };
+    var result;
+    try {
+      result = classDecs[i](newClass, ctx);
+    } finally {
+      state.finished = true;
+    }
+
+    if (result !== undefined) {
+      if (typeof result !== "function") {
+        throw new TypeError("A class decorator's return value must be a function");
+      }
+      newClass = result;
+    }
+  }
+
+  Object.defineProperty(newClass, metadataKey, {
+    value: metadata,
+    enumerable: true,
+    configurable: true,
+    writable: true
+  });
+
+  for (var j = 0; j < initializers.length; j++) {
+    initializers[j].call(newClass);
+  }
+
+  return newClass;
+}
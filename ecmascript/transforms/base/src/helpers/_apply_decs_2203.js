@@ -0,0 +1,200 @@
+function _applyDecs2203(targetClass, memberDecs) {
+  var kinds = ["field", "accessor", "method", "getter", "setter"];
+  var metadataKey = typeof Symbol === "function" ? Symbol.metadata || Symbol.for("Symbol.metadata") : "@@metadata";
+  var parentClass = Object.getPrototypeOf(targetClass);
+  var metadata = Object.create((parentClass && parentClass[metadataKey]) || null);
+  var protoInitializers = [];
+  var staticInitializers = [];
+  var ret = [];
+
+  function createAddInitializer(initializers, state) {
+    return function addInitializer(initializer) {
+      if (state.finished) {
+        throw new Error("attempted to call addInitializer after decoration was finished");
+      }
+      if (typeof initializer !== "function") {
+        throw new TypeError("An initializer must be a function");
+      }
+      initializers.push(initializer);
+    };
+  }
+
+  function createAccess(kind, name, isPrivate, get, set) {
+    var access = {};
+    if (kind === 0 || kind === 1 || kind === 2 || kind === 3) {
+      access.get = isPrivate ? get : function (instance) {
+        return instance[name];
+      };
+    }
+    if (kind === 0 || kind === 1 || kind === 4) {
+      access.set = isPrivate ? set : function (instance, value) {
+        instance[name] = value;
+      };
+    }
+    access.has = isPrivate ? function (instance) {
+      try {
+        get(instance);
+        return true;
+      } catch (e) {
+        return false;
+      }
+    } : function (instance) {
+      return name in instance;
+    };
+    return access;
+  }
+
+  function createInitializer(initializers) {
+    return function (instance, value) {
+      for (var i = 0; i < initializers.length; i++) {
+        value = initializers[i].call(instance, value);
+      }
+      return value;
+    };
+  }
+
+  function createPrivateGet(get) {
+    return function (instance) {
+      return get.call(instance);
+    };
+  }
+
+  function createPrivateSet(set) {
+    return function (instance, value) {
+      set.call(instance, value);
+    };
+  }
+
+  function assertCallable(value, hint) {
+    if (typeof value !== "function") {
+      throw new TypeError(hint + " must be a function");
+    }
+  }
+
+  for (var i = 0; i < memberDecs.length; i++) {
+    var decInfo = memberDecs[i];
+    var decs = decInfo[0];
+    var kind = decInfo[1] & 7;
+    var isStatic = (decInfo[1] & 8) !== 0;
+    var isPrivate = (decInfo[1] & 16) !== 0;
+    var name = decInfo[2];
+    var target = isStatic ? targetClass : targetClass.prototype;
+    var extraInitializers = isStatic ? staticInitializers : protoInitializers;
+    var initializers = [];
+    var desc = !isPrivate && kind !== 0 ? Object.getOwnPropertyDescriptor(target, name) : undefined;
+    var value;
+
+    if (kind === 1) {
+      if (isPrivate) {
+        value = {
+          get: (function (get) {
+            return function () {
+              return get(this);
+            };
+          })(decInfo[3]),
+          set: (function (set) {
+            return function (v) {
+              set(this, v);
+            };
+          })(decInfo[4])
+        };
+      } else {
+        value = { get: desc.get, set: desc.set };
+      }
+    } else if (kind === 2) {
+      value = desc.value;
+    } else if (kind === 3) {
+      value = desc.get;
+    } else if (kind === 4) {
+      value = desc.set;
+    }
+
+    for (var j = decs.length - 1; j >= 0; j--) {
+      var state = { finished: false };
+      var ctx = {
+        kind: kinds[kind],
+        name: name,
+        "static": isStatic,
+        "private": isPrivate,
+        access: createAccess(kind, name, isPrivate, decInfo[3], decInfo[4]),
+        addInitializer: createAddInitializer(extraInitializers, state),
+        metadata: metadata
+      };
+      var newValue;
+      try {
+        newValue = decs[j](kind === 0 ? undefined : value, ctx);
+      } finally {
+        state.finished = true;
+      }
+
+      if (newValue === undefined) {
+        continue;
+      }
+      if (kind === 0) {
+        assertCallable(newValue, "A field decorator's return value");
+        initializers.unshift(newValue);
+      } else if (kind === 1) {
+        if (typeof newValue !== "object" || newValue === null) {
+          throw new TypeError("An accessor decorator must return an object or undefined");
+        }
+        // `value` was passed to the decorator, so it should not be modified.
+        value = { get: value.get, set: value.set };
+        if (newValue.get !== undefined) {
+          assertCallable(newValue.get, "accessor.get");
+          value.get = newValue.get;
+        }
+        if (newValue.set !== undefined) {
+          assertCallable(newValue.set, "accessor.set");
+          value.set = newValue.set;
+        }
+        if (newValue.init !== undefined) {
+          assertCallable(newValue.init, "accessor.init");
+          initializers.unshift(newValue.init);
+        }
+      } else {
+        assertCallable(newValue, "A method decorator's return value");
+        value = newValue;
+      }
+    }
+
+    if (kind === 0 || kind === 1) {
+      ret.push(createInitializer(initializers));
+    }
+
+    if (isPrivate) {
+      if (kind === 1) {
+        ret.push(createPrivateGet(value.get), createPrivateSet(value.set));
+      }
+    } else if (kind !== 0) {
+      if (kind === 1 || kind === 3) {
+        desc.get = kind === 1 ? value.get : value;
+      }
+      if (kind === 1 || kind === 4) {
+        desc.set = kind === 1 ? value.set : value;
+      }
+      if (kind === 2) {
+        desc.value = value;
+      }
+      Object.defineProperty(target, name, desc);
+    }
+  }
+
+  Object.defineProperty(targetClass, metadataKey, {
+    value: metadata,
+    enumerable: true,
+    configurable: true,
+    writable: true
+  });
+
+  for (var k = 0; k < staticInitializers.length; k++) {
+    staticInitializers[k].call(targetClass);
+  }
+
+  ret.push(function (instance) {
+    for (var i = 0; i < protoInitializers.length; i++) {
+      protoInitializers[i].call(instance);
+    }
+  });
+
+  return ret;
+}
@@ -0,0 +1,9 @@
+function _tsMetadata(target, key, metadata) {
+  if (typeof Reflect === "undefined" || typeof Reflect.metadata !== "function") {
+    return;
+  }
+
+  for (var i = 0; i < metadata.length; i++) {
+    Reflect.metadata(metadata[i][0], metadata[i][1])(target, key);
+  }
+}
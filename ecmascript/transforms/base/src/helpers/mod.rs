@@ -163,7 +163,9 @@ macro_rules! define_helpers {
 }
 
 define_helpers!(Helpers {
+    apply_class_decs_2203: (),
     apply_decorated_descriptor: (),
+    apply_decs_2203: (),
     array_with_holes: (),
     array_without_holes: (),
     assert_this_initialized: (),
@@ -231,6 +233,7 @@ define_helpers!(Helpers {
     to_consumable_array: (array_without_holes, iterable_to_array, non_iterable_spread),
     to_primitive: (type_of),
     to_property_key: (type_of, to_primitive),
+    ts_metadata: (),
     type_of: (),
     wrap_async_generator: (async_generator),
     wrap_native_super: (
@@ -1577,7 +1577,7 @@ to_ts!(
     }
     "#,
     r#"
-    class PartWriter {
+    class PartWriter implements Deno.Writer {
         constructor(private writer__2: Deno.Writer, readonly boundary__2: string, public headers__2: Headers, isFirstBoundary__2: boolean){
             let buf__2 = "";
             if (isFirstBoundary__2) {
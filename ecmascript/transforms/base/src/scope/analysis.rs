@@ -0,0 +1,773 @@
+use super::ScopeKind;
+use fxhash::{FxHashMap, FxHashSet};
+use std::ops::{Deref, DerefMut};
+use swc_common::{Span, Spanned, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_utils::{find_ids, ident::IdentLike, Id};
+use swc_ecma_visit::{noop_visit_type, Node, Visit, VisitWith};
+
+/// Collects scopes, bindings and references of a program.
+///
+/// # When to run
+///
+/// The program should be processed by [resolver](crate::resolver::resolver)
+/// (or [ts_resolver](crate::resolver::ts_resolver)) first, because bindings
+/// are identified by their [Id], which includes the
+/// [SyntaxContext](swc_common::SyntaxContext) assigned by the resolver.
+///
+/// # Example
+///
+/// ```ignore
+/// let program = program.fold_with(&mut resolver());
+/// let analysis = analyze(&program);
+///
+/// for binding in analysis.bindings() {
+///     if !binding.exported && analysis.references_to(&binding.id).next().is_none() {
+///         // `binding` is unused.
+///     }
+/// }
+/// ```
+pub fn analyze(program: &Program) -> ScopeAnalysis {
+    let mut v = Analyzer {
+        data: Default::default(),
+        ctx: Default::default(),
+        scope: ScopeId(0),
+        exported: Default::default(),
+    };
+    v.data.scopes.push(Scope {
+        kind: ScopeKind::Fn,
+        parent: None,
+        span: program.span(),
+    });
+
+    program.visit_with(&Invalid { span: DUMMY_SP }, &mut v);
+
+    v.finish()
+}
+
+/// Index of a [Scope] in [ScopeAnalysis].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ScopeId(u32);
+
+impl ScopeId {
+    /// The scope of the program itself.
+    pub const ROOT: ScopeId = ScopeId(0);
+
+    #[inline]
+    fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Scope {
+    /// [ScopeKind::Fn] for the program, functions, arrow functions, methods,
+    /// constructors and named class expressions. [ScopeKind::Block] for
+    /// everything else.
+    pub kind: ScopeKind,
+    /// `None` only for [ScopeId::ROOT].
+    pub parent: Option<ScopeId>,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BindingKind {
+    Var,
+    Let,
+    Const,
+    Function,
+    Class,
+    Param,
+    CatchParam,
+    Import,
+}
+
+impl From<VarDeclKind> for BindingKind {
+    fn from(kind: VarDeclKind) -> Self {
+        match kind {
+            VarDeclKind::Var => BindingKind::Var,
+            VarDeclKind::Let => BindingKind::Let,
+            VarDeclKind::Const => BindingKind::Const,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Binding {
+    pub id: Id,
+    pub kind: BindingKind,
+    /// Span of the first declaration.
+    pub span: Span,
+    /// The scope which owns the binding.
+    ///
+    /// `var`s are stored in the nearest function scope.
+    pub scope: ScopeId,
+    /// `true` if the binding is assigned after the declaration, including
+    /// redeclarations of a `var` with an initializer.
+    pub reassigned: bool,
+    /// `true` if the binding is exported from the module.
+    pub exported: bool,
+    /// `true` if the binding is referenced from a function other than the one
+    /// declaring it.
+    pub captured: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReferenceKind {
+    /// `a`
+    Read,
+    /// `a = 1`, `[a] = arr`, `for (a of arr)`
+    Write,
+    /// `a += 1`, `a++`
+    ReadWrite,
+}
+
+impl ReferenceKind {
+    pub fn is_read(self) -> bool {
+        match self {
+            ReferenceKind::Read | ReferenceKind::ReadWrite => true,
+            ReferenceKind::Write => false,
+        }
+    }
+
+    pub fn is_write(self) -> bool {
+        match self {
+            ReferenceKind::Write | ReferenceKind::ReadWrite => true,
+            ReferenceKind::Read => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Reference {
+    pub id: Id,
+    pub kind: ReferenceKind,
+    pub span: Span,
+    /// The innermost scope containing the reference.
+    pub scope: ScopeId,
+}
+
+/// Result of [analyze].
+#[derive(Debug, Default)]
+pub struct ScopeAnalysis {
+    scopes: Vec<Scope>,
+    bindings: Vec<Binding>,
+    binding_indices: FxHashMap<Id, usize>,
+    references: Vec<Reference>,
+    reference_indices: FxHashMap<Id, Vec<usize>>,
+}
+
+impl ScopeAnalysis {
+    /// All scopes, in the order of their appearance. A scope comes after its
+    /// parent.
+    pub fn scopes(&self) -> &[Scope] {
+        &self.scopes
+    }
+
+    pub fn scope(&self, id: ScopeId) -> &Scope {
+        &self.scopes[id.index()]
+    }
+
+    /// Iterates over `id` and its ancestors.
+    pub fn ancestors(&self, id: ScopeId) -> impl '_ + Iterator<Item = ScopeId> {
+        let mut cur = Some(id);
+        std::iter::from_fn(move || {
+            let id = cur?;
+            cur = self.scope(id).parent;
+            Some(id)
+        })
+    }
+
+    /// The nearest scope of kind [ScopeKind::Fn] containing `id`, including
+    /// `id` itself.
+    pub fn fn_scope(&self, id: ScopeId) -> ScopeId {
+        self.ancestors(id)
+            .find(|&id| self.scope(id).kind == ScopeKind::Fn)
+            .unwrap_or(ScopeId::ROOT)
+    }
+
+    /// All bindings, in the order of their declaration.
+    pub fn bindings(&self) -> &[Binding] {
+        &self.bindings
+    }
+
+    pub fn binding(&self, id: &Id) -> Option<&Binding> {
+        self.binding_indices.get(id).map(|&idx| &self.bindings[idx])
+    }
+
+    /// Bindings owned by `scope`.
+    pub fn bindings_in(&self, scope: ScopeId) -> impl '_ + Iterator<Item = &Binding> {
+        self.bindings.iter().filter(move |b| b.scope == scope)
+    }
+
+    /// All references, in the order of their appearance.
+    pub fn references(&self) -> &[Reference] {
+        &self.references
+    }
+
+    pub fn references_to<'a>(&'a self, id: &Id) -> impl 'a + Iterator<Item = &'a Reference> {
+        self.reference_indices
+            .get(id)
+            .into_iter()
+            .flatten()
+            .map(move |&idx| &self.references[idx])
+    }
+
+    /// References which do not resolve to a binding of the program, like
+    /// references to globals.
+    pub fn unresolved_references(&self) -> impl '_ + Iterator<Item = &Reference> {
+        self.references
+            .iter()
+            .filter(move |r| !self.binding_indices.contains_key(&r.id))
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+struct Ctx {
+    /// Set while visiting a pattern which declares bindings.
+    binding_kind: Option<BindingKind>,
+    /// Set while visiting a pattern of a declaration with an initializer.
+    has_init: bool,
+    /// Set while visiting the target of an assignment or an update expression.
+    write_kind: Option<ReferenceKind>,
+}
+
+struct Analyzer {
+    data: ScopeAnalysis,
+    ctx: Ctx,
+    scope: ScopeId,
+    exported: FxHashSet<Id>,
+}
+
+impl Analyzer {
+    fn with_ctx(&mut self, ctx: Ctx) -> WithCtx {
+        let orig_ctx = self.ctx;
+        self.ctx = ctx;
+        WithCtx {
+            analyzer: self,
+            orig_ctx,
+        }
+    }
+
+    /// Visits expressions nested in a pattern, like default values and
+    /// computed keys.
+    fn with_expr_ctx(&mut self) -> WithCtx {
+        self.with_ctx(Ctx::default())
+    }
+
+    fn with_child<F>(&mut self, kind: ScopeKind, span: Span, op: F)
+    where
+        F: FnOnce(&mut Analyzer),
+    {
+        let child = ScopeId(self.data.scopes.len() as u32);
+        self.data.scopes.push(Scope {
+            kind,
+            parent: Some(self.scope),
+            span,
+        });
+
+        let orig_scope = self.scope;
+        let orig_ctx = self.ctx;
+        self.scope = child;
+        self.ctx = Ctx::default();
+
+        op(self);
+
+        self.scope = orig_scope;
+        self.ctx = orig_ctx;
+    }
+
+    fn declare(&mut self, i: &Ident, kind: BindingKind, has_init: bool) {
+        let id = i.to_id();
+        if let Some(&idx) = self.data.binding_indices.get(&id) {
+            if kind == BindingKind::Var && has_init {
+                self.data.bindings[idx].reassigned = true;
+            }
+            return;
+        }
+
+        let scope = match kind {
+            BindingKind::Var => self.data.fn_scope(self.scope),
+            _ => self.scope,
+        };
+
+        self.data
+            .binding_indices
+            .insert(id.clone(), self.data.bindings.len());
+        self.data.bindings.push(Binding {
+            id,
+            kind,
+            span: i.span,
+            scope,
+            reassigned: false,
+            exported: false,
+            captured: false,
+        });
+    }
+
+    fn reference(&mut self, i: &Ident, kind: ReferenceKind) {
+        let id = i.to_id();
+        self.data
+            .reference_indices
+            .entry(id.clone())
+            .or_default()
+            .push(self.data.references.len());
+        self.data.references.push(Reference {
+            id,
+            kind,
+            span: i.span,
+            scope: self.scope,
+        });
+    }
+
+    /// Handles an identifier in a pattern, which is either a binding or the
+    /// target of an assignment.
+    fn pat_ident(&mut self, i: &Ident) {
+        match self.ctx.binding_kind {
+            Some(kind) => self.declare(i, kind, self.ctx.has_init),
+            None => self.reference(i, self.ctx.write_kind.unwrap_or(ReferenceKind::Write)),
+        }
+    }
+
+    /// Visits a function body without creating a block scope for it.
+    fn visit_fn_body(&mut self, body: &Option<BlockStmt>) {
+        match body {
+            Some(body) => body.visit_children_with(self),
+            None => {}
+        }
+    }
+
+    fn declare_params<N>(&mut self, params: &N)
+    where
+        N: VisitWith<Self>,
+    {
+        let ctx = Ctx {
+            binding_kind: Some(BindingKind::Param),
+            ..Default::default()
+        };
+        params.visit_with(&Invalid { span: DUMMY_SP }, &mut *self.with_ctx(ctx));
+    }
+
+    fn finish(mut self) -> ScopeAnalysis {
+        for id in self.exported.drain() {
+            if let Some(&idx) = self.data.binding_indices.get(&id) {
+                self.data.bindings[idx].exported = true;
+            }
+        }
+
+        for r in 0..self.data.references.len() {
+            let r = &self.data.references[r];
+            let idx = match self.data.binding_indices.get(&r.id) {
+                Some(&idx) => idx,
+                None => continue,
+            };
+
+            let is_write = r.kind.is_write();
+            let captured =
+                self.data.fn_scope(r.scope) != self.data.fn_scope(self.data.bindings[idx].scope);
+
+            let binding = &mut self.data.bindings[idx];
+            binding.reassigned |= is_write;
+            binding.captured |= captured;
+        }
+
+        self.data
+    }
+}
+
+impl Visit for Analyzer {
+    noop_visit_type!();
+
+    fn visit_arrow_expr(&mut self, n: &ArrowExpr, _: &dyn Node) {
+        self.with_child(ScopeKind::Fn, n.span, |child| {
+            child.declare_params(&n.params);
+
+            match &n.body {
+                BlockStmtOrExpr::BlockStmt(body) => body.visit_children_with(child),
+                BlockStmtOrExpr::Expr(body) => body.visit_with(n, child),
+            }
+        })
+    }
+
+    fn visit_assign_expr(&mut self, n: &AssignExpr, _: &dyn Node) {
+        let ctx = Ctx {
+            write_kind: Some(if n.op == AssignOp::Assign {
+                ReferenceKind::Write
+            } else {
+                ReferenceKind::ReadWrite
+            }),
+            ..Default::default()
+        };
+        n.left.visit_with(n, &mut *self.with_ctx(ctx));
+
+        n.right.visit_with(n, &mut *self.with_expr_ctx());
+    }
+
+    fn visit_assign_pat(&mut self, n: &AssignPat, _: &dyn Node) {
+        n.left.visit_with(n, self);
+
+        n.right.visit_with(n, &mut *self.with_expr_ctx());
+    }
+
+    fn visit_assign_pat_prop(&mut self, n: &AssignPatProp, _: &dyn Node) {
+        self.pat_ident(&n.key);
+
+        n.value.visit_with(n, &mut *self.with_expr_ctx());
+    }
+
+    fn visit_binding_ident(&mut self, n: &BindingIdent, _: &dyn Node) {
+        self.pat_ident(&n.id);
+    }
+
+    fn visit_block_stmt(&mut self, n: &BlockStmt, _: &dyn Node) {
+        self.with_child(ScopeKind::Block, n.span, |child| {
+            n.visit_children_with(child)
+        })
+    }
+
+    fn visit_catch_clause(&mut self, n: &CatchClause, _: &dyn Node) {
+        self.with_child(ScopeKind::Block, n.span, |child| {
+            {
+                let ctx = Ctx {
+                    binding_kind: Some(BindingKind::CatchParam),
+                    ..Default::default()
+                };
+                n.param.visit_with(n, &mut *child.with_ctx(ctx));
+            }
+
+            n.body.visit_children_with(child);
+        })
+    }
+
+    fn visit_class_decl(&mut self, n: &ClassDecl, _: &dyn Node) {
+        self.declare(&n.ident, BindingKind::Class, true);
+
+        n.class.visit_with(n, self);
+    }
+
+    fn visit_class_expr(&mut self, n: &ClassExpr, _: &dyn Node) {
+        match &n.ident {
+            // The name of a class expression is only accessible within the class.
+            Some(ident) => self.with_child(ScopeKind::Fn, n.class.span, |child| {
+                child.declare(ident, BindingKind::Class, true);
+
+                n.class.visit_with(n, child);
+            }),
+            None => n.class.visit_with(n, self),
+        }
+    }
+
+    fn visit_class_prop(&mut self, n: &ClassProp, _: &dyn Node) {
+        n.decorators.visit_with(n, self);
+
+        if n.computed {
+            n.key.visit_with(n, self);
+        }
+
+        n.value.visit_with(n, self);
+    }
+
+    fn visit_constructor(&mut self, n: &Constructor, _: &dyn Node) {
+        n.key.visit_with(n, self);
+
+        self.with_child(ScopeKind::Fn, n.span, |child| {
+            child.declare_params(&n.params);
+
+            child.visit_fn_body(&n.body);
+        })
+    }
+
+    fn visit_export_decl(&mut self, n: &ExportDecl, _: &dyn Node) {
+        n.decl.visit_with(n, self);
+
+        match &n.decl {
+            Decl::Class(ClassDecl { ident, .. }) | Decl::Fn(FnDecl { ident, .. }) => {
+                self.exported.insert(ident.to_id());
+            }
+            Decl::Var(v) => {
+                let ids: Vec<Id> = find_ids(&v.decls);
+                self.exported.extend(ids);
+            }
+            _ => {}
+        }
+    }
+
+    fn visit_export_default_decl(&mut self, n: &ExportDefaultDecl, _: &dyn Node) {
+        // Named default exports are declarations even though they are parsed as
+        // expressions.
+        match &n.decl {
+            DefaultDecl::Class(ClassExpr {
+                ident: Some(ident),
+                class,
+            }) => {
+                self.declare(ident, BindingKind::Class, true);
+                self.exported.insert(ident.to_id());
+
+                class.visit_with(n, self);
+            }
+            DefaultDecl::Fn(FnExpr {
+                ident: Some(ident),
+                function,
+            }) => {
+                self.declare(ident, BindingKind::Function, true);
+                self.exported.insert(ident.to_id());
+
+                function.visit_with(n, self);
+            }
+            _ => n.visit_children_with(self),
+        }
+    }
+
+    fn visit_export_default_expr(&mut self, n: &ExportDefaultExpr, _: &dyn Node) {
+        n.visit_children_with(self);
+
+        match &*n.expr {
+            Expr::Ident(i) => {
+                self.exported.insert(i.to_id());
+            }
+            _ => {}
+        }
+    }
+
+    fn visit_export_named_specifier(&mut self, n: &ExportNamedSpecifier, _: &dyn Node) {
+        self.reference(&n.orig, ReferenceKind::Read);
+        self.exported.insert(n.orig.to_id());
+    }
+
+    fn visit_expr(&mut self, n: &Expr, _: &dyn Node) {
+        match n {
+            Expr::Ident(i) => {
+                self.reference(i, self.ctx.write_kind.unwrap_or(ReferenceKind::Read));
+            }
+            _ => n.visit_children_with(self),
+        }
+    }
+
+    fn visit_fn_decl(&mut self, n: &FnDecl, _: &dyn Node) {
+        self.declare(&n.ident, BindingKind::Function, true);
+
+        n.function.visit_with(n, self);
+    }
+
+    fn visit_fn_expr(&mut self, n: &FnExpr, _: &dyn Node) {
+        match &n.ident {
+            // The name of a function expression is only accessible within the function.
+            Some(ident) => {
+                n.function.decorators.visit_with(n, self);
+
+                self.with_child(ScopeKind::Fn, n.function.span, |child| {
+                    child.declare(ident, BindingKind::Function, true);
+
+                    child.declare_params(&n.function.params);
+                    child.visit_fn_body(&n.function.body);
+                })
+            }
+            None => n.function.visit_with(n, self),
+        }
+    }
+
+    fn visit_for_in_stmt(&mut self, n: &ForInStmt, _: &dyn Node) {
+        self.with_child(ScopeKind::Block, n.span, |child| {
+            n.left.visit_with(n, child);
+            n.right.visit_with(n, child);
+            n.body.visit_with(n, child);
+        })
+    }
+
+    fn visit_for_of_stmt(&mut self, n: &ForOfStmt, _: &dyn Node) {
+        self.with_child(ScopeKind::Block, n.span, |child| {
+            n.left.visit_with(n, child);
+            n.right.visit_with(n, child);
+            n.body.visit_with(n, child);
+        })
+    }
+
+    fn visit_for_stmt(&mut self, n: &ForStmt, _: &dyn Node) {
+        self.with_child(ScopeKind::Block, n.span, |child| {
+            n.init.visit_with(n, child);
+            n.test.visit_with(n, child);
+            n.update.visit_with(n, child);
+            n.body.visit_with(n, child);
+        })
+    }
+
+    fn visit_function(&mut self, n: &Function, _: &dyn Node) {
+        n.decorators.visit_with(n, self);
+
+        self.with_child(ScopeKind::Fn, n.span, |child| {
+            child.declare_params(&n.params);
+
+            child.visit_fn_body(&n.body);
+        })
+    }
+
+    fn visit_getter_prop(&mut self, n: &GetterProp, _: &dyn Node) {
+        n.key.visit_with(n, self);
+
+        self.with_child(ScopeKind::Fn, n.span, |child| child.visit_fn_body(&n.body))
+    }
+
+    fn visit_import_default_specifier(&mut self, n: &ImportDefaultSpecifier, _: &dyn Node) {
+        self.declare(&n.local, BindingKind::Import, true);
+    }
+
+    fn visit_import_named_specifier(&mut self, n: &ImportNamedSpecifier, _: &dyn Node) {
+        self.declare(&n.local, BindingKind::Import, true);
+    }
+
+    fn visit_import_star_as_specifier(&mut self, n: &ImportStarAsSpecifier, _: &dyn Node) {
+        self.declare(&n.local, BindingKind::Import, true);
+    }
+
+    fn visit_jsx_element_name(&mut self, n: &JSXElementName, _: &dyn Node) {
+        match n {
+            // Lowercase names are intrinsic elements.
+            JSXElementName::Ident(i) => {
+                if !i.sym.starts_with(|c: char| c.is_ascii_lowercase()) {
+                    self.reference(i, ReferenceKind::Read);
+                }
+            }
+            _ => n.visit_children_with(self),
+        }
+    }
+
+    fn visit_jsx_member_expr(&mut self, n: &JSXMemberExpr, _: &dyn Node) {
+        n.obj.visit_with(n, self);
+    }
+
+    fn visit_jsx_object(&mut self, n: &JSXObject, _: &dyn Node) {
+        match n {
+            JSXObject::Ident(i) => self.reference(i, ReferenceKind::Read),
+            JSXObject::JSXMemberExpr(e) => e.visit_with(n, self),
+        }
+    }
+
+    fn visit_key_value_pat_prop(&mut self, n: &KeyValuePatProp, _: &dyn Node) {
+        n.key.visit_with(n, &mut *self.with_expr_ctx());
+
+        n.value.visit_with(n, self);
+    }
+
+    fn visit_member_expr(&mut self, n: &MemberExpr, _: &dyn Node) {
+        let mut v = self.with_expr_ctx();
+
+        n.obj.visit_with(n, &mut *v);
+
+        if n.computed {
+            n.prop.visit_with(n, &mut *v);
+        }
+    }
+
+    fn visit_named_export(&mut self, n: &NamedExport, _: &dyn Node) {
+        // Re-exports do not reference local bindings.
+        if n.src.is_some() {
+            return;
+        }
+
+        n.visit_children_with(self);
+    }
+
+    fn visit_param(&mut self, n: &Param, _: &dyn Node) {
+        n.decorators.visit_with(n, &mut *self.with_expr_ctx());
+
+        n.pat.visit_with(n, self);
+    }
+
+    fn visit_param_or_ts_param_prop(&mut self, n: &ParamOrTsParamProp, _: &dyn Node) {
+        match n {
+            ParamOrTsParamProp::Param(p) => p.visit_with(n, self),
+            // `visit_ts_param_prop` is a noop because of `noop_visit_type`.
+            ParamOrTsParamProp::TsParamProp(p) => {
+                p.decorators.visit_with(p, &mut *self.with_expr_ctx());
+
+                match &p.param {
+                    TsParamPropParam::Ident(i) => i.visit_with(p, self),
+                    TsParamPropParam::Assign(a) => a.visit_with(p, self),
+                }
+            }
+        }
+    }
+
+    fn visit_prop(&mut self, n: &Prop, _: &dyn Node) {
+        match n {
+            Prop::Shorthand(i) => self.reference(i, ReferenceKind::Read),
+            _ => n.visit_children_with(self),
+        }
+    }
+
+    fn visit_prop_name(&mut self, n: &PropName, _: &dyn Node) {
+        match n {
+            PropName::Computed(n) => n.visit_with(&Invalid { span: DUMMY_SP }, self),
+            _ => {}
+        }
+    }
+
+    fn visit_setter_prop(&mut self, n: &SetterProp, _: &dyn Node) {
+        n.key.visit_with(n, self);
+
+        self.with_child(ScopeKind::Fn, n.span, |child| {
+            child.declare_params(&n.param);
+
+            child.visit_fn_body(&n.body);
+        })
+    }
+
+    fn visit_switch_stmt(&mut self, n: &SwitchStmt, _: &dyn Node) {
+        n.discriminant.visit_with(n, self);
+
+        self.with_child(ScopeKind::Block, n.span, |child| {
+            n.cases.visit_with(n, child)
+        })
+    }
+
+    fn visit_update_expr(&mut self, n: &UpdateExpr, _: &dyn Node) {
+        let ctx = Ctx {
+            write_kind: Some(ReferenceKind::ReadWrite),
+            ..Default::default()
+        };
+        n.arg.visit_with(n, &mut *self.with_ctx(ctx));
+    }
+
+    fn visit_var_decl(&mut self, n: &VarDecl, _: &dyn Node) {
+        let ctx = Ctx {
+            binding_kind: Some(n.kind.into()),
+            ..Default::default()
+        };
+        n.decls.visit_with(n, &mut *self.with_ctx(ctx));
+    }
+
+    fn visit_var_declarator(&mut self, n: &VarDeclarator, _: &dyn Node) {
+        let ctx = Ctx {
+            has_init: n.init.is_some(),
+            ..self.ctx
+        };
+        n.name.visit_with(n, &mut *self.with_ctx(ctx));
+
+        n.init.visit_with(n, &mut *self.with_expr_ctx());
+    }
+}
+
+struct WithCtx<'a> {
+    analyzer: &'a mut Analyzer,
+    orig_ctx: Ctx,
+}
+
+impl Deref for WithCtx<'_> {
+    type Target = Analyzer;
+
+    fn deref(&self) -> &Self::Target {
+        &self.analyzer
+    }
+}
+
+impl DerefMut for WithCtx<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.analyzer
+    }
+}
+
+impl Drop for WithCtx<'_> {
+    fn drop(&mut self) {
+        self.analyzer.ctx = self.orig_ctx;
+    }
+}
@@ -1,3 +1,11 @@
+pub use self::analysis::{
+    analyze, Binding, BindingKind, Reference, ReferenceKind, Scope, ScopeAnalysis, ScopeId,
+};
+
+mod analysis;
+#[cfg(test)]
+mod tests;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ScopeKind {
     Block,
@@ -0,0 +1,206 @@
+use super::*;
+use crate::{resolver::resolver, tests::Tester};
+use swc_ecma_ast::*;
+use swc_ecma_utils::Id;
+use swc_ecma_visit::FoldWith;
+
+fn analyze_src(src: &str) -> ScopeAnalysis {
+    let mut analysis = None;
+
+    Tester::run(|tester| {
+        let module = tester.parse_module("input.js", src)?;
+        let program = Program::Module(module).fold_with(&mut resolver());
+
+        analysis = Some(analyze(&program));
+        Ok(())
+    });
+
+    analysis.unwrap()
+}
+
+fn binding<'a>(analysis: &'a ScopeAnalysis, sym: &str) -> &'a Binding {
+    let mut found = analysis.bindings().iter().filter(|b| &*b.id.0 == sym);
+    let b = found
+        .next()
+        .unwrap_or_else(|| panic!("`{}` is not declared", sym));
+    assert!(
+        found.next().is_none(),
+        "`{}` is declared multiple times",
+        sym
+    );
+    b
+}
+
+fn reference_kinds(analysis: &ScopeAnalysis, id: &Id) -> Vec<ReferenceKind> {
+    analysis.references_to(id).map(|r| r.kind).collect()
+}
+
+#[test]
+fn scopes() {
+    let analysis = analyze_src(
+        "
+function foo(a) {
+    {
+        let b;
+    }
+}
+",
+    );
+
+    let kinds: Vec<_> = analysis.scopes().iter().map(|s| s.kind).collect();
+    assert_eq!(kinds, vec![ScopeKind::Fn, ScopeKind::Fn, ScopeKind::Block]);
+
+    let b = binding(&analysis, "b");
+    assert_eq!(analysis.scope(b.scope).kind, ScopeKind::Block);
+    assert_eq!(
+        analysis.ancestors(b.scope).collect::<Vec<_>>(),
+        vec![b.scope, binding(&analysis, "a").scope, ScopeId::ROOT]
+    );
+    assert_eq!(binding(&analysis, "foo").scope, ScopeId::ROOT);
+}
+
+#[test]
+fn var_is_hoisted_to_fn_scope() {
+    let analysis = analyze_src(
+        "
+function foo() {
+    if (x) {
+        var a = 1;
+        let b = 2;
+    }
+}
+",
+    );
+
+    let a = binding(&analysis, "a");
+    let b = binding(&analysis, "b");
+    assert_eq!(a.kind, BindingKind::Var);
+    assert_eq!(b.kind, BindingKind::Let);
+    assert_eq!(analysis.scope(a.scope).kind, ScopeKind::Fn);
+    assert_eq!(analysis.scope(b.scope).kind, ScopeKind::Block);
+    assert_eq!(analysis.fn_scope(b.scope), a.scope);
+}
+
+#[test]
+fn reference_kinds_are_classified() {
+    let analysis = analyze_src(
+        "
+let a = 1;
+a;
+a = 2;
+a += 3;
+a++;
+[a] = [];
+({ b: a } = {});
+for (a of []);
+",
+    );
+
+    let a = binding(&analysis, "a");
+    assert!(a.reassigned);
+    assert_eq!(
+        reference_kinds(&analysis, &a.id),
+        vec![
+            ReferenceKind::Read,
+            ReferenceKind::Write,
+            ReferenceKind::ReadWrite,
+            ReferenceKind::ReadWrite,
+            ReferenceKind::Write,
+            ReferenceKind::Write,
+            ReferenceKind::Write,
+        ]
+    );
+}
+
+#[test]
+fn member_assignment_reads_object() {
+    let analysis = analyze_src(
+        "
+const a = {};
+a.b = 1;
+a[c] = 2;
+",
+    );
+
+    let a = binding(&analysis, "a");
+    assert!(!a.reassigned);
+    assert_eq!(
+        reference_kinds(&analysis, &a.id),
+        vec![ReferenceKind::Read, ReferenceKind::Read]
+    );
+    assert_eq!(
+        analysis
+            .unresolved_references()
+            .map(|r| &*r.id.0)
+            .collect::<Vec<_>>(),
+        vec!["c"]
+    );
+}
+
+#[test]
+fn captured() {
+    let analysis = analyze_src(
+        "
+let a = 1, b = 2;
+{
+    b;
+}
+const f = () => a;
+",
+    );
+
+    assert!(binding(&analysis, "a").captured);
+    assert!(!binding(&analysis, "b").captured);
+    assert!(!binding(&analysis, "f").captured);
+}
+
+#[test]
+fn exported() {
+    let analysis = analyze_src(
+        "
+import def, { named } from 'mod';
+export const a = 1;
+export function b(c) {}
+const d = 1;
+export { d as e };
+export default def;
+let f;
+",
+    );
+
+    for sym in &["a", "b", "d", "def"] {
+        assert!(
+            binding(&analysis, sym).exported,
+            "`{}` should be exported",
+            sym
+        );
+    }
+    for sym in &["c", "f", "named"] {
+        assert!(
+            !binding(&analysis, sym).exported,
+            "`{}` should not be exported",
+            sym
+        );
+    }
+    assert_eq!(binding(&analysis, "named").kind, BindingKind::Import);
+    assert_eq!(binding(&analysis, "c").kind, BindingKind::Param);
+}
+
+#[test]
+fn non_references() {
+    let analysis = analyze_src(
+        "
+const a = 1;
+const o = { a: 1, [a]: 2, b() {} };
+o.a;
+class C { a() {} }
+label: for (;;) break label;
+",
+    );
+
+    assert_eq!(
+        analysis.references_to(&binding(&analysis, "a").id).count(),
+        1
+    );
+    assert_eq!(analysis.unresolved_references().count(), 0);
+}
@@ -0,0 +1,335 @@
+use swc_atoms::js_word;
+use swc_common::{SourceMap, Span, Spanned, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{noop_visit_type, Node, Visit, VisitWith};
+
+/// A syntax which is not supported by the target.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    pub span: Span,
+    /// Name of the syntax, e.g. `arrow function`.
+    pub feature: &'static str,
+    /// The first version which supports the syntax, e.g. `es2015`.
+    pub since: &'static str,
+}
+
+/// Finds syntax which is not supported by `target`.
+///
+/// This is intended to be used after all transforms are applied, to verify
+/// that the output can be parsed by engines which only support `target`.
+///
+/// Module declarations are not reported, as they are controlled by the module
+/// config.
+///
+/// `cm` is used to find trailing commas, which are preserved by the code
+/// generator. Only the ones of array literals are checked, as the code
+/// generator drops trailing commas of parameters, arguments and object
+/// literals.
+pub fn check_target(cm: &SourceMap, program: &Program, target: EsVersion) -> Vec<Violation> {
+    let mut v = TargetChecker {
+        cm,
+        target,
+        violations: vec![],
+    };
+    program.visit_with(&Invalid { span: DUMMY_SP } as _, &mut v);
+    v.violations
+}
+
+/// Version of syntax which is newer than [EsVersion::latest].
+const ESNEXT: Option<EsVersion> = None;
+
+fn version_name(v: Option<EsVersion>) -> &'static str {
+    match v {
+        Some(EsVersion::Es3) => "es3",
+        Some(EsVersion::Es5) => "es5",
+        Some(EsVersion::Es2015) => "es2015",
+        Some(EsVersion::Es2016) => "es2016",
+        Some(EsVersion::Es2017) => "es2017",
+        Some(EsVersion::Es2018) => "es2018",
+        Some(EsVersion::Es2019) => "es2019",
+        Some(EsVersion::Es2020) => "es2020",
+        None => "esnext",
+    }
+}
+
+struct TargetChecker<'a> {
+    cm: &'a SourceMap,
+    target: EsVersion,
+    violations: Vec<Violation>,
+}
+
+impl TargetChecker<'_> {
+    fn report(&mut self, span: Span, feature: &'static str, since: Option<EsVersion>) {
+        let supported = match since {
+            Some(since) => since <= self.target,
+            None => false,
+        };
+        if supported {
+            return;
+        }
+
+        self.violations.push(Violation {
+            span,
+            feature,
+            since: version_name(since),
+        });
+    }
+
+    /// Property names which are reserved words should be quoted for es3.
+    fn check_prop_ident(&mut self, i: &Ident) {
+        if i.is_reserved_for_es3() {
+            self.report(
+                i.span,
+                "reserved word as a property name",
+                Some(EsVersion::Es5),
+            );
+        }
+    }
+
+    /// Same as the check of the code generator, which emits a trailing comma
+    /// if the original code has one.
+    fn has_trailing_comma(&self, span: Span) -> bool {
+        if span.is_dummy() {
+            return false;
+        }
+
+        match self.cm.span_to_snippet(span) {
+            Ok(snippet) => snippet.len() >= 3 && snippet[..snippet.len() - 1].trim().ends_with(','),
+            Err(..) => false,
+        }
+    }
+}
+
+impl Visit for TargetChecker<'_> {
+    noop_visit_type!();
+
+    fn visit_array_lit(&mut self, n: &ArrayLit, _: &dyn Node) {
+        n.visit_children_with(self);
+
+        // es3 engines count the trailing comma as a hole.
+        if self.has_trailing_comma(n.span) {
+            self.report(n.span, "trailing comma", Some(EsVersion::Es5));
+        }
+    }
+
+    fn visit_arrow_expr(&mut self, n: &ArrowExpr, _: &dyn Node) {
+        n.visit_children_with(self);
+
+        if n.is_async {
+            self.report(n.span, "async arrow function", Some(EsVersion::Es2017));
+        } else {
+            self.report(n.span, "arrow function", Some(EsVersion::Es2015));
+        }
+    }
+
+    fn visit_assign_expr(&mut self, n: &AssignExpr, _: &dyn Node) {
+        n.visit_children_with(self);
+
+        match n.op {
+            op!("**=") => self.report(n.span, "exponentiation operator", Some(EsVersion::Es2016)),
+            op!("&&=") | op!("||=") | op!("??=") => {
+                self.report(n.span, "logical assignment", ESNEXT)
+            }
+            _ => {}
+        }
+    }
+
+    fn visit_big_int(&mut self, n: &BigInt, _: &dyn Node) {
+        self.report(n.span, "bigint literal", Some(EsVersion::Es2020));
+    }
+
+    fn visit_bin_expr(&mut self, n: &BinExpr, _: &dyn Node) {
+        n.visit_children_with(self);
+
+        match n.op {
+            op!("**") => self.report(n.span, "exponentiation operator", Some(EsVersion::Es2016)),
+            op!("??") => self.report(n.span, "nullish coalescing", Some(EsVersion::Es2020)),
+            _ => {}
+        }
+    }
+
+    fn visit_call_expr(&mut self, n: &CallExpr, _: &dyn Node) {
+        n.visit_children_with(self);
+
+        match &n.callee {
+            ExprOrSuper::Expr(callee) => match &**callee {
+                Expr::Ident(Ident {
+                    sym: js_word!("import"),
+                    ..
+                }) => self.report(n.span, "dynamic import", Some(EsVersion::Es2020)),
+                _ => {}
+            },
+            ExprOrSuper::Super(..) => {}
+        }
+    }
+
+    fn visit_catch_clause(&mut self, n: &CatchClause, _: &dyn Node) {
+        n.visit_children_with(self);
+
+        if n.param.is_none() {
+            self.report(n.span, "optional catch binding", Some(EsVersion::Es2019));
+        }
+    }
+
+    fn visit_class(&mut self, n: &Class, _: &dyn Node) {
+        n.visit_children_with(self);
+
+        self.report(n.span, "class", Some(EsVersion::Es2015));
+    }
+
+    fn visit_class_prop(&mut self, n: &ClassProp, _: &dyn Node) {
+        n.visit_children_with(self);
+
+        self.report(n.span, "class field", ESNEXT);
+    }
+
+    fn visit_computed_prop_name(&mut self, n: &ComputedPropName, _: &dyn Node) {
+        n.visit_children_with(self);
+
+        self.report(n.span, "computed property name", Some(EsVersion::Es2015));
+    }
+
+    fn visit_expr_or_spread(&mut self, n: &ExprOrSpread, _: &dyn Node) {
+        n.visit_children_with(self);
+
+        if let Some(span) = n.spread {
+            self.report(span, "spread element", Some(EsVersion::Es2015));
+        }
+    }
+
+    fn visit_export_namespace_specifier(&mut self, n: &ExportNamespaceSpecifier, _: &dyn Node) {
+        n.visit_children_with(self);
+
+        self.report(n.span, "`export * as ns`", Some(EsVersion::Es2020));
+    }
+
+    fn visit_for_of_stmt(&mut self, n: &ForOfStmt, _: &dyn Node) {
+        n.visit_children_with(self);
+
+        if n.await_token.is_some() {
+            self.report(n.span, "for-await-of statement", Some(EsVersion::Es2018));
+        } else {
+            self.report(n.span, "for-of statement", Some(EsVersion::Es2015));
+        }
+    }
+
+    fn visit_function(&mut self, n: &Function, _: &dyn Node) {
+        n.visit_children_with(self);
+
+        match (n.is_async, n.is_generator) {
+            (true, true) => self.report(n.span, "async generator", Some(EsVersion::Es2018)),
+            (true, false) => self.report(n.span, "async function", Some(EsVersion::Es2017)),
+            (false, true) => self.report(n.span, "generator", Some(EsVersion::Es2015)),
+            (false, false) => {}
+        }
+    }
+
+    fn visit_member_expr(&mut self, n: &MemberExpr, _: &dyn Node) {
+        n.obj.visit_with(n as _, self);
+
+        if n.computed {
+            n.prop.visit_with(n as _, self);
+        } else {
+            match &*n.prop {
+                Expr::Ident(i) => self.check_prop_ident(i),
+                Expr::PrivateName(..) => {
+                    n.prop.visit_with(n as _, self);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn visit_meta_prop_expr(&mut self, n: &MetaPropExpr, _: &dyn Node) {
+        match (&*n.meta.sym, &*n.prop.sym) {
+            ("new", "target") => self.report(n.span(), "new.target", Some(EsVersion::Es2015)),
+            ("import", "meta") => self.report(n.span(), "import.meta", Some(EsVersion::Es2020)),
+            _ => {}
+        }
+    }
+
+    fn visit_object_pat_prop(&mut self, n: &ObjectPatProp, _: &dyn Node) {
+        n.visit_children_with(self);
+
+        if let ObjectPatProp::Rest(r) = n {
+            self.report(r.span, "object rest", Some(EsVersion::Es2018));
+        }
+    }
+
+    fn visit_opt_chain_expr(&mut self, n: &OptChainExpr, _: &dyn Node) {
+        n.visit_children_with(self);
+
+        self.report(n.span, "optional chaining", Some(EsVersion::Es2020));
+    }
+
+    fn visit_pat(&mut self, n: &Pat, _: &dyn Node) {
+        n.visit_children_with(self);
+
+        match n {
+            Pat::Array(p) => self.report(p.span, "array destructuring", Some(EsVersion::Es2015)),
+            Pat::Object(p) => self.report(p.span, "object destructuring", Some(EsVersion::Es2015)),
+            Pat::Rest(p) => self.report(p.span, "rest element", Some(EsVersion::Es2015)),
+            Pat::Assign(p) => self.report(p.span, "default value", Some(EsVersion::Es2015)),
+            _ => {}
+        }
+    }
+
+    fn visit_private_name(&mut self, n: &PrivateName, _: &dyn Node) {
+        self.report(n.span, "private name", ESNEXT);
+    }
+
+    fn visit_prop(&mut self, n: &Prop, _: &dyn Node) {
+        n.visit_children_with(self);
+
+        match n {
+            Prop::Shorthand(i) => {
+                self.report(i.span, "shorthand property", Some(EsVersion::Es2015))
+            }
+            Prop::Getter(p) => self.report(p.span, "getter", Some(EsVersion::Es5)),
+            Prop::Setter(p) => self.report(p.span, "setter", Some(EsVersion::Es5)),
+            Prop::Method(p) => self.report(p.function.span, "method", Some(EsVersion::Es2015)),
+            _ => {}
+        }
+    }
+
+    fn visit_prop_name(&mut self, n: &PropName, _: &dyn Node) {
+        n.visit_children_with(self);
+
+        if let PropName::Ident(i) = n {
+            self.check_prop_ident(i);
+        }
+    }
+
+    fn visit_prop_or_spread(&mut self, n: &PropOrSpread, _: &dyn Node) {
+        n.visit_children_with(self);
+
+        if let PropOrSpread::Spread(s) = n {
+            self.report(s.dot3_token, "object spread", Some(EsVersion::Es2018));
+        }
+    }
+
+    fn visit_regex(&mut self, n: &Regex, _: &dyn Node) {
+        if n.flags.contains('u') || n.flags.contains('y') {
+            self.report(n.span, "regexp flag u or y", Some(EsVersion::Es2015));
+        }
+        if n.flags.contains('s') {
+            self.report(n.span, "regexp flag s", Some(EsVersion::Es2018));
+        }
+    }
+
+    fn visit_tpl(&mut self, n: &Tpl, _: &dyn Node) {
+        n.visit_children_with(self);
+
+        self.report(n.span, "template literal", Some(EsVersion::Es2015));
+    }
+
+    fn visit_var_decl(&mut self, n: &VarDecl, _: &dyn Node) {
+        n.visit_children_with(self);
+
+        match n.kind {
+            VarDeclKind::Var => {}
+            VarDeclKind::Let => self.report(n.span, "let declaration", Some(EsVersion::Es2015)),
+            VarDeclKind::Const => self.report(n.span, "const declaration", Some(EsVersion::Es2015)),
+        }
+    }
+}
@@ -409,6 +409,11 @@ where
                 ClassMember::PrivateProp(..) => unreachable!(
                     "classes pass: private property\nclass_properties pass should remove this"
                 ),
+                ClassMember::AutoAccessor(..) => {
+                    unreachable!(
+                        "classes pass: auto accessor\nclass_properties pass should remove this"
+                    )
+                }
                 ClassMember::TsIndexSignature(..) => {
                     // We just strip this.
                 }
@@ -338,10 +338,14 @@ impl ClassProperties {
         let mut statics = HashSet::default();
         let mut private_methods = HashSet::default();
 
-        for member in class.body {
+        let body = self.lower_auto_accessors(class.body, &mut vars);
+
+        for member in body {
             match member {
                 ClassMember::Empty(..) | ClassMember::TsIndexSignature(..) => members.push(member),
 
+                ClassMember::AutoAccessor(..) => unreachable!("auto accessors are lowered above"),
+
                 ClassMember::Method(method) => {
                     // we handle computed key here to preserve the execution order
                     let key = match method.key {
@@ -856,6 +860,198 @@ impl ClassProperties {
             None
         }
     }
+
+    /// Converts auto accessors into private properties, which are lowered
+    /// like other private properties.
+    ///
+    /// ```js
+    /// class Foo {
+    ///     accessor a = 1;
+    /// }
+    /// ```
+    ///
+    /// becomes
+    ///
+    /// ```js
+    /// class Foo {
+    ///     #a_accessor_storage = 1;
+    ///     get a() {
+    ///         return this.#a_accessor_storage;
+    ///     }
+    ///     set a(v) {
+    ///         this.#a_accessor_storage = v;
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// An auto accessor with a private name can't be observed differently
+    /// from a private property, so it becomes a private property.
+    fn lower_auto_accessors(
+        &mut self,
+        members: Vec<ClassMember>,
+        vars: &mut Vec<VarDeclarator>,
+    ) -> Vec<ClassMember> {
+        let mut cnt = 0;
+        let mut buf = Vec::with_capacity(members.len());
+
+        for member in members {
+            let a = match member {
+                ClassMember::AutoAccessor(a) => a,
+                _ => {
+                    buf.push(member);
+                    continue;
+                }
+            };
+
+            let key = match a.key {
+                Key::Private(key) => {
+                    buf.push(ClassMember::PrivateProp(PrivateProp {
+                        span: a.span,
+                        key,
+                        value: a.value,
+                        type_ann: None,
+                        is_static: a.is_static,
+                        decorators: vec![],
+                        computed: false,
+                        accessibility: None,
+                        is_abstract: false,
+                        is_optional: false,
+                        is_override: false,
+                        readonly: false,
+                        definite: false,
+                    }));
+                    continue;
+                }
+                Key::Public(key) => key,
+            };
+
+            let storage = PrivateName {
+                span: DUMMY_SP,
+                id: Ident::new(
+                    match &key {
+                        PropName::Ident(i) => format!("{}_accessor_storage", i.sym).into(),
+                        _ => {
+                            cnt += 1;
+                            format!("_accessor_storage{}", cnt).into()
+                        }
+                    },
+                    DUMMY_SP,
+                ),
+            };
+            let storage_expr = || {
+                Box::new(Expr::Member(MemberExpr {
+                    span: DUMMY_SP,
+                    obj: ThisExpr { span: DUMMY_SP }.as_obj(),
+                    prop: Box::new(Expr::PrivateName(storage.clone())),
+                    computed: false,
+                }))
+            };
+
+            // A computed key is evaluated only once, by the getter.
+            let (getter_key, setter_key) = match key {
+                PropName::Computed(c) => {
+                    let var = private_ident!("_key");
+                    vars.push(VarDeclarator {
+                        span: DUMMY_SP,
+                        name: Pat::Ident(var.clone().into()),
+                        init: None,
+                        definite: false,
+                    });
+                    (
+                        PropName::Computed(ComputedPropName {
+                            span: c.span,
+                            expr: Box::new(Expr::Assign(AssignExpr {
+                                span: DUMMY_SP,
+                                op: op!("="),
+                                left: PatOrExpr::Pat(Box::new(Pat::Ident(var.clone().into()))),
+                                right: c.expr,
+                            })),
+                        }),
+                        PropName::Computed(ComputedPropName {
+                            span: c.span,
+                            expr: Box::new(Expr::Ident(var)),
+                        }),
+                    )
+                }
+                key => (key.clone(), key),
+            };
+
+            let v = private_ident!("v");
+            let getter = Function {
+                params: vec![],
+                decorators: vec![],
+                span: DUMMY_SP,
+                body: Some(BlockStmt {
+                    span: DUMMY_SP,
+                    stmts: vec![Stmt::Return(ReturnStmt {
+                        span: DUMMY_SP,
+                        arg: Some(storage_expr()),
+                    })],
+                }),
+                is_generator: false,
+                is_async: false,
+                type_params: None,
+                return_type: None,
+            };
+            let setter = Function {
+                params: vec![Param {
+                    span: DUMMY_SP,
+                    decorators: vec![],
+                    pat: Pat::Ident(v.clone().into()),
+                }],
+                decorators: vec![],
+                span: DUMMY_SP,
+                body: Some(BlockStmt {
+                    span: DUMMY_SP,
+                    stmts: vec![Expr::Assign(AssignExpr {
+                        span: DUMMY_SP,
+                        op: op!("="),
+                        left: PatOrExpr::Expr(storage_expr()),
+                        right: Box::new(Expr::Ident(v)),
+                    })
+                    .into_stmt()],
+                }),
+                is_generator: false,
+                is_async: false,
+                type_params: None,
+                return_type: None,
+            };
+
+            buf.push(ClassMember::PrivateProp(PrivateProp {
+                span: a.span,
+                key: storage,
+                value: a.value,
+                type_ann: None,
+                is_static: a.is_static,
+                decorators: vec![],
+                computed: false,
+                accessibility: None,
+                is_abstract: false,
+                is_optional: false,
+                is_override: false,
+                readonly: false,
+                definite: false,
+            }));
+            let (span, is_static) = (a.span, a.is_static);
+            let method = |key, function, kind| {
+                ClassMember::Method(ClassMethod {
+                    span,
+                    key,
+                    function,
+                    kind,
+                    is_static,
+                    accessibility: None,
+                    is_abstract: false,
+                    is_optional: false,
+                    is_override: false,
+                })
+            };
+            buf.push(method(getter_key, getter, MethodKind::Getter));
+            buf.push(method(setter_key, setter, MethodKind::Setter));
+        }
+
+        buf
+    }
 }
 
 #[derive(Default)]
@@ -896,6 +1092,10 @@ impl Visit for ShouldWork {
         self.found = true;
     }
 
+    fn visit_auto_accessor(&mut self, _: &AutoAccessor, _: &dyn Node) {
+        self.found = true;
+    }
+
     fn visit_constructor(&mut self, _: &Constructor, _: &dyn Node) {
         self.found = true;
     }
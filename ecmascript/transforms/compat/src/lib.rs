@@ -1,13 +1,14 @@
 //! New-generation javascript to old-javascript compiler.
 
 pub use self::{
-    bugfixes::bugfixes, es2015::es2015, es2016::es2016, es2017::es2017, es2018::es2018,
-    es2020::es2020, es3::es3,
+    bugfixes::bugfixes, check_target::check_target, es2015::es2015, es2016::es2016, es2017::es2017,
+    es2018::es2018, es2020::es2020, es3::es3,
 };
 
 #[macro_use]
 mod macros;
 pub mod bugfixes;
+pub mod check_target;
 pub mod es2015;
 pub mod es2016;
 pub mod es2017;
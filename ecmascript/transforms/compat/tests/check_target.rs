@@ -0,0 +1,120 @@
+use swc_ecma_ast::*;
+use swc_ecma_parser::{EsConfig, Syntax};
+use swc_ecma_transforms_compat::check_target;
+use swc_ecma_transforms_testing::Tester;
+
+fn syntax() -> Syntax {
+    Syntax::Es(EsConfig {
+        dynamic_import: true,
+        import_meta: true,
+        nullish_coalescing: true,
+        optional_chaining: true,
+        ..Default::default()
+    })
+}
+
+/// Returns `(feature, since)` of violations.
+fn check(src: &str, target: EsVersion) -> Vec<(&'static str, &'static str)> {
+    Tester::run_with_module("input.js", syntax(), src, |tester, module| {
+        check_target(&tester.cm, &Program::Module(module), target)
+            .into_iter()
+            .map(|v| (v.feature, v.since))
+            .collect()
+    })
+}
+
+#[test]
+fn es5_output_is_valid_for_es5() {
+    let src = "
+    var obj = { get a() { return 1; }, 'default': 2 };
+    function foo(a, b) {
+        return obj['default'] + a + b;
+    }
+    ";
+
+    assert_eq!(check(src, EsVersion::Es5), vec![]);
+}
+
+#[test]
+fn es3_getter_and_reserved_word() {
+    let src = "
+    var obj = { get a() { return 1; }, default: 2 };
+    obj.class;
+    ";
+
+    assert_eq!(
+        check(src, EsVersion::Es3),
+        vec![
+            ("getter", "es5"),
+            ("reserved word as a property name", "es5"),
+            ("reserved word as a property name", "es5"),
+        ]
+    );
+    assert_eq!(check(src, EsVersion::Es5), vec![]);
+}
+
+#[test]
+fn es2015_syntax() {
+    let src = "
+    let a = 1;
+    const f = (x, ...rest) => `${x}`;
+    class Foo {}
+    for (const v of [...a]) {}
+    ";
+
+    assert_eq!(
+        check(src, EsVersion::Es5),
+        vec![
+            ("let declaration", "es2015"),
+            ("rest element", "es2015"),
+            ("template literal", "es2015"),
+            ("arrow function", "es2015"),
+            ("const declaration", "es2015"),
+            ("class", "es2015"),
+            ("const declaration", "es2015"),
+            ("spread element", "es2015"),
+            ("for-of statement", "es2015"),
+        ]
+    );
+    assert_eq!(check(src, EsVersion::Es2015), vec![]);
+}
+
+#[test]
+fn newer_syntax() {
+    let src = "
+    async function foo() {
+        try {
+            await import('foo');
+        } catch {}
+        return a?.b ?? a ** 2;
+    }
+    ";
+
+    assert_eq!(
+        check(src, EsVersion::Es2016),
+        vec![
+            ("dynamic import", "es2020"),
+            ("optional catch binding", "es2019"),
+            ("optional chaining", "es2020"),
+            ("nullish coalescing", "es2020"),
+            ("async function", "es2017"),
+        ]
+    );
+    assert_eq!(check(src, EsVersion::Es2020), vec![]);
+}
+
+#[test]
+fn es3_trailing_comma() {
+    let src = "
+    var a = [1, 2,];
+    var b = [1, 2];
+    var c = { a: 1, };
+    foo(1, 2,);
+    function bar(x, y,) {}
+    ";
+
+    // The code generator only preserves trailing commas of array literals.
+
+    assert_eq!(check(src, EsVersion::Es3), vec![("trailing comma", "es5")]);
+    assert_eq!(check(src, EsVersion::Es5), vec![]);
+}
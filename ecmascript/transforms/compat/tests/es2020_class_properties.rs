@@ -5479,3 +5479,94 @@ test!(
     new Foo();
     "
 );
+
+test!(
+    ts(),
+    |t| tr(t),
+    auto_accessor,
+    "
+class A {
+    accessor x = 1;
+    static accessor [key()] = 2;
+    accessor #y = 3;
+}
+",
+    r#"
+var _key, tmp = _key = key(), tmp1 = _key;
+var A = function() {
+    "use strict";
+    function A() {
+        _classCallCheck(this, A);
+        _x_accessor_storage.set(this, {
+            writable: true,
+            value: 1
+        });
+        _y.set(this, {
+            writable: true,
+            value: 3
+        });
+    }
+    _createClass(A, [
+        {
+            key: "x",
+            get: function() {
+                return _classPrivateFieldGet(this, _x_accessor_storage);
+            },
+            set: function(v) {
+                _classPrivateFieldSet(this, _x_accessor_storage, v);
+            }
+        }
+    ], [
+        {
+            key: tmp,
+            get: function() {
+                return _classStaticPrivateFieldSpecGet(this, A, __accessor_storage1);
+            },
+            set: function(v) {
+                _classStaticPrivateFieldSpecSet(this, A, __accessor_storage1, v);
+            }
+        }
+    ]);
+    return A;
+}();
+var _x_accessor_storage = new WeakMap();
+var __accessor_storage1 = {
+    writable: true,
+    value: 2
+};
+var _y = new WeakMap();
+"#
+);
+
+test_exec!(
+    ts(),
+    |t| tr(t),
+    auto_accessor_exec,
+    "
+let i = 0;
+const key = () => {
+    i++;
+    return 'y';
+};
+class A {
+    accessor x = 1;
+    static accessor [key()] = 2;
+    accessor #z = 3;
+
+    getZ() {
+        return this.#z;
+    }
+}
+
+const a = new A();
+expect(a.x).toBe(1);
+a.x = 4;
+expect(a.x).toBe(4);
+expect(A.y).toBe(2);
+A.y = 5;
+expect(A.y).toBe(5);
+expect(i).toBe(1);
+expect(a.getZ()).toBe(3);
+expect(Object.keys(a)).toEqual([]);
+"
+);
@@ -1,6 +1,7 @@
 use super::util::{
-    self, define_es_module, define_property, has_use_strict, initialize_to_undefined,
-    local_name_for_src, make_descriptor, use_strict, Exports, ModulePass, Scope,
+    self, define_es_module, define_property, has_top_level_await, has_use_strict,
+    initialize_to_undefined, local_name_for_src, make_descriptor, use_strict, wrap_top_level_await,
+    Exports, ModulePass, Scope, TLA_EXPORT,
 };
 use fxhash::FxHashSet;
 use serde::{Deserialize, Serialize};
@@ -71,6 +72,16 @@ impl Fold for Amd {
         let mut has_export = false;
         let exports_ident = self.exports.0.clone();
 
+        let is_async = self.config.config.top_level_await && has_top_level_await(&items);
+        // Exports of function declarations, hoisted to the start of the async
+        // module body.
+        let mut hoisted_exports = vec![];
+        if is_async {
+            // `export *` should not override the promise of this module.
+            exports.push(TLA_EXPORT.into());
+            has_export = true;
+        }
+
         // Process items
         for item in items {
             let decl = match item {
@@ -154,6 +165,8 @@ impl Fold for Amd {
 
                             let append_to: &mut Vec<_> = if is_class {
                                 &mut extra_stmts
+                            } else if is_async {
+                                &mut hoisted_exports
                             } else {
                                 // Function declaration cannot throw
                                 &mut stmts
@@ -417,6 +430,8 @@ impl Fold for Amd {
 
         // Prepended to statements.
         let mut import_stmts = vec![];
+        // Dependencies awaited before the body of an async module.
+        let mut tla_deps = vec![];
         let mut define_deps_arg = ArrayLit {
             span: DUMMY_SP,
             elems: vec![],
@@ -496,6 +511,9 @@ impl Fold for Amd {
                 )
             });
             let ident = Ident::new(import.0.clone(), import.1);
+            if is_async {
+                tla_deps.push(Expr::Ident(ident.clone()));
+            }
 
             define_deps_arg
                 .elems
@@ -538,7 +556,12 @@ impl Fold for Amd {
         }
 
         prepend_stmts(&mut stmts, import_stmts.into_iter());
-        stmts.append(&mut extra_stmts);
+        if is_async {
+            let body = hoisted_exports.into_iter().chain(extra_stmts).collect();
+            stmts.push(wrap_top_level_await(self.exports.0.clone(), tla_deps, body));
+        } else {
+            stmts.append(&mut extra_stmts);
+        }
 
         // ====================
         //  Emit
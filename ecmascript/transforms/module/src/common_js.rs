@@ -1,7 +1,8 @@
 pub use super::util::Config;
 use super::util::{
-    define_es_module, define_property, has_use_strict, initialize_to_undefined, make_descriptor,
-    make_require_call, use_strict, ModulePass, Scope,
+    define_es_module, define_property, has_top_level_await, has_use_strict,
+    initialize_to_undefined, make_descriptor, make_require_call, use_strict, wrap_top_level_await,
+    ModulePass, Scope, TLA_EXPORT,
 };
 use fxhash::FxHashSet;
 use swc_atoms::js_word;
@@ -49,6 +50,15 @@ impl Fold for CommonJs {
         let mut initialized = FxHashSet::default();
         let mut export_alls = vec![];
 
+        let is_async = self.config.top_level_await && has_top_level_await(&items);
+        // Exports of function declarations, hoisted to the start of the async
+        // module body.
+        let mut hoisted_exports = vec![];
+        if is_async {
+            // `export *` should not override the promise of this module.
+            exports.push(TLA_EXPORT.into());
+        }
+
         for item in items {
             self.in_top_level = true;
 
@@ -146,6 +156,8 @@ impl Fold for CommonJs {
 
                             let append_to: &mut Vec<_> = if is_class {
                                 &mut extra_stmts
+                            } else if is_async {
+                                &mut hoisted_exports
                             } else {
                                 // Function declaration cannot throw
                                 &mut stmts
@@ -258,6 +270,8 @@ impl Fold for CommonJs {
 
                                     let to = if will_inject_var {
                                         &mut extra_stmts
+                                    } else if is_async {
+                                        &mut hoisted_exports
                                     } else {
                                         &mut stmts
                                     };
@@ -458,6 +472,8 @@ impl Fold for CommonJs {
         }
 
         let has_export = !exports.is_empty();
+        // Dependencies awaited before the body of an async module.
+        let mut tla_deps = vec![];
 
         // Used only if export * exists
         let exported_names = {
@@ -528,6 +544,13 @@ impl Fold for CommonJs {
 
             let require = make_require_call(self.root_mark, src.clone());
 
+            if is_async && !lazy {
+                tla_deps.push(match import {
+                    Some(ref import) => Expr::Ident(Ident::new(import.0.clone(), import.1)),
+                    None => make_require_call(self.root_mark, src.clone()),
+                });
+            }
+
             match import {
                 Some(import) => {
                     let ty = self.scope.import_types.get(&src);
@@ -636,7 +659,25 @@ impl Fold for CommonJs {
             }
         }
 
-        stmts.append(&mut extra_stmts);
+        if is_async {
+            let mut body = vec![];
+            let mut decls = vec![];
+            for item in hoisted_exports.into_iter().chain(extra_stmts) {
+                match item {
+                    ModuleItem::Stmt(stmt) => body.push(stmt),
+                    ModuleItem::ModuleDecl(..) => decls.push(item),
+                }
+            }
+
+            stmts.push(ModuleItem::Stmt(wrap_top_level_await(
+                quote_ident!("exports"),
+                tla_deps,
+                body,
+            )));
+            stmts.extend(decls);
+        } else {
+            stmts.append(&mut extra_stmts);
+        }
 
         stmts
     }
@@ -2,6 +2,8 @@
 
 pub use self::amd::amd;
 pub use self::common_js::common_js;
+pub use self::paths::paths;
+pub use self::system_js::system_js;
 pub use self::umd::umd;
 
 #[macro_use]
@@ -9,4 +11,6 @@ pub mod util;
 pub mod amd;
 pub mod common_js;
 pub mod import_analysis;
+pub mod paths;
+pub mod system_js;
 pub mod umd;
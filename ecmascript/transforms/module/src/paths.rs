@@ -0,0 +1,227 @@
+//! Rewrites module specifiers which are aliased by `paths` of `tsconfig.json`.
+
+use std::{
+    collections::HashMap,
+    path::{Component, Path, PathBuf},
+};
+use swc_atoms::js_word;
+use swc_ecma_ast::*;
+use swc_ecma_visit::{as_folder, noop_visit_mut_type, Fold, VisitMut, VisitMutWith};
+
+/// Extensions which are tried to check if a substitution exists.
+const EXTENSIONS: &[&str] = &["ts", "tsx", "d.ts", "js", "jsx", "mjs", "cjs", "json"];
+
+#[derive(Debug, Clone, Default)]
+pub struct Config {
+    /// Directory which substitutions of `paths` are relative to.
+    pub base_url: PathBuf,
+
+    /// Patterns, which contain at most one `*`, and their substitutions.
+    pub paths: HashMap<String, Vec<String>>,
+}
+
+/// Rewrites specifiers of imports, exports, dynamic imports and `require()`
+/// calls in `file` into relative paths, using the matching rules of `paths` of
+/// typescript.
+///
+/// If a pattern has multiple substitutions, the first one which exists on the
+/// file system is used. Specifiers which don't match any pattern are resolved
+/// relative to `base_url`, only if the file exists.
+pub fn paths(file: &Path, config: Config) -> impl Fold {
+    as_folder(Paths {
+        dir: normalize(file.parent().unwrap_or_else(|| Path::new(""))),
+        config,
+    })
+}
+
+struct Paths {
+    /// Directory of the current file.
+    dir: PathBuf,
+    config: Config,
+}
+
+impl Paths {
+    /// Returns substitutions of the best matching pattern, like `tsc`.
+    ///
+    /// A pattern without `*` should match exactly, and a pattern with the
+    /// longest prefix wins among patterns with `*`.
+    fn substitutions(&self, specifier: &str) -> Vec<String> {
+        if let Some(substitutions) = self.config.paths.get(specifier) {
+            if !specifier.contains('*') {
+                return substitutions.clone();
+            }
+        }
+
+        let mut best: Option<(&str, &str, &Vec<String>)> = None;
+        for (pattern, substitutions) in &self.config.paths {
+            let star = match pattern.find('*') {
+                Some(v) => v,
+                None => continue,
+            };
+            let (prefix, suffix) = (&pattern[..star], &pattern[star + 1..]);
+            if specifier.len() < prefix.len() + suffix.len()
+                || !specifier.starts_with(prefix)
+                || !specifier.ends_with(suffix)
+            {
+                continue;
+            }
+
+            // Ties are broken by the pattern itself, to be deterministic.
+            let is_better = match best {
+                Some((best, ..)) => {
+                    let best_prefix = best.find('*').unwrap();
+                    (prefix.len(), best) > (best_prefix, &**pattern)
+                }
+                None => true,
+            };
+            if is_better {
+                let matched = &specifier[prefix.len()..specifier.len() - suffix.len()];
+                best = Some((&**pattern, matched, substitutions));
+            }
+        }
+
+        match best {
+            Some((_, matched, substitutions)) => substitutions
+                .iter()
+                .map(|s| s.replacen('*', matched, 1))
+                .collect(),
+            None => vec![],
+        }
+    }
+
+    fn resolve(&self, specifier: &str) -> Option<String> {
+        if specifier.starts_with('.') || Path::new(specifier).is_absolute() {
+            return None;
+        }
+
+        let candidates = self
+            .substitutions(specifier)
+            .into_iter()
+            .map(|s| normalize(&self.config.base_url.join(s)))
+            .collect::<Vec<_>>();
+
+        let target = match candidates.iter().find(|path| exists(path)) {
+            Some(path) => path,
+            None => match candidates.first() {
+                Some(path) => path,
+                None => {
+                    let path = normalize(&self.config.base_url.join(specifier));
+                    if !exists(&path) {
+                        return None;
+                    }
+                    return Some(relative(&self.dir, &path));
+                }
+            },
+        };
+
+        Some(relative(&self.dir, target))
+    }
+
+    fn rewrite(&self, src: &mut Str) {
+        if let Some(v) = self.resolve(&src.value) {
+            *src = Str {
+                span: src.span,
+                value: v.into(),
+                has_escape: false,
+                kind: Default::default(),
+            };
+        }
+    }
+}
+
+impl VisitMut for Paths {
+    noop_visit_mut_type!();
+
+    fn visit_mut_call_expr(&mut self, e: &mut CallExpr) {
+        e.visit_mut_children_with(self);
+
+        let is_import_or_require = match &e.callee {
+            ExprOrSuper::Expr(callee) => match &**callee {
+                Expr::Ident(Ident {
+                    sym: js_word!("import"),
+                    ..
+                })
+                | Expr::Ident(Ident {
+                    sym: js_word!("require"),
+                    ..
+                }) => true,
+                _ => false,
+            },
+            _ => false,
+        };
+        if !is_import_or_require || e.args.len() != 1 {
+            return;
+        }
+
+        if let ExprOrSpread { spread: None, expr } = &mut e.args[0] {
+            if let Expr::Lit(Lit::Str(src)) = &mut **expr {
+                self.rewrite(src);
+            }
+        }
+    }
+
+    fn visit_mut_export_all(&mut self, e: &mut ExportAll) {
+        self.rewrite(&mut e.src);
+    }
+
+    fn visit_mut_import_decl(&mut self, i: &mut ImportDecl) {
+        self.rewrite(&mut i.src);
+    }
+
+    fn visit_mut_named_export(&mut self, e: &mut NamedExport) {
+        if let Some(src) = &mut e.src {
+            self.rewrite(src);
+        }
+    }
+}
+
+fn exists(path: &Path) -> bool {
+    if path.is_file() {
+        return true;
+    }
+
+    EXTENSIONS.iter().any(|ext| {
+        PathBuf::from(format!("{}.{}", path.display(), ext)).is_file()
+            || path.join(format!("index.{}", ext)).is_file()
+    })
+}
+
+/// Removes `.` and `..` without accessing the file system.
+fn normalize(path: &Path) -> PathBuf {
+    let mut buf = PathBuf::new();
+    for c in path.components() {
+        match c {
+            Component::CurDir => {}
+            Component::ParentDir => match buf.components().last() {
+                Some(Component::Normal(..)) => {
+                    buf.pop();
+                }
+                _ => buf.push(c),
+            },
+            _ => buf.push(c),
+        }
+    }
+    buf
+}
+
+/// Returns a relative specifier, like `./foo` or `../foo`.
+fn relative(from: &Path, to: &Path) -> String {
+    let from = from.components().collect::<Vec<_>>();
+    let to = to.components().collect::<Vec<_>>();
+    let common = from.iter().zip(&to).take_while(|(a, b)| a == b).count();
+
+    let mut parts: Vec<String> = vec![];
+    parts.extend((common..from.len()).map(|_| "..".into()));
+    parts.extend(
+        to[common..]
+            .iter()
+            .map(|c| c.as_os_str().to_string_lossy().into_owned()),
+    );
+    let path = parts.join("/");
+
+    if path == ".." || path.starts_with("../") {
+        path
+    } else {
+        format!("./{}", path)
+    }
+}
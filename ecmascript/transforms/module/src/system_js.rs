@@ -0,0 +1,837 @@
+use super::util::{has_top_level_await, has_use_strict, local_name_for_src, use_strict};
+use fxhash::{FxHashMap, FxHashSet};
+use indexmap::{IndexMap, IndexSet};
+use serde::{Deserialize, Serialize};
+use std::{iter, mem};
+use swc_atoms::{js_word, JsWord};
+use swc_common::{Mark, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_utils::{
+    find_ids, id, member_expr, private_ident, quote_ident, quote_str, ExprFactory, Id,
+};
+use swc_ecma_visit::{noop_fold_type, Fold, FoldWith};
+
+/// Converts es modules to the [SystemJS] format.
+///
+/// ```js
+/// System.register(["foo"], function (_export, _context) {
+///     "use strict";
+///     var foo, bar;
+///     return {
+///         setters: [function (_foo) {
+///             foo = _foo.default;
+///         }],
+///         execute: function () {
+///             _export("bar", bar = foo());
+///         }
+///     };
+/// });
+/// ```
+///
+/// [SystemJS]: https://github.com/systemjs/systemjs/blob/master/docs/system-register.md
+pub fn system_js(config: Config) -> impl Fold {
+    SystemJs {
+        config,
+        export_ident: private_ident!("_export"),
+        context_ident: private_ident!("_context"),
+        exports: Default::default(),
+        vars: Default::default(),
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct Config {
+    #[serde(default)]
+    pub module_id: Option<String>,
+
+    #[serde(default = "default_strict_mode")]
+    pub strict_mode: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            module_id: None,
+            strict_mode: default_strict_mode(),
+        }
+    }
+}
+
+const fn default_strict_mode() -> bool {
+    true
+}
+
+struct SystemJs {
+    config: Config,
+    export_ident: Ident,
+    context_ident: Ident,
+    /// Local bindings to the names they are exported as.
+    exports: FxHashMap<Id, Vec<JsWord>>,
+    /// Variables declared at the top of the declaration function.
+    vars: Vec<Ident>,
+}
+
+/// A dependency, which becomes an element of `setters`.
+struct Dep {
+    /// Parameter of the setter.
+    ident: Ident,
+    /// Assignments to imported bindings.
+    setters: Vec<Stmt>,
+    /// `export { foo as bar } from 'foo'`
+    reexports: Vec<(JsWord, Expr)>,
+    /// `export * from 'foo'`
+    export_all: bool,
+}
+
+fn dep<'a>(deps: &'a mut IndexMap<JsWord, Dep>, src: &Str) -> &'a mut Dep {
+    deps.entry(src.value.clone()).or_insert_with(|| Dep {
+        ident: Ident::new(
+            local_name_for_src(&src.value),
+            DUMMY_SP.apply_mark(Mark::fresh(Mark::root())),
+        ),
+        setters: Default::default(),
+        reexports: Default::default(),
+        export_all: false,
+    })
+}
+
+impl SystemJs {
+    fn export_local(&mut self, local: &Ident, exported: JsWord) {
+        self.exports.entry(id(local)).or_default().push(exported);
+    }
+
+    /// `_export("foo", value)`
+    fn export_call(&self, name: &JsWord, value: Expr) -> Expr {
+        Expr::Call(CallExpr {
+            span: DUMMY_SP,
+            callee: self.export_ident.clone().as_callee(),
+            args: vec![Lit::Str(quote_str!(name.clone())).as_arg(), value.as_arg()],
+            type_args: Default::default(),
+        })
+    }
+
+    /// Wraps `value` with a call to `_export` for each name `local` is exported
+    /// as.
+    fn export_value(&self, local: &Id, value: Expr) -> Expr {
+        match self.exports.get(local) {
+            Some(names) => names
+                .iter()
+                .fold(value, |value, name| self.export_call(name, value)),
+            None => value,
+        }
+    }
+
+    fn is_exported(&self, i: &Ident) -> bool {
+        self.exports.contains_key(&id(i))
+    }
+
+    /// Declares a temporary variable at the top of the declaration function.
+    fn alloc_tmp(&mut self) -> Ident {
+        let tmp = private_ident!("_tmp");
+        self.vars.push(tmp.clone());
+        tmp
+    }
+
+    /// Declarations are hoisted to the declaration function so that they can
+    /// be accessed while dependencies are being set up. Initializers are
+    /// left in `execute`.
+    fn hoist_var(&mut self, var: VarDecl) -> Vec<Stmt> {
+        let mut stmts = vec![];
+
+        for decl in var.decls {
+            let ids: Vec<Ident> = find_ids(&decl.name);
+            self.vars.extend(ids);
+
+            if let Some(init) = decl.init {
+                stmts.push(
+                    AssignExpr {
+                        span: decl.span,
+                        left: PatOrExpr::Pat(Box::new(decl.name)),
+                        op: op!("="),
+                        right: init,
+                    }
+                    .into_stmt(),
+                );
+            }
+        }
+
+        stmts
+    }
+
+    fn hoist_class(&mut self, ident: Ident, class: Class) -> Stmt {
+        self.vars.push(ident.clone());
+
+        AssignExpr {
+            span: DUMMY_SP,
+            left: PatOrExpr::Pat(Box::new(Pat::Ident(ident.clone().into()))),
+            op: op!("="),
+            right: Box::new(Expr::Class(ClassExpr {
+                ident: Some(ident),
+                class,
+            })),
+        }
+        .into_stmt()
+    }
+
+    /// Exports bindings modified by an assignment.
+    fn fold_assign(&mut self, assign: AssignExpr) -> Expr {
+        let simple = match &assign.left {
+            PatOrExpr::Pat(pat) => match &**pat {
+                Pat::Ident(i) => Some(id(&i.id)),
+                Pat::Expr(e) => match &**e {
+                    Expr::Ident(i) => Some(id(i)),
+                    _ => None,
+                },
+                _ => None,
+            },
+            PatOrExpr::Expr(e) => match &**e {
+                Expr::Ident(i) => Some(id(i)),
+                _ => return Expr::Assign(assign),
+            },
+        };
+
+        if let Some(local) = simple {
+            return self.export_value(&local, Expr::Assign(assign));
+        }
+
+        let ids: Vec<Ident> = match &assign.left {
+            PatOrExpr::Pat(pat) => find_ids(pat),
+            PatOrExpr::Expr(..) => unreachable!(),
+        };
+        let exported = ids
+            .into_iter()
+            .filter(|i| self.is_exported(i))
+            .collect::<Vec<_>>();
+        if exported.is_empty() {
+            return Expr::Assign(assign);
+        }
+
+        // ({ foo } = bar)
+        //  -> (_tmp = bar, { foo } = _tmp, _export("foo", foo), _tmp)
+        let tmp = self.alloc_tmp();
+        let mut exprs = vec![
+            Box::new(Expr::Assign(AssignExpr {
+                span: DUMMY_SP,
+                left: PatOrExpr::Pat(Box::new(Pat::Ident(tmp.clone().into()))),
+                op: op!("="),
+                right: assign.right,
+            })),
+            Box::new(Expr::Assign(AssignExpr {
+                right: Box::new(Expr::Ident(tmp.clone())),
+                ..assign
+            })),
+        ];
+        for i in exported {
+            exprs.push(Box::new(self.export_value(&id(&i), Expr::Ident(i))));
+        }
+        exprs.push(Box::new(Expr::Ident(tmp)));
+
+        Expr::Seq(SeqExpr {
+            span: assign.span,
+            exprs,
+        })
+    }
+
+    /// Exports bindings modified by `++` or `--`.
+    fn fold_update(&mut self, update: UpdateExpr) -> Expr {
+        let local = match &*update.arg {
+            Expr::Ident(i) if self.is_exported(i) => i.clone(),
+            _ => return Expr::Update(update),
+        };
+
+        if update.prefix {
+            return self.export_value(&id(&local), Expr::Update(update));
+        }
+
+        // foo++
+        //  -> (_tmp = foo++, _export("foo", foo), _tmp)
+        let tmp = self.alloc_tmp();
+        Expr::Seq(SeqExpr {
+            span: update.span,
+            exprs: vec![
+                Box::new(Expr::Assign(AssignExpr {
+                    span: DUMMY_SP,
+                    left: PatOrExpr::Pat(Box::new(Pat::Ident(tmp.clone().into()))),
+                    op: op!("="),
+                    right: Box::new(Expr::Update(update)),
+                })),
+                Box::new(self.export_value(&id(&local), Expr::Ident(local))),
+                Box::new(Expr::Ident(tmp)),
+            ],
+        })
+    }
+}
+
+impl Fold for SystemJs {
+    noop_fold_type!();
+
+    fn fold_expr(&mut self, expr: Expr) -> Expr {
+        let expr = expr.fold_children_with(self);
+
+        match expr {
+            Expr::Assign(assign) => self.fold_assign(assign),
+            Expr::Update(update) => self.fold_update(update),
+
+            // import.meta
+            //  -> _context.meta
+            Expr::MetaProp(MetaPropExpr {
+                meta:
+                    Ident {
+                        sym: js_word!("import"),
+                        ..
+                    },
+                prop,
+            }) if &*prop.sym == "meta" => {
+                self.context_ident.clone().make_member(quote_ident!("meta"))
+            }
+
+            // import('foo')
+            //  -> _context.import('foo')
+            Expr::Call(CallExpr {
+                span,
+                callee: ExprOrSuper::Expr(callee),
+                args,
+                type_args,
+            }) => match *callee {
+                Expr::Ident(Ident {
+                    sym: js_word!("import"),
+                    ..
+                }) => Expr::Call(CallExpr {
+                    span,
+                    callee: self
+                        .context_ident
+                        .clone()
+                        .make_member(quote_ident!("import"))
+                        .as_callee(),
+                    args,
+                    type_args,
+                }),
+                callee => Expr::Call(CallExpr {
+                    span,
+                    callee: ExprOrSuper::Expr(Box::new(callee)),
+                    args,
+                    type_args,
+                }),
+            },
+
+            _ => expr,
+        }
+    }
+
+    fn fold_module(&mut self, module: Module) -> Module {
+        let mut items = module.body;
+
+        let is_async = has_top_level_await(&items);
+
+        let mut stmts = vec![];
+        if has_use_strict(&items) {
+            items.remove(0);
+            stmts.push(use_strict());
+        } else if self.config.strict_mode {
+            stmts.push(use_strict());
+        }
+
+        // Collect exported bindings before transforming anything, as
+        // assignments to them should be reported via `_export`.
+        for item in &items {
+            match item {
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { decl, .. })) => {
+                    match decl {
+                        Decl::Fn(FnDecl { ident, .. }) | Decl::Class(ClassDecl { ident, .. }) => {
+                            self.export_local(ident, ident.sym.clone())
+                        }
+                        Decl::Var(var) => {
+                            let ids: Vec<Ident> = find_ids(&var.decls);
+                            for ident in ids {
+                                self.export_local(&ident, ident.sym.clone());
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+                    decl:
+                        DefaultDecl::Fn(FnExpr {
+                            ident: Some(ident), ..
+                        }),
+                    ..
+                }))
+                | ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+                    decl:
+                        DefaultDecl::Class(ClassExpr {
+                            ident: Some(ident), ..
+                        }),
+                    ..
+                })) => self.export_local(ident, js_word!("default")),
+
+                ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(NamedExport {
+                    src: None,
+                    specifiers,
+                    ..
+                })) => {
+                    for s in specifiers {
+                        if let ExportSpecifier::Named(ExportNamedSpecifier {
+                            orig, exported, ..
+                        }) = s
+                        {
+                            let exported = exported.as_ref().unwrap_or(orig).sym.clone();
+                            self.export_local(orig, exported);
+                        }
+                    }
+                }
+
+                _ => {}
+            }
+        }
+
+        let mut deps: IndexMap<JsWord, Dep> = Default::default();
+        let mut fn_decls = vec![];
+        let mut execute = vec![];
+
+        for item in items {
+            let decl = match item {
+                ModuleItem::Stmt(Stmt::Decl(Decl::Fn(f))) => {
+                    fn_decls.push(f);
+                    continue;
+                }
+                ModuleItem::Stmt(Stmt::Decl(Decl::Var(var))) => {
+                    execute.extend(self.hoist_var(var));
+                    continue;
+                }
+                ModuleItem::Stmt(Stmt::Decl(Decl::Class(ClassDecl { ident, class, .. }))) => {
+                    execute.push(self.hoist_class(ident, class));
+                    continue;
+                }
+                ModuleItem::Stmt(stmt) => {
+                    execute.push(stmt);
+                    continue;
+                }
+                ModuleItem::ModuleDecl(decl) => decl,
+            };
+
+            match decl {
+                ModuleDecl::Import(import) => {
+                    if import.type_only {
+                        continue;
+                    }
+                    let dep = dep(&mut deps, &import.src);
+
+                    for s in import.specifiers {
+                        let (local, value) = match s {
+                            ImportSpecifier::Named(ImportNamedSpecifier {
+                                local,
+                                imported,
+                                ..
+                            }) => {
+                                let imported = imported.unwrap_or_else(|| local.clone());
+                                let value =
+                                    dep.ident.clone().make_member(quote_ident!(imported.sym));
+                                (local, value)
+                            }
+                            ImportSpecifier::Default(ImportDefaultSpecifier { local, .. }) => {
+                                let value = dep.ident.clone().make_member(quote_ident!("default"));
+                                (local, value)
+                            }
+                            ImportSpecifier::Namespace(ImportStarAsSpecifier { local, .. }) => {
+                                (local, Expr::Ident(dep.ident.clone()))
+                            }
+                        };
+
+                        self.vars.push(local.clone());
+                        dep.setters.push(
+                            AssignExpr {
+                                span: DUMMY_SP,
+                                left: PatOrExpr::Pat(Box::new(Pat::Ident(local.into()))),
+                                op: op!("="),
+                                right: Box::new(value),
+                            }
+                            .into_stmt(),
+                        );
+                    }
+                }
+
+                ModuleDecl::ExportDecl(ExportDecl { decl, .. }) => match decl {
+                    Decl::Fn(f) => fn_decls.push(f),
+                    Decl::Var(var) => execute.extend(self.hoist_var(var)),
+                    Decl::Class(ClassDecl { ident, class, .. }) => {
+                        execute.push(self.hoist_class(ident, class))
+                    }
+                    _ => {}
+                },
+
+                ModuleDecl::ExportDefaultDecl(ExportDefaultDecl { decl, .. }) => match decl {
+                    DefaultDecl::Fn(FnExpr { ident, function }) => {
+                        let ident = ident.unwrap_or_else(|| {
+                            let ident = private_ident!("_default");
+                            self.export_local(&ident, js_word!("default"));
+                            ident
+                        });
+
+                        fn_decls.push(FnDecl {
+                            ident,
+                            function,
+                            declare: false,
+                        });
+                    }
+                    DefaultDecl::Class(ClassExpr { ident, class }) => {
+                        let ident = ident.unwrap_or_else(|| {
+                            let ident = private_ident!("_default");
+                            self.export_local(&ident, js_word!("default"));
+                            ident
+                        });
+
+                        execute.push(self.hoist_class(ident, class));
+                    }
+                    DefaultDecl::TsInterfaceDecl(..) => {}
+                },
+
+                ModuleDecl::ExportDefaultExpr(ExportDefaultExpr { expr, .. }) => {
+                    execute.push(self.export_call(&js_word!("default"), *expr).into_stmt());
+                }
+
+                ModuleDecl::ExportNamed(NamedExport {
+                    src: Some(src),
+                    specifiers,
+                    type_only: false,
+                    ..
+                }) => {
+                    let dep = dep(&mut deps, &src);
+
+                    for s in specifiers {
+                        let (exported, value) = match s {
+                            ExportSpecifier::Named(ExportNamedSpecifier {
+                                orig, exported, ..
+                            }) => {
+                                let exported = exported.as_ref().unwrap_or(&orig).sym.clone();
+                                let value = dep.ident.clone().make_member(quote_ident!(orig.sym));
+                                (exported, value)
+                            }
+                            ExportSpecifier::Default(ExportDefaultSpecifier { exported }) => {
+                                let value = dep.ident.clone().make_member(quote_ident!("default"));
+                                (exported.sym, value)
+                            }
+                            ExportSpecifier::Namespace(ExportNamespaceSpecifier {
+                                name, ..
+                            }) => (name.sym, Expr::Ident(dep.ident.clone())),
+                        };
+
+                        dep.reexports.push((exported, value));
+                    }
+                }
+
+                ModuleDecl::ExportAll(export) => dep(&mut deps, &export.src).export_all = true,
+
+                ModuleDecl::ExportNamed(..)
+                | ModuleDecl::TsImportEquals(..)
+                | ModuleDecl::TsExportAssignment(..)
+                | ModuleDecl::TsNamespaceExport(..) => {}
+            }
+        }
+
+        let fn_decls = fn_decls
+            .into_iter()
+            .map(|f| f.fold_with(self))
+            .collect::<Vec<_>>();
+        let execute = execute.fold_with(self);
+        for dep in deps.values_mut() {
+            dep.setters = mem::take(&mut dep.setters).fold_with(self);
+        }
+
+        // ====================
+        //  Emit
+        // ====================
+
+        if !self.vars.is_empty() {
+            let mut declared = FxHashSet::default();
+            let vars = self
+                .vars
+                .drain(..)
+                .filter(|i| declared.insert(id(i)))
+                .collect::<Vec<_>>();
+
+            stmts.push(Stmt::Decl(Decl::Var(VarDecl {
+                span: DUMMY_SP,
+                kind: VarDeclKind::Var,
+                declare: false,
+                decls: vars
+                    .into_iter()
+                    .map(|i| VarDeclarator {
+                        span: DUMMY_SP,
+                        name: Pat::Ident(i.into()),
+                        init: None,
+                        definite: false,
+                    })
+                    .collect(),
+            })));
+        }
+
+        // Function declarations can be accessed before the module is executed.
+        let mut fn_exports = vec![];
+        for f in &fn_decls {
+            let local = id(&f.ident);
+            if self.exports.contains_key(&local) {
+                fn_exports.push(
+                    self.export_value(&local, Expr::Ident(f.ident.clone()))
+                        .into_stmt(),
+                );
+            }
+        }
+        stmts.extend(fn_decls.into_iter().map(|f| Stmt::Decl(Decl::Fn(f))));
+        stmts.extend(fn_exports);
+
+        // Names which should not be overridden by `export *`.
+        let exported_names = iter::once(js_word!("default"))
+            .chain(iter::once("__esModule".into()))
+            .chain(self.exports.values().flatten().cloned())
+            .chain(
+                deps.values()
+                    .flat_map(|dep| dep.reexports.iter().map(|(name, _)| name.clone())),
+            )
+            .collect::<IndexSet<_>>();
+
+        let mut dep_srcs = Vec::with_capacity(deps.len());
+        let mut setters = Vec::with_capacity(deps.len());
+        for (src, dep) in deps {
+            let mut body = dep.setters;
+
+            if dep.export_all {
+                body.extend(self.export_all(&dep.ident, &exported_names));
+            }
+
+            if !dep.reexports.is_empty() {
+                body.push(
+                    CallExpr {
+                        span: DUMMY_SP,
+                        callee: self.export_ident.clone().as_callee(),
+                        args: vec![ObjectLit {
+                            span: DUMMY_SP,
+                            props: dep
+                                .reexports
+                                .into_iter()
+                                .map(|(name, value)| {
+                                    PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                                        key: PropName::Ident(quote_ident!(name)),
+                                        value: Box::new(value),
+                                    })))
+                                })
+                                .collect(),
+                        }
+                        .as_arg()],
+                        type_args: Default::default(),
+                    }
+                    .into_stmt(),
+                );
+            }
+
+            dep_srcs.push(Some(Lit::Str(quote_str!(src)).as_arg()));
+            setters.push(Some(
+                FnExpr {
+                    ident: None,
+                    function: Function {
+                        span: DUMMY_SP,
+                        decorators: Default::default(),
+                        is_async: false,
+                        is_generator: false,
+                        params: vec![Param {
+                            span: DUMMY_SP,
+                            decorators: Default::default(),
+                            pat: Pat::Ident(dep.ident.into()),
+                        }],
+                        body: Some(BlockStmt {
+                            span: DUMMY_SP,
+                            stmts: body,
+                        }),
+                        type_params: Default::default(),
+                        return_type: Default::default(),
+                    },
+                }
+                .as_arg(),
+            ));
+        }
+
+        stmts.push(Stmt::Return(ReturnStmt {
+            span: DUMMY_SP,
+            arg: Some(Box::new(Expr::Object(ObjectLit {
+                span: DUMMY_SP,
+                props: vec![
+                    PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                        key: PropName::Ident(quote_ident!("setters")),
+                        value: Box::new(Expr::Array(ArrayLit {
+                            span: DUMMY_SP,
+                            elems: setters,
+                        })),
+                    }))),
+                    PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                        key: PropName::Ident(quote_ident!("execute")),
+                        value: Box::new(Expr::Fn(FnExpr {
+                            ident: None,
+                            function: Function {
+                                span: DUMMY_SP,
+                                decorators: Default::default(),
+                                is_async,
+                                is_generator: false,
+                                params: Default::default(),
+                                body: Some(BlockStmt {
+                                    span: DUMMY_SP,
+                                    stmts: execute,
+                                }),
+                                type_params: Default::default(),
+                                return_type: Default::default(),
+                            },
+                        })),
+                    }))),
+                ],
+            }))),
+        }));
+
+        let declare = FnExpr {
+            ident: None,
+            function: Function {
+                span: DUMMY_SP,
+                decorators: Default::default(),
+                is_async: false,
+                is_generator: false,
+                params: vec![
+                    Param {
+                        span: DUMMY_SP,
+                        decorators: Default::default(),
+                        pat: Pat::Ident(self.export_ident.clone().into()),
+                    },
+                    Param {
+                        span: DUMMY_SP,
+                        decorators: Default::default(),
+                        pat: Pat::Ident(self.context_ident.clone().into()),
+                    },
+                ],
+                body: Some(BlockStmt {
+                    span: DUMMY_SP,
+                    stmts,
+                }),
+                type_params: Default::default(),
+                return_type: Default::default(),
+            },
+        };
+
+        Module {
+            body: vec![CallExpr {
+                span: DUMMY_SP,
+                callee: member_expr!(DUMMY_SP, System.register).as_callee(),
+                args: self
+                    .config
+                    .module_id
+                    .clone()
+                    .map(|s| quote_str!(s).as_arg())
+                    .into_iter()
+                    .chain(iter::once(
+                        ArrayLit {
+                            span: DUMMY_SP,
+                            elems: dep_srcs,
+                        }
+                        .as_arg(),
+                    ))
+                    .chain(iter::once(declare.as_arg()))
+                    .collect(),
+                type_args: Default::default(),
+            }
+            .into_stmt()
+            .into()],
+            ..module
+        }
+    }
+}
+
+impl SystemJs {
+    /// Creates
+    ///
+    /// ```js
+    /// var _exportObj = {};
+    /// for (var _key in _foo) {
+    ///     if (_key !== "default" && _key !== "__esModule") _exportObj[_key] = _foo[_key];
+    /// }
+    /// _export(_exportObj);
+    /// ```
+    fn export_all(&self, dep: &Ident, exported_names: &IndexSet<JsWord>) -> Vec<Stmt> {
+        let export_obj = private_ident!("_exportObj");
+        let key = private_ident!("_key");
+
+        let test = exported_names
+            .iter()
+            .map(|name| {
+                Box::new(Expr::Bin(BinExpr {
+                    span: DUMMY_SP,
+                    op: op!("!=="),
+                    left: Box::new(Expr::Ident(key.clone())),
+                    right: Box::new(Expr::Lit(Lit::Str(quote_str!(name.clone())))),
+                }))
+            })
+            .reduce(|left, right| {
+                Box::new(Expr::Bin(BinExpr {
+                    span: DUMMY_SP,
+                    op: op!("&&"),
+                    left,
+                    right,
+                }))
+            })
+            .unwrap();
+
+        vec![
+            Stmt::Decl(Decl::Var(VarDecl {
+                span: DUMMY_SP,
+                kind: VarDeclKind::Var,
+                declare: false,
+                decls: vec![VarDeclarator {
+                    span: DUMMY_SP,
+                    name: Pat::Ident(export_obj.clone().into()),
+                    init: Some(Box::new(Expr::Object(ObjectLit {
+                        span: DUMMY_SP,
+                        props: Default::default(),
+                    }))),
+                    definite: false,
+                }],
+            })),
+            Stmt::ForIn(ForInStmt {
+                span: DUMMY_SP,
+                left: VarDeclOrPat::VarDecl(VarDecl {
+                    span: DUMMY_SP,
+                    kind: VarDeclKind::Var,
+                    declare: false,
+                    decls: vec![VarDeclarator {
+                        span: DUMMY_SP,
+                        name: Pat::Ident(key.clone().into()),
+                        init: None,
+                        definite: false,
+                    }],
+                }),
+                right: Box::new(Expr::Ident(dep.clone())),
+                body: Box::new(Stmt::Block(BlockStmt {
+                    span: DUMMY_SP,
+                    stmts: vec![Stmt::If(IfStmt {
+                        span: DUMMY_SP,
+                        test,
+                        cons: Box::new(
+                            AssignExpr {
+                                span: DUMMY_SP,
+                                left: PatOrExpr::Expr(Box::new(
+                                    export_obj.clone().computed_member(key.clone()),
+                                )),
+                                op: op!("="),
+                                right: Box::new(dep.clone().computed_member(key)),
+                            }
+                            .into_stmt(),
+                        ),
+                        alt: None,
+                    })],
+                })),
+            }),
+            CallExpr {
+                span: DUMMY_SP,
+                callee: self.export_ident.clone().as_callee(),
+                args: vec![export_obj.as_arg()],
+                type_args: Default::default(),
+            }
+            .into_stmt(),
+        ]
+    }
+}
@@ -1,8 +1,9 @@
 use self::config::BuiltConfig;
 pub use self::config::Config;
 use super::util::{
-    self, define_es_module, define_property, has_use_strict, initialize_to_undefined,
-    local_name_for_src, make_descriptor, make_require_call, use_strict, Exports, ModulePass, Scope,
+    self, define_es_module, define_property, has_top_level_await, has_use_strict,
+    initialize_to_undefined, local_name_for_src, make_descriptor, make_require_call, use_strict,
+    wrap_top_level_await, Exports, ModulePass, Scope, TLA_EXPORT,
 };
 use fxhash::FxHashSet;
 use swc_atoms::js_word;
@@ -70,6 +71,16 @@ impl Fold for Umd {
         let mut has_export = false;
         let exports_ident = self.exports.0.clone();
 
+        let is_async = self.config.config.top_level_await && has_top_level_await(&items);
+        // Exports of function declarations, hoisted to the start of the async
+        // module body.
+        let mut hoisted_exports = vec![];
+        if is_async {
+            // `export *` should not override the promise of this module.
+            exports.push(TLA_EXPORT.into());
+            has_export = true;
+        }
+
         // Process items
         for item in items {
             let decl = match item {
@@ -153,6 +164,8 @@ impl Fold for Umd {
 
                             let append_to: &mut Vec<_> = if is_class {
                                 &mut extra_stmts
+                            } else if is_async {
+                                &mut hoisted_exports
                             } else {
                                 // Function declaration cannot throw
                                 &mut stmts
@@ -410,6 +423,8 @@ impl Fold for Umd {
 
         // Prepended to statements.
         let mut import_stmts = vec![];
+        // Dependencies awaited before the body of an async module.
+        let mut tla_deps = vec![];
         let mut define_deps_arg = ArrayLit {
             span: DUMMY_SP,
             elems: vec![],
@@ -494,6 +509,9 @@ impl Fold for Umd {
                 )
             });
             let ident = Ident::new(import.0.clone(), import.1);
+            if is_async {
+                tla_deps.push(Expr::Ident(ident.clone()));
+            }
 
             define_deps_arg
                 .elems
@@ -545,7 +563,12 @@ impl Fold for Umd {
         }
 
         prepend_stmts(&mut stmts, import_stmts.into_iter());
-        stmts.append(&mut extra_stmts);
+        if is_async {
+            let body = hoisted_exports.into_iter().chain(extra_stmts).collect();
+            stmts.push(wrap_top_level_await(self.exports.0.clone(), tla_deps, body));
+        } else {
+            stmts.append(&mut extra_stmts);
+        }
 
         // ====================
         //  Emit
@@ -16,7 +16,7 @@ use swc_ecma_utils::private_ident;
 use swc_ecma_utils::quote_ident;
 use swc_ecma_utils::quote_str;
 use swc_ecma_utils::{undefined, DestructuringFinder, ExprFactory};
-use swc_ecma_visit::{Fold, FoldWith, VisitWith};
+use swc_ecma_visit::{noop_visit_type, Fold, FoldWith, Node, Visit, VisitWith};
 
 pub(super) trait ModulePass: Fold {
     fn config(&self) -> &Config;
@@ -37,6 +37,11 @@ pub struct Config {
     pub lazy: Lazy,
     #[serde(default)]
     pub no_interop: bool,
+    /// If true, a module containing top-level `await` is wrapped in an async
+    /// function and exposes the returned promise as `exports.__tla`.
+    /// Dependencies are awaited through their `__tla` before the body runs.
+    #[serde(default)]
+    pub top_level_await: bool,
 }
 
 impl Default for Config {
@@ -46,6 +51,7 @@ impl Default for Config {
             strict_mode: default_strict_mode(),
             lazy: Lazy::default(),
             no_interop: false,
+            top_level_await: false,
         }
     }
 }
@@ -886,6 +892,131 @@ pub(super) fn make_descriptor(get_expr: Box<Expr>) -> ObjectLit {
     }
 }
 
+/// Name of the property used to expose the evaluation promise of a module
+/// containing top-level await.
+pub(super) const TLA_EXPORT: &str = "__tla";
+
+/// Returns true if `items` contains `await` or `for await` outside of any
+/// function.
+pub(super) fn has_top_level_await(items: &[ModuleItem]) -> bool {
+    let mut v = TopLevelAwaitFinder { found: false };
+    v.visit_module_items(items, &Invalid { span: DUMMY_SP });
+    v.found
+}
+
+struct TopLevelAwaitFinder {
+    found: bool,
+}
+
+impl Visit for TopLevelAwaitFinder {
+    noop_visit_type!();
+
+    fn visit_function(&mut self, _: &Function, _: &dyn Node) {}
+
+    fn visit_arrow_expr(&mut self, _: &ArrowExpr, _: &dyn Node) {}
+
+    fn visit_constructor(&mut self, _: &Constructor, _: &dyn Node) {}
+
+    fn visit_getter_prop(&mut self, n: &GetterProp, _: &dyn Node) {
+        n.key.visit_with(n as _, self);
+    }
+
+    fn visit_setter_prop(&mut self, n: &SetterProp, _: &dyn Node) {
+        n.key.visit_with(n as _, self);
+    }
+
+    /// Initializers of class properties are evaluated lazily.
+    fn visit_class_prop(&mut self, n: &ClassProp, _: &dyn Node) {
+        if n.computed {
+            n.key.visit_with(n as _, self);
+        }
+    }
+
+    fn visit_private_prop(&mut self, _: &PrivateProp, _: &dyn Node) {}
+
+    fn visit_await_expr(&mut self, _: &AwaitExpr, _: &dyn Node) {
+        self.found = true;
+    }
+
+    fn visit_for_of_stmt(&mut self, n: &ForOfStmt, _: &dyn Node) {
+        if n.await_token.is_some() {
+            self.found = true;
+            return;
+        }
+
+        n.visit_children_with(self);
+    }
+}
+
+/// Creates
+///
+/// ```js
+/// exports.__tla = (async function () {
+///     await Promise.all([_foo.__tla, _bar.__tla]);
+///     // body
+/// })();
+/// ```
+///
+/// Dependencies are required synchronously before this statement, so async
+/// siblings are evaluated concurrently and awaited together.
+pub(super) fn wrap_top_level_await(exports: Ident, deps: Vec<Expr>, body: Vec<Stmt>) -> Stmt {
+    let mut stmts = Vec::with_capacity(body.len() + 1);
+
+    if !deps.is_empty() {
+        stmts.push(Stmt::Expr(ExprStmt {
+            span: DUMMY_SP,
+            expr: Box::new(Expr::Await(AwaitExpr {
+                span: DUMMY_SP,
+                arg: Box::new(Expr::Call(CallExpr {
+                    span: DUMMY_SP,
+                    callee: member_expr!(DUMMY_SP, Promise.all).as_callee(),
+                    args: vec![ArrayLit {
+                        span: DUMMY_SP,
+                        elems: deps
+                            .into_iter()
+                            .map(|dep| Some(dep.make_member(quote_ident!(TLA_EXPORT)).as_arg()))
+                            .collect(),
+                    }
+                    .as_arg()],
+                    type_args: Default::default(),
+                })),
+            })),
+        }));
+    }
+    stmts.extend(body);
+
+    let evaluation = CallExpr {
+        span: DUMMY_SP,
+        callee: FnExpr {
+            ident: None,
+            function: Function {
+                span: DUMMY_SP,
+                is_async: true,
+                is_generator: false,
+                decorators: Default::default(),
+                params: vec![],
+                body: Some(BlockStmt {
+                    span: DUMMY_SP,
+                    stmts,
+                }),
+                return_type: Default::default(),
+                type_params: Default::default(),
+            },
+        }
+        .as_callee(),
+        args: vec![],
+        type_args: Default::default(),
+    };
+
+    AssignExpr {
+        span: DUMMY_SP,
+        left: PatOrExpr::Expr(Box::new(exports.make_member(quote_ident!(TLA_EXPORT)))),
+        op: op!("="),
+        right: Box::new(Expr::Call(evaluation)),
+    }
+    .into_stmt()
+}
+
 /// Private `_exports` ident.
 pub(super) struct Exports(pub Ident);
 
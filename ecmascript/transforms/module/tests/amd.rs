@@ -1267,3 +1267,37 @@ _exports.default = _default;
 
 "#
 );
+
+test!(
+    Syntax::Es(EsConfig {
+        top_level_await: true,
+        ..Default::default()
+    }),
+    |_| tr(Config {
+        config: util::Config {
+            top_level_await: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    }),
+    top_level_await_1,
+    "
+    import { foo } from './foo';
+
+    export const value = await foo();
+    ",
+    "
+    define(['exports', './foo'], function(_exports, _foo) {
+        'use strict';
+        Object.defineProperty(_exports, '__esModule', {
+            value: true
+        });
+        _exports.value = void 0;
+        _exports.__tla = (async function() {
+            await Promise.all([_foo.__tla]);
+            const value = await (0, _foo).foo();
+            _exports.value = value;
+        })();
+    });
+    "
+);
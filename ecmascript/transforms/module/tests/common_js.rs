@@ -4588,7 +4588,8 @@ test!(
         no_interop: false,
         strict: true,
         strict_mode: true,
-        lazy: Lazy::Bool(false)
+        lazy: Lazy::Bool(false),
+        top_level_await: false,
     }),
     issue_1480_1,
     "
@@ -4608,7 +4609,8 @@ test!(
         no_interop: false,
         strict: true,
         strict_mode: true,
-        lazy: Lazy::Bool(false)
+        lazy: Lazy::Bool(false),
+        top_level_await: false,
     }),
     issue_1480_2,
     "
@@ -4712,3 +4714,102 @@ test!(
     })();
     "
 );
+
+test!(
+    syntax(),
+    |_| tr(Config {
+        top_level_await: true,
+        ..Default::default()
+    }),
+    top_level_await_1,
+    "
+    import { foo } from './foo';
+    import './side';
+
+    export function get() {
+        return value;
+    }
+
+    export const value = await foo();
+    ",
+    "
+    'use strict';
+    Object.defineProperty(exports, '__esModule', {
+        value: true
+    });
+    exports.value = void 0;
+    var _foo = require('./foo');
+    require('./side');
+    exports.__tla = (async function() {
+        await Promise.all([_foo.__tla, require('./side').__tla]);
+        exports.get = get;
+        function get() {
+            return value;
+        }
+        const value = await (0, _foo).foo();
+        exports.value = value;
+    })();
+    "
+);
+
+test!(
+    syntax(),
+    |_| tr(Config {
+        top_level_await: true,
+        ..Default::default()
+    }),
+    top_level_await_export_all,
+    "
+    export * from './other';
+
+    await 1;
+    ",
+    "
+    'use strict';
+    Object.defineProperty(exports, '__esModule', {
+        value: true
+    });
+    var _exportNames = {
+        __tla: true
+    };
+    var _other = require('./other');
+    exports.__tla = (async function() {
+        await Promise.all([_other.__tla]);
+        await 1;
+        Object.keys(_other).forEach(function(key) {
+            if (key === 'default' || key === '__esModule') return;
+            if (Object.prototype.hasOwnProperty.call(_exportNames, key)) return;
+            Object.defineProperty(exports, key, {
+                enumerable: true,
+                get: function() {
+                    return _other[key];
+                }
+            });
+        });
+    })();
+    "
+);
+
+test!(
+    syntax(),
+    |_| tr(Config {
+        top_level_await: true,
+        ..Default::default()
+    }),
+    top_level_await_nested_only,
+    "
+    export async function load() {
+        await foo();
+    }
+    ",
+    "
+    'use strict';
+    Object.defineProperty(exports, '__esModule', {
+        value: true
+    });
+    exports.load = load;
+    async function load() {
+        await foo();
+    }
+    "
+);
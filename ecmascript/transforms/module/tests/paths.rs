@@ -0,0 +1,103 @@
+use std::path::{Path, PathBuf};
+use swc_ecma_parser::{EsConfig, Syntax};
+use swc_ecma_transforms_module::paths::{paths, Config};
+use swc_ecma_transforms_testing::test;
+use swc_ecma_visit::Fold;
+
+fn syntax() -> Syntax {
+    Syntax::Es(EsConfig {
+        dynamic_import: true,
+        ..Default::default()
+    })
+}
+
+fn tr(file: &str, base_url: PathBuf, patterns: &[(&str, &[&str])]) -> impl Fold {
+    paths(
+        Path::new(file),
+        Config {
+            base_url,
+            paths: patterns
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.iter().map(|v| v.to_string()).collect()))
+                .collect(),
+        },
+    )
+}
+
+test!(
+    syntax(),
+    |_| tr(
+        "/project/src/pages/index.js",
+        "/project".into(),
+        &[("@app/*", &["src/*"]), ("@app/utils/*", &["lib/utils/*"])]
+    ),
+    longest_prefix,
+    r#"
+    import a from "@app/components/button";
+    import b from "@app/utils/format";
+    export * from "@app/pages/about";
+    export { c } from "@app/pages/index";
+    "#,
+    r#"
+    import a from "../components/button";
+    import b from "../../lib/utils/format";
+    export * from "./about";
+    export { c } from "./index";
+    "#
+);
+
+test!(
+    syntax(),
+    |_| tr(
+        "/project/src/index.js",
+        "/project".into(),
+        &[("config", &["config/prod"]), ("*", &["vendor/*"])]
+    ),
+    exact_match,
+    r#"
+    const config = require("config");
+    const lazy = import("lodash");
+    const other = require("./other");
+    "#,
+    r#"
+    const config = require("../config/prod");
+    const lazy = import("../vendor/lodash");
+    const other = require("./other");
+    "#
+);
+
+test!(
+    syntax(),
+    |_| tr(
+        "/project/src/index.js",
+        "/project".into(),
+        &[("@app/*", &["src/*"])]
+    ),
+    not_matched,
+    r#"
+    import react from "react";
+    const x = require(dynamic);
+    "#,
+    r#"
+    import react from "react";
+    const x = require(dynamic);
+    "#
+);
+
+test!(
+    syntax(),
+    |_| tr(
+        concat!(env!("CARGO_MANIFEST_DIR"), "/tests/paths/src/index.js"),
+        concat!(env!("CARGO_MANIFEST_DIR"), "/tests/paths").into(),
+        &[("~/*", &["missing/*", "lib/*"])]
+    ),
+    existing_substitution,
+    r#"
+    import { util } from "~/util";
+    import { other } from "~/other";
+    "#,
+    r#"
+    import { util } from "../lib/util";
+    import { other } from "../missing/other";
+    "#
+);
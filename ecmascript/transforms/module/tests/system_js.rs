@@ -0,0 +1,225 @@
+use swc_common::chain;
+use swc_ecma_parser::{EsConfig, Syntax};
+use swc_ecma_transforms_base::resolver::resolver;
+use swc_ecma_transforms_module::system_js::system_js;
+use swc_ecma_transforms_module::system_js::Config;
+use swc_ecma_transforms_testing::test;
+use swc_ecma_visit::Fold;
+
+fn syntax() -> Syntax {
+    Syntax::Es(EsConfig {
+        dynamic_import: true,
+        import_meta: true,
+        top_level_await: true,
+        ..Default::default()
+    })
+}
+
+fn tr(config: Config) -> impl Fold {
+    chain!(resolver(), system_js(config))
+}
+
+test!(
+    syntax(),
+    |_| tr(Default::default()),
+    imports,
+    "
+    import foo, { bar as baz } from 'foo';
+    import * as ns from 'ns';
+    import 'side-effect';
+
+    foo(baz, ns);
+    ",
+    "
+    System.register(['foo', 'ns', 'side-effect'], function (_export, _context) {
+        'use strict';
+        var foo, baz, ns;
+        return {
+            setters: [function (_foo) {
+                foo = _foo.default;
+                baz = _foo.bar;
+            }, function (_ns) {
+                ns = _ns;
+            }, function (_sideEffect) {}],
+            execute: function () {
+                foo(baz, ns);
+            }
+        };
+    });
+    "
+);
+
+test!(
+    syntax(),
+    |_| tr(Default::default()),
+    exports,
+    "
+    import foo from 'foo';
+
+    export const value = foo();
+    export function fn() {}
+    export default function () {}
+    ",
+    "
+    System.register(['foo'], function (_export, _context) {
+        'use strict';
+        var foo, value;
+        function fn() {}
+        function _default() {}
+        _export('fn', fn);
+        _export('default', _default);
+        return {
+            setters: [function (_foo) {
+                foo = _foo.default;
+            }],
+            execute: function () {
+                _export('value', value = foo());
+            }
+        };
+    });
+    "
+);
+
+test!(
+    syntax(),
+    |_| tr(Default::default()),
+    live_binding,
+    "
+    export let count = 0;
+    export function inc() {
+        count++;
+    }
+    export function reset() {
+        count = 0;
+    }
+    export { count as current };
+    ",
+    "
+    System.register([], function (_export, _context) {
+        'use strict';
+        var count, _tmp;
+        function inc() {
+            _tmp = count++, _export('current', _export('count', count)), _tmp;
+        }
+        function reset() {
+            _export('current', _export('count', count = 0));
+        }
+        _export('inc', inc);
+        _export('reset', reset);
+        return {
+            setters: [],
+            execute: function () {
+                _export('current', _export('count', count = 0));
+            }
+        };
+    });
+    "
+);
+
+test!(
+    syntax(),
+    |_| tr(Default::default()),
+    export_from,
+    "
+    export * from 'foo';
+    export { a as b } from 'bar';
+    export const c = 1;
+    ",
+    "
+    System.register(['foo', 'bar'], function (_export, _context) {
+        'use strict';
+        var c;
+        return {
+            setters: [function (_foo) {
+                var _exportObj = {};
+                for (var _key in _foo) {
+                    if (_key !== 'default' && _key !== '__esModule' && _key !== 'c' &&
+                        _key !== 'b') _exportObj[_key] = _foo[_key];
+                }
+                _export(_exportObj);
+            }, function (_bar) {
+                _export({
+                    b: _bar.a
+                });
+            }],
+            execute: function () {
+                _export('c', c = 1);
+            }
+        };
+    });
+    "
+);
+
+test!(
+    syntax(),
+    |_| tr(Default::default()),
+    import_meta_and_dynamic_import,
+    "
+    export default import.meta.url;
+    export const load = () => import('./lazy');
+    ",
+    "
+    System.register([], function (_export, _context) {
+        'use strict';
+        var load;
+        return {
+            setters: [],
+            execute: function () {
+                _export('default', _context.meta.url);
+                _export('load', load = () => _context.import('./lazy'));
+            }
+        };
+    });
+    "
+);
+
+test!(
+    syntax(),
+    |_| tr(Default::default()),
+    top_level_await,
+    "
+    import { foo } from './foo';
+
+    export const value = await foo();
+    ",
+    "
+    System.register(['./foo'], function (_export, _context) {
+        'use strict';
+        var foo, value;
+        return {
+            setters: [function (_foo) {
+                foo = _foo.foo;
+            }],
+            execute: async function () {
+                _export('value', value = await foo());
+            }
+        };
+    });
+    "
+);
+
+test!(
+    syntax(),
+    |_| tr(Config {
+        module_id: Some("mod".into()),
+        ..Default::default()
+    }),
+    module_id_and_destructuring,
+    "
+    export const { a, b: [c] } = obj;
+    export default class {}
+    ",
+    "
+    System.register('mod', [], function (_export, _context) {
+        'use strict';
+        var a, c, _default, _tmp;
+        return {
+            setters: [],
+            execute: function () {
+                _tmp = obj, ({ a, b: [c] } = _tmp), _export('a', a), _export('c', c), _tmp;
+                _export('default', _default = class _default {});
+            }
+        };
+    });
+    "
+);
@@ -1981,3 +1981,48 @@ _exports.default = _default;
 
 "#
 );
+
+test!(
+    Syntax::Es(EsConfig {
+        top_level_await: true,
+        ..Default::default()
+    }),
+    |tester| tr(
+        tester,
+        Config {
+            config: util::Config {
+                top_level_await: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    ),
+    top_level_await_1,
+    "
+    import { foo } from './foo';
+
+    await foo();
+    ",
+    "
+    (function(global, factory) {
+        if (typeof define === 'function' && define.amd) {
+            define(['exports', './foo'], factory);
+        } else if (typeof exports !== 'undefined') {
+            factory(exports, require('./foo'));
+        } else {
+            var mod = {
+                exports: {
+                }
+            };
+            factory(mod.exports, global.foo);
+            global.input = mod.exports;
+        }
+    })(this, function(_exports, _foo) {
+        'use strict';
+        _exports.__tla = (async function() {
+            await Promise.all([_foo.__tla]);
+            await (0, _foo).foo();
+        })();
+    });
+    "
+);
@@ -484,7 +484,8 @@ test!(
         strip(),
         decorators(decorators::Config {
             legacy: true,
-            emit_metadata: false
+            emit_metadata: false,
+            ..Default::default()
         }),
         dce(Default::default())
     ),
@@ -531,7 +532,8 @@ test!(
         strip(),
         decorators(decorators::Config {
             legacy: true,
-            emit_metadata: false
+            emit_metadata: false,
+            ..Default::default()
         }),
         dce(Default::default())
     ),
@@ -1,5 +1,5 @@
 use either::Either;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::iter;
 use swc_common::{Spanned, DUMMY_SP};
 use swc_ecma_ast::*;
@@ -13,7 +13,9 @@ use swc_ecma_utils::{
 };
 use swc_ecma_visit::{noop_fold_type, Fold, FoldWith, Node, Visit, VisitWith};
 
+mod decorator_2022_03;
 mod legacy;
+mod metadata;
 
 /// ## Simple class decorator
 ///
@@ -55,16 +57,43 @@ mod legacy;
 ///   }
 /// }
 /// ```
+///
+/// ## 2022-03
+///
+/// If [Config::version] is [DecoratorVersion::V202203], decorators receive a
+/// `context` object, which has `addInitializer` and `metadata`, and
+/// auto-accessors (`accessor x = 1;`) are supported.
+///
+/// ```js
+/// class C {
+///   @logged
+///   accessor count = 0;
+/// }
+///
+/// function logged(value, { kind, name, metadata }) {
+///   metadata[name] = kind;
+///   return value;
+/// }
+///
+/// C[Symbol.metadata].count; // "accessor"
+/// ```
+///
+/// If [Config::emit_metadata] is `true`, `design:*` metadata of decorated
+/// elements is defined with `Reflect.metadata` before decorators are applied.
 pub fn decorators(c: Config) -> impl Fold {
     if c.legacy {
         Either::Left(self::legacy::new(c.emit_metadata))
+    } else if c.version == DecoratorVersion::V202203 {
+        Either::Right(Either::Left(self::decorator_2022_03::decorator_2022_03(
+            c.emit_metadata,
+        )))
     } else {
         if c.emit_metadata {
-            unimplemented!("emitting decorator metadata while using new proposal")
+            unimplemented!("emitting decorator metadata while using the 2018-09 proposal")
         }
-        Either::Right(Decorators {
+        Either::Right(Either::Right(Decorators {
             is_in_strict: false,
-        })
+        }))
     }
 }
 
@@ -72,8 +101,29 @@ pub fn decorators(c: Config) -> impl Fold {
 #[serde(rename_all = "camelCase")]
 pub struct Config {
     pub legacy: bool,
+    /// Emits `design:*` metadata of decorated elements.
+    ///
+    /// This is not supported by the 2018-09 proposal, and [decorators] panics
+    /// if it's used with it.
     #[serde(default)]
     pub emit_metadata: bool,
+    /// Version of the proposal, which is used if `legacy` is `false`.
+    #[serde(default)]
+    pub version: DecoratorVersion,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DecoratorVersion {
+    #[serde(rename = "2018-09")]
+    V201809,
+    #[serde(rename = "2022-03")]
+    V202203,
+}
+
+impl Default for DecoratorVersion {
+    fn default() -> Self {
+        DecoratorVersion::V201809
+    }
 }
 
 #[derive(Debug, Default)]
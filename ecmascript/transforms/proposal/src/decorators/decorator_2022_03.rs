@@ -0,0 +1,934 @@
+//! Decorators of the `2022-03` version of the proposal, which passes `context`
+//! objects to decorators and adds auto-accessors.
+//!
+//! Element decorators are applied by a static private field which is the
+//! first element of the class, so they are applied before other static fields
+//! are initialized. Class decorators are applied after the class is defined.
+//!
+//! `design:*` metadata is defined by the same static field, before decorators
+//! are applied.
+
+use super::metadata::{Metadata, TypeInfo};
+use std::mem::{replace, take};
+use swc_common::{Spanned, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_transforms_base::helper;
+use swc_ecma_utils::{
+    constructor::inject_after_super, default_constructor, private_ident, quote_ident, quote_str,
+    undefined, ExprFactory, StmtLike, HANDLER,
+};
+use swc_ecma_visit::{as_folder, noop_visit_mut_type, Fold, VisitMut, VisitMutWith, VisitWith};
+
+/// Values of the second element of an element passed to `_applyDecs2203`.
+const FIELD: u8 = 0;
+const ACCESSOR: u8 = 1;
+const METHOD: u8 = 2;
+const GETTER: u8 = 3;
+const SETTER: u8 = 4;
+const STATIC: u8 = 8;
+const PRIVATE: u8 = 16;
+
+pub(super) fn decorator_2022_03(metadata: bool) -> impl Fold {
+    as_folder(Decorator202203 {
+        metadata,
+        ..Default::default()
+    })
+}
+
+#[derive(Default)]
+struct Decorator202203 {
+    /// Emit `design:*` metadata.
+    metadata: bool,
+    types: TypeInfo,
+    /// Declared before the statement which is being processed.
+    vars: Vec<Ident>,
+    /// Used to name storages of auto-accessors with non-identifier keys.
+    accessor_cnt: usize,
+}
+
+/// Expressions which should wrap a class.
+struct Wrap {
+    /// Evaluated before the class.
+    pre: Vec<Box<Expr>>,
+    decorators: Vec<Box<Expr>>,
+}
+
+impl Wrap {
+    fn apply(self, class: ClassExpr) -> Box<Expr> {
+        let mut expr = Box::new(Expr::Class(class));
+
+        if !self.decorators.is_empty() {
+            expr = Box::new(Expr::Call(CallExpr {
+                span: DUMMY_SP,
+                callee: helper!(apply_class_decs_2203, "applyClassDecs2203"),
+                args: vec![expr.as_arg(), array(self.decorators).as_arg()],
+                type_args: Default::default(),
+            }));
+        }
+
+        if self.pre.is_empty() {
+            return expr;
+        }
+
+        let mut exprs = self.pre;
+        exprs.push(expr);
+        Box::new(Expr::Seq(SeqExpr {
+            span: DUMMY_SP,
+            exprs,
+        }))
+    }
+}
+
+impl Decorator202203 {
+    /// Lowers decorators of members and auto-accessors of `class`, and returns
+    /// expressions which should wrap it.
+    fn handle(&mut self, class: &mut Class, class_name: Option<&Ident>) -> Option<Wrap> {
+        if class.decorators.is_empty() && !class.body.iter().any(needs_transform) {
+            return None;
+        }
+
+        // Calls to `_tsMetadata`.
+        let mut metadata = vec![];
+        let class_metadata = match self.design(class_name) {
+            Some(design) if !class.decorators.is_empty() => design.class_metadata(class),
+            _ => vec![],
+        };
+
+        let mut pre = vec![];
+        let decorators: Vec<Box<Expr>> = take(&mut class.decorators)
+            .into_iter()
+            .map(|dec| self.hoist_decorator(dec, &mut pre))
+            .collect();
+
+        // Arguments of `_applyDecs2203`.
+        let mut dec_infos = vec![];
+        // Values returned by `_applyDecs2203`.
+        let mut rets = vec![];
+        let mut has_instance_decorator = false;
+
+        let mut body = Vec::with_capacity(class.body.len() + 1);
+        for member in take(&mut class.body) {
+            match member {
+                ClassMember::Method(mut m) if !m.function.decorators.is_empty() => {
+                    let design = self
+                        .design(class_name)
+                        .map(|design| design.method_metadata(m.kind, &m.function));
+                    let decs = self.hoist_decorators(take(&mut m.function.decorators), &mut pre);
+                    let name = self.prop_name_key(&mut m.key);
+                    if let Some(design) = design {
+                        metadata.push(ts_metadata(m.is_static, name.clone(), design));
+                    }
+                    let kind = match m.kind {
+                        MethodKind::Method => METHOD,
+                        MethodKind::Getter => GETTER,
+                        MethodKind::Setter => SETTER,
+                    };
+                    has_instance_decorator |= !m.is_static;
+
+                    dec_infos.push(dec_info(decs, kind, m.is_static, name, vec![]));
+                    body.push(ClassMember::Method(m));
+                }
+
+                ClassMember::PrivateMethod(mut m) if !m.function.decorators.is_empty() => {
+                    HANDLER.with(|handler| {
+                        handler
+                            .struct_span_err(
+                                m.span,
+                                "decorators on private methods are not supported yet",
+                            )
+                            .emit();
+                    });
+                    m.function.decorators.clear();
+                    body.push(ClassMember::PrivateMethod(m));
+                }
+
+                ClassMember::ClassProp(mut p) if !p.decorators.is_empty() => {
+                    let decs = self.hoist_decorators(take(&mut p.decorators), &mut pre);
+                    let name = if p.computed {
+                        self.computed_key(&mut p.key)
+                    } else {
+                        match &*p.key {
+                            Expr::Ident(i) => {
+                                Box::new(Expr::Lit(Lit::Str(quote_str!(i.span, i.sym.clone()))))
+                            }
+                            _ => p.key.clone(),
+                        }
+                    };
+                    if let Some(design) = self.design(class_name) {
+                        if let Some(type_ann) = &p.type_ann {
+                            let ty = design.serialize_type(Some(type_ann));
+                            metadata.push(ts_metadata(
+                                p.is_static,
+                                name.clone(),
+                                vec![("design:type", ty)],
+                            ));
+                        }
+                    }
+                    let init = self.declare(&format!("_init_{}", name_hint(&p.key)));
+                    p.value = Some(call_with_this(&init, p.value));
+                    has_instance_decorator |= !p.is_static;
+
+                    rets.push(init);
+                    dec_infos.push(dec_info(decs, FIELD, p.is_static, name, vec![]));
+                    body.push(ClassMember::ClassProp(p));
+                }
+
+                ClassMember::PrivateProp(mut p) if !p.decorators.is_empty() => {
+                    let decs = self.hoist_decorators(take(&mut p.decorators), &mut pre);
+                    let init = self.declare(&format!("_init_{}", p.key.id.sym));
+                    p.value = Some(call_with_this(&init, p.value));
+                    has_instance_decorator |= !p.is_static;
+
+                    rets.push(init);
+                    dec_infos.push(dec_info(
+                        decs,
+                        FIELD | PRIVATE,
+                        p.is_static,
+                        private_name_str(&p.key),
+                        vec![private_get(&p.key), private_set(&p.key)],
+                    ));
+                    body.push(ClassMember::PrivateProp(p));
+                }
+
+                ClassMember::AutoAccessor(a) => {
+                    let decorated = !a.decorators.is_empty();
+                    has_instance_decorator |= decorated && !a.is_static;
+                    let decs = self.hoist_decorators(a.decorators, &mut pre);
+
+                    let hint = match &a.key {
+                        Key::Private(p) => Some(p.id.sym.to_string()),
+                        Key::Public(PropName::Ident(i)) => Some(i.sym.to_string()),
+                        _ => None,
+                    };
+                    let storage = PrivateName {
+                        span: DUMMY_SP,
+                        id: Ident::new(
+                            match &hint {
+                                Some(hint) => format!("{}_accessor_storage", hint).into(),
+                                None => {
+                                    self.accessor_cnt += 1;
+                                    format!("_accessor_storage{}", self.accessor_cnt).into()
+                                }
+                            },
+                            DUMMY_SP,
+                        ),
+                    };
+                    let hint = hint.unwrap_or_else(|| "accessor".into());
+
+                    let mut value = a.value;
+                    // `this.#storage` or calls to functions returned by `_applyDecs2203`.
+                    let (mut get_body, mut set_body) = (
+                        Box::new(Expr::Member(MemberExpr {
+                            span: DUMMY_SP,
+                            obj: ThisExpr { span: DUMMY_SP }.as_obj(),
+                            prop: Box::new(Expr::PrivateName(storage.clone())),
+                            computed: false,
+                        })),
+                        None,
+                    );
+
+                    if decorated {
+                        let init = self.declare(&format!("_init_{}", hint));
+                        value = Some(call_with_this(&init, value));
+                        rets.push(init);
+                    }
+
+                    let (getter, setter) = match a.key {
+                        Key::Public(mut key) => {
+                            let name = self.prop_name_key(&mut key);
+                            let setter_key = match key {
+                                PropName::Computed(..) => PropName::Computed(ComputedPropName {
+                                    span: DUMMY_SP,
+                                    expr: name.clone(),
+                                }),
+                                _ => key.clone(),
+                            };
+                            if decorated {
+                                if let (Some(design), Some(type_ann)) =
+                                    (self.design(class_name), &a.type_ann)
+                                {
+                                    let ty = design.serialize_type(Some(type_ann));
+                                    metadata.push(ts_metadata(
+                                        a.is_static,
+                                        name.clone(),
+                                        vec![("design:type", ty)],
+                                    ));
+                                }
+                                dec_infos.push(dec_info(decs, ACCESSOR, a.is_static, name, vec![]));
+                            }
+
+                            let getter = ClassMember::Method(ClassMethod {
+                                span: a.span,
+                                key,
+                                function: getter_fn(get_body),
+                                kind: MethodKind::Getter,
+                                is_static: a.is_static,
+                                accessibility: a.accessibility,
+                                is_abstract: false,
+                                is_optional: false,
+                                is_override: false,
+                            });
+                            let setter = ClassMember::Method(ClassMethod {
+                                span: a.span,
+                                key: setter_key,
+                                function: setter_fn(&storage, set_body),
+                                kind: MethodKind::Setter,
+                                is_static: a.is_static,
+                                accessibility: a.accessibility,
+                                is_abstract: false,
+                                is_optional: false,
+                                is_override: false,
+                            });
+                            (getter, setter)
+                        }
+
+                        Key::Private(key) => {
+                            if decorated {
+                                let get = self.declare(&format!("_get_{}", hint));
+                                let set = self.declare(&format!("_set_{}", hint));
+                                dec_infos.push(dec_info(
+                                    decs,
+                                    ACCESSOR | PRIVATE,
+                                    a.is_static,
+                                    private_name_str(&key),
+                                    vec![private_get(&storage), private_set(&storage)],
+                                ));
+
+                                get_body = call_with_this(&get, None);
+                                set_body = Some(Box::new(Expr::Call(CallExpr {
+                                    span: DUMMY_SP,
+                                    callee: set.clone().as_callee(),
+                                    args: vec![
+                                        ThisExpr { span: DUMMY_SP }.as_arg(),
+                                        quote_ident!("v").as_arg(),
+                                    ],
+                                    type_args: Default::default(),
+                                })));
+                                rets.push(get);
+                                rets.push(set);
+                            }
+
+                            let getter = ClassMember::PrivateMethod(PrivateMethod {
+                                span: a.span,
+                                key: key.clone(),
+                                function: getter_fn(get_body),
+                                kind: MethodKind::Getter,
+                                is_static: a.is_static,
+                                accessibility: a.accessibility,
+                                is_abstract: false,
+                                is_optional: false,
+                                is_override: false,
+                            });
+                            let setter = ClassMember::PrivateMethod(PrivateMethod {
+                                span: a.span,
+                                key,
+                                function: setter_fn(&storage, set_body),
+                                kind: MethodKind::Setter,
+                                is_static: a.is_static,
+                                accessibility: a.accessibility,
+                                is_abstract: false,
+                                is_optional: false,
+                                is_override: false,
+                            });
+                            (getter, setter)
+                        }
+                    };
+
+                    body.push(ClassMember::PrivateProp(PrivateProp {
+                        span: a.span,
+                        key: storage,
+                        value,
+                        type_ann: a.type_ann,
+                        is_static: a.is_static,
+                        decorators: vec![],
+                        computed: false,
+                        accessibility: None,
+                        is_abstract: false,
+                        is_optional: false,
+                        is_override: false,
+                        readonly: false,
+                        definite: false,
+                    }));
+                    body.push(getter);
+                    body.push(setter);
+                }
+
+                _ => body.push(member),
+            }
+        }
+
+        if !class_metadata.is_empty() {
+            metadata.push(ts_metadata(true, undefined(DUMMY_SP), class_metadata));
+        }
+
+        if !dec_infos.is_empty() || !metadata.is_empty() {
+            if has_instance_decorator {
+                let init_proto = self.declare("_initProto");
+                inject_init_proto(&mut body, &init_proto, class.super_class.is_some());
+                rets.push(init_proto);
+            }
+
+            let mut init = metadata;
+            if !dec_infos.is_empty() {
+                init.push(apply_decs(dec_infos, rets));
+            }
+            let init = if init.len() == 1 {
+                init.pop().unwrap()
+            } else {
+                Box::new(Expr::Seq(SeqExpr {
+                    span: DUMMY_SP,
+                    exprs: init,
+                }))
+            };
+
+            body.insert(
+                0,
+                ClassMember::PrivateProp(PrivateProp {
+                    span: DUMMY_SP,
+                    key: PrivateName {
+                        span: DUMMY_SP,
+                        id: quote_ident!("_initDecorators"),
+                    },
+                    value: Some(init),
+                    type_ann: None,
+                    is_static: true,
+                    decorators: vec![],
+                    computed: false,
+                    accessibility: None,
+                    is_abstract: false,
+                    is_optional: false,
+                    is_override: false,
+                    readonly: false,
+                    definite: false,
+                }),
+            );
+        }
+
+        class.body = body;
+
+        if pre.is_empty() && decorators.is_empty() {
+            return None;
+        }
+        Some(Wrap { pre, decorators })
+    }
+
+    fn design<'a>(&'a self, class_name: Option<&'a Ident>) -> Option<Metadata<'a>> {
+        if !self.metadata {
+            return None;
+        }
+
+        Some(Metadata {
+            types: &self.types,
+            class_name,
+        })
+    }
+
+    fn declare(&mut self, name: &str) -> Ident {
+        let i = private_ident!(name.to_string());
+        self.vars.push(i.clone());
+        i
+    }
+
+    /// Decorators are evaluated before the class, like the spec.
+    fn hoist_decorator(&mut self, dec: Decorator, pre: &mut Vec<Box<Expr>>) -> Box<Expr> {
+        match *dec.expr {
+            Expr::Ident(..) => dec.expr,
+            _ => {
+                let i = self.declare("_dec");
+                pre.push(Box::new(Expr::Assign(AssignExpr {
+                    span: dec.span,
+                    op: op!("="),
+                    left: PatOrExpr::Pat(Box::new(Pat::Ident(i.clone().into()))),
+                    right: dec.expr,
+                })));
+                Box::new(Expr::Ident(i))
+            }
+        }
+    }
+
+    fn hoist_decorators(
+        &mut self,
+        decs: Vec<Decorator>,
+        pre: &mut Vec<Box<Expr>>,
+    ) -> Vec<Box<Expr>> {
+        decs.into_iter()
+            .map(|dec| self.hoist_decorator(dec, pre))
+            .collect()
+    }
+
+    /// Returns an expression for the name of a member, which can be used
+    /// multiple times.
+    fn prop_name_key(&mut self, key: &mut PropName) -> Box<Expr> {
+        match key {
+            PropName::Ident(i) => Box::new(Expr::Lit(Lit::Str(quote_str!(i.span, i.sym.clone())))),
+            PropName::Str(s) => Box::new(Expr::Lit(Lit::Str(s.clone()))),
+            PropName::Num(n) => Box::new(Expr::Lit(Lit::Num(n.clone()))),
+            PropName::BigInt(b) => Box::new(Expr::Lit(Lit::BigInt(b.clone()))),
+            PropName::Computed(c) => self.computed_key(&mut c.expr),
+        }
+    }
+
+    /// Stores the value of a computed key into a variable, as it should be
+    /// evaluated only once.
+    fn computed_key(&mut self, key: &mut Box<Expr>) -> Box<Expr> {
+        match &**key {
+            Expr::Lit(Lit::Str(..)) | Expr::Lit(Lit::Num(..)) => key.clone(),
+            _ => {
+                let i = self.declare("_computedKey");
+                let expr = replace(key, Box::new(Expr::Invalid(Invalid { span: DUMMY_SP })));
+                *key = Box::new(Expr::Assign(AssignExpr {
+                    span: expr.span(),
+                    op: op!("="),
+                    left: PatOrExpr::Pat(Box::new(Pat::Ident(i.clone().into()))),
+                    right: Box::new(Expr::Call(CallExpr {
+                        span: DUMMY_SP,
+                        callee: helper!(to_property_key, "toPropertyKey"),
+                        args: vec![expr.as_arg()],
+                        type_args: Default::default(),
+                    })),
+                }));
+                Box::new(Expr::Ident(i))
+            }
+        }
+    }
+
+    fn visit_mut_stmt_like<T>(
+        &mut self,
+        stmts: &mut Vec<T>,
+        mut handle: impl FnMut(&mut Self, T) -> Vec<T>,
+    ) where
+        T: StmtLike,
+    {
+        let old = take(&mut self.vars);
+
+        let mut buf = Vec::with_capacity(stmts.len());
+        for stmt in take(stmts) {
+            let items = handle(self, stmt);
+
+            if !self.vars.is_empty() {
+                buf.push(T::from_stmt(Stmt::Decl(Decl::Var(VarDecl {
+                    span: DUMMY_SP,
+                    kind: VarDeclKind::Var,
+                    declare: false,
+                    decls: take(&mut self.vars)
+                        .into_iter()
+                        .map(|i| VarDeclarator {
+                            span: DUMMY_SP,
+                            name: Pat::Ident(i.into()),
+                            init: None,
+                            definite: false,
+                        })
+                        .collect(),
+                }))));
+            }
+            buf.extend(items);
+        }
+        *stmts = buf;
+
+        self.vars = old;
+    }
+}
+
+impl VisitMut for Decorator202203 {
+    noop_visit_mut_type!();
+
+    fn visit_mut_decl(&mut self, d: &mut Decl) {
+        d.visit_mut_children_with(self);
+
+        let wrap = match d {
+            Decl::Class(c) if !c.declare => self.handle(&mut c.class, Some(&c.ident)),
+            _ => None,
+        };
+        if let Some(wrap) = wrap {
+            let c = match replace(
+                d,
+                Decl::Var(VarDecl {
+                    span: DUMMY_SP,
+                    kind: VarDeclKind::Let,
+                    declare: false,
+                    decls: vec![],
+                }),
+            ) {
+                Decl::Class(c) => c,
+                _ => unreachable!(),
+            };
+
+            *d = Decl::Var(let_decl(
+                c.ident.clone(),
+                wrap.apply(ClassExpr {
+                    ident: Some(c.ident),
+                    class: c.class,
+                }),
+            ));
+        }
+    }
+
+    fn visit_mut_expr(&mut self, e: &mut Expr) {
+        e.visit_mut_children_with(self);
+
+        let wrap = match e {
+            Expr::Class(c) => self.handle(&mut c.class, c.ident.as_ref()),
+            _ => None,
+        };
+        if let Some(wrap) = wrap {
+            let c = match replace(e, Expr::Invalid(Invalid { span: DUMMY_SP })) {
+                Expr::Class(c) => c,
+                _ => unreachable!(),
+            };
+            *e = *wrap.apply(c);
+        }
+    }
+
+    fn visit_mut_module(&mut self, m: &mut Module) {
+        if self.metadata {
+            m.visit_with(&Invalid { span: DUMMY_SP }, &mut self.types);
+        }
+
+        m.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_module_items(&mut self, items: &mut Vec<ModuleItem>) {
+        self.visit_mut_stmt_like(items, |v, item| match item {
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+                span,
+                decl: DefaultDecl::Class(mut c),
+            })) => {
+                c.visit_mut_children_with(v);
+
+                match v.handle(&mut c.class, c.ident.as_ref()) {
+                    Some(wrap) => {
+                        let ident = c
+                            .ident
+                            .clone()
+                            .unwrap_or_else(|| private_ident!("_default"));
+
+                        vec![
+                            ModuleItem::Stmt(Stmt::Decl(Decl::Var(let_decl(
+                                ident.clone(),
+                                wrap.apply(c),
+                            )))),
+                            ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(NamedExport {
+                                span,
+                                specifiers: vec![ExportSpecifier::Named(ExportNamedSpecifier {
+                                    span: DUMMY_SP,
+                                    orig: ident,
+                                    exported: Some(quote_ident!("default")),
+                                })],
+                                src: None,
+                                type_only: false,
+                                asserts: None,
+                            })),
+                        ]
+                    }
+                    None => vec![ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(
+                        ExportDefaultDecl {
+                            span,
+                            decl: DefaultDecl::Class(c),
+                        },
+                    ))],
+                }
+            }
+
+            mut item => {
+                item.visit_mut_with(v);
+                vec![item]
+            }
+        });
+    }
+
+    fn visit_mut_script(&mut self, s: &mut Script) {
+        if self.metadata {
+            s.visit_with(&Invalid { span: DUMMY_SP }, &mut self.types);
+        }
+
+        s.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_stmts(&mut self, stmts: &mut Vec<Stmt>) {
+        self.visit_mut_stmt_like(stmts, |v, mut stmt| {
+            stmt.visit_mut_with(v);
+            vec![stmt]
+        });
+    }
+}
+
+fn needs_transform(member: &ClassMember) -> bool {
+    match member {
+        ClassMember::Method(m) => !m.function.decorators.is_empty(),
+        ClassMember::PrivateMethod(m) => !m.function.decorators.is_empty(),
+        ClassMember::ClassProp(p) => !p.decorators.is_empty(),
+        ClassMember::PrivateProp(p) => !p.decorators.is_empty(),
+        ClassMember::AutoAccessor(..) => true,
+        _ => false,
+    }
+}
+
+/// `_initProto(this)` runs before fields are initialized, so it's injected
+/// into the first instance field if there's one.
+fn inject_init_proto(body: &mut Vec<ClassMember>, init_proto: &Ident, has_super: bool) {
+    let call = call_with_this(init_proto, None);
+
+    for member in body.iter_mut() {
+        let value = match member {
+            ClassMember::ClassProp(p) if !p.is_static && !p.declare => &mut p.value,
+            ClassMember::PrivateProp(p) if !p.is_static => &mut p.value,
+            _ => continue,
+        };
+        *value = Some(Box::new(Expr::Seq(SeqExpr {
+            span: DUMMY_SP,
+            exprs: vec![call, value.take().unwrap_or_else(|| undefined(DUMMY_SP))],
+        })));
+        return;
+    }
+
+    let constructor = body.iter_mut().find_map(|member| match member {
+        ClassMember::Constructor(c) if c.body.is_some() => Some(c),
+        _ => None,
+    });
+    match constructor {
+        Some(c) => inject_after_super(c, vec![call]),
+        None => {
+            let mut c = default_constructor(has_super);
+            inject_after_super(&mut c, vec![call]);
+            body.push(ClassMember::Constructor(c));
+        }
+    }
+}
+
+/// `[rets...] = _applyDecs2203(this, [dec_infos...])`
+fn apply_decs(dec_infos: Vec<Box<Expr>>, rets: Vec<Ident>) -> Box<Expr> {
+    let init = Box::new(Expr::Call(CallExpr {
+        span: DUMMY_SP,
+        callee: helper!(apply_decs_2203, "applyDecs2203"),
+        args: vec![
+            ThisExpr { span: DUMMY_SP }.as_arg(),
+            array(dec_infos).as_arg(),
+        ],
+        type_args: Default::default(),
+    }));
+    if rets.is_empty() {
+        return init;
+    }
+
+    Box::new(Expr::Assign(AssignExpr {
+        span: DUMMY_SP,
+        op: op!("="),
+        left: PatOrExpr::Pat(Box::new(Pat::Array(ArrayPat {
+            span: DUMMY_SP,
+            elems: rets
+                .into_iter()
+                .map(|i| Some(Pat::Ident(i.into())))
+                .collect(),
+            optional: false,
+            type_ann: None,
+        }))),
+        right: init,
+    }))
+}
+
+/// `_tsMetadata(target, key, [[design, value]...])`, where `target` is the
+/// class or its prototype.
+fn ts_metadata(is_static: bool, key: Box<Expr>, metadata: Vec<(&'static str, Expr)>) -> Box<Expr> {
+    let target = if is_static {
+        Box::new(Expr::This(ThisExpr { span: DUMMY_SP }))
+    } else {
+        Box::new(Expr::Member(MemberExpr {
+            span: DUMMY_SP,
+            obj: ThisExpr { span: DUMMY_SP }.as_obj(),
+            prop: Box::new(Expr::Ident(quote_ident!("prototype"))),
+            computed: false,
+        }))
+    };
+
+    Box::new(Expr::Call(CallExpr {
+        span: DUMMY_SP,
+        callee: helper!(ts_metadata, "tsMetadata"),
+        args: vec![
+            target.as_arg(),
+            key.as_arg(),
+            array(
+                metadata
+                    .into_iter()
+                    .map(|(design, value)| {
+                        array(vec![
+                            Box::new(Expr::Lit(Lit::Str(quote_str!(design)))),
+                            Box::new(value),
+                        ])
+                    })
+                    .collect(),
+            )
+            .as_arg(),
+        ],
+        type_args: Default::default(),
+    }))
+}
+
+/// `[decorators, flags, name, ...extra]`
+fn dec_info(
+    decs: Vec<Box<Expr>>,
+    kind: u8,
+    is_static: bool,
+    name: Box<Expr>,
+    extra: Vec<Box<Expr>>,
+) -> Box<Expr> {
+    let flags = kind | if is_static { STATIC } else { 0 };
+
+    let mut elems = vec![
+        array(decs),
+        Box::new(Expr::Lit(Lit::Num(Number {
+            span: DUMMY_SP,
+            value: flags as f64,
+        }))),
+        name,
+    ];
+    elems.extend(extra);
+    array(elems)
+}
+
+fn array(elems: Vec<Box<Expr>>) -> Box<Expr> {
+    Box::new(Expr::Array(ArrayLit {
+        span: DUMMY_SP,
+        elems: elems.into_iter().map(|e| Some(e.as_arg())).collect(),
+    }))
+}
+
+fn name_hint(key: &Expr) -> String {
+    match key {
+        Expr::Ident(i) => i.sym.to_string(),
+        _ => "computedKey".into(),
+    }
+}
+
+fn private_name_str(name: &PrivateName) -> Box<Expr> {
+    Box::new(Expr::Lit(Lit::Str(quote_str!(
+        name.span,
+        format!("#{}", name.id.sym)
+    ))))
+}
+
+fn private_member(obj: &Ident, name: &PrivateName) -> Box<Expr> {
+    Box::new(Expr::Member(MemberExpr {
+        span: DUMMY_SP,
+        obj: obj.clone().as_obj(),
+        prop: Box::new(Expr::PrivateName(name.clone())),
+        computed: false,
+    }))
+}
+
+/// `o => o.#name`
+fn private_get(name: &PrivateName) -> Box<Expr> {
+    let o = private_ident!("o");
+
+    Box::new(Expr::Arrow(ArrowExpr {
+        span: DUMMY_SP,
+        params: vec![Pat::Ident(o.clone().into())],
+        body: BlockStmtOrExpr::Expr(private_member(&o, name)),
+        is_async: false,
+        is_generator: false,
+        type_params: None,
+        return_type: None,
+    }))
+}
+
+/// `(o, v) => o.#name = v`
+fn private_set(name: &PrivateName) -> Box<Expr> {
+    let o = private_ident!("o");
+    let v = private_ident!("v");
+
+    Box::new(Expr::Arrow(ArrowExpr {
+        span: DUMMY_SP,
+        params: vec![Pat::Ident(o.clone().into()), Pat::Ident(v.clone().into())],
+        body: BlockStmtOrExpr::Expr(Box::new(Expr::Assign(AssignExpr {
+            span: DUMMY_SP,
+            op: op!("="),
+            left: PatOrExpr::Expr(private_member(&o, name)),
+            right: Box::new(Expr::Ident(v)),
+        }))),
+        is_async: false,
+        is_generator: false,
+        type_params: None,
+        return_type: None,
+    }))
+}
+
+/// `f(this, value)`
+fn call_with_this(f: &Ident, value: Option<Box<Expr>>) -> Box<Expr> {
+    Box::new(Expr::Call(CallExpr {
+        span: DUMMY_SP,
+        callee: f.clone().as_callee(),
+        args: Some(ThisExpr { span: DUMMY_SP }.as_arg())
+            .into_iter()
+            .chain(value.map(|v| v.as_arg()))
+            .collect(),
+        type_args: Default::default(),
+    }))
+}
+
+fn getter_fn(value: Box<Expr>) -> Function {
+    Function {
+        params: vec![],
+        decorators: vec![],
+        span: DUMMY_SP,
+        body: Some(BlockStmt {
+            span: DUMMY_SP,
+            stmts: vec![Stmt::Return(ReturnStmt {
+                span: DUMMY_SP,
+                arg: Some(value),
+            })],
+        }),
+        is_generator: false,
+        is_async: false,
+        type_params: None,
+        return_type: None,
+    }
+}
+
+/// If `body` is [None], `v` is stored to `storage`.
+fn setter_fn(storage: &PrivateName, body: Option<Box<Expr>>) -> Function {
+    let v = quote_ident!("v");
+    let body = body.unwrap_or_else(|| {
+        Box::new(Expr::Assign(AssignExpr {
+            span: DUMMY_SP,
+            op: op!("="),
+            left: PatOrExpr::Expr(Box::new(Expr::Member(MemberExpr {
+                span: DUMMY_SP,
+                obj: ThisExpr { span: DUMMY_SP }.as_obj(),
+                prop: Box::new(Expr::PrivateName(storage.clone())),
+                computed: false,
+            }))),
+            right: Box::new(Expr::Ident(v.clone())),
+        }))
+    });
+
+    Function {
+        params: vec![Param {
+            span: DUMMY_SP,
+            decorators: vec![],
+            pat: Pat::Ident(v.into()),
+        }],
+        decorators: vec![],
+        span: DUMMY_SP,
+        body: Some(BlockStmt {
+            span: DUMMY_SP,
+            stmts: vec![body.into_stmt()],
+        }),
+        is_generator: false,
+        is_async: false,
+        type_params: None,
+        return_type: None,
+    }
+}
+
+fn let_decl(name: Ident, init: Box<Expr>) -> VarDecl {
+    VarDecl {
+        span: DUMMY_SP,
+        kind: VarDeclKind::Let,
+        declare: false,
+        decls: vec![VarDeclarator {
+            span: DUMMY_SP,
+            name: Pat::Ident(name.into()),
+            init: Some(init),
+            definite: false,
+        }],
+    }
+}
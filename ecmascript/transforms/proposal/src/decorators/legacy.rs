@@ -1,7 +1,6 @@
-use self::metadata::{Metadata, ParamMetadata};
 use super::contains_decorator;
+use super::metadata::{Metadata, ParamMetadata, TypeInfo};
 use super::DecoratorFinder;
-use fxhash::FxHashMap;
 use smallvec::SmallVec;
 use std::mem::replace;
 use swc_common::{util::move_map::MoveMap, DUMMY_SP};
@@ -14,17 +13,7 @@ use swc_ecma_utils::{
     alias_if_required, default_constructor, prepend, prop_name_to_expr_value, undefined,
     ExprFactory, ModuleItemLike, StmtLike,
 };
-use swc_ecma_utils::{ident::IdentLike, Id};
-use swc_ecma_visit::{noop_fold_type, Fold, FoldWith, Node, Visit, VisitWith};
-
-mod metadata;
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum EnumKind {
-    Mixed,
-    Str,
-    Num,
-}
+use swc_ecma_visit::{noop_fold_type, Fold, FoldWith, VisitWith};
 
 #[derive(Debug)]
 pub(super) struct Legacy {
@@ -32,7 +21,7 @@ pub(super) struct Legacy {
     uninitialized_vars: Vec<VarDeclarator>,
     initialized_vars: Vec<VarDeclarator>,
     exports: Vec<ExportSpecifier>,
-    enums: FxHashMap<Id, EnumKind>,
+    types: TypeInfo,
 }
 
 pub(super) fn new(metadata: bool) -> Legacy {
@@ -41,48 +30,7 @@ pub(super) fn new(metadata: bool) -> Legacy {
         uninitialized_vars: Default::default(),
         initialized_vars: Default::default(),
         exports: Default::default(),
-        enums: Default::default(),
-    }
-}
-
-impl Visit for Legacy {
-    fn visit_ts_enum_decl(&mut self, e: &TsEnumDecl, _: &dyn Node) {
-        let enum_kind = e
-            .members
-            .iter()
-            .map(|member| member.init.as_ref())
-            .map(|init| match init {
-                Some(e) => match &**e {
-                    Expr::Lit(lit) => match lit {
-                        Lit::Str(_) => EnumKind::Str,
-                        Lit::Num(_) => EnumKind::Num,
-                        _ => EnumKind::Mixed,
-                    },
-                    _ => EnumKind::Mixed,
-                },
-                None => EnumKind::Num,
-            })
-            .fold(None, |opt: Option<EnumKind>, item| {
-                //
-                let a = match item {
-                    EnumKind::Mixed => return Some(EnumKind::Mixed),
-                    _ => item,
-                };
-
-                let b = match opt {
-                    Some(EnumKind::Mixed) => return Some(EnumKind::Mixed),
-                    Some(v) => v,
-                    None => return Some(item),
-                };
-                if a == b {
-                    return Some(a);
-                } else {
-                    return Some(EnumKind::Mixed);
-                }
-            });
-        if let Some(kind) = enum_kind {
-            self.enums.insert(e.id.to_id(), kind);
-        }
+        types: Default::default(),
     }
 }
 
@@ -137,7 +85,7 @@ impl Fold for Legacy {
     fn fold_module(&mut self, m: Module) -> Module {
         // Collect required information.
         // For example, value type of enum affects codegen
-        m.visit_with(&Invalid { span: DUMMY_SP }, self);
+        m.visit_with(&Invalid { span: DUMMY_SP }, &mut self.types);
 
         let mut m = m.fold_children_with(self);
 
@@ -276,7 +224,7 @@ impl Legacy {
             let i = c.ident.clone();
 
             c = c.fold_with(&mut ParamMetadata).fold_with(&mut Metadata {
-                enums: &self.enums,
+                types: &self.types,
                 class_name: i.as_ref(),
             });
         }
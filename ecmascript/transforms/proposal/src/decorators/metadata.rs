@@ -1,12 +1,83 @@
-use super::EnumKind;
-use fxhash::FxHashMap;
+use fxhash::{FxHashMap, FxHashSet};
 use swc_atoms::js_word;
 use swc_common::{util::move_map::MoveMap, Spanned, DUMMY_SP};
 use swc_ecma_ast::*;
 use swc_ecma_utils::member_expr;
 use swc_ecma_utils::quote_ident;
 use swc_ecma_utils::{ident::IdentLike, undefined, ExprFactory, Id};
-use swc_ecma_visit::{noop_fold_type, Fold, FoldWith};
+use swc_ecma_visit::{noop_fold_type, Fold, FoldWith, Node, Visit};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum EnumKind {
+    Mixed,
+    Str,
+    Num,
+}
+
+/// Information about declarations of a file, which is required to serialize
+/// types like tsc.
+#[derive(Debug, Default)]
+pub(super) struct TypeInfo {
+    enums: FxHashMap<Id, EnumKind>,
+    /// Bindings imported by `import type`, which don't exist at runtime.
+    type_only: FxHashSet<Id>,
+}
+
+impl Visit for TypeInfo {
+    fn visit_import_decl(&mut self, i: &ImportDecl, _: &dyn Node) {
+        if !i.type_only {
+            return;
+        }
+
+        for s in &i.specifiers {
+            let local = match s {
+                ImportSpecifier::Named(s) => &s.local,
+                ImportSpecifier::Default(s) => &s.local,
+                ImportSpecifier::Namespace(s) => &s.local,
+            };
+            self.type_only.insert(local.to_id());
+        }
+    }
+
+    fn visit_ts_enum_decl(&mut self, e: &TsEnumDecl, _: &dyn Node) {
+        let enum_kind = e
+            .members
+            .iter()
+            .map(|member| member.init.as_ref())
+            .map(|init| match init {
+                Some(e) => match &**e {
+                    Expr::Lit(lit) => match lit {
+                        Lit::Str(_) => EnumKind::Str,
+                        Lit::Num(_) => EnumKind::Num,
+                        _ => EnumKind::Mixed,
+                    },
+                    _ => EnumKind::Mixed,
+                },
+                None => EnumKind::Num,
+            })
+            .fold(None, |opt: Option<EnumKind>, item| {
+                //
+                let a = match item {
+                    EnumKind::Mixed => return Some(EnumKind::Mixed),
+                    _ => item,
+                };
+
+                let b = match opt {
+                    Some(EnumKind::Mixed) => return Some(EnumKind::Mixed),
+                    Some(v) => v,
+                    None => return Some(item),
+                };
+                if a == b {
+                    return Some(a);
+                } else {
+                    return Some(EnumKind::Mixed);
+                }
+            });
+        if let Some(kind) = enum_kind {
+            self.enums.insert(e.id.to_id(), kind);
+        }
+    }
+}
 
 /// https://github.com/leonardfactory/babel-plugin-transform-typescript-metadata/blob/master/src/parameter/parameterVisitor.ts
 pub(super) struct ParamMetadata;
@@ -123,7 +194,7 @@ impl ParamMetadata {
 
 /// https://github.com/leonardfactory/babel-plugin-transform-typescript-metadata/blob/master/src/metadata/metadataVisitor.ts
 pub(super) struct Metadata<'a> {
-    pub(super) enums: &'a FxHashMap<Id, EnumKind>,
+    pub(super) types: &'a TypeInfo,
 
     pub(super) class_name: Option<&'a Ident>,
 }
@@ -138,46 +209,8 @@ impl Fold for Metadata<'_> {
             return c;
         }
 
-        let constructor = c.body.iter().find_map(|m| match m {
-            ClassMember::Constructor(c) => Some(c),
-            _ => None,
-        });
-        if constructor.is_none() {
-            return c;
-        }
-
-        {
-            let dec = self
-                .create_metadata_design_decorator("design:type", quote_ident!("Function").as_arg());
-            c.decorators.push(dec);
-        }
-        {
-            let dec = self.create_metadata_design_decorator(
-                "design:paramtypes",
-                ArrayLit {
-                    span: DUMMY_SP,
-                    elems: constructor
-                        .as_ref()
-                        .unwrap()
-                        .params
-                        .iter()
-                        .map(|v| match v {
-                            ParamOrTsParamProp::TsParamProp(p) => {
-                                let ann = match &p.param {
-                                    TsParamPropParam::Ident(i) => i.type_ann.as_ref(),
-                                    TsParamPropParam::Assign(a) => get_type_ann_of_pat(&a.left),
-                                };
-                                Some(serialize_type(self.class_name, ann).as_arg())
-                            }
-                            ParamOrTsParamProp::Param(p) => Some(
-                                serialize_type(self.class_name, get_type_ann_of_pat(&p.pat))
-                                    .as_arg(),
-                            ),
-                        })
-                        .collect(),
-                }
-                .as_arg(),
-            );
+        for (design, value) in self.class_metadata(&c) {
+            let dec = self.create_metadata_design_decorator(design, value.as_arg());
             c.decorators.push(dec);
         }
         c
@@ -188,30 +221,8 @@ impl Fold for Metadata<'_> {
             return m;
         }
 
-        {
-            let dec = self
-                .create_metadata_design_decorator("design:type", quote_ident!("Function").as_arg());
-            m.function.decorators.push(dec);
-        }
-        {
-            let dec = self.create_metadata_design_decorator(
-                "design:paramtypes",
-                ArrayLit {
-                    span: DUMMY_SP,
-                    elems: m
-                        .function
-                        .params
-                        .iter()
-                        .map(|v| {
-                            Some(
-                                serialize_type(self.class_name, get_type_ann_of_pat(&v.pat))
-                                    .as_arg(),
-                            )
-                        })
-                        .collect(),
-                }
-                .as_arg(),
-            );
+        for (design, value) in self.method_metadata(m.kind, &m.function) {
+            let dec = self.create_metadata_design_decorator(design, value.as_arg());
             m.function.decorators.push(dec);
         }
         m
@@ -226,38 +237,9 @@ impl Fold for Metadata<'_> {
             return p;
         }
 
-        if let Some(name) = p
-            .type_ann
-            .as_ref()
-            .map(|ty| &ty.type_ann)
-            .map(|type_ann| match &**type_ann {
-                TsType::TsTypeRef(r) => Some(r),
-                _ => None,
-            })
-            .flatten()
-            .map(|r| match &r.type_name {
-                TsEntityName::TsQualifiedName(_) => None,
-                TsEntityName::Ident(i) => Some(i),
-            })
-            .flatten()
-        {
-            if let Some(kind) = self.enums.get(&name.to_id()) {
-                let dec = self.create_metadata_design_decorator(
-                    "design:type",
-                    match kind {
-                        EnumKind::Mixed => quote_ident!("Object").as_arg(),
-                        EnumKind::Str => quote_ident!("String").as_arg(),
-                        EnumKind::Num => quote_ident!("Number").as_arg(),
-                    },
-                );
-                p.decorators.push(dec);
-                return p;
-            }
-        }
-
         let dec = self.create_metadata_design_decorator(
             "design:type",
-            serialize_type(self.class_name, p.type_ann.as_ref()).as_arg(),
+            self.serialize_type(p.type_ann.as_ref()).as_arg(),
         );
         p.decorators.push(dec);
 
@@ -266,6 +248,71 @@ impl Fold for Metadata<'_> {
 }
 
 impl Metadata<'_> {
+    /// `design:type` and `design:paramtypes` of a class, which are emitted only
+    /// if the class has a constructor.
+    pub(super) fn class_metadata(&self, c: &Class) -> Vec<(&'static str, Expr)> {
+        let constructor = c.body.iter().find_map(|m| match m {
+            ClassMember::Constructor(c) => Some(c),
+            _ => None,
+        });
+        let constructor = match constructor {
+            Some(v) => v,
+            None => return vec![],
+        };
+
+        let param_types = self.serialize_param_types(constructor.params.iter().map(|p| match p {
+            ParamOrTsParamProp::TsParamProp(p) => match &p.param {
+                TsParamPropParam::Ident(i) => ParamType::Ann(i.type_ann.as_ref()),
+                TsParamPropParam::Assign(a) => ParamType::Ann(get_type_ann_of_pat(&a.left)),
+            },
+            ParamOrTsParamProp::Param(p) => ParamType::Pat(&p.pat),
+        }));
+
+        vec![
+            ("design:type", quote_ident!("Function").into()),
+            ("design:paramtypes", param_types),
+        ]
+    }
+
+    /// Metadata of a method or an accessor, which are emitted like tsc.
+    ///
+    ///  - Methods have `design:type`, `design:paramtypes` and
+    ///    `design:returntype`.
+    ///  - Getters have `design:type`.
+    ///  - Setters have `design:type` and `design:paramtypes`.
+    pub(super) fn method_metadata(
+        &self,
+        kind: MethodKind,
+        function: &Function,
+    ) -> Vec<(&'static str, Expr)> {
+        let param_types =
+            || self.serialize_param_types(function.params.iter().map(|p| ParamType::Pat(&p.pat)));
+
+        match kind {
+            MethodKind::Method => vec![
+                ("design:type", quote_ident!("Function").into()),
+                ("design:paramtypes", param_types()),
+                ("design:returntype", self.serialize_return_type(function)),
+            ],
+            MethodKind::Getter => vec![(
+                "design:type",
+                self.serialize_type(function.return_type.as_ref()),
+            )],
+            MethodKind::Setter => vec![
+                (
+                    "design:type",
+                    self.serialize_type(
+                        function
+                            .params
+                            .first()
+                            .and_then(|p| get_type_ann_of_pat(&p.pat)),
+                    ),
+                ),
+                ("design:paramtypes", param_types()),
+            ],
+        }
+    }
+
     fn create_metadata_design_decorator(&self, design: &str, type_arg: ExprOrSpread) -> Decorator {
         Decorator {
             span: DUMMY_SP,
@@ -325,65 +372,104 @@ impl Metadata<'_> {
             })),
         }
     }
-}
 
-fn serialize_type(class_name: Option<&Ident>, param: Option<&TsTypeAnn>) -> Expr {
-    fn serialize_type_ref(class_name: &str, ty: &TsTypeRef) -> Expr {
+    fn serialize_param_types<'a>(&self, params: impl Iterator<Item = ParamType<'a>>) -> Expr {
+        Expr::Array(ArrayLit {
+            span: DUMMY_SP,
+            elems: params
+                .filter_map(|param| {
+                    let ann = match param {
+                        ParamType::Ann(ann) => ann,
+                        // `this` parameters are not parameters at runtime.
+                        ParamType::Pat(Pat::Ident(i)) if &*i.id.sym == "this" => return None,
+                        // tsc serializes the type of elements of a rest parameter.
+                        ParamType::Pat(Pat::Rest(RestPat {
+                            type_ann: Some(ann),
+                            ..
+                        })) => {
+                            let elem_type = match &*ann.type_ann {
+                                TsType::TsArrayType(ty) => Some(&*ty.elem_type),
+                                TsType::TsTypeRef(TsTypeRef {
+                                    type_params: Some(params),
+                                    ..
+                                }) if params.params.len() == 1 => Some(&*params.params[0]),
+                                _ => None,
+                            };
+
+                            return Some(
+                                elem_type
+                                    .map(|ty| self.serialize_type_node(ty))
+                                    .unwrap_or_else(|| quote_ident!("Object").into())
+                                    .as_arg(),
+                            );
+                        }
+                        ParamType::Pat(pat) => get_type_ann_of_pat(pat),
+                    };
+
+                    Some(self.serialize_type(ann).as_arg())
+                })
+                .map(Some)
+                .collect(),
+        })
+    }
+
+    fn serialize_return_type(&self, function: &Function) -> Expr {
+        match &function.return_type {
+            Some(ty) => self.serialize_type(Some(ty)),
+            None if function.is_async => quote_ident!("Promise").into(),
+            None => *undefined(DUMMY_SP),
+        }
+    }
+
+    pub(super) fn serialize_type(&self, param: Option<&TsTypeAnn>) -> Expr {
+        let param = match param {
+            Some(v) => &v.type_ann,
+            None => return *undefined(DUMMY_SP),
+        };
+
+        self.serialize_type_node(&**param)
+    }
+
+    fn serialize_type_ref(&self, ty: &TsTypeRef) -> Expr {
         match &ty.type_name {
             // We should omit references to self (class) since it will throw a ReferenceError at
             // runtime due to babel transpile output.
-            TsEntityName::Ident(i) if &*i.sym == class_name => {
+            TsEntityName::Ident(i) if self.class_name.map_or(false, |c| c.sym == i.sym) => {
                 return quote_ident!("Object").into()
             }
+
+            TsEntityName::Ident(i) => match &*i.sym {
+                "Array" | "ReadonlyArray" => return quote_ident!("Array").into(),
+                "Promise" => return quote_ident!("Promise").into(),
+                _ => {}
+            },
+
             _ => {}
         }
 
-        let member_expr = ts_entity_to_member_expr(&ty.type_name);
+        // `Enum` or `Enum.Member`
+        let enum_name = match &ty.type_name {
+            TsEntityName::Ident(i) => Some(i),
+            TsEntityName::TsQualifiedName(q) => match &q.left {
+                TsEntityName::Ident(i) => Some(i),
+                _ => None,
+            },
+        };
+        if let Some(kind) = enum_name.and_then(|i| self.types.enums.get(&i.to_id())) {
+            return match kind {
+                EnumKind::Mixed => quote_ident!("Object").into(),
+                EnumKind::Str => quote_ident!("String").into(),
+                EnumKind::Num => quote_ident!("Number").into(),
+            };
+        }
 
-        fn check_object_existed(expr: Box<Expr>) -> Box<Expr> {
-            match *expr {
-                Expr::Member(ref member_expr) => {
-                    let obj_expr = match member_expr.obj {
-                        ExprOrSuper::Expr(ref exp) => exp.clone(),
-                        ExprOrSuper::Super(_) => panic!("Unreachable code path"),
-                    };
-                    Box::new(Expr::Bin(BinExpr {
-                        span: DUMMY_SP,
-                        left: check_object_existed(obj_expr),
-                        op: op!("||"),
-                        right: Box::new(Expr::Bin(BinExpr {
-                            span: DUMMY_SP,
-                            left: Box::new(Expr::Unary(UnaryExpr {
-                                span: DUMMY_SP,
-                                op: op!("typeof"),
-                                arg: expr.clone(),
-                            })),
-                            op: op!("==="),
-                            right: Box::new(Expr::Lit(Lit::Str(Str {
-                                span: DUMMY_SP,
-                                value: "undefined".into(),
-                                has_escape: false,
-                                kind: Default::default(),
-                            }))),
-                        })),
-                    }))
-                }
-                _ => Box::new(Expr::Bin(BinExpr {
-                    span: DUMMY_SP,
-                    left: Box::new(Expr::Unary(UnaryExpr {
-                        span: DUMMY_SP,
-                        op: op!("typeof"),
-                        arg: expr.clone(),
-                    })),
-                    op: op!("==="),
-                    right: Box::new(Expr::Lit(Lit::Str(Str {
-                        span: DUMMY_SP,
-                        value: "undefined".into(),
-                        has_escape: false,
-                        kind: Default::default(),
-                    }))),
-                })),
-            }
+        // Type-only imports are removed, so we can't reference them.
+        if self
+            .types
+            .type_only
+            .contains(&root_ident(&ty.type_name).to_id())
+        {
+            return quote_ident!("Object").into();
         }
 
         // We don't know if type is just a type (interface, etc.) or a concrete value
@@ -391,16 +477,10 @@ fn serialize_type(class_name: Option<&Ident>, param: Option<&TsTypeAnn>) -> Expr
         //
         // `typeof` operator allows us to use the expression even if it is not defined,
         // fallback is just `Object`.
-
-        Expr::Cond(CondExpr {
-            span: DUMMY_SP,
-            test: check_object_existed(Box::new(member_expr.clone())),
-            cons: Box::new(quote_ident!("Object").into()),
-            alt: Box::new(member_expr),
-        })
+        value_or_object(ts_entity_to_member_expr(&ty.type_name))
     }
 
-    fn serialize_type_list(class_name: &str, types: &[Box<TsType>]) -> Expr {
+    fn serialize_type_list(&self, types: &[Box<TsType>]) -> Expr {
         let mut u = None;
 
         for ty in types {
@@ -435,7 +515,7 @@ fn serialize_type(class_name: Option<&Ident>, param: Option<&TsTypeAnn>) -> Expr
                 _ => {}
             }
 
-            let item = serialize_type_node(class_name, &ty);
+            let item = self.serialize_type_node(&ty);
 
             // One of the individual is global object, return immediately
             match item {
@@ -470,10 +550,10 @@ fn serialize_type(class_name: Option<&Ident>, param: Option<&TsTypeAnn>) -> Expr
             }
         }
 
-        *undefined(DUMMY_SP)
+        u.unwrap_or_else(|| *undefined(DUMMY_SP))
     }
 
-    fn serialize_type_node(class_name: &str, ty: &TsType) -> Expr {
+    fn serialize_type_node(&self, ty: &TsType) -> Expr {
         let span = ty.span();
         match ty {
             TsType::TsKeywordType(TsKeywordType {
@@ -493,7 +573,7 @@ fn serialize_type(class_name: Option<&Ident>, param: Option<&TsTypeAnn>) -> Expr
                 ..
             }) => return *undefined(span),
 
-            TsType::TsParenthesizedType(ty) => serialize_type_node(class_name, &*ty.type_ann),
+            TsType::TsParenthesizedType(ty) => self.serialize_type_node(&*ty.type_ann),
 
             TsType::TsFnOrConstructorType(_) => quote_ident!("Function").into(),
 
@@ -523,62 +603,115 @@ fn serialize_type(class_name: Option<&Ident>, param: Option<&TsTypeAnn>) -> Expr
             | TsType::TsKeywordType(TsKeywordType {
                 kind: TsKeywordTypeKind::TsNumberKeyword,
                 ..
+            }) => quote_ident!("Number").into(),
+
+            // `BigInt` does not exist in old runtimes.
+            TsType::TsLitType(TsLitType {
+                lit: TsLit::BigInt(..),
+                ..
             })
             | TsType::TsKeywordType(TsKeywordType {
                 kind: TsKeywordTypeKind::TsBigIntKeyword,
                 ..
-            }) => quote_ident!("Number").into(),
-
-            TsType::TsLitType(ty) => {
-                // TODO: Proper error reporting
-                panic!("Bad type for decoration: {:?}", ty);
-            }
+            }) => value_or_object(quote_ident!("BigInt").into()),
 
             TsType::TsKeywordType(TsKeywordType {
                 kind: TsKeywordTypeKind::TsSymbolKeyword,
                 ..
             }) => quote_ident!("Symbol").into(),
 
-            TsType::TsTypeQuery(_)
-            | TsType::TsTypeOperator(_)
-            | TsType::TsIndexedAccessType(_)
-            | TsType::TsTypeLit(_)
-            | TsType::TsMappedType(_)
-            | TsType::TsKeywordType(TsKeywordType {
-                kind: TsKeywordTypeKind::TsAnyKeyword,
-                ..
-            })
-            | TsType::TsKeywordType(TsKeywordType {
-                kind: TsKeywordTypeKind::TsUnknownKeyword,
+            TsType::TsTypeOperator(TsTypeOperator {
+                op: TsTypeOperatorOp::ReadOnly,
+                type_ann,
                 ..
-            })
-            | TsType::TsThisType(..) => quote_ident!("Object").into(),
+            }) => self.serialize_type_node(type_ann),
 
             TsType::TsUnionOrIntersectionType(ty) => match ty {
-                TsUnionOrIntersectionType::TsUnionType(ty) => {
-                    serialize_type_list(class_name, &ty.types)
-                }
+                TsUnionOrIntersectionType::TsUnionType(ty) => self.serialize_type_list(&ty.types),
                 TsUnionOrIntersectionType::TsIntersectionType(ty) => {
-                    serialize_type_list(class_name, &ty.types)
+                    self.serialize_type_list(&ty.types)
                 }
             },
 
             TsType::TsConditionalType(ty) => {
-                serialize_type_list(class_name, &[ty.true_type.clone(), ty.false_type.clone()])
+                self.serialize_type_list(&[ty.true_type.clone(), ty.false_type.clone()])
             }
 
-            TsType::TsTypeRef(ty) => serialize_type_ref(class_name, ty),
+            TsType::TsTypeRef(ty) => self.serialize_type_ref(ty),
+
+            // `any`, `unknown`, `this`, type queries, type literals, mapped types, etc.
+            _ => quote_ident!("Object").into(),
+        }
+    }
+}
+
+/// Type of a parameter.
+enum ParamType<'a> {
+    Ann(Option<&'a TsTypeAnn>),
+    Pat(&'a Pat),
+}
 
-            _ => panic!("Bad type for decorator: {:?}", ty),
+fn check_object_existed(expr: Box<Expr>) -> Box<Expr> {
+    match *expr {
+        Expr::Member(ref member_expr) => {
+            let obj_expr = match member_expr.obj {
+                ExprOrSuper::Expr(ref exp) => exp.clone(),
+                ExprOrSuper::Super(_) => panic!("Unreachable code path"),
+            };
+            Box::new(Expr::Bin(BinExpr {
+                span: DUMMY_SP,
+                left: check_object_existed(obj_expr),
+                op: op!("||"),
+                right: Box::new(Expr::Bin(BinExpr {
+                    span: DUMMY_SP,
+                    left: Box::new(Expr::Unary(UnaryExpr {
+                        span: DUMMY_SP,
+                        op: op!("typeof"),
+                        arg: expr.clone(),
+                    })),
+                    op: op!("==="),
+                    right: Box::new(Expr::Lit(Lit::Str(Str {
+                        span: DUMMY_SP,
+                        value: "undefined".into(),
+                        has_escape: false,
+                        kind: Default::default(),
+                    }))),
+                })),
+            }))
         }
+        _ => Box::new(Expr::Bin(BinExpr {
+            span: DUMMY_SP,
+            left: Box::new(Expr::Unary(UnaryExpr {
+                span: DUMMY_SP,
+                op: op!("typeof"),
+                arg: expr.clone(),
+            })),
+            op: op!("==="),
+            right: Box::new(Expr::Lit(Lit::Str(Str {
+                span: DUMMY_SP,
+                value: "undefined".into(),
+                has_escape: false,
+                kind: Default::default(),
+            }))),
+        })),
     }
+}
 
-    let param = match param {
-        Some(v) => &v.type_ann,
-        None => return *undefined(DUMMY_SP),
-    };
+/// `typeof expr === "undefined" ? Object : expr`
+fn value_or_object(expr: Expr) -> Expr {
+    Expr::Cond(CondExpr {
+        span: DUMMY_SP,
+        test: check_object_existed(Box::new(expr.clone())),
+        cons: Box::new(quote_ident!("Object").into()),
+        alt: Box::new(expr),
+    })
+}
 
-    serialize_type_node(class_name.map(|v| &*v.sym).unwrap_or(""), &**param)
+fn root_ident(type_name: &TsEntityName) -> &Ident {
+    match type_name {
+        TsEntityName::TsQualifiedName(q) => root_ident(&q.left),
+        TsEntityName::Ident(i) => i,
+    }
 }
 
 fn ts_entity_to_member_expr(type_name: &TsEntityName) -> Expr {
@@ -599,15 +732,15 @@ fn ts_entity_to_member_expr(type_name: &TsEntityName) -> Expr {
 
 fn get_type_ann_of_pat(p: &Pat) -> Option<&TsTypeAnn> {
     match p {
-        Pat::Ident(p) => &p.type_ann,
-        Pat::Array(p) => &p.type_ann,
-        Pat::Rest(p) => &p.type_ann,
-        Pat::Object(p) => &p.type_ann,
-        Pat::Assign(p) => &p.type_ann,
-        Pat::Invalid(_) => return None,
-        Pat::Expr(_) => return None,
+        Pat::Ident(p) => p.type_ann.as_ref(),
+        Pat::Array(p) => p.type_ann.as_ref(),
+        Pat::Rest(p) => p.type_ann.as_ref(),
+        Pat::Object(p) => p.type_ann.as_ref(),
+        // `p: T = v` is parsed as an assignment pattern with an annotated left side.
+        Pat::Assign(p) => p.type_ann.as_ref().or_else(|| get_type_ann_of_pat(&p.left)),
+        Pat::Invalid(_) => None,
+        Pat::Expr(_) => None,
     }
-    .as_ref()
 }
 
 fn is_str(ty: &TsType) -> bool {
@@ -616,6 +749,10 @@ fn is_str(ty: &TsType) -> bool {
             lit: TsLit::Str(..),
             ..
         })
+        | TsType::TsLitType(TsLitType {
+            lit: TsLit::Tpl(..),
+            ..
+        })
         | TsType::TsKeywordType(TsKeywordType {
             kind: TsKeywordTypeKind::TsStringKeyword,
             ..
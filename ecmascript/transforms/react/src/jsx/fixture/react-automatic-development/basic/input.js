@@ -0,0 +1,6 @@
+var x = (
+  <div>
+    <span key="a" />
+    {[1, 2].map(function (n) { return <b>{n}</b>; })}
+  </div>
+);
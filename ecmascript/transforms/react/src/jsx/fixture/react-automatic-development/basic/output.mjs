@@ -0,0 +1,20 @@
+import { jsxDEV as _jsxDEV } from "react/jsx-dev-runtime";
+var x = /*#__PURE__*/_jsxDEV("div", {
+  children: [/*#__PURE__*/_jsxDEV("span", {}, "a", false, {
+    fileName: "input.js",
+    lineNumber: 3,
+    columnNumber: 5
+  }, this), [1, 2].map(function (n) {
+    return /*#__PURE__*/_jsxDEV("b", {
+      children: n
+    }, void 0, false, {
+      fileName: "input.js",
+      lineNumber: 4,
+      columnNumber: 39
+    }, this);
+  })]
+}, void 0, true, {
+  fileName: "input.js",
+  lineNumber: 2,
+  columnNumber: 3
+}, this);
@@ -0,0 +1,11 @@
+class A extends B {
+  constructor() {
+    super(<div />);
+  }
+  render() {
+    return <>
+      <div />
+      <div />
+    </>;
+  }
+}
@@ -0,0 +1,27 @@
+import { jsxDEV as _jsxDEV, Fragment as _Fragment } from "react/jsx-dev-runtime";
+class A extends B {
+  constructor() {
+    super( /*#__PURE__*/_jsxDEV("div", {}, void 0, false, {
+      fileName: "input.js",
+      lineNumber: 3,
+      columnNumber: 11
+    }));
+  }
+  render() {
+    return /*#__PURE__*/_jsxDEV(_Fragment, {
+      children: [/*#__PURE__*/_jsxDEV("div", {}, void 0, false, {
+        fileName: "input.js",
+        lineNumber: 7,
+        columnNumber: 7
+      }, this), /*#__PURE__*/_jsxDEV("div", {}, void 0, false, {
+        fileName: "input.js",
+        lineNumber: 8,
+        columnNumber: 7
+      }, this)]
+    }, void 0, true, {
+      fileName: "input.js",
+      lineNumber: 6,
+      columnNumber: 12
+    }, this);
+  }
+}
@@ -0,0 +1,2 @@
+/** @jsxImportSource @emotion/react */
+var x = <div {...props} key="1" />;
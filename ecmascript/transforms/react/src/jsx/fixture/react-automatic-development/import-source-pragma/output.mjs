@@ -0,0 +1,12 @@
+import { createElement as _createElement } from "@emotion/react";
+
+/** @jsxImportSource @emotion/react */
+var x = /*#__PURE__*/_createElement("div", { ...props,
+  key: "1",
+  __source: {
+    fileName: "input.js",
+    lineNumber: 2,
+    columnNumber: 9
+  },
+  __self: this
+});
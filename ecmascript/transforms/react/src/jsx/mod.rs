@@ -12,7 +12,7 @@ use swc_common::{
     comments::{CommentKind, Comments},
     iter::IdentifyLast,
     sync::Lrc,
-    FileName, SourceMap, Spanned, DUMMY_SP,
+    FileName, SourceMap, Span, Spanned, DUMMY_SP,
 };
 use swc_ecma_ast::*;
 use swc_ecma_parser::{Parser, StringInput, Syntax};
@@ -23,6 +23,7 @@ use swc_ecma_utils::member_expr;
 use swc_ecma_utils::prepend;
 use swc_ecma_utils::private_ident;
 use swc_ecma_utils::quote_ident;
+use swc_ecma_utils::undefined;
 use swc_ecma_utils::ExprFactory;
 use swc_ecma_utils::HANDLER;
 use swc_ecma_visit::as_folder;
@@ -168,8 +169,12 @@ where
         import_source: options.import_source.into(),
         import_jsx: None,
         import_jsxs: None,
+        import_jsx_dev: None,
         import_fragment: None,
         import_create_element: None,
+        development: options.development,
+        this_allowed: true,
+        in_derived_class: false,
 
         pragma: ExprOrSuper::Expr(parse_classic_option(&cm, "pragma", options.pragma)),
         comments,
@@ -198,12 +203,22 @@ where
     import_jsx: Option<Ident>,
     /// For automatic runtime.
     import_jsxs: Option<Ident>,
+    /// For automatic runtime with `development`.
+    import_jsx_dev: Option<Ident>,
     /// For automatic runtime.
     import_create_element: Option<Ident>,
     /// For automatic runtime.
     import_fragment: Option<Ident>,
     top_level_node: bool,
 
+    /// For automatic runtime, `jsxDEV` of `jsx-dev-runtime` is used instead
+    /// of `jsx` and `jsxs`.
+    development: bool,
+    /// `false` if `this` may not be initialized, like in the constructor of a
+    /// derived class.
+    this_allowed: bool,
+    in_derived_class: bool,
+
     pragma: ExprOrSuper,
     comments: Option<C>,
     pragma_frag: ExprOrSpread,
@@ -227,7 +242,11 @@ where
 
         match self.runtime {
             Runtime::Automatic => {
-                let jsx = if use_jsxs {
+                let jsx = if self.development {
+                    self.import_jsx_dev
+                        .get_or_insert_with(|| private_ident!("_jsxDEV"))
+                        .clone()
+                } else if use_jsxs {
                     self.import_jsxs
                         .get_or_insert_with(|| private_ident!("_jsxs"))
                         .clone()
@@ -262,7 +281,9 @@ where
                                 callee: ExprOrSuper::Expr(callee),
                                 ..
                             }) => match &**callee {
-                                Expr::Ident(Ident { sym, .. }) => *sym != *"_jsx",
+                                Expr::Ident(Ident { sym, .. }) => {
+                                    *sym != *"_jsx" && *sym != *"_jsxDEV"
+                                }
                                 _ => true,
                             },
                             _ => true,
@@ -288,10 +309,24 @@ where
                     }
                 }
 
+                let mut args = vec![fragment.as_arg(), props_obj.as_arg()];
+                if self.development {
+                    // `key` and `isStaticChildren`
+                    args.push(undefined(DUMMY_SP).as_arg());
+                    args.push(
+                        Lit::Bool(Bool {
+                            span: DUMMY_SP,
+                            value: use_jsxs,
+                        })
+                        .as_arg(),
+                    );
+                    self.push_source_self(span, &mut args);
+                }
+
                 Expr::Call(CallExpr {
                     span,
                     callee: jsx.as_callee(),
-                    args: vec![fragment.as_arg(), props_obj.as_arg()],
+                    args,
                     type_args: None,
                 })
             }
@@ -344,6 +379,10 @@ where
                     self.import_create_element
                         .get_or_insert_with(|| private_ident!("_createElement"))
                         .clone()
+                } else if self.development {
+                    self.import_jsx_dev
+                        .get_or_insert_with(|| private_ident!("_jsxDEV"))
+                        .clone()
                 } else if use_jsxs {
                     self.import_jsxs
                         .get_or_insert_with(|| private_ident!("_jsxs"))
@@ -367,6 +406,14 @@ where
                             //
                             match attr.name {
                                 JSXAttrName::Ident(i) => {
+                                    // Added by `jsx_src` and `jsx_self`, which don't know
+                                    // the runtime of the file. They are computed below.
+                                    if self.development
+                                        && (&*i.sym == "__source" || &*i.sym == "__self")
+                                    {
+                                        continue;
+                                    }
+
                                     //
                                     if !use_create_element && i.sym == js_word!("key") {
                                         key = attr
@@ -488,13 +535,58 @@ where
 
                 self.top_level_node = top_level_node;
 
+                if !self.development {
+                    return Expr::Call(CallExpr {
+                        span,
+                        callee: jsx.as_callee(),
+                        args: once(name.as_arg())
+                            .chain(once(props_obj.as_arg()))
+                            .chain(key)
+                            .collect(),
+                        type_args: Default::default(),
+                    });
+                }
+
+                if use_create_element {
+                    // `createElement` receives them as props.
+                    for (prop, value) in once(("__source", self.source_obj(span)))
+                        .chain(once(("__self", self.self_arg())))
+                    {
+                        if let Some(value) = value {
+                            props_obj
+                                .props
+                                .push(PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                                    key: PropName::Ident(quote_ident!(prop)),
+                                    value,
+                                }))));
+                        }
+                    }
+
+                    return Expr::Call(CallExpr {
+                        span,
+                        callee: jsx.as_callee(),
+                        args: vec![name.as_arg(), props_obj.as_arg()],
+                        type_args: Default::default(),
+                    });
+                }
+
+                // jsxDEV(type, props, key, isStaticChildren, source, self)
+                let mut args = vec![
+                    name.as_arg(),
+                    props_obj.as_arg(),
+                    key.unwrap_or_else(|| undefined(DUMMY_SP).as_arg()),
+                    Lit::Bool(Bool {
+                        span: DUMMY_SP,
+                        value: use_jsxs,
+                    })
+                    .as_arg(),
+                ];
+                self.push_source_self(span, &mut args);
+
                 Expr::Call(CallExpr {
                     span,
                     callee: jsx.as_callee(),
-                    args: once(name.as_arg())
-                        .chain(once(props_obj.as_arg()))
-                        .chain(key)
-                        .collect(),
+                    args,
                     type_args: Default::default(),
                 })
             }
@@ -679,6 +771,69 @@ where
         }
     }
 
+    /// `{ fileName, lineNumber, columnNumber }` of `span`.
+    /// `this`, if it can be used at the current position.
+    fn self_arg(&self) -> Option<Box<Expr>> {
+        if self.this_allowed {
+            Some(Box::new(Expr::This(ThisExpr { span: DUMMY_SP })))
+        } else {
+            None
+        }
+    }
+
+    /// Appends `source` and `self` arguments of `jsxDEV`.
+    fn push_source_self(&self, span: Span, args: &mut Vec<ExprOrSpread>) {
+        match (self.source_obj(span), self.self_arg()) {
+            (Some(source), Some(self_arg)) => {
+                args.push(source.as_arg());
+                args.push(self_arg.as_arg());
+            }
+            (Some(source), None) => args.push(source.as_arg()),
+            (None, Some(self_arg)) => {
+                args.push(undefined(DUMMY_SP).as_arg());
+                args.push(self_arg.as_arg());
+            }
+            (None, None) => {}
+        }
+    }
+
+    fn source_obj(&self, span: Span) -> Option<Box<Expr>> {
+        if span.is_dummy() {
+            return None;
+        }
+
+        let loc = self.cm.lookup_char_pos(span.lo);
+        let prop = |key: &str, value: Expr| {
+            PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                key: PropName::Ident(quote_ident!(key)),
+                value: Box::new(value),
+            })))
+        };
+        let num = |value: usize| {
+            Expr::Lit(Lit::Num(Number {
+                span: DUMMY_SP,
+                value: value as _,
+            }))
+        };
+
+        Some(Box::new(Expr::Object(ObjectLit {
+            span: DUMMY_SP,
+            props: vec![
+                prop(
+                    "fileName",
+                    Expr::Lit(Lit::Str(Str {
+                        span: DUMMY_SP,
+                        value: loc.file.name.to_string().into(),
+                        has_escape: false,
+                        kind: Default::default(),
+                    })),
+                ),
+                prop("lineNumber", num(loc.line)),
+                prop("columnNumber", num(loc.col.0 + 1)),
+            ],
+        })))
+    }
+
     fn attr_to_prop(&mut self, a: JSXAttr) -> Prop {
         let key = to_prop_name(a.name);
         let value = a
@@ -827,7 +982,7 @@ where
                         specifiers: vec![specifier],
                         src: Str {
                             span: DUMMY_SP,
-                            value: "react".into(),
+                            value: self.import_source.clone(),
                             has_escape: false,
                             kind: Default::default(),
                         },
@@ -851,6 +1006,15 @@ where
                     local,
                     imported: Some(quote_ident!("jsxs")),
                 }))
+                .chain(
+                    self.import_jsx_dev
+                        .take()
+                        .map(|local| ImportNamedSpecifier {
+                            span: DUMMY_SP,
+                            local,
+                            imported: Some(quote_ident!("jsxDEV")),
+                        }),
+                )
                 .chain(
                     self.import_fragment
                         .take()
@@ -871,7 +1035,11 @@ where
                         specifiers: imports,
                         src: Str {
                             span: DUMMY_SP,
-                            value: format!("{}/jsx-runtime", self.import_source).into(),
+                            value: if self.development {
+                                format!("{}/jsx-dev-runtime", self.import_source).into()
+                            } else {
+                                format!("{}/jsx-runtime", self.import_source).into()
+                            },
                             has_escape: false,
                             kind: Default::default(),
                         },
@@ -883,6 +1051,23 @@ where
         }
     }
 
+    fn visit_mut_class(&mut self, c: &mut Class) {
+        c.decorators.visit_mut_with(self);
+        c.super_class.visit_mut_with(self);
+
+        let old = self.in_derived_class;
+        self.in_derived_class = c.super_class.is_some();
+        c.body.visit_mut_with(self);
+        self.in_derived_class = old;
+    }
+
+    fn visit_mut_constructor(&mut self, c: &mut Constructor) {
+        let old = self.this_allowed;
+        self.this_allowed = !self.in_derived_class;
+        c.visit_mut_children_with(self);
+        self.this_allowed = old;
+    }
+
     fn visit_mut_expr(&mut self, expr: &mut Expr) {
         let top_level_node = self.top_level_node;
         let mut did_work = false;
@@ -918,6 +1103,13 @@ where
         self.top_level_node = top_level_node;
     }
 
+    fn visit_mut_function(&mut self, f: &mut Function) {
+        let old = self.this_allowed;
+        self.this_allowed = true;
+        f.visit_mut_children_with(self);
+        self.this_allowed = old;
+    }
+
     fn visit_mut_member_expr(&mut self, e: &mut MemberExpr) {
         e.obj.visit_mut_with(self);
         if e.computed {
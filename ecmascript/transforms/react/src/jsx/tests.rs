@@ -1227,6 +1227,33 @@ test!(
      melody'), ' listen to a randomly generated melody');"
 );
 
+test!(
+    ::swc_ecma_parser::Syntax::Es(::swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| crate::react(
+        t.cm.clone(),
+        Some(t.comments.clone()),
+        Options {
+            runtime: Some(Runtime::Automatic),
+            development: true,
+            ..Default::default()
+        }
+    ),
+    development_classic_runtime_pragma,
+    "/** @jsxRuntime classic */
+var x = <div />;",
+    "/** @jsxRuntime classic */
+var x = React.createElement('div', {
+    __source: {
+        fileName: 'input.js',
+        lineNumber: 2
+    },
+    __self: this
+});"
+);
+
 // regression_2775
 test!(
     // Module
@@ -26,11 +26,13 @@ pub fn react<C>(cm: Lrc<SourceMap>, comments: Option<C>, mut options: Options) -
 where
     C: Comments + Clone,
 {
-    let Options { development, .. } = options;
-
+    let development = options.development;
     let refresh_options = mem::replace(&mut options.refresh, None);
 
     chain!(
+        // `__source` and `__self` are added for any runtime, as it can be changed
+        // by `@jsxRuntime`. `jsx` converts them into arguments of `jsxDEV` for the
+        // automatic runtime.
         jsx_src(development, cm.clone()),
         jsx_self(development),
         refresh(development, refresh_options, cm.clone(), comments.clone()),
@@ -62,6 +62,24 @@ impl<'a> Tester<'a> {
         }
     }
 
+    /// Parses `src` as a module and returns the result of `op`.
+    ///
+    /// This is useful to test analyses, which return values instead of
+    /// transforming modules.
+    pub fn run_with_module<F, T>(file_name: &str, syntax: Syntax, src: &str, op: F) -> T
+    where
+        F: FnOnce(&mut Tester<'_>, Module) -> T,
+    {
+        let mut res = None;
+        Tester::run(|tester| {
+            let module = tester.with_parser(file_name, syntax, src, |p| p.parse_module())?;
+
+            res = Some(op(tester, module));
+            Ok(())
+        });
+        res.unwrap()
+    }
+
     pub(crate) fn run_captured<F, T>(op: F) -> (Option<T>, NormalizedOutput)
     where
         F: FnOnce(&mut Tester<'_>) -> Result<T, ()>,
@@ -4645,6 +4645,7 @@ test!(
     |_| decorators(Config {
         legacy: true,
         emit_metadata: true,
+        ..Default::default()
     }),
     legacy_metadata_generics_base,
     "@Decorate
@@ -4661,12 +4662,12 @@ test!(
     ) {}
   }",
     r#"
-    var _class, _dec, _dec1, _dec2;
-var _dec3 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:paramtypes", [
+    var _class, _dec, _dec1, _dec2, _dec3;
+var _dec4 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:paramtypes", [
     typeof Generic === "undefined" ? Object : Generic,
     typeof Generic === "undefined" ? Object : Generic
-]), _dec4 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Function);
-let MyClass = _class = Decorate(_class = _dec4(_class = _dec3(((_class = class MyClass {
+]), _dec5 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Function);
+let MyClass = _class = Decorate(_class = _dec5(_class = _dec4(((_class = class MyClass {
     constructor(private generic: Generic<A>, generic2: Generic<A, B>){
     }
     method(generic: Inter<A>, generic2: InterGen<A, B>) {
@@ -4676,11 +4677,12 @@ let MyClass = _class = Decorate(_class = _dec4(_class = _dec3(((_class = class M
 }, _dec1 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Function), _dec2 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:paramtypes", [
     typeof Inter === "undefined" ? Object : Inter,
     typeof InterGen === "undefined" ? Object : InterGen
-]), _applyDecoratedDescriptor(_class.prototype, "method", [
+]), _dec3 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:returntype", void 0), _applyDecoratedDescriptor(_class.prototype, "method", [
     Run,
     _dec,
     _dec1,
-    _dec2
+    _dec2,
+    _dec3
 ], Object.getOwnPropertyDescriptor(_class.prototype, "method"), _class.prototype), _class)) || _class) || _class) || _class;
 "#
 );
@@ -4690,6 +4692,7 @@ test!(
     |_| decorators(Config {
         legacy: true,
         emit_metadata: true,
+        ..Default::default()
     }),
     legacy_metadata_generics_1,
     "@Decorate
@@ -4716,6 +4719,7 @@ test!(
     |_| decorators(Config {
         legacy: true,
         emit_metadata: true,
+        ..Default::default()
     }),
     legacy_metadata_nest_injection,
     "import { AppService } from './app.service';
@@ -4748,14 +4752,14 @@ test!(
         return res.redirect(state.returnUrl ?? '/')
       }
     }",
-    r#"var _class, _descriptor, _descriptor1, _dec, _dec1, _dec2, _dec3, _dec4, _dec5, _dec6, _dec7;
+    r#"var _class, _descriptor, _descriptor1, _dec, _dec1, _dec2, _dec3, _dec4, _dec5, _dec6, _dec7, _dec8, _dec9;
     import { AppService } from "./app.service";
     import { Session, Res } from "@nestjs/common";
     import * as express from "express";
-    var _dec8 = Inject(), _dec9 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", typeof AppService === "undefined" ? Object : AppService), _dec10 = Inject(), _dec11 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", typeof AppService === "undefined" ? Object : AppService), _dec12 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:paramtypes", [
+    var _dec10 = Inject(), _dec11 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", typeof AppService === "undefined" ? Object : AppService), _dec12 = Inject(), _dec13 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", typeof AppService === "undefined" ? Object : AppService), _dec14 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:paramtypes", [
         typeof AppService === "undefined" ? Object : AppService
-    ]), _dec13 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Function), _dec14 = Controller();
-    export let AppController = _class = _dec14(_class = _dec13(_class = _dec12(((_class = class AppController {
+    ]), _dec15 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Function), _dec16 = Controller();
+    export let AppController = _class = _dec16(_class = _dec15(_class = _dec14(((_class = class AppController {
         constructor(private appService: AppService){
             _initializerDefineProperty(this, "appService", _descriptor, this);
             _initializerDefineProperty(this, "appService2", _descriptor1, this);
@@ -4771,38 +4775,40 @@ test!(
             return res.redirect(state.returnUrl ?? "/");
         }
     }) || _class, _descriptor = _applyDecoratedDescriptor(_class.prototype, "appService", [
-        _dec8,
-        _dec9
+        _dec10,
+        _dec11
     ], {
         configurable: true,
         enumerable: true,
         writable: true,
         initializer: void 0,
     }), _descriptor1 = _applyDecoratedDescriptor(_class.prototype, "appService2", [
-        _dec10,
-        _dec11
+        _dec12,
+        _dec13
     ], {
         configurable: true,
         enumerable: true,
         writable: true,
         initializer: void 0,
-    }), _dec = Get(), _dec1 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Function), _dec2 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:paramtypes", []), _applyDecoratedDescriptor(_class.prototype, "getHello", [
+    }), _dec = Get(), _dec1 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Function), _dec2 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:paramtypes", []), _dec3 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:returntype", String), _applyDecoratedDescriptor(_class.prototype, "getHello", [
         _dec,
         _dec1,
-        _dec2
-    ], Object.getOwnPropertyDescriptor(_class.prototype, "getHello"), _class.prototype), _dec3 = Get("/callback"), _dec4 = function(target, key) {
+        _dec2,
+        _dec3
+    ], Object.getOwnPropertyDescriptor(_class.prototype, "getHello"), _class.prototype), _dec4 = Get("/callback"), _dec5 = function(target, key) {
         return Res()(target, key, 0);
-    }, _dec5 = function(target, key) {
+    }, _dec6 = function(target, key) {
         return Session()(target, key, 1);
-    }, _dec6 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Function), _dec7 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:paramtypes", [
+    }, _dec7 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Function), _dec8 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:paramtypes", [
         typeof express === "undefined" || typeof express.Response === "undefined" ? Object : express.Response,
         typeof express === "undefined" || typeof express.Express === "undefined" || typeof express.Express.Session === "undefined" ? Object : express.Express.Session
-    ]), _applyDecoratedDescriptor(_class.prototype, "callback", [
-        _dec3,
+    ]), _dec9 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:returntype", void 0), _applyDecoratedDescriptor(_class.prototype, "callback", [
         _dec4,
         _dec5,
         _dec6,
-        _dec7
+        _dec7,
+        _dec8,
+        _dec9
     ], Object.getOwnPropertyDescriptor(_class.prototype, "callback"), _class.prototype), _class)) || _class) || _class) || _class;"#
 );
 
@@ -4811,6 +4817,7 @@ test!(
     |_| decorators(Config {
         legacy: true,
         emit_metadata: true,
+        ..Default::default()
     }),
     legacy_metadata_parameter_decorated_types,
     "class Injected {}
@@ -4842,27 +4849,27 @@ test!(
       method(@inject() param: string) {}
     }",
     r##"
-    var _class, _class1, _dec, _dec1, _dec2, _dec3, _dec4, _dec5, _dec6, _dec7, _class2, _dec8, _dec9, _dec10, _dec11;
+    var _class, _class1, _dec, _dec1, _dec2, _dec3, _dec4, _dec5, _dec6, _dec7, _dec8, _dec9, _class2, _dec10, _dec11, _dec12, _dec13, _dec14;
     class Injected {
     }
-    var _dec12 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:paramtypes", [
+    var _dec15 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:paramtypes", [
         typeof Injected === "undefined" ? Object : Injected
-    ]), _dec13 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Function), _dec14 = function(target, key) {
+    ]), _dec16 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Function), _dec17 = function(target, key) {
         return inject()(target, undefined, 0);
     };
-    let MyClass = _class = _dec14(_class = _dec13(_class = _dec12((_class = class MyClass {
+    let MyClass = _class = _dec17(_class = _dec16(_class = _dec15((_class = class MyClass {
         constructor(parameter: Injected){
         }
     }) || _class) || _class) || _class) || _class;
-    var _dec15 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:paramtypes", [
+    var _dec18 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:paramtypes", [
         typeof Injected === "undefined" ? Object : Injected,
         typeof Injected === "undefined" ? Object : Injected
-    ]), _dec16 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Function), _dec17 = function(target, key) {
+    ]), _dec19 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Function), _dec20 = function(target, key) {
         return inject("KIND")(target, undefined, 1);
-    }, _dec18 = function(target, key) {
+    }, _dec21 = function(target, key) {
         return inject()(target, undefined, 0);
     };
-    let MyOtherClass = _class1 = _dec18(_class1 = _dec17(_class1 = _dec16(_class1 = _dec15(((_class1 = class MyOtherClass {
+    let MyOtherClass = _class1 = _dec21(_class1 = _dec20(_class1 = _dec19(_class1 = _dec18(((_class1 = class MyOtherClass {
         constructor(private readonly parameter: Injected, otherParam: Injected){
         }
         methodUndecorated(param: string, otherParam) {
@@ -4874,46 +4881,49 @@ test!(
     }, _dec1 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Function), _dec2 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:paramtypes", [
         String,
         void 0
-    ]), _applyDecoratedDescriptor(_class1.prototype, "methodUndecorated", [
+    ]), _dec3 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:returntype", void 0), _applyDecoratedDescriptor(_class1.prototype, "methodUndecorated", [
         _dec,
         _dec1,
-        _dec2
-    ], Object.getOwnPropertyDescriptor(_class1.prototype, "methodUndecorated"), _class1.prototype), _dec3 = decorate("named"), _dec4 = function(target, key) {
+        _dec2,
+        _dec3
+    ], Object.getOwnPropertyDescriptor(_class1.prototype, "methodUndecorated"), _class1.prototype), _dec4 = decorate("named"), _dec5 = function(target, key) {
         return inject()(target, key, 0);
-    }, _dec5 = function(target, key) {
+    }, _dec6 = function(target, key) {
         return arg()(target, key, 1);
-    }, _dec6 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Function), _dec7 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:paramtypes", [
+    }, _dec7 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Function), _dec8 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:paramtypes", [
         typeof Injected === "undefined" ? Object : Injected,
         typeof Schema === "undefined" ? Object : Schema
-    ]), _applyDecoratedDescriptor(_class1.prototype, "method", [
-        _dec3,
+    ]), _dec9 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:returntype", void 0), _applyDecoratedDescriptor(_class1.prototype, "method", [
         _dec4,
         _dec5,
         _dec6,
-        _dec7
+        _dec7,
+        _dec8,
+        _dec9
     ], Object.getOwnPropertyDescriptor(_class1.prototype, "method"), _class1.prototype), _class1)) || _class1) || _class1) || _class1) || _class1;
-    var _dec19 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:paramtypes", [
+    var _dec22 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:paramtypes", [
         typeof Injected === "undefined" ? Object : Injected,
         typeof Injected === "undefined" ? Object : Injected
-    ]), _dec20 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Function), _dec21 = function(target, key) {
+    ]), _dec23 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Function), _dec24 = function(target, key) {
         return inject()(target, undefined, 1);
-    }, _dec22 = function(target, key) {
+    }, _dec25 = function(target, key) {
         return inject()(target, undefined, 0);
     };
-    let DecoratedClass = _class2 = Decorate(_class2 = _dec22(_class2 = _dec21(_class2 = _dec20(_class2 = _dec19(((_class2 = class DecoratedClass {
+    let DecoratedClass = _class2 = Decorate(_class2 = _dec25(_class2 = _dec24(_class2 = _dec23(_class2 = _dec22(((_class2 = class DecoratedClass {
         constructor(private readonly module: Injected, otherModule: Injected){
         }
         method(param: string) {
         }
-    }) || _class2, _dec8 = decorate("example"), _dec9 = function(target, key) {
+    }) || _class2, _dec10 = decorate("example"), _dec11 = function(target, key) {
         return inject()(target, key, 0);
-    }, _dec10 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Function), _dec11 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:paramtypes", [
+    }, _dec12 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Function), _dec13 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:paramtypes", [
         String
-    ]), _applyDecoratedDescriptor(_class2.prototype, "method", [
-        _dec8,
-        _dec9,
+    ]), _dec14 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:returntype", void 0), _applyDecoratedDescriptor(_class2.prototype, "method", [
         _dec10,
-        _dec11
+        _dec11,
+        _dec12,
+        _dec13,
+        _dec14
     ], Object.getOwnPropertyDescriptor(_class2.prototype, "method"), _class2.prototype), _class2)) || _class2) || _class2) || _class2) || _class2) || _class2;
     "##
 );
@@ -4923,6 +4933,7 @@ test!(
     |_| decorators(Config {
         legacy: true,
         emit_metadata: true,
+        ..Default::default()
     }),
     legacy_metadata_type_serialization,
     "import { Service } from './service';
@@ -4993,11 +5004,11 @@ test!(
         p0: string = 'abc'
       ) {}
     }",
-    r##"var _class, _dec, _dec1, _dec2, _dec3, _dec4, _dec5, _dec6, _dec7, _dec8;
+    r##"var _class, _dec, _dec1, _dec2, _dec3, _dec4, _dec5, _dec6, _dec7, _dec8, _dec9, _dec10, _dec11;
 import { Service } from "./service";
 import { Decorate } from "./Decorate";
 const sym = Symbol();
-var _dec9 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:paramtypes", [
+var _dec12 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:paramtypes", [
     typeof String === "undefined" ? Object : String,
     typeof Number === "undefined" ? Object : Number,
     Number,
@@ -5016,9 +5027,9 @@ var _dec9 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "funct
     Function,
     Boolean,
     Boolean,
-    void 0
-]), _dec10 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Function), _dec11 = Decorate();
-let Sample = _class = _dec11(_class = _dec10(_class = _dec9(((_class = class Sample {
+    String
+]), _dec13 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Function), _dec14 = Decorate();
+let Sample = _class = _dec14(_class = _dec13(_class = _dec12(((_class = class Sample {
     constructor(private p0: String, p1: Number, p2: 10, p3: "ABC", p4: boolean, p5: string, p6: number, p7: Object, p8: () => any, p9: "abc" | "def", p10: String | Number, p11: Function, p12: null, p13: undefined, p14: any, p15: (abc: any) => void, p16: false, p17: true, p18: string = "abc"){
     }
     method(p0: Symbol, p1: typeof sym, p2: string | null, p3: never, p4: string | never, p5: (string | null), p6: Maybe<string>, p7: Object | string, p8: string & MyStringType, p9: string[], p10: [string, number], p11: void, p12: this is number, p13: null | undefined, p14: (string | (string | null)), p15: Object, p16: any, p17: bigint) {
@@ -5032,10 +5043,10 @@ let Sample = _class = _dec11(_class = _dec10(_class = _dec9(((_class = class Sam
 }, _dec1 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Function), _dec2 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:paramtypes", [
     typeof Symbol === "undefined" ? Object : Symbol,
     Object,
+    String,
     void 0,
-    void 0,
-    void 0,
-    void 0,
+    String,
+    String,
     typeof Maybe === "undefined" ? Object : Maybe,
     Object,
     Object,
@@ -5044,28 +5055,31 @@ let Sample = _class = _dec11(_class = _dec10(_class = _dec9(((_class = class Sam
     void 0,
     Boolean,
     void 0,
-    Object,
+    String,
     typeof Object === "undefined" ? Object : Object,
     Object,
-    Number
-]), _applyDecoratedDescriptor(_class.prototype, "method", [
+    typeof BigInt === "undefined" ? Object : BigInt
+]), _dec3 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:returntype", void 0), _applyDecoratedDescriptor(_class.prototype, "method", [
     Decorate,
     _dec,
     _dec1,
-    _dec2
-], Object.getOwnPropertyDescriptor(_class.prototype, "method"), _class.prototype), _dec3 = Decorate(), _dec4 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Function), _dec5 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:paramtypes", [
-    void 0,
+    _dec2,
+    _dec3
+], Object.getOwnPropertyDescriptor(_class.prototype, "method"), _class.prototype), _dec4 = Decorate(), _dec5 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Function), _dec6 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:paramtypes", [
+    typeof Decorate === "undefined" || typeof Decorate.Name === "undefined" ? Object : Decorate.Name,
     typeof Decorate === "undefined" || typeof Decorate.Name === "undefined" ? Object : Decorate.Name
-]), _applyDecoratedDescriptor(_class.prototype, "method2", [
-    _dec3,
+]), _dec7 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:returntype", void 0), _applyDecoratedDescriptor(_class.prototype, "method2", [
     _dec4,
-    _dec5
-], Object.getOwnPropertyDescriptor(_class.prototype, "method2"), _class.prototype), _dec6 = Decorate(), _dec7 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Function), _dec8 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:paramtypes", [
-    void 0
-]), _applyDecoratedDescriptor(_class.prototype, "assignments", [
+    _dec5,
     _dec6,
-    _dec7,
-    _dec8
+    _dec7
+], Object.getOwnPropertyDescriptor(_class.prototype, "method2"), _class.prototype), _dec8 = Decorate(), _dec9 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Function), _dec10 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:paramtypes", [
+    String
+]), _dec11 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:returntype", void 0), _applyDecoratedDescriptor(_class.prototype, "assignments", [
+    _dec8,
+    _dec9,
+    _dec10,
+    _dec11
 ], Object.getOwnPropertyDescriptor(_class.prototype, "assignments"), _class.prototype), _class)) || _class) || _class) || _class;"##,
     ok_if_code_eq
 );
@@ -5075,6 +5089,196 @@ test!(
     |_| decorators(Config {
         legacy: true,
         emit_metadata: true,
+        ..Default::default()
+    }),
+    legacy_metadata_type_references,
+    "import type { Options } from './options';
+
+    enum Num { A, B }
+    enum Str { A = 'a' }
+
+    class Service {
+      @Inject() num: Num | null;
+      @Inject() str: Str.A;
+      @Inject() options: Options;
+      @Inject() list: Array<string>;
+
+      @Get()
+      async find(this: Service, ...ids: string[]): Promise<Options> {}
+    }",
+    r#"var _class, _descriptor, _descriptor1, _descriptor2, _descriptor3, _dec, _dec1, _dec2, _dec3;
+    import type { Options } from "./options";
+    enum Num {
+        A,
+        B
+    }
+    enum Str {
+        A = "a"
+    }
+    var _dec4 = Inject(), _dec5 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Number), _dec6 = Inject(), _dec7 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", String), _dec8 = Inject(), _dec9 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Object), _dec10 = Inject(), _dec11 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Array);
+    let Service = ((_class = class Service {
+        async find(this: Service, ...ids: string[]): Promise<Options> {
+        }
+        constructor(){
+            _initializerDefineProperty(this, "num", _descriptor, this);
+            _initializerDefineProperty(this, "str", _descriptor1, this);
+            _initializerDefineProperty(this, "options", _descriptor2, this);
+            _initializerDefineProperty(this, "list", _descriptor3, this);
+        }
+    }) || _class, _descriptor = _applyDecoratedDescriptor(_class.prototype, "num", [
+        _dec4,
+        _dec5
+    ], {
+        configurable: true,
+        enumerable: true,
+        writable: true,
+        initializer: void 0
+    }), _descriptor1 = _applyDecoratedDescriptor(_class.prototype, "str", [
+        _dec6,
+        _dec7
+    ], {
+        configurable: true,
+        enumerable: true,
+        writable: true,
+        initializer: void 0
+    }), _descriptor2 = _applyDecoratedDescriptor(_class.prototype, "options", [
+        _dec8,
+        _dec9
+    ], {
+        configurable: true,
+        enumerable: true,
+        writable: true,
+        initializer: void 0
+    }), _descriptor3 = _applyDecoratedDescriptor(_class.prototype, "list", [
+        _dec10,
+        _dec11
+    ], {
+        configurable: true,
+        enumerable: true,
+        writable: true,
+        initializer: void 0
+    }), _dec = Get(), _dec1 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Function), _dec2 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:paramtypes", [
+        String
+    ]), _dec3 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:returntype", Promise), _applyDecoratedDescriptor(_class.prototype, "find", [
+        _dec,
+        _dec1,
+        _dec2,
+        _dec3
+    ], Object.getOwnPropertyDescriptor(_class.prototype, "find"), _class.prototype), _class);"#
+);
+
+test!(
+    ts(),
+    |_| decorators(Config {
+        legacy: true,
+        emit_metadata: true,
+        ..Default::default()
+    }),
+    legacy_metadata_imported_and_literal_types,
+    "import { Color } from './color';
+    import type { Options } from './options';
+
+    class Service {
+      @Inject() color: Color | null;
+      @Inject() mode: 'a' | 'b';
+      @Inject() count: 1 | 2;
+      @Inject() flag: true;
+      @Inject() names: string[];
+      @Inject() pair: [string, number];
+      @Inject() options: Options;
+
+      @Get()
+      async load(options: Options, colors: Color[]): Promise<Color> {}
+    }",
+    r#"var _class, _descriptor, _descriptor1, _descriptor2, _descriptor3, _descriptor4, _descriptor5, _descriptor6, _dec, _dec1, _dec2, _dec3;
+    import { Color } from "./color";
+    import type { Options } from "./options";
+    var _dec4 = Inject(), _dec5 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", typeof Color === "undefined" ? Object : Color), _dec6 = Inject(), _dec7 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", String), _dec8 = Inject(), _dec9 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Number), _dec10 = Inject(), _dec11 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Boolean), _dec12 = Inject(), _dec13 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Array), _dec14 = Inject(), _dec15 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Array), _dec16 = Inject(), _dec17 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Object);
+    let Service = ((_class = class Service {
+        async load(options: Options, colors: Color[]): Promise<Color> {
+        }
+        constructor(){
+            _initializerDefineProperty(this, "color", _descriptor, this);
+            _initializerDefineProperty(this, "mode", _descriptor1, this);
+            _initializerDefineProperty(this, "count", _descriptor2, this);
+            _initializerDefineProperty(this, "flag", _descriptor3, this);
+            _initializerDefineProperty(this, "names", _descriptor4, this);
+            _initializerDefineProperty(this, "pair", _descriptor5, this);
+            _initializerDefineProperty(this, "options", _descriptor6, this);
+        }
+    }) || _class, _descriptor = _applyDecoratedDescriptor(_class.prototype, "color", [
+        _dec4,
+        _dec5
+    ], {
+        configurable: true,
+        enumerable: true,
+        writable: true,
+        initializer: void 0
+    }), _descriptor1 = _applyDecoratedDescriptor(_class.prototype, "mode", [
+        _dec6,
+        _dec7
+    ], {
+        configurable: true,
+        enumerable: true,
+        writable: true,
+        initializer: void 0
+    }), _descriptor2 = _applyDecoratedDescriptor(_class.prototype, "count", [
+        _dec8,
+        _dec9
+    ], {
+        configurable: true,
+        enumerable: true,
+        writable: true,
+        initializer: void 0
+    }), _descriptor3 = _applyDecoratedDescriptor(_class.prototype, "flag", [
+        _dec10,
+        _dec11
+    ], {
+        configurable: true,
+        enumerable: true,
+        writable: true,
+        initializer: void 0
+    }), _descriptor4 = _applyDecoratedDescriptor(_class.prototype, "names", [
+        _dec12,
+        _dec13
+    ], {
+        configurable: true,
+        enumerable: true,
+        writable: true,
+        initializer: void 0
+    }), _descriptor5 = _applyDecoratedDescriptor(_class.prototype, "pair", [
+        _dec14,
+        _dec15
+    ], {
+        configurable: true,
+        enumerable: true,
+        writable: true,
+        initializer: void 0
+    }), _descriptor6 = _applyDecoratedDescriptor(_class.prototype, "options", [
+        _dec16,
+        _dec17
+    ], {
+        configurable: true,
+        enumerable: true,
+        writable: true,
+        initializer: void 0
+    }), _dec = Get(), _dec1 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Function), _dec2 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:paramtypes", [
+        Object,
+        Array
+    ]), _dec3 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:returntype", Promise), _applyDecoratedDescriptor(_class.prototype, "load", [
+        _dec,
+        _dec1,
+        _dec2,
+        _dec3
+    ], Object.getOwnPropertyDescriptor(_class.prototype, "load"), _class.prototype), _class);"#
+);
+
+test!(
+    ts(),
+    |_| decorators(Config {
+        legacy: true,
+        emit_metadata: true,
+        ..Default::default()
     }),
     issue_1160_1,
     "
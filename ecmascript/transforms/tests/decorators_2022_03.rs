@@ -0,0 +1,421 @@
+#![cfg(feature = "swc_ecma_transforms_proposal")]
+
+use swc_ecma_parser::{EsConfig, Syntax, TsConfig};
+use swc_ecma_transforms_proposal::decorators::{decorators, Config, DecoratorVersion};
+use swc_ecma_transforms_testing::{test, test_exec};
+use swc_ecma_visit::Fold;
+
+fn syntax() -> Syntax {
+    Syntax::Es(EsConfig {
+        decorators: true,
+        class_props: true,
+        class_private_props: true,
+        class_private_methods: true,
+        ..Default::default()
+    })
+}
+
+fn ts() -> Syntax {
+    Syntax::Typescript(TsConfig {
+        decorators: true,
+        ..Default::default()
+    })
+}
+
+fn tr() -> impl Fold {
+    decorators(Config {
+        version: DecoratorVersion::V202203,
+        ..Default::default()
+    })
+}
+
+test!(
+    syntax(),
+    |_| tr(),
+    auto_accessors,
+    r#"
+class A {
+    accessor a = 1;
+    static accessor #b;
+}
+"#,
+    r#"
+class A {
+    #a_accessor_storage = 1;
+    get a() {
+        return this.#a_accessor_storage;
+    }
+    set a(v) {
+        this.#a_accessor_storage = v;
+    }
+    static #b_accessor_storage;
+    static get #b() {
+        return this.#b_accessor_storage;
+    }
+    static set #b(v) {
+        this.#b_accessor_storage = v;
+    }
+}
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    members,
+    r#"
+class A {
+    @dec m() {}
+    @dec static s() {}
+    @dec x = 1;
+    @dec accessor y;
+    @dec #z;
+}
+"#,
+    r##"
+var _init_x, _init_y, _init_z, _initProto;
+class A {
+    static #_initDecorators = [_init_x, _init_y, _init_z, _initProto] = _applyDecs2203(this, [
+        [[dec], 2, "m"],
+        [[dec], 10, "s"],
+        [[dec], 0, "x"],
+        [[dec], 1, "y"],
+        [[dec], 16, "#z", (o) => o.#z, (o, v) => o.#z = v],
+    ]);
+    m() {}
+    static s() {}
+    x = (_initProto(this), _init_x(this, 1));
+    #y_accessor_storage = _init_y(this);
+    get y() {
+        return this.#y_accessor_storage;
+    }
+    set y(v) {
+        this.#y_accessor_storage = v;
+    }
+    #z = _init_z(this);
+}
+"##
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    private_accessor,
+    r#"
+class A {
+    @dec static accessor #a = 1;
+}
+"#,
+    r##"
+var _init_a, _get_a, _set_a;
+class A {
+    static #_initDecorators = [_init_a, _get_a, _set_a] = _applyDecs2203(this, [
+        [[dec], 25, "#a", (o) => o.#a_accessor_storage, (o, v) => o.#a_accessor_storage = v],
+    ]);
+    static #a_accessor_storage = _init_a(this, 1);
+    static get #a() {
+        return _get_a(this);
+    }
+    static set #a(v) {
+        _set_a(this, v);
+    }
+}
+"##
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    computed_key,
+    r#"
+class A extends B {
+    @dec [foo()]() {}
+}
+"#,
+    r#"
+var _computedKey, _initProto;
+class A extends B {
+    static #_initDecorators = [_initProto] = _applyDecs2203(this, [
+        [[dec], 2, _computedKey],
+    ]);
+    [_computedKey = _toPropertyKey(foo())]() {}
+    constructor(...args) {
+        super(...args);
+        _initProto(this);
+    }
+}
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    class_decorators,
+    r#"
+@dec
+@foo()
+class A {}
+"#,
+    r#"
+var _dec;
+let A = (_dec = foo(), _applyClassDecs2203(class A {}, [dec, _dec]));
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    export_default,
+    r#"
+export default @dec class {}
+"#,
+    r#"
+export default _applyClassDecs2203(class {}, [dec]);
+"#
+);
+
+test!(
+    ts(),
+    |_| decorators(Config {
+        version: DecoratorVersion::V202203,
+        emit_metadata: true,
+        ..Default::default()
+    }),
+    metadata,
+    r#"
+@dec
+class A {
+    constructor(a: string, b: Foo) {}
+    @dec m(x: number): string {}
+    @dec p: Foo | null;
+}
+"#,
+    r#"
+var _init_p, _initProto;
+let A = _applyClassDecs2203(class A {
+    static #_initDecorators = (
+        _tsMetadata(this.prototype, "m", [
+            ["design:type", Function],
+            ["design:paramtypes", [Number]],
+            ["design:returntype", String],
+        ]),
+        _tsMetadata(this.prototype, "p", [
+            ["design:type", typeof Foo === "undefined" ? Object : Foo],
+        ]),
+        _tsMetadata(this, void 0, [
+            ["design:type", Function],
+            ["design:paramtypes", [String, typeof Foo === "undefined" ? Object : Foo]],
+        ]),
+        [_init_p, _initProto] = _applyDecs2203(this, [
+            [[dec], 2, "m"],
+            [[dec], 0, "p"],
+        ])
+    );
+    constructor(a: string, b: Foo) {}
+    m(x: number): string {}
+    p: Foo | null = (_initProto(this), _init_p(this));
+}, [dec]);
+"#
+);
+
+test!(
+    ts(),
+    |_| decorators(Config {
+        version: DecoratorVersion::V202203,
+        emit_metadata: true,
+        ..Default::default()
+    }),
+    metadata_type_references,
+    r#"
+import { Color } from "./color";
+import type { Options } from "./options";
+
+class A {
+    @dec color: Color | null;
+    @dec mode: "a" | "b";
+    @dec count: 1 | 2;
+    @dec names: string[];
+    @dec options: Options;
+    @dec async load(options: Options): Promise<Color> {}
+}
+"#,
+    r#"
+import { Color } from "./color";
+import type { Options } from "./options";
+var _init_color, _init_mode, _init_count, _init_names, _init_options, _initProto;
+class A {
+    static #_initDecorators = (
+        _tsMetadata(this.prototype, "color", [
+            ["design:type", typeof Color === "undefined" ? Object : Color],
+        ]),
+        _tsMetadata(this.prototype, "mode", [["design:type", String]]),
+        _tsMetadata(this.prototype, "count", [["design:type", Number]]),
+        _tsMetadata(this.prototype, "names", [["design:type", Array]]),
+        _tsMetadata(this.prototype, "options", [["design:type", Object]]),
+        _tsMetadata(this.prototype, "load", [
+            ["design:type", Function],
+            ["design:paramtypes", [Object]],
+            ["design:returntype", Promise],
+        ]),
+        [_init_color, _init_mode, _init_count, _init_names, _init_options, _initProto] =
+            _applyDecs2203(this, [
+                [[dec], 0, "color"],
+                [[dec], 0, "mode"],
+                [[dec], 0, "count"],
+                [[dec], 0, "names"],
+                [[dec], 0, "options"],
+                [[dec], 2, "load"],
+            ])
+    );
+    color: Color | null = (_initProto(this), _init_color(this));
+    mode: "a" | "b" = _init_mode(this);
+    count: 1 | 2 = _init_count(this);
+    names: string[] = _init_names(this);
+    options: Options = _init_options(this);
+    async load(options: Options): Promise<Color> {}
+}
+"#
+);
+
+test_exec!(
+    syntax(),
+    |_| tr(),
+    context_exec,
+    r##"
+const contexts = [];
+function record(value, context) {
+    contexts.push(context);
+}
+
+@record
+class A {
+    @record static s() {}
+    @record m() {}
+    @record get g() { return 1; }
+    @record x;
+    @record accessor y;
+    @record #z;
+}
+
+expect(contexts.map(c => [c.kind, c.name, c.static, c.private])).toEqual([
+    ["method", "s", true, false],
+    ["method", "m", false, false],
+    ["getter", "g", false, false],
+    ["field", "x", false, false],
+    ["accessor", "y", false, false],
+    ["field", "#z", false, true],
+    ["class", "A", undefined, undefined],
+]);
+
+const a = new A();
+a.y = 2;
+expect(contexts[4].access.get(a)).toBe(2);
+contexts[5].access.set(a, 3);
+expect(contexts[5].access.get(a)).toBe(3);
+expect(contexts[5].access.has(a)).toBe(true);
+expect(contexts[5].access.has({})).toBe(false);
+"##
+);
+
+test_exec!(
+    syntax(),
+    |_| tr(),
+    replace_exec,
+    r#"
+function double(value, { kind }) {
+    if (kind === "field") {
+        return v => v * 2;
+    }
+    if (kind === "accessor") {
+        return {
+            get() {
+                return value.get.call(this) * 2;
+            },
+            init: v => v + 1,
+        };
+    }
+    return function (...args) {
+        return value.apply(this, args) * 2;
+    };
+}
+
+class A {
+    @double x = 1;
+    @double accessor y = 1;
+    @double m() { return 3; }
+}
+
+const a = new A();
+expect(a.x).toBe(2);
+expect(a.y).toBe(4);
+a.y = 5;
+expect(a.y).toBe(10);
+expect(a.m()).toBe(6);
+expect(Object.getOwnPropertyNames(A.prototype)).toEqual(["constructor", "y", "m"]);
+"#
+);
+
+test_exec!(
+    syntax(),
+    |_| tr(),
+    initializers_exec,
+    r#"
+const log = [];
+function init(value, { kind, name, addInitializer }) {
+    addInitializer(function () {
+        log.push(`${kind} ${name} ${typeof this}`);
+    });
+}
+
+class A {
+    @init static s() {}
+    @init m() {}
+    field = log.push("field");
+}
+
+expect(log).toEqual(["method s function"]);
+new A();
+expect(log).toEqual(["method s function", "method m object", "field"]);
+
+class Replaced {}
+function replace(cls, { addInitializer }) {
+    addInitializer(function () {
+        log.push(`replaced ${this === Replaced}`);
+    });
+    return Replaced;
+}
+
+@replace
+class B {}
+
+expect(B).toBe(Replaced);
+expect(log[3]).toBe("replaced true");
+"#
+);
+
+test_exec!(
+    syntax(),
+    |_| tr(),
+    metadata_exec,
+    r#"
+function meta(value, { name, metadata }) {
+    metadata[name] = true;
+}
+
+@meta
+class A {
+    @meta a;
+    @meta static b() {}
+}
+
+class B extends A {
+    @meta c;
+}
+
+const key = Symbol.metadata || Symbol.for("Symbol.metadata");
+expect(A[key]).toEqual({ a: true, b: true, A: true });
+expect(B[key].c).toBe(true);
+expect(B[key].a).toBe(true);
+expect(Object.getPrototypeOf(B[key])).toBe(A[key]);
+"#
+);
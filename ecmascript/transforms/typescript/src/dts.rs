@@ -0,0 +1,876 @@
+//! Declaration (`.d.ts`) emitter.
+//!
+//! Types are never inferred, so every declaration which ends up in the output
+//! should have an explicit type annotation (like `isolatedDeclarations` of
+//! `tsc`). The only exceptions are primitive literals, type assertions and
+//! function expressions with annotated parameters and return type.
+
+use fxhash::FxHashSet;
+use swc_atoms::JsWord;
+use swc_common::{Span, Spanned, DUMMY_SP};
+use swc_ecma_ast::*;
+
+/// A declaration whose type cannot be emitted without type inference.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DtsError {
+    pub span: Span,
+    pub msg: &'static str,
+}
+
+/// Creates a declaration-only module from `module`.
+///
+/// Bodies of functions, initializers of variables and non-exported values are
+/// removed and declarations are marked as `declare`.
+pub fn dts(module: &Module) -> Result<Module, Vec<DtsError>> {
+    let mut d = Dts::default();
+    let mut body = d.module_items(&module.body);
+
+    if !d.errors.is_empty() {
+        return Err(d.errors);
+    }
+
+    // Non-exported declarations of an ambient module are exported implicitly
+    // unless the module contains `export {}`.
+    let is_module = module
+        .body
+        .iter()
+        .any(|item| matches!(item, ModuleItem::ModuleDecl(..)));
+    if is_module && d.has_local_decl {
+        body.push(ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(
+            NamedExport {
+                span: DUMMY_SP,
+                specifiers: vec![],
+                src: None,
+                type_only: false,
+                asserts: None,
+            },
+        )));
+    }
+
+    Ok(Module {
+        span: module.span,
+        body,
+        shebang: None,
+    })
+}
+
+#[derive(Default)]
+struct Dts {
+    errors: Vec<DtsError>,
+    /// `true` if a declaration without `export` is emitted at the top level.
+    has_local_decl: bool,
+    /// `declare` is not allowed in an ambient namespace.
+    in_namespace: bool,
+}
+
+impl Dts {
+    fn error(&mut self, span: Span, msg: &'static str) {
+        self.errors.push(DtsError { span, msg });
+    }
+
+    fn module_items(&mut self, items: &[ModuleItem]) -> Vec<ModuleItem> {
+        let overloaded = overloaded_fns(items);
+        let exported = locally_exported(items);
+
+        items
+            .iter()
+            .filter_map(|item| match item {
+                ModuleItem::ModuleDecl(decl) => self
+                    .module_decl(decl, &overloaded)
+                    .map(ModuleItem::ModuleDecl),
+                ModuleItem::Stmt(Stmt::Decl(decl)) => {
+                    let is_exported = match decl {
+                        Decl::Fn(f) => exported.contains(&f.ident.sym),
+                        Decl::Var(v) => v.decls.iter().any(|d| match &d.name {
+                            Pat::Ident(i) => exported.contains(&i.id.sym),
+                            _ => false,
+                        }),
+                        _ => false,
+                    };
+                    let decl = self.decl(decl, is_exported, &overloaded)?;
+                    if !self.in_namespace {
+                        self.has_local_decl = true;
+                    }
+                    Some(ModuleItem::Stmt(Stmt::Decl(decl)))
+                }
+                ModuleItem::Stmt(..) => None,
+            })
+            .collect()
+    }
+
+    fn module_decl(
+        &mut self,
+        decl: &ModuleDecl,
+        overloaded: &FxHashSet<JsWord>,
+    ) -> Option<ModuleDecl> {
+        match decl {
+            ModuleDecl::Import(..)
+            | ModuleDecl::ExportNamed(..)
+            | ModuleDecl::ExportAll(..)
+            | ModuleDecl::TsImportEquals(..)
+            | ModuleDecl::TsNamespaceExport(..) => Some(decl.clone()),
+
+            ModuleDecl::ExportDecl(export) => Some(ModuleDecl::ExportDecl(ExportDecl {
+                span: export.span,
+                decl: self.decl(&export.decl, true, overloaded)?,
+            })),
+
+            ModuleDecl::ExportDefaultDecl(export) => {
+                let decl = match &export.decl {
+                    DefaultDecl::Class(c) => DefaultDecl::Class(ClassExpr {
+                        ident: c.ident.clone(),
+                        class: self.class(&c.class),
+                    }),
+                    DefaultDecl::Fn(f) => DefaultDecl::Fn(FnExpr {
+                        ident: f.ident.clone(),
+                        function: self.function(&f.function, f.function.body.is_some()),
+                    }),
+                    DefaultDecl::TsInterfaceDecl(..) => export.decl.clone(),
+                };
+
+                Some(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+                    span: export.span,
+                    decl,
+                }))
+            }
+
+            ModuleDecl::ExportDefaultExpr(ExportDefaultExpr { expr, .. })
+            | ModuleDecl::TsExportAssignment(TsExportAssignment { expr, .. }) => match &**expr {
+                Expr::Ident(..) => Some(decl.clone()),
+                _ => {
+                    self.error(
+                        expr.span(),
+                        "default export must be an identifier to emit declarations",
+                    );
+                    None
+                }
+            },
+        }
+    }
+
+    /// Returns [None] if `decl` should not be emitted.
+    fn decl(
+        &mut self,
+        decl: &Decl,
+        exported: bool,
+        overloaded: &FxHashSet<JsWord>,
+    ) -> Option<Decl> {
+        let declare = !self.in_namespace;
+
+        match decl {
+            Decl::Class(ClassDecl { declare: true, .. })
+            | Decl::Fn(FnDecl { declare: true, .. })
+            | Decl::Var(VarDecl { declare: true, .. })
+            | Decl::TsEnum(TsEnumDecl { declare: true, .. })
+            | Decl::TsModule(TsModuleDecl { declare: true, .. })
+            | Decl::TsInterface(..)
+            | Decl::TsTypeAlias(..) => Some(decl.clone()),
+
+            Decl::Class(c) => Some(Decl::Class(ClassDecl {
+                ident: c.ident.clone(),
+                declare,
+                class: self.class(&c.class),
+            })),
+
+            // Values can't be referenced from the declaration file.
+            Decl::Fn(..) | Decl::Var(..) if !exported => None,
+
+            Decl::Fn(f) => {
+                // Only signatures of an overloaded function are emitted.
+                if f.function.body.is_some() && overloaded.contains(&f.ident.sym) {
+                    return None;
+                }
+
+                Some(Decl::Fn(FnDecl {
+                    ident: f.ident.clone(),
+                    declare,
+                    function: self.function(&f.function, f.function.body.is_some()),
+                }))
+            }
+
+            Decl::Var(v) => Some(Decl::Var(VarDecl {
+                span: v.span,
+                kind: v.kind,
+                declare,
+                decls: v.decls.iter().map(|d| self.var_declarator(v, d)).collect(),
+            })),
+
+            Decl::TsEnum(e) => {
+                for m in &e.members {
+                    if let Some(init) = &m.init {
+                        if !is_const_lit(init) {
+                            self.error(
+                                init.span(),
+                                "enum member initializer must be a literal to emit declarations",
+                            );
+                        }
+                    }
+                }
+
+                Some(Decl::TsEnum(TsEnumDecl {
+                    declare,
+                    ..e.clone()
+                }))
+            }
+
+            Decl::TsModule(m) => {
+                if m.global || matches!(m.id, TsModuleName::Str(..)) {
+                    return Some(decl.clone());
+                }
+
+                let old = self.in_namespace;
+                self.in_namespace = true;
+                let body = m.body.as_ref().map(|body| self.ns_body(body));
+                self.in_namespace = old;
+
+                Some(Decl::TsModule(TsModuleDecl {
+                    span: m.span,
+                    declare,
+                    global: m.global,
+                    id: m.id.clone(),
+                    body,
+                }))
+            }
+        }
+    }
+
+    fn ns_body(&mut self, body: &TsNamespaceBody) -> TsNamespaceBody {
+        match body {
+            TsNamespaceBody::TsModuleBlock(block) => {
+                TsNamespaceBody::TsModuleBlock(TsModuleBlock {
+                    span: block.span,
+                    body: self.module_items(&block.body),
+                })
+            }
+            TsNamespaceBody::TsNamespaceDecl(decl) => {
+                TsNamespaceBody::TsNamespaceDecl(TsNamespaceDecl {
+                    span: decl.span,
+                    declare: false,
+                    global: decl.global,
+                    id: decl.id.clone(),
+                    body: Box::new(self.ns_body(&decl.body)),
+                })
+            }
+        }
+    }
+
+    fn var_declarator(&mut self, v: &VarDecl, d: &VarDeclarator) -> VarDeclarator {
+        let name = match &d.name {
+            Pat::Ident(i) if i.type_ann.is_none() => {
+                let type_ann = d
+                    .init
+                    .as_ref()
+                    .and_then(|init| self.infer(init, v.kind != VarDeclKind::Const));
+                if type_ann.is_none() {
+                    self.error(
+                        d.span,
+                        "variable must have an explicit type annotation to emit declarations",
+                    );
+                }
+
+                Pat::Ident(BindingIdent {
+                    id: i.id.clone(),
+                    type_ann,
+                })
+            }
+            Pat::Ident(..) => d.name.clone(),
+            _ => {
+                self.error(
+                    d.name.span(),
+                    "destructuring declaration can't be emitted; declare each binding with an \
+                     explicit type",
+                );
+                d.name.clone()
+            }
+        };
+
+        VarDeclarator {
+            span: d.span,
+            name,
+            init: None,
+            definite: false,
+        }
+    }
+
+    fn function(&mut self, f: &Function, return_type_required: bool) -> Function {
+        if return_type_required && f.return_type.is_none() {
+            self.error(
+                f.span,
+                "function must have an explicit return type annotation to emit declarations",
+            );
+        }
+
+        Function {
+            params: f
+                .params
+                .iter()
+                .map(|p| Param {
+                    span: p.span,
+                    decorators: vec![],
+                    pat: self.param(&p.pat),
+                })
+                .collect(),
+            decorators: vec![],
+            span: f.span,
+            body: None,
+            is_generator: false,
+            is_async: false,
+            type_params: f.type_params.clone(),
+            return_type: f.return_type.clone(),
+        }
+    }
+
+    /// Removes default values from `pat`, while making it optional.
+    fn param(&mut self, pat: &Pat) -> Pat {
+        match pat {
+            Pat::Assign(p) => {
+                let mut left = strip_defaults(&p.left);
+                match &mut left {
+                    Pat::Ident(i) => {
+                        i.id.optional = true;
+                        if i.type_ann.is_none() {
+                            i.type_ann = p.type_ann.clone().or_else(|| self.infer(&p.right, true));
+                        }
+                        if i.type_ann.is_none() {
+                            self.error(
+                                p.span,
+                                "parameter must have an explicit type annotation to emit \
+                                 declarations",
+                            );
+                        }
+                    }
+                    Pat::Array(ArrayPat {
+                        optional, type_ann, ..
+                    })
+                    | Pat::Object(ObjectPat {
+                        optional, type_ann, ..
+                    }) => {
+                        *optional = true;
+                        if type_ann.is_none() {
+                            *type_ann = p.type_ann.clone();
+                        }
+                        if type_ann.is_none() {
+                            self.error(
+                                p.span,
+                                "parameter must have an explicit type annotation to emit \
+                                 declarations",
+                            );
+                        }
+                    }
+                    _ => {}
+                }
+                left
+            }
+            _ => {
+                let type_ann = match pat {
+                    Pat::Ident(p) => &p.type_ann,
+                    Pat::Array(p) => &p.type_ann,
+                    Pat::Object(p) => &p.type_ann,
+                    Pat::Rest(p) => &p.type_ann,
+                    _ => return pat.clone(),
+                };
+                if type_ann.is_none() {
+                    self.error(
+                        pat.span(),
+                        "parameter must have an explicit type annotation to emit declarations",
+                    );
+                }
+                strip_defaults(pat)
+            }
+        }
+    }
+
+    /// Creates a type of `e` if it can be known without type inference.
+    ///
+    /// If `widen` is true, literal types are widened to their primitive types.
+    fn infer(&mut self, e: &Expr, widen: bool) -> Option<TsTypeAnn> {
+        let span = e.span();
+        let keyword = |kind| TsType::TsKeywordType(TsKeywordType { span, kind });
+        let lit = |lit| TsType::TsLitType(TsLitType { span, lit });
+
+        let ty = match e {
+            Expr::Paren(ParenExpr { expr, .. }) => return self.infer(expr, widen),
+
+            Expr::Lit(Lit::Str(..)) | Expr::Tpl(..) if widen => {
+                keyword(TsKeywordTypeKind::TsStringKeyword)
+            }
+            Expr::Lit(Lit::Num(..))
+            | Expr::Unary(UnaryExpr {
+                op: op!(unary, "-"),
+                ..
+            }) if widen && is_const_lit(e) => keyword(TsKeywordTypeKind::TsNumberKeyword),
+            Expr::Lit(Lit::Bool(..)) if widen => keyword(TsKeywordTypeKind::TsBooleanKeyword),
+            Expr::Lit(Lit::BigInt(..)) if widen => keyword(TsKeywordTypeKind::TsBigIntKeyword),
+
+            Expr::Lit(Lit::Str(s)) => lit(TsLit::Str(s.clone())),
+            Expr::Lit(Lit::Num(n)) => lit(TsLit::Number(n.clone())),
+            Expr::Lit(Lit::Bool(b)) => lit(TsLit::Bool(b.clone())),
+            Expr::Lit(Lit::BigInt(b)) => lit(TsLit::BigInt(b.clone())),
+            Expr::Unary(UnaryExpr {
+                op: op!(unary, "-"),
+                arg,
+                ..
+            }) => match &**arg {
+                Expr::Lit(Lit::Num(n)) => lit(TsLit::Number(Number {
+                    span,
+                    value: -n.value,
+                })),
+                _ => return None,
+            },
+
+            Expr::TsAs(TsAsExpr { type_ann, .. })
+            | Expr::TsTypeAssertion(TsTypeAssertion { type_ann, .. }) => {
+                return Some(TsTypeAnn {
+                    span,
+                    type_ann: type_ann.clone(),
+                })
+            }
+
+            Expr::Arrow(f) => {
+                let params = f.params.iter().map(|p| self.param(p)).collect();
+                self.fn_type(f.span, params, &f.type_params, &f.return_type)?
+            }
+            Expr::Fn(FnExpr { function: f, .. }) => {
+                let params = f.params.iter().map(|p| self.param(&p.pat)).collect();
+                self.fn_type(f.span, params, &f.type_params, &f.return_type)?
+            }
+
+            _ => return None,
+        };
+
+        Some(TsTypeAnn {
+            span,
+            type_ann: Box::new(ty),
+        })
+    }
+
+    fn fn_type(
+        &mut self,
+        span: Span,
+        params: Vec<Pat>,
+        type_params: &Option<TsTypeParamDecl>,
+        return_type: &Option<TsTypeAnn>,
+    ) -> Option<TsType> {
+        let return_type = match return_type {
+            Some(v) => v.clone(),
+            None => {
+                self.error(
+                    span,
+                    "function must have an explicit return type annotation to emit declarations",
+                );
+                return None;
+            }
+        };
+
+        let params = params
+            .into_iter()
+            .filter_map(|p| match p {
+                Pat::Ident(p) => Some(TsFnParam::Ident(p)),
+                Pat::Array(p) => Some(TsFnParam::Array(p)),
+                Pat::Rest(p) => Some(TsFnParam::Rest(p)),
+                Pat::Object(p) => Some(TsFnParam::Object(p)),
+                _ => None,
+            })
+            .collect();
+
+        Some(TsType::TsFnOrConstructorType(
+            TsFnOrConstructorType::TsFnType(TsFnType {
+                span,
+                params,
+                type_params: type_params.clone(),
+                type_ann: return_type,
+            }),
+        ))
+    }
+
+    fn class(&mut self, c: &Class) -> Class {
+        if let Some(super_class) = &c.super_class {
+            match &**super_class {
+                Expr::Ident(..) | Expr::Member(..) => {}
+                _ => self.error(
+                    super_class.span(),
+                    "extends clause must be an identifier or a property access to emit \
+                     declarations",
+                ),
+            }
+        }
+
+        // Only signatures of overloaded methods are emitted.
+        let mut overloaded = FxHashSet::default();
+        let mut ctor_overloaded = false;
+        for member in &c.body {
+            match member {
+                ClassMember::Constructor(Constructor { body: None, .. }) => ctor_overloaded = true,
+                ClassMember::Method(ClassMethod {
+                    key,
+                    function: Function { body: None, .. },
+                    ..
+                }) => {
+                    if let Some(name) = prop_name_sym(key) {
+                        overloaded.insert(name);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let mut body = vec![];
+        let mut has_private_name = false;
+        let mut private_members = FxHashSet::default();
+
+        for member in &c.body {
+            match member {
+                ClassMember::Constructor(ctor) => {
+                    if ctor.body.is_some() && ctor_overloaded {
+                        continue;
+                    }
+                    let is_private = ctor.accessibility == Some(Accessibility::Private);
+
+                    let mut params = vec![];
+                    for param in &ctor.params {
+                        let pat = match param {
+                            ParamOrTsParamProp::TsParamProp(p) => {
+                                let (id, type_ann, default) = match &p.param {
+                                    TsParamPropParam::Ident(i) => (&i.id, &i.type_ann, None),
+                                    TsParamPropParam::Assign(a) => match &*a.left {
+                                        Pat::Ident(i) => (
+                                            &i.id,
+                                            if i.type_ann.is_some() {
+                                                &i.type_ann
+                                            } else {
+                                                &a.type_ann
+                                            },
+                                            Some(&a.right),
+                                        ),
+                                        _ => continue,
+                                    },
+                                };
+
+                                let prop_type_ann =
+                                    if p.accessibility == Some(Accessibility::Private) {
+                                        None
+                                    } else {
+                                        type_ann.clone().or_else(|| {
+                                            default.and_then(|e| self.infer(e, !p.readonly))
+                                        })
+                                    };
+                                body.push(ClassMember::ClassProp(ClassProp {
+                                    span: p.span,
+                                    key: Box::new(Expr::Ident(Ident {
+                                        optional: false,
+                                        ..id.clone()
+                                    })),
+                                    value: None,
+                                    type_ann: prop_type_ann,
+                                    is_static: false,
+                                    decorators: vec![],
+                                    computed: false,
+                                    accessibility: p.accessibility,
+                                    is_abstract: false,
+                                    is_optional: id.optional,
+                                    is_override: p.is_override,
+                                    readonly: p.readonly,
+                                    declare: false,
+                                    definite: false,
+                                }));
+
+                                match &p.param {
+                                    TsParamPropParam::Ident(i) => Pat::Ident(i.clone()),
+                                    TsParamPropParam::Assign(a) => Pat::Assign(a.clone()),
+                                }
+                            }
+                            ParamOrTsParamProp::Param(p) => p.pat.clone(),
+                        };
+
+                        if !is_private {
+                            params.push(ParamOrTsParamProp::Param(Param {
+                                span: pat.span(),
+                                decorators: vec![],
+                                pat: self.param(&pat),
+                            }));
+                        }
+                    }
+
+                    body.push(ClassMember::Constructor(Constructor {
+                        span: ctor.span,
+                        key: ctor.key.clone(),
+                        params,
+                        body: None,
+                        accessibility: ctor.accessibility,
+                        is_optional: ctor.is_optional,
+                    }));
+                }
+
+                ClassMember::Method(m) => {
+                    if m.function.body.is_some() {
+                        if let Some(name) = prop_name_sym(&m.key) {
+                            if overloaded.contains(&name) {
+                                continue;
+                            }
+                        }
+                    }
+
+                    // Types of private members are not emitted.
+                    if m.accessibility == Some(Accessibility::Private) {
+                        if let Some(name) = prop_name_sym(&m.key) {
+                            if !private_members.insert(name) {
+                                continue;
+                            }
+                        }
+
+                        let (key, computed) = prop_name_to_expr(&m.key);
+                        body.push(ClassMember::ClassProp(ClassProp {
+                            span: m.span,
+                            key,
+                            value: None,
+                            type_ann: None,
+                            is_static: m.is_static,
+                            decorators: vec![],
+                            computed,
+                            accessibility: m.accessibility,
+                            is_abstract: false,
+                            is_optional: false,
+                            is_override: m.is_override,
+                            readonly: false,
+                            declare: false,
+                            definite: false,
+                        }));
+                        continue;
+                    }
+
+                    let return_type_required =
+                        m.function.body.is_some() && m.kind != MethodKind::Setter;
+                    body.push(ClassMember::Method(ClassMethod {
+                        function: self.function(&m.function, return_type_required),
+                        ..m.clone()
+                    }));
+                }
+
+                ClassMember::ClassProp(p) => {
+                    let type_ann = if p.accessibility == Some(Accessibility::Private) {
+                        None
+                    } else if p.type_ann.is_some() {
+                        p.type_ann.clone()
+                    } else {
+                        let type_ann = p
+                            .value
+                            .as_ref()
+                            .and_then(|value| self.infer(value, !p.readonly));
+                        if type_ann.is_none() {
+                            self.error(
+                                p.span,
+                                "property must have an explicit type annotation to emit \
+                                 declarations",
+                            );
+                        }
+                        type_ann
+                    };
+
+                    body.push(ClassMember::ClassProp(ClassProp {
+                        value: None,
+                        type_ann,
+                        decorators: vec![],
+                        declare: false,
+                        definite: false,
+                        ..p.clone()
+                    }));
+                }
+
+                ClassMember::AutoAccessor(a) if a.key.is_public() => {
+                    let type_ann = if a.accessibility == Some(Accessibility::Private) {
+                        None
+                    } else if a.type_ann.is_some() {
+                        a.type_ann.clone()
+                    } else {
+                        let type_ann = a.value.as_ref().and_then(|value| self.infer(value, true));
+                        if type_ann.is_none() {
+                            self.error(
+                                a.span,
+                                "accessor must have an explicit type annotation to emit \
+                                 declarations",
+                            );
+                        }
+                        type_ann
+                    };
+
+                    body.push(ClassMember::AutoAccessor(AutoAccessor {
+                        value: None,
+                        type_ann,
+                        decorators: vec![],
+                        ..a.clone()
+                    }));
+                }
+
+                ClassMember::PrivateMethod(..)
+                | ClassMember::PrivateProp(..)
+                | ClassMember::AutoAccessor(..) => {
+                    has_private_name = true;
+                }
+
+                ClassMember::TsIndexSignature(..) => body.push(member.clone()),
+
+                ClassMember::Empty(..) => {}
+            }
+        }
+
+        // Like `tsc`, `#private;` is emitted to make the class nominal.
+        if has_private_name {
+            body.insert(
+                0,
+                ClassMember::PrivateProp(PrivateProp {
+                    span: DUMMY_SP,
+                    key: PrivateName {
+                        span: DUMMY_SP,
+                        id: Ident::new("private".into(), DUMMY_SP),
+                    },
+                    value: None,
+                    type_ann: None,
+                    is_static: false,
+                    decorators: vec![],
+                    computed: false,
+                    accessibility: None,
+                    is_abstract: false,
+                    is_optional: false,
+                    is_override: false,
+                    readonly: false,
+                    definite: false,
+                }),
+            );
+        }
+
+        Class {
+            span: c.span,
+            decorators: vec![],
+            body,
+            super_class: c.super_class.clone(),
+            is_abstract: c.is_abstract,
+            type_params: c.type_params.clone(),
+            super_type_params: c.super_type_params.clone(),
+            implements: c.implements.clone(),
+        }
+    }
+}
+
+/// Returns names of functions which have a signature without body.
+fn overloaded_fns(items: &[ModuleItem]) -> FxHashSet<JsWord> {
+    items
+        .iter()
+        .filter_map(|item| match item {
+            ModuleItem::Stmt(Stmt::Decl(Decl::Fn(f)))
+            | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                decl: Decl::Fn(f),
+                ..
+            })) if !f.declare && f.function.body.is_none() => Some(f.ident.sym.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Returns names of local bindings exported by `export { a }` or
+/// `export default a`.
+fn locally_exported(items: &[ModuleItem]) -> FxHashSet<JsWord> {
+    let mut names = FxHashSet::default();
+
+    for item in items {
+        match item {
+            ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(NamedExport {
+                specifiers,
+                src: None,
+                ..
+            })) => {
+                for s in specifiers {
+                    if let ExportSpecifier::Named(s) = s {
+                        names.insert(s.orig.sym.clone());
+                    }
+                }
+            }
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(ExportDefaultExpr {
+                expr,
+                ..
+            }))
+            | ModuleItem::ModuleDecl(ModuleDecl::TsExportAssignment(TsExportAssignment {
+                expr,
+                ..
+            })) => {
+                if let Expr::Ident(i) = &**expr {
+                    names.insert(i.sym.clone());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    names
+}
+
+fn prop_name_sym(key: &PropName) -> Option<JsWord> {
+    match key {
+        PropName::Ident(i) => Some(i.sym.clone()),
+        PropName::Str(s) => Some(s.value.clone()),
+        _ => None,
+    }
+}
+
+fn prop_name_to_expr(key: &PropName) -> (Box<Expr>, bool) {
+    match key {
+        PropName::Ident(i) => (Box::new(Expr::Ident(i.clone())), false),
+        PropName::Str(s) => (Box::new(Expr::Lit(Lit::Str(s.clone()))), false),
+        PropName::Num(n) => (Box::new(Expr::Lit(Lit::Num(n.clone()))), false),
+        PropName::BigInt(n) => (Box::new(Expr::Lit(Lit::BigInt(n.clone()))), false),
+        PropName::Computed(c) => (c.expr.clone(), true),
+    }
+}
+
+fn is_const_lit(e: &Expr) -> bool {
+    match e {
+        Expr::Lit(Lit::Str(..)) | Expr::Lit(Lit::Num(..)) => true,
+        Expr::Unary(UnaryExpr {
+            op: op!(unary, "-"),
+            arg,
+            ..
+        }) => matches!(&**arg, Expr::Lit(Lit::Num(..))),
+        Expr::Paren(ParenExpr { expr, .. }) => is_const_lit(expr),
+        _ => false,
+    }
+}
+
+/// Removes default values from a destructuring pattern.
+fn strip_defaults(pat: &Pat) -> Pat {
+    match pat {
+        Pat::Assign(p) => strip_defaults(&p.left),
+        Pat::Rest(p) => Pat::Rest(RestPat {
+            arg: Box::new(strip_defaults(&p.arg)),
+            ..p.clone()
+        }),
+        Pat::Array(p) => Pat::Array(ArrayPat {
+            elems: p
+                .elems
+                .iter()
+                .map(|e| e.as_ref().map(strip_defaults))
+                .collect(),
+            ..p.clone()
+        }),
+        Pat::Object(p) => Pat::Object(ObjectPat {
+            props: p
+                .props
+                .iter()
+                .map(|prop| match prop {
+                    ObjectPatProp::KeyValue(kv) => ObjectPatProp::KeyValue(KeyValuePatProp {
+                        key: kv.key.clone(),
+                        value: Box::new(strip_defaults(&kv.value)),
+                    }),
+                    ObjectPatProp::Assign(a) => ObjectPatProp::Assign(AssignPatProp {
+                        value: None,
+                        ..a.clone()
+                    }),
+                    ObjectPatProp::Rest(r) => ObjectPatProp::Rest(RestPat {
+                        arg: Box::new(strip_defaults(&r.arg)),
+                        ..r.clone()
+                    }),
+                })
+                .collect(),
+            ..p.clone()
+        }),
+        _ => pat.clone(),
+    }
+}
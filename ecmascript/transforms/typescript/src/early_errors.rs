@@ -0,0 +1,384 @@
+//! Reports typescript code which `tsc` rejects without type information, like
+//! misplaced `abstract` members or overload signatures without an
+//! implementation.
+//!
+//! Errors reported by the parser are not reported again.
+
+use swc_atoms::{js_word, JsWord};
+use swc_common::{Span, Spanned, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{Node, Visit, VisitWith};
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Config {
+    /// The file is a declaration file (`.d.ts`), so everything in it is
+    /// ambient.
+    pub dts: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EarlyError {
+    pub span: Span,
+    pub msg: String,
+}
+
+/// Finds errors which `tsc` reports while checking declarations, but which
+/// don't require type information.
+///
+/// The module should not be transformed before being checked.
+pub fn check_early_errors(module: &Module, config: Config) -> Vec<EarlyError> {
+    let mut checker = Checker {
+        errors: vec![],
+        in_ambient: config.dts,
+    };
+    module.visit_with(&Invalid { span: DUMMY_SP } as _, &mut checker);
+
+    checker.errors
+}
+
+struct Checker {
+    errors: Vec<EarlyError>,
+    in_ambient: bool,
+}
+
+impl Checker {
+    fn report(&mut self, span: Span, msg: String) {
+        self.errors.push(EarlyError { span, msg })
+    }
+
+    /// Names which can't be used as the name of a type, like `string`.
+    fn check_type_name(&mut self, i: &Ident, kind: &str) {
+        match i.sym {
+            js_word!("any")
+            | js_word!("unknown")
+            | js_word!("never")
+            | js_word!("number")
+            | js_word!("bigint")
+            | js_word!("boolean")
+            | js_word!("string")
+            | js_word!("symbol")
+            | js_word!("void")
+            | js_word!("object")
+            | js_word!("undefined") => {
+                self.report(i.span, format!("{} name cannot be '{}'.", kind, i.sym));
+            }
+            _ => {}
+        }
+    }
+
+    /// Each overload signature of a function should be followed by another
+    /// signature or by the implementation.
+    fn check_fn_overloads(&mut self, fns: Vec<Option<&FnDecl>>) {
+        if self.in_ambient {
+            return;
+        }
+
+        for (i, f) in fns.iter().enumerate() {
+            let f = match f {
+                Some(f) if !f.declare && f.function.body.is_none() => f,
+                _ => continue,
+            };
+
+            match fns.get(i + 1) {
+                Some(Some(next)) if next.ident.sym == f.ident.sym => {}
+                Some(Some(next)) if next.function.body.is_some() => self.report(
+                    next.ident.span,
+                    format!("Function implementation name must be '{}'.", f.ident.sym),
+                ),
+                _ => self.report(
+                    f.ident.span,
+                    "Function implementation is missing or not immediately following the \
+                     declaration."
+                        .into(),
+                ),
+            }
+        }
+    }
+
+    /// Same as [Checker::check_fn_overloads], but for methods and
+    /// constructors.
+    fn check_method_overloads(&mut self, members: &[ClassMember]) {
+        for (i, member) in members.iter().enumerate() {
+            let next = members.get(i + 1);
+
+            match member {
+                ClassMember::Constructor(c) if c.body.is_none() => match next {
+                    Some(ClassMember::Constructor(..)) => {}
+                    _ => self.report(
+                        c.key.span(),
+                        "Constructor implementation is missing.".into(),
+                    ),
+                },
+                ClassMember::Method(m)
+                    if m.function.body.is_none() && !m.is_abstract && !m.is_optional =>
+                {
+                    let name = prop_name(&m.key);
+                    match next {
+                        Some(ClassMember::Method(next))
+                            if next.is_static == m.is_static
+                                && name.is_some()
+                                && prop_name(&next.key) == name => {}
+                        // Computed keys can't be compared.
+                        Some(ClassMember::Method(next))
+                            if name.is_none() || prop_name(&next.key).is_none() => {}
+                        Some(ClassMember::Method(next))
+                            if next.function.body.is_some() && prop_name(&next.key) != name =>
+                        {
+                            self.report(
+                                next.key.span(),
+                                format!(
+                                    "Function implementation name must be '{}'.",
+                                    name.unwrap()
+                                ),
+                            )
+                        }
+                        _ => self.report(
+                            m.key.span(),
+                            "Function implementation is missing or not immediately following the \
+                             declaration."
+                                .into(),
+                        ),
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn check_abstract_member(
+        &mut self,
+        class: &Class,
+        span: Span,
+        accessibility: Option<Accessibility>,
+        is_method: bool,
+    ) {
+        if !class.is_abstract {
+            self.report(
+                span,
+                if is_method {
+                    "Abstract methods can only appear within an abstract class.".into()
+                } else {
+                    "Abstract properties can only appear within an abstract class.".into()
+                },
+            );
+        }
+        if accessibility == Some(Accessibility::Private) {
+            self.report(
+                span,
+                "'private' modifier cannot be used with 'abstract' modifier.".into(),
+            );
+        }
+    }
+
+    /// `const` variables and `readonly` properties may have a literal
+    /// initializer in ambient contexts.
+    fn check_ambient_init(&mut self, init: &Expr, allow_literal: bool) {
+        if allow_literal {
+            if !is_literal(init) {
+                self.report(
+                    init.span(),
+                    "A 'const' initializer in an ambient context must be a string or numeric \
+                     literal or literal enum reference."
+                        .into(),
+                );
+            }
+            return;
+        }
+
+        self.report(
+            init.span(),
+            "Initializers are not allowed in ambient contexts.".into(),
+        );
+    }
+}
+
+impl Visit for Checker {
+    fn visit_class(&mut self, c: &Class, _: &dyn Node) {
+        if !self.in_ambient {
+            self.check_method_overloads(&c.body);
+        }
+
+        for member in &c.body {
+            match member {
+                ClassMember::Method(m) if m.is_abstract => {
+                    self.check_abstract_member(c, m.key.span(), m.accessibility, true);
+                    if m.function.body.is_some() {
+                        self.report(
+                            m.key.span(),
+                            format!(
+                                "Method '{}' cannot have an implementation because it is marked \
+                                 abstract.",
+                                prop_name(&m.key).unwrap_or_default()
+                            ),
+                        );
+                    }
+                }
+                ClassMember::PrivateMethod(m) if m.is_abstract => {
+                    self.report(
+                        m.key.span,
+                        "'abstract' modifier cannot be used with a private identifier.".into(),
+                    );
+                }
+                ClassMember::ClassProp(p) => {
+                    if p.is_abstract {
+                        self.check_abstract_member(c, p.key.span(), p.accessibility, false);
+                        if p.value.is_some() {
+                            self.report(
+                                p.key.span(),
+                                format!(
+                                    "Property '{}' cannot have an initializer because it is \
+                                     marked abstract.",
+                                    expr_name(&p.key).unwrap_or_default()
+                                ),
+                            );
+                        }
+                    }
+                    if let Some(value) = &p.value {
+                        if p.declare || self.in_ambient {
+                            self.check_ambient_init(value, p.readonly && self.in_ambient);
+                        }
+                    }
+                }
+                ClassMember::PrivateProp(p) if p.is_abstract => {
+                    self.report(
+                        p.key.span,
+                        "'abstract' modifier cannot be used with a private identifier.".into(),
+                    );
+                }
+                _ => {}
+            }
+        }
+
+        c.visit_children_with(self);
+    }
+
+    fn visit_class_decl(&mut self, n: &ClassDecl, _: &dyn Node) {
+        // `class any {}` is reported by the parser.
+        if n.ident.sym != js_word!("any") {
+            self.check_type_name(&n.ident, "Class");
+        }
+
+        let old = self.in_ambient;
+        self.in_ambient |= n.declare;
+        n.class.visit_with(n, self);
+        self.in_ambient = old;
+    }
+
+    fn visit_function(&mut self, f: &Function, _: &dyn Node) {
+        // Bodies of functions are not ambient, even if the function is in a
+        // class expression of a declaration file.
+        let old = self.in_ambient;
+        self.in_ambient = false;
+        f.visit_children_with(self);
+        self.in_ambient = old;
+    }
+
+    fn visit_module_items(&mut self, items: &[ModuleItem], parent: &dyn Node) {
+        self.check_fn_overloads(
+            items
+                .iter()
+                .map(|item| match item {
+                    ModuleItem::Stmt(Stmt::Decl(Decl::Fn(f)))
+                    | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                        decl: Decl::Fn(f),
+                        ..
+                    })) => Some(f),
+                    _ => None,
+                })
+                .collect(),
+        );
+
+        for item in items {
+            self.visit_module_item(item, parent);
+        }
+    }
+
+    fn visit_stmts(&mut self, stmts: &[Stmt], parent: &dyn Node) {
+        self.check_fn_overloads(
+            stmts
+                .iter()
+                .map(|stmt| match stmt {
+                    Stmt::Decl(Decl::Fn(f)) => Some(f),
+                    _ => None,
+                })
+                .collect(),
+        );
+
+        for stmt in stmts {
+            self.visit_stmt(stmt, parent);
+        }
+    }
+
+    fn visit_ts_enum_decl(&mut self, n: &TsEnumDecl, _: &dyn Node) {
+        self.check_type_name(&n.id, "Enum");
+        n.visit_children_with(self);
+    }
+
+    fn visit_ts_module_decl(&mut self, n: &TsModuleDecl, _: &dyn Node) {
+        let old = self.in_ambient;
+        self.in_ambient |= n.declare || n.global;
+        n.body.visit_with(n, self);
+        self.in_ambient = old;
+    }
+
+    fn visit_ts_type_alias_decl(&mut self, n: &TsTypeAliasDecl, _: &dyn Node) {
+        self.check_type_name(&n.id, "Type alias");
+        n.visit_children_with(self);
+    }
+
+    fn visit_ts_type_param(&mut self, n: &TsTypeParam, _: &dyn Node) {
+        self.check_type_name(&n.name, "Type parameter");
+        n.visit_children_with(self);
+    }
+
+    fn visit_var_decl(&mut self, n: &VarDecl, _: &dyn Node) {
+        if n.declare || self.in_ambient {
+            for d in &n.decls {
+                if let Some(init) = &d.init {
+                    self.check_ambient_init(init, n.kind == VarDeclKind::Const);
+                }
+            }
+        }
+
+        n.visit_children_with(self);
+    }
+}
+
+fn prop_name(n: &PropName) -> Option<JsWord> {
+    match n {
+        PropName::Ident(i) => Some(i.sym.clone()),
+        PropName::Str(s) => Some(s.value.clone()),
+        PropName::Num(n) => Some(n.value.to_string().into()),
+        PropName::BigInt(n) => Some(n.value.to_string().into()),
+        PropName::Computed(..) => None,
+    }
+}
+
+/// Name of a property key of [ClassProp].
+fn expr_name(e: &Expr) -> Option<JsWord> {
+    match e {
+        Expr::Ident(i) => Some(i.sym.clone()),
+        Expr::Lit(Lit::Str(s)) => Some(s.value.clone()),
+        Expr::Lit(Lit::Num(n)) => Some(n.value.to_string().into()),
+        _ => None,
+    }
+}
+
+fn is_literal(e: &Expr) -> bool {
+    match e {
+        Expr::Lit(Lit::Str(..)) | Expr::Lit(Lit::Num(..)) | Expr::Lit(Lit::BigInt(..)) => true,
+        Expr::Tpl(t) => t.exprs.is_empty(),
+        Expr::Unary(UnaryExpr {
+            op: op!(unary, "-"),
+            arg,
+            ..
+        }) => matches!(&**arg, Expr::Lit(Lit::Num(..)) | Expr::Lit(Lit::BigInt(..))),
+        // Enum members
+        Expr::Member(MemberExpr {
+            obj: ExprOrSuper::Expr(obj),
+            computed: false,
+            ..
+        }) => matches!(&**obj, Expr::Ident(..)),
+        _ => false,
+    }
+}
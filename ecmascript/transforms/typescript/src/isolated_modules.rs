@@ -0,0 +1,266 @@
+//! Reports code which can't be transpiled correctly file-by-file, like
+//! `tsc --isolatedModules`.
+
+use fxhash::{FxHashMap, FxHashSet};
+use swc_atoms::{js_word, JsWord};
+use swc_common::{Span, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_utils::{ident::IdentLike, Id};
+use swc_ecma_visit::{Node, Visit, VisitWith};
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Config {
+    /// The output is an ES module, i.e. it's not transformed by a module pass.
+    pub esm: bool,
+}
+
+/// Code which can't be transpiled correctly without type information of other
+/// files.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IsolatedModulesError {
+    pub span: Span,
+    pub msg: &'static str,
+}
+
+/// Finds code which `tsc --isolatedModules` reports.
+///
+/// Only information available in `module` is used, so a re-exported name is
+/// reported only if this file shows that it's a type, e.g. it's imported with
+/// `import type` or it's used only as a type.
+///
+/// Identifiers are compared with their syntax contexts, so `module` should be
+/// resolved for accurate results.
+pub fn check_isolated_modules(module: &Module, config: Config) -> Vec<IsolatedModulesError> {
+    let mut usage = Usage::default();
+    module.visit_with(&Invalid { span: DUMMY_SP } as _, &mut usage);
+
+    let mut errors = vec![];
+    let mut report = |span, msg| errors.push(IsolatedModulesError { span, msg });
+
+    if !module
+        .body
+        .iter()
+        .any(|item| matches!(item, ModuleItem::ModuleDecl(..)))
+    {
+        report(
+            module.span,
+            "a file must be a module when `isolatedModules` is enabled; add an import, an export \
+             or an empty `export {}`",
+        );
+    }
+
+    // Imported bindings, by `(src, imported)` and by local name.
+    let mut imports: FxHashMap<(JsWord, JsWord), &Ident> = Default::default();
+    let mut type_only_imports: FxHashSet<Id> = Default::default();
+    for item in &module.body {
+        if let ModuleItem::ModuleDecl(ModuleDecl::Import(import)) = item {
+            for s in &import.specifiers {
+                let (imported, local) = match s {
+                    ImportSpecifier::Named(s) => (
+                        s.imported.as_ref().unwrap_or(&s.local).sym.clone(),
+                        &s.local,
+                    ),
+                    ImportSpecifier::Default(s) => (js_word!("default"), &s.local),
+                    ImportSpecifier::Namespace(..) => continue,
+                };
+                if import.type_only {
+                    type_only_imports.insert(local.to_id());
+                }
+                imports.insert((import.src.value.clone(), imported), local);
+            }
+        }
+    }
+    let is_type = |local: &Ident| {
+        let id = local.to_id();
+        type_only_imports.contains(&id)
+            || (usage.types.contains(&id) && !usage.value_ids.contains(&id))
+    };
+
+    let has_other_exports = module.body.iter().any(|item| match item {
+        ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(NamedExport {
+            type_only: true, ..
+        })) => false,
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(..))
+        | ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(..))
+        | ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(..))
+        | ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(..))
+        | ModuleItem::ModuleDecl(ModuleDecl::ExportAll(..)) => true,
+        _ => false,
+    });
+
+    for item in &module.body {
+        match item {
+            ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(export)) if !export.type_only => {
+                for s in &export.specifiers {
+                    let s = match s {
+                        ExportSpecifier::Named(s) => s,
+                        _ => continue,
+                    };
+                    let local = match &export.src {
+                        Some(src) => imports.get(&(src.value.clone(), s.orig.sym.clone())),
+                        None => imports
+                            .values()
+                            .find(|local| local.to_id() == s.orig.to_id()),
+                    };
+                    if local.map_or(false, |local| is_type(*local)) {
+                        report(
+                            s.span,
+                            "re-exporting a type requires `export type` when `isolatedModules` is \
+                             enabled",
+                        );
+                    }
+                }
+            }
+
+            ModuleItem::ModuleDecl(ModuleDecl::TsExportAssignment(export)) => {
+                if config.esm {
+                    report(
+                        export.span,
+                        "`export =` can't be used when targeting ECMAScript modules; use `export \
+                         default` or another module format",
+                    );
+                }
+                if has_other_exports {
+                    report(
+                        export.span,
+                        "`export =` can't be used in a module with other exported elements",
+                    );
+                }
+            }
+
+            ModuleItem::ModuleDecl(ModuleDecl::TsImportEquals(import))
+                if config.esm && !import.is_type_only =>
+            {
+                if let TsModuleRef::TsExternalModuleRef(..) = import.module_ref {
+                    report(
+                        import.span,
+                        "`import = require()` can't be used when targeting ECMAScript modules; \
+                         use `import` or another module format",
+                    );
+                }
+            }
+
+            _ => {}
+        }
+    }
+
+    for i in &usage.values {
+        if usage.ambient_const_enums.contains(&i.to_id()) {
+            report(
+                i.span,
+                "ambient const enums can't be accessed when `isolatedModules` is enabled",
+            );
+        }
+    }
+
+    errors
+}
+
+#[derive(Default)]
+struct Usage {
+    /// References in value positions, in order.
+    values: Vec<Ident>,
+    /// Ids of `values` and of `typeof` queries.
+    value_ids: FxHashSet<Id>,
+    /// References in type positions.
+    types: FxHashSet<Id>,
+    ambient_const_enums: FxHashSet<Id>,
+    in_ambient: bool,
+}
+
+impl Usage {
+    fn value(&mut self, i: &Ident) {
+        self.value_ids.insert(i.to_id());
+        self.values.push(i.clone());
+    }
+}
+
+fn root_of(mut name: &TsEntityName) -> &Ident {
+    loop {
+        match name {
+            TsEntityName::TsQualifiedName(q) => name = &q.left,
+            TsEntityName::Ident(i) => return i,
+        }
+    }
+}
+
+impl Visit for Usage {
+    fn visit_expr(&mut self, e: &Expr, _: &dyn Node) {
+        match e {
+            Expr::Ident(i) => self.value(i),
+            _ => e.visit_children_with(self),
+        }
+    }
+
+    fn visit_jsx_element_name(&mut self, n: &JSXElementName, _: &dyn Node) {
+        match n {
+            // `<div>` is not a reference.
+            JSXElementName::Ident(i) if i.sym.starts_with(|c: char| c.is_ascii_uppercase()) => {
+                self.value(i)
+            }
+            _ => n.visit_children_with(self),
+        }
+    }
+
+    fn visit_jsx_object(&mut self, n: &JSXObject, _: &dyn Node) {
+        match n {
+            JSXObject::Ident(i) => self.value(i),
+            _ => n.visit_children_with(self),
+        }
+    }
+
+    fn visit_member_expr(&mut self, e: &MemberExpr, _: &dyn Node) {
+        e.obj.visit_with(e, self);
+        if e.computed {
+            e.prop.visit_with(e, self);
+        }
+    }
+
+    fn visit_prop(&mut self, p: &Prop, _: &dyn Node) {
+        match p {
+            Prop::Shorthand(i) => self.value(i),
+            _ => p.visit_children_with(self),
+        }
+    }
+
+    fn visit_prop_name(&mut self, n: &PropName, _: &dyn Node) {
+        if let PropName::Computed(c) = n {
+            c.visit_with(n, self);
+        }
+    }
+
+    fn visit_ts_entity_name(&mut self, n: &TsEntityName, _: &dyn Node) {
+        self.types.insert(root_of(n).to_id());
+    }
+
+    fn visit_ts_enum_decl(&mut self, n: &TsEnumDecl, _: &dyn Node) {
+        if n.is_const && (n.declare || self.in_ambient) {
+            self.ambient_const_enums.insert(n.id.to_id());
+        }
+        n.members.visit_with(n, self);
+    }
+
+    /// `B` of `import A = B.C` is a value.
+    fn visit_ts_import_equals_decl(&mut self, n: &TsImportEqualsDecl, _: &dyn Node) {
+        if let TsModuleRef::TsEntityName(name) = &n.module_ref {
+            self.value_ids.insert(root_of(name).to_id());
+        }
+    }
+
+    fn visit_ts_module_decl(&mut self, n: &TsModuleDecl, _: &dyn Node) {
+        let old = self.in_ambient;
+        self.in_ambient |= n.declare || n.global;
+        n.body.visit_with(n, self);
+        self.in_ambient = old;
+    }
+
+    /// `typeof A` is erased, but it shows that `A` is a value.
+    fn visit_ts_type_query(&mut self, n: &TsTypeQuery, _: &dyn Node) {
+        match &n.expr_name {
+            TsTypeQueryExpr::TsEntityName(name) => {
+                self.value_ids.insert(root_of(name).to_id());
+            }
+            TsTypeQueryExpr::Import(i) => i.visit_with(n, self),
+        }
+    }
+}
@@ -1,3 +1,6 @@
 pub use self::strip::strip;
 
+pub mod dts;
+pub mod early_errors;
+pub mod isolated_modules;
 pub mod strip;
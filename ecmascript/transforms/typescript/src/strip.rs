@@ -1,9 +1,13 @@
 use fxhash::FxHashMap;
 use fxhash::FxHashSet;
 use serde::{Deserialize, Serialize};
-use std::{borrow::Borrow, mem::take};
+use std::{borrow::Borrow, iter::once, mem::take};
 use swc_atoms::{js_word, JsWord};
-use swc_common::{util::move_map::MoveMap, Span, Spanned, SyntaxContext, DUMMY_SP};
+use swc_common::{
+    comments::{Comment, CommentKind, Comments},
+    util::move_map::MoveMap,
+    Span, Spanned, SyntaxContext, DUMMY_SP,
+};
 use swc_ecma_ast::*;
 use swc_ecma_transforms_base::ext::MapWithMut;
 use swc_ecma_utils::member_expr;
@@ -12,13 +16,24 @@ use swc_ecma_utils::quote_ident;
 use swc_ecma_utils::var::VarCollector;
 use swc_ecma_utils::ExprFactory;
 use swc_ecma_utils::{constructor::inject_after_super, default_constructor};
+use swc_ecma_utils::{find_ids, HANDLER};
 use swc_ecma_utils::{ident::IdentLike, prepend, Id, ModuleItemLike, StmtLike};
-use swc_ecma_visit::{as_folder, Fold, Node, Visit, VisitMut, VisitMutWith, VisitWith};
+use swc_ecma_visit::{
+    as_folder, noop_visit_mut_type, noop_visit_type, Fold, Node, Visit, VisitMut, VisitMutWith,
+    VisitWith,
+};
 
 /// Value does not contain TsLit::Bool
 type EnumValues = FxHashMap<Id, TsLit>;
 
-#[derive(Debug, Serialize, Deserialize)]
+/// Values of const enums, by the name of enum and the name of member.
+type ConstEnums = FxHashMap<Id, FxHashMap<JsWord, TsLit>>;
+
+/// Const enums exported from namespaces, by the name of the outermost
+/// namespace and the path to the enum (e.g. `A.B.Enum`).
+type NamespacedConstEnums = FxHashMap<(Id, Vec<JsWord>), Id>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[non_exhaustive]
 pub enum ImportsNotUsedAsValues {
     #[serde(rename = "remove")]
@@ -66,12 +81,38 @@ pub struct Config {
     /// https://github.com/swc-project/swc/issues/1698
     #[serde(default)]
     pub no_empty_export: bool,
+
+    /// Emit `const enum` declarations, like `enum` declarations.
+    ///
+    /// Members of const enums are inlined regardless of this option.
+    #[serde(default)]
+    pub preserve_const_enums: bool,
 }
 
 pub fn strip_with_config(config: Config) -> impl Fold {
+    strip_with_comments(config, None::<&dyn Comments>)
+}
+
+/// Same as [strip_with_config], but inlined members of const enums are
+/// annotated with comments like `/* Direction.Up */`.
+pub fn strip_with_comments<C>(config: Config, comments: Option<C>) -> impl Fold
+where
+    C: Comments,
+{
     as_folder(Strip {
         config,
-        ..Default::default()
+        comments,
+        non_top_level: Default::default(),
+        scope: Default::default(),
+        is_side_effect_import: Default::default(),
+        is_type_only_export: Default::default(),
+        uninitialized_vars: Default::default(),
+        decl_names: Default::default(),
+        in_var_pat: Default::default(),
+        import_aliases: Default::default(),
+        const_enums: Default::default(),
+        namespaced_const_enums: Default::default(),
+        shadowed: Default::default(),
     })
 }
 
@@ -80,9 +121,9 @@ pub fn strip() -> impl Fold {
     strip_with_config(Default::default())
 }
 
-#[derive(Default)]
-struct Strip {
+struct Strip<C: Comments> {
     config: Config,
+    comments: Option<C>,
     non_top_level: bool,
     scope: Scope,
 
@@ -100,9 +141,19 @@ struct Strip {
     /// This field is filled by [Visit] impl and [VisitMut] impl.
     decl_names: FxHashSet<Id>,
     in_var_pat: bool,
+
+    /// `(A, B)` for `import A = B.C`.
+    import_aliases: Vec<(Id, Id)>,
+
+    /// Computed values of const enums, which are inlined.
+    const_enums: ConstEnums,
+    namespaced_const_enums: NamespacedConstEnums,
+    /// Bindings in the current scope which shadow const enums, or namespaces
+    /// containing them.
+    shadowed: Vec<Id>,
 }
 
-impl Strip {
+impl<C: Comments> Strip<C> {
     /// Creates an uninitialized variable if `name` is not in scope.
     fn create_uninit_var(&mut self, span: Span, name: Id) -> Option<VarDeclarator> {
         if !self.decl_names.insert(name.clone()) {
@@ -119,6 +170,26 @@ impl Strip {
             definite: false,
         })
     }
+
+    /// Visits children of `node` while `ids` are declared in the scope of
+    /// `node`.
+    fn visit_mut_shadowing<N>(&mut self, ids: Vec<Id>, node: &mut N)
+    where
+        N: VisitMutWith<Self>,
+    {
+        let ids = ids
+            .into_iter()
+            .filter(|id| {
+                self.const_enums.contains_key(id)
+                    || self.namespaced_const_enums.keys().any(|(ns, _)| ns == id)
+            })
+            .collect::<Vec<_>>();
+
+        let len = self.shadowed.len();
+        self.shadowed.extend(ids);
+        node.visit_mut_children_with(self);
+        self.shadowed.truncate(len);
+    }
 }
 
 #[derive(Default)]
@@ -135,7 +206,7 @@ struct DeclInfo {
     has_concrete: bool,
 }
 
-impl Strip {
+impl<C: Comments> Strip<C> {
     fn store(&mut self, sym: JsWord, ctxt: SyntaxContext, concrete: bool) {
         let entry = self.scope.decls.entry((sym, ctxt)).or_default();
 
@@ -169,9 +240,11 @@ impl Strip {
                 }
             }
 
-            Decl::TsEnum(TsEnumDecl { ref id, .. }) => {
-                // Currently swc cannot remove constant enums
-                self.store(id.sym.clone(), id.span.ctxt, true);
+            Decl::TsEnum(ref e) => {
+                let id = &e.id;
+                if !self.should_remove_enum(e) {
+                    self.store(id.sym.clone(), id.span.ctxt, true);
+                }
                 self.store(id.sym.clone(), id.span.ctxt, false);
             }
 
@@ -195,7 +268,7 @@ impl Strip {
     }
 }
 
-impl Strip {
+impl<C: Comments> Strip<C> {
     fn fold_class_as_decl(&mut self, ident: Ident, mut class: Class) -> (Decl, Vec<Box<Expr>>) {
         class.is_abstract = false;
         class.type_params = None;
@@ -489,6 +562,11 @@ impl Strip {
 
     /// Returns [Some] if the method should be called again.
     fn handle_expr<'a>(&mut self, n: &'a mut Expr) -> Vec<&'a mut Expr> {
+        if let Some(value) = self.const_enum_member(n) {
+            *n = value;
+            return vec![];
+        }
+
         if n.is_class() {
             let ClassExpr {
                 ident: old_ident,
@@ -571,160 +649,110 @@ impl Strip {
         vec![]
     }
 
-    fn handle_enum<T>(&mut self, e: TsEnumDecl, stmts: &mut Vec<T>)
-    where
-        T: StmtLike,
-    {
-        /// Called only for enums.
-        ///
-        /// If both of the default value and the initialization is None, this
-        /// method returns [Err].
-        fn compute(
-            e: &TsEnumDecl,
-            span: Span,
-            values: &mut EnumValues,
-            default: Option<i64>,
-            init: Option<&Expr>,
-        ) -> Result<TsLit, ()> {
-            fn compute_bin(
-                e: &TsEnumDecl,
-                span: Span,
-                values: &mut EnumValues,
-                expr: &BinExpr,
-            ) -> Result<TsLit, ()> {
-                let l = compute(e, span, values, None, Some(&expr.left))?;
-                let r = compute(e, span, values, None, Some(&expr.right))?;
-
-                Ok(match (l, r) {
-                    (
-                        TsLit::Number(Number { value: l, .. }),
-                        TsLit::Number(Number { value: r, .. }),
-                    ) => {
-                        TsLit::Number(Number {
-                            span,
-                            value: match expr.op {
-                                op!(bin, "+") => l + r,
-                                op!(bin, "-") => l - r,
-                                op!("*") => l * r,
-                                op!("/") => l / r,
-
-                                // TODO
-                                op!("&") => ((l.round() as i64) & (r.round() as i64)) as _,
-                                op!("|") => ((l.round() as i64) | (r.round() as i64)) as _,
-                                op!("^") => ((l.round() as i64) ^ (r.round() as i64)) as _,
-
-                                op!("<<") => ((l.round() as i64) << (r.round() as i64)) as _,
-                                op!(">>") => ((l.round() as i64) >> (r.round() as i64)) as _,
-                                // TODO: Verify this
-                                op!(">>>") => ((l.round() as u64) >> (r.round() as u64)) as _,
-                                _ => Err(())?,
-                            },
-                        })
-                    }
-                    (TsLit::Str(l), TsLit::Str(r)) if expr.op == op!(bin, "+") => TsLit::Str(Str {
-                        span,
-                        value: format!("{}{}", l.value, r.value).into(),
-                        has_escape: l.has_escape || r.has_escape,
-                        kind: Default::default(),
-                    }),
-                    (TsLit::Number(l), TsLit::Str(r)) if expr.op == op!(bin, "+") => {
-                        TsLit::Str(Str {
-                            span,
-                            value: format!("{}{}", l.value, r.value).into(),
-                            has_escape: r.has_escape,
-                            kind: Default::default(),
-                        })
-                    }
-                    (TsLit::Str(l), TsLit::Number(r)) if expr.op == op!(bin, "+") => {
-                        TsLit::Str(Str {
-                            span,
-                            value: format!("{}{}", l.value, r.value).into(),
-                            has_escape: l.has_escape,
-                            kind: Default::default(),
-                        })
-                    }
-                    _ => Err(())?,
-                })
-            }
-
-            if let Some(expr) = init {
-                match expr {
-                    Expr::Lit(Lit::Str(s)) => return Ok(TsLit::Str(s.clone())),
-                    Expr::Lit(Lit::Num(s)) => return Ok(TsLit::Number(*s)),
-                    Expr::Bin(ref bin) => return compute_bin(e, span, values, &bin),
-                    Expr::Paren(ref paren) => {
-                        return compute(e, span, values, default, Some(&paren.expr))
-                    }
-
-                    Expr::Ident(ref id) => {
-                        if let Some(v) = values.get(&id.clone().into_id()) {
-                            return Ok(v.clone());
-                        }
-                        //
-                        for m in e.members.iter() {
-                            match m.id {
-                                TsEnumMemberId::Str(Str { value: ref sym, .. })
-                                | TsEnumMemberId::Ident(Ident { ref sym, .. }) => {
-                                    if *sym == id.sym {
-                                        return compute(
-                                            e,
-                                            span,
-                                            values,
-                                            None,
-                                            m.init.as_ref().map(|v| &**v),
-                                        );
-                                    }
-                                }
-                            }
-                        }
-                        return Err(());
-                    }
-                    Expr::Unary(ref expr) => {
-                        let v = compute(e, span, values, None, Some(&expr.arg))?;
-                        match v {
-                            TsLit::BigInt(BigInt { .. }) => {}
-                            TsLit::Number(Number { value: v, .. }) => {
-                                return Ok(TsLit::Number(Number {
-                                    span,
-                                    value: match expr.op {
-                                        op!(unary, "+") => v,
-                                        op!(unary, "-") => -v,
-                                        op!("!") => {
-                                            if v == 0.0f64 {
-                                                0.0
-                                            } else {
-                                                1.0
-                                            }
-                                        }
-                                        op!("~") => (!(v as i32)) as f64,
-                                        _ => Err(())?,
-                                    },
-                                }))
-                            }
-                            TsLit::Str(_) => {}
-                            TsLit::Bool(_) => {}
-                            TsLit::Tpl(_) => {}
-                        }
-                    }
-
-                    Expr::Tpl(ref t) if t.exprs.is_empty() => {
-                        if let Some(v) = &t.quasis[0].cooked {
-                            return Ok(v.clone().into());
-                        }
-                    }
+    /// Returns true if `e` is a const enum which should not be emitted.
+    fn should_remove_enum(&self, e: &TsEnumDecl) -> bool {
+        e.is_const
+            && (e.declare
+                || (!self.config.preserve_const_enums
+                    && self.const_enums.contains_key(&e.id.to_id())))
+    }
 
-                    _ => {}
-                }
-            } else {
-                if let Some(value) = default {
-                    return Ok(TsLit::Number(Number {
-                        span,
-                        value: value as _,
-                    }));
+    /// Returns the value of `Foo.Bar` if `Foo` is a const enum.
+    ///
+    /// `Ns.Foo.Bar` is also inlined if `Foo` is a const enum exported from
+    /// namespace `Ns`.
+    fn const_enum_member(&self, e: &Expr) -> Option<Expr> {
+        let span = e.span();
+        let mut path = vec![];
+        let mut e = e;
+        let obj = loop {
+            match e {
+                Expr::Member(MemberExpr {
+                    obj: ExprOrSuper::Expr(obj),
+                    prop,
+                    computed,
+                    ..
+                }) => {
+                    path.push(member_prop_name(prop, *computed)?.clone());
+                    e = &**obj;
                 }
+                Expr::Ident(obj) if !path.is_empty() => break obj,
+                _ => return None,
             }
+        };
+        if self.shadowed.contains(&obj.to_id()) {
+            return None;
+        }
+        path.reverse();
+        let prop = path.pop()?;
 
-            Err(())
+        let enum_id = if path.is_empty() {
+            obj.to_id()
+        } else {
+            self.namespaced_const_enums
+                .get(&(obj.to_id(), path.clone()))?
+                .clone()
+        };
+        let value = self.const_enums.get(&enum_id)?.get(&prop)?;
+
+        if let Some(comments) = &self.comments {
+            if !span.is_dummy() {
+                comments.add_leading(
+                    span.lo,
+                    Comment {
+                        kind: CommentKind::Block,
+                        span: DUMMY_SP,
+                        text: format!(
+                            " {} ",
+                            once(&obj.sym)
+                                .chain(&path)
+                                .chain(once(&prop))
+                                .map(|s| &**s)
+                                .collect::<Vec<_>>()
+                                .join(".")
+                        ),
+                    },
+                );
+            }
+        }
+
+        Some(match value {
+            TsLit::Number(n) if n.value.is_sign_negative() => Expr::Unary(UnaryExpr {
+                span,
+                op: op!(unary, "-"),
+                arg: Box::new(Expr::Lit(Lit::Num(Number {
+                    span,
+                    value: -n.value,
+                }))),
+            }),
+            TsLit::Number(n) => Expr::Lit(Lit::Num(Number {
+                span,
+                value: n.value,
+            })),
+            TsLit::Str(s) => Expr::Lit(Lit::Str(Str {
+                span,
+                kind: Default::default(),
+                ..s.clone()
+            })),
+            TsLit::Bool(b) => Expr::Lit(Lit::Bool(Bool {
+                span,
+                value: b.value,
+            })),
+            TsLit::BigInt(b) => Expr::Lit(Lit::BigInt(BigInt { span, ..b.clone() })),
+            TsLit::Tpl(t) => Expr::Lit(Lit::Str(Str {
+                span,
+                kind: Default::default(),
+                ..t.quasis[0].raw.clone()
+            })),
+        })
+    }
+
+    fn handle_enum<T>(&mut self, e: TsEnumDecl, stmts: &mut Vec<T>)
+    where
+        T: StmtLike,
+    {
+        if self.should_remove_enum(&e) {
+            return;
         }
 
         let id = e.id.clone();
@@ -741,6 +769,7 @@ impl Strip {
                     &e,
                     id_span,
                     &mut values,
+                    &self.const_enums,
                     Some(default),
                     m.init.as_ref().map(|v| &**v),
                 )
@@ -908,25 +937,60 @@ impl Strip {
         let body = module.body?;
         let mut body = match body {
             TsNamespaceBody::TsModuleBlock(body) => body,
-            TsNamespaceBody::TsNamespaceDecl(_) => return None,
+            // `namespace A.B {}` is `namespace A { export namespace B {} }`
+            TsNamespaceBody::TsNamespaceDecl(decl) => TsModuleBlock {
+                span: decl.span,
+                body: vec![ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                    span: decl.span,
+                    decl: Decl::TsModule(TsModuleDecl {
+                        span: decl.span,
+                        declare: decl.declare,
+                        global: decl.global,
+                        id: TsModuleName::Ident(decl.id),
+                        body: Some(*decl.body),
+                    }),
+                }))],
+            },
         };
 
         let mut init_stmts = vec![];
 
-        let var = self.create_uninit_var(module_name.span, module_name.to_id());
-
         // This makes body valid javascript.
+        //
+        // Declarations in the body are scoped to the namespace.
+        let decl_names = take(&mut self.decl_names);
         body.body.visit_mut_with(self);
+        self.decl_names = decl_names;
         if body.body.is_empty() {
             return None;
         }
 
+        let var = self.create_uninit_var(module_name.span, module_name.to_id());
+
         let private_name = private_ident!(module_name.sym.clone());
-        let mut delayed_vars = vec![];
 
-        for item in body.body {
-            // Drop
+        // Exported variables are accessed as properties of the namespace, so the
+        // namespace can be merged with other blocks.
+        let mut exported = FxHashSet::default();
+        for item in &body.body {
+            if let ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                decl: Decl::Var(v),
+                ..
+            })) = item
+            {
+                for decl in &v.decls {
+                    exported.extend(find_ids::<_, Id>(&decl.name));
+                }
+            }
+        }
+        if !exported.is_empty() {
+            body.body.visit_mut_with(&mut NamespaceExportRewriter {
+                namespace: private_name.clone(),
+                exported,
+            });
+        }
 
+        for item in body.body {
             match item {
                 ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
                     span, decl, ..
@@ -937,26 +1001,10 @@ impl Strip {
                         Decl::Var(v) => {
                             let mut exprs = vec![];
                             for decl in v.decls {
+                                // Enums and namespaces are initialized using the property.
                                 let init = match decl.init {
                                     Some(v) => v,
-                                    None => {
-                                        // We should handle enums
-
-                                        match &decl.name {
-                                            Pat::Ident(name) => {
-                                                delayed_vars.push(name.id.clone());
-                                                init_stmts.push(Stmt::Decl(Decl::Var(VarDecl {
-                                                    span: DUMMY_SP,
-                                                    kind: v.kind,
-                                                    declare: false,
-                                                    decls: vec![decl],
-                                                })))
-                                            }
-                                            _ => {}
-                                        }
-
-                                        continue;
-                                    }
+                                    None => continue,
                                 };
                                 match decl.name {
                                     Pat::Ident(name) => {
@@ -1003,7 +1051,6 @@ impl Strip {
                                 }));
                             }
 
-                            // TODO: Implement this using alias.
                             continue;
                         }
                         Decl::TsInterface(_)
@@ -1035,37 +1082,17 @@ impl Strip {
                 }
 
                 ModuleItem::Stmt(stmt) => init_stmts.push(stmt),
-                _ => {}
-            }
-        }
 
-        if !delayed_vars.is_empty() {
-            init_stmts.push(Stmt::Expr(ExprStmt {
-                span: DUMMY_SP,
-                expr: Box::new(Expr::Seq(SeqExpr {
-                    span: DUMMY_SP,
-                    exprs: delayed_vars
-                        .into_iter()
-                        .map(|id| {
-                            //
-                            let mut prop = id.clone();
-                            prop.span.ctxt = SyntaxContext::empty();
-                            Expr::Assign(AssignExpr {
-                                span: DUMMY_SP,
-                                op: op!("="),
-                                left: PatOrExpr::Expr(Box::new(Expr::Member(MemberExpr {
-                                    span: DUMMY_SP,
-                                    obj: private_name.clone().as_obj(),
-                                    prop: Box::new(Expr::Ident(prop)),
-                                    computed: false,
-                                }))),
-                                right: Box::new(Expr::Ident(id)),
-                            })
-                        })
-                        .map(Box::new)
-                        .collect(),
-                })),
-            }))
+                ModuleItem::ModuleDecl(decl) => HANDLER.with(|handler| {
+                    handler
+                        .struct_span_err(
+                            decl.span(),
+                            "import and export declarations other than `export` of a declaration \
+                             are not allowed in a namespace",
+                        )
+                        .emit()
+                }),
+            }
         }
 
         let init_fn_expr = FnExpr {
@@ -1128,7 +1155,7 @@ impl Strip {
     }
 }
 
-impl Visit for Strip {
+impl<C: Comments> Visit for Strip<C> {
     fn visit_binding_ident(&mut self, n: &BindingIdent, _: &dyn Node) {
         if !self.in_var_pat {
             n.visit_children_with(self)
@@ -1178,7 +1205,12 @@ impl Visit for Strip {
                 self.decl_names.insert(class.ident.to_id());
                 class.class.visit_with(class, self);
             }
-            Decl::Fn(f) => f.function.visit_with(f, self),
+            Decl::Fn(f) => {
+                if !old && !f.declare && f.function.body.is_some() {
+                    self.decl_names.insert(f.ident.to_id());
+                }
+                f.function.visit_with(f, self)
+            }
             Decl::Var(ref var) => {
                 for decl in &var.decls {
                     self.in_var_pat = true;
@@ -1244,6 +1276,29 @@ impl Visit for Strip {
         }
     }
 
+    fn visit_ts_import_equals_decl(&mut self, n: &TsImportEqualsDecl, _: &dyn Node) {
+        // The alias itself is not a reference.
+        n.module_ref.visit_with(n, self);
+
+        if let TsModuleRef::TsEntityName(name) = &n.module_ref {
+            let mut root = name;
+            while let TsEntityName::TsQualifiedName(q) = root {
+                root = &q.left;
+            }
+            if let TsEntityName::Ident(root) = root {
+                if n.is_export {
+                    self.scope
+                        .referenced_idents
+                        .entry(root.to_id())
+                        .or_default()
+                        .has_concrete = true;
+                } else {
+                    self.import_aliases.push((n.id.to_id(), root.to_id()));
+                }
+            }
+        }
+    }
+
     fn visit_ts_entity_name(&mut self, name: &TsEntityName, _: &dyn Node) {
         match *name {
             TsEntityName::Ident(ref i) => {
@@ -1267,7 +1322,7 @@ macro_rules! type_to_none {
     };
 }
 
-impl VisitMut for Strip {
+impl<C: Comments> VisitMut for Strip<C> {
     fn visit_mut_block_stmt_or_expr(&mut self, n: &mut BlockStmtOrExpr) {
         match n {
             BlockStmtOrExpr::Expr(expr) if expr.is_class() => {
@@ -1302,8 +1357,24 @@ impl VisitMut for Strip {
         n.optional = false;
     }
 
+    fn visit_mut_arrow_expr(&mut self, n: &mut ArrowExpr) {
+        let mut ids = find_ids(&n.params);
+        ids.extend(hoisted_var_ids(&n.body));
+        self.visit_mut_shadowing(ids, n);
+    }
+
+    fn visit_mut_block_stmt(&mut self, n: &mut BlockStmt) {
+        self.visit_mut_shadowing(lexical_ids(&n.stmts), n);
+    }
+
+    fn visit_mut_catch_clause(&mut self, n: &mut CatchClause) {
+        self.visit_mut_shadowing(find_ids(&n.param), n);
+    }
+
     fn visit_mut_constructor(&mut self, n: &mut Constructor) {
-        n.visit_mut_children_with(self);
+        let mut ids = find_ids(&n.params);
+        ids.extend(hoisted_var_ids(&n.body));
+        self.visit_mut_shadowing(ids, n);
 
         let mut assign_exprs = vec![];
 
@@ -1377,6 +1448,28 @@ impl VisitMut for Strip {
         }
     }
 
+    fn visit_mut_for_in_stmt(&mut self, n: &mut ForInStmt) {
+        self.visit_mut_shadowing(lexical_ids_of_head(&n.left), n);
+    }
+
+    fn visit_mut_for_of_stmt(&mut self, n: &mut ForOfStmt) {
+        self.visit_mut_shadowing(lexical_ids_of_head(&n.left), n);
+    }
+
+    fn visit_mut_for_stmt(&mut self, n: &mut ForStmt) {
+        let ids = match &n.init {
+            Some(VarDeclOrExpr::VarDecl(v)) if v.kind != VarDeclKind::Var => find_ids(&v.decls),
+            _ => vec![],
+        };
+        self.visit_mut_shadowing(ids, n);
+    }
+
+    fn visit_mut_function(&mut self, n: &mut Function) {
+        let mut ids = find_ids(&n.params);
+        ids.extend(hoisted_var_ids(&n.body));
+        self.visit_mut_shadowing(ids, n);
+    }
+
     fn visit_mut_ident(&mut self, i: &mut Ident) {
         i.optional = false;
         i.visit_mut_children_with(self);
@@ -1497,6 +1590,8 @@ impl VisitMut for Strip {
                     stmts.push(init)
                 }
 
+                Stmt::Decl(Decl::TsEnum(ref e)) if self.should_remove_enum(e) => continue,
+
                 Stmt::Decl(Decl::TsEnum(e)) => {
                     // var Foo;
                     // (function (Foo) {
@@ -1593,6 +1688,16 @@ impl VisitMut for Strip {
     }
 
     fn visit_mut_module(&mut self, module: &mut Module) {
+        module.visit_with(
+            &Invalid { span: DUMMY_SP } as _,
+            &mut ConstEnumCollector {
+                const_enums: &mut self.const_enums,
+                namespaced_const_enums: &mut self.namespaced_const_enums,
+                namespaces: vec![],
+                in_export_decl: false,
+            },
+        );
+
         let was_module = module.body.iter().any(|item| match item {
             ModuleItem::ModuleDecl(..) => true,
             _ => false,
@@ -1643,6 +1748,16 @@ impl VisitMut for Strip {
     }
 
     fn visit_mut_script(&mut self, n: &mut Script) {
+        n.visit_with(
+            &Invalid { span: DUMMY_SP } as _,
+            &mut ConstEnumCollector {
+                const_enums: &mut self.const_enums,
+                namespaced_const_enums: &mut self.namespaced_const_enums,
+                namespaces: vec![],
+                in_export_decl: false,
+            },
+        );
+
         n.visit_mut_children_with(self);
         if !self.uninitialized_vars.is_empty() {
             prepend(
@@ -1662,6 +1777,32 @@ impl VisitMut for Strip {
         self.visit_mut_stmt_like(items);
         items.visit_with(&Invalid { span: DUMMY_SP }, self);
 
+        // `B` of `import A = B.C` is used as a value if `A` is used as a value.
+        loop {
+            let mut changed = false;
+            for (alias, target) in &self.import_aliases {
+                let used_as_value = self
+                    .scope
+                    .referenced_idents
+                    .get(alias)
+                    .map_or(false, |i| i.has_concrete);
+                if used_as_value {
+                    let entry = self
+                        .scope
+                        .referenced_idents
+                        .entry(target.clone())
+                        .or_default();
+                    if !entry.has_concrete {
+                        entry.has_concrete = true;
+                        changed = true;
+                    }
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+
         let mut stmts = Vec::with_capacity(items.len());
         for mut item in take(items) {
             self.is_side_effect_import = false;
@@ -1790,6 +1931,16 @@ impl VisitMut for Strip {
                     }
                 }
 
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                    decl: Decl::TsEnum(ref e),
+                    ..
+                }))
+                | ModuleItem::Stmt(Stmt::Decl(Decl::TsEnum(ref e)))
+                    if self.should_remove_enum(e) =>
+                {
+                    continue
+                }
+
                 ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
                     decl: Decl::TsEnum(e),
                     ..
@@ -1848,24 +1999,40 @@ impl VisitMut for Strip {
                 }
 
                 ModuleItem::ModuleDecl(ModuleDecl::TsImportEquals(import)) => {
-                    if !import.is_export {
+                    if import.declare || import.is_type_only {
+                        continue;
+                    }
+                    // Like tsc, an alias which is not exported is preserved only if it's
+                    // used as a value.
+                    let used_as_value = self
+                        .scope
+                        .referenced_idents
+                        .get(&import.id.to_id())
+                        .map_or(false, |i| i.has_concrete);
+                    if !import.is_export && !used_as_value {
                         continue;
                     }
 
-                    stmts.push(ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                    let decl = Decl::Var(VarDecl {
                         span: DUMMY_SP,
-                        decl: Decl::Var(VarDecl {
+                        kind: VarDeclKind::Var,
+                        decls: vec![VarDeclarator {
                             span: DUMMY_SP,
-                            kind: VarDeclKind::Var,
-                            decls: vec![VarDeclarator {
-                                span: DUMMY_SP,
-                                name: Pat::Ident(import.id.into()),
-                                init: Some(Box::new(module_ref_to_expr(import.module_ref))),
-                                definite: false,
-                            }],
-                            declare: false,
-                        }),
-                    })));
+                            name: Pat::Ident(import.id.into()),
+                            init: Some(Box::new(module_ref_to_expr(import.module_ref))),
+                            definite: false,
+                        }],
+                        declare: false,
+                    });
+
+                    if import.is_export {
+                        stmts.push(ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                            span: DUMMY_SP,
+                            decl,
+                        })));
+                    } else {
+                        stmts.push(ModuleItem::Stmt(Stmt::Decl(decl)));
+                    }
                 }
 
                 ModuleItem::ModuleDecl(ModuleDecl::TsExportAssignment(export)) => {
@@ -1923,10 +2090,371 @@ impl VisitMut for Strip {
     }
 }
 
+/// Computes values of const enums, in the order of declarations.
+///
+/// A const enum is not inlined if any of its members cannot be computed.
+struct ConstEnumCollector<'a> {
+    const_enums: &'a mut ConstEnums,
+    namespaced_const_enums: &'a mut NamespacedConstEnums,
+    /// Enclosing namespaces, with whether each of them is exported.
+    namespaces: Vec<(Id, bool)>,
+    in_export_decl: bool,
+}
+
+impl ConstEnumCollector<'_> {
+    fn visit_namespace_body(&mut self, id: &Ident, exported: bool, body: &TsNamespaceBody) {
+        self.namespaces.push((id.to_id(), exported));
+        let old = self.in_export_decl;
+        self.in_export_decl = false;
+        body.visit_with(&Invalid { span: DUMMY_SP } as _, self);
+        self.in_export_decl = old;
+        self.namespaces.pop();
+    }
+}
+
+impl Visit for ConstEnumCollector<'_> {
+    /// Const enums declared in blocks are emitted as objects, as they may
+    /// share names with other bindings.
+    fn visit_block_stmt(&mut self, _: &BlockStmt, _: &dyn Node) {}
+
+    fn visit_export_decl(&mut self, n: &ExportDecl, _: &dyn Node) {
+        let old = self.in_export_decl;
+        self.in_export_decl = true;
+        n.decl.visit_with(n, self);
+        self.in_export_decl = old;
+    }
+
+    fn visit_stmt(&mut self, n: &Stmt, _: &dyn Node) {
+        let old = self.in_export_decl;
+        self.in_export_decl = false;
+        n.visit_children_with(self);
+        self.in_export_decl = old;
+    }
+
+    fn visit_ts_module_decl(&mut self, n: &TsModuleDecl, _: &dyn Node) {
+        match (&n.id, &n.body) {
+            (TsModuleName::Ident(id), Some(body)) => {
+                self.visit_namespace_body(id, self.in_export_decl, body)
+            }
+            _ => n.visit_children_with(self),
+        }
+    }
+
+    fn visit_ts_namespace_decl(&mut self, n: &TsNamespaceDecl, _: &dyn Node) {
+        // `B` of `namespace A.B {}` is always exported.
+        self.visit_namespace_body(&n.id, true, &n.body);
+    }
+
+    fn visit_ts_enum_decl(&mut self, e: &TsEnumDecl, _: &dyn Node) {
+        if !e.is_const {
+            return;
+        }
+
+        let mut default = 0;
+        let mut values = Default::default();
+        let mut members = FxHashMap::default();
+        for m in &e.members {
+            let val = match compute(
+                e,
+                m.id.span(),
+                &mut values,
+                self.const_enums,
+                Some(default),
+                m.init.as_deref(),
+            ) {
+                Ok(v) => v,
+                Err(()) => return,
+            };
+
+            if let TsLit::Number(n) = &val {
+                default = n.value as i64 + 1;
+            }
+
+            let id = match &m.id {
+                TsEnumMemberId::Ident(i) => i.clone(),
+                TsEnumMemberId::Str(s) => Ident::new(s.value.clone(), s.span),
+            };
+            members.insert(id.sym.clone(), val.clone());
+            values.insert(id.into_id(), val);
+        }
+
+        // `Ns.Enum` is available for every enclosing namespace `Ns` which can
+        // reach the enum through exported members.
+        if self.in_export_decl {
+            let mut path = vec![e.id.sym.clone()];
+            for (ns, exported) in self.namespaces.iter().rev() {
+                self.namespaced_const_enums
+                    .insert((ns.clone(), path.clone()), e.id.to_id());
+                if !exported {
+                    break;
+                }
+                path.insert(0, ns.0.clone());
+            }
+        }
+        self.const_enums.insert(e.id.to_id(), members);
+    }
+}
+
+/// Returns names declared by `var` declarations in `body`, which are hoisted
+/// to the enclosing function.
+fn hoisted_var_ids<N>(body: &N) -> Vec<Id>
+where
+    N: for<'any> VisitWith<HoistedVarCollector<'any>>,
+{
+    let mut ids = vec![];
+    body.visit_with(
+        &Invalid { span: DUMMY_SP } as _,
+        &mut HoistedVarCollector { to: &mut ids },
+    );
+    ids
+}
+
+struct HoistedVarCollector<'a> {
+    to: &'a mut Vec<Id>,
+}
+
+impl Visit for HoistedVarCollector<'_> {
+    noop_visit_type!();
+
+    fn visit_arrow_expr(&mut self, _: &ArrowExpr, _: &dyn Node) {}
+
+    fn visit_class(&mut self, _: &Class, _: &dyn Node) {}
+
+    fn visit_expr(&mut self, _: &Expr, _: &dyn Node) {}
+
+    fn visit_function(&mut self, _: &Function, _: &dyn Node) {}
+
+    fn visit_var_decl(&mut self, n: &VarDecl, _: &dyn Node) {
+        if n.kind == VarDeclKind::Var {
+            self.to.extend(find_ids::<_, Id>(&n.decls));
+        }
+    }
+}
+
+/// Returns names declared by `stmts` which are scoped to the enclosing block.
+fn lexical_ids(stmts: &[Stmt]) -> Vec<Id> {
+    let mut ids = vec![];
+    for stmt in stmts {
+        match stmt {
+            Stmt::Decl(Decl::Var(v)) if v.kind != VarDeclKind::Var => {
+                ids.extend(find_ids::<_, Id>(&v.decls))
+            }
+            Stmt::Decl(Decl::Class(ClassDecl { ident, .. }))
+            | Stmt::Decl(Decl::Fn(FnDecl { ident, .. }))
+            | Stmt::Decl(Decl::TsEnum(TsEnumDecl { id: ident, .. }))
+            | Stmt::Decl(Decl::TsModule(TsModuleDecl {
+                id: TsModuleName::Ident(ident),
+                ..
+            })) => ids.push(ident.to_id()),
+            _ => {}
+        }
+    }
+    ids
+}
+
+/// Returns names declared by `let` or `const` in the head of a `for-in` or
+/// `for-of` statement.
+fn lexical_ids_of_head(head: &VarDeclOrPat) -> Vec<Id> {
+    match head {
+        VarDeclOrPat::VarDecl(v) if v.kind != VarDeclKind::Var => find_ids(&v.decls),
+        _ => vec![],
+    }
+}
+
+/// Called only for enums.
+///
+/// If both of the default value and the initialization is None, this
+/// method returns [Err].
+fn compute(
+    e: &TsEnumDecl,
+    span: Span,
+    values: &mut EnumValues,
+    const_enums: &ConstEnums,
+    default: Option<i64>,
+    init: Option<&Expr>,
+) -> Result<TsLit, ()> {
+    fn compute_bin(
+        e: &TsEnumDecl,
+        span: Span,
+        values: &mut EnumValues,
+        const_enums: &ConstEnums,
+        expr: &BinExpr,
+    ) -> Result<TsLit, ()> {
+        let l = compute(e, span, values, const_enums, None, Some(&expr.left))?;
+        let r = compute(e, span, values, const_enums, None, Some(&expr.right))?;
+
+        Ok(match (l, r) {
+            (TsLit::Number(Number { value: l, .. }), TsLit::Number(Number { value: r, .. })) => {
+                TsLit::Number(Number {
+                    span,
+                    value: match expr.op {
+                        op!(bin, "+") => l + r,
+                        op!(bin, "-") => l - r,
+                        op!("*") => l * r,
+                        op!("/") => l / r,
+
+                        // TODO
+                        op!("&") => ((l.round() as i64) & (r.round() as i64)) as _,
+                        op!("|") => ((l.round() as i64) | (r.round() as i64)) as _,
+                        op!("^") => ((l.round() as i64) ^ (r.round() as i64)) as _,
+
+                        op!("<<") => ((l.round() as i64) << (r.round() as i64)) as _,
+                        op!(">>") => ((l.round() as i64) >> (r.round() as i64)) as _,
+                        // TODO: Verify this
+                        op!(">>>") => ((l.round() as u64) >> (r.round() as u64)) as _,
+                        _ => Err(())?,
+                    },
+                })
+            }
+            (TsLit::Str(l), TsLit::Str(r)) if expr.op == op!(bin, "+") => TsLit::Str(Str {
+                span,
+                value: format!("{}{}", l.value, r.value).into(),
+                has_escape: l.has_escape || r.has_escape,
+                kind: Default::default(),
+            }),
+            (TsLit::Number(l), TsLit::Str(r)) if expr.op == op!(bin, "+") => TsLit::Str(Str {
+                span,
+                value: format!("{}{}", l.value, r.value).into(),
+                has_escape: r.has_escape,
+                kind: Default::default(),
+            }),
+            (TsLit::Str(l), TsLit::Number(r)) if expr.op == op!(bin, "+") => TsLit::Str(Str {
+                span,
+                value: format!("{}{}", l.value, r.value).into(),
+                has_escape: l.has_escape,
+                kind: Default::default(),
+            }),
+            _ => Err(())?,
+        })
+    }
+
+    if let Some(expr) = init {
+        match expr {
+            Expr::Lit(Lit::Str(s)) => return Ok(TsLit::Str(s.clone())),
+            Expr::Lit(Lit::Num(s)) => return Ok(TsLit::Number(*s)),
+            Expr::Bin(ref bin) => return compute_bin(e, span, values, const_enums, &bin),
+            Expr::Paren(ref paren) => {
+                return compute(e, span, values, const_enums, default, Some(&paren.expr))
+            }
+
+            Expr::Ident(ref id) => {
+                if let Some(v) = values.get(&id.clone().into_id()) {
+                    return Ok(v.clone());
+                }
+                //
+                for m in e.members.iter() {
+                    match m.id {
+                        TsEnumMemberId::Str(Str { value: ref sym, .. })
+                        | TsEnumMemberId::Ident(Ident { ref sym, .. }) => {
+                            if *sym == id.sym {
+                                return compute(
+                                    e,
+                                    span,
+                                    values,
+                                    const_enums,
+                                    None,
+                                    m.init.as_ref().map(|v| &**v),
+                                );
+                            }
+                        }
+                    }
+                }
+                return Err(());
+            }
+            // Foo.Bar, Foo["Bar"]
+            Expr::Member(MemberExpr {
+                obj: ExprOrSuper::Expr(obj),
+                prop,
+                computed,
+                ..
+            }) => {
+                if let (Expr::Ident(obj), Some(prop)) = (&**obj, member_prop_name(prop, *computed))
+                {
+                    if obj.sym == e.id.sym {
+                        return compute(
+                            e,
+                            span,
+                            values,
+                            const_enums,
+                            None,
+                            Some(&Expr::Ident(Ident::new(prop.clone(), obj.span))),
+                        );
+                    }
+
+                    if let Some(v) = const_enums.get(&obj.to_id()).and_then(|m| m.get(prop)) {
+                        return Ok(v.clone());
+                    }
+                }
+            }
+            Expr::Unary(ref expr) => {
+                let v = compute(e, span, values, const_enums, None, Some(&expr.arg))?;
+                match v {
+                    TsLit::BigInt(BigInt { .. }) => {}
+                    TsLit::Number(Number { value: v, .. }) => {
+                        return Ok(TsLit::Number(Number {
+                            span,
+                            value: match expr.op {
+                                op!(unary, "+") => v,
+                                op!(unary, "-") => -v,
+                                op!("!") => {
+                                    if v == 0.0f64 {
+                                        0.0
+                                    } else {
+                                        1.0
+                                    }
+                                }
+                                op!("~") => (!(v as i32)) as f64,
+                                _ => Err(())?,
+                            },
+                        }))
+                    }
+                    TsLit::Str(_) => {}
+                    TsLit::Bool(_) => {}
+                    TsLit::Tpl(_) => {}
+                }
+            }
+
+            Expr::Tpl(ref t) if t.exprs.is_empty() => {
+                if let Some(v) = &t.quasis[0].cooked {
+                    return Ok(v.clone().into());
+                }
+            }
+
+            _ => {}
+        }
+    } else {
+        if let Some(value) = default {
+            return Ok(TsLit::Number(Number {
+                span,
+                value: value as _,
+            }));
+        }
+    }
+
+    Err(())
+}
+
+/// Returns the name of the property of `Foo.Bar` or `Foo["Bar"]`.
+fn member_prop_name(prop: &Expr, computed: bool) -> Option<&JsWord> {
+    match (prop, computed) {
+        (Expr::Ident(i), false) => Some(&i.sym),
+        (Expr::Lit(Lit::Str(s)), true) => Some(&s.value),
+        _ => None,
+    }
+}
+
 fn module_ref_to_expr(r: TsModuleRef) -> Expr {
     match r {
         TsModuleRef::TsEntityName(name) => ts_entity_name_to_expr(name),
-        _ => unimplemented!("export import A = B where B != TsEntityName\nB: {:?}", r),
+        // `export import fs = require('fs')`
+        TsModuleRef::TsExternalModuleRef(TsExternalModuleRef { expr, .. }) => {
+            Expr::Call(CallExpr {
+                span: DUMMY_SP,
+                callee: quote_ident!("require").as_callee(),
+                args: vec![expr.as_arg()],
+                type_args: None,
+            })
+        }
     }
 }
 
@@ -1965,9 +2493,10 @@ fn create_prop_pat(obj: &Ident, pat: Pat) -> Pat {
                 .collect(),
             ..p
         }),
-        Pat::Rest(_) => {
-            todo!("Rest pattern in an exported variable from namespace")
-        }
+        Pat::Rest(p) => Pat::Rest(RestPat {
+            arg: Box::new(create_prop_pat(obj, *p.arg)),
+            ..p
+        }),
         Pat::Object(p) => Pat::Object(ObjectPat {
             props: p
                 .props
@@ -1977,10 +2506,26 @@ fn create_prop_pat(obj: &Ident, pat: Pat) -> Pat {
                         value: Box::new(create_prop_pat(obj, *kv.value)),
                         ..kv
                     }),
-                    ObjectPatProp::Assign(..) => prop,
-                    ObjectPatProp::Rest(_) => {
-                        todo!("Rest pattern property in an exported variable from namespace")
+                    // `{ a = 1 }` => `{ a: ns.a = 1 }`
+                    ObjectPatProp::Assign(AssignPatProp { span, key, value }) => {
+                        let left = create_prop_pat(obj, Pat::Ident(key.clone().into()));
+                        ObjectPatProp::KeyValue(KeyValuePatProp {
+                            key: PropName::Ident(key),
+                            value: Box::new(match value {
+                                Some(right) => Pat::Assign(AssignPat {
+                                    span,
+                                    left: Box::new(left),
+                                    right,
+                                    type_ann: None,
+                                }),
+                                None => left,
+                            }),
+                        })
                     }
+                    ObjectPatProp::Rest(p) => ObjectPatProp::Rest(RestPat {
+                        arg: Box::new(create_prop_pat(obj, *p.arg)),
+                        ..p
+                    }),
                 })
                 .collect(),
             ..p
@@ -1989,7 +2534,207 @@ fn create_prop_pat(obj: &Ident, pat: Pat) -> Pat {
             left: Box::new(create_prop_pat(obj, *p.left)),
             ..p
         }),
-        // TODO
+        // Not a binding pattern.
         Pat::Expr(..) => pat,
     }
 }
+
+/// Replaces references to exported variables of a namespace with properties
+/// of the namespace object, like `tsc`.
+///
+/// ```ts
+/// namespace Foo {
+///     export let a = 1;
+///     a++;
+/// }
+/// ```
+///
+/// becomes
+///
+/// ```js
+/// (function (Foo1) {
+///     Foo1.a = 1;
+///     Foo1.a++;
+/// })(Foo || (Foo = {}));
+/// ```
+struct NamespaceExportRewriter {
+    namespace: Ident,
+    exported: FxHashSet<Id>,
+}
+
+impl NamespaceExportRewriter {
+    fn prop_of(&self, i: &Ident) -> Expr {
+        Expr::Member(MemberExpr {
+            span: i.span,
+            obj: self.namespace.clone().as_obj(),
+            prop: Box::new(Expr::Ident(Ident::new(
+                i.sym.clone(),
+                i.span.with_ctxt(SyntaxContext::empty()),
+            ))),
+            computed: false,
+        })
+    }
+
+    /// Stops replacing `ids` because they are shadowed, and returns ids which
+    /// should be restored.
+    fn shadow(&mut self, ids: Vec<Id>) -> Vec<Id> {
+        ids.into_iter()
+            .filter(|id| self.exported.remove(id))
+            .collect()
+    }
+
+    fn visit_mut_scope<N, B>(&mut self, params: &mut N, body: &mut B)
+    where
+        N: for<'any> VisitWith<swc_ecma_utils::DestructuringFinder<'any, Id>> + VisitMutWith<Self>,
+        B: for<'any> VisitWith<HoistedVarCollector<'any>> + VisitMutWith<Self>,
+    {
+        let mut ids: Vec<Id> = find_ids(&*params);
+        ids.extend(hoisted_var_ids(&*body));
+
+        let shadowed = self.shadow(ids);
+        params.visit_mut_with(self);
+        body.visit_mut_with(self);
+        self.exported.extend(shadowed);
+    }
+
+    /// Visits children of `node` while `ids` are declared in the scope of
+    /// `node`.
+    fn visit_mut_shadowing<N>(&mut self, ids: Vec<Id>, node: &mut N)
+    where
+        N: VisitMutWith<Self>,
+    {
+        let shadowed = self.shadow(ids);
+        node.visit_mut_children_with(self);
+        self.exported.extend(shadowed);
+    }
+}
+
+impl VisitMut for NamespaceExportRewriter {
+    noop_visit_mut_type!();
+
+    fn visit_mut_arrow_expr(&mut self, n: &mut ArrowExpr) {
+        self.visit_mut_scope(&mut n.params, &mut n.body);
+    }
+
+    fn visit_mut_block_stmt(&mut self, n: &mut BlockStmt) {
+        self.visit_mut_shadowing(lexical_ids(&n.stmts), n);
+    }
+
+    fn visit_mut_catch_clause(&mut self, n: &mut CatchClause) {
+        self.visit_mut_shadowing(find_ids(&n.param), n);
+    }
+
+    fn visit_mut_class_prop(&mut self, n: &mut ClassProp) {
+        if n.computed {
+            n.key.visit_mut_with(self);
+        }
+        n.value.visit_mut_with(self);
+    }
+
+    fn visit_mut_constructor(&mut self, n: &mut Constructor) {
+        self.visit_mut_scope(&mut n.params, &mut n.body);
+    }
+
+    fn visit_mut_expr(&mut self, e: &mut Expr) {
+        match e {
+            Expr::Ident(i) if self.exported.contains(&i.to_id()) => {
+                *e = self.prop_of(i);
+            }
+            _ => e.visit_mut_children_with(self),
+        }
+    }
+
+    fn visit_mut_for_in_stmt(&mut self, n: &mut ForInStmt) {
+        self.visit_mut_shadowing(lexical_ids_of_head(&n.left), n);
+    }
+
+    fn visit_mut_for_of_stmt(&mut self, n: &mut ForOfStmt) {
+        self.visit_mut_shadowing(lexical_ids_of_head(&n.left), n);
+    }
+
+    fn visit_mut_for_stmt(&mut self, n: &mut ForStmt) {
+        let ids = match &n.init {
+            Some(VarDeclOrExpr::VarDecl(v)) if v.kind != VarDeclKind::Var => find_ids(&v.decls),
+            _ => vec![],
+        };
+        self.visit_mut_shadowing(ids, n);
+    }
+
+    fn visit_mut_function(&mut self, n: &mut Function) {
+        n.decorators.visit_mut_with(self);
+        self.visit_mut_scope(&mut n.params, &mut n.body);
+    }
+
+    fn visit_mut_getter_prop(&mut self, n: &mut GetterProp) {
+        n.key.visit_mut_with(self);
+        self.visit_mut_scope(&mut Vec::<Pat>::new(), &mut n.body);
+    }
+
+    fn visit_mut_member_expr(&mut self, e: &mut MemberExpr) {
+        e.obj.visit_mut_with(self);
+
+        if e.computed {
+            e.prop.visit_mut_with(self);
+        }
+    }
+
+    fn visit_mut_object_pat_prop(&mut self, n: &mut ObjectPatProp) {
+        n.visit_mut_children_with(self);
+
+        if let ObjectPatProp::Assign(AssignPatProp { key, value, .. }) = n {
+            if self.exported.contains(&key.to_id()) {
+                let left = Pat::Expr(Box::new(self.prop_of(key)));
+                *n = ObjectPatProp::KeyValue(KeyValuePatProp {
+                    key: PropName::Ident(key.clone()),
+                    value: Box::new(match value.take() {
+                        Some(right) => Pat::Assign(AssignPat {
+                            span: DUMMY_SP,
+                            left: Box::new(left),
+                            right,
+                            type_ann: None,
+                        }),
+                        None => left,
+                    }),
+                });
+            }
+        }
+    }
+
+    /// Assignments to exported variables.
+    fn visit_mut_pat(&mut self, p: &mut Pat) {
+        match p {
+            Pat::Ident(i) if self.exported.contains(&i.id.to_id()) => {
+                *p = Pat::Expr(Box::new(self.prop_of(&i.id)));
+            }
+            _ => p.visit_mut_children_with(self),
+        }
+    }
+
+    fn visit_mut_prop(&mut self, p: &mut Prop) {
+        match p {
+            Prop::Shorthand(i) if self.exported.contains(&i.to_id()) => {
+                *p = Prop::KeyValue(KeyValueProp {
+                    key: PropName::Ident(i.clone()),
+                    value: Box::new(self.prop_of(i)),
+                });
+            }
+            _ => p.visit_mut_children_with(self),
+        }
+    }
+
+    fn visit_mut_prop_name(&mut self, n: &mut PropName) {
+        if let PropName::Computed(c) = n {
+            c.visit_mut_with(self);
+        }
+    }
+
+    fn visit_mut_setter_prop(&mut self, n: &mut SetterProp) {
+        n.key.visit_mut_with(self);
+        self.visit_mut_scope(&mut n.param, &mut n.body);
+    }
+
+    /// Declared names are not references.
+    fn visit_mut_var_declarator(&mut self, n: &mut VarDeclarator) {
+        n.init.visit_mut_with(self);
+    }
+}
@@ -0,0 +1,199 @@
+use std::rc::Rc;
+use swc_common::comments::SingleThreadedComments;
+use swc_ecma_parser::{Syntax, TsConfig};
+use swc_ecma_transforms_testing::Tester;
+use swc_ecma_transforms_typescript::dts::dts;
+
+fn syntax() -> Syntax {
+    Syntax::Typescript(TsConfig {
+        decorators: true,
+        ..Default::default()
+    })
+}
+
+/// Returns messages of errors if declarations can't be emitted.
+fn emit(src: &str) -> Result<String, Vec<&'static str>> {
+    Tester::run_with_module("input.ts", syntax(), src, |tester, module| {
+        match dts(&module) {
+            Ok(module) => Ok(tester.print(&module, &Rc::new(SingleThreadedComments::default()))),
+            Err(errors) => Err(errors.into_iter().map(|e| e.msg).collect()),
+        }
+    })
+}
+
+/// Normalizes `expected` by parsing and printing it.
+fn normalize(expected: &str) -> String {
+    let mut res = None;
+    Tester::run(|tester| {
+        let module =
+            tester.with_parser("expected.d.ts", syntax(), expected, |p| p.parse_module())?;
+
+        res = Some(tester.print(&module, &Rc::new(SingleThreadedComments::default())));
+        Ok(())
+    });
+    res.unwrap()
+}
+
+fn assert_dts(src: &str, expected: &str) {
+    assert_eq!(emit(src).unwrap(), normalize(expected));
+}
+
+#[test]
+fn functions_and_variables() {
+    assert_dts(
+        "
+        export function foo(a: string, b: number = 1, ...rest: boolean[]): string {
+            return a + b;
+        }
+        export async function bar({ a = 1 }: { a?: number } = {}): Promise<void> {}
+        export function overloaded(a: string): string;
+        export function overloaded(a: number): number;
+        export function overloaded(a: any) {
+            return a;
+        }
+        export const a: number = foo('', 1).length;
+        export const b = 'literal', c = -1;
+        export let d = 'widened';
+        export const f = (x: number): string => `${x}`;
+        export const g = {} as Record<string, number>;
+        ",
+        "
+        export declare function foo(a: string, b?: number, ...rest: boolean[]): string;
+        export declare function bar({ a }?: { a?: number }): Promise<void>;
+        export declare function overloaded(a: string): string;
+        export declare function overloaded(a: number): number;
+        export declare const a: number;
+        export declare const b: 'literal', c: -1;
+        export declare let d: string;
+        export declare const f: (x: number) => string;
+        export declare const g: Record<string, number>;
+        ",
+    );
+}
+
+#[test]
+fn classes() {
+    assert_dts(
+        "
+        export abstract class Foo<T> extends Base implements Bar {
+            #secret = 1;
+            static count = 0;
+            readonly name = 'foo';
+            private cache = new Map();
+            value?: T;
+
+            constructor(public readonly id: string, private store: Store, size: number = 1) {
+                super();
+            }
+
+            get size(): number {
+                return 1;
+            }
+
+            set size(v: number) {}
+
+            private helper() {}
+
+            abstract run(input: T): void;
+
+            @dec
+            method<U>(a: U): U {
+                return a;
+            }
+        }
+        ",
+        "
+        export declare abstract class Foo<T> extends Base implements Bar {
+            #private;
+            static count: number;
+            readonly name: 'foo';
+            private cache;
+            value?: T;
+            public readonly id: string;
+            private store;
+            constructor(id: string, store: Store, size?: number);
+            get size(): number;
+            set size(v: number);
+            private helper;
+            abstract run(input: T): void;
+            method<U>(a: U): U;
+        }
+        ",
+    );
+}
+
+#[test]
+fn types_and_namespaces() {
+    assert_dts(
+        "
+        import { Dep } from './dep';
+        export interface Props { dep: Dep }
+        export type Alias = Props | string;
+        interface Local { a: number }
+        export const enum Kind { A = 1, B = 'b' }
+        export namespace A.B {
+            export function c(): void {}
+            export const d: Local = { a: 1 };
+        }
+        function notExported() {}
+        const value = 1;
+        notExported();
+        ",
+        "
+        import { Dep } from './dep';
+        export interface Props { dep: Dep }
+        export type Alias = Props | string;
+        interface Local { a: number }
+        export declare const enum Kind { A = 1, B = 'b' }
+        export declare module A.B {
+            export function c(): void;
+            export const d: Local;
+        }
+        export {};
+        ",
+    );
+}
+
+#[test]
+fn local_exports() {
+    assert_dts(
+        "
+        function foo(): void {}
+        const bar: string = '';
+        export { foo, bar as baz };
+        export default foo;
+        ",
+        "
+        declare function foo(): void;
+        declare const bar: string;
+        export { foo, bar as baz };
+        export default foo;
+        export {};
+        ",
+    );
+}
+
+#[test]
+fn requires_inference() {
+    assert_eq!(
+        emit(
+            "
+            export function foo(a) {
+                return 1;
+            }
+            export const bar = foo(1);
+            export class Baz {
+                prop = foo(1);
+            }
+            export default foo(2);
+            ",
+        ),
+        Err(vec![
+            "function must have an explicit return type annotation to emit declarations",
+            "parameter must have an explicit type annotation to emit declarations",
+            "variable must have an explicit type annotation to emit declarations",
+            "property must have an explicit type annotation to emit declarations",
+            "default export must be an identifier to emit declarations",
+        ])
+    );
+}
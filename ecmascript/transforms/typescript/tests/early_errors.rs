@@ -0,0 +1,191 @@
+use swc_ecma_parser::{Syntax, TsConfig};
+use swc_ecma_transforms_testing::Tester;
+use swc_ecma_transforms_typescript::early_errors::{check_early_errors, Config};
+
+/// Returns messages of errors.
+fn check(src: &str, config: Config) -> Vec<String> {
+    Tester::run_with_module(
+        "input.ts",
+        Syntax::Typescript(TsConfig {
+            dts: config.dts,
+            ..Default::default()
+        }),
+        src,
+        |_, module| {
+            check_early_errors(&module, config)
+                .into_iter()
+                .map(|e| e.msg)
+                .collect()
+        },
+    )
+}
+
+const MISSING_IMPL: &str =
+    "Function implementation is missing or not immediately following the declaration.";
+
+#[test]
+fn valid() {
+    assert_eq!(
+        check(
+            "
+            export function f(a: string): void;
+            export function f(a: number): void;
+            export function f(a: any) {}
+            declare function g(): void;
+            declare const a = 1, b = -1n;
+            declare namespace N {
+                function h(): void;
+                class C {
+                    m(): void;
+                    static readonly x = 'x';
+                }
+            }
+            abstract class A<T> {
+                constructor(a: string);
+                constructor(a: any) {}
+                abstract m(): void;
+                protected abstract p: string;
+                n?(): void;
+                o(): void;
+                o() {}
+            }
+            ",
+            Default::default()
+        ),
+        Vec::<String>::new()
+    );
+}
+
+#[test]
+fn reserved_type_names() {
+    assert_eq!(
+        check(
+            "
+            type string = number;
+            enum number {}
+            class symbol {}
+            function f<undefined>() {}
+            ",
+            Default::default()
+        ),
+        vec![
+            "Type alias name cannot be 'string'.",
+            "Enum name cannot be 'number'.",
+            "Class name cannot be 'symbol'.",
+            "Type parameter name cannot be 'undefined'.",
+        ]
+    );
+}
+
+#[test]
+fn function_overloads() {
+    assert_eq!(
+        check(
+            "
+            function f(): void;
+            function g() {}
+            function h(): void;
+            const a = 1;
+            function i(): void;
+            ",
+            Default::default()
+        ),
+        vec![
+            "Function implementation name must be 'f'.",
+            MISSING_IMPL,
+            MISSING_IMPL,
+        ]
+    );
+}
+
+#[test]
+fn method_overloads() {
+    assert_eq!(
+        check(
+            "
+            class A {
+                constructor();
+                m(): void;
+                n() {}
+                o(): void;
+                static o() {}
+                p(): void;
+            }
+            ",
+            Default::default()
+        ),
+        vec![
+            "Constructor implementation is missing.",
+            "Function implementation name must be 'm'.",
+            MISSING_IMPL,
+            MISSING_IMPL,
+        ]
+    );
+}
+
+#[test]
+fn abstract_members() {
+    assert_eq!(
+        check(
+            "
+            class A {
+                abstract m(): void;
+                abstract p: string;
+            }
+            abstract class B {
+                abstract m() {}
+                abstract p = 1;
+                private abstract q: string;
+            }
+            ",
+            Default::default()
+        ),
+        vec![
+            "Abstract methods can only appear within an abstract class.",
+            "Abstract properties can only appear within an abstract class.",
+            "Method 'm' cannot have an implementation because it is marked abstract.",
+            "Property 'p' cannot have an initializer because it is marked abstract.",
+            "'private' modifier cannot be used with 'abstract' modifier.",
+        ]
+    );
+}
+
+#[test]
+fn ambient_initializers() {
+    assert_eq!(
+        check(
+            "
+            declare let a = 1;
+            declare const b = foo();
+            declare class C {
+                x = 1;
+            }
+            class D {
+                declare y: number = 1;
+            }
+            ",
+            Default::default()
+        ),
+        vec![
+            "Initializers are not allowed in ambient contexts.",
+            "A 'const' initializer in an ambient context must be a string or numeric literal or \
+             literal enum reference.",
+            "Initializers are not allowed in ambient contexts.",
+            "Initializers are not allowed in ambient contexts.",
+        ]
+    );
+}
+
+#[test]
+fn dts() {
+    assert_eq!(
+        check(
+            "
+            export function f(): void;
+            export let a = 1;
+            ",
+            Config { dts: true }
+        ),
+        vec!["Initializers are not allowed in ambient contexts."]
+    );
+}
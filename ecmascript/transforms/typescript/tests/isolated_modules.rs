@@ -0,0 +1,139 @@
+use swc_ecma_parser::{Syntax, TsConfig};
+use swc_ecma_transforms_testing::Tester;
+use swc_ecma_transforms_typescript::isolated_modules::{check_isolated_modules, Config};
+
+/// Returns messages of errors.
+fn check(src: &str, config: Config) -> Vec<&'static str> {
+    Tester::run_with_module(
+        "input.ts",
+        Syntax::Typescript(TsConfig {
+            tsx: true,
+            ..Default::default()
+        }),
+        src,
+        |_, module| {
+            check_isolated_modules(&module, config)
+                .into_iter()
+                .map(|e| e.msg)
+                .collect()
+        },
+    )
+}
+
+const RE_EXPORT: &str =
+    "re-exporting a type requires `export type` when `isolatedModules` is enabled";
+
+#[test]
+fn valid_module() {
+    assert_eq!(
+        check(
+            "
+            import { Foo, Bar } from './foo';
+            import type { Baz } from './baz';
+            export { Foo };
+            export type { Baz };
+            export { Qux } from './qux';
+            const foo: Foo = new Foo();
+            const el = <Bar />;
+            ",
+            Default::default()
+        ),
+        Vec::<&str>::new()
+    );
+}
+
+#[test]
+fn re_exported_types() {
+    assert_eq!(
+        check(
+            "
+            import type { A } from './a';
+            import { B, C, D } from './b';
+            export { A, B, C };
+            export { D as E } from './b';
+            let b: B;
+            let c: C = C;
+            let d: D;
+            ",
+            Default::default()
+        ),
+        vec![RE_EXPORT, RE_EXPORT, RE_EXPORT]
+    );
+}
+
+#[test]
+fn typeof_is_a_value() {
+    assert_eq!(
+        check(
+            "
+            import { A } from './a';
+            export { A };
+            let a: typeof A;
+            ",
+            Default::default()
+        ),
+        Vec::<&str>::new()
+    );
+}
+
+#[test]
+fn ambient_const_enums() {
+    assert_eq!(
+        check(
+            "
+            declare const enum A { X }
+            declare global {
+                const enum B { Y }
+            }
+            const enum C { Z }
+            let a: A = A.X;
+            let b = B.Y, c = C.Z;
+            export {};
+            ",
+            Default::default()
+        ),
+        vec![
+            "ambient const enums can't be accessed when `isolatedModules` is enabled",
+            "ambient const enums can't be accessed when `isolatedModules` is enabled",
+        ]
+    );
+}
+
+#[test]
+fn global_script() {
+    assert_eq!(
+        check("const a = 1;", Default::default()),
+        vec![
+            "a file must be a module when `isolatedModules` is enabled; add an import, an export \
+             or an empty `export {}`"
+        ]
+    );
+}
+
+#[test]
+fn export_assignment() {
+    let src = "
+    import fs = require('fs');
+    export = fs;
+    ";
+    assert_eq!(check(src, Config { esm: false }), Vec::<&str>::new());
+    assert_eq!(
+        check(src, Config { esm: true }),
+        vec![
+            "`import = require()` can't be used when targeting ECMAScript modules; use `import` \
+             or another module format",
+            "`export =` can't be used when targeting ECMAScript modules; use `export default` or \
+             another module format",
+        ]
+    );
+    assert_eq!(
+        check(
+            "
+            export const a = 1;
+            export = a;
+            ",
+            Config { esm: false }
+        ),
+        vec!["`export =` can't be used in a module with other exported elements"]
+    );
+}
@@ -1,6 +1,6 @@
-use swc_common::chain;
+use swc_common::{chain, Mark};
 use swc_ecma_parser::{Syntax, TsConfig};
-use swc_ecma_transforms_base::resolver::resolver;
+use swc_ecma_transforms_base::resolver::{resolver, ts_resolver};
 use swc_ecma_transforms_compat::es2017::async_to_generator;
 use swc_ecma_transforms_compat::es2020::class_properties;
 use swc_ecma_transforms_compat::es2020::nullish_coalescing;
@@ -9,6 +9,7 @@ use swc_ecma_transforms_proposal::decorators;
 use swc_ecma_transforms_testing::test;
 use swc_ecma_transforms_testing::test_exec;
 use swc_ecma_transforms_typescript::strip;
+use swc_ecma_transforms_typescript::strip::strip_with_comments;
 use swc_ecma_transforms_typescript::strip::strip_with_config;
 use swc_ecma_visit::Fold;
 
@@ -713,7 +714,7 @@ to!(
     "export abstract class Kernel {
   [key: string]: any
 }",
-    "export abstract class Kernel {}"
+    "export class Kernel {}"
 );
 
 to!(
@@ -3451,13 +3452,11 @@ to!(
     "
     var Test;
     (function(Test1) {
-        var DummyValues;
         (function(DummyValues) {
             DummyValues['A'] = 'A';
             DummyValues['B'] = 'B';
-        })(DummyValues || (DummyValues = {
+        })(Test1.DummyValues || (Test1.DummyValues = {
         }));
-        Test1.DummyValues = DummyValues;
     })(Test || (Test = {
     }));
     console(Test.DummyValues.A);
@@ -3983,3 +3982,405 @@ to!(
     console.log({ foo: 1 });
     "
 );
+
+test!(
+    Syntax::Typescript(Default::default()),
+    |t| strip_with_comments(
+        strip::Config {
+            no_empty_export: true,
+            ..Default::default()
+        },
+        Some(t.comments.clone())
+    ),
+    const_enum_inline,
+    "
+    const enum Direction {
+        Up,
+        Down = Up + 2,
+        Left = Direction.Down * 2,
+        Right = -1,
+    }
+    const enum Other {
+        A = Direction.Left | 1,
+        B = 'b',
+    }
+    declare const enum Ambient {
+        C = 3,
+    }
+    console.log(Direction.Up, Direction['Down'], Other.A, Other.B, Direction.Right, Ambient.C);
+    ",
+    "
+    console.log(/* Direction.Up */ 0, /* Direction.Down */ 2, /* Other.A */ 5, /* Other.B */ 'b', \
+     /* Direction.Right */ -1, /* Ambient.C */ 3);
+    ",
+    ok_if_code_eq
+);
+
+test!(
+    Syntax::Typescript(Default::default()),
+    |t| strip_with_comments(
+        strip::Config {
+            no_empty_export: true,
+            preserve_const_enums: true,
+            ..Default::default()
+        },
+        Some(t.comments.clone())
+    ),
+    const_enum_preserve,
+    "
+    const enum Foo {
+        A = 1,
+    }
+    Foo.A;
+    ",
+    "
+    var Foo;
+    (function (Foo) {
+        Foo[Foo['A'] = 1] = 'A';
+    })(Foo || (Foo = {}));
+    /* Foo.A */ 1;
+    ",
+    ok_if_code_eq
+);
+
+test!(
+    Syntax::Typescript(Default::default()),
+    |t| strip_with_comments(
+        strip::Config {
+            no_empty_export: true,
+            ..Default::default()
+        },
+        Some(t.comments.clone())
+    ),
+    const_enum_in_namespace,
+    "
+    namespace A {
+        export namespace B {
+            export const enum E {
+                X = 1,
+            }
+        }
+        console.log(B.E.X);
+    }
+    console.log(A.B.E.X);
+    ",
+    "
+    var A;
+    (function (A1) {
+        console.log(/* B.E.X */ 1);
+    })(A || (A = {}));
+    console.log(/* A.B.E.X */ 1);
+    ",
+    ok_if_code_eq
+);
+
+to!(
+    const_enum_not_computable,
+    "
+    const enum Foo {
+        A = bar(),
+    }
+    Foo.A;
+    ",
+    "
+    var Foo;
+    (function (Foo) {
+        Foo[Foo['A'] = bar()] = 'A';
+    })(Foo || (Foo = {}));
+    Foo.A;
+    "
+);
+
+test!(
+    Syntax::Typescript(Default::default()),
+    |t| strip_with_comments(
+        strip::Config {
+            no_empty_export: true,
+            ..Default::default()
+        },
+        Some(t.comments.clone())
+    ),
+    const_enum_shadowed,
+    "
+    const enum E {
+        A = 1,
+    }
+    namespace Ns {
+        export const enum F {
+            B = 2,
+        }
+    }
+    function f(E) {
+        return E.A;
+    }
+    function g() {
+        var Ns = { F: { B: 3 } };
+        return Ns.F.B;
+    }
+    try {
+    } catch (E) {
+        E.A;
+    }
+    {
+        let E = { A: 4 };
+        E.A;
+    }
+    for (const E of []) {
+        E.A;
+    }
+    E.A;
+    ",
+    "
+    function f(E) {
+        return E.A;
+    }
+    function g() {
+        var Ns = { F: { B: 3 } };
+        return Ns.F.B;
+    }
+    try {
+    } catch (E) {
+        E.A;
+    }
+    {
+        let E = { A: 4 };
+        E.A;
+    }
+    for (const E of []) {
+        E.A;
+    }
+    /* E.A */ 1;
+    ",
+    ok_if_code_eq
+);
+
+test!(
+    Syntax::Typescript(Default::default()),
+    |t| chain!(
+        ts_resolver(Mark::fresh(Mark::root())),
+        strip_with_comments(
+            strip::Config {
+                no_empty_export: true,
+                ..Default::default()
+            },
+            Some(t.comments.clone())
+        )
+    ),
+    const_enum_in_block,
+    "
+    const enum E {
+        A = 1,
+    }
+    {
+        const enum E {
+            A = 2,
+        }
+        E.A;
+    }
+    E.A;
+    ",
+    "
+    {
+        var E;
+        (function (E) {
+            E[E['A'] = 2] = 'A';
+        })(E || (E = {}));
+        E.A;
+    }
+    /* E.A */ 1;
+    ",
+    ok_if_code_eq
+);
+
+to!(
+    namespace_exported_destructuring,
+    "
+    namespace Foo {
+        export const { a, b = 1, ...rest } = obj;
+        export const [c, ...d] = arr;
+    }
+    ",
+    "
+    var Foo;
+    (function(Foo1) {
+        ({ a: Foo1.a, b: Foo1.b = 1, ...Foo1.rest } = obj);
+        [Foo1.c, ...Foo1.d] = arr;
+    })(Foo || (Foo = {
+    }));
+    "
+);
+
+to!(
+    namespace_exported_var_references,
+    "
+    namespace Counter {
+        export let count = 0;
+        export function inc() {
+            count++;
+        }
+        function reset(count: number) {
+            return count;
+        }
+    }
+    ",
+    "
+    var Counter;
+    (function(Counter1) {
+        Counter1.count = 0;
+        function inc() {
+            Counter1.count++;
+        }
+        Counter1.inc = inc;
+        function reset(count) {
+            return count;
+        }
+    })(Counter || (Counter = {
+    }));
+    "
+);
+
+to!(
+    namespace_exported_var_block_scope,
+    "
+    namespace Ns {
+        export let x = 1;
+        function f() {
+            {
+                let x = 2;
+                x++;
+            }
+            return x;
+        }
+        if (x) {
+            const x = 3;
+            console.log(x);
+        }
+        for (let x = 0; x < 1; x++) {}
+        try {
+        } catch (x) {
+            x;
+        }
+        x;
+    }
+    ",
+    "
+    var Ns;
+    (function(Ns1) {
+        Ns1.x = 1;
+        function f() {
+            {
+                let x = 2;
+                x++;
+            }
+            return Ns1.x;
+        }
+        if (Ns1.x) {
+            const x = 3;
+            console.log(x);
+        }
+        for(let x = 0; x < 1; x++){}
+        try {
+        } catch (x) {
+            x;
+        }
+        Ns1.x;
+    })(Ns || (Ns = {
+    }));
+    "
+);
+
+to!(
+    namespace_nested_name,
+    "
+    namespace A.B.C {
+        export const d = 1;
+    }
+    ",
+    "
+    var A;
+    (function(A1) {
+        (function(B) {
+            (function(C) {
+                C.d = 1;
+            })(B.C || (B.C = {
+            }));
+        })(A1.B || (A1.B = {
+        }));
+    })(A || (A = {
+    }));
+    "
+);
+
+to!(
+    namespace_merging,
+    "
+    namespace Foo {
+        export const a = 1;
+    }
+    namespace Foo {
+        export namespace Bar {
+            export const b = 2;
+        }
+        console.log(a, Bar.b);
+    }
+    ",
+    "
+    var Foo;
+    (function(Foo1) {
+        Foo1.a = 1;
+    })(Foo || (Foo = {
+    }));
+    (function(Foo1) {
+        (function(Bar) {
+            Bar.b = 2;
+        })(Foo1.Bar || (Foo1.Bar = {
+        }));
+        console.log(a, Foo1.Bar.b);
+    })(Foo || (Foo = {
+    }));
+    "
+);
+
+to!(
+    namespace_import_require,
+    "
+    export import fs = require('fs');
+    ",
+    "
+    export var fs = require('fs');
+    "
+);
+
+to!(
+    namespace_alias_used_as_value,
+    "
+    namespace N {
+        export const value = 1;
+    }
+    import V = N.value;
+    console.log(V);
+    ",
+    "
+    var N;
+    (function(N1) {
+        N1.value = 1;
+    })(N || (N = {
+    }));
+    var V = N.value;
+    console.log(V);
+    "
+);
+
+to!(
+    namespace_alias_used_as_type,
+    "
+    namespace N {
+        export interface I {}
+    }
+    import I = N.I;
+    let x: I;
+    ",
+    "
+    let x;
+    "
+);
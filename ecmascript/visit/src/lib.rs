@@ -431,6 +431,7 @@ define!({
         PrivateProp(PrivateProp),
         TsIndexSignature(TsIndexSignature),
         Empty(EmptyStmt),
+        AutoAccessor(AutoAccessor),
     }
 
     pub struct ClassProp {
@@ -464,6 +465,19 @@ define!({
         pub readonly: bool,
         pub definite: bool,
     }
+    pub struct AutoAccessor {
+        pub span: Span,
+        pub key: Key,
+        pub value: Option<Box<Expr>>,
+        pub type_ann: Option<TsTypeAnn>,
+        pub is_static: bool,
+        pub decorators: Vec<Decorator>,
+        pub accessibility: Option<Accessibility>,
+    }
+    pub enum Key {
+        Private(PrivateName),
+        Public(PropName),
+    }
     pub struct ClassMethod {
         pub span: Span,
         pub key: PropName,
@@ -0,0 +1,123 @@
+use crate::{
+    get_compiler,
+    util::{CtxtExt, MapErr},
+};
+use anyhow::Context as _;
+use napi::{CallContext, Env, JsObject, JsString, Task};
+use path_clean::clean;
+use std::{path::PathBuf, sync::Arc};
+use swc::{config::Options, CheckDiagnostic, Compiler};
+use swc_common::{FileName, SourceFile};
+
+// ----- Checking -----
+
+pub enum Input {
+    /// Raw source code.
+    Source(Arc<SourceFile>),
+    /// File
+    File(PathBuf),
+}
+
+pub struct CheckTask {
+    pub c: Arc<Compiler>,
+    pub input: Input,
+    pub options: Options,
+}
+
+pub fn complete_check(env: &Env, diagnostics: Vec<CheckDiagnostic>) -> napi::Result<JsObject> {
+    env.to_js_value(&diagnostics)?.coerce_to_object()
+}
+
+fn check_input(
+    c: &Compiler,
+    input: &Input,
+    options: &Options,
+) -> napi::Result<Vec<CheckDiagnostic>> {
+    c.run(|| {
+        let fm = match input {
+            Input::Source(fm) => fm.clone(),
+            Input::File(path) => {
+                c.cm.load_file(path)
+                    .context("failed to read module")
+                    .convert_err()?
+            }
+        };
+
+        c.check(fm, options).convert_err()
+    })
+}
+
+impl Task for CheckTask {
+    type Output = Vec<CheckDiagnostic>;
+    type JsValue = JsObject;
+
+    fn compute(&mut self) -> napi::Result<Self::Output> {
+        check_input(&self.c, &self.input, &self.options)
+    }
+
+    fn resolve(self, env: Env, result: Self::Output) -> napi::Result<Self::JsValue> {
+        complete_check(&env, result)
+    }
+}
+
+fn source_input(c: &Compiler, src: String, options: &Options) -> Input {
+    Input::Source(c.cm.new_source_file(
+        if options.filename.is_empty() {
+            FileName::Anon
+        } else {
+            FileName::Real(options.filename.clone().into())
+        },
+        src,
+    ))
+}
+
+#[js_function(2)]
+pub fn check(cx: CallContext) -> napi::Result<JsObject> {
+    let c = get_compiler(&cx);
+    let src = cx.get::<JsString>(0)?.into_utf8()?.as_str()?.to_owned();
+    let options: Options = cx.get_deserialized(1)?;
+
+    let input = source_input(&c, src, &options);
+
+    cx.env
+        .spawn(CheckTask { c, input, options })
+        .map(|t| t.promise_object())
+}
+
+#[js_function(2)]
+pub fn check_sync(cx: CallContext) -> napi::Result<JsObject> {
+    let c = get_compiler(&cx);
+    let src = cx.get::<JsString>(0)?.into_utf8()?.as_str()?.to_owned();
+    let options: Options = cx.get_deserialized(1)?;
+
+    let input = source_input(&c, src, &options);
+    let diagnostics = check_input(&c, &input, &options)?;
+
+    complete_check(cx.env, diagnostics)
+}
+
+#[js_function(2)]
+pub fn check_file(cx: CallContext) -> napi::Result<JsObject> {
+    let c = get_compiler(&cx);
+    let path = clean(cx.get::<JsString>(0)?.into_utf8()?.as_str()?);
+    let options: Options = cx.get_deserialized(1)?;
+
+    cx.env
+        .spawn(CheckTask {
+            c,
+            input: Input::File(path.into()),
+            options,
+        })
+        .map(|t| t.promise_object())
+}
+
+#[js_function(2)]
+pub fn check_file_sync(cx: CallContext) -> napi::Result<JsObject> {
+    let c = get_compiler(&cx);
+    let path = clean(cx.get::<JsString>(0)?.into_utf8()?.as_str()?);
+    let options: Options = cx.get_deserialized(1)?;
+
+    let diagnostics = check_input(&c, &Input::File(path.into()), &options)?;
+
+    complete_check(cx.env, diagnostics)
+}
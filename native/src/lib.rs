@@ -17,6 +17,7 @@ use swc_common::{
 };
 
 mod bundle;
+mod check;
 mod parse;
 mod print;
 mod transform;
@@ -60,6 +61,11 @@ fn init(mut exports: JsObject) -> napi::Result<()> {
 
     exports.create_named_method("bundle", bundle::bundle)?;
 
+    exports.create_named_method("check", check::check)?;
+    exports.create_named_method("checkSync", check::check_sync)?;
+    exports.create_named_method("checkFile", check::check_file)?;
+    exports.create_named_method("checkFileSync", check::check_file_sync)?;
+
     Ok(())
 }
 
@@ -1,4 +1,5 @@
 import {
+  CheckDiagnostic,
   Plugin,
   ParseOptions,
   Module,
@@ -215,6 +216,25 @@ export class Compiler {
     return bindings.transformFileSync(path, /* isModule */ false, toBuffer(newOptions));
   }
 
+  /**
+   * Parses `src` and reports syntax errors and early errors of typescript,
+   * without transforming it.
+   */
+  async check(src: string, options?: Options): Promise<CheckDiagnostic[]> {
+    return bindings.check(src, toBuffer(options ?? {}));
+  }
+
+  checkSync(src: string, options?: Options): CheckDiagnostic[] {
+    return bindings.checkSync(src, toBuffer(options ?? {}));
+  }
+
+  async checkFile(path: string, options?: Options): Promise<CheckDiagnostic[]> {
+    return bindings.checkFile(path, toBuffer(options ?? {}));
+  }
+
+  checkFileSync(path: string, options?: Options): CheckDiagnostic[] {
+    return bindings.checkFileSync(path, toBuffer(options ?? {}));
+  }
 
   async bundle(options?: BundleInput | string): Promise<{ [name: string]: Output }> {
     const opts = await compileBundleOptions(options);
@@ -316,6 +336,28 @@ export function transformFileSync(path: string, options?: Options): Output {
   return compiler.transformFileSync(path, options);
 }
 
+export function check(
+  src: string,
+  options?: Options
+): Promise<CheckDiagnostic[]> {
+  return compiler.check(src, options);
+}
+
+export function checkSync(src: string, options?: Options): CheckDiagnostic[] {
+  return compiler.checkSync(src, options);
+}
+
+export function checkFile(
+  path: string,
+  options?: Options
+): Promise<CheckDiagnostic[]> {
+  return compiler.checkFile(path, options);
+}
+
+export function checkFileSync(path: string, options?: Options): CheckDiagnostic[] {
+  return compiler.checkFileSync(path, options);
+}
+
 export function bundle(
   options?: BundleInput | string
 ): Promise<{ [name: string]: Output }> {
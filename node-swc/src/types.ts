@@ -129,6 +129,18 @@ export interface Options extends Config {
    */
   swcrc?: boolean;
 
+  /**
+   * Read `compilerOptions` of the nearest `tsconfig.json`, following
+   * `extends`.
+   *
+   * Options of `.swcrc` and `configFile` take precedence over options of
+   * `tsconfig.json`. The typescript parser is used only for `.ts` and `.tsx`
+   * files.
+   *
+   * Defaults to `false`.
+   */
+  tsconfig?: boolean;
+
   /**
    * By default, Babel will only search for .babelrc files within the "root" package
    *  because otherwise Babel cannot know if a given .babelrc is meant to be loaded,
@@ -213,6 +225,13 @@ export interface Config {
    *  and handle the rest in your own code, depending on your use case.
    */
   sourceMaps?: boolean | "inline";
+
+  /**
+   * Emit typescript declarations (`.d.ts`) to `Output.dts`.
+   *
+   * Types are not inferred, so exported declarations should have explicit type annotations.
+   */
+  declaration?: boolean;
 }
 
 /**
@@ -247,6 +266,18 @@ export interface EnvConfig {
 
   shippedProposals?: boolean;
 
+  /**
+   * Polyfill all proposals supported by core-js.
+   */
+  proposals?: boolean;
+
+  /**
+   * Path of the core-js package used for injected imports.
+   *
+   * Defaults to `core-js`.
+   */
+  polyfillPath?: string;
+
   /**
    * Enable all trnasforms
    */
@@ -270,6 +301,117 @@ export interface JscConfig {
    * Defaults to `es3` (which enableds **all** pass).
    */
   target?: JscTarget;
+
+  /**
+   * Reports syntax in the output which is not supported by `target`.
+   *
+   * Trailing commas are only checked in array literals. The output does not
+   * contain trailing commas of parameters, arguments and object literals.
+   */
+  checkTarget?: "warn" | "error";
+
+  /**
+   * Reports typescript code which can't be transpiled file-by-file, like
+   * `isolatedModules` of `tsc`.
+   *
+   * Defaults to `false`.
+   */
+  isolatedModules?: boolean;
+
+  /**
+   * Enables builtin lint rules. Rules which are not configured use their
+   * default levels.
+   *
+   * A problem can be suppressed with `// swc-disable-next-line rule-id`.
+   */
+  lints?: LintConfig;
+
+  /**
+   * Base directory of `paths`.
+   */
+  baseUrl?: string;
+
+  /**
+   * Aliases of module specifiers, like `paths` of `tsconfig.json`.
+   */
+  paths?: {
+    [from: string]: string[];
+  };
+
+  experimental?: {
+    /**
+     * Plugins, which are applied in order before any builtin pass.
+     *
+     * Each plugin is a tuple of the path to the plugin, which is resolved
+     * against `cwd`, and the config passed to the plugin. Paths ending with
+     * `.wasm` are loaded as sandboxed wasm plugins, and other paths are
+     * loaded as native shared libraries.
+     *
+     * Not supported by `@swc/wasm`.
+     */
+    plugins?: [string, any][];
+
+    /**
+     * Limits of each invocation of a wasm plugin.
+     */
+    pluginLimits?: {
+      /**
+       * Maximum size of the memory of a plugin, in bytes.
+       *
+       * Defaults to 512 MiB.
+       */
+      maxMemory?: number;
+      /**
+       * In milliseconds.
+       *
+       * Defaults to `30000`.
+       */
+      timeout?: number;
+    };
+  };
+}
+
+export type LintRuleLevel = "off" | "warn" | "error";
+
+/**
+ * `"warn"` or `["warn", { ...options }]`
+ */
+export type LintRuleConfig<T = never> = LintRuleLevel | [LintRuleLevel, T];
+
+/**
+ * Rules default to `"error"`, except `no-unused-vars`.
+ */
+export interface LintConfig {
+  "no-undef"?: LintRuleConfig<{
+    /**
+     * Global variables other than builtins of ECMAScript, like `console`.
+     */
+    globals?: string[];
+    /**
+     * Reports `typeof a` if `a` is not defined.
+     */
+    checkTypeof?: boolean;
+  }>;
+  /**
+   * Defaults to `"warn"`.
+   */
+  "no-unused-vars"?: LintRuleConfig<{
+    /**
+     * Reports unused parameters after the last used one.
+     *
+     * Defaults to `true`.
+     */
+    args?: boolean;
+    /**
+     * Regex of names which are not reported, like `^_`.
+     */
+    ignorePattern?: string;
+  }>;
+  "no-dupe-keys"?: LintRuleConfig;
+  "no-const-assign"?: LintRuleConfig;
+  "no-unreachable"?: LintRuleConfig;
+  "getter-return"?: LintRuleConfig;
+  "no-duplicate-case"?: LintRuleConfig;
 }
 
 export type JscTarget =
@@ -384,8 +526,38 @@ export interface TransformConfig {
 
   /**
    * https://swc.rs/docs/configuring-swc.html#jsctransformdecoratormetadata
+   *
+   * Requires `legacyDecorator` or `decoratorVersion: "2022-03"`.
    */
   decoratorMetadata?: boolean;
+
+  /**
+   * Version of the decorators proposal, used unless `legacyDecorator` is set.
+   *
+   * `2022-03` supports `context` objects, auto-accessors and `Symbol.metadata`.
+   *
+   * Defaults to `2018-09`.
+   */
+  decoratorVersion?: "2018-09" | "2022-03";
+
+  /**
+   * Emit `const enum` declarations instead of removing them.
+   *
+   * Members of const enums are inlined regardless of this option.
+   */
+  preserveConstEnums?: boolean;
+
+  /**
+   * Align the semantics of typescript class fields with TC39 class fields.
+   *
+   * Defaults to `false`.
+   */
+  useDefineForClassFields?: boolean;
+
+  /**
+   * Defaults to `remove`.
+   */
+  importsNotUsedAsValues?: "remove" | "preserve";
 }
 
 export interface ReactConfig {
@@ -414,6 +586,9 @@ export interface ReactConfig {
    * Toggles plugins that aid in development, such as @swc/plugin-transform-react-jsx-self
    * and @swc/plugin-transform-react-jsx-source.
    *
+   * With `runtime: 'automatic'`, `jsxDEV` of `jsx-dev-runtime` is used instead, and
+   * the source location and `this` are passed as its arguments.
+   *
    * Defaults to `false`,
    *
    */
@@ -435,6 +610,8 @@ export interface ReactConfig {
 
   /**
    * Declares the module specifier to be used for importing the `jsx` and `jsxs` factory functions when using `runtime` 'automatic'
+   *
+   * This can be overridden per file with a `@jsxImportSource` pragma comment.
    */
   importSource: string
 }
@@ -479,7 +656,11 @@ export interface GlobalPassOption {
   envs?: string[];
 }
 
-export type ModuleConfig = CommonJsConfig | UmdConfig | AmdConfig;
+export type ModuleConfig =
+  | CommonJsConfig
+  | UmdConfig
+  | AmdConfig
+  | SystemJsConfig;
 
 export interface BaseModuleConfig {
   /**
@@ -563,6 +744,18 @@ export interface AmdConfig extends BaseModuleConfig {
   moduleId: string;
 }
 
+export interface SystemJsConfig {
+  type: "systemjs";
+  moduleId?: string;
+
+  /**
+   * Emits 'use strict' directive.
+   *
+   * Defaults to `true`.
+   */
+  strictMode?: boolean;
+}
+
 export interface Output {
   /**
    * Transformed code
@@ -572,6 +765,38 @@ export interface Output {
    * Sourcemap (**not** base64 encoded)
    */
   map?: string;
+  /**
+   * Typescript declarations, if `declaration` is enabled.
+   */
+  dts?: string;
+  /**
+   * Module specifiers of the polyfills injected into the file, if `env` is
+   * enabled.
+   */
+  injectedPolyfills?: string[];
+}
+
+/**
+ * An error reported by `check()`.
+ */
+export interface CheckDiagnostic {
+  message: string;
+  /**
+   * 1-based line of the start of the error.
+   */
+  line: number;
+  /**
+   * 0-based column of the start of the error.
+   */
+  column: number;
+  /**
+   * Byte offset of the start of the error.
+   */
+  start: number;
+  /**
+   * Byte offset of the end of the error.
+   */
+  end: number;
 }
 
 export interface MatchPattern { }
@@ -0,0 +1,233 @@
+//! Abi of native plugins of swc.
+//!
+//! A plugin is a `cdylib` which exports the functions declared by
+//! [plugin!]. The [Program] and the config of the plugin are passed as json,
+//! so a plugin doesn't have to be compiled with the same version of rustc as
+//! swc. It should use the same version of `swc_ecma_ast` though, as the json
+//! is the serde representation of the ast.
+//!
+//! # Example
+//!
+//! ```
+//! use swc_plugin::{plugin, serde_json::Value, swc_ecma_ast::Program};
+//!
+//! /// Removes all statements if `{ "clear": true }` is passed.
+//! fn process(mut program: Program, config: Value) -> Result<Program, String> {
+//!     if config["clear"] == true {
+//!         match &mut program {
+//!             Program::Module(m) => m.body.clear(),
+//!             Program::Script(s) => s.body.clear(),
+//!         }
+//!     }
+//!     Ok(program)
+//! }
+//!
+//! plugin!(process);
+//! # fn main() {
+//! # use swc_plugin::{serde_json, swc_ecma_ast::*, Buffer};
+//! # use swc_common::DUMMY_SP;
+//! #
+//! # let script = |body| Program::Script(Script { span: DUMMY_SP, body, shebang: None });
+//! # let input = serde_json::to_vec(&script(vec![Stmt::Empty(EmptyStmt { span: DUMMY_SP })])).unwrap();
+//! # let config = br#"{ "clear": true }"#;
+//! #
+//! # // This is what swc does after loading the plugin.
+//! # assert_eq!(swc_plugin_abi_version(), swc_plugin::ABI_VERSION);
+//! # let mut out = Buffer::empty();
+//! # let code = unsafe {
+//! #     swc_plugin_process_v1(input.as_ptr(), input.len(), config.as_ptr(), config.len(), &mut out)
+//! # };
+//! # assert_eq!(code, 0);
+//! # let output: Program = serde_json::from_slice(unsafe { out.as_slice() }).unwrap();
+//! # unsafe { swc_plugin_free_v1(out) };
+//! # assert_eq!(output, script(vec![]));
+//! #
+//! # let mut out = Buffer::empty();
+//! # let code = unsafe { swc_plugin_process_v1(b"{".as_ptr(), 1, config.as_ptr(), config.len(), &mut out) };
+//! # assert_eq!(code, 1);
+//! # assert!(unsafe { out.as_slice() }.starts_with(b"failed to deserialize program"));
+//! # unsafe { swc_plugin_free_v1(out) };
+//! # }
+//! ```
+//!
+//! Note that `Span`s are preserved, but the syntax contexts in
+//! them are meaningless in a plugin.
+//!
+//! If a plugin is built for `wasm32-unknown-unknown`, [plugin!] declares the
+//! functions of a wasm plugin instead. See [wasm] for details.
+
+pub use serde_json;
+use std::{fmt::Display, panic, slice};
+pub use swc_ecma_ast;
+use swc_ecma_ast::Program;
+
+pub mod wasm;
+
+/// Version of the abi. This is changed if the functions declared by [plugin!]
+/// or the json representation of the ast changes.
+pub const ABI_VERSION: u32 = 1;
+
+/// `fn() -> u32`, which returns the [ABI_VERSION] used to build the plugin.
+pub const ABI_VERSION_SYMBOL: &[u8] = b"swc_plugin_abi_version";
+
+/// `unsafe fn(*const u8, usize, *const u8, usize, *mut Buffer) -> i32`, which
+/// transforms a program.
+///
+/// Arguments are the json of the [Program], the json of the config and the
+/// [Buffer] to store the result. The result is the json of the transformed
+/// [Program] if `0` is returned, and an error message otherwise.
+pub const PROCESS_SYMBOL: &[u8] = b"swc_plugin_process_v1";
+
+/// `unsafe fn(Buffer)`, which frees a [Buffer] allocated by the plugin.
+pub const FREE_SYMBOL: &[u8] = b"swc_plugin_free_v1";
+
+/// Bytes allocated by a plugin.
+///
+/// This should be freed by the plugin, as the allocator of the plugin may
+/// differ from the allocator of swc.
+#[repr(C)]
+#[derive(Debug)]
+pub struct Buffer {
+    pub ptr: *mut u8,
+    pub len: usize,
+    pub cap: usize,
+}
+
+impl Buffer {
+    pub const fn empty() -> Self {
+        Buffer {
+            ptr: std::ptr::null_mut(),
+            len: 0,
+            cap: 0,
+        }
+    }
+
+    fn from_vec(mut v: Vec<u8>) -> Self {
+        let buf = Buffer {
+            ptr: v.as_mut_ptr(),
+            len: v.len(),
+            cap: v.capacity(),
+        };
+        std::mem::forget(v);
+        buf
+    }
+
+    /// # Safety
+    ///
+    /// The buffer should be filled by the plugin.
+    pub unsafe fn as_slice(&self) -> &[u8] {
+        if self.ptr.is_null() {
+            return &[];
+        }
+        slice::from_raw_parts(self.ptr, self.len)
+    }
+}
+
+/// Declares the functions of a plugin, using `$process` to transform programs.
+///
+/// `$process` should be a `fn(Program, serde_json::Value) -> Result<Program,
+/// E>` where `E: Display`.
+#[macro_export]
+macro_rules! plugin {
+    ($process:path) => {
+        #[no_mangle]
+        pub extern "C" fn swc_plugin_abi_version() -> u32 {
+            $crate::ABI_VERSION
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        #[no_mangle]
+        pub extern "C" fn swc_plugin_alloc(len: u32) -> *mut u8 {
+            $crate::wasm::alloc_bytes(len)
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        #[no_mangle]
+        pub unsafe extern "C" fn swc_plugin_free(ptr: *mut u8, len: u32) {
+            $crate::wasm::free_bytes(ptr, len)
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        #[no_mangle]
+        pub unsafe extern "C" fn swc_plugin_process_wasm_v1(
+            program: *const u8,
+            program_len: u32,
+            config: *const u8,
+            config_len: u32,
+        ) -> i32 {
+            $crate::wasm::process(program, program_len, config, config_len, $process)
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        #[no_mangle]
+        pub unsafe extern "C" fn swc_plugin_process_v1(
+            program: *const u8,
+            program_len: usize,
+            config: *const u8,
+            config_len: usize,
+            out: *mut $crate::Buffer,
+        ) -> i32 {
+            $crate::process(program, program_len, config, config_len, out, $process)
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        #[no_mangle]
+        pub unsafe extern "C" fn swc_plugin_free_v1(buf: $crate::Buffer) {
+            $crate::free(buf)
+        }
+    };
+}
+
+/// Used by [plugin!].
+///
+/// # Safety
+///
+/// Pointers should be valid.
+#[doc(hidden)]
+pub unsafe fn process<F, E>(
+    program: *const u8,
+    program_len: usize,
+    config: *const u8,
+    config_len: usize,
+    out: *mut Buffer,
+    op: F,
+) -> i32
+where
+    F: FnOnce(Program, serde_json::Value) -> Result<Program, E>,
+    E: Display,
+{
+    let program = slice::from_raw_parts(program, program_len);
+    let config = slice::from_raw_parts(config, config_len);
+
+    let res = panic::catch_unwind(panic::AssertUnwindSafe(|| -> Result<Vec<u8>, String> {
+        let program = serde_json::from_slice(program)
+            .map_err(|err| format!("failed to deserialize program: {}", err))?;
+        let config = serde_json::from_slice(config)
+            .map_err(|err| format!("failed to deserialize config: {}", err))?;
+
+        let program = op(program, config).map_err(|err| err.to_string())?;
+
+        serde_json::to_vec(&program).map_err(|err| format!("failed to serialize program: {}", err))
+    }));
+
+    let (code, bytes) = match res {
+        Ok(Ok(v)) => (0, v),
+        Ok(Err(msg)) => (1, msg.into_bytes()),
+        Err(..) => (2, b"plugin panicked".to_vec()),
+    };
+    *out = Buffer::from_vec(bytes);
+
+    code
+}
+
+/// Used by [plugin!].
+///
+/// # Safety
+///
+/// `buf` should be created by [process].
+#[doc(hidden)]
+pub unsafe fn free(buf: Buffer) {
+    if !buf.ptr.is_null() {
+        drop(Vec::from_raw_parts(buf.ptr, buf.len, buf.cap));
+    }
+}
@@ -0,0 +1,84 @@
+//! Abi of wasm plugins.
+//!
+//! Wasm plugins are sandboxed, and they run on every platform supported by
+//! swc. A wasm plugin is a `cdylib` built for `wasm32-unknown-unknown`, which
+//! uses [plugin!](crate::plugin) just like native plugins.
+//!
+//! Unlike native plugins, the [Program](swc_ecma_ast::Program) is passed as
+//! cbor, which is much more compact than json. The config of the plugin is
+//! still passed as json.
+//!
+//! As the memory of a plugin is not shared with swc, the source map, comments
+//! and the diagnostic handler of swc are accessed through functions imported
+//! from [HOST_MODULE]. They are wrapped by [PluginSourceMap], [PluginComments]
+//! and [PluginHandler].
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+#[cfg(target_arch = "wasm32")]
+pub use self::guest::*;
+
+#[cfg(target_arch = "wasm32")]
+mod guest;
+
+/// `fn() -> u32`, which returns the [ABI_VERSION](crate::ABI_VERSION) used to
+/// build the plugin.
+pub const ABI_VERSION_EXPORT: &str = "swc_plugin_abi_version";
+
+/// `fn(len: u32) -> u32`, which allocates `len` bytes in the memory of the
+/// plugin.
+pub const ALLOC_EXPORT: &str = "swc_plugin_alloc";
+
+/// `fn(ptr: u32, len: u32)`, which frees bytes allocated by [ALLOC_EXPORT].
+pub const FREE_EXPORT: &str = "swc_plugin_free";
+
+/// `fn(program: u32, program_len: u32, config: u32, config_len: u32) -> i32`,
+/// which transforms a program.
+///
+/// The cbor of the transformed [Program](swc_ecma_ast::Program) is passed to
+/// `set_output` if `0` is returned, and an error message otherwise.
+pub const PROCESS_EXPORT: &str = "swc_plugin_process_wasm_v1";
+
+/// Name of the module of functions provided by swc.
+///
+/// Functions which return bytes store them in the host and return the length
+/// of them. Bytes are copied to the memory of the plugin by `take_result`.
+pub const HOST_MODULE: &str = "swc_host";
+
+/// Result of `lookup_char_pos`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PluginLoc {
+    pub file_name: String,
+    /// 1-based
+    pub line: u32,
+    /// 0-based, in chars.
+    pub col: u32,
+}
+
+/// Level of diagnostics passed to `emit_diagnostic`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u32)]
+pub enum DiagnosticLevel {
+    Error = 0,
+    Warning = 1,
+}
+
+impl DiagnosticLevel {
+    pub fn from_u32(v: u32) -> Option<Self> {
+        match v {
+            0 => Some(DiagnosticLevel::Error),
+            1 => Some(DiagnosticLevel::Warning),
+            _ => None,
+        }
+    }
+}
+
+/// Serializes a value passed between swc and a plugin.
+pub fn serialize<T: Serialize>(v: &T) -> Result<Vec<u8>, serde_cbor::Error> {
+    serde_cbor::to_vec(v)
+}
+
+/// Deserializes a value passed between swc and a plugin.
+pub fn deserialize<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, serde_cbor::Error> {
+    serde_cbor::from_slice(bytes)
+}
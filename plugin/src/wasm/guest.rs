@@ -0,0 +1,203 @@
+use super::{deserialize, serialize, DiagnosticLevel, PluginLoc};
+use std::{
+    alloc::{alloc, dealloc, Layout},
+    fmt::Display,
+    slice,
+};
+use swc_common::{
+    comments::{Comment, Comments},
+    BytePos, Span,
+};
+use swc_ecma_ast::Program;
+
+mod sys {
+    #[link(wasm_import_module = "swc_host")]
+    extern "C" {
+        pub fn take_result(ptr: *mut u8);
+        pub fn set_output(ptr: *const u8, len: u32);
+
+        pub fn lookup_char_pos(pos: u32) -> u32;
+        pub fn span_to_snippet(lo: u32, hi: u32) -> i32;
+
+        pub fn add_leading_comments(pos: u32, ptr: *const u8, len: u32);
+        pub fn has_leading(pos: u32) -> i32;
+        pub fn move_leading(from: u32, to: u32);
+        pub fn take_leading(pos: u32) -> i32;
+        pub fn add_trailing_comments(pos: u32, ptr: *const u8, len: u32);
+        pub fn has_trailing(pos: u32) -> i32;
+        pub fn move_trailing(from: u32, to: u32);
+        pub fn take_trailing(pos: u32) -> i32;
+
+        pub fn emit_diagnostic(level: u32, lo: u32, hi: u32, ptr: *const u8, len: u32);
+    }
+}
+
+/// Copies bytes stored in the host.
+fn take_result(len: u32) -> Vec<u8> {
+    let mut buf = vec![0; len as usize];
+    unsafe { sys::take_result(buf.as_mut_ptr()) };
+    buf
+}
+
+/// `-1` means [None].
+fn take_optional_result(len: i32) -> Option<Vec<u8>> {
+    if len < 0 {
+        None
+    } else {
+        Some(take_result(len as u32))
+    }
+}
+
+/// Source map of swc.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PluginSourceMap;
+
+impl PluginSourceMap {
+    pub fn lookup_char_pos(&self, pos: BytePos) -> PluginLoc {
+        let len = unsafe { sys::lookup_char_pos(pos.0) };
+        deserialize(&take_result(len)).expect("failed to deserialize location")
+    }
+
+    pub fn span_to_snippet(&self, span: Span) -> Option<String> {
+        let len = unsafe { sys::span_to_snippet(span.lo.0, span.hi.0) };
+        take_optional_result(len).map(|v| String::from_utf8_lossy(&v).into_owned())
+    }
+}
+
+/// Comments of the file being transformed.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PluginComments;
+
+impl Comments for PluginComments {
+    fn add_leading(&self, pos: BytePos, cmt: Comment) {
+        self.add_leading_comments(pos, vec![cmt])
+    }
+
+    fn add_leading_comments(&self, pos: BytePos, comments: Vec<Comment>) {
+        let bytes = serialize(&comments).expect("failed to serialize comments");
+        unsafe { sys::add_leading_comments(pos.0, bytes.as_ptr(), bytes.len() as u32) }
+    }
+
+    fn has_leading(&self, pos: BytePos) -> bool {
+        unsafe { sys::has_leading(pos.0) != 0 }
+    }
+
+    fn move_leading(&self, from: BytePos, to: BytePos) {
+        unsafe { sys::move_leading(from.0, to.0) }
+    }
+
+    fn take_leading(&self, pos: BytePos) -> Option<Vec<Comment>> {
+        let len = unsafe { sys::take_leading(pos.0) };
+        take_optional_result(len).map(|v| deserialize(&v).expect("failed to deserialize comments"))
+    }
+
+    fn add_trailing(&self, pos: BytePos, cmt: Comment) {
+        self.add_trailing_comments(pos, vec![cmt])
+    }
+
+    fn add_trailing_comments(&self, pos: BytePos, comments: Vec<Comment>) {
+        let bytes = serialize(&comments).expect("failed to serialize comments");
+        unsafe { sys::add_trailing_comments(pos.0, bytes.as_ptr(), bytes.len() as u32) }
+    }
+
+    fn has_trailing(&self, pos: BytePos) -> bool {
+        unsafe { sys::has_trailing(pos.0) != 0 }
+    }
+
+    fn move_trailing(&self, from: BytePos, to: BytePos) {
+        unsafe { sys::move_trailing(from.0, to.0) }
+    }
+
+    fn take_trailing(&self, pos: BytePos) -> Option<Vec<Comment>> {
+        let len = unsafe { sys::take_trailing(pos.0) };
+        take_optional_result(len).map(|v| deserialize(&v).expect("failed to deserialize comments"))
+    }
+}
+
+/// Emits diagnostics using the handler of swc.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PluginHandler;
+
+impl PluginHandler {
+    pub fn err(&self, span: Span, msg: &str) {
+        self.emit(DiagnosticLevel::Error, span, msg)
+    }
+
+    pub fn warn(&self, span: Span, msg: &str) {
+        self.emit(DiagnosticLevel::Warning, span, msg)
+    }
+
+    fn emit(&self, level: DiagnosticLevel, span: Span, msg: &str) {
+        unsafe {
+            sys::emit_diagnostic(
+                level as u32,
+                span.lo.0,
+                span.hi.0,
+                msg.as_ptr(),
+                msg.len() as u32,
+            )
+        }
+    }
+}
+
+/// Used by [plugin!](crate::plugin).
+#[doc(hidden)]
+pub fn alloc_bytes(len: u32) -> *mut u8 {
+    unsafe { alloc(layout(len)) }
+}
+
+/// Used by [plugin!](crate::plugin).
+///
+/// # Safety
+///
+/// `ptr` should be allocated by [alloc_bytes] with same `len`.
+#[doc(hidden)]
+pub unsafe fn free_bytes(ptr: *mut u8, len: u32) {
+    dealloc(ptr, layout(len))
+}
+
+fn layout(len: u32) -> Layout {
+    // Zero-sized allocation is not allowed.
+    Layout::array::<u8>(len.max(1) as usize).unwrap()
+}
+
+/// Used by [plugin!](crate::plugin).
+///
+/// # Safety
+///
+/// Pointers should be valid.
+#[doc(hidden)]
+pub unsafe fn process<F, E>(
+    program: *const u8,
+    program_len: u32,
+    config: *const u8,
+    config_len: u32,
+    op: F,
+) -> i32
+where
+    F: FnOnce(Program, serde_json::Value) -> Result<Program, E>,
+    E: Display,
+{
+    let program = slice::from_raw_parts(program, program_len as usize);
+    let config = slice::from_raw_parts(config, config_len as usize);
+
+    // Panics abort on wasm, so they are reported by the host as traps.
+    let res = (|| -> Result<Vec<u8>, String> {
+        let program = deserialize(program)
+            .map_err(|err| format!("failed to deserialize program: {}", err))?;
+        let config = serde_json::from_slice(config)
+            .map_err(|err| format!("failed to deserialize config: {}", err))?;
+
+        let program = op(program, config).map_err(|err| err.to_string())?;
+
+        serialize(&program).map_err(|err| format!("failed to serialize program: {}", err))
+    })();
+
+    let (code, bytes) = match res {
+        Ok(v) => (0, v),
+        Err(msg) => (1, msg.into_bytes()),
+    };
+    sys::set_output(bytes.as_ptr(), bytes.len() as u32);
+
+    code
+}
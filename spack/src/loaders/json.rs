@@ -9,6 +9,7 @@ use swc_ecma_ast::*;
 use swc_ecma_parser::lexer::Lexer;
 use swc_ecma_parser::Parser;
 use swc_ecma_parser::Syntax;
+use swc_ecma_quote::quote;
 
 pub(super) fn load_json_as_module(fm: &Arc<SourceFile>) -> Result<Module, Error> {
     let lexer = Lexer::new(
@@ -22,23 +23,7 @@ pub(super) fn load_json_as_module(fm: &Arc<SourceFile>) -> Result<Module, Error>
         .parse_expr()
         .map_err(|err| anyhow!("failed parse json as javascript object: {:#?}", err))?;
 
-    let export = ModuleItem::Stmt(Stmt::Expr(ExprStmt {
-        span: DUMMY_SP,
-        expr: Box::new(Expr::Assign(AssignExpr {
-            span: DUMMY_SP,
-            op: op!("="),
-            left: PatOrExpr::Expr(Box::new(Expr::Member(MemberExpr {
-                span: DUMMY_SP,
-                obj: ExprOrSuper::Expr(Box::new(Expr::Ident(Ident::new(
-                    "module".into(),
-                    DUMMY_SP,
-                )))),
-                prop: Box::new(Expr::Ident(Ident::new("exports".into(), DUMMY_SP))),
-                computed: false,
-            }))),
-            right: expr,
-        })),
-    }));
+    let export = quote!("module.exports = $expr;" as ModuleItem, expr: Expr = expr);
 
     Ok(Module {
         span: DUMMY_SP,
@@ -118,6 +118,7 @@ impl Load for SwcLoader {
                                             optimizer: None,
                                             legacy_decorator: c.legacy_decorator,
                                             decorator_metadata: c.decorator_metadata,
+                                            preserve_const_enums: c.preserve_const_enums,
                                             hidden: Default::default(),
                                         })
                                     } else {
@@ -182,6 +182,7 @@ fn reference_tests(tests: &mut Vec<TestDescAndFn>, errors: bool) -> Result<(), i
                             .into_iter()
                             .map(From::from)
                             .collect(),
+                            top_level_await: false,
                         },
                         Box::new(Hook),
                     );
@@ -5,6 +5,7 @@ use std::{collections::HashMap, sync::Arc};
 use swc_atoms::JsWord;
 use swc_common::{chain, comments::Comments, errors::Handler, Mark, SourceMap};
 use swc_ecma_parser::Syntax;
+use swc_ecma_preset_env::InjectedPolyfills;
 use swc_ecma_transforms::hygiene::hygiene_with_config;
 use swc_ecma_transforms::{
     compat, fixer, helpers, hygiene, modules, optimization::const_modules, pass::Optional,
@@ -129,6 +130,20 @@ impl<'a, 'b, P: swc_ecma_visit::Fold> PassBuilder<'a, 'b, P> {
         module: Option<ModuleConfig>,
         comments: Option<&'cmt dyn Comments>,
     ) -> impl 'cmt + swc_ecma_visit::Fold
+    where
+        P: 'cmt,
+    {
+        self.finalize_with_report(syntax, module, comments).0
+    }
+
+    /// Same as [PassBuilder::finalize], but also returns the polyfills
+    /// injected by `preset_env`, if it's enabled.
+    pub fn finalize_with_report<'cmt>(
+        self,
+        syntax: Syntax,
+        module: Option<ModuleConfig>,
+        comments: Option<&'cmt dyn Comments>,
+    ) -> (impl 'cmt + swc_ecma_visit::Fold, Option<InjectedPolyfills>)
     where
         P: 'cmt,
     {
@@ -136,15 +151,27 @@ impl<'a, 'b, P: swc_ecma_visit::Fold> PassBuilder<'a, 'b, P> {
             Some(ModuleConfig::CommonJs(ref c)) => !c.no_interop,
             Some(ModuleConfig::Amd(ref c)) => !c.config.no_interop,
             Some(ModuleConfig::Umd(ref c)) => !c.config.no_interop,
-            Some(ModuleConfig::Es6) | None => false,
+            Some(ModuleConfig::SystemJs(..)) | Some(ModuleConfig::Es6) | None => false,
+        };
+
+        // Errors are reported to the handler, and the config is rejected by the
+        // caller.
+        let (cm, global_mark, handler) = (self.cm, self.global_mark, self.handler);
+        let (preset_env, injected_polyfills) = match self.env.and_then(|env| {
+            swc_ecma_preset_env::preset_env_with_report(cm.clone(), global_mark, comments, env)
+                .map_err(|err| handler.struct_err(&format!("{:?}", err)).emit())
+                .ok()
+        }) {
+            Some((pass, injected)) => (Some(pass), Some(injected)),
+            None => (None, None),
         };
 
         // compat
-        let compat_pass = if let Some(env) = self.env {
+        let compat_pass = if let Some(preset_env) = preset_env {
             Either::Left(chain!(
                 import_assertions(),
                 Optional::new(typescript::strip(), syntax.typescript()),
-                swc_ecma_preset_env::preset_env(self.global_mark, comments, env)
+                preset_env
             ))
         } else {
             Either::Right(chain!(
@@ -177,7 +204,7 @@ impl<'a, 'b, P: swc_ecma_visit::Fold> PassBuilder<'a, 'b, P> {
             ))
         };
 
-        chain!(
+        let pass = chain!(
             self.pass,
             compat_pass,
             compat::reserved_words::reserved_words(),
@@ -194,6 +221,8 @@ impl<'a, 'b, P: swc_ecma_visit::Fold> PassBuilder<'a, 'b, P> {
                 self.hygiene.is_some()
             ),
             Optional::new(fixer(comments), self.fixer),
-        )
+        );
+
+        (pass, injected_polyfills)
     }
 }
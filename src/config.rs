@@ -17,21 +17,26 @@ pub use swc_common::chain;
 use swc_common::{comments::Comments, errors::Handler, FileName, Mark, SourceMap};
 use swc_ecma_ast::{Expr, ExprStmt, ModuleItem, Stmt};
 use swc_ecma_ext_transforms::jest;
+use swc_ecma_lints::LintConfig;
 pub use swc_ecma_parser::JscTarget;
 use swc_ecma_parser::{lexer::Lexer, Parser, StringInput, Syntax, TsConfig};
+use swc_ecma_preset_env::InjectedPolyfills;
 use swc_ecma_transforms::hygiene;
 use swc_ecma_transforms::{
     modules,
     optimization::const_modules,
     optimization::{inline_globals, json_parse, simplifier},
     pass::{noop, Optional},
-    proposals::{decorators, export_default_from},
+    proposals::{decorators, decorators::DecoratorVersion, export_default_from},
     react, resolver_with_mark, typescript,
 };
 use swc_ecma_visit::Fold;
 
+pub(crate) use self::tsconfig::{load_tsconfig, merge_json};
+
 #[cfg(test)]
 mod tests;
+mod tsconfig;
 
 #[derive(Default, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -98,6 +103,14 @@ pub struct Options {
     #[serde(default = "default_swcrc")]
     pub swcrc: bool,
 
+    /// Read `compilerOptions` of the nearest `tsconfig.json`.
+    ///
+    /// Options of `.swcrc` and `config_file` take precedence over options of
+    /// `tsconfig.json`. The typescript parser is used only for `.ts` and
+    /// `.tsx` files.
+    #[serde(default)]
+    pub tsconfig: bool,
+
     #[cfg(not(target_arch = "wasm32"))]
     #[serde(default)]
     pub swcrc_roots: Option<PathBuf>,
@@ -189,15 +202,42 @@ impl Options {
             target,
             loose,
             keep_class_names,
+            check_target,
+            isolated_modules,
+            lints,
+            base_url,
+            paths,
+            experimental,
         } = config.jsc;
         let target = target.unwrap_or_default();
 
         let syntax = syntax.unwrap_or_default();
+        let isolated_modules = if isolated_modules && syntax.typescript() {
+            Some(typescript::isolated_modules::Config {
+                esm: config.module.is_none(),
+            })
+        } else {
+            None
+        };
         let mut transform = transform.unwrap_or_default();
 
-        if syntax.typescript() {
+        if syntax.typescript() && transform.decorator_version != DecoratorVersion::V202203 {
             transform.legacy_decorator = true;
         }
+        // Decorators of the 2018-09 proposal receive descriptors, so they can't be
+        // combined with `Reflect.metadata`.
+        if transform.decorator_metadata
+            && !transform.legacy_decorator
+            && transform.decorator_version == DecoratorVersion::V201809
+        {
+            handler
+                .struct_err(
+                    "`jsc.transform.decoratorMetadata` requires `legacyDecorator` or \
+                     `decoratorVersion: \"2022-03\"`",
+                )
+                .emit();
+            transform.decorator_metadata = false;
+        }
         let optimizer = transform.optimizer;
         let enable_optimizer = optimizer.is_some();
 
@@ -228,6 +268,32 @@ impl Options {
             pass
         };
 
+        // Specifiers are rewritten relative to `filename`.
+        let paths = {
+            let enabled = !paths.is_empty() && !self.filename.is_empty();
+            let file = PathBuf::from(&self.filename);
+            let base_url = base_url.unwrap_or_default();
+            #[cfg(not(target_arch = "wasm32"))]
+            let (file, base_url) = (self.cwd.join(file), self.cwd.join(base_url));
+
+            Optional::new(
+                modules::paths::paths(&file, modules::paths::Config { base_url, paths }),
+                enabled,
+            )
+        };
+
+        // Paths of plugins are relative to `cwd`.
+        #[cfg(not(target_arch = "wasm32"))]
+        let plugins = experimental
+            .plugins
+            .into_iter()
+            .map(|PluginConfig(path, config)| {
+                PluginConfig(self.cwd.join(path).to_string_lossy().into_owned(), config)
+            })
+            .collect();
+        #[cfg(target_arch = "wasm32")]
+        let plugins = experimental.plugins;
+
         let root_mark = self
             .global_mark
             .unwrap_or_else(|| Mark::fresh(Mark::root()));
@@ -243,10 +309,23 @@ impl Options {
                 decorators(decorators::Config {
                     legacy: transform.legacy_decorator,
                     emit_metadata: transform.decorator_metadata,
+                    version: transform.decorator_version,
                 }),
                 syntax.decorators()
             ),
-            Optional::new(typescript::strip(), syntax.typescript()),
+            Optional::new(
+                typescript::strip::strip_with_comments(
+                    typescript::strip::Config {
+                        import_not_used_as_values: transform.imports_not_used_as_values,
+                        use_define_for_class_fields: transform.use_define_for_class_fields,
+                        preserve_const_enums: transform.preserve_const_enums,
+                        ..Default::default()
+                    },
+                    comments
+                ),
+                syntax.typescript()
+            ),
+            paths,
             resolver_with_mark(root_mark),
             const_modules,
             optimization,
@@ -255,7 +334,7 @@ impl Options {
             json_parse_pass
         );
 
-        let pass = PassBuilder::new(&cm, &handler, loose, root_mark, pass)
+        let (pass, injected_polyfills) = PassBuilder::new(&cm, &handler, loose, root_mark, pass)
             .target(target)
             .skip_helper_injection(self.skip_helper_injection)
             .hygiene(if self.disable_hygiene {
@@ -265,16 +344,23 @@ impl Options {
             })
             .fixer(!self.disable_fixer)
             .preset_env(config.env)
-            .finalize(syntax, config.module, comments);
+            .finalize_with_report(syntax, config.module, comments);
 
         let pass = chain!(pass, Optional::new(jest::jest(), transform.hidden.jest));
 
         BuiltConfig {
             minify: config.minify.unwrap_or(false),
+            declaration: config.declaration.unwrap_or(false) && syntax.typescript(),
             pass,
             external_helpers,
             syntax,
             target,
+            check_target,
+            isolated_modules,
+            lints,
+            plugins,
+            plugin_limits: experimental.plugin_limits.unwrap_or_default(),
+            injected_polyfills,
             is_module,
             source_maps: self
                 .source_maps
@@ -351,10 +437,17 @@ impl Default for Rc {
                     target: Default::default(),
                     loose: false,
                     keep_class_names: false,
+                    check_target: None,
+                    isolated_modules: false,
+                    lints: None,
+                    base_url: None,
+                    paths: Default::default(),
+                    experimental: Default::default(),
                 },
                 module: None,
                 minify: None,
                 source_maps: None,
+                declaration: None,
             },
             Config {
                 env: None,
@@ -370,10 +463,17 @@ impl Default for Rc {
                     target: Default::default(),
                     loose: false,
                     keep_class_names: false,
+                    check_target: None,
+                    isolated_modules: false,
+                    lints: None,
+                    base_url: None,
+                    paths: Default::default(),
+                    experimental: Default::default(),
                 },
                 module: None,
                 minify: None,
                 source_maps: None,
+                declaration: None,
             },
             Config {
                 env: None,
@@ -389,10 +489,17 @@ impl Default for Rc {
                     target: Default::default(),
                     loose: false,
                     keep_class_names: false,
+                    check_target: None,
+                    isolated_modules: false,
+                    lints: None,
+                    base_url: None,
+                    paths: Default::default(),
+                    experimental: Default::default(),
                 },
                 module: None,
                 minify: None,
                 source_maps: None,
+                declaration: None,
             },
         ])
     }
@@ -460,6 +567,14 @@ pub struct Config {
     /// Possible values are: `'inline'`, `true`, `false`.
     #[serde(default)]
     pub source_maps: Option<SourceMapsConfig>,
+
+    /// Emit typescript declarations (`.d.ts`) to
+    /// [TransformOutput::dts](crate::TransformOutput::dts).
+    ///
+    /// Types are not inferred, so exported declarations should have explicit
+    /// type annotations.
+    #[serde(default)]
+    pub declaration: Option<bool>,
 }
 
 impl Config {
@@ -557,7 +672,19 @@ pub struct BuiltConfig<P: swc_ecma_visit::Fold> {
     pub pass: P,
     pub syntax: Syntax,
     pub target: JscTarget,
+    pub check_target: Option<CheckTarget>,
+    /// Reports typescript code which can't be transpiled file-by-file.
+    pub isolated_modules: Option<typescript::isolated_modules::Config>,
+    /// Lint rules, which are applied to the input.
+    pub lints: Option<LintConfig>,
+    /// Plugins, which are applied before builtin passes.
+    pub plugins: Vec<PluginConfig>,
+    pub plugin_limits: PluginLimits,
+    /// Polyfills injected by `env`, if it's enabled.
+    pub injected_polyfills: Option<InjectedPolyfills>,
     pub minify: bool,
+    /// Emit typescript declarations.
+    pub declaration: bool,
     pub external_helpers: bool,
     pub source_maps: SourceMapsConfig,
     pub input_source_map: InputSourceMap,
@@ -584,6 +711,97 @@ pub struct JscConfig {
 
     #[serde(default)]
     pub keep_class_names: bool,
+
+    /// Reports syntax in the output which is not supported by `target`.
+    ///
+    /// Trailing commas are only checked in array literals. The output does not
+    /// contain trailing commas of parameters, arguments and object literals.
+    #[serde(default)]
+    pub check_target: Option<CheckTarget>,
+
+    /// Reports typescript code which can't be transpiled file-by-file, like
+    /// `isolatedModules` of `tsc`.
+    #[serde(default)]
+    pub isolated_modules: bool,
+
+    /// Enables builtin lint rules, which report problems of the input. Rules
+    /// which are not configured use their default levels.
+    #[serde(default)]
+    pub lints: Option<LintConfig>,
+
+    /// Base directory of `paths`.
+    #[serde(default)]
+    pub base_url: Option<PathBuf>,
+
+    /// Aliases of module specifiers, like `paths` of `tsconfig.json`.
+    #[serde(default)]
+    pub paths: HashMap<String, Vec<String>>,
+
+    #[serde(default)]
+    pub experimental: JscExperimental,
+}
+
+/// Options which may be changed or removed without a major version bump.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct JscExperimental {
+    /// Plugins, which are applied in order before any builtin pass.
+    #[serde(default)]
+    pub plugins: Vec<PluginConfig>,
+
+    #[serde(default)]
+    pub plugin_limits: Option<PluginLimits>,
+}
+
+/// `["path/to/plugin.so", { ...config }]`
+///
+/// The path is resolved against `cwd`, and the config is passed to the plugin
+/// as is. A plugin is loaded as a wasm plugin if the extension of the path is
+/// `.wasm`, and as a native plugin otherwise.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PluginConfig(pub String, pub serde_json::Value);
+
+/// Limits of each invocation of a wasm plugin.
+///
+/// Native plugins are not sandboxed, so these are not applied to them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct PluginLimits {
+    /// Maximum size of the memory of a plugin, in bytes.
+    #[serde(default = "PluginLimits::default_max_memory")]
+    pub max_memory: usize,
+
+    /// In milliseconds.
+    #[serde(default = "PluginLimits::default_timeout")]
+    pub timeout: u64,
+}
+
+impl PluginLimits {
+    const fn default_max_memory() -> usize {
+        512 * 1024 * 1024
+    }
+
+    const fn default_timeout() -> u64 {
+        30_000
+    }
+}
+
+impl Default for PluginLimits {
+    fn default() -> Self {
+        PluginLimits {
+            max_memory: Self::default_max_memory(),
+            timeout: Self::default_timeout(),
+        }
+    }
+}
+
+/// How syntax which is not supported by the target is reported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CheckTarget {
+    #[serde(rename = "warn")]
+    Warn,
+    #[serde(rename = "error")]
+    Error,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -596,6 +814,8 @@ pub enum ModuleConfig {
     Umd(modules::umd::Config),
     #[serde(rename = "amd")]
     Amd(modules::amd::Config),
+    #[serde(rename = "systemjs")]
+    SystemJs(modules::system_js::Config),
     #[serde(rename = "es6")]
     Es6,
 }
@@ -613,6 +833,7 @@ impl ModuleConfig {
             }
             Some(ModuleConfig::Umd(config)) => Box::new(modules::umd::umd(cm, root_mark, config)),
             Some(ModuleConfig::Amd(config)) => Box::new(modules::amd::amd(config)),
+            Some(ModuleConfig::SystemJs(config)) => Box::new(modules::system_js::system_js(config)),
         }
     }
 }
@@ -635,6 +856,22 @@ pub struct TransformConfig {
     #[serde(default)]
     pub decorator_metadata: bool,
 
+    /// Version of the decorators proposal, used unless `legacy_decorator` is
+    /// set.
+    #[serde(default)]
+    pub decorator_version: DecoratorVersion,
+
+    /// Emit `const enum` declarations of typescript instead of removing them.
+    #[serde(default)]
+    pub preserve_const_enums: bool,
+
+    /// See [typescript::strip::Config::use_define_for_class_fields].
+    #[serde(default)]
+    pub use_define_for_class_fields: bool,
+
+    #[serde(default)]
+    pub imports_not_used_as_values: typescript::strip::ImportsNotUsedAsValues,
+
     #[serde(default)]
     pub hidden: HiddenTransformConfig,
 }
@@ -799,6 +1036,7 @@ impl Merge for Config {
         self.minify.merge(&from.minify);
         self.env.merge(&from.env);
         self.source_maps.merge(&from.source_maps);
+        self.declaration.merge(&from.declaration);
     }
 }
 
@@ -826,6 +1064,29 @@ impl Merge for JscConfig {
         self.target.merge(&from.target);
         self.external_helpers.merge(&from.external_helpers);
         self.keep_class_names.merge(&from.keep_class_names);
+        self.check_target.merge(&from.check_target);
+        self.isolated_modules.merge(&from.isolated_modules);
+        if from.lints.is_some() {
+            self.lints = from.lints.clone();
+        }
+        if from.base_url.is_some() {
+            self.base_url = from.base_url.clone();
+        }
+        if !from.paths.is_empty() {
+            self.paths = from.paths.clone();
+        }
+        if !from.experimental.plugins.is_empty() {
+            self.experimental.plugins = from.experimental.plugins.clone();
+        }
+        if from.experimental.plugin_limits.is_some() {
+            self.experimental.plugin_limits = from.experimental.plugin_limits;
+        }
+    }
+}
+
+impl Merge for CheckTarget {
+    fn merge(&mut self, from: &Self) {
+        *self = *from;
     }
 }
 
@@ -1,4 +1,4 @@
-use super::Rc;
+use super::{PluginLimits, Rc};
 use crate::Options;
 use serde_json;
 
@@ -19,3 +19,47 @@ fn issue_1532() {
     let err = res.expect_err("should fail");
     assert!(err.to_string().contains("unknown variant `esnext`"));
 }
+
+#[test]
+fn plugins() {
+    let rc: Rc = serde_json::from_str(
+        r#"{
+            "jsc": {
+                "experimental": {
+                    "plugins": [["./plugin.so", { "foo": true }]]
+                }
+            }
+        }"#,
+    )
+    .expect("failed to parse");
+
+    let config = rc.into_config(None).unwrap().unwrap();
+    let plugins = config.jsc.experimental.plugins;
+    assert_eq!(plugins.len(), 1);
+    assert_eq!(plugins[0].0, "./plugin.so");
+    assert_eq!(plugins[0].1, serde_json::json!({ "foo": true }));
+}
+
+#[test]
+fn plugin_limits() {
+    let rc: Rc = serde_json::from_str(
+        r#"{
+            "jsc": {
+                "experimental": {
+                    "plugins": [["./plugin.wasm", {}]],
+                    "pluginLimits": { "timeout": 1000 }
+                }
+            }
+        }"#,
+    )
+    .expect("failed to parse");
+
+    let config = rc.into_config(None).unwrap().unwrap();
+    assert_eq!(
+        config.jsc.experimental.plugin_limits,
+        Some(PluginLimits {
+            timeout: 1000,
+            ..Default::default()
+        })
+    );
+}
@@ -0,0 +1,449 @@
+//! Support for `tsconfig.json` as a configuration source.
+//!
+//! `compilerOptions` are converted into a value which has the shape of
+//! `.swcrc`, so `.swcrc` can be layered on top of it.
+
+use crate::config::JscTarget;
+use anyhow::{bail, Context, Error};
+use serde::Deserialize;
+use serde_json::{json, Map, Value};
+use std::{
+    collections::HashMap,
+    fs::read_to_string,
+    path::{Path, PathBuf},
+};
+
+/// Keys of `.swcrc` which are replaced as a whole instead of being merged.
+const ATOMIC_KEYS: &[&str] = &["parser", "module", "paths"];
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TsConfigFile {
+    #[serde(default)]
+    extends: Option<Extends>,
+    #[serde(default)]
+    compiler_options: CompilerOptions,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Extends {
+    One(String),
+    Many(Vec<String>),
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CompilerOptions {
+    target: Option<String>,
+    jsx: Option<String>,
+    jsx_factory: Option<String>,
+    jsx_fragment_factory: Option<String>,
+    jsx_import_source: Option<String>,
+    experimental_decorators: Option<bool>,
+    emit_decorator_metadata: Option<bool>,
+    use_define_for_class_fields: Option<bool>,
+    imports_not_used_as_values: Option<String>,
+    preserve_const_enums: Option<bool>,
+    module: Option<String>,
+    base_url: Option<PathBuf>,
+    paths: Option<HashMap<String, Vec<String>>>,
+
+    /// Directory of the file which declares `paths`.
+    #[serde(skip)]
+    paths_base: Option<PathBuf>,
+}
+
+impl CompilerOptions {
+    /// Options of `self` override options of `base`.
+    fn inherit(self, base: CompilerOptions) -> CompilerOptions {
+        CompilerOptions {
+            target: self.target.or(base.target),
+            jsx: self.jsx.or(base.jsx),
+            jsx_factory: self.jsx_factory.or(base.jsx_factory),
+            jsx_fragment_factory: self.jsx_fragment_factory.or(base.jsx_fragment_factory),
+            jsx_import_source: self.jsx_import_source.or(base.jsx_import_source),
+            experimental_decorators: self
+                .experimental_decorators
+                .or(base.experimental_decorators),
+            emit_decorator_metadata: self
+                .emit_decorator_metadata
+                .or(base.emit_decorator_metadata),
+            use_define_for_class_fields: self
+                .use_define_for_class_fields
+                .or(base.use_define_for_class_fields),
+            imports_not_used_as_values: self
+                .imports_not_used_as_values
+                .or(base.imports_not_used_as_values),
+            preserve_const_enums: self.preserve_const_enums.or(base.preserve_const_enums),
+            module: self.module.or(base.module),
+            base_url: self.base_url.or(base.base_url),
+            paths_base: if self.paths.is_some() {
+                self.paths_base
+            } else {
+                base.paths_base
+            },
+            paths: self.paths.or(base.paths),
+        }
+    }
+
+    /// Converts options for `file` into the shape of `.swcrc`.
+    ///
+    /// The typescript parser is used only if `file` is a `.ts` or `.tsx`
+    /// file.
+    ///
+    /// Values which can't be represented by swc (e.g. `"jsx": "preserve"` or
+    /// `"module": "node16"`) are ignored.
+    fn into_swcrc(self, file: &Path) -> Value {
+        let mut jsc = Map::new();
+        let mut transform = Map::new();
+        let mut react = Map::new();
+
+        let ext = file.extension().and_then(|v| v.to_str());
+        if ext == Some("ts") || ext == Some("tsx") {
+            jsc.insert(
+                "parser".into(),
+                json!({
+                    "syntax": "typescript",
+                    "tsx": ext == Some("tsx"),
+                    "decorators": self.experimental_decorators.unwrap_or(false),
+                    "dynamicImport": true,
+                }),
+            );
+        }
+
+        if let Some(target) = self.target.as_deref().and_then(parse_target) {
+            jsc.insert("target".into(), json!(target));
+        }
+
+        match self.jsx.as_deref().map(str::to_ascii_lowercase).as_deref() {
+            Some("react") => {
+                react.insert("runtime".into(), json!("classic"));
+            }
+            Some("react-jsx") => {
+                react.insert("runtime".into(), json!("automatic"));
+            }
+            Some("react-jsxdev") => {
+                react.insert("runtime".into(), json!("automatic"));
+                react.insert("development".into(), json!(true));
+            }
+            _ => {}
+        }
+        if let Some(v) = self.jsx_factory {
+            react.insert("pragma".into(), json!(v));
+        }
+        if let Some(v) = self.jsx_fragment_factory {
+            react.insert("pragmaFrag".into(), json!(v));
+        }
+        if let Some(v) = self.jsx_import_source {
+            react.insert("importSource".into(), json!(v));
+        }
+        if !react.is_empty() {
+            transform.insert("react".into(), Value::Object(react));
+        }
+
+        if let Some(v) = self.experimental_decorators {
+            transform.insert("legacyDecorator".into(), json!(v));
+        }
+        if let Some(v) = self.emit_decorator_metadata {
+            transform.insert("decoratorMetadata".into(), json!(v));
+        }
+        if let Some(v) = self.use_define_for_class_fields {
+            transform.insert("useDefineForClassFields".into(), json!(v));
+        }
+        match self
+            .imports_not_used_as_values
+            .as_deref()
+            .map(str::to_ascii_lowercase)
+            .as_deref()
+        {
+            Some("remove") => {
+                transform.insert("importsNotUsedAsValues".into(), json!("remove"));
+            }
+            // swc can't report errors for unused imports, so `error` is treated like
+            // `preserve`.
+            Some("preserve") | Some("error") => {
+                transform.insert("importsNotUsedAsValues".into(), json!("preserve"));
+            }
+            _ => {}
+        }
+        if let Some(v) = self.preserve_const_enums {
+            transform.insert("preserveConstEnums".into(), json!(v));
+        }
+        if !transform.is_empty() {
+            jsc.insert("transform".into(), Value::Object(transform));
+        }
+
+        // Paths are resolved relative to `baseUrl`, or to the file which declares
+        // them.
+        let base_url = self.base_url.or(if self.paths.is_some() {
+            self.paths_base
+        } else {
+            None
+        });
+        if let Some(base_url) = base_url {
+            jsc.insert("baseUrl".into(), json!(base_url));
+        }
+        if let Some(paths) = self.paths {
+            jsc.insert("paths".into(), json!(paths));
+        }
+
+        let mut swcrc = Map::new();
+        swcrc.insert("jsc".into(), Value::Object(jsc));
+
+        let module = match self
+            .module
+            .as_deref()
+            .map(str::to_ascii_lowercase)
+            .as_deref()
+        {
+            Some("commonjs") => Some("commonjs"),
+            Some("amd") => Some("amd"),
+            Some("umd") => Some("umd"),
+            Some("system") => Some("systemjs"),
+            Some("es6") | Some("es2015") | Some("es2020") | Some("es2022") | Some("esnext") => {
+                Some("es6")
+            }
+            _ => None,
+        };
+        if let Some(module) = module {
+            swcrc.insert("module".into(), json!({ "type": module }));
+        }
+
+        Value::Object(swcrc)
+    }
+}
+
+fn parse_target(s: &str) -> Option<JscTarget> {
+    Some(match &*s.to_ascii_lowercase() {
+        "es3" => JscTarget::Es3,
+        "es5" => JscTarget::Es5,
+        "es6" | "es2015" => JscTarget::Es2015,
+        "es2016" => JscTarget::Es2016,
+        "es2017" => JscTarget::Es2017,
+        "es2018" => JscTarget::Es2018,
+        "es2019" => JscTarget::Es2019,
+        "es2020" => JscTarget::Es2020,
+        "es2021" | "es2022" | "esnext" => JscTarget::latest(),
+        _ => return None,
+    })
+}
+
+/// Loads `compilerOptions` of a `tsconfig.json`, following `extends`, and
+/// returns them in the shape of `.swcrc` for `file`.
+pub(crate) fn load_tsconfig(path: &Path, file: &Path) -> Result<Value, Error> {
+    let options = read_compiler_options(path, &mut vec![])
+        .with_context(|| format!("failed to load tsconfig ({})", path.display()))?;
+
+    Ok(options.into_swcrc(file))
+}
+
+fn read_compiler_options(path: &Path, stack: &mut Vec<PathBuf>) -> Result<CompilerOptions, Error> {
+    if stack.iter().any(|p| p == path) {
+        bail!(
+            "circularity detected while resolving `extends` ({})",
+            path.display()
+        )
+    }
+
+    let content = read_to_string(path)
+        .with_context(|| format!("failed to read tsconfig ({})", path.display()))?;
+    let file: TsConfigFile = serde_json::from_str(&strip_jsonc(&content))
+        .with_context(|| format!("failed to deserialize tsconfig ({})", path.display()))?;
+
+    let dir = path.parent().unwrap_or_else(|| Path::new(""));
+    let mut options = file.compiler_options;
+    options.base_url = options.base_url.map(|v| dir.join(v));
+    if options.paths.is_some() {
+        options.paths_base = Some(dir.to_path_buf());
+    }
+
+    let extends = match file.extends {
+        None => vec![],
+        Some(Extends::One(v)) => vec![v],
+        Some(Extends::Many(v)) => v,
+    };
+
+    stack.push(path.to_path_buf());
+    let mut base = CompilerOptions::default();
+    for name in extends {
+        let path = resolve_extends(dir, &name)?;
+        base = read_compiler_options(&path, stack)?.inherit(base);
+    }
+    stack.pop();
+
+    Ok(options.inherit(base))
+}
+
+/// Resolves `extends` like `tsc`. Both of paths and packages in `node_modules`
+/// are supported.
+fn resolve_extends(dir: &Path, name: &str) -> Result<PathBuf, Error> {
+    let with_ext = |path: PathBuf| {
+        if !path.exists() && !name.ends_with(".json") {
+            PathBuf::from(format!("{}.json", path.display()))
+        } else {
+            path
+        }
+    };
+
+    if name.starts_with('.') || Path::new(name).is_absolute() {
+        return Ok(with_ext(dir.join(name)));
+    }
+
+    for ancestor in dir.ancestors() {
+        let path = ancestor.join("node_modules").join(name);
+        if path.is_dir() {
+            return Ok(path.join("tsconfig.json"));
+        }
+        let path = with_ext(path);
+        if path.is_file() {
+            return Ok(path);
+        }
+    }
+
+    bail!(
+        "failed to resolve `extends` ({}) from {}",
+        name,
+        dir.display()
+    )
+}
+
+/// Removes comments and trailing commas, which are allowed in `tsconfig.json`.
+fn strip_jsonc(s: &str) -> String {
+    let mut without_comments = String::with_capacity(s.len());
+    let mut chars = s.chars().peekable();
+    let mut in_str = false;
+    while let Some(c) = chars.next() {
+        if in_str {
+            without_comments.push(c);
+            match c {
+                '\\' => without_comments.extend(chars.next()),
+                '"' => in_str = false,
+                _ => {}
+            }
+            continue;
+        }
+
+        match (c, chars.peek()) {
+            ('/', Some('/')) => {
+                while let Some(&c) = chars.peek() {
+                    if c == '\n' {
+                        break;
+                    }
+                    chars.next();
+                }
+            }
+            ('/', Some('*')) => {
+                chars.next();
+                let mut prev = None;
+                while let Some(c) = chars.next() {
+                    if prev == Some('*') && c == '/' {
+                        break;
+                    }
+                    prev = Some(c);
+                }
+                without_comments.push(' ');
+            }
+            _ => {
+                if c == '"' {
+                    in_str = true;
+                }
+                without_comments.push(c);
+            }
+        }
+    }
+
+    let mut buf = String::with_capacity(without_comments.len());
+    let mut in_str = false;
+    let mut escaped = false;
+    for (i, c) in without_comments.char_indices() {
+        if in_str {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_str = false,
+                _ => {}
+            }
+        } else if c == '"' {
+            in_str = true;
+        } else if c == ',' {
+            let next = without_comments[i + 1..].trim_start().chars().next();
+            if next == Some('}') || next == Some(']') {
+                continue;
+            }
+        }
+        buf.push(c);
+    }
+    buf
+}
+
+/// Layers `over` on top of `base`. Objects are merged recursively, except for
+/// [ATOMIC_KEYS].
+pub(crate) fn merge_json(base: &mut Value, over: Value) {
+    match (base, over) {
+        (Value::Object(base), Value::Object(over)) => {
+            for (k, v) in over {
+                match base.get_mut(&k) {
+                    Some(b) if !ATOMIC_KEYS.contains(&&*k) => merge_json(b, v),
+                    _ => {
+                        base.insert(k, v);
+                    }
+                }
+            }
+        }
+        (base, over) => *base = over,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn jsonc() {
+        let v: Value = serde_json::from_str(&strip_jsonc(
+            r#"{
+                // comment
+                "a": "// not a comment", /* comment */
+                "b": ["\"/*", 1,],
+            }"#,
+        ))
+        .unwrap();
+
+        assert_eq!(v, json!({ "a": "// not a comment", "b": ["\"/*", 1] }));
+    }
+
+    #[test]
+    fn merge() {
+        let mut base = json!({
+            "jsc": {
+                "parser": { "syntax": "typescript", "tsx": true },
+                "target": "es2019",
+                "transform": { "legacyDecorator": true },
+            },
+            "module": { "type": "commonjs", "noInterop": true },
+        });
+        merge_json(
+            &mut base,
+            json!({
+                "jsc": {
+                    "parser": { "syntax": "ecmascript" },
+                    "transform": { "decoratorMetadata": true },
+                },
+                "module": { "type": "amd" },
+            }),
+        );
+
+        assert_eq!(
+            base,
+            json!({
+                "jsc": {
+                    "parser": { "syntax": "ecmascript" },
+                    "target": "es2019",
+                    "transform": { "legacyDecorator": true, "decoratorMetadata": true },
+                },
+                "module": { "type": "amd" },
+            })
+        );
+    }
+}
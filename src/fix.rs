@@ -0,0 +1,116 @@
+//! Applies suggestions of diagnostics to source files.
+
+use std::sync::{Arc, Mutex};
+use swc_common::{
+    errors::{Applicability, CodeSuggestion, DiagnosticBuilder, Emitter},
+    BytePos, SourceFile, Span,
+};
+
+/// Collects suggestions of emitted diagnostics.
+#[derive(Clone, Default)]
+pub(crate) struct SuggestionCollector(Arc<Mutex<Vec<CodeSuggestion>>>);
+
+impl SuggestionCollector {
+    pub fn take(&self) -> Vec<CodeSuggestion> {
+        std::mem::take(&mut *self.0.lock().unwrap())
+    }
+}
+
+impl Emitter for SuggestionCollector {
+    fn emit(&mut self, db: &DiagnosticBuilder<'_>) {
+        self.0
+            .lock()
+            .unwrap()
+            .extend(db.suggestions.iter().cloned());
+    }
+}
+
+/// Replacement of `lo..hi`.
+#[derive(Debug, PartialEq)]
+pub(crate) struct Edit {
+    lo: BytePos,
+    hi: BytePos,
+    text: String,
+}
+
+impl Edit {
+    /// Insertions at the same position conflict, as their order is unknown.
+    fn overlaps(&self, other: &Edit) -> bool {
+        (self.lo < other.hi && other.lo < self.hi) || (self.lo == other.lo && self.hi == other.hi)
+    }
+}
+
+/// Selects edits of machine-applicable suggestions for `fm`.
+///
+/// Suggestions are selected in order, and ones overlapping with already
+/// selected suggestions are returned as conflicts. Suggestions identical to a
+/// selected one are ignored, because diagnostics may share a fix.
+pub(crate) fn select(
+    fm: &SourceFile,
+    suggestions: Vec<CodeSuggestion>,
+) -> (Vec<Edit>, Vec<(Span, String)>) {
+    let mut selected: Vec<Vec<Edit>> = vec![];
+    let mut conflicts = vec![];
+
+    for s in suggestions {
+        if s.applicability != Applicability::MachineApplicable {
+            continue;
+        }
+        let parts = match s.substitutions.into_iter().next() {
+            Some(v) => v.parts,
+            None => continue,
+        };
+        if parts
+            .iter()
+            .any(|p| p.span.lo() < fm.start_pos || fm.end_pos < p.span.hi())
+        {
+            continue;
+        }
+
+        let mut edits: Vec<_> = parts
+            .into_iter()
+            .map(|p| Edit {
+                lo: p.span.lo(),
+                hi: p.span.hi(),
+                text: p.snippet,
+            })
+            .collect();
+        edits.sort_by_key(|e| (e.lo, e.hi));
+        let span = match (edits.first(), edits.iter().map(|e| e.hi).max()) {
+            (Some(first), Some(hi)) => Span::new(first.lo, hi, Default::default()),
+            _ => continue,
+        };
+
+        if selected.contains(&edits) {
+            continue;
+        }
+        let conflicts_with = |other: &Edit| edits.iter().any(|e| e.overlaps(other));
+        if edits.windows(2).any(|w| w[0].overlaps(&w[1]))
+            || selected.iter().flatten().any(conflicts_with)
+        {
+            conflicts.push((span, s.msg));
+            continue;
+        }
+        selected.push(edits);
+    }
+
+    let mut edits: Vec<_> = selected.into_iter().flatten().collect();
+    edits.sort_by_key(|e| (e.lo, e.hi));
+    (edits, conflicts)
+}
+
+/// Applies non-overlapping `edits`, sorted by their positions, to `fm`.
+pub(crate) fn apply(fm: &SourceFile, edits: &[Edit]) -> String {
+    let mut code = String::with_capacity(fm.src.len());
+    let mut pos = 0;
+    for e in edits {
+        let lo = (e.lo - fm.start_pos).0 as usize;
+        let hi = (e.hi - fm.start_pos).0 as usize;
+        code.push_str(&fm.src[pos..lo]);
+        code.push_str(&e.text);
+        pos = hi;
+    }
+    code.push_str(&fm.src[pos..]);
+
+    code
+}
@@ -2,8 +2,8 @@
 
 pub use crate::builder::PassBuilder;
 use crate::config::{
-    BuiltConfig, Config, ConfigFile, InputSourceMap, JscTarget, Merge, Options, Rc, RootMode,
-    SourceMapsConfig,
+    load_tsconfig, merge_json, BuiltConfig, CheckTarget, Config, ConfigFile, InputSourceMap,
+    JscTarget, Merge, Options, Rc, RootMode, SourceMapsConfig,
 };
 use anyhow::{bail, Context, Error};
 use dashmap::DashMap;
@@ -20,19 +20,30 @@ use swc_common::{
     comments::{Comment, Comments},
     errors::Handler,
     input::StringInput,
-    BytePos, FileName, Globals, SourceFile, SourceMap, Spanned, GLOBALS,
+    BytePos, FileName, Globals, Mark, SourceFile, SourceMap, Span, Spanned, GLOBALS,
 };
-use swc_ecma_ast::Program;
+use swc_ecma_ast::{Module, ModuleItem, Program};
 use swc_ecma_codegen::{self, Emitter, Node};
+use swc_ecma_lints::{LintConfig, Linter};
 use swc_ecma_parser::{lexer::Lexer, Parser, Syntax};
 use swc_ecma_transforms::{
+    compat::check_target,
     helpers::{self, Helpers},
     pass::noop,
+    resolver::{resolver_with_mark, ts_resolver},
+    typescript::{
+        dts::dts,
+        early_errors::{self, check_early_errors},
+        isolated_modules::check_isolated_modules,
+    },
 };
 use swc_ecma_visit::FoldWith;
 
 mod builder;
 pub mod config;
+mod fix;
+#[cfg(not(target_arch = "wasm32"))]
+mod plugin;
 
 pub struct Compiler {
     /// swc uses rustc's span interning.
@@ -50,8 +61,46 @@ pub struct TransformOutput {
     pub code: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub map: Option<String>,
+    /// Typescript declarations, if [Config::declaration] is enabled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dts: Option<String>,
+    /// Module specifiers of the polyfills injected into the file, if `env` is
+    /// enabled.
+    #[serde(rename = "injectedPolyfills", skip_serializing_if = "Option::is_none")]
+    pub injected_polyfills: Option<Vec<String>>,
+}
+
+/// An error found by [Compiler::check], or a fix which is not applied by
+/// [Compiler::apply_fixes].
+#[derive(Debug, Clone, Serialize)]
+pub struct CheckDiagnostic {
+    pub message: String,
+    /// 1-based line of the start of the error.
+    pub line: usize,
+    /// 0-based column of the start of the error, in characters.
+    pub column: usize,
+    /// Byte offset of the start of the error in the file.
+    pub start: u32,
+    /// Byte offset of the end of the error in the file.
+    pub end: u32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FixOutput {
+    pub code: String,
+    /// The number of applied edits.
+    pub fixed: usize,
+    /// Fixes which are not applied because they conflict with other fixes,
+    /// located in `code`.
+    ///
+    /// If fixes are still found after [MAX_FIX_PASSES] passes, the remaining
+    /// ones are not applied nor reported.
+    pub conflicts: Vec<CheckDiagnostic>,
 }
 
+/// Maximum number of times [Compiler::apply_fixes] applies fixes to a file.
+const MAX_FIX_PASSES: usize = 10;
+
 /// These are **low-level** apis.
 impl Compiler {
     pub fn globals(&self) -> &Globals {
@@ -304,7 +353,12 @@ impl Compiler {
                 }
             };
 
-            Ok(TransformOutput { code, map })
+            Ok(TransformOutput {
+                code,
+                map,
+                dts: None,
+                injected_polyfills: None,
+            })
         })
     }
 }
@@ -326,6 +380,7 @@ impl Compiler {
                 ref root,
                 root_mode,
                 swcrc,
+                tsconfig,
                 config_file,
                 ..
             } = opts;
@@ -338,8 +393,31 @@ impl Compiler {
                 }
             });
 
+            // Both of `.swcrc` files and `configFile` are layered on top of it.
+            let tsconfig = match name {
+                FileName::Real(ref path) if *tsconfig => {
+                    let mut found = None;
+                    let mut parent = path.parent();
+                    while let Some(dir) = parent {
+                        let tsconfig = dir.join("tsconfig.json");
+
+                        if tsconfig.exists() {
+                            found = Some(load_tsconfig(&tsconfig, path)?);
+                            break;
+                        }
+
+                        if dir == root && *root_mode == RootMode::Root {
+                            break;
+                        }
+                        parent = dir.parent();
+                    }
+                    found
+                }
+                _ => None,
+            };
+
             let config_file = match config_file {
-                Some(ConfigFile::Str(ref s)) => Some(load_swcrc(Path::new(&s))?),
+                Some(ConfigFile::Str(ref s)) => Some(load_swcrc(Path::new(&s), tsconfig.as_ref())?),
                 _ => None,
             };
 
@@ -351,7 +429,7 @@ impl Compiler {
                             let swcrc = dir.join(".swcrc");
 
                             if swcrc.exists() {
-                                let config = load_swcrc(&swcrc)?;
+                                let config = load_swcrc(&swcrc, tsconfig.as_ref())?;
 
                                 let mut config = config
                                     .into_config(Some(path))
@@ -371,8 +449,15 @@ impl Compiler {
                         }
                     }
 
-                    let config_file = config_file.unwrap_or_else(|| Rc::default());
-                    let config = config_file.into_config(Some(path))?;
+                    let config = match (config_file, tsconfig) {
+                        (Some(config_file), _) => config_file,
+                        (None, Some(tsconfig)) => Rc::Single(
+                            serde_json::from_value(tsconfig)
+                                .context("failed to convert tsconfig into config")?,
+                        ),
+                        (None, None) => Rc::default(),
+                    };
+                    let config = config.into_config(Some(path))?;
 
                     return Ok(config);
                 }
@@ -410,6 +495,7 @@ impl Compiler {
                 Some(v) => v,
                 None => return Ok(None),
             };
+            let err_count = self.handler.err_count();
             let built = opts.build(
                 &self.cm,
                 &self.handler,
@@ -417,6 +503,9 @@ impl Compiler {
                 Some(config),
                 Some(&self.comments),
             );
+            if self.handler.err_count() > err_count {
+                bail!("failed to build transforms from the config")
+            }
             Ok(Some(built))
         })
         .with_context(|| format!("failed to load config for file '{:?}'", name))
@@ -467,7 +556,14 @@ impl Compiler {
                 pass: chain!(config.pass, custom_after_pass),
                 syntax: config.syntax,
                 target: config.target,
+                check_target: config.check_target,
+                isolated_modules: config.isolated_modules,
+                lints: config.lints,
+                plugins: config.plugins,
+                plugin_limits: config.plugin_limits,
+                injected_polyfills: config.injected_polyfills,
                 minify: config.minify,
+                declaration: config.declaration,
                 external_helpers: config.external_helpers,
                 source_maps: config.source_maps,
                 input_source_map: config.input_source_map,
@@ -525,6 +621,10 @@ impl Compiler {
         config: BuiltConfig<impl swc_ecma_visit::Fold>,
     ) -> Result<TransformOutput, Error> {
         self.run(|| {
+            // Lint directives are read from comments, which may be removed below.
+            if let Some(lints) = &config.lints {
+                self.lint(&program, config.syntax, lints)?;
+            }
             if config.minify {
                 let preserve_excl = |_: &BytePos, vc: &mut Vec<Comment>| -> bool {
                     vc.retain(|c: &Comment| c.text.starts_with("!"));
@@ -533,6 +633,38 @@ impl Compiler {
                 self.comments.leading.retain(preserve_excl);
                 self.comments.trailing.retain(preserve_excl);
             }
+            if let Some(isolated_modules) = config.isolated_modules {
+                // Bindings are compared using syntax contexts.
+                let module =
+                    to_module(&program).fold_with(&mut ts_resolver(Mark::fresh(Mark::root())));
+                let errors = check_isolated_modules(&module, isolated_modules);
+
+                for e in &errors {
+                    self.handler.struct_span_err(e.span, e.msg).emit();
+                }
+                if !errors.is_empty() {
+                    bail!(
+                        "{} error(s) found because of `isolatedModules`",
+                        errors.len()
+                    )
+                }
+            }
+
+            // Types are removed by the pass.
+            let dts = if config.declaration {
+                Some(self.emit_dts(&program)?)
+            } else {
+                None
+            };
+
+            #[cfg(not(target_arch = "wasm32"))]
+            let program = plugin::apply(self, program, &config.plugins, config.plugin_limits)?;
+            #[cfg(target_arch = "wasm32")]
+            if !config.plugins.is_empty() {
+                bail!("plugins are not supported by wasm")
+            }
+
+            let file = self.cm.span_to_filename(program.span());
             let mut pass = config.pass;
             let program = helpers::HELPERS.set(&Helpers::new(config.external_helpers), || {
                 swc_ecma_utils::HANDLER.set(&self.handler, || {
@@ -541,18 +673,277 @@ impl Compiler {
                 })
             });
 
-            self.print(
+            if let Some(level) = config.check_target {
+                let violations = check_target(&self.cm, &program, config.target);
+
+                for v in &violations {
+                    let msg = format!(
+                        "{} is not supported by the target ({:?}); it requires {}",
+                        v.feature, config.target, v.since
+                    );
+                    match level {
+                        CheckTarget::Warn => self.handler.struct_span_warn(v.span, &msg).emit(),
+                        CheckTarget::Error => self.handler.struct_span_err(v.span, &msg).emit(),
+                    }
+                }
+
+                if level == CheckTarget::Error && !violations.is_empty() {
+                    bail!(
+                        "output contains {} syntax(es) which are not supported by the target",
+                        violations.len()
+                    )
+                }
+            }
+
+            let output = self.print(
                 &program,
                 config.target,
                 config.source_maps,
                 orig,
                 config.minify,
-            )
+            )?;
+
+            let injected_polyfills = config.injected_polyfills.map(|injected| {
+                injected
+                    .get(&file)
+                    .into_iter()
+                    .map(|src| src.to_string())
+                    .collect()
+            });
+
+            Ok(TransformOutput {
+                dts,
+                injected_polyfills,
+                ..output
+            })
+        })
+    }
+
+    /// Parses `fm` and reports syntax errors and early errors of typescript,
+    /// without transforming it.
+    ///
+    /// Errors are returned instead of being emitted to the handler. Only
+    /// `jsc.parser` and `jsc.target` of the config are used.
+    pub fn check(
+        &self,
+        fm: Arc<SourceFile>,
+        opts: &Options,
+    ) -> Result<Vec<CheckDiagnostic>, Error> {
+        self.run(|| -> Result<_, Error> {
+            let mut config = match self.read_config(opts, &fm.name)? {
+                Some(v) => v,
+                // Ignored by .swcrc
+                None => return Ok(vec![]),
+            };
+            config.merge(&opts.config);
+
+            let target = config.jsc.target.unwrap_or_default();
+            let mut syntax = config.jsc.syntax.unwrap_or_default();
+            if let (Syntax::Typescript(c), FileName::Real(path)) = (&mut syntax, &fm.name) {
+                c.dts = path.to_string_lossy().ends_with(".d.ts");
+            }
+
+            let lexer = Lexer::new(syntax, target, StringInput::from(&*fm), None);
+            let mut parser = Parser::new_from(lexer);
+            let program = if opts.is_module {
+                parser.parse_module().map(Program::Module)
+            } else {
+                parser.parse_script().map(Program::Script)
+            };
+
+            let mut errors: Vec<_> = parser
+                .take_errors()
+                .into_iter()
+                .map(|e| (e.span(), e.into_kind().msg().into_owned()))
+                .collect();
+            match program {
+                Ok(program) => {
+                    if syntax.typescript() {
+                        let config = early_errors::Config { dts: syntax.dts() };
+                        errors.extend(
+                            check_early_errors(&to_module(&program), config)
+                                .into_iter()
+                                .map(|e| (e.span, e.msg)),
+                        );
+                    }
+                }
+                Err(e) => errors.push((e.span(), e.into_kind().msg().into_owned())),
+            }
+            errors.sort_by_key(|(span, _)| span.lo());
+
+            Ok(errors
+                .into_iter()
+                .map(|(span, message)| self.check_diagnostic(&fm, span, message))
+                .collect())
+        })
+        .with_context(|| format!("failed to check file ({})", fm.name))
+    }
+
+    /// Applies machine-applicable suggestions of diagnostics reported while
+    /// processing `fm` with `opts`, like fixes of lint rules.
+    ///
+    /// Overlapping suggestions are not applied at once. Instead, the fixed code
+    /// is processed again until no suggestion can be applied, up to
+    /// [MAX_FIX_PASSES] times. Suggestions which still conflict are returned
+    /// as [FixOutput::conflicts].
+    ///
+    /// Diagnostics are not emitted to the handler, and errors of processing
+    /// are ignored as the fixed code may be valid.
+    pub fn apply_fixes(&self, fm: Arc<SourceFile>, opts: &Options) -> Result<FixOutput, Error> {
+        let mut fm = fm;
+        let mut fixed = 0;
+        let mut passes = 0;
+
+        let conflicts = loop {
+            let collector = fix::SuggestionCollector::default();
+            let handler = Handler::with_emitter(true, false, Box::new(collector.clone()));
+            let c = Compiler::new(self.cm.clone(), Arc::new(handler));
+            let _ = c.process_js_file(fm.clone(), opts);
+
+            let (edits, conflicts) = fix::select(&fm, collector.take());
+            if edits.is_empty() || passes == MAX_FIX_PASSES {
+                break conflicts;
+            }
+            passes += 1;
+
+            fixed += edits.len();
+            let code = fix::apply(&fm, &edits);
+            fm = self.cm.new_source_file(fm.name.clone(), code);
+        };
+
+        Ok(FixOutput {
+            code: fm.src.to_string(),
+            fixed,
+            conflicts: conflicts
+                .into_iter()
+                .map(|(span, message)| self.check_diagnostic(&fm, span, message))
+                .collect(),
         })
     }
+
+    fn check_diagnostic(&self, fm: &SourceFile, span: Span, message: String) -> CheckDiagnostic {
+        let loc = self.cm.lookup_char_pos(span.lo());
+
+        CheckDiagnostic {
+            message,
+            line: loc.line,
+            column: loc.col.0,
+            start: (span.lo() - fm.start_pos).0,
+            end: (span.hi() - fm.start_pos).0,
+        }
+    }
+
+    /// Emits typescript declarations (`.d.ts`) of `program`.
+    ///
+    /// Each declaration which requires type inference is reported as an error.
+    pub fn emit_dts(&self, program: &Program) -> Result<String, Error> {
+        self.run(|| {
+            let module = match dts(&to_module(program)) {
+                Ok(v) => v,
+                Err(errors) => {
+                    for e in &errors {
+                        self.handler.struct_span_err(e.span, e.msg).emit();
+                    }
+                    bail!(
+                        "failed to emit declarations: {} declaration(s) require type inference",
+                        errors.len()
+                    )
+                }
+            };
+
+            let mut buf = vec![];
+            {
+                let mut emitter = Emitter {
+                    cfg: Default::default(),
+                    comments: None,
+                    cm: self.cm.clone(),
+                    wr: Box::new(swc_ecma_codegen::text_writer::JsWriter::new(
+                        self.cm.clone(),
+                        "\n",
+                        &mut buf,
+                        None,
+                    )),
+                };
+
+                module
+                    .emit_with(&mut emitter)
+                    .context("failed to emit declarations")?;
+            }
+
+            String::from_utf8(buf).context("declarations are not utf-8")
+        })
+    }
+
+    /// Reports problems found by lint rules.
+    ///
+    /// `program` should not be transformed yet. Returns an error if a problem
+    /// of a rule configured as an error is found.
+    pub fn lint(
+        &self,
+        program: &Program,
+        syntax: Syntax,
+        config: &LintConfig,
+    ) -> Result<(), Error> {
+        self.run(|| {
+            let linter = Linter::new(config).context("invalid `jsc.lints`")?;
+
+            let mark = Mark::fresh(Mark::root());
+            let program = if syntax.typescript() {
+                program.clone().fold_with(&mut ts_resolver(mark))
+            } else {
+                program.clone().fold_with(&mut resolver_with_mark(mark))
+            };
+
+            // Only `swc-disable-next-line` directives are used by the linter.
+            //
+            // Comments of all files are stored in `self.comments`, so we only
+            // use comments in the file of `program`.
+            let fm = self.cm.lookup_char_pos(program.span().lo()).file;
+            let mut directives = vec![];
+            for entry in self
+                .comments
+                .leading
+                .iter()
+                .chain(self.comments.trailing.iter())
+            {
+                directives.extend(
+                    entry
+                        .value()
+                        .iter()
+                        .filter(|c| fm.start_pos <= c.span.lo && c.span.hi <= fm.end_pos)
+                        .filter(|c| c.text.contains("swc-disable"))
+                        .cloned(),
+                );
+            }
+
+            let diagnostics = linter.lint(&self.cm, &directives, &program);
+            for d in &diagnostics {
+                d.emit(&self.handler);
+            }
+
+            let errors = diagnostics.iter().filter(|d| d.is_error()).count();
+            if errors != 0 {
+                bail!("{} error(s) found by lint rules", errors)
+            }
+            Ok(())
+        })
+    }
+}
+
+fn to_module(program: &Program) -> Module {
+    match program {
+        Program::Module(m) => m.clone(),
+        Program::Script(s) => Module {
+            span: s.span,
+            body: s.body.iter().cloned().map(ModuleItem::Stmt).collect(),
+            shebang: None,
+        },
+    }
 }
 
-fn load_swcrc(path: &Path) -> Result<Rc, Error> {
+/// `base` is the config from `tsconfig.json`, which is overridden by the
+/// `.swcrc` file.
+fn load_swcrc(path: &Path, base: Option<&serde_json::Value>) -> Result<Rc, Error> {
     fn convert_json_err(e: serde_json::Error) -> Error {
         let line = e.line();
         let column = e.column();
@@ -571,6 +962,24 @@ fn load_swcrc(path: &Path) -> Result<Rc, Error> {
 
     let content = read_to_string(path).context("failed to read config (.swcrc) file")?;
 
+    if let Some(base) = base {
+        let layer = |over: serde_json::Value| {
+            let mut v = base.clone();
+            merge_json(&mut v, over);
+            v
+        };
+        let value = match serde_json::from_str(&content).map_err(convert_json_err)? {
+            serde_json::Value::Array(configs) => {
+                serde_json::Value::Array(configs.into_iter().map(layer).collect())
+            }
+            config => layer(config),
+        };
+
+        return serde_json::from_value(value.clone())
+            .or_else(|_| serde_json::from_value::<Config>(value).map(Rc::Single))
+            .map_err(convert_json_err);
+    }
+
     match serde_json::from_str(&content) {
         Ok(v) => return Ok(v),
         Err(..) => {}
@@ -0,0 +1,110 @@
+//! Loads plugins configured by `jsc.experimental.plugins`.
+//!
+//! See `swc_plugin` for the abi.
+
+use crate::{
+    config::{PluginConfig, PluginLimits},
+    Compiler,
+};
+use anyhow::{anyhow, bail, Context, Error};
+use dashmap::DashMap;
+use libloading::{Library, Symbol};
+use once_cell::sync::Lazy;
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+use swc_ecma_ast::Program;
+use swc_plugin::{Buffer, ABI_VERSION, ABI_VERSION_SYMBOL, FREE_SYMBOL, PROCESS_SYMBOL};
+
+mod wasm;
+
+type AbiVersionFn = unsafe extern "C" fn() -> u32;
+type ProcessFn = unsafe extern "C" fn(*const u8, usize, *const u8, usize, *mut Buffer) -> i32;
+type FreeFn = unsafe extern "C" fn(Buffer);
+
+/// Libraries are never unloaded, as a plugin may be used by multiple files.
+static LIBRARIES: Lazy<DashMap<PathBuf, Arc<Library>>> = Lazy::new(Default::default);
+
+fn load(path: &Path) -> Result<Arc<Library>, Error> {
+    if let Some(lib) = LIBRARIES.get(path) {
+        return Ok(lib.clone());
+    }
+
+    let lib = unsafe { Library::new(path) }
+        .with_context(|| format!("failed to load plugin ({})", path.display()))?;
+
+    let version = unsafe {
+        let f: Symbol<AbiVersionFn> = lib
+            .get(ABI_VERSION_SYMBOL)
+            .context("the library is not a swc plugin")?;
+        f()
+    };
+    if version != ABI_VERSION {
+        bail!(
+            "plugin ({}) uses abi version {}, but swc uses abi version {}",
+            path.display(),
+            version,
+            ABI_VERSION
+        )
+    }
+
+    let lib = Arc::new(lib);
+    LIBRARIES.insert(path.to_path_buf(), lib.clone());
+    Ok(lib)
+}
+
+/// Applies plugins in order.
+pub(crate) fn apply(
+    c: &Compiler,
+    mut program: Program,
+    plugins: &[PluginConfig],
+    limits: PluginLimits,
+) -> Result<Program, Error> {
+    for PluginConfig(path, config) in plugins {
+        let path = Path::new(path);
+        let res = if path.extension().map_or(false, |ext| ext == "wasm") {
+            wasm::apply(c, path, program, config, limits)
+        } else {
+            apply_native(path, program, config)
+        };
+        program = res.with_context(|| format!("failed to apply plugin ({})", path.display()))?;
+    }
+
+    Ok(program)
+}
+
+fn apply_native(
+    path: &Path,
+    program: Program,
+    config: &serde_json::Value,
+) -> Result<Program, Error> {
+    let lib = load(path)?;
+
+    let program = serde_json::to_vec(&program).context("failed to serialize program")?;
+    let config = serde_json::to_vec(config).context("failed to serialize config")?;
+
+    let (code, out) = unsafe {
+        let process: Symbol<ProcessFn> = lib.get(PROCESS_SYMBOL)?;
+        let free: Symbol<FreeFn> = lib.get(FREE_SYMBOL)?;
+
+        let mut buf = Buffer::empty();
+        let code = process(
+            program.as_ptr(),
+            program.len(),
+            config.as_ptr(),
+            config.len(),
+            &mut buf,
+        );
+        let out = buf.as_slice().to_vec();
+        free(buf);
+
+        (code, out)
+    };
+
+    if code != 0 {
+        return Err(anyhow!("{}", String::from_utf8_lossy(&out)));
+    }
+
+    serde_json::from_slice(&out).context("failed to deserialize program returned by plugin")
+}
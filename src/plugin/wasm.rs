@@ -0,0 +1,382 @@
+//! Runs wasm plugins in a sandbox.
+//!
+//! A plugin can only access the source map, comments and the handler of the
+//! [Compiler], using functions of [HOST_MODULE].
+
+use crate::{config::PluginLimits, Compiler, SwcComments};
+use anyhow::{bail, Context, Error};
+use dashmap::DashMap;
+use once_cell::sync::Lazy;
+use std::{
+    mem::take,
+    panic::{catch_unwind, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    sync::{
+        mpsc::{channel, RecvTimeoutError},
+        Arc,
+    },
+    thread,
+    time::Duration,
+};
+use swc_common::{
+    comments::{Comment, Comments},
+    errors::Handler,
+    BytePos, SourceMap, Span,
+};
+use swc_ecma_ast::Program;
+use swc_plugin::{
+    wasm::{
+        deserialize, serialize, DiagnosticLevel, PluginLoc, ABI_VERSION_EXPORT, ALLOC_EXPORT,
+        FREE_EXPORT, HOST_MODULE, PROCESS_EXPORT,
+    },
+    ABI_VERSION,
+};
+use wasmtime::{
+    Caller, Config, Engine, Instance, Linker, Memory, Module, Store, StoreLimits,
+    StoreLimitsBuilder, Trap,
+};
+
+static ENGINE: Lazy<Engine> = Lazy::new(|| {
+    let mut config = Config::new();
+    config.interruptable(true);
+    Engine::new(&config).expect("failed to create wasm engine")
+});
+
+/// Compiled modules are cached, as a plugin may be used by multiple files.
+static MODULES: Lazy<DashMap<PathBuf, Module>> = Lazy::new(Default::default);
+
+struct HostState {
+    cm: Arc<SourceMap>,
+    handler: Arc<Handler>,
+    comments: SwcComments,
+    limits: StoreLimits,
+    /// Bytes returned by the last host function, which are copied by
+    /// `take_result`.
+    result: Vec<u8>,
+    /// Set by `set_output`.
+    output: Option<Vec<u8>>,
+}
+
+fn load(path: &Path) -> Result<Module, Error> {
+    if let Some(module) = MODULES.get(path) {
+        return Ok(module.clone());
+    }
+
+    let module = Module::from_file(&ENGINE, path)
+        .with_context(|| format!("failed to load wasm plugin ({})", path.display()))?;
+
+    MODULES.insert(path.to_path_buf(), module.clone());
+    Ok(module)
+}
+
+pub(super) fn apply(
+    c: &Compiler,
+    path: &Path,
+    program: Program,
+    config: &serde_json::Value,
+    limits: PluginLimits,
+) -> Result<Program, Error> {
+    let module = load(path)?;
+
+    let mut store = Store::new(
+        &ENGINE,
+        HostState {
+            cm: c.cm.clone(),
+            handler: c.handler.clone(),
+            comments: c.comments.clone(),
+            limits: StoreLimitsBuilder::new()
+                .memory_size(limits.max_memory)
+                .build(),
+            result: vec![],
+            output: None,
+        },
+    );
+    store.limiter(|s| &mut s.limits);
+
+    // The plugin is interrupted if it does not finish in time.
+    let interrupt = store.interrupt_handle()?;
+    let (done, rx) = channel::<()>();
+    let timeout = Duration::from_millis(limits.timeout);
+    let watchdog = thread::spawn(move || match rx.recv_timeout(timeout) {
+        Err(RecvTimeoutError::Timeout) => {
+            interrupt.interrupt();
+            true
+        }
+        _ => false,
+    });
+
+    let res = run(&mut store, &module, program, config);
+
+    drop(done);
+    if watchdog.join().unwrap_or(false) {
+        bail!("wasm plugin timed out after {}ms", limits.timeout)
+    }
+
+    res
+}
+
+fn run(
+    store: &mut Store<HostState>,
+    module: &Module,
+    program: Program,
+    config: &serde_json::Value,
+) -> Result<Program, Error> {
+    let instance = linker()?.instantiate(&mut *store, module)?;
+
+    let version = instance
+        .get_typed_func::<(), u32, _>(&mut *store, ABI_VERSION_EXPORT)
+        .context("the module is not a swc plugin")?
+        .call(&mut *store, ())?;
+    if version != ABI_VERSION {
+        bail!(
+            "wasm plugin uses abi version {}, but swc uses abi version {}",
+            version,
+            ABI_VERSION
+        )
+    }
+
+    let program = serialize(&program).context("failed to serialize program")?;
+    let config = serde_json::to_vec(config).context("failed to serialize config")?;
+
+    let (program_ptr, program_len) = write(store, &instance, &program)?;
+    let (config_ptr, config_len) = write(store, &instance, &config)?;
+
+    let code = instance
+        .get_typed_func::<(u32, u32, u32, u32), i32, _>(&mut *store, PROCESS_EXPORT)?
+        .call(
+            &mut *store,
+            (program_ptr, program_len, config_ptr, config_len),
+        )?;
+
+    let free = instance.get_typed_func::<(u32, u32), (), _>(&mut *store, FREE_EXPORT)?;
+    free.call(&mut *store, (program_ptr, program_len))?;
+    free.call(&mut *store, (config_ptr, config_len))?;
+
+    let output = store.data_mut().output.take().unwrap_or_default();
+    if code != 0 {
+        bail!("{}", String::from_utf8_lossy(&output))
+    }
+
+    deserialize(&output).context("failed to deserialize program returned by plugin")
+}
+
+/// Copies `bytes` to the memory of the plugin.
+fn write(
+    store: &mut Store<HostState>,
+    instance: &Instance,
+    bytes: &[u8],
+) -> Result<(u32, u32), Error> {
+    let len = bytes.len() as u32;
+    let ptr = instance
+        .get_typed_func::<u32, u32, _>(&mut *store, ALLOC_EXPORT)?
+        .call(&mut *store, len)?;
+
+    instance
+        .get_memory(&mut *store, "memory")
+        .context("the plugin does not export memory")?
+        .write(&mut *store, ptr as usize, bytes)?;
+
+    Ok((ptr, len))
+}
+
+fn memory(caller: &mut Caller<'_, HostState>) -> Result<Memory, Trap> {
+    caller
+        .get_export("memory")
+        .and_then(|e| e.into_memory())
+        .ok_or_else(|| Trap::new("the plugin does not export memory"))
+}
+
+fn read(caller: &mut Caller<'_, HostState>, ptr: u32, len: u32) -> Result<Vec<u8>, Trap> {
+    let mut buf = vec![0; len as usize];
+    memory(caller)?
+        .read(&*caller, ptr as usize, &mut buf)
+        .map_err(|err| Trap::new(err.to_string()))?;
+    Ok(buf)
+}
+
+/// Stores bytes to be copied by `take_result`, and returns the length of them.
+fn set_result(caller: &mut Caller<'_, HostState>, bytes: Vec<u8>) -> u32 {
+    let len = bytes.len() as u32;
+    caller.data_mut().result = bytes;
+    len
+}
+
+fn set_optional_result(caller: &mut Caller<'_, HostState>, bytes: Option<Vec<u8>>) -> i32 {
+    match bytes {
+        Some(bytes) => set_result(caller, bytes) as i32,
+        None => -1,
+    }
+}
+
+fn read_comments(
+    caller: &mut Caller<'_, HostState>,
+    ptr: u32,
+    len: u32,
+) -> Result<Vec<Comment>, Trap> {
+    deserialize(&read(caller, ptr, len)?).map_err(|err| Trap::new(err.to_string()))
+}
+
+fn serialize_comments(comments: Option<Vec<Comment>>) -> Result<Option<Vec<u8>>, Trap> {
+    comments
+        .map(|comments| serialize(&comments))
+        .transpose()
+        .map_err(|err| Trap::new(err.to_string()))
+}
+
+fn linker() -> Result<Linker<HostState>, Error> {
+    let mut linker = Linker::new(&ENGINE);
+
+    linker.func_wrap(
+        HOST_MODULE,
+        "take_result",
+        |mut caller: Caller<'_, HostState>, ptr: u32| -> Result<(), Trap> {
+            let result = take(&mut caller.data_mut().result);
+            memory(&mut caller)?
+                .write(&mut caller, ptr as usize, &result)
+                .map_err(|err| Trap::new(err.to_string()))
+        },
+    )?;
+
+    linker.func_wrap(
+        HOST_MODULE,
+        "set_output",
+        |mut caller: Caller<'_, HostState>, ptr: u32, len: u32| -> Result<(), Trap> {
+            let output = read(&mut caller, ptr, len)?;
+            caller.data_mut().output = Some(output);
+            Ok(())
+        },
+    )?;
+
+    linker.func_wrap(
+        HOST_MODULE,
+        "lookup_char_pos",
+        |mut caller: Caller<'_, HostState>, pos: u32| -> Result<u32, Trap> {
+            let cm = caller.data().cm.clone();
+            // The source map panics if `pos` does not belong to a file.
+            let loc = catch_unwind(AssertUnwindSafe(|| cm.lookup_char_pos(BytePos(pos))))
+                .map_err(|_| Trap::new(format!("invalid position: {}", pos)))?;
+            let loc = PluginLoc {
+                file_name: loc.file.name.to_string(),
+                line: loc.line as u32,
+                col: loc.col.0 as u32,
+            };
+
+            let bytes = serialize(&loc).map_err(|err| Trap::new(err.to_string()))?;
+            Ok(set_result(&mut caller, bytes))
+        },
+    )?;
+
+    linker.func_wrap(
+        HOST_MODULE,
+        "span_to_snippet",
+        |mut caller: Caller<'_, HostState>, lo: u32, hi: u32| -> i32 {
+            let span = Span::new(BytePos(lo), BytePos(hi), Default::default());
+            let snippet = caller.data().cm.span_to_snippet(span).ok();
+            set_optional_result(&mut caller, snippet.map(String::into_bytes))
+        },
+    )?;
+
+    linker.func_wrap(
+        HOST_MODULE,
+        "add_leading_comments",
+        |mut caller: Caller<'_, HostState>, pos: u32, ptr: u32, len: u32| -> Result<(), Trap> {
+            let comments = read_comments(&mut caller, ptr, len)?;
+            caller
+                .data()
+                .comments
+                .add_leading_comments(BytePos(pos), comments);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        HOST_MODULE,
+        "has_leading",
+        |caller: Caller<'_, HostState>, pos: u32| -> i32 {
+            caller.data().comments.has_leading(BytePos(pos)) as i32
+        },
+    )?;
+    linker.func_wrap(
+        HOST_MODULE,
+        "move_leading",
+        |caller: Caller<'_, HostState>, from: u32, to: u32| {
+            caller
+                .data()
+                .comments
+                .move_leading(BytePos(from), BytePos(to))
+        },
+    )?;
+    linker.func_wrap(
+        HOST_MODULE,
+        "take_leading",
+        |mut caller: Caller<'_, HostState>, pos: u32| -> Result<i32, Trap> {
+            let comments = caller.data().comments.take_leading(BytePos(pos));
+            let bytes = serialize_comments(comments)?;
+            Ok(set_optional_result(&mut caller, bytes))
+        },
+    )?;
+
+    linker.func_wrap(
+        HOST_MODULE,
+        "add_trailing_comments",
+        |mut caller: Caller<'_, HostState>, pos: u32, ptr: u32, len: u32| -> Result<(), Trap> {
+            let comments = read_comments(&mut caller, ptr, len)?;
+            caller
+                .data()
+                .comments
+                .add_trailing_comments(BytePos(pos), comments);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        HOST_MODULE,
+        "has_trailing",
+        |caller: Caller<'_, HostState>, pos: u32| -> i32 {
+            caller.data().comments.has_trailing(BytePos(pos)) as i32
+        },
+    )?;
+    linker.func_wrap(
+        HOST_MODULE,
+        "move_trailing",
+        |caller: Caller<'_, HostState>, from: u32, to: u32| {
+            caller
+                .data()
+                .comments
+                .move_trailing(BytePos(from), BytePos(to))
+        },
+    )?;
+    linker.func_wrap(
+        HOST_MODULE,
+        "take_trailing",
+        |mut caller: Caller<'_, HostState>, pos: u32| -> Result<i32, Trap> {
+            let comments = caller.data().comments.take_trailing(BytePos(pos));
+            let bytes = serialize_comments(comments)?;
+            Ok(set_optional_result(&mut caller, bytes))
+        },
+    )?;
+
+    linker.func_wrap(
+        HOST_MODULE,
+        "emit_diagnostic",
+        |mut caller: Caller<'_, HostState>,
+         level: u32,
+         lo: u32,
+         hi: u32,
+         ptr: u32,
+         len: u32|
+         -> Result<(), Trap> {
+            let msg = read(&mut caller, ptr, len)?;
+            let msg = String::from_utf8_lossy(&msg);
+            let span = Span::new(BytePos(lo), BytePos(hi), Default::default());
+
+            let handler = &caller.data().handler;
+            match DiagnosticLevel::from_u32(level) {
+                Some(DiagnosticLevel::Error) => handler.struct_span_err(span, &msg).emit(),
+                Some(DiagnosticLevel::Warning) => handler.struct_span_warn(span, &msg).emit(),
+                None => return Err(Trap::new(format!("invalid diagnostic level: {}", level))),
+            }
+            Ok(())
+        },
+    )?;
+
+    Ok(linker)
+}
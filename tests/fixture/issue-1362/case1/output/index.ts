@@ -30,11 +30,11 @@ function _applyDecoratedDescriptor(target, property, decorators, descriptor, con
     }
     return desc;
 }
-var _class, _dec, _dec1, _dec2, _dec3, _dec4, _dec5, _dec6;
-var _dec7 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:paramtypes", [
+var _class, _dec, _dec1, _dec2, _dec3, _dec4, _dec5, _dec6, _dec7, _dec8;
+var _dec9 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:paramtypes", [
     typeof _appService.AppService === "undefined" ? Object : _appService.AppService
-]), _dec8 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Function), _dec9 = (0, _common).Controller();
-let AppController = _class = _dec9(_class = _dec8(_class = _dec7(((_class = class AppController1 {
+]), _dec10 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Function), _dec11 = (0, _common).Controller();
+let AppController = _class = _dec11(_class = _dec10(_class = _dec9(((_class = class AppController1 {
     async getHello() {
         const result = await this.appService.getHello();
         return result;
@@ -46,18 +46,20 @@ let AppController = _class = _dec9(_class = _dec8(_class = _dec7(((_class = clas
     constructor(appService){
         this.appService = appService;
     }
-}) || _class, _dec = (0, _common).Get(), _dec1 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Function), _dec2 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:paramtypes", []), _applyDecoratedDescriptor(_class.prototype, "getHello", [
+}) || _class, _dec = (0, _common).Get(), _dec1 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Function), _dec2 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:paramtypes", []), _dec3 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:returntype", Promise), _applyDecoratedDescriptor(_class.prototype, "getHello", [
     _dec,
     _dec1,
-    _dec2
-], Object.getOwnPropertyDescriptor(_class.prototype, "getHello"), _class.prototype), _dec3 = (0, _common).Post(), _dec4 = function(target, key) {
+    _dec2,
+    _dec3
+], Object.getOwnPropertyDescriptor(_class.prototype, "getHello"), _class.prototype), _dec4 = (0, _common).Post(), _dec5 = function(target, key) {
     return (0, _common).Body()(target, key, 0);
-}, _dec5 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Function), _dec6 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:paramtypes", [
+}, _dec6 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Function), _dec7 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:paramtypes", [
     typeof _createUserDto.CreateUserDto === "undefined" ? Object : _createUserDto.CreateUserDto
-]), _applyDecoratedDescriptor(_class.prototype, "create", [
-    _dec3,
+]), _dec8 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:returntype", Promise), _applyDecoratedDescriptor(_class.prototype, "create", [
     _dec4,
     _dec5,
-    _dec6
+    _dec6,
+    _dec7,
+    _dec8
 ], Object.getOwnPropertyDescriptor(_class.prototype, "create"), _class.prototype), _class)) || _class) || _class) || _class;
 exports.AppController = AppController;
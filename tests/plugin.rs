@@ -0,0 +1,152 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use std::{env, fs, path::PathBuf, sync::Arc};
+use swc::{
+    config::{Config, JscConfig, JscExperimental, Options, PluginConfig, PluginLimits},
+    Compiler,
+};
+use swc_common::{FileName, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_plugin::{wasm::serialize, ABI_VERSION};
+use testing::Tester;
+
+/// Address of the program returned by plugins.
+const OUTPUT_PTR: usize = 16;
+
+/// Writes a wasm plugin, in the text format, which runs `process` as the body
+/// of `swc_plugin_process_wasm_v1`.
+///
+/// The cbor of `"plugin";` is stored at [OUTPUT_PTR], and `{ptr}` and `{len}`
+/// in `process` are replaced with the address and the length of it.
+/// `swc_plugin_alloc` is a bump allocator which does not grow the memory.
+fn wasm_plugin(name: &str, process: &str) -> PathBuf {
+    let output = Program::Script(Script {
+        span: DUMMY_SP,
+        body: vec![Stmt::Expr(ExprStmt {
+            span: DUMMY_SP,
+            expr: Box::new(Expr::Lit(Lit::Str(Str {
+                span: DUMMY_SP,
+                value: "plugin".into(),
+                has_escape: false,
+                kind: Default::default(),
+            }))),
+        })],
+        shebang: None,
+    });
+    let output = serialize(&output).unwrap();
+    let data: String = output.iter().map(|b| format!("\\{:02x}", b)).collect();
+
+    let wat = format!(
+        r#"(module
+    (import "swc_host" "set_output" (func $set_output (param i32 i32)))
+    (memory (export "memory") 1)
+    (global $heap (mut i32) (i32.const {heap}))
+    (data (i32.const {ptr}) "{data}")
+    (data (i32.const 0) "out of memory")
+
+    (func (export "swc_plugin_abi_version") (result i32)
+        (i32.const {version}))
+
+    (func (export "swc_plugin_alloc") (param $len i32) (result i32)
+        (global.get $heap)
+        (global.set $heap (i32.add (global.get $heap) (local.get $len))))
+
+    (func (export "swc_plugin_free") (param i32 i32))
+
+    (func (export "swc_plugin_process_wasm_v1") (param i32 i32 i32 i32) (result i32)
+        {process}))
+"#,
+        heap = OUTPUT_PTR + output.len(),
+        ptr = OUTPUT_PTR,
+        data = data,
+        version = ABI_VERSION,
+        process = process
+            .replace("{ptr}", &OUTPUT_PTR.to_string())
+            .replace("{len}", &output.len().to_string()),
+    );
+
+    let path = env::temp_dir().join(format!("swc-plugin-{}-{}.wasm", name, std::process::id()));
+    fs::write(&path, wat).unwrap();
+    path
+}
+
+fn run(plugin: PathBuf, limits: PluginLimits) -> Result<String, String> {
+    let res = Tester::new().print_errors(|cm, handler| {
+        let c = Compiler::new(cm.clone(), Arc::new(handler));
+
+        let fm = cm.new_source_file(FileName::Real("input.js".into()), "'input';".into());
+        let res = c.process_js_file(
+            fm,
+            &Options {
+                swcrc: false,
+                config: Config {
+                    jsc: JscConfig {
+                        experimental: JscExperimental {
+                            plugins: vec![PluginConfig(
+                                plugin.to_string_lossy().into_owned(),
+                                serde_json::json!({}),
+                            )],
+                            plugin_limits: Some(limits),
+                        },
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        );
+
+        Ok(res.map(|v| v.code).map_err(|err| format!("{:?}", err)))
+    });
+    let _ = fs::remove_file(plugin);
+
+    res.unwrap()
+}
+
+#[test]
+fn wasm_transform() {
+    let plugin = wasm_plugin(
+        "transform",
+        "(call $set_output (i32.const {ptr}) (i32.const {len})) (i32.const 0)",
+    );
+
+    let code = run(plugin, Default::default()).unwrap();
+    assert_eq!(code.trim(), "\"plugin\";");
+}
+
+#[test]
+fn wasm_timeout() {
+    let plugin = wasm_plugin("timeout", "(loop $l (br $l)) (i32.const 0)");
+
+    let err = run(
+        plugin,
+        PluginLimits {
+            timeout: 100,
+            ..Default::default()
+        },
+    )
+    .unwrap_err();
+    assert!(err.contains("wasm plugin timed out after 100ms"), "{}", err);
+}
+
+#[test]
+fn wasm_memory_limit() {
+    // Grows the memory by 2 MiB, which is larger than the limit.
+    let plugin = wasm_plugin(
+        "memory",
+        "(if (i32.eq (memory.grow (i32.const 32)) (i32.const -1))
+            (then (call $set_output (i32.const 0) (i32.const 13)) (return (i32.const 1))))
+        (call $set_output (i32.const {ptr}) (i32.const {len}))
+        (i32.const 0)",
+    );
+
+    let err = run(
+        plugin,
+        PluginLimits {
+            max_memory: 1024 * 1024,
+            ..Default::default()
+        },
+    )
+    .unwrap_err();
+    assert!(err.contains("out of memory"), "{}", err);
+}
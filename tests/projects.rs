@@ -5,7 +5,9 @@ use std::{
     sync::Arc,
 };
 use swc::{
-    config::{Config, JscConfig, ModuleConfig, Options, SourceMapsConfig, TransformConfig},
+    config::{
+        Config, ConfigFile, JscConfig, ModuleConfig, Options, SourceMapsConfig, TransformConfig,
+    },
     Compiler,
 };
 use swc_common::FileName;
@@ -730,3 +732,85 @@ fn tests(dir: PathBuf) {
         .map(|_| ())
         .expect("failed");
 }
+
+#[test]
+fn tsconfig() {
+    let f = file_with_opt(
+        "tests/projects/tsconfig/input.tsx",
+        Options {
+            swcrc: true,
+            tsconfig: true,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    assert!(f.contains("h(\"div\", null)"));
+    assert!(f.contains("async"));
+    assert!(f.contains("exports.render"));
+}
+
+#[test]
+fn tsconfig_overridden_by_swcrc() {
+    let f = file_with_opt(
+        "tests/projects/tsconfig-swcrc/input.ts",
+        Options {
+            swcrc: true,
+            tsconfig: true,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    assert!(f.contains("async"));
+    assert!(f.contains("exports.f"));
+}
+
+#[test]
+fn tsconfig_jsx_with_ts_file() {
+    let f = file_with_opt(
+        "tests/projects/tsconfig-ts/input.ts",
+        Options {
+            swcrc: true,
+            tsconfig: true,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    assert!(f.contains("export const value = foo;"));
+}
+
+#[test]
+fn tsconfig_with_js_file() {
+    let f = file_with_opt(
+        "tests/projects/tsconfig-ts/input.js",
+        Options {
+            swcrc: true,
+            tsconfig: true,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    assert!(f.contains("f < T > x"));
+}
+
+#[test]
+fn tsconfig_overridden_by_config_file() {
+    let f = file_with_opt(
+        "tests/projects/tsconfig-config-file/input.ts",
+        Options {
+            swcrc: false,
+            tsconfig: true,
+            config_file: Some(ConfigFile::Str(
+                "tests/projects/tsconfig-config-file/config.json".into(),
+            )),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    assert!(f.contains("async"));
+    assert!(f.contains("exports.f"));
+}
@@ -0,0 +1 @@
+export const value = <number>foo;
@@ -0,0 +1,4 @@
+@component
+class App {}
+
+export const render = async () => <div />;
@@ -1,12 +1,13 @@
 use std::sync::Arc;
 use swc::{
-    config::{Config, JscConfig, Options},
+    config::{CheckTarget, Config, JscConfig, Options, TransformConfig},
     Compiler,
 };
 use swc_common::FileName;
 use swc_ecma_ast::EsVersion;
 use swc_ecma_parser::TsConfig;
 use swc_ecma_parser::{EsConfig, Syntax};
+use swc_ecma_preset_env::Query;
 use testing::Tester;
 
 fn compile(src: &str, options: Options) -> String {
@@ -148,3 +149,557 @@ fn test_tsx_escape_xhtml() {
 
     assert_eq!(compiled_es2020, expected);
 }
+
+#[test]
+fn check_target_es5() {
+    compile(
+        "
+        const { a, ...rest } = obj;
+        class Foo {
+            get bar() {
+                return `${a}`;
+            }
+        }
+        export const foo = async (...args) => [...args];
+        ",
+        Options {
+            config: Config {
+                jsc: JscConfig {
+                    target: Some(EsVersion::Es5),
+                    check_target: Some(CheckTarget::Error),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            swcrc: false,
+            ..Default::default()
+        },
+    );
+}
+
+/// `env` ignores `jsc.target`, so the output is only checked.
+#[test]
+fn check_target_error() {
+    let err = Tester::new()
+        .print_errors(|cm, handler| {
+            let c = Compiler::new(cm.clone(), Arc::new(handler));
+
+            let fm = cm.new_source_file(
+                FileName::Real("input.js".into()),
+                "const a = [1, 2,];".into(),
+            );
+            let s = c.process_js_file(
+                fm,
+                &Options {
+                    is_module: true,
+                    config: Config {
+                        env: Some(swc_ecma_preset_env::Config {
+                            targets: Some(Query::Single("chrome 80".into()).into()),
+                            ..Default::default()
+                        }),
+                        jsc: JscConfig {
+                            target: Some(EsVersion::Es3),
+                            check_target: Some(CheckTarget::Error),
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    },
+                    swcrc: false,
+                    ..Default::default()
+                },
+            );
+
+            match s {
+                Ok(..) => Ok(()),
+                Err(..) => Err(()),
+            }
+        })
+        .expect_err("es2015 syntax should be rejected");
+
+    let err = err.to_string();
+    assert!(
+        err.contains("const declaration is not supported by the target (Es3); it requires es2015"),
+        "{}",
+        err
+    );
+    assert!(
+        err.contains("trailing comma is not supported by the target (Es3); it requires es5"),
+        "{}",
+        err
+    );
+}
+
+#[test]
+fn declaration() {
+    let dts = Tester::new()
+        .print_errors(|cm, handler| {
+            let c = Compiler::new(cm.clone(), Arc::new(handler));
+
+            let fm = cm.new_source_file(
+                FileName::Real("input.ts".into()),
+                "
+                export interface Foo {
+                    bar: string;
+                }
+                export function foo(bar: string = ''): Foo {
+                    return { bar };
+                }
+                "
+                .into(),
+            );
+            let s = c.process_js_file(
+                fm,
+                &Options {
+                    is_module: true,
+                    config: Config {
+                        jsc: JscConfig {
+                            syntax: Some(Syntax::Typescript(Default::default())),
+                            ..Default::default()
+                        },
+                        declaration: Some(true),
+                        ..Default::default()
+                    },
+                    swcrc: false,
+                    ..Default::default()
+                },
+            );
+
+            match s {
+                Ok(v) => Ok(v.dts),
+                Err(..) => Err(()),
+            }
+        })
+        .unwrap()
+        .expect("declarations should be emitted");
+
+    assert!(
+        dts.contains("export declare function foo(bar?: string): Foo;"),
+        "{}",
+        dts
+    );
+    assert!(!dts.contains("return"), "{}", dts);
+}
+
+#[test]
+fn isolated_modules() {
+    let err = Tester::new()
+        .print_errors(|cm, handler| {
+            let c = Compiler::new(cm.clone(), Arc::new(handler));
+
+            let fm = cm.new_source_file(
+                FileName::Real("input.ts".into()),
+                "
+                import type { Foo } from './foo';
+                export { Foo };
+                "
+                .into(),
+            );
+            let s = c.process_js_file(
+                fm,
+                &Options {
+                    is_module: true,
+                    config: Config {
+                        jsc: JscConfig {
+                            syntax: Some(Syntax::Typescript(Default::default())),
+                            isolated_modules: true,
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    },
+                    swcrc: false,
+                    ..Default::default()
+                },
+            );
+
+            match s {
+                Ok(..) => Ok(()),
+                Err(..) => Err(()),
+            }
+        })
+        .expect_err("re-exporting a type should be reported");
+
+    assert!(
+        err.to_string()
+            .contains("re-exporting a type requires `export type`"),
+        "{}",
+        err
+    );
+}
+
+/// A binding which shadows an import doesn't make the import a value.
+#[test]
+fn isolated_modules_shadowed_import() {
+    let err = Tester::new()
+        .print_errors(|cm, handler| {
+            let c = Compiler::new(cm.clone(), Arc::new(handler));
+
+            let fm = cm.new_source_file(
+                FileName::Real("input.ts".into()),
+                "
+                import { Foo } from './foo';
+                let foo: Foo;
+                function bar(Foo: number) {
+                    return Foo;
+                }
+                export { Foo };
+                "
+                .into(),
+            );
+            let s = c.process_js_file(
+                fm,
+                &Options {
+                    is_module: true,
+                    config: Config {
+                        jsc: JscConfig {
+                            syntax: Some(Syntax::Typescript(Default::default())),
+                            isolated_modules: true,
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    },
+                    swcrc: false,
+                    ..Default::default()
+                },
+            );
+
+            match s {
+                Ok(..) => Ok(()),
+                Err(..) => Err(()),
+            }
+        })
+        .expect_err("re-exporting a type should be reported");
+
+    assert!(
+        err.to_string()
+            .contains("re-exporting a type requires `export type`"),
+        "{}",
+        err
+    );
+}
+
+#[test]
+fn preset_env_unknown_query() {
+    let err = Tester::new()
+        .print_errors(|cm, handler| {
+            let c = Compiler::new(cm.clone(), Arc::new(handler));
+
+            let fm = cm.new_source_file(FileName::Real("input.js".into()), "foo?.bar;".into());
+            let s = c.process_js_file(
+                fm,
+                &Options {
+                    is_module: true,
+                    config: Config {
+                        env: Some(swc_ecma_preset_env::Config {
+                            targets: Some(Query::Single("unknown 10".into()).into()),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    },
+                    swcrc: false,
+                    ..Default::default()
+                },
+            );
+
+            match s {
+                Ok(..) => Ok(()),
+                Err(..) => Err(()),
+            }
+        })
+        .expect_err("an unknown browserslist query should be reported");
+
+    assert!(
+        err.to_string().contains("unknown browser `unknown`"),
+        "{}",
+        err
+    );
+}
+
+#[test]
+fn decorator_metadata_with_2018_09_decorators() {
+    let err = Tester::new()
+        .print_errors(|cm, handler| {
+            let c = Compiler::new(cm.clone(), Arc::new(handler));
+
+            let fm = cm.new_source_file(
+                FileName::Real("input.js".into()),
+                "@dec class Foo {}".into(),
+            );
+            let s = c.process_js_file(
+                fm,
+                &Options {
+                    is_module: true,
+                    config: Config {
+                        jsc: JscConfig {
+                            syntax: Some(Syntax::Es(EsConfig {
+                                decorators: true,
+                                ..Default::default()
+                            })),
+                            transform: Some(TransformConfig {
+                                decorator_metadata: true,
+                                ..Default::default()
+                            }),
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    },
+                    swcrc: false,
+                    ..Default::default()
+                },
+            );
+
+            match s {
+                Ok(..) => Ok(()),
+                Err(..) => Err(()),
+            }
+        })
+        .expect_err("decorator metadata should be rejected");
+
+    assert!(
+        err.to_string()
+            .contains("`jsc.transform.decoratorMetadata` requires `legacyDecorator`"),
+        "{}",
+        err
+    );
+}
+
+#[test]
+fn preset_env_injected_polyfills() {
+    let outputs = Tester::new()
+        .print_errors(|cm, handler| {
+            let c = Compiler::new(cm.clone(), Arc::new(handler));
+
+            let opts = Options {
+                is_module: true,
+                config: Config {
+                    env: Some(swc_ecma_preset_env::Config {
+                        mode: Some(swc_ecma_preset_env::Mode::Usage),
+                        core_js: Some("3".parse().unwrap()),
+                        targets: Some(Query::Single("ie 11".into()).into()),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+                swcrc: false,
+                ..Default::default()
+            };
+
+            let mut outputs = vec![];
+            for (name, src) in &[("a.js", "'a'.padStart(2);"), ("b.js", "foo();")] {
+                let fm = cm.new_source_file(FileName::Real(name.into()), src.to_string());
+                outputs.push(c.process_js_file(fm, &opts).map_err(|_| ())?);
+            }
+
+            Ok(outputs)
+        })
+        .unwrap();
+
+    assert_eq!(
+        outputs[0].injected_polyfills,
+        Some(vec!["core-js/modules/es.string.pad-start".to_string()])
+    );
+    assert_eq!(outputs[1].injected_polyfills, Some(vec![]));
+}
+
+#[test]
+fn paths() {
+    let code = compile(
+        "
+        import { format } from '@app/utils/format';
+        export * from '@app/components';
+        ",
+        Options {
+            filename: "/project/src/pages/index.js".into(),
+            config: Config {
+                jsc: JscConfig {
+                    base_url: Some("/project".into()),
+                    paths: vec![("@app/*".into(), vec!["src/*".into()])]
+                        .into_iter()
+                        .collect(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            swcrc: false,
+            ..Default::default()
+        },
+    );
+
+    assert!(code.contains("\"../utils/format\""), "{}", code);
+    assert!(code.contains("\"../components\""), "{}", code);
+}
+
+#[test]
+fn check() {
+    let diagnostics = Tester::new()
+        .print_errors(|cm, handler| {
+            let c = Compiler::new(cm.clone(), Arc::new(handler));
+
+            let fm = cm.new_source_file(
+                FileName::Real("input.ts".into()),
+                "class A {\n    abstract m(): void;\n}\ndelete a;\n".into(),
+            );
+            let diagnostics = c
+                .check(
+                    fm,
+                    &Options {
+                        is_module: true,
+                        config: Config {
+                            jsc: JscConfig {
+                                syntax: Some(Syntax::Typescript(Default::default())),
+                                ..Default::default()
+                            },
+                            ..Default::default()
+                        },
+                        swcrc: false,
+                        ..Default::default()
+                    },
+                )
+                .unwrap();
+            assert!(!c.handler.has_errors());
+
+            Ok(diagnostics)
+        })
+        .unwrap();
+
+    assert_eq!(diagnostics.len(), 3, "{:?}", diagnostics);
+    assert_eq!(
+        diagnostics[0].message,
+        "Abstract methods can only appear within an abstract class."
+    );
+    assert_eq!((diagnostics[0].line, diagnostics[0].column), (2, 13));
+    assert_eq!((diagnostics[0].start, diagnostics[0].end), (23, 24));
+    assert_eq!(
+        diagnostics[1].message,
+        "'delete' cannot be called on an identifier in strict mode"
+    );
+    assert_eq!(diagnostics[1].line, 4);
+    assert_eq!(
+        diagnostics[2].message,
+        "The operand of a delete operator must be a property reference."
+    );
+    assert_eq!(diagnostics[2].line, 4);
+}
+
+#[test]
+fn lints() {
+    let err = Tester::new()
+        .print_errors(|cm, handler| {
+            let c = Compiler::new(cm.clone(), Arc::new(handler));
+
+            let fm = cm.new_source_file(
+                FileName::Real("input.js".into()),
+                "
+                foo();
+                // swc-disable-next-line no-undef
+                bar();
+                "
+                .into(),
+            );
+            let s = c.process_js_file(
+                fm,
+                &Options {
+                    is_module: true,
+                    config: Config {
+                        jsc: JscConfig {
+                            lints: Some(Default::default()),
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    },
+                    swcrc: false,
+                    ..Default::default()
+                },
+            );
+
+            match s {
+                Ok(..) => Ok(()),
+                Err(..) => Err(()),
+            }
+        })
+        .expect_err("undefined variables should be reported");
+
+    let err = err.to_string();
+    assert!(err.contains("`foo` is not defined"), "{}", err);
+    assert!(!err.contains("`bar` is not defined"), "{}", err);
+}
+
+#[test]
+fn lint_directives_of_other_files() {
+    let err = Tester::new()
+        .print_errors(|cm, handler| {
+            let c = Compiler::new(cm.clone(), Arc::new(handler));
+            let options = Options {
+                is_module: true,
+                config: Config {
+                    jsc: JscConfig {
+                        lints: Some(Default::default()),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                swcrc: false,
+                ..Default::default()
+            };
+
+            let a = cm.new_source_file(
+                FileName::Real("a.js".into()),
+                "// swc-disable-next-line no-undef\nfoo();\n".into(),
+            );
+            c.process_js_file(a, &options)
+                .expect("`foo` should not be reported");
+
+            // `bar` is on the line disabled in `a.js`.
+            let b = cm.new_source_file(FileName::Real("b.js".into()), "\nbar();\n".into());
+            match c.process_js_file(b, &options) {
+                Ok(..) => Ok(()),
+                Err(..) => Err(()),
+            }
+        })
+        .expect_err("directives of `a.js` should not be applied to `b.js`");
+
+    let err = err.to_string();
+    assert!(err.contains("`bar` is not defined"), "{}", err);
+    assert!(!err.contains("`foo` is not defined"), "{}", err);
+}
+
+#[test]
+fn apply_fixes() {
+    let output = Tester::new()
+        .print_errors(|cm, handler| {
+            let c = Compiler::new(cm.clone(), Arc::new(handler));
+
+            let fm = cm.new_source_file(
+                FileName::Real("input.js".into()),
+                "import { a, b, c } from 'a';\nimport d from 'd';\nexport default a;\n".into(),
+            );
+            let output = c
+                .apply_fixes(
+                    fm,
+                    &Options {
+                        is_module: true,
+                        config: Config {
+                            jsc: JscConfig {
+                                lints: Some(Default::default()),
+                                ..Default::default()
+                            },
+                            ..Default::default()
+                        },
+                        swcrc: false,
+                        ..Default::default()
+                    },
+                )
+                .unwrap();
+            assert!(!c.handler.has_errors());
+
+            Ok(output)
+        })
+        .unwrap();
+
+    assert_eq!(
+        output.code,
+        "import { a } from 'a';\nimport 'd';\nexport default a;\n"
+    );
+    // Removing `b` and `c` conflicts, so `c` is removed in the second pass.
+    assert_eq!(output.fixed, 3);
+    assert!(output.conflicts.is_empty(), "{:?}", output.conflicts);
+}
@@ -7,10 +7,10 @@ use std::{collections::HashSet, mem::replace};
 use swc_macros_common::{call_site, def_site};
 use syn::{
     parse_quote::parse, punctuated::Punctuated, spanned::Spanned, Arm, AttrStyle, Attribute, Block,
-    Expr, ExprBlock, ExprMatch, FieldValue, Fields, FnArg, GenericArgument, ImplItem,
-    ImplItemMethod, Index, Item, ItemImpl, ItemTrait, Member, Path, PathArguments, ReturnType,
-    Signature, Stmt, Token, TraitItem, TraitItemMethod, Type, TypePath, TypeReference, VisPublic,
-    Visibility,
+    Expr, ExprBlock, ExprMatch, Field, FieldValue, Fields, FnArg, GenericArgument, ImplItem,
+    ImplItemMethod, Index, Item, ItemEnum, ItemImpl, ItemTrait, LitStr, Member, Path,
+    PathArguments, ReturnType, Signature, Stmt, Token, TraitItem, TraitItemMethod, Type, TypePath,
+    TypeReference, VisPublic, Visibility,
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -19,6 +19,8 @@ enum Mode {
     VisitAll,
     VisitMut,
     Fold,
+    VisitAstPath,
+    VisitMutAstPath,
 }
 
 impl Mode {
@@ -28,14 +30,16 @@ impl Mode {
             Mode::VisitAll => "VisitAll",
             Mode::Visit => "Visit",
             Mode::VisitMut => "VisitMut",
+            Mode::VisitAstPath => "VisitAstPath",
+            Mode::VisitMutAstPath => "VisitMutAstPath",
         }
     }
 
     fn prefix(self) -> &'static str {
         match self {
             Mode::Fold => "fold",
-            Mode::Visit | Mode::VisitAll => "visit",
-            Mode::VisitMut => "visit_mut",
+            Mode::Visit | Mode::VisitAll | Mode::VisitAstPath => "visit",
+            Mode::VisitMut | Mode::VisitMutAstPath => "visit_mut",
         }
     }
 }
@@ -47,6 +51,9 @@ impl Mode {
 ///  - highly extensible and used to create Visitor for any types
 ///
 ///  - create `Visit`, `VisitAll`, `VisitMut`, `Fold`
+///
+///  - create `VisitAstPath`, `VisitMutAstPath` in `path` module if the feature
+///    `path` of the invoking crate is enabled
 #[proc_macro]
 pub fn define(tts: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let block: Block = parse(tts.into());
@@ -56,10 +63,392 @@ pub fn define(tts: proc_macro::TokenStream) -> proc_macro::TokenStream {
     q.push_tokens(&make(Mode::Visit, &block.stmts));
     q.push_tokens(&make(Mode::VisitAll, &block.stmts));
     q.push_tokens(&make(Mode::VisitMut, &block.stmts));
+    q.push_tokens(&make_ast_path(&block.stmts));
 
     proc_macro2::TokenStream::from(q).into()
 }
 
+/// Creates `path` module, which contains `AstKind` and visitors maintaining
+/// ancestors of the node being visited.
+fn make_ast_path(stmts: &[Stmt]) -> Quote {
+    let mut kind_enum = q!({
+        /// Kind of an ancestor node.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum AstKind {}
+    })
+    .parse::<ItemEnum>();
+
+    for stmt in stmts {
+        let ident = match stmt {
+            Stmt::Item(Item::Struct(s)) => &s.ident,
+            Stmt::Item(Item::Enum(e)) => &e.ident,
+            _ => unimplemented!("error reporting for something other than Item"),
+        };
+
+        kind_enum
+            .variants
+            .push(q!(Vars { ident }, { ident }).parse());
+    }
+
+    let mut body = Quote::new_call_site();
+    body.push_tokens(&kind_enum);
+    body.push_tokens(&make_ast_node_ref(stmts));
+    body.push_tokens(&make(Mode::VisitAstPath, stmts));
+    body.push_tokens(&make(Mode::VisitMutAstPath, stmts));
+
+    q!(Vars { body }, {
+        /// Visitors which provide the path from the root node to the parent
+        /// of the node being visited.
+        #[cfg(feature = "path")]
+        pub mod path {
+            use super::*;
+
+            pub type AstPath = ::swc_visit::AstKindPath<AstKind>;
+
+            pub type AstPathEntry = ::swc_visit::AstPathEntry<AstKind>;
+
+            body
+        }
+    })
+}
+
+/// Creates `AstNodeRef`, which allows inspecting fields of nodes without
+/// knowing their types.
+fn make_ast_node_ref(stmts: &[Stmt]) -> Quote {
+    let items = stmts
+        .iter()
+        .map(|stmt| match stmt {
+            Stmt::Item(item @ Item::Struct(..)) | Stmt::Item(item @ Item::Enum(..)) => item,
+            _ => unimplemented!("error reporting for something other than Item"),
+        })
+        .collect::<Vec<_>>();
+    let names = items
+        .iter()
+        .map(|item| match item {
+            Item::Struct(s) => s.ident.to_string(),
+            Item::Enum(e) => e.ident.to_string(),
+            _ => unreachable!(),
+        })
+        .collect::<HashSet<_>>();
+
+    let mut ref_enum = q!({
+        /// Reference to a node, which can be used to inspect fields of the
+        /// node.
+        #[derive(Clone, Copy)]
+        pub enum AstNodeRef<'ast> {}
+    })
+    .parse::<ItemEnum>();
+
+    let mut kind_match: ExprMatch = q!((match self {})).parse();
+    let mut name_match: ExprMatch = q!((match self {})).parse();
+    let mut variant_match: ExprMatch = q!((match self {})).parse();
+    let mut fields_match: ExprMatch = q!((match self {})).parse();
+    let mut from_impls = q!({});
+
+    for item in items {
+        let (ident, arms) = match item {
+            Item::Struct(s) => {
+                variant_match
+                    .arms
+                    .push(q!(Vars { Name: &s.ident }, { AstNodeRef::Name(..) => None, }).parse());
+
+                let arm = make_fields_arm(&names, &s.ident.clone().into(), None, &s.fields);
+                (&s.ident, vec![arm])
+            }
+            Item::Enum(e) => {
+                let mut inner: ExprMatch = q!((match n {})).parse();
+                let mut arms = vec![];
+
+                for variant in &e.variants {
+                    let path = q!(
+                        Vars {
+                            Enum: &e.ident,
+                            Variant: &variant.ident
+                        },
+                        { Enum::Variant }
+                    )
+                    .parse::<Path>();
+
+                    inner.arms.push(
+                        q!(
+                            Vars {
+                                path: &path,
+                                name: LitStr::new(&variant.ident.to_string(), call_site()),
+                            },
+                            { path { .. } => name, }
+                        )
+                        .parse(),
+                    );
+                    arms.push(make_fields_arm(
+                        &names,
+                        &path,
+                        Some(&variant.ident),
+                        &variant.fields,
+                    ));
+                }
+
+                variant_match.arms.push(
+                    q!(Vars { Name: &e.ident, inner }, { AstNodeRef::Name(n) => Some(inner), })
+                        .parse(),
+                );
+
+                (&e.ident, arms)
+            }
+            _ => unreachable!(),
+        };
+
+        ref_enum
+            .variants
+            .push(q!(Vars { Name: ident }, { Name(&'ast Name) }).parse());
+        kind_match
+            .arms
+            .push(q!(Vars { Name: ident }, { AstNodeRef::Name(..) => AstKind::Name, }).parse());
+        name_match.arms.push(
+            q!(
+                Vars {
+                    Name: ident,
+                    name: LitStr::new(&ident.to_string(), call_site()),
+                },
+                { AstKind::Name => name, }
+            )
+            .parse(),
+        );
+
+        let mut inner: ExprMatch = q!((match n {})).parse();
+        inner.arms.extend(arms);
+        fields_match
+            .arms
+            .push(q!(Vars { Name: ident, inner }, { AstNodeRef::Name(n) => inner, }).parse());
+
+        from_impls.push_tokens(&q!(Vars { Name: ident }, {
+            impl<'ast> From<&'ast Name> for AstNodeRef<'ast> {
+                fn from(n: &'ast Name) -> Self {
+                    AstNodeRef::Name(n)
+                }
+            }
+        }));
+    }
+
+    let mut tokens = q!(
+        Vars {
+            ref_enum,
+            kind_match,
+            name_match,
+            variant_match,
+            fields_match,
+        },
+        {
+            impl AstKind {
+                /// Name of the type.
+                pub fn name(self) -> &'static str {
+                    name_match
+                }
+            }
+
+            ref_enum
+
+            /// A field of a node.
+            #[derive(Clone, Copy)]
+            pub enum AstField<'ast> {
+                Node(AstNodeRef<'ast>),
+                /// A value which is not a node, like `bool` or `JsWord`.
+                Value(&'ast dyn ::std::any::Any),
+            }
+
+            impl<'ast> AstNodeRef<'ast> {
+                pub fn kind(self) -> AstKind {
+                    kind_match
+                }
+
+                /// Name of the variant if the node is an enum.
+                pub fn variant(self) -> Option<&'static str> {
+                    variant_match
+                }
+
+                /// Invokes `__op` with the name, the index in the vector and the value
+                /// of each field. For enums, the name of the field is the name of
+                /// the variant.
+                ///
+                /// Fields of type `Option<T>` are skipped if they are `None`.
+                #[allow(unused_variables)]
+                pub fn for_each_field(
+                    self,
+                    __op: &mut dyn FnMut(&'static str, Option<usize>, AstField<'ast>),
+                ) {
+                    fields_match
+                }
+            }
+        }
+    );
+    tokens.push_tokens(&from_impls);
+
+    tokens
+}
+
+/// Creates a match arm for `AstNodeRef::for_each_field`.
+fn make_fields_arm(
+    names: &HashSet<String>,
+    path: &Path,
+    variant_name: Option<&Ident>,
+    variant: &Fields,
+) -> Arm {
+    let mut stmts = vec![];
+    let mut fields: Punctuated<FieldValue, Token![,]> = Default::default();
+
+    for (i, field) in variant.iter().enumerate() {
+        let binding_ident = field
+            .ident
+            .clone()
+            .unwrap_or_else(|| Ident::new(&format!("_{}", i), call_site()));
+
+        let field_name = ast_field_name(field, i, variant_name, variant.len());
+        let expr = make_field_expr(
+            names,
+            &field.ty,
+            q!(
+                Vars {
+                    binding_ident: &binding_ident
+                },
+                { binding_ident }
+            )
+            .parse(),
+            &field_name,
+            q!({ None }).parse(),
+            0,
+        );
+        stmts.push(Stmt::Semi(expr, call_site()));
+
+        if field.ident.is_some() {
+            fields.push(
+                q!(
+                    Vars {
+                        field: &binding_ident
+                    },
+                    { field }
+                )
+                .parse(),
+            );
+        } else {
+            fields.push(FieldValue {
+                attrs: vec![],
+                member: Member::Unnamed(Index {
+                    index: i as _,
+                    span: path.span(),
+                }),
+                colon_token: Some(def_site()),
+                expr: q!(Vars { binding_ident }, { binding_ident }).parse(),
+            });
+        }
+    }
+
+    Arm {
+        attrs: vec![],
+        pat: q!(Vars { Path: path, fields }, { Path { fields } }).parse(),
+        guard: None,
+        fat_arrow_token: def_site(),
+        body: Box::new(Expr::Block(ExprBlock {
+            attrs: vec![],
+            label: None,
+            block: Block {
+                brace_token: def_site(),
+                stmts,
+            },
+        })),
+        comma: None,
+    }
+}
+
+/// Calls `__op` with each value stored in `expr`, which is a reference to `ty`.
+fn make_field_expr(
+    names: &HashSet<String>,
+    ty: &Type,
+    expr: Expr,
+    field_name: &LitStr,
+    index: Expr,
+    depth: usize,
+) -> Expr {
+    if let Some(arg) = as_box(ty).or_else(|| extract_generic("Arc", ty)) {
+        return make_field_expr(
+            names,
+            arg,
+            q!(Vars { expr }, { (&**expr) }).parse(),
+            field_name,
+            index,
+            depth,
+        );
+    }
+
+    if let Some(arg) = extract_generic("Option", ty) {
+        let v = Ident::new(&format!("v{}", depth), call_site());
+        let body = make_field_expr(
+            names,
+            arg,
+            q!(Vars { v: &v }, { v }).parse(),
+            field_name,
+            index,
+            depth + 1,
+        );
+
+        return q!(Vars { v, expr, body }, {
+            if let Some(v) = expr {
+                body
+            }
+        })
+        .parse();
+    }
+
+    if let Some(arg) = extract_vec(ty) {
+        let v = Ident::new(&format!("v{}", depth), call_site());
+        let idx = Ident::new(&format!("idx{}", depth), call_site());
+        let body = make_field_expr(
+            names,
+            arg,
+            q!(Vars { v: &v }, { v }).parse(),
+            field_name,
+            q!(Vars { idx: &idx }, { Some(idx) }).parse(),
+            depth + 1,
+        );
+
+        return q!(Vars { v, idx, expr, body }, {
+            for (idx, v) in expr.iter().enumerate() {
+                body
+            }
+        })
+        .parse();
+    }
+
+    let is_node = match ty {
+        Type::Path(p) => {
+            let last = p.path.segments.last().unwrap();
+            last.arguments.is_empty() && names.contains(&last.ident.to_string())
+        }
+        _ => false,
+    };
+
+    if is_node {
+        q!(
+            Vars {
+                Name: Ident::new(&type_to_name(ty), call_site()),
+                field_name,
+                index,
+                expr
+            },
+            { __op(field_name, index, AstField::Node(AstNodeRef::Name(expr))) }
+        )
+        .parse()
+    } else {
+        q!(
+            Vars {
+                field_name,
+                index,
+                expr
+            },
+            { __op(field_name, index, AstField::Value(expr)) }
+        )
+        .parse()
+    }
+}
+
 fn make(mode: Mode, stmts: &[Stmt]) -> Quote {
     let mut types = vec![];
     let mut methods = vec![];
@@ -130,6 +519,9 @@ fn make(mode: Mode, stmts: &[Stmt]) -> Quote {
                 Mode::Visit | Mode::VisitAll => {
                     q!(Vars { visit: &name }, ({ (**self).visit(n, _parent) })).parse()
                 }
+                Mode::VisitAstPath | Mode::VisitMutAstPath => {
+                    q!(Vars { visit: &name }, ({ (**self).visit(n, __ast_path) })).parse()
+                }
                 Mode::Fold | Mode::VisitMut => {
                     q!(Vars { visit: &name }, ({ (**self).visit(n) })).parse()
                 }
@@ -163,6 +555,16 @@ fn make(mode: Mode, stmts: &[Stmt]) -> Quote {
                         })
                     )
                     .parse(),
+                    Mode::VisitAstPath | Mode::VisitMutAstPath => q!(
+                        Vars { visit: &name },
+                        ({
+                            match self {
+                                swc_visit::Either::Left(v) => v.visit(n, __ast_path),
+                                swc_visit::Either::Right(v) => v.visit(n, __ast_path),
+                            }
+                        })
+                    )
+                    .parse(),
                     Mode::Fold | Mode::VisitMut => q!(
                         Vars { fold: &name },
                         ({
@@ -204,6 +606,15 @@ fn make(mode: Mode, stmts: &[Stmt]) -> Quote {
                         })
                     )
                     .parse(),
+                    Mode::VisitAstPath | Mode::VisitMutAstPath => q!(
+                        Vars { visit: &name },
+                        ({
+                            if self.enabled {
+                                self.visitor.visit(n, __ast_path)
+                            }
+                        })
+                    )
+                    .parse(),
                     Mode::Fold => q!(
                         Vars { fold: &name },
                         ({
@@ -264,6 +675,12 @@ fn make(mode: Mode, stmts: &[Stmt]) -> Quote {
                     }
                 })
                 .parse(),
+                Mode::VisitAstPath | Mode::VisitMutAstPath => q!(Vars { fn_name: &fn_name }, {
+                    {
+                        fn_name(self, n, __ast_path)
+                    }
+                })
+                .parse(),
                 Mode::VisitAll => Block {
                     brace_token: def_site(),
                     stmts: Default::default(),
@@ -334,6 +751,25 @@ fn make(mode: Mode, stmts: &[Stmt]) -> Quote {
                 }
             )),
 
+            Mode::VisitAstPath | Mode::VisitMutAstPath => tokens.push_tokens(&q!(
+                Vars {
+                    fn_name,
+                    default_body,
+                    Type: arg_ty,
+                    Trait: Ident::new(mode.trait_name(), call_site()),
+                },
+                {
+                    #[allow(unused_variables)]
+                    pub fn fn_name<V: ?Sized + Trait>(
+                        _visitor: &mut V,
+                        n: Type,
+                        __ast_path: &mut AstPath,
+                    ) {
+                        default_body
+                    }
+                }
+            )),
+
             Mode::VisitAll => {}
         }
     });
@@ -542,6 +978,50 @@ fn make(mode: Mode, stmts: &[Stmt]) -> Quote {
                     }
                 }
             }),
+            Mode::VisitAstPath => q!({
+                pub trait VisitWithPath<V: VisitAstPath> {
+                    fn visit_with_path(&self, v: &mut V, ast_path: &mut AstPath);
+
+                    /// Visit children nodes of self with `v`
+                    fn visit_children_with_path(&self, v: &mut V, ast_path: &mut AstPath);
+                }
+
+                impl<V, T> VisitWithPath<V> for Box<T>
+                where
+                    V: VisitAstPath,
+                    T: 'static + VisitWithPath<V>,
+                {
+                    fn visit_with_path(&self, v: &mut V, ast_path: &mut AstPath) {
+                        (**self).visit_with_path(v, ast_path)
+                    }
+
+                    /// Visit children nodes of self with `v`
+                    fn visit_children_with_path(&self, v: &mut V, ast_path: &mut AstPath) {
+                        (**self).visit_children_with_path(v, ast_path)
+                    }
+                }
+            }),
+            Mode::VisitMutAstPath => q!({
+                pub trait VisitMutWithPath<V: VisitMutAstPath> {
+                    fn visit_mut_with_path(&mut self, v: &mut V, ast_path: &mut AstPath);
+
+                    fn visit_mut_children_with_path(&mut self, v: &mut V, ast_path: &mut AstPath);
+                }
+
+                impl<V, T> VisitMutWithPath<V> for Box<T>
+                where
+                    V: VisitMutAstPath,
+                    T: 'static + VisitMutWithPath<V>,
+                {
+                    fn visit_mut_with_path(&mut self, v: &mut V, ast_path: &mut AstPath) {
+                        (**self).visit_mut_with_path(v, ast_path);
+                    }
+
+                    fn visit_mut_children_with_path(&mut self, v: &mut V, ast_path: &mut AstPath) {
+                        (**self).visit_mut_children_with_path(v, ast_path);
+                    }
+                }
+            }),
         };
         tokens.push_tokens(&trait_decl);
 
@@ -668,6 +1148,82 @@ fn make(mode: Mode, stmts: &[Stmt]) -> Quote {
                     ));
                 }
 
+                Mode::VisitAstPath => {
+                    let default_body = adjust_expr(mode, ty, q!({ self }).parse(), |expr| {
+                        q!(
+                            Vars {
+                                expr,
+                                method_name: &method_name
+                            },
+                            { method_name(_visitor, expr, __ast_path) }
+                        )
+                        .parse()
+                    });
+
+                    tokens.push_tokens(&q!(
+                        Vars {
+                            Type: ty,
+                            expr,
+                            default_body,
+                        },
+                        {
+                            impl<V: VisitAstPath> VisitWithPath<V> for Type {
+                                fn visit_with_path(&self, v: &mut V, __ast_path: &mut AstPath) {
+                                    expr
+                                }
+
+                                fn visit_children_with_path(
+                                    &self,
+                                    _visitor: &mut V,
+                                    __ast_path: &mut AstPath,
+                                ) {
+                                    default_body
+                                }
+                            }
+                        }
+                    ));
+                }
+
+                Mode::VisitMutAstPath => {
+                    let default_body = adjust_expr(mode, ty, q!({ self }).parse(), |expr| {
+                        q!(
+                            Vars {
+                                expr,
+                                method_name: &method_name
+                            },
+                            { method_name(_visitor, expr, __ast_path) }
+                        )
+                        .parse()
+                    });
+
+                    tokens.push_tokens(&q!(
+                        Vars {
+                            default_body,
+                            Type: ty,
+                            expr,
+                        },
+                        {
+                            impl<V: VisitMutAstPath> VisitMutWithPath<V> for Type {
+                                fn visit_mut_with_path(
+                                    &mut self,
+                                    v: &mut V,
+                                    __ast_path: &mut AstPath,
+                                ) {
+                                    expr
+                                }
+
+                                fn visit_mut_children_with_path(
+                                    &mut self,
+                                    _visitor: &mut V,
+                                    __ast_path: &mut AstPath,
+                                ) {
+                                    default_body
+                                }
+                            }
+                        }
+                    ));
+                }
+
                 Mode::Fold => {
                     tokens.push_tokens(&q!(
                         Vars {
@@ -703,24 +1259,26 @@ where
         expr = if is_opt_vec(ty) {
             match mode {
                 Mode::Fold => expr,
-                Mode::VisitMut => expr,
-                Mode::Visit | Mode::VisitAll => {
+                Mode::VisitMut | Mode::VisitMutAstPath => expr,
+                Mode::Visit | Mode::VisitAll | Mode::VisitAstPath => {
                     q!(Vars { expr }, { expr.as_ref().map(|v| &**v) }).parse()
                 }
             }
         } else {
             match mode {
                 Mode::Fold => expr,
-                Mode::VisitMut => expr,
-                Mode::Visit | Mode::VisitAll => q!(Vars { expr }, { expr.as_ref() }).parse(),
+                Mode::VisitMut | Mode::VisitMutAstPath => expr,
+                Mode::Visit | Mode::VisitAll | Mode::VisitAstPath => {
+                    q!(Vars { expr }, { expr.as_ref() }).parse()
+                }
             }
         };
     }
 
     if as_box(ty).is_some() {
         expr = match mode {
-            Mode::Visit | Mode::VisitAll => expr,
-            Mode::VisitMut => {
+            Mode::Visit | Mode::VisitAll | Mode::VisitAstPath => expr,
+            Mode::VisitMut | Mode::VisitMutAstPath => {
                 // TODO
                 expr
             }
@@ -732,8 +1290,8 @@ where
 
     if as_box(ty).is_some() {
         expr = match mode {
-            Mode::Visit | Mode::VisitAll => expr,
-            Mode::VisitMut => {
+            Mode::Visit | Mode::VisitAll | Mode::VisitAstPath => expr,
+            Mode::VisitMut | Mode::VisitMutAstPath => {
                 // TODO
                 expr
             }
@@ -771,10 +1329,28 @@ fn visit_expr(mode: Mode, ty: &Type, visitor: &Expr, expr: Expr) -> Expr {
             { visitor.visit_name(expr, _parent as _) }
         )
         .parse(),
+
+        Mode::VisitAstPath | Mode::VisitMutAstPath => q!(
+            Vars {
+                visitor,
+                expr,
+                visit_name
+            },
+            { visitor.visit_name(expr, __ast_path) }
+        )
+        .parse(),
     })
 }
 
-fn make_arm_from_struct(mode: Mode, path: &Path, variant: &Fields) -> Arm {
+/// `kind` is the name of the type and `variant_name` is the name of the enum
+/// variant, if `path` is a variant.
+fn make_arm_from_struct(
+    mode: Mode,
+    kind: &Ident,
+    variant_name: Option<&Ident>,
+    path: &Path,
+    variant: &Fields,
+) -> Arm {
     let mut stmts = vec![];
     let mut fields: Punctuated<FieldValue, Token![,]> = Default::default();
 
@@ -798,6 +1374,25 @@ fn make_arm_from_struct(mode: Mode, path: &Path, variant: &Fields) -> Arm {
             let expr = visit_expr(mode, ty, &q!({ _visitor }).parse(), expr);
             stmts.push(match mode {
                 Mode::VisitAll | Mode::Visit | Mode::VisitMut => Stmt::Semi(expr, call_site()),
+                Mode::VisitAstPath | Mode::VisitMutAstPath => {
+                    let field_name = ast_field_name(field, i, variant_name, variant.len());
+
+                    q!(
+                        Vars {
+                            Kind: kind,
+                            field_name,
+                            expr,
+                        },
+                        {
+                            {
+                                __ast_path.push(AstKind::Kind, field_name);
+                                expr;
+                                __ast_path.pop();
+                            }
+                        }
+                    )
+                    .parse()
+                }
                 Mode::Fold => q!(
                     Vars {
                         name: &binding_ident,
@@ -851,7 +1446,11 @@ fn make_arm_from_struct(mode: Mode, path: &Path, variant: &Fields) -> Arm {
                 .parse(),
             )
         }
-        Mode::VisitAll | Mode::Visit | Mode::VisitMut => {}
+        Mode::VisitAll
+        | Mode::Visit
+        | Mode::VisitMut
+        | Mode::VisitAstPath
+        | Mode::VisitMutAstPath => {}
     }
 
     let block = Block {
@@ -892,11 +1491,11 @@ fn method_sig(mode: Mode, ty: &Type) -> Signature {
                     p.push_value(q!(Vars { Type: ty }, { n: Type }).parse());
                 }
 
-                Mode::VisitMut => {
+                Mode::VisitMut | Mode::VisitMutAstPath => {
                     p.push_value(q!(Vars { Type: ty }, { n: &mut Type }).parse());
                 }
 
-                Mode::Visit | Mode::VisitAll => {
+                Mode::Visit | Mode::VisitAll | Mode::VisitAstPath => {
                     p.push_value(q!(Vars { Type: ty }, { n: &Type }).parse());
                 }
             }
@@ -909,6 +1508,10 @@ fn method_sig(mode: Mode, ty: &Type) -> Signature {
                     p.push_punct(def_site());
                     p.push_value(q!(Vars {}, { _parent: &dyn Node }).parse());
                 }
+                Mode::VisitAstPath | Mode::VisitMutAstPath => {
+                    p.push_punct(def_site());
+                    p.push_value(q!(Vars {}, { __ast_path: &mut AstPath }).parse());
+                }
             }
 
             p
@@ -932,6 +1535,19 @@ fn method_sig_from_ident(mode: Mode, v: &Ident) -> Signature {
 }
 
 /// Returns None if it's skipped.
+/// Name of the field used in `AstPath`. For unnamed fields of an enum variant,
+/// this is the name of the variant.
+fn ast_field_name(field: &Field, i: usize, variant_name: Option<&Ident>, len: usize) -> LitStr {
+    let name = match (&field.ident, variant_name) {
+        (Some(ident), _) => ident.to_string(),
+        (None, Some(v)) if len == 1 => v.to_string(),
+        (None, Some(v)) => format!("{}.{}", v, i),
+        (None, None) => i.to_string(),
+    };
+
+    LitStr::new(&name, call_site())
+}
+
 fn make_method(mode: Mode, e: &Item, types: &mut Vec<Type>) -> Option<TraitItemMethod> {
     Some(match e {
         Item::Struct(s) => {
@@ -949,7 +1565,8 @@ fn make_method(mode: Mode, e: &Item, types: &mut Vec<Type>) -> Option<TraitItemM
             }
 
             let block = {
-                let arm = make_arm_from_struct(mode, &s.ident.clone().into(), &s.fields);
+                let arm =
+                    make_arm_from_struct(mode, &s.ident, None, &s.ident.clone().into(), &s.fields);
 
                 let mut match_expr: ExprMatch = q!((match n {})).parse();
                 match_expr.arms.push(arm);
@@ -996,6 +1613,8 @@ fn make_method(mode: Mode, e: &Item, types: &mut Vec<Type>) -> Option<TraitItemM
 
                     let arm = make_arm_from_struct(
                         mode,
+                        &e.ident,
+                        Some(&variant.ident),
                         &q!(
                             Vars {
                                 Enum: &e.ident,
@@ -1058,6 +1677,10 @@ fn create_method_sig(mode: Mode, ty: &Type) -> Signature {
                         p.push_punct(def_site());
                         p.push_value(q!(Vars {}, { _parent: &dyn Node }).parse());
                     }
+                    Mode::VisitAstPath | Mode::VisitMutAstPath => {
+                        p.push_punct(def_site());
+                        p.push_value(q!(Vars {}, { __ast_path: &mut AstPath }).parse());
+                    }
                 }
 
                 p
@@ -1104,11 +1727,11 @@ fn create_method_sig(mode: Mode, ty: &Type) -> Signature {
                             return mk_exact(mode, ident, &arg);
                         }
 
-                        Mode::VisitMut => {
+                        Mode::VisitMut | Mode::VisitMutAstPath => {
                             return mk_ref(mode, ident, &arg, true);
                         }
 
-                        Mode::Visit | Mode::VisitAll => {
+                        Mode::Visit | Mode::VisitAll | Mode::VisitAstPath => {
                             return mk_ref(mode, ident, &arg, false);
                         }
                     }
@@ -1126,14 +1749,14 @@ fn create_method_sig(mode: Mode, ty: &Type) -> Signature {
                                     &q!(Vars { item }, { Option<Vec<item>> }).parse(),
                                 );
                             }
-                            Mode::VisitMut => {
+                            Mode::VisitMut | Mode::VisitMutAstPath => {
                                 return mk_exact(
                                     mode,
                                     ident,
                                     &q!(Vars { item }, { &mut Option<Vec<item>> }).parse(),
                                 );
                             }
-                            Mode::Visit | Mode::VisitAll => {
+                            Mode::Visit | Mode::VisitAll | Mode::VisitAstPath => {
                                 return mk_exact(
                                     mode,
                                     ident,
@@ -1151,14 +1774,14 @@ fn create_method_sig(mode: Mode, ty: &Type) -> Signature {
                                 &q!(Vars { arg }, { Option<arg> }).parse(),
                             );
                         }
-                        Mode::VisitMut => {
+                        Mode::VisitMut | Mode::VisitMutAstPath => {
                             return mk_exact(
                                 mode,
                                 ident,
                                 &q!(Vars { arg }, { &mut Option<arg> }).parse(),
                             );
                         }
-                        Mode::Visit | Mode::VisitAll => {
+                        Mode::Visit | Mode::VisitAll | Mode::VisitAstPath => {
                             return mk_exact(
                                 mode,
                                 ident,
@@ -1185,7 +1808,7 @@ fn create_method_sig(mode: Mode, ty: &Type) -> Signature {
                                                 &q!(Vars { arg }, { Vec<arg> }).parse(),
                                             );
                                         }
-                                        Mode::VisitMut => {
+                                        Mode::VisitMut | Mode::VisitMutAstPath => {
                                             return mk_ref(
                                                 mode,
                                                 ident,
@@ -1193,7 +1816,7 @@ fn create_method_sig(mode: Mode, ty: &Type) -> Signature {
                                                 true,
                                             );
                                         }
-                                        Mode::Visit | Mode::VisitAll => {
+                                        Mode::Visit | Mode::VisitAll | Mode::VisitAstPath => {
                                             return mk_ref(
                                                 mode,
                                                 ident,
@@ -1213,10 +1836,10 @@ fn create_method_sig(mode: Mode, ty: &Type) -> Signature {
 
             match mode {
                 Mode::Fold => return mk_exact(mode, ident, ty),
-                Mode::VisitMut => {
+                Mode::VisitMut | Mode::VisitMutAstPath => {
                     return mk_ref(mode, ident, ty, true);
                 }
-                Mode::Visit | Mode::VisitAll => {
+                Mode::Visit | Mode::VisitAll | Mode::VisitAstPath => {
                     return mk_ref(mode, ident, ty, false);
                 }
             }
@@ -1241,7 +1864,12 @@ fn create_method_body(mode: Mode, ty: &Type) -> Block {
 
                 return q!(Vars { visit }, ({ _visitor.visit(n, _parent) })).parse();
             }
-            Mode::VisitMut => {
+            Mode::VisitAstPath => {
+                let visit = method_name(mode, ty);
+
+                return q!(Vars { visit }, ({ _visitor.visit(n, __ast_path) })).parse();
+            }
+            Mode::VisitMut | Mode::VisitMutAstPath => {
                 return Block {
                     brace_token: def_site(),
                     stmts: vec![],
@@ -1275,7 +1903,11 @@ fn create_method_body(mode: Mode, ty: &Type) -> Block {
                             )
                             .parse();
                         }
-                        Mode::VisitAll | Mode::Visit | Mode::VisitMut => {
+                        Mode::VisitAll
+                        | Mode::Visit
+                        | Mode::VisitMut
+                        | Mode::VisitAstPath
+                        | Mode::VisitMutAstPath => {
                             return create_method_body(mode, arg);
                         }
                     }
@@ -1346,6 +1978,17 @@ fn create_method_body(mode: Mode, ty: &Type) -> Block {
                                             })
                                         )
                                         .parse(),
+
+                                        Mode::VisitAstPath | Mode::VisitMutAstPath => q!(
+                                            Vars { ident },
+                                            ({
+                                                match n {
+                                                    Some(n) => _visitor.ident(n, __ast_path),
+                                                    None => {}
+                                                }
+                                            })
+                                        )
+                                        .parse(),
                                     };
                                 }
                                 _ => unimplemented!("generic parameter other than type"),
@@ -1379,8 +2022,8 @@ fn create_method_body(mode: Mode, ty: &Type) -> Block {
                                                 .parse();
                                             }
                                         }
-                                        Mode::Visit | Mode::VisitAll => {}
-                                        Mode::VisitMut => {}
+                                        Mode::Visit | Mode::VisitAll | Mode::VisitAstPath => {}
+                                        Mode::VisitMut | Mode::VisitMutAstPath => {}
                                     }
 
                                     return if is_option(arg) {
@@ -1409,6 +2052,28 @@ fn create_method_body(mode: Mode, ty: &Type) -> Block {
                                                 })
                                             )
                                             .parse(),
+                                            Mode::VisitAstPath => q!(
+                                                Vars { ident },
+                                                ({
+                                                    n.iter().enumerate().for_each(|(idx, v)| {
+                                                        __ast_path.with_index(idx, |__ast_path| {
+                                                            _visitor.ident(v.as_ref(), __ast_path)
+                                                        })
+                                                    })
+                                                })
+                                            )
+                                            .parse(),
+                                            Mode::VisitMutAstPath => q!(
+                                                Vars { ident },
+                                                ({
+                                                    n.iter_mut().enumerate().for_each(|(idx, v)| {
+                                                        __ast_path.with_index(idx, |__ast_path| {
+                                                            _visitor.ident(v, __ast_path)
+                                                        })
+                                                    })
+                                                })
+                                            )
+                                            .parse(),
                                         }
                                     } else {
                                         match mode {
@@ -1437,6 +2102,30 @@ fn create_method_body(mode: Mode, ty: &Type) -> Block {
                                                 })
                                             )
                                             .parse(),
+
+                                            Mode::VisitAstPath => q!(
+                                                Vars { ident },
+                                                ({
+                                                    n.iter().enumerate().for_each(|(idx, v)| {
+                                                        __ast_path.with_index(idx, |__ast_path| {
+                                                            _visitor.ident(v, __ast_path)
+                                                        })
+                                                    })
+                                                })
+                                            )
+                                            .parse(),
+
+                                            Mode::VisitMutAstPath => q!(
+                                                Vars { ident },
+                                                ({
+                                                    n.iter_mut().enumerate().for_each(|(idx, v)| {
+                                                        __ast_path.with_index(idx, |__ast_path| {
+                                                            _visitor.ident(v, __ast_path)
+                                                        })
+                                                    })
+                                                })
+                                            )
+                                            .parse(),
                                         }
                                     };
                                 }
@@ -1450,7 +2139,11 @@ fn create_method_body(mode: Mode, ty: &Type) -> Block {
 
             match mode {
                 Mode::Fold => q!(({ return n })).parse(),
-                Mode::VisitAll | Mode::Visit | Mode::VisitMut => q!(({})).parse(),
+                Mode::VisitAll
+                | Mode::Visit
+                | Mode::VisitMut
+                | Mode::VisitAstPath
+                | Mode::VisitMutAstPath => q!(({})).parse(),
             }
         }
         Type::Ptr(_) => unimplemented!("type: pointer"),
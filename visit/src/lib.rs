@@ -1,4 +1,5 @@
 pub use either::Either;
+use std::ops::Deref;
 pub use swc_visit_macros::define;
 
 pub mod util;
@@ -118,3 +119,106 @@ where
         self.second.reset();
     }
 }
+
+/// An entry of [AstKindPath].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AstPathEntry<K> {
+    /// Kind of the ancestor node.
+    pub kind: K,
+    /// Name of the field which contains the next node. For enums, this is the
+    /// name of the variant.
+    pub field: &'static str,
+    /// Index of the next node if the field is a vector.
+    pub index: Option<usize>,
+}
+
+/// Ancestors of the node being visited, from the root node to the direct
+/// parent.
+///
+/// This is maintained by the `VisitAstPath` and `VisitMutAstPath` visitors
+/// generated by [define].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AstKindPath<K> {
+    path: Vec<AstPathEntry<K>>,
+}
+
+impl<K> Default for AstKindPath<K> {
+    fn default() -> Self {
+        Self { path: vec![] }
+    }
+}
+
+impl<K> AstKindPath<K> {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn push(&mut self, kind: K, field: &'static str) {
+        self.path.push(AstPathEntry {
+            kind,
+            field,
+            index: None,
+        });
+    }
+
+    pub fn pop(&mut self) -> Option<AstPathEntry<K>> {
+        self.path.pop()
+    }
+
+    /// Invokes `op` with `kind` and `field` pushed to the path.
+    pub fn with<F, Ret>(&mut self, kind: K, field: &'static str, op: F) -> Ret
+    where
+        F: FnOnce(&mut Self) -> Ret,
+    {
+        self.push(kind, field);
+        let ret = op(self);
+        self.pop();
+        ret
+    }
+
+    /// Invokes `op` with the index of the last entry set to `index`.
+    pub fn with_index<F, Ret>(&mut self, index: usize, op: F) -> Ret
+    where
+        F: FnOnce(&mut Self) -> Ret,
+    {
+        let old = self.path.last_mut().map(|e| e.index.replace(index));
+        let ret = op(self);
+        if let (Some(old), Some(last)) = (old, self.path.last_mut()) {
+            last.index = old;
+        }
+        ret
+    }
+
+    /// Direct parent of the node being visited.
+    pub fn parent(&self) -> Option<&AstPathEntry<K>> {
+        self.path.last()
+    }
+
+    /// Iterates over ancestors, starting from the direct parent.
+    pub fn ancestors(&self) -> impl '_ + Iterator<Item = &AstPathEntry<K>> {
+        self.path.iter().rev()
+    }
+
+    /// Returns true if kinds of the innermost ancestors are `kinds`.
+    ///
+    /// `kinds` is ordered from the outermost one, so the last element is
+    /// compared with the kind of the direct parent.
+    pub fn ends_with_kinds(&self, kinds: &[K]) -> bool
+    where
+        K: PartialEq,
+    {
+        kinds.len() <= self.path.len()
+            && self.path[self.path.len() - kinds.len()..]
+                .iter()
+                .zip(kinds)
+                .all(|(entry, kind)| entry.kind == *kind)
+    }
+}
+
+impl<K> Deref for AstKindPath<K> {
+    type Target = [AstPathEntry<K>];
+
+    fn deref(&self) -> &Self::Target {
+        &self.path
+    }
+}
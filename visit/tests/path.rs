@@ -0,0 +1,217 @@
+use std::any::Any;
+use swc_visit::define;
+
+/// Visitable nodes.
+pub trait Node: Any {}
+
+impl<T: ?Sized> Node for T where T: Any {}
+
+pub struct Call {
+    pub callee: Box<Expr>,
+    pub args: Vec<Expr>,
+}
+
+pub struct Object {
+    pub props: Vec<Option<Prop>>,
+}
+
+pub struct Prop {
+    pub key: Ident,
+    pub value: Option<Box<Expr>>,
+}
+
+pub struct Ident {
+    pub sym: String,
+}
+
+pub enum Expr {
+    Call(Call),
+    Object(Object),
+    Ident(Ident),
+}
+
+define!({
+    pub struct Call {
+        pub callee: Box<Expr>,
+        pub args: Vec<Expr>,
+    }
+    pub struct Object {
+        pub props: Vec<Option<Prop>>,
+    }
+    pub struct Prop {
+        pub key: Ident,
+        pub value: Option<Box<Expr>>,
+    }
+    pub struct Ident {
+        pub sym: String,
+    }
+    pub enum Expr {
+        Call(Call),
+        Object(Object),
+        Ident(Ident),
+    }
+});
+
+use self::path::{
+    AstField, AstKind, AstNodeRef, AstPath, VisitAstPath, VisitMutAstPath, VisitMutWithPath,
+    VisitWithPath,
+};
+
+fn ident(sym: &str) -> Ident {
+    Ident { sym: sym.into() }
+}
+
+/// `require(a, { b: c, d })`
+fn sample() -> Expr {
+    Expr::Call(Call {
+        callee: Box::new(Expr::Ident(ident("require"))),
+        args: vec![
+            Expr::Ident(ident("a")),
+            Expr::Object(Object {
+                props: vec![
+                    Some(Prop {
+                        key: ident("b"),
+                        value: Some(Box::new(Expr::Ident(ident("c")))),
+                    }),
+                    None,
+                    Some(Prop {
+                        key: ident("d"),
+                        value: None,
+                    }),
+                ],
+            }),
+        ],
+    })
+}
+
+fn describe(path: &AstPath) -> String {
+    path.iter()
+        .map(|e| match e.index {
+            Some(idx) => format!("{:?}.{}[{}]", e.kind, e.field, idx),
+            None => format!("{:?}.{}", e.kind, e.field),
+        })
+        .collect::<Vec<_>>()
+        .join(" > ")
+}
+
+#[derive(Default)]
+struct Collector {
+    idents: Vec<(String, String)>,
+}
+
+impl VisitAstPath for Collector {
+    fn visit_ident(&mut self, n: &Ident, ast_path: &mut AstPath) {
+        self.idents.push((n.sym.clone(), describe(ast_path)));
+    }
+}
+
+#[test]
+fn visit_records_ancestors() {
+    let mut v = Collector::default();
+    let mut path = AstPath::new();
+    sample().visit_with_path(&mut v, &mut path);
+
+    assert!(path.is_empty());
+    assert_eq!(
+        v.idents,
+        vec![
+            (
+                "require".to_string(),
+                "Expr.Call > Call.callee > Expr.Ident".to_string()
+            ),
+            (
+                "a".to_string(),
+                "Expr.Call > Call.args[0] > Expr.Ident".to_string()
+            ),
+            (
+                "b".to_string(),
+                "Expr.Call > Call.args[1] > Expr.Object > Object.props[0] > Prop.key".to_string()
+            ),
+            (
+                "c".to_string(),
+                "Expr.Call > Call.args[1] > Expr.Object > Object.props[0] > Prop.value > \
+                 Expr.Ident"
+                    .to_string()
+            ),
+            (
+                "d".to_string(),
+                "Expr.Call > Call.args[1] > Expr.Object > Object.props[2] > Prop.key".to_string()
+            ),
+        ]
+    );
+}
+
+/// Renames keys of objects passed to `require`.
+struct RenameRequireKeys;
+
+impl VisitMutAstPath for RenameRequireKeys {
+    fn visit_mut_ident(&mut self, n: &mut Ident, ast_path: &mut AstPath) {
+        let is_key = ast_path
+            .parent()
+            .map_or(false, |p| p.kind == AstKind::Prop && p.field == "key");
+
+        if is_key
+            && ast_path.ends_with_kinds(&[
+                AstKind::Call,
+                AstKind::Expr,
+                AstKind::Object,
+                AstKind::Prop,
+            ])
+        {
+            n.sym = format!("_{}", n.sym);
+        }
+    }
+}
+
+#[test]
+fn visit_mut_records_ancestors() {
+    let mut node = sample();
+    node.visit_mut_with_path(&mut RenameRequireKeys, &mut AstPath::new());
+
+    let mut v = Collector::default();
+    node.visit_with_path(&mut v, &mut AstPath::new());
+
+    let syms = v.idents.into_iter().map(|(sym, _)| sym).collect::<Vec<_>>();
+    assert_eq!(syms, vec!["require", "a", "_b", "c", "_d"]);
+}
+
+#[test]
+fn node_ref_fields() {
+    let node = sample();
+    let node = AstNodeRef::from(&node);
+    assert_eq!(node.kind(), AstKind::Expr);
+    assert_eq!(node.variant(), Some("Call"));
+
+    let mut fields = vec![];
+    node.for_each_field(&mut |name, _, field| match field {
+        AstField::Node(call) => call.for_each_field(&mut |name, idx, field| {
+            let desc = match field {
+                AstField::Node(n) => format!("{}", n.kind().name()),
+                AstField::Value(..) => "value".to_string(),
+            };
+            fields.push((name, idx, desc));
+        }),
+        AstField::Value(..) => unreachable!("{} should be a node", name),
+    });
+
+    assert_eq!(
+        fields,
+        vec![
+            ("callee", None, "Expr".to_string()),
+            ("args", Some(0), "Expr".to_string()),
+            ("args", Some(1), "Expr".to_string()),
+        ]
+    );
+
+    let key = Prop {
+        key: ident("key"),
+        value: None,
+    };
+    let mut sym = None;
+    AstNodeRef::from(&key.key).for_each_field(&mut |name, _, field| {
+        if let AstField::Value(v) = field {
+            sym = v.downcast_ref::<String>().cloned().map(|s| (name, s));
+        }
+    });
+    assert_eq!(sym, Some(("sym", "key".to_string())));
+}